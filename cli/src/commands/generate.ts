@@ -1,6 +1,35 @@
 import { promises as fs } from 'fs';
 import path from 'path';
 
+// The directory of the nearest package.json, so the command works from
+// anywhere inside a package, like npm itself does.
+async function packageRoot(): Promise<string> {
+  let dir = process.cwd();
+  while (true) {
+    try {
+      await fs.access(path.join(dir, 'package.json'));
+      return dir;
+    } catch {
+      const parent = path.dirname(dir);
+      if (parent === dir) return process.cwd();
+      dir = parent;
+    }
+  }
+}
+
+// Creates a generated file, refusing to replace one that already exists so a
+// model or schema written by hand is never overwritten.
+async function createFile(filePath: string, content: string) {
+  await fs.mkdir(path.dirname(filePath), { recursive: true });
+  try {
+    await fs.writeFile(filePath, content, { flag: 'wx' });
+  } catch (error: any) {
+    if (error.code !== 'EEXIST') throw error;
+    console.error(`❌ ${filePath} already exists; remove it or pick another name`);
+    process.exit(1);
+  }
+}
+
 export async function generateModel(type: string, name: string, options: any) {
   console.log(`🔧 Generating ${type}: ${name}`);
   
@@ -22,7 +51,8 @@ export async function generateModel(type: string, name: string, options: any) {
 
 async function generateModelFile(name: string, options: any) {
   const modelName = name.charAt(0).toUpperCase() + name.slice(1);
-  const tableName = name.toLowerCase() + 's';
+  // The name is the one passed to loadSchema, which is the table's name.
+  const tableName = name;
   
   const fields = options.fields ? 
     options.fields.split(',').map((f: string) => {
@@ -70,9 +100,8 @@ ${fields}
 // const ${modelName}Model = await client.loadSchema<${modelName}>('${tableName}', ${name}Schema);
 `;
 
-  const modelPath = path.join(process.cwd(), 'src/models', `${name}.ts`);
-  await fs.mkdir(path.dirname(modelPath), { recursive: true });
-  await fs.writeFile(modelPath, modelContent);
+  const modelPath = path.join(await packageRoot(), 'src/models', `${name}.ts`);
+  await createFile(modelPath, modelContent);
   
   console.log(`✅ Model created: ${modelPath}`);
 }
@@ -108,9 +137,8 @@ export async function down(evolution: SchemaEvolution) {
 }
 `;
 
-  const migrationPath = path.join(process.cwd(), 'src/migrations', `${migrationName}.ts`);
-  await fs.mkdir(path.dirname(migrationPath), { recursive: true });
-  await fs.writeFile(migrationPath, migrationContent);
+  const migrationPath = path.join(await packageRoot(), 'src/migrations', `${migrationName}.ts`);
+  await createFile(migrationPath, migrationContent);
   
   console.log(`✅ Migration created: ${migrationPath}`);
 }
@@ -149,9 +177,8 @@ export const ${name}Schema = {
 };
 `;
 
-  const schemaPath = path.join(process.cwd(), 'src/schemas', `${name}.ts`);
-  await fs.mkdir(path.dirname(schemaPath), { recursive: true });
-  await fs.writeFile(schemaPath, schemaContent);
+  const schemaPath = path.join(await packageRoot(), 'src/schemas', `${name}.ts`);
+  await createFile(schemaPath, schemaContent);
   
   console.log(`✅ Schema created: ${schemaPath}`);
 }
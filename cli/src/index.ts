@@ -3,9 +3,9 @@
 import { Command } from 'commander';
 import chalk from 'chalk';
 import { initCommand } from './commands/init.js';
-import { generateCommand } from './commands/generate.js';
-import { migrateCommand } from './commands/migrate.js';
-import { dashboardCommand } from './commands/dashboard.js';
+import { generateModel } from './commands/generate.js';
+import { runMigrations } from './commands/migrate.js';
+import { startDashboard } from './commands/dashboard.js';
 
 const program = new Command();
 
@@ -38,7 +38,7 @@ program
   .argument('<type>', 'Type to generate (model, schema, migration)')
   .argument('<name>', 'Name of the generated item')
   .option('-f, --fields <fields>', 'Fields definition (e.g., "name:text,age:int")')
-  .action(generateCommand);
+  .action(generateModel);
 
 program
   .command('migrate')
@@ -46,13 +46,13 @@ program
   .option('-u, --up', 'Run migrations up')
   .option('-d, --down', 'Run migrations down')
   .option('-s, --status', 'Show migration status')
-  .action(migrateCommand);
+  .action(runMigrations);
 
 program
   .command('dashboard')
   .description('Launch the web dashboard')
   .option('-p, --port <port>', 'Port to run dashboard on', '3000')
   .option('-o, --open', 'Open browser automatically')
-  .action(dashboardCommand);
+  .action(startDashboard);
 
 program.parse();
@@ -69,7 +69,7 @@ describe('Zed Extension', () => {
 
     beforeEach(() => {
       try {
-        const grammarPath = join(__dirname, '../../zed-extension/grammars/cassandraorm.scm');
+        const grammarPath = join(__dirname, '../../zed-extension/languages/cassandraorm-schema/highlights.scm');
         grammarContent = readFileSync(grammarPath, 'utf-8');
       } catch (error) {
         grammarContent = `
@@ -0,0 +1,163 @@
+import { describe, it, expect } from '@jest/globals';
+import { check, counterTableCode, unknownTypes } from '../../zed-extension/src/analysis';
+import { parseSchema } from '../../zed-extension/src/schema';
+
+// Each problem `check` finds in the schema, as `[message, the text it points at]`
+const diagnostics = (source: string) =>
+  check(parseSchema(source, 0)).map(err => [err.message, source.slice(err.span.start, err.span.end)]);
+
+describe('cassandraorm-lsp schema analysis', () => {
+  it('has no diagnostics for valid schemas', () => {
+    expect(
+      diagnostics(`{
+  fields: { tenant: 'uuid', day: 'date', at: 'timeuuid', tags: 'frozen<set<text>>' },
+  key: [['tenant', 'tags'], 'day', 'at'],
+  clustering_order: { day: 'DESC', at: 'asc' },
+  materialized_views: {
+    by_day: { select: ['*'], key: ['day', 'tenant', 'tags', 'at'], clustering_order: { at: 'desc' } }
+  },
+  options: { timestamps: true }
+}`)
+    ).toEqual([]);
+
+    expect(
+      diagnostics("{ fields: { id: 'uuid' }, key: ['createdAt', 'id'], options: { timestamps: true } }")
+    ).toEqual([]);
+
+    expect(
+      diagnostics(`{
+  fields: { page: 'text', day: 'date', views: 'counter', label: { type: 'text', virtual: true } },
+  key: ['page', 'day'],
+  options: { timestamps: false, default_time_to_live: 0 }
+}`)
+    ).toEqual([]);
+  });
+
+  it('requires keys to name stored fields', () => {
+    expect(
+      diagnostics(`{
+  fields: { id: 'uuid', name: 'text', label: { type: 'text', virtual: true } },
+  key: [['id', 'tenant'], 'label'],
+  materialized_views: { by_name: { select: ['*'], key: ['nme', 'id'] } }
+}`)
+    ).toEqual([
+      ["`tenant` isn't a field of this model", 'tenant'],
+      ["`label` is a virtual field, which isn't stored, so it can't be in a key", 'label'],
+      ["`nme` isn't a field of this model", 'nme']
+    ]);
+  });
+
+  it('only orders clustering columns', () => {
+    expect(
+      diagnostics(`{
+  fields: { tenant: 'uuid', day: 'date', at: 'timeuuid', name: 'text' },
+  key: [['tenant'], 'day', 'at'],
+  clustering_order: { tenant: 'ASC', name: 'DESC', at: 'DESC' },
+  materialized_views: {
+    by_name: { select: ['*'], key: ['name', 'tenant', 'day', 'at'], clustering_order: { name: 'ASC' } },
+    flat: { select: ['*'], key: [['tenant', 'day', 'at']], clustering_order: { name: 'ASC' } }
+  }
+}`)
+    ).toEqual([
+      ['`tenant` is in the partition key; only clustering columns have an order', 'tenant'],
+      ["`name` isn't a clustering column; the clustering columns are `day`, `at`", 'name'],
+      ['`name` is in the partition key; only clustering columns have an order', 'name'],
+      ["`name` isn't a clustering column; the key has no clustering columns", 'name']
+    ]);
+  });
+
+  it('keeps collections and counters out of keys', () => {
+    expect(
+      diagnostics(`{
+  fields: { tags: { type: 'set', typeDef: '<text>' }, hits: 'counter', at: 'list<int>', id: 'uuid' },
+  key: [['tags', 'hits'], 'at', 'id']
+}`)
+    ).toEqual([
+      [
+        "`tags` is a set<text>; partition key columns can't be collections unless frozen, as in `frozen<set<text>>`",
+        'tags'
+      ],
+      ["`hits` is a counter; partition key columns can't be counters", 'hits'],
+      [
+        "`at` is a list<int>; clustering columns can't be collections unless frozen, as in `frozen<list<int>>`",
+        'at'
+      ]
+    ]);
+  });
+
+  it('reports invalid field types on the type', () => {
+    expect(
+      diagnostics(
+        "{ fields: { id: 'uuid', tags: 'list<lst<int>>', nested: 'map<text, set<int>>', " +
+          "home: 'frozen<address>', name: 'txt' }, key: 'id' }"
+      )
+    ).toEqual([
+      ['unknown type `lst`', 'lst'],
+      ['collections inside collections must be frozen, as in `frozen<set<int>>`', 'set<int>']
+    ]);
+  });
+
+  it('reports unknown user types apart', () => {
+    const source =
+      "{ fields: { id: 'uuid', home: 'frozen<address>', name: 'txt', tags: 'list<lst<int>>' }, key: 'id' }";
+    const schema = parseSchema(source, 0);
+    expect(unknownTypes(schema, ['address']).map(err => source.slice(err.span.start, err.span.end))).toEqual([
+      'txt'
+    ]);
+    expect(unknownTypes(schema, ['address', 'txt'])).toEqual([]);
+  });
+
+  it('only allows counters outside the key of counter tables', () => {
+    const counterTable = 'but `views` makes this a counter table';
+    expect(
+      diagnostics(`{
+  fields: { page: 'text', views: 'counter', title: 'text', tags: { type: 'set', typeDef: '<text>' }, hits: 'counter' },
+  key: ['page'],
+  options: { timestamps: true, versions: { key: 'rev' } }
+}`)
+    ).toEqual([
+      [
+        `\`title\` is a text, ${counterTable}, where every column outside the primary key must be a counter`,
+        'text'
+      ],
+      [
+        `\`tags\` is a set<text>, ${counterTable}, where every column outside the primary key must be a counter`,
+        'set'
+      ],
+      [
+        `\`timestamps\` adds \`createdAt\` timestamp and \`updatedAt\` timestamp, ${counterTable}, which can only add counters`,
+        'true'
+      ],
+      [`\`versions\` adds \`rev\` timeuuid, ${counterTable}, which can only add counters`, "{ key: 'rev' }"]
+    ]);
+  });
+
+  it('codes the problems moving the counters fixes', () => {
+    const schema = parseSchema(
+      "{ fields: { page: 'text', views: { type: 'counter', default: 0 }, title: 'text' }, key: ['page'] }",
+      0
+    );
+    expect(check(schema).map(err => [err.message.slice(0, 7), err.code])).toEqual([
+      ['`views`', undefined],
+      ['`title`', counterTableCode]
+    ]);
+  });
+
+  it('gives counters no defaults, indexes or TTL', () => {
+    expect(
+      diagnostics(`{
+  fields: { page: 'text', views: { type: 'counter', default: 0 }, hits: 'counter' },
+  key: ['page'],
+  indexes: ['views', 'page'],
+  options: { default_time_to_live: 86400 }
+}`)
+    ).toEqual([
+      ["`views` is a counter, which can't have a default; counters only change by being incremented", '0'],
+      ["`views` is a counter, which can't have a secondary index", 'views'],
+      [
+        "`views` makes this a counter table, and counters can't expire, so it can't have a `default_time_to_live`",
+        '86400'
+      ]
+    ]);
+  });
+});
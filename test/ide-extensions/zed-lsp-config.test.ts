@@ -0,0 +1,144 @@
+import { describe, it, expect } from '@jest/globals';
+import { discover, packageOptions, usesCassandraOrm } from '../../zed-extension/src/config';
+
+// Serves the given files as the contents of a package folder
+const files = (entries: Record<string, string>) => (name: string) => entries[name];
+
+describe('cassandraorm-lsp package configs', () => {
+  describe('discover', () => {
+    it('reads config files before the package.json key', () => {
+      const jsonc = `{
+        // local cluster
+        "clientOptions": { "contactPoints": ["127.0.0.1"], "localDataCenter": "dc1", },
+      }`;
+      const packageJson = JSON.stringify({
+        name: 'service',
+        cassandraorm: { clientOptions: { contactPoints: [], localDataCenter: 'dc2' } }
+      });
+
+      const found = discover(files({ 'cassandraorm.config.jsonc': jsonc, 'package.json': packageJson }));
+      expect(found?.source).toBe('cassandraorm.config.jsonc');
+      expect(found?.config.clientOptions.localDataCenter).toBe('dc1');
+
+      const fromPackage = discover(files({ 'package.json': packageJson }));
+      expect(fromPackage?.source).toBe('package.json#cassandraorm');
+      expect(fromPackage?.config.clientOptions.localDataCenter).toBe('dc2');
+
+      expect(discover(files({}))).toBeUndefined();
+      expect(discover(files({ 'package.json': '{ "name": "service" }' }))).toBeUndefined();
+    });
+
+    it('passes driver options through', () => {
+      const config = JSON.stringify({
+        clientOptions: {
+          contactPoints: ['10.0.0.1'],
+          localDataCenter: 'dc1',
+          protocolOptions: { port: 9042 },
+          sslOptions: { rejectUnauthorized: true }
+        },
+        ormOptions: {
+          defaultReplicationStrategy: { class: 'NetworkTopologyStrategy', dc1: 3 },
+          udfs: { avgState: { language: 'java', code: 'return state;' } }
+        }
+      });
+      expect(discover(files({ 'cassandraorm.config.json': config }))?.config.clientOptions.contactPoints)
+        .toEqual(['10.0.0.1']);
+    });
+
+    it('reports problems with their file and key path, like the extension', () => {
+      const problem = (name: string, config: unknown) => {
+        try {
+          discover(files({ [name]: JSON.stringify(config) }));
+        } catch (err) {
+          return (err as Error).message;
+        }
+        return undefined;
+      };
+      const clientOptions = { contactPoints: ['127.0.0.1'], localDataCenter: 'dc1' };
+
+      expect(problem('cassandraorm.config.json', { clientOptions, ormOptions: { udfs: {}, createKeyspace: 'yes' } }))
+        .toMatch(/^cassandraorm\.config\.json: ormOptions\.createKeyspace: invalid type/);
+      expect(problem('cassandraorm.config.json', { clientOptions, aiml: { openAI: {} } }))
+        .toMatch(/^cassandraorm\.config\.json: aiml\.openAI: unknown field `openAI`/);
+      expect(problem('cassandraorm.config.json', { clientOptions, ormOptions: { migration: 'recreate' } }))
+        .toMatch(/^cassandraorm\.config\.json: ormOptions\.migration: unknown variant `recreate`/);
+      expect(problem('package.json', {
+        cassandraorm: { clientOptions, distributed: { redis: { port: '6379' } } }
+      })).toMatch(/^package\.json: cassandraorm\.distributed\.redis\.port: invalid type/);
+      expect(problem('cassandraorm.config.json', { clientOptions: { contactPoints: ['127.0.0.1', 9042] } }))
+        .toMatch(/^cassandraorm\.config\.json: clientOptions\.contactPoints\[1\]: invalid type/);
+      expect(problem('cassandraorm.config.json', { clientOptions: { contactPoints: [] } }))
+        .toBe('cassandraorm.config.json: clientOptions: missing field `localDataCenter`');
+      expect(problem('cassandraorm.config.json', {}))
+        .toBe('cassandraorm.config.json: missing field `clientOptions`');
+      expect(() => discover(files({ 'cassandraorm.config.json': '{' }))).toThrow(/^cassandraorm\.config\.json: /);
+    });
+  });
+
+  describe('usesCassandraOrm', () => {
+    it('recognizes packages that depend on the ORM or have a config', () => {
+      expect(usesCassandraOrm(files({
+        'package.json': JSON.stringify({ dependencies: { 'cassandraorm-js': '^2.1.0' } })
+      }))).toBe(true);
+      expect(usesCassandraOrm(files({
+        'package.json': JSON.stringify({ devDependencies: { 'cassandraorm-js': '^2.1.0' } })
+      }))).toBe(true);
+      expect(usesCassandraOrm(files({ 'cassandraorm.config.json': '{}' }))).toBe(true);
+      expect(usesCassandraOrm(files({
+        'package.json': JSON.stringify({ cassandraorm: { clientOptions: {} } })
+      }))).toBe(true);
+      // A broken config still marks the package, so the server reports it.
+      expect(usesCassandraOrm(files({ 'cassandraorm.config.jsonc': '{' }))).toBe(true);
+
+      expect(usesCassandraOrm(files({
+        'package.json': JSON.stringify({ dependencies: { typescript: '^5.0.0' } })
+      }))).toBe(false);
+      expect(usesCassandraOrm(files({ 'package.json': '{ not json' }))).toBe(false);
+      expect(usesCassandraOrm(files({}))).toBe(false);
+    });
+  });
+
+  describe('packageOptions', () => {
+    const config = {
+      source: 'cassandraorm.config.json',
+      config: {
+        clientOptions: {
+          contactPoints: ['127.0.0.1'],
+          localDataCenter: 'dc1',
+          keyspace: 'billing',
+          credentials: { username: 'cassandra', password: 'secret' }
+        },
+        ormOptions: { migration: 'safe', udts: { invoice_line: { sku: 'text' } } }
+      }
+    };
+
+    it('takes the connection settings of the package config without credentials', () => {
+      expect(packageOptions('/repo/services/billing', config, undefined)).toEqual({
+        project: { root: '/repo/services/billing', configFile: 'cassandraorm.config.json' },
+        clientOptions: { contactPoints: ['127.0.0.1'], localDataCenter: 'dc1', keyspace: 'billing' },
+        ormOptions: { migration: 'safe', udts: { invoice_line: { sku: 'text' } } }
+      });
+    });
+
+    it('applies the user\'s options on top of the package config', () => {
+      const user = { clientOptions: { keyspace: 'billing_dev' }, ormOptions: { migration: 'alter' } };
+      const options = packageOptions('/repo/services/billing', config, user);
+      expect(options.clientOptions).toEqual({
+        contactPoints: ['127.0.0.1'],
+        localDataCenter: 'dc1',
+        keyspace: 'billing_dev'
+      });
+      expect(options.ormOptions).toEqual({ migration: 'alter', udts: { invoice_line: { sku: 'text' } } });
+    });
+
+    it('leaves out a package config that can\'t be read and reports it', () => {
+      const error = new Error('cassandraorm.config.json: ormOptions.migration: unknown variant `recreate`');
+      expect(packageOptions('/repo/services/users', error, undefined)).toEqual({
+        project: { root: '/repo/services/users', configFile: null, configError: error.message },
+        clientOptions: {},
+        ormOptions: {}
+      });
+      expect(packageOptions('/repo/services/users', undefined, undefined).ormOptions).toEqual({});
+    });
+  });
+});
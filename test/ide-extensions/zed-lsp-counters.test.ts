@@ -0,0 +1,119 @@
+import { describe, it, expect } from '@jest/globals';
+import { companionModel, movedSpans } from '../../zed-extension/src/counters';
+import { findModels, tableName } from '../../zed-extension/src/schema';
+import type { ModelSchema } from '../../zed-extension/src/schema';
+
+const PAGES = `
+export const Pages = await client.loadSchema('pages', {
+  fields: {
+    site: 'text',
+    path: { type: 'text', rule: { required: true } },
+    title: 'text',
+    views: { type: 'counter', default: 0 },
+    shares: 'counter'
+  },
+  key: [['site'], 'path'],
+  clustering_order: { path: 'asc' },
+  indexes: ['views']
+});
+`;
+
+const schemaOf = (source: string) => findModels(source)[0].schema as ModelSchema;
+
+// The companion model, or the message of the error stopping it
+const companion = (source: string) => {
+  const model = findModels(source)[0];
+  const schema = model.schema as ModelSchema;
+  try {
+    return companionModel(tableName(schema, model.name.value), schema, model.declaration, source);
+  } catch (err) {
+    return (err as Error).message;
+  }
+};
+
+// The original schema with the moved spans deleted
+const trimmed = (source: string) => {
+  const schema = schemaOf(source);
+  let text = source.slice(schema.span.start, schema.span.end);
+  for (const span of movedSpans(schema, source).reverse()) {
+    text = text.slice(0, span.start - schema.span.start) + text.slice(span.end - schema.span.start);
+  }
+  return text;
+};
+
+describe('cassandraorm-lsp counter tables', () => {
+  it('moves counters to a model with the same key', () => {
+    expect(companion(PAGES)).toBe(`export const PagesCounters = await client.loadSchema('pages_counters', {
+  fields: {
+    site: 'text',
+    path: { type: 'text', rule: { required: true } },
+    views: 'counter',
+    shares: 'counter'
+  },
+  key: [['site'], 'path'],
+  clustering_order: { path: 'ASC' }
+});`);
+
+    const source =
+      "const stats = this.orm.loadSchema('page_stats', { fields: { id: 'uuid', hits: 'counter' }, " +
+      "key: ['id', 'createdAt'], options: { timestamps: true } })";
+    expect(companion(source)).toBe(`const statsCounters = this.orm.loadSchema('page_stats_counters', {
+  fields: {
+    id: 'uuid',
+    createdAt: 'timestamp',
+    hits: 'counter'
+  },
+  key: ['id', 'createdAt']
+});`);
+  });
+
+  it('declares companion models like the original', () => {
+    expect(
+      companion("await loadSchema('pages', { fields: { id: 'uuid', title: 'text', views: 'counter' }, key: 'id' });")
+    ).toMatch(/^await loadSchema\('pages_counters', \{\n/);
+
+    expect(
+      companion(`export const pageSchema: ModelSchema = {
+  fields: { id: 'uuid', title: 'text', views: 'counter' },
+  key: 'id',
+  table_name: 'pages'
+};`)
+    ).toBe(`export const pageCountersSchema: ModelSchema = {
+  fields: {
+    id: 'uuid',
+    views: 'counter'
+  },
+  key: 'id',
+  table_name: 'pages_counters'
+};`);
+  });
+
+  it('only splits models mixing counters', () => {
+    expect(companion("loadSchema('pages', { fields: { id: 'uuid', title: 'text' }, key: ['id'] })")).toBe(
+      '`pages` has no counters to move'
+    );
+    expect(companion("loadSchema('pages', { fields: { id: 'uuid', views: 'counter' }, key: ['id'] })")).toBe(
+      '`pages` only has counters outside its primary key, so it can keep them'
+    );
+  });
+
+  it('removes counters and their indexes from the original model', () => {
+    expect(trimmed(PAGES)).toBe(`{
+  fields: {
+    site: 'text',
+    path: { type: 'text', rule: { required: true } },
+    title: 'text'
+  },
+  key: [['site'], 'path'],
+  clustering_order: { path: 'asc' },
+  indexes: []
+}`);
+
+    expect(
+      trimmed(
+        "loadSchema('t', { fields: { id: 'uuid', hits: 'counter', name: 'text' }, key: 'id', " +
+          "indexes: { by_hits: { target: 'hits' }, by_name: { target: 'name' } } })"
+      )
+    ).toBe("{ fields: { id: 'uuid', name: 'text' }, key: 'id', indexes: { by_name: { target: 'name' } } }");
+  });
+});
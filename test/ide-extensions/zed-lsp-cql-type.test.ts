@@ -0,0 +1,146 @@
+import { describe, it, expect } from '@jest/globals';
+import { formatType, parseType, unknownUserTypes, validateType } from '../../zed-extension/src/cql-type';
+import type { CqlType } from '../../zed-extension/src/cql-type';
+import { SchemaError } from '../../zed-extension/src/literal';
+import type { Span } from '../../zed-extension/src/literal';
+
+const parse = (text: string, offset = 0) => parseType([[text, { start: offset, end: offset + text.length }]]);
+
+// The text a span points at
+const at = (text: string, span: Span) => text.slice(span.start, span.end);
+
+// The error `read` throws
+const error = (read: () => unknown) => {
+  try {
+    read();
+  } catch (err) {
+    return err as SchemaError;
+  }
+  throw new Error('expected an error');
+};
+
+describe('cassandraorm-lsp CQL types', () => {
+  it('parses and normalizes types', () => {
+    const cases: [string, string][] = [
+      ['int', 'int'],
+      ['VARCHAR', 'text'],
+      ['json', 'text'],
+      ['list<text>', 'list<text>'],
+      ['Set < TimeUUID >', 'set<timeuuid>'],
+      ['map<varchar,int>', 'map<text, int>'],
+      ['frozen<map<text, list<int>>>', 'frozen<map<text, list<int>>>'],
+      ['tuple<int,text,frozen<list<blob>>>', 'tuple<int, text, frozen<list<blob>>>'],
+      ['vector<float, 1536>', 'vector<float, 1536>'],
+      ['frozen<Address>', 'frozen<address>'],
+      ['frozen<shop."PostalAddress">', 'frozen<shop."PostalAddress">'],
+      ['"text"', '"text"'],
+      ["'org.example.Point'", "'org.example.Point'"]
+    ];
+    for (const [text, normalized] of cases) {
+      expect(formatType(parse(text))).toBe(normalized);
+    }
+  });
+
+  it('keeps the span of every node', () => {
+    const text = 'frozen<map<text, list<int>>>';
+    const ty = parse(text);
+    expect(ty.span).toEqual({ start: 0, end: text.length });
+    const map = ty.kind === 'frozen' ? ty.element : undefined;
+    expect(map?.kind).toBe('map');
+    if (map?.kind !== 'map') {
+      return;
+    }
+    expect(at(text, map.span)).toBe('map<text, list<int>>');
+    expect(at(text, map.key.span)).toBe('text');
+    expect(at(text, map.value.span)).toBe('list<int>');
+
+    const vector = parse('vector<float, 1536>');
+    expect(vector).toMatchObject({ kind: 'vector', element: { kind: 'native', name: 'float' }, dimension: 1536 });
+
+    expect(parse('Shop.Address')).toMatchObject({ kind: 'udt', keyspace: 'shop', name: 'address' });
+  });
+
+  it('maps types split across strings back to each string', () => {
+    const source = "{ type: 'map', typeDef: '<text, lst<int>>' }";
+    const ty = source.indexOf('map');
+    const typeDef = source.indexOf('<');
+    const piece = (text: string, start: number): [string, Span] => [text, { start, end: start + text.length }];
+
+    const err = error(() => parseType([piece('map', ty), piece('<text, lst<int>>', typeDef)]));
+    expect(err.message).toBe('unknown type `lst`');
+    expect(at(source, err.span)).toBe('lst');
+
+    const map = parseType([piece('map', ty), piece('<text, int>', typeDef)]);
+    expect(formatType(map)).toBe('map<text, int>');
+    expect(map.span).toEqual({ start: ty, end: typeDef + '<text, int>'.length });
+  });
+
+  it('points syntax errors at the problem', () => {
+    const cases: [string, string, string][] = [
+      ['', 'expected a type', ''],
+      ['lst<int>', 'unknown type `lst`', 'lst'],
+      ['int<text>', "`int` doesn't take type arguments", '<'],
+      ['list', '`list` needs type arguments, as in `list<text>`', 'list'],
+      ['map<text>', '`map` takes 2 type arguments, found 1', '<text>'],
+      ['list<int, text>', '`list` takes 1 type argument, found 2', '<int, text>'],
+      ['list<int', 'expected `,` or `>`', ''],
+      ['list<int>>', 'unexpected `>`', '>'],
+      ['map<text, >', 'expected a type, found `>`', '>'],
+      ['tuple<>', 'expected a type, found `>`', '>'],
+      ['vector<float>', 'expected `,`, found `>`', '>'],
+      ['vector<float, 0>', 'a vector needs between 1 and 4294967295 dimensions', '0'],
+      ["list<'org.T>", 'unterminated quote', "'org.T>"],
+      ['text;', 'unexpected `;`', ';']
+    ];
+    for (const [text, message, span] of cases) {
+      const err = error(() => parse(text));
+      expect([err.message, at(text, err.span)]).toEqual([message, span]);
+    }
+  });
+
+  it('reports nesting and freezing mistakes', () => {
+    const problems = (ty: CqlType, text: string) => validateType(ty).map(err => [err.message, at(text, err.span)]);
+    const cases: [string, string, string][] = [
+      ['list<list<int>>', 'collections inside collections must be frozen, as in `frozen<list<int>>`', 'list<int>'],
+      ['map<text, set<int>>', 'collections inside collections must be frozen, as in `frozen<set<int>>`', 'set<int>'],
+      [
+        'vector<list<float>, 3>',
+        'collections inside collections must be frozen, as in `frozen<list<float>>`',
+        'list<float>'
+      ],
+      ['list<address>', 'user-defined types inside collections must be frozen, as in `frozen<address>`', 'address'],
+      ['frozen<int>', "`int` can't be frozen; only collections, tuples and user-defined types can", 'frozen<int>'],
+      ['list<counter>', "counters can't be used inside other types", 'counter'],
+      ['set<duration>', "sets can't contain durations", 'duration'],
+      ['map<duration, int>', "durations can't be map keys", 'duration']
+    ];
+    for (const [text, message, span] of cases) {
+      expect(problems(parse(text), text)).toEqual([[message, span]]);
+    }
+
+    for (const text of [
+      'counter',
+      'address',
+      'frozen<list<frozen<map<text, int>>>>',
+      'list<frozen<address>>',
+      'tuple<int, list<int>>',
+      'frozen<tuple<int, text>>',
+      'vector<float, 1536>'
+    ]) {
+      expect(problems(parse(text), text)).toEqual([]);
+    }
+  });
+
+  it('finds unknown user-defined types anywhere in a type', () => {
+    const known = ['address', 'shop.phone'];
+    const text = 'map<frozen<address>, frozen<adress>>';
+    const errors = unknownUserTypes(parse(text), known);
+    expect(errors.map(err => [err.message, at(text, err.span)])).toEqual([
+      ['unknown type `adress`; declare user-defined types in `ormOptions.udts`', 'adress']
+    ]);
+
+    expect(unknownUserTypes(parse('frozen<shop.phone>'), known)).toEqual([]);
+    expect(unknownUserTypes(parse('tuple<int, phone>'), known)).toHaveLength(1);
+    expect(unknownUserTypes(parse('list<int>'), [])).toEqual([]);
+  });
+});
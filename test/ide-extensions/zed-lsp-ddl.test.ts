@@ -0,0 +1,137 @@
+import { describe, it, expect } from '@jest/globals';
+import { statements } from '../../zed-extension/src/ddl';
+import { findModels, parseSchema } from '../../zed-extension/src/schema';
+import type { ModelSchema } from '../../zed-extension/src/schema';
+import { SchemaError } from '../../zed-extension/src/literal';
+
+const USERS = `
+export const Users = await client.loadSchema('users', {
+  fields: {
+    tenant: 'uuid',
+    id: 'timeuuid',
+    email: 'varchar',
+    userID: 'int',
+    tags: { type: 'set', typeDef: '<text>' },
+    info: { type: 'map', typeDef: '<text, frozen<list<int>>>' },
+    full_name: { type: 'text', virtual: true },
+    createdAt: 'timestamp'
+  },
+  key: [['tenant', 'userID'], 'id', 'email'],
+  clustering_order: { email: 'desc' },
+  indexes: ['tags', 'keys(info)'],
+  materialized_views: {
+    users_by_email: {
+      select: ['email', 'id'],
+      key: ['email', 'tenant', 'userID', 'id'],
+      clustering_order: { id: 'DESC' },
+      filters: { email: { $gte: 'a', $isnt: null }, id: { $isnt: null } }
+    }
+  },
+  options: {
+    timestamps: true,
+    versions: { key: 'version' },
+    compaction: { class: 'LeveledCompactionStrategy', sstable_size_in_mb: 160 },
+    caching: { keys: 'ALL', rows_per_partition: 'NONE' },
+    gc_grace_seconds: 864000,
+    default_time_to_live: 0,
+    comment: "Users' accounts"
+  }
+});
+`;
+
+const generate = (source: string, keyspace?: string) => {
+  const model = findModels(source)[0];
+  return statements(model.name.value, model.schema as ModelSchema, keyspace);
+};
+
+describe('cassandraorm-lsp CQL DDL', () => {
+  it('creates tables with their keys and options', () => {
+    const [table] = generate(USERS, 'myapp');
+    expect(table.label).toBe('CREATE TABLE users');
+    expect(table.text).toBe(`CREATE TABLE IF NOT EXISTS myapp.users (
+  tenant uuid,
+  id timeuuid,
+  email text,
+  "userID" int,
+  tags set<text>,
+  info map<text, frozen<list<int>>>,
+  "createdAt" timestamp,
+  "updatedAt" timestamp,
+  version timeuuid,
+  PRIMARY KEY ((tenant, "userID"), id, email)
+) WITH CLUSTERING ORDER BY (id ASC, email DESC)
+  AND compaction = {'class': 'LeveledCompactionStrategy', 'sstable_size_in_mb': 160}
+  AND caching = {'keys': 'ALL', 'rows_per_partition': 'NONE'}
+  AND gc_grace_seconds = 864000
+  AND default_time_to_live = 0
+  AND comment = 'Users'' accounts';`);
+  });
+
+  it('creates indexes and views after the table', () => {
+    const generated = generate(USERS);
+    expect(generated.map(statement => statement.label)).toEqual([
+      'CREATE TABLE users',
+      'CREATE INDEX users_tags_idx',
+      'CREATE INDEX users_keys_info_idx',
+      'CREATE MATERIALIZED VIEW users_by_email'
+    ]);
+    expect(generated[1].text).toBe('CREATE INDEX IF NOT EXISTS users_tags_idx ON users (tags);');
+    expect(generated[2].text).toBe('CREATE INDEX IF NOT EXISTS users_keys_info_idx ON users (KEYS(info));');
+    expect(generated[3].text).toBe(`CREATE MATERIALIZED VIEW IF NOT EXISTS users_by_email AS
+  SELECT email, id FROM users
+  WHERE email IS NOT NULL
+    AND tenant IS NOT NULL
+    AND "userID" IS NOT NULL
+    AND id IS NOT NULL
+    AND email >= 'a'
+  PRIMARY KEY (email, tenant, "userID", id)
+  WITH CLUSTERING ORDER BY (tenant ASC, "userID" ASC, id DESC);`);
+  });
+
+  it('keeps the name and options of named indexes', () => {
+    const schema = parseSchema(
+      `{
+  fields: { id: 'uuid', name: 'text', bio: 'text' },
+  key: 'id',
+  table_name: 'people',
+  indexes: {
+    people_name: { target: 'name', options: { using: 'org.apache.cassandra.index.sasi.SASIIndex', mode: 'CONTAINS' } },
+    people_text: { target: ['name', 'bio'], options: { using: 'sai' } }
+  }
+}`,
+      0
+    );
+    expect(statements('person', schema, undefined).map(statement => statement.text)).toEqual([
+      'CREATE TABLE IF NOT EXISTS people (\n  id uuid,\n  name text,\n  bio text,\n  PRIMARY KEY (id)\n);',
+      "CREATE CUSTOM INDEX IF NOT EXISTS people_name ON people (name) USING 'org.apache.cassandra.index.sasi.SASIIndex' WITH OPTIONS = {'mode': 'CONTAINS'};",
+      "CREATE INDEX IF NOT EXISTS people_text_name ON people (name) USING 'sai';",
+      "CREATE INDEX IF NOT EXISTS people_text_bio ON people (bio) USING 'sai';"
+    ]);
+  });
+
+  it('reports invalid types and options', () => {
+    const cases: [string, string][] = [
+      ["{ fields: { id: 'uuid' } }", 'missing `key`'],
+      [
+        "{ fields: { id: 'uuid', tags: 'list<list<int>>' }, key: 'id' }",
+        'fields.tags: collections inside collections must be frozen, as in `frozen<list<int>>`'
+      ],
+      ["{ fields: { id: 'uuid', tags: { type: 'lst', typeDef: '<int>' } }, key: 'id' }", 'fields.tags: unknown type `lst`'],
+      [
+        "{ fields: { id: 'uuid' }, key: 'id', options: { compaction: { class: Strategy } } }",
+        'options.compaction.class: expected a string, number or boolean'
+      ]
+    ];
+    for (const [source, message] of cases) {
+      const schema = parseSchema(source, 0);
+      let err: unknown;
+      try {
+        statements('t', schema, undefined);
+      } catch (e) {
+        err = e;
+      }
+      expect(err).toBeInstanceOf(SchemaError);
+      expect([source, (err as SchemaError).message]).toEqual([source, message]);
+    }
+  });
+});
@@ -0,0 +1,119 @@
+import { describe, it, expect } from '@jest/globals';
+import { SchemaError, get, lex, parseValue } from '../../zed-extension/src/literal';
+import type { Span, Value } from '../../zed-extension/src/literal';
+
+const parse = (source: string) => parseValue(source, 0);
+
+// The source text a span points at
+const text = (source: string, span: Span) => source.slice(span.start, span.end);
+
+// The error `read` throws
+const error = (read: () => unknown) => {
+  try {
+    read();
+  } catch (err) {
+    return err as SchemaError;
+  }
+  throw new Error('expected an error');
+};
+
+const items = (value: Value | undefined) => (value?.kind === 'array' ? value.items : []);
+
+describe('cassandraorm-lsp literals', () => {
+  it('parses literals with their spans', () => {
+    const source = `{ id: 'uuid', "count": 42, ok: true, none: null, neg: -1.5e3, hex: 0xff, list: [1, , 'a',], }`;
+    const value = parse(source);
+    expect(value.span).toEqual({ start: 0, end: source.length });
+    expect(get(value, 'id')).toMatchObject({ kind: 'string', value: 'uuid' });
+    expect(text(source, get(value, 'id')!.span)).toBe("'uuid'");
+    expect(get(value, 'count')).toMatchObject({ kind: 'number', value: 42 });
+    expect(get(value, 'ok')).toMatchObject({ kind: 'bool', value: true });
+    expect(get(value, 'none')?.kind).toBe('null');
+    expect(get(value, 'neg')).toMatchObject({ kind: 'number', value: -1500 });
+    expect(get(value, 'hex')).toMatchObject({ kind: 'number', value: 255 });
+    expect(items(get(value, 'list'))).toHaveLength(2);
+
+    const key = value.kind === 'object' ? value.properties[1].key : undefined;
+    expect(key?.value).toBe('count');
+    expect(text(source, key!.span)).toBe('"count"');
+  });
+
+  it('unescapes strings', () => {
+    const value = parse(`['it\\'s', "a\\tb", \`multi
+line\`, 'A\\u{1F600}\\x42']`);
+    expect(items(value).map(item => (item.kind === 'string' ? item.value : undefined)))
+      .toEqual(["it's", 'a\tb', 'multi\nline', 'A😀B']);
+  });
+
+  it('keeps code as opaque expressions', () => {
+    const source = `{
+      default: () => new Date(),
+      uuid: Uuid.random(),
+      pattern: /^[a-z\\/]+$/i,
+      custom: (value) => value.length > 2 && value !== '}',
+      label: \`\${first} \${last}\`,
+      compute(a, b) { return { a, b }; },
+      async load() { await x; },
+      ref: Types.uuid,
+      n: total / count,
+      after: 'still parsed',
+    }`;
+    const value = parse(source);
+    for (const key of ['default', 'uuid', 'pattern', 'custom', 'label', 'compute', 'load', 'n']) {
+      expect(get(value, key)?.kind).toBe('expression');
+    }
+    expect(text(source, get(value, 'default')!.span)).toBe('() => new Date()');
+    expect(text(source, get(value, 'compute')!.span)).toBe('(a, b) { return { a, b }; }');
+    expect(get(value, 'ref')).toMatchObject({ kind: 'identifier', name: 'Types.uuid' });
+    expect(get(value, 'after')).toMatchObject({ kind: 'string', value: 'still parsed' });
+  });
+
+  it('keeps the value of TypeScript assertions', () => {
+    const source = "{ order: 'DESC' as const, opts: { a: 1 } satisfies Record<string, number>, key: KEY!, }";
+    const value = parse(source);
+    expect(get(value, 'order')).toMatchObject({ kind: 'string', value: 'DESC' });
+    expect(text(source, get(value, 'order')!.span)).toBe("'DESC'");
+    expect(get(get(value, 'opts')!, 'a')).toBeDefined();
+    expect(get(value, 'key')).toMatchObject({ kind: 'identifier', name: 'KEY' });
+  });
+
+  it('skips comments and spreads and reads shorthand properties', () => {
+    const source = `{
+      // the id
+      id, /* inline */ ...base,
+      [computed]: 1,
+      'quoted-key': 2,
+    }`;
+    const value = parse(source);
+    const keys = value.kind === 'object' ? value.properties.map(property => property.key.value) : [];
+    expect(keys).toEqual(['id', '[computed]', 'quoted-key']);
+    expect(get(value, 'id')).toMatchObject({ kind: 'identifier', name: 'id' });
+  });
+
+  it('starts parsing at the offset and stops after the value', () => {
+    const source = "loadSchema('users', { key: ['id'] });\nconst other = {";
+    const value = parseValue(source, source.indexOf('{'));
+    expect(text(source, value.span)).toBe("{ key: ['id'] }");
+  });
+
+  it('points syntax errors at the problem', () => {
+    let source = "{ id: 'uuid' name: 'text' }";
+    let err = error(() => parse(source));
+    expect(err.message).toBe('expected `,`');
+    expect(text(source, err.span)).toBe('name');
+
+    source = "{ id: 'uuid, }";
+    err = error(() => parse(source));
+    expect(err.message).toBe('unterminated string');
+    expect(err.span.start).toBe(6);
+
+    expect(error(() => parse('{ id: }')).message).toBe('expected a value');
+  });
+
+  it('records the comments it skips', () => {
+    const source = 'a // line\n/* block */ b';
+    const { tokens, comments } = lex(source);
+    expect(tokens.map(token => text(source, token.span))).toEqual(['a', 'b']);
+    expect(comments.map(comment => text(source, comment))).toEqual([' line', ' block ']);
+  });
+});
@@ -0,0 +1,291 @@
+import { describe, it, expect } from '@jest/globals';
+import {
+  field,
+  findModels,
+  findUserTypes,
+  parseFieldType,
+  parseSchema,
+  tableName
+} from '../../zed-extension/src/schema';
+import type { Model, ModelSchema } from '../../zed-extension/src/schema';
+import { formatType } from '../../zed-extension/src/cql-type';
+import { SchemaError } from '../../zed-extension/src/literal';
+import type { Span, Spanned } from '../../zed-extension/src/literal';
+
+// The source text a span points at
+const text = (source: string, span: Span) => source.slice(span.start, span.end);
+
+const names = (items: Spanned<string>[]) => items.map(item => item.value);
+
+const schemaOf = (model: Model) => {
+  expect(model.schema).not.toBeInstanceOf(SchemaError);
+  return model.schema as ModelSchema;
+};
+
+// The error `read` throws
+const error = (read: () => unknown) => {
+  try {
+    read();
+  } catch (err) {
+    return err as SchemaError;
+  }
+  throw new Error('expected an error');
+};
+
+const USERS = `
+const client = createClient({ clientOptions: { keyspace: 'myapp' } });
+
+export const Users = await client.loadSchema('users', {
+  fields: {
+    tenant: 'uuid',
+    id: 'timeuuid',
+    email: {
+      type: 'text',
+      unique: true,
+      validate: { required: true, isEmail: true, maxLength: 255, pattern: /^\\S+@\\S+$/ }
+    },
+    tags: { type: 'set', typeDef: '<text>' },
+    created_at: { type: 'timestamp', default: () => new Date() },
+    full_name: { type: 'text', virtual: true }
+  },
+  key: [['tenant'], 'id'],
+  clustering_order: { id: 'DESC' as const },
+  relations: {
+    posts: { model: 'posts', foreignKey: 'user_id', type: 'hasMany' }
+  },
+  indexes: {
+    users_email_idx: { target: 'email', options: { mode: 'CONTAINS' } }
+  },
+  materialized_views: {
+    users_by_email: { select: ['*'], key: ['email', 'tenant', 'id'], clustering_order: { id: 'asc' } }
+  },
+  options: {
+    timestamps: { createdAt: 'created_at' },
+    versions: true,
+    compaction: { class: 'LeveledCompactionStrategy' },
+    gc_grace_seconds: 864000,
+    comment: 'Application users'
+  },
+  methods: {
+    greet() { return \`hi \${this.email}\`; }
+  },
+  before_save: (instance) => true
+});
+`;
+
+describe('cassandraorm-lsp schemas', () => {
+  describe('findModels', () => {
+    it('parses loadSchema calls into typed schemas', () => {
+      const models = findModels(USERS);
+      expect(models).toHaveLength(1);
+      expect(models[0].name.value).toBe('users');
+      expect(text(USERS, models[0].name.span)).toBe('users');
+
+      const schema = schemaOf(models[0]);
+      expect(text(USERS, schema.span).startsWith('{\n  fields')).toBe(true);
+      expect(tableName(schema, 'users')).toBe('users');
+      expect(schema.fields.map(f => [f.name.value, formatType(parseFieldType(f))])).toEqual([
+        ['tenant', 'uuid'],
+        ['id', 'timeuuid'],
+        ['email', 'text'],
+        ['tags', 'set<text>'],
+        ['created_at', 'timestamp'],
+        ['full_name', 'text']
+      ]);
+
+      const tenant = field(schema, 'tenant')!;
+      expect(text(USERS, tenant.span)).toBe("tenant: 'uuid'");
+      expect(text(USERS, tenant.cqlType.span)).toBe('uuid');
+
+      const email = field(schema, 'email')!;
+      expect(text(USERS, email.cqlType.span)).toBe('text');
+      expect(email.unique?.value).toBe(true);
+      expect(email.validate?.isEmail?.value).toBe(true);
+      expect(email.validate?.maxLength?.value).toBe(255);
+      expect(email.validate?.pattern?.kind).toBe('expression');
+
+      expect(text(USERS, field(schema, 'created_at')!.default!.span)).toBe('() => new Date()');
+      expect(field(schema, 'full_name')?.isVirtual?.value).toBe(true);
+
+      const key = schema.key!;
+      expect(names(key.partition)).toEqual(['tenant']);
+      expect(names(key.clustering)).toEqual(['id']);
+      expect(text(USERS, key.clustering[0].span)).toBe('id');
+
+      expect(schema.clusteringOrder).toHaveLength(1);
+      expect(schema.clusteringOrder[0].order.value).toBe('desc');
+      expect(text(USERS, schema.clusteringOrder[0].order.span)).toBe('DESC');
+
+      const relation = schema.relations[0];
+      expect([relation.name.value, relation.kind.value, relation.foreignKey.value]).toEqual([
+        'posts',
+        'hasMany',
+        'user_id'
+      ]);
+
+      const index = schema.indexes[0];
+      expect(index.name?.value).toBe('users_email_idx');
+      expect(names(index.target)).toEqual(['email']);
+      expect(index.options[0].key.value).toBe('mode');
+
+      const view = schema.materializedViews[0];
+      expect(view.name.value).toBe('users_by_email');
+      expect(names(view.select)).toEqual(['*']);
+      expect(names(view.key.partition)).toEqual(['email']);
+      expect(names(view.key.clustering)).toEqual(['tenant', 'id']);
+      expect(view.clusteringOrder[0].order.value).toBe('asc');
+
+      const options = schema.options!;
+      expect(options.timestamps?.enabled).toBe(true);
+      expect(options.timestamps?.createdAt?.value).toBe('created_at');
+      expect(options.timestamps?.updatedAt).toBeUndefined();
+      expect(options.versions?.enabled).toBe(true);
+      expect(options.compaction?.value[0].key.value).toBe('class');
+      expect(options.gcGraceSeconds?.value).toBe(864000);
+      expect(options.comment?.value).toBe('Application users');
+
+      expect(names(schema.methods)).toEqual(['greet']);
+    });
+
+    it('finds schemas declared as variables and exports', () => {
+      const source = `
+import type { ModelSchema } from 'cassandraorm-js';
+
+const postSchema: ModelSchema = { fields: { id: 'uuid' }, key: 'id' };
+export const Posts = await client.loadSchema('posts', postSchema);
+
+export const eventSchema = {
+  fields: { day: 'date', at: 'timestamp' },
+  key: ['day', 'at'],
+  table_name: 'events'
+};
+export const settings = { theme: 'dark' };
+`;
+      const models = findModels(source);
+      expect(models.map(model => model.name.value)).toEqual(['posts', 'events']);
+
+      const posts = schemaOf(models[0]);
+      expect(posts.fields[0].name.value).toBe('id');
+      expect(text(source, posts.span)).toBe("{ fields: { id: 'uuid' }, key: 'id' }");
+      expect(names(posts.key!.partition)).toEqual(['id']);
+
+      expect(tableName(schemaOf(models[1]), 'eventSchema')).toBe('events');
+      expect(text(source, models[1].name.span)).toBe('events');
+    });
+
+    it('names exported schemas after the loadSchema call using them', () => {
+      // What `cassandraorm generate model users` writes
+      const generated = `import { createClient } from 'cassandraorm-js';
+
+export interface Users {
+  id: string;
+  name: string;
+  created_at: Date;
+}
+
+export const usersSchema = {
+  fields: {
+    id: 'uuid',
+    name: 'text',
+    created_at: 'timestamp'
+  },
+  key: ['id']
+};
+
+// Usage example:
+// const client = createClient(config);
+// const UsersModel = await client.loadSchema<Users>('users', usersSchema);
+`;
+      const models = findModels(generated);
+      expect(models.map(model => model.name.value)).toEqual(['users']);
+      expect(text(generated, models[0].name.span)).toBe('users');
+      expect(tableName(schemaOf(models[0]), models[0].name.value)).toBe('users');
+
+      const source = `
+export const Users = await client.loadSchema<User>('users', usersSchema);
+export const usersSchema = { fields: { id: 'uuid' }, key: ['id'] };
+export const auditSchema = { fields: { id: 'uuid' }, key: ['id'] };
+`;
+      // The table of an unreferenced schema without `tableName` is unknown.
+      expect(findModels(source).map(model => model.name.value)).toEqual(['users']);
+    });
+
+    it('still lists unparsable schemas', () => {
+      const models = findModels("client.loadSchema('broken', { fields: { id: 42 } });");
+      expect(models).toHaveLength(1);
+      expect(models[0].name.value).toBe('broken');
+      expect(models[0].schema).toBeInstanceOf(SchemaError);
+    });
+  });
+
+  describe('parseSchema', () => {
+    it('reads express-cassandra index lists', () => {
+      const schema = parseSchema("{ fields: { name: 'text' }, key: ['name'], indexes: ['name', 'keys(info)'] }", 0);
+      expect(schema.indexes.map(index => [index.name === undefined, index.target[0].value])).toEqual([
+        [true, 'name'],
+        [true, 'keys(info)']
+      ]);
+    });
+
+    it('parses field types from type and typeDef', () => {
+      let source =
+        "{ fields: { tags: { type: 'set', typeDef: '<varchar>' }, info: { type: 'map', typeDef: '<text, lst<int>>' } } }";
+      let schema = parseSchema(source, 0);
+      const tags = parseFieldType(field(schema, 'tags')!);
+      expect(formatType(tags)).toBe('set<text>');
+      expect(text(source, tags.span)).toBe("set', typeDef: '<varchar>");
+
+      let err = error(() => parseFieldType(field(schema, 'info')!));
+      expect(err.message).toBe('unknown type `lst`');
+      expect(text(source, err.span)).toBe('lst');
+
+      // Offsets in a string with escapes can't be mapped back to the source.
+      source = "{ fields: { tags: '\\tlst<int>é' } }";
+      schema = parseSchema(source, 0);
+      err = error(() => parseFieldType(field(schema, 'tags')!));
+      expect(err.message).toBe('unknown type `lst`');
+      expect(text(source, err.span)).toBe('\\tlst<int>é');
+    });
+
+    it('names the path in errors and points at the value', () => {
+      const cases: [string, string, string][] = [
+        ["{ key: 'id' }", 'missing `fields`', "{ key: 'id' }"],
+        ['{ fields: { id: 42 } }', 'fields.id: expected a CQL type or a field definition', '42'],
+        ['{ fields: { id: { required: true } } }', 'fields.id: missing `type`', '{ required: true }'],
+        ["{ fields: { id: 'uuid' }, key: [] }", 'key: the key is empty', '[]'],
+        ["{ fields: { id: 'uuid' }, key: [['a'], 3] }", 'key[1]: expected a string', '3'],
+        ["{ fields: {}, clustering_order: { id: 'down' } }", "clustering_order.id: expected 'ASC' or 'DESC'", 'down'],
+        [
+          "{ fields: {}, relations: { a: { model: 'b', foreignKey: 'c', type: 'many' } } }",
+          "relations.a.type: expected 'hasOne', 'hasMany' or 'belongsTo'",
+          'many'
+        ],
+        ["{ fields: {}, options: { gc_grace_seconds: '10' } }", 'options.gc_grace_seconds: expected a number', "'10'"],
+        [
+          "{ fields: {}, options: { timestamps: 'yes' } }",
+          'options.timestamps: expected true, false or an object',
+          "'yes'"
+        ],
+        ["{ fields: { id: 'uuid' } key: 'id' }", 'expected `,`', 'key']
+      ];
+      for (const [source, message, at] of cases) {
+        const err = error(() => parseSchema(source, 0));
+        expect([source, err.message, text(source, err.span)]).toEqual([source, message, at]);
+      }
+    });
+  });
+
+  describe('findUserTypes', () => {
+    it('finds user types in udts objects', () => {
+      const source = `
+const client = createClient({
+  ormOptions: {
+    udts: { address: { fields: { city: 'text' } }, 'phone_number': { fields: {} } }
+  }
+});
+const other = { udts: {} };
+`;
+      expect(findUserTypes(source)).toEqual(['address', 'phone_number']);
+    });
+  });
+});
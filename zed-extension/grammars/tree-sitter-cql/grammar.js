@@ -0,0 +1,869 @@
+/**
+ * Tree-sitter grammar for the Cassandra Query Language (CQL 3, up to
+ * Cassandra 5.0): DDL, DML, batches, lightweight transactions, user-defined
+ * types, functions, aggregates, indexes and materialized views.
+ *
+ * Keywords are case-insensitive and most of them are not reserved, so they
+ * are matched through keyword extraction on `identifier` and stay usable as
+ * column names wherever CQL allows it.
+ */
+
+const KEYWORDS = [
+  'add', 'aggregate', 'allow', 'alter', 'and', 'apply', 'as', 'asc',
+  'batch', 'begin', 'by', 'called', 'cast', 'clustering', 'columnfamily',
+  'compact', 'contains', 'counter', 'create', 'custom', 'default', 'delete',
+  'desc', 'describe', 'distinct', 'drop', 'entries', 'exists', 'filtering',
+  'finalfunc', 'from', 'frozen', 'full', 'function', 'functions', 'group',
+  'if', 'in', 'index', 'initcond', 'input', 'insert', 'into', 'is', 'json',
+  'key', 'keys', 'keyspace', 'keyspaces', 'language', 'like', 'limit', 'list',
+  'map', 'materialized', 'not', 'null', 'on', 'options', 'or', 'order',
+  'partition', 'per', 'primary', 'rename', 'replace', 'returns', 'schema',
+  'select', 'set', 'sfunc', 'static', 'storage', 'stype', 'table', 'tables',
+  'to', 'token', 'truncate', 'ttl', 'tuple', 'type', 'types', 'unlogged',
+  'unset', 'update', 'use', 'using', 'values', 'vector', 'view', 'where',
+  'with', 'timestamp',
+];
+
+// `counter` and `timestamp` are also keywords and are added in `native_type`.
+const NATIVE_TYPES = [
+  'ascii', 'bigint', 'blob', 'boolean', 'date', 'decimal', 'double',
+  'duration', 'float', 'inet', 'int', 'smallint', 'text', 'time', 'timeuuid',
+  'tinyint', 'uuid', 'varchar', 'varint',
+];
+
+/**
+ * Builds a case-insensitive token for `word`. The precedence lets it win over
+ * `identifier` so that keyword extraction picks it up.
+ */
+function keyword(word) {
+  const pattern = word
+    .split('')
+    .map((c) => (/[a-z]/.test(c) ? `[${c}${c.toUpperCase()}]` : c))
+    .join('');
+  return token(prec(1, new RegExp(pattern)));
+}
+
+function commaSep1(rule) {
+  return seq(rule, repeat(seq(',', rule)));
+}
+
+function commaSep(rule) {
+  return optional(commaSep1(rule));
+}
+
+module.exports = grammar({
+  name: 'cql',
+
+  // The backticks of the JavaScript template strings the editor injects
+  // separate statements like whitespace.
+  extras: ($) => [/\s/, $.comment, '`'],
+
+  word: ($) => $._unquoted_identifier,
+
+  conflicts: ($) => [
+    [$._term, $._selector_expression],
+  ],
+
+  rules: {
+    // Statements may also follow each other without a semicolon, as the
+    // strings the editor injects from JavaScript do when they are parsed
+    // together.
+    source_file: ($) => repeat(choice($._statement, ';')),
+
+    _statement: ($) =>
+      choice(
+        $.create_keyspace_statement,
+        $.alter_keyspace_statement,
+        $.drop_keyspace_statement,
+        $.use_statement,
+        $.create_table_statement,
+        $.alter_table_statement,
+        $.drop_table_statement,
+        $.truncate_statement,
+        $.create_type_statement,
+        $.alter_type_statement,
+        $.drop_type_statement,
+        $.create_index_statement,
+        $.drop_index_statement,
+        $.create_materialized_view_statement,
+        $.alter_materialized_view_statement,
+        $.drop_materialized_view_statement,
+        $.create_function_statement,
+        $.drop_function_statement,
+        $.create_aggregate_statement,
+        $.drop_aggregate_statement,
+        $.describe_statement,
+        $.batch_statement,
+        $._dml_statement,
+      ),
+
+    _dml_statement: ($) =>
+      choice(
+        $.select_statement,
+        $.insert_statement,
+        $.update_statement,
+        $.delete_statement,
+      ),
+
+    // Keyspaces
+
+    create_keyspace_statement: ($) =>
+      seq(
+        $.keyword_create,
+        choice($.keyword_keyspace, $.keyword_schema),
+        optional($.if_not_exists),
+        field('name', $.identifier),
+        $.keyword_with,
+        $.properties,
+      ),
+
+    alter_keyspace_statement: ($) =>
+      seq(
+        $.keyword_alter,
+        choice($.keyword_keyspace, $.keyword_schema),
+        optional($.if_exists),
+        field('name', $.identifier),
+        $.keyword_with,
+        $.properties,
+      ),
+
+    drop_keyspace_statement: ($) =>
+      seq(
+        $.keyword_drop,
+        choice($.keyword_keyspace, $.keyword_schema),
+        optional($.if_exists),
+        field('name', $.identifier),
+      ),
+
+    use_statement: ($) => seq($.keyword_use, field('keyspace', $.identifier)),
+
+    // Tables
+
+    create_table_statement: ($) =>
+      seq(
+        $.keyword_create,
+        choice($.keyword_table, $.keyword_columnfamily),
+        optional($.if_not_exists),
+        field('name', $.qualified_name),
+        '(',
+        commaSep1(choice($.column_definition, $.primary_key_definition)),
+        optional(','),
+        ')',
+        optional(seq($.keyword_with, $.table_options)),
+      ),
+
+    column_definition: ($) =>
+      seq(
+        field('name', $.identifier),
+        field('type', $._type),
+        optional($.keyword_static),
+        optional(seq($.keyword_primary, $.keyword_key)),
+      ),
+
+    primary_key_definition: ($) =>
+      seq(
+        $.keyword_primary,
+        $.keyword_key,
+        '(',
+        field('partition_key', $.partition_key),
+        repeat(seq(',', field('clustering_column', $.identifier))),
+        ')',
+      ),
+
+    partition_key: ($) =>
+      choice($.identifier, seq('(', commaSep1($.identifier), ')')),
+
+    table_options: ($) =>
+      seq($._table_option, repeat(seq($.keyword_and, $._table_option))),
+
+    _table_option: ($) =>
+      choice($.property, $.clustering_order, $.compact_storage),
+
+    clustering_order: ($) =>
+      seq(
+        $.keyword_clustering,
+        $.keyword_order,
+        $.keyword_by,
+        '(',
+        commaSep1($.ordering),
+        ')',
+      ),
+
+    // A `DESC` after a column is its direction, not a `DESC` statement.
+    ordering: ($) =>
+      prec.right(
+        seq(
+          field('column', $.identifier),
+          optional(field('direction', choice($.keyword_asc, $.keyword_desc))),
+        ),
+      ),
+
+    compact_storage: ($) => seq($.keyword_compact, $.keyword_storage),
+
+    alter_table_statement: ($) =>
+      seq(
+        $.keyword_alter,
+        choice($.keyword_table, $.keyword_columnfamily),
+        optional($.if_exists),
+        field('name', $.qualified_name),
+        choice(
+          seq(
+            $.keyword_add,
+            optional($.if_not_exists),
+            choice(
+              $.column_definition,
+              seq('(', commaSep1($.column_definition), ')'),
+            ),
+          ),
+          seq(
+            $.keyword_drop,
+            optional($.if_exists),
+            choice($.identifier, seq('(', commaSep1($.identifier), ')')),
+          ),
+          seq(
+            $.keyword_alter,
+            field('column', $.identifier),
+            $.keyword_type,
+            field('type', $._type),
+          ),
+          seq($.keyword_rename, optional($.if_exists), $._renames),
+          seq($.keyword_with, $.table_options),
+        ),
+      ),
+
+    _renames: ($) =>
+      seq($.rename, repeat(seq($.keyword_and, $.rename))),
+
+    rename: ($) =>
+      seq(
+        field('from', $.identifier),
+        $.keyword_to,
+        field('to', $.identifier),
+      ),
+
+    drop_table_statement: ($) =>
+      seq(
+        $.keyword_drop,
+        choice($.keyword_table, $.keyword_columnfamily),
+        optional($.if_exists),
+        field('name', $.qualified_name),
+      ),
+
+    truncate_statement: ($) =>
+      seq(
+        $.keyword_truncate,
+        optional(choice($.keyword_table, $.keyword_columnfamily)),
+        field('name', $.qualified_name),
+      ),
+
+    // User-defined types
+
+    create_type_statement: ($) =>
+      seq(
+        $.keyword_create,
+        $.keyword_type,
+        optional($.if_not_exists),
+        field('name', $.qualified_name),
+        '(',
+        commaSep1($.field_definition),
+        optional(','),
+        ')',
+      ),
+
+    field_definition: ($) =>
+      seq(field('name', $.identifier), field('type', $._type)),
+
+    alter_type_statement: ($) =>
+      seq(
+        $.keyword_alter,
+        $.keyword_type,
+        optional($.if_exists),
+        field('name', $.qualified_name),
+        choice(
+          seq($.keyword_add, optional($.if_not_exists), $.field_definition),
+          seq($.keyword_rename, optional($.if_exists), $._renames),
+        ),
+      ),
+
+    drop_type_statement: ($) =>
+      seq(
+        $.keyword_drop,
+        $.keyword_type,
+        optional($.if_exists),
+        field('name', $.qualified_name),
+      ),
+
+    // Secondary indexes
+
+    create_index_statement: ($) =>
+      seq(
+        $.keyword_create,
+        optional($.keyword_custom),
+        $.keyword_index,
+        optional($.if_not_exists),
+        optional(field('name', $.identifier)),
+        $.keyword_on,
+        field('table', $.qualified_name),
+        '(',
+        $.index_target,
+        ')',
+        optional(
+          seq(
+            $.keyword_using,
+            field('class', $.string_literal),
+            optional(
+              seq($.keyword_with, $.keyword_options, '=', $.map_literal),
+            ),
+          ),
+        ),
+      ),
+
+    index_target: ($) =>
+      choice(
+        field('column', $.identifier),
+        seq(
+          choice(
+            $.keyword_keys,
+            $.keyword_values,
+            $.keyword_entries,
+            $.keyword_full,
+          ),
+          '(',
+          field('column', $.identifier),
+          ')',
+        ),
+      ),
+
+    drop_index_statement: ($) =>
+      seq(
+        $.keyword_drop,
+        $.keyword_index,
+        optional($.if_exists),
+        field('name', $.qualified_name),
+      ),
+
+    // Materialized views
+
+    create_materialized_view_statement: ($) =>
+      seq(
+        $.keyword_create,
+        $.keyword_materialized,
+        $.keyword_view,
+        optional($.if_not_exists),
+        field('name', $.qualified_name),
+        $.keyword_as,
+        $.keyword_select,
+        $.selection,
+        $.keyword_from,
+        field('base_table', $.qualified_name),
+        $.where_clause,
+        $.primary_key_definition,
+        optional(seq($.keyword_with, $.table_options)),
+      ),
+
+    alter_materialized_view_statement: ($) =>
+      seq(
+        $.keyword_alter,
+        $.keyword_materialized,
+        $.keyword_view,
+        optional($.if_exists),
+        field('name', $.qualified_name),
+        $.keyword_with,
+        $.table_options,
+      ),
+
+    drop_materialized_view_statement: ($) =>
+      seq(
+        $.keyword_drop,
+        $.keyword_materialized,
+        $.keyword_view,
+        optional($.if_exists),
+        field('name', $.qualified_name),
+      ),
+
+    // Functions and aggregates
+
+    create_function_statement: ($) =>
+      seq(
+        $.keyword_create,
+        optional(seq($.keyword_or, $.keyword_replace)),
+        $.keyword_function,
+        optional($.if_not_exists),
+        field('name', $.qualified_name),
+        '(',
+        commaSep($.parameter),
+        ')',
+        $.null_input_behavior,
+        $.keyword_returns,
+        field('return_type', $._type),
+        $.keyword_language,
+        field('language', $.identifier),
+        $.keyword_as,
+        field('body', choice($.string_literal, $.dollar_string)),
+      ),
+
+    parameter: ($) =>
+      seq(field('name', $.identifier), field('type', $._type)),
+
+    null_input_behavior: ($) =>
+      seq(
+        choice($.keyword_called, seq($.keyword_returns, $.keyword_null)),
+        $.keyword_on,
+        $.keyword_null,
+        $.keyword_input,
+      ),
+
+    drop_function_statement: ($) =>
+      seq(
+        $.keyword_drop,
+        $.keyword_function,
+        optional($.if_exists),
+        field('name', $.qualified_name),
+        optional(seq('(', commaSep($._type), ')')),
+      ),
+
+    create_aggregate_statement: ($) =>
+      seq(
+        $.keyword_create,
+        optional(seq($.keyword_or, $.keyword_replace)),
+        $.keyword_aggregate,
+        optional($.if_not_exists),
+        field('name', $.qualified_name),
+        '(',
+        commaSep($._type),
+        ')',
+        $.keyword_sfunc,
+        field('state_function', $.identifier),
+        $.keyword_stype,
+        field('state_type', $._type),
+        optional(seq($.keyword_finalfunc, field('final_function', $.identifier))),
+        optional(seq($.keyword_initcond, field('initial_condition', $._term))),
+      ),
+
+    drop_aggregate_statement: ($) =>
+      seq(
+        $.keyword_drop,
+        $.keyword_aggregate,
+        optional($.if_exists),
+        field('name', $.qualified_name),
+        optional(seq('(', commaSep($._type), ')')),
+      ),
+
+    // DESCRIBE, as used by cqlsh and schema dumps
+
+    describe_statement: ($) =>
+      seq(
+        choice($.keyword_describe, $.keyword_desc),
+        choice(
+          $.keyword_keyspaces,
+          $.keyword_tables,
+          $.keyword_types,
+          $.keyword_functions,
+          $.keyword_schema,
+          seq(
+            choice(
+              $.keyword_keyspace,
+              $.keyword_table,
+              $.keyword_type,
+              $.keyword_index,
+              $.keyword_function,
+              $.keyword_aggregate,
+              seq($.keyword_materialized, $.keyword_view),
+            ),
+            optional(field('name', $.qualified_name)),
+          ),
+          field('name', $.qualified_name),
+        ),
+      ),
+
+    // Data manipulation
+
+    select_statement: ($) =>
+      seq(
+        $.keyword_select,
+        optional($.keyword_json),
+        optional($.keyword_distinct),
+        $.selection,
+        $.keyword_from,
+        field('table', $.qualified_name),
+        optional($.where_clause),
+        optional($.group_by_clause),
+        optional($.order_by_clause),
+        optional($.per_partition_limit_clause),
+        optional($.limit_clause),
+        optional(seq($.keyword_allow, $.keyword_filtering)),
+      ),
+
+    selection: ($) => choice('*', commaSep1($.selector)),
+
+    selector: ($) =>
+      seq(
+        $._selector_expression,
+        optional(seq($.keyword_as, field('alias', $.identifier))),
+      ),
+
+    _selector_expression: ($) =>
+      choice(
+        $.column_reference,
+        $.function_call,
+        $.cast_expression,
+        $._term,
+      ),
+
+    column_reference: ($) =>
+      prec.left(seq($.identifier, repeat(seq('.', $.identifier)))),
+
+    cast_expression: ($) =>
+      seq(
+        $.keyword_cast,
+        '(',
+        $._selector_expression,
+        $.keyword_as,
+        $._type,
+        ')',
+      ),
+
+    where_clause: ($) =>
+      seq($.keyword_where, $.relation, repeat(seq($.keyword_and, $.relation))),
+
+    relation: ($) =>
+      choice(
+        seq(
+          field('left', $._relation_target),
+          field('operator', $.operator),
+          field('right', $._term),
+        ),
+        seq(
+          field('left', $._relation_target),
+          $.keyword_in,
+          '(',
+          commaSep($._term),
+          ')',
+        ),
+        seq(field('left', $._relation_target), $.keyword_in, $.bind_marker),
+        seq(
+          field('left', $._relation_target),
+          $.keyword_contains,
+          optional($.keyword_key),
+          field('right', $._term),
+        ),
+        seq(
+          field('left', $._relation_target),
+          $.keyword_is,
+          $.keyword_not,
+          $.keyword_null,
+        ),
+      ),
+
+    _relation_target: ($) =>
+      choice(
+        $.column_reference,
+        $.token_call,
+        $.tuple_of_columns,
+      ),
+
+    tuple_of_columns: ($) => seq('(', commaSep1($.identifier), ')'),
+
+    token_call: ($) =>
+      seq($.keyword_token, '(', commaSep1($.identifier), ')'),
+
+    operator: ($) => choice('=', '!=', '<', '<=', '>', '>=', $.keyword_like),
+
+    group_by_clause: ($) =>
+      seq($.keyword_group, $.keyword_by, commaSep1($.identifier)),
+
+    order_by_clause: ($) =>
+      seq($.keyword_order, $.keyword_by, commaSep1($.ordering)),
+
+    per_partition_limit_clause: ($) =>
+      seq(
+        $.keyword_per,
+        $.keyword_partition,
+        $.keyword_limit,
+        choice($.integer, $.bind_marker),
+      ),
+
+    limit_clause: ($) =>
+      seq($.keyword_limit, choice($.integer, $.bind_marker)),
+
+    insert_statement: ($) =>
+      seq(
+        $.keyword_insert,
+        $.keyword_into,
+        field('table', $.qualified_name),
+        choice(
+          seq(
+            '(',
+            field('columns', commaSep1($.identifier)),
+            ')',
+            $.keyword_values,
+            '(',
+            field('values', commaSep1($._term)),
+            ')',
+          ),
+          seq(
+            $.keyword_json,
+            choice($.string_literal, $.bind_marker),
+            optional(
+              seq(
+                $.keyword_default,
+                choice($.keyword_null, $.keyword_unset),
+              ),
+            ),
+          ),
+        ),
+        optional($.if_not_exists),
+        optional($.using_clause),
+      ),
+
+    update_statement: ($) =>
+      seq(
+        $.keyword_update,
+        field('table', $.qualified_name),
+        optional($.using_clause),
+        $.keyword_set,
+        commaSep1($.assignment),
+        $.where_clause,
+        optional(choice($.if_exists, $.if_clause)),
+      ),
+
+    assignment: ($) =>
+      seq(
+        field('target', $._assignment_target),
+        field('operator', choice('=', '+=', '-=')),
+        field('value', $._expression),
+      ),
+
+    _assignment_target: ($) =>
+      choice($.column_reference, $.element_access),
+
+    element_access: ($) =>
+      seq(field('column', $.identifier), '[', field('index', $._term), ']'),
+
+    _expression: ($) => choice($._term, $.binary_expression),
+
+    binary_expression: ($) =>
+      prec.left(
+        1,
+        seq(
+          field('left', choice($._term, $.column_reference)),
+          field('operator', choice('+', '-')),
+          field('right', choice($._term, $.column_reference)),
+        ),
+      ),
+
+    delete_statement: ($) =>
+      seq(
+        $.keyword_delete,
+        optional(commaSep1(choice($.column_reference, $.element_access))),
+        $.keyword_from,
+        field('table', $.qualified_name),
+        optional($.using_clause),
+        $.where_clause,
+        optional(choice($.if_exists, $.if_clause)),
+      ),
+
+    using_clause: ($) =>
+      seq(
+        $.keyword_using,
+        $.using_option,
+        repeat(seq($.keyword_and, $.using_option)),
+      ),
+
+    using_option: ($) =>
+      seq(
+        choice($.keyword_ttl, $.keyword_timestamp),
+        choice($.integer, $.bind_marker),
+      ),
+
+    // Lightweight transactions
+    if_clause: ($) =>
+      seq($.keyword_if, $.condition, repeat(seq($.keyword_and, $.condition))),
+
+    condition: ($) =>
+      choice(
+        seq(
+          field('left', $._assignment_target),
+          field('operator', $.operator),
+          field('right', $._term),
+        ),
+        seq(
+          field('left', $._assignment_target),
+          $.keyword_in,
+          '(',
+          commaSep($._term),
+          ')',
+        ),
+      ),
+
+    batch_statement: ($) =>
+      seq(
+        $.keyword_begin,
+        optional(choice($.keyword_unlogged, $.keyword_counter)),
+        $.keyword_batch,
+        optional($.using_clause),
+        repeat(seq(choice($.insert_statement, $.update_statement, $.delete_statement), optional(';'))),
+        $.keyword_apply,
+        $.keyword_batch,
+      ),
+
+    if_not_exists: ($) => seq($.keyword_if, $.keyword_not, $.keyword_exists),
+
+    if_exists: ($) => seq($.keyword_if, $.keyword_exists),
+
+    // Types
+
+    _type: ($) =>
+      choice(
+        $.native_type,
+        $.collection_type,
+        $.frozen_type,
+        $.tuple_type,
+        $.vector_type,
+        $.user_type,
+        $.custom_type,
+      ),
+
+    native_type: ($) =>
+      choice(
+        ...NATIVE_TYPES.map(keyword),
+        $.keyword_counter,
+        $.keyword_timestamp,
+      ),
+
+    collection_type: ($) =>
+      choice(
+        seq(choice($.keyword_list, $.keyword_set), '<', $._type, '>'),
+        seq($.keyword_map, '<', $._type, ',', $._type, '>'),
+      ),
+
+    frozen_type: ($) => seq($.keyword_frozen, '<', $._type, '>'),
+
+    tuple_type: ($) => seq($.keyword_tuple, '<', commaSep1($._type), '>'),
+
+    vector_type: ($) =>
+      seq($.keyword_vector, '<', $._type, ',', field('dimension', $.integer), '>'),
+
+    user_type: ($) => $.qualified_name,
+
+    custom_type: ($) => $.string_literal,
+
+    // Terms
+
+    _term: ($) =>
+      choice(
+        $._literal,
+        $.bind_marker,
+        $.function_call,
+        $.type_hint,
+        $.list_literal,
+        $.map_literal,
+        $.set_literal,
+        $.tuple_literal,
+      ),
+
+    _literal: ($) =>
+      choice(
+        $.string_literal,
+        $.dollar_string,
+        $.integer,
+        $.float,
+        $.boolean,
+        $.uuid,
+        $.blob,
+        $.duration,
+        alias($.keyword_null, $.null),
+      ),
+
+    function_call: ($) =>
+      seq(
+        field('name', $.qualified_name),
+        '(',
+        optional(choice('*', commaSep1($._selector_expression))),
+        ')',
+      ),
+
+    type_hint: ($) => prec(1, seq('(', $._type, ')', $._term)),
+
+    list_literal: ($) => seq('[', commaSep($._term), ']'),
+
+    set_literal: ($) => seq('{', commaSep1($._term), '}'),
+
+    map_literal: ($) => seq('{', commaSep($.map_entry), '}'),
+
+    map_entry: ($) =>
+      seq(
+        field('key', choice($._term, $.identifier)),
+        ':',
+        field('value', $._term),
+      ),
+
+    tuple_literal: ($) => seq('(', $._term, repeat1(seq(',', $._term)), ')'),
+
+    properties: ($) =>
+      seq($.property, repeat(seq($.keyword_and, $.property))),
+
+    property: ($) =>
+      seq(field('name', $.identifier), '=', field('value', choice($._term, $.identifier))),
+
+    bind_marker: ($) => choice('?', seq(':', $.identifier), $.interpolation),
+
+    qualified_name: ($) =>
+      seq(
+        optional(seq(field('keyspace', $.identifier), '.')),
+        field('name', $.identifier),
+      ),
+
+    identifier: ($) => choice($._unquoted_identifier, $.quoted_identifier, prec(1, $.interpolation)),
+
+    _unquoted_identifier: (_) => /[a-zA-Z_][a-zA-Z0-9_]*/,
+
+    quoted_identifier: (_) => /"([^"]|"")*"/,
+
+    // A JavaScript `${}` interpolation in a template string, standing for a
+    // name or a value.
+    interpolation: (_) => token(seq('${', repeat(choice(/[^{}]/, seq('{', /[^{}]*/, '}'))), '}')),
+
+    string_literal: (_) => /'([^']|'')*'/,
+
+    dollar_string: (_) => /\$\$([^$]|\$[^$])*\$\$/,
+
+    integer: (_) => /-?\d+/,
+
+    float: (_) =>
+      choice(
+        /-?\d+\.\d*([eE][+-]?\d+)?/,
+        /-?\d+[eE][+-]?\d+/,
+        keyword('nan'),
+        seq(optional('-'), keyword('infinity')),
+      ),
+
+    boolean: (_) => choice(keyword('true'), keyword('false')),
+
+    uuid: (_) =>
+      /[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}/,
+
+    blob: (_) => /0[xX][0-9a-fA-F]*/,
+
+    duration: (_) =>
+      token(
+        choice(
+          /-?(\d+([yY]|[mM][oO]|[wW]|[dD]|[hH]|[mM][sS]|[uU][sS]|µ[sS]|[nN][sS]|[mM]|[sS]))+/,
+          /-?P[0-9YMWDTHS]+/,
+        ),
+      ),
+
+    comment: (_) =>
+      token(
+        choice(
+          seq('--', /.*/),
+          seq('//', /.*/),
+          seq('/*', /[^*]*\*+([^/*][^*]*\*+)*/, '/'),
+        ),
+      ),
+
+    ...Object.fromEntries(
+      KEYWORDS.map((word) => [`keyword_${word}`, (_) => keyword(word)]),
+    ),
+  },
+});
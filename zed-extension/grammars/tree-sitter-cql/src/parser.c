@@ -0,0 +1,31734 @@
+/* Automatically @generated by tree-sitter v0.25.10 */
+
+#include "tree_sitter/parser.h"
+
+#if defined(__GNUC__) || defined(__clang__)
+#pragma GCC diagnostic ignored "-Wmissing-field-initializers"
+#endif
+
+#define LANGUAGE_VERSION 15
+#define STATE_COUNT 1114
+#define LARGE_STATE_COUNT 5
+#define SYMBOL_COUNT 268
+#define ALIAS_COUNT 1
+#define TOKEN_COUNT 152
+#define EXTERNAL_TOKEN_COUNT 0
+#define FIELD_COUNT 30
+#define MAX_ALIAS_SEQUENCE_LENGTH 18
+#define MAX_RESERVED_WORD_SET_SIZE 0
+#define PRODUCTION_ID_COUNT 110
+#define SUPERTYPE_COUNT 0
+
+enum ts_symbol_identifiers {
+  sym__unquoted_identifier = 1,
+  anon_sym_SEMI = 2,
+  anon_sym_LPAREN = 3,
+  anon_sym_COMMA = 4,
+  anon_sym_RPAREN = 5,
+  anon_sym_EQ = 6,
+  anon_sym_STAR = 7,
+  anon_sym_DOT = 8,
+  anon_sym_BANG_EQ = 9,
+  anon_sym_LT = 10,
+  anon_sym_LT_EQ = 11,
+  anon_sym_GT = 12,
+  anon_sym_GT_EQ = 13,
+  anon_sym_PLUS_EQ = 14,
+  anon_sym_DASH_EQ = 15,
+  anon_sym_LBRACK = 16,
+  anon_sym_RBRACK = 17,
+  anon_sym_PLUS = 18,
+  anon_sym_DASH = 19,
+  aux_sym_native_type_token1 = 20,
+  aux_sym_native_type_token2 = 21,
+  aux_sym_native_type_token3 = 22,
+  aux_sym_native_type_token4 = 23,
+  aux_sym_native_type_token5 = 24,
+  aux_sym_native_type_token6 = 25,
+  aux_sym_native_type_token7 = 26,
+  aux_sym_native_type_token8 = 27,
+  aux_sym_native_type_token9 = 28,
+  aux_sym_native_type_token10 = 29,
+  aux_sym_native_type_token11 = 30,
+  aux_sym_native_type_token12 = 31,
+  aux_sym_native_type_token13 = 32,
+  aux_sym_native_type_token14 = 33,
+  aux_sym_native_type_token15 = 34,
+  aux_sym_native_type_token16 = 35,
+  aux_sym_native_type_token17 = 36,
+  aux_sym_native_type_token18 = 37,
+  aux_sym_native_type_token19 = 38,
+  anon_sym_LBRACE = 39,
+  anon_sym_RBRACE = 40,
+  anon_sym_COLON = 41,
+  anon_sym_QMARK = 42,
+  sym_quoted_identifier = 43,
+  sym_interpolation = 44,
+  sym_string_literal = 45,
+  sym_dollar_string = 46,
+  sym_integer = 47,
+  aux_sym_float_token1 = 48,
+  aux_sym_float_token2 = 49,
+  aux_sym_float_token3 = 50,
+  aux_sym_float_token4 = 51,
+  aux_sym_boolean_token1 = 52,
+  aux_sym_boolean_token2 = 53,
+  sym_uuid = 54,
+  sym_blob = 55,
+  sym_duration = 56,
+  sym_comment = 57,
+  sym_keyword_add = 58,
+  sym_keyword_aggregate = 59,
+  sym_keyword_allow = 60,
+  sym_keyword_alter = 61,
+  sym_keyword_and = 62,
+  sym_keyword_apply = 63,
+  sym_keyword_as = 64,
+  sym_keyword_asc = 65,
+  sym_keyword_batch = 66,
+  sym_keyword_begin = 67,
+  sym_keyword_by = 68,
+  sym_keyword_called = 69,
+  sym_keyword_cast = 70,
+  sym_keyword_clustering = 71,
+  sym_keyword_columnfamily = 72,
+  sym_keyword_compact = 73,
+  sym_keyword_contains = 74,
+  sym_keyword_counter = 75,
+  sym_keyword_create = 76,
+  sym_keyword_custom = 77,
+  sym_keyword_default = 78,
+  sym_keyword_delete = 79,
+  sym_keyword_desc = 80,
+  sym_keyword_describe = 81,
+  sym_keyword_distinct = 82,
+  sym_keyword_drop = 83,
+  sym_keyword_entries = 84,
+  sym_keyword_exists = 85,
+  sym_keyword_filtering = 86,
+  sym_keyword_finalfunc = 87,
+  sym_keyword_from = 88,
+  sym_keyword_frozen = 89,
+  sym_keyword_full = 90,
+  sym_keyword_function = 91,
+  sym_keyword_functions = 92,
+  sym_keyword_group = 93,
+  sym_keyword_if = 94,
+  sym_keyword_in = 95,
+  sym_keyword_index = 96,
+  sym_keyword_initcond = 97,
+  sym_keyword_input = 98,
+  sym_keyword_insert = 99,
+  sym_keyword_into = 100,
+  sym_keyword_is = 101,
+  sym_keyword_json = 102,
+  sym_keyword_key = 103,
+  sym_keyword_keys = 104,
+  sym_keyword_keyspace = 105,
+  sym_keyword_keyspaces = 106,
+  sym_keyword_language = 107,
+  sym_keyword_like = 108,
+  sym_keyword_limit = 109,
+  sym_keyword_list = 110,
+  sym_keyword_map = 111,
+  sym_keyword_materialized = 112,
+  sym_keyword_not = 113,
+  sym_keyword_null = 114,
+  sym_keyword_on = 115,
+  sym_keyword_options = 116,
+  sym_keyword_or = 117,
+  sym_keyword_order = 118,
+  sym_keyword_partition = 119,
+  sym_keyword_per = 120,
+  sym_keyword_primary = 121,
+  sym_keyword_rename = 122,
+  sym_keyword_replace = 123,
+  sym_keyword_returns = 124,
+  sym_keyword_schema = 125,
+  sym_keyword_select = 126,
+  sym_keyword_set = 127,
+  sym_keyword_sfunc = 128,
+  sym_keyword_static = 129,
+  sym_keyword_storage = 130,
+  sym_keyword_stype = 131,
+  sym_keyword_table = 132,
+  sym_keyword_tables = 133,
+  sym_keyword_to = 134,
+  sym_keyword_token = 135,
+  sym_keyword_truncate = 136,
+  sym_keyword_ttl = 137,
+  sym_keyword_tuple = 138,
+  sym_keyword_type = 139,
+  sym_keyword_types = 140,
+  sym_keyword_unlogged = 141,
+  sym_keyword_unset = 142,
+  sym_keyword_update = 143,
+  sym_keyword_use = 144,
+  sym_keyword_using = 145,
+  sym_keyword_values = 146,
+  sym_keyword_vector = 147,
+  sym_keyword_view = 148,
+  sym_keyword_where = 149,
+  sym_keyword_with = 150,
+  sym_keyword_timestamp = 151,
+  sym_source_file = 152,
+  sym__statement = 153,
+  sym__dml_statement = 154,
+  sym_create_keyspace_statement = 155,
+  sym_alter_keyspace_statement = 156,
+  sym_drop_keyspace_statement = 157,
+  sym_use_statement = 158,
+  sym_create_table_statement = 159,
+  sym_column_definition = 160,
+  sym_primary_key_definition = 161,
+  sym_partition_key = 162,
+  sym_table_options = 163,
+  sym__table_option = 164,
+  sym_clustering_order = 165,
+  sym_ordering = 166,
+  sym_compact_storage = 167,
+  sym_alter_table_statement = 168,
+  sym__renames = 169,
+  sym_rename = 170,
+  sym_drop_table_statement = 171,
+  sym_truncate_statement = 172,
+  sym_create_type_statement = 173,
+  sym_field_definition = 174,
+  sym_alter_type_statement = 175,
+  sym_drop_type_statement = 176,
+  sym_create_index_statement = 177,
+  sym_index_target = 178,
+  sym_drop_index_statement = 179,
+  sym_create_materialized_view_statement = 180,
+  sym_alter_materialized_view_statement = 181,
+  sym_drop_materialized_view_statement = 182,
+  sym_create_function_statement = 183,
+  sym_parameter = 184,
+  sym_null_input_behavior = 185,
+  sym_drop_function_statement = 186,
+  sym_create_aggregate_statement = 187,
+  sym_drop_aggregate_statement = 188,
+  sym_describe_statement = 189,
+  sym_select_statement = 190,
+  sym_selection = 191,
+  sym_selector = 192,
+  sym__selector_expression = 193,
+  sym_column_reference = 194,
+  sym_cast_expression = 195,
+  sym_where_clause = 196,
+  sym_relation = 197,
+  sym__relation_target = 198,
+  sym_tuple_of_columns = 199,
+  sym_token_call = 200,
+  sym_operator = 201,
+  sym_group_by_clause = 202,
+  sym_order_by_clause = 203,
+  sym_per_partition_limit_clause = 204,
+  sym_limit_clause = 205,
+  sym_insert_statement = 206,
+  sym_update_statement = 207,
+  sym_assignment = 208,
+  sym__assignment_target = 209,
+  sym_element_access = 210,
+  sym__expression = 211,
+  sym_binary_expression = 212,
+  sym_delete_statement = 213,
+  sym_using_clause = 214,
+  sym_using_option = 215,
+  sym_if_clause = 216,
+  sym_condition = 217,
+  sym_batch_statement = 218,
+  sym_if_not_exists = 219,
+  sym_if_exists = 220,
+  sym__type = 221,
+  sym_native_type = 222,
+  sym_collection_type = 223,
+  sym_frozen_type = 224,
+  sym_tuple_type = 225,
+  sym_vector_type = 226,
+  sym_user_type = 227,
+  sym_custom_type = 228,
+  sym__term = 229,
+  sym__literal = 230,
+  sym_function_call = 231,
+  sym_type_hint = 232,
+  sym_list_literal = 233,
+  sym_set_literal = 234,
+  sym_map_literal = 235,
+  sym_map_entry = 236,
+  sym_tuple_literal = 237,
+  sym_properties = 238,
+  sym_property = 239,
+  sym_bind_marker = 240,
+  sym_qualified_name = 241,
+  sym_identifier = 242,
+  sym_float = 243,
+  sym_boolean = 244,
+  aux_sym_source_file_repeat1 = 245,
+  aux_sym_create_table_statement_repeat1 = 246,
+  aux_sym_primary_key_definition_repeat1 = 247,
+  aux_sym_partition_key_repeat1 = 248,
+  aux_sym_table_options_repeat1 = 249,
+  aux_sym_clustering_order_repeat1 = 250,
+  aux_sym_alter_table_statement_repeat1 = 251,
+  aux_sym__renames_repeat1 = 252,
+  aux_sym_create_type_statement_repeat1 = 253,
+  aux_sym_create_function_statement_repeat1 = 254,
+  aux_sym_drop_function_statement_repeat1 = 255,
+  aux_sym_selection_repeat1 = 256,
+  aux_sym_column_reference_repeat1 = 257,
+  aux_sym_where_clause_repeat1 = 258,
+  aux_sym_relation_repeat1 = 259,
+  aux_sym_update_statement_repeat1 = 260,
+  aux_sym_delete_statement_repeat1 = 261,
+  aux_sym_using_clause_repeat1 = 262,
+  aux_sym_if_clause_repeat1 = 263,
+  aux_sym_batch_statement_repeat1 = 264,
+  aux_sym_function_call_repeat1 = 265,
+  aux_sym_map_literal_repeat1 = 266,
+  aux_sym_properties_repeat1 = 267,
+  alias_sym_null = 268,
+};
+
+static const char * const ts_symbol_names[] = {
+  [ts_builtin_sym_end] = "end",
+  [sym__unquoted_identifier] = "_unquoted_identifier",
+  [anon_sym_SEMI] = ";",
+  [anon_sym_LPAREN] = "(",
+  [anon_sym_COMMA] = ",",
+  [anon_sym_RPAREN] = ")",
+  [anon_sym_EQ] = "=",
+  [anon_sym_STAR] = "*",
+  [anon_sym_DOT] = ".",
+  [anon_sym_BANG_EQ] = "!=",
+  [anon_sym_LT] = "<",
+  [anon_sym_LT_EQ] = "<=",
+  [anon_sym_GT] = ">",
+  [anon_sym_GT_EQ] = ">=",
+  [anon_sym_PLUS_EQ] = "+=",
+  [anon_sym_DASH_EQ] = "-=",
+  [anon_sym_LBRACK] = "[",
+  [anon_sym_RBRACK] = "]",
+  [anon_sym_PLUS] = "+",
+  [anon_sym_DASH] = "-",
+  [aux_sym_native_type_token1] = "native_type_token1",
+  [aux_sym_native_type_token2] = "native_type_token2",
+  [aux_sym_native_type_token3] = "native_type_token3",
+  [aux_sym_native_type_token4] = "native_type_token4",
+  [aux_sym_native_type_token5] = "native_type_token5",
+  [aux_sym_native_type_token6] = "native_type_token6",
+  [aux_sym_native_type_token7] = "native_type_token7",
+  [aux_sym_native_type_token8] = "native_type_token8",
+  [aux_sym_native_type_token9] = "native_type_token9",
+  [aux_sym_native_type_token10] = "native_type_token10",
+  [aux_sym_native_type_token11] = "native_type_token11",
+  [aux_sym_native_type_token12] = "native_type_token12",
+  [aux_sym_native_type_token13] = "native_type_token13",
+  [aux_sym_native_type_token14] = "native_type_token14",
+  [aux_sym_native_type_token15] = "native_type_token15",
+  [aux_sym_native_type_token16] = "native_type_token16",
+  [aux_sym_native_type_token17] = "native_type_token17",
+  [aux_sym_native_type_token18] = "native_type_token18",
+  [aux_sym_native_type_token19] = "native_type_token19",
+  [anon_sym_LBRACE] = "{",
+  [anon_sym_RBRACE] = "}",
+  [anon_sym_COLON] = ":",
+  [anon_sym_QMARK] = "\?",
+  [sym_quoted_identifier] = "quoted_identifier",
+  [sym_interpolation] = "interpolation",
+  [sym_string_literal] = "string_literal",
+  [sym_dollar_string] = "dollar_string",
+  [sym_integer] = "integer",
+  [aux_sym_float_token1] = "float_token1",
+  [aux_sym_float_token2] = "float_token2",
+  [aux_sym_float_token3] = "float_token3",
+  [aux_sym_float_token4] = "float_token4",
+  [aux_sym_boolean_token1] = "boolean_token1",
+  [aux_sym_boolean_token2] = "boolean_token2",
+  [sym_uuid] = "uuid",
+  [sym_blob] = "blob",
+  [sym_duration] = "duration",
+  [sym_comment] = "comment",
+  [sym_keyword_add] = "keyword_add",
+  [sym_keyword_aggregate] = "keyword_aggregate",
+  [sym_keyword_allow] = "keyword_allow",
+  [sym_keyword_alter] = "keyword_alter",
+  [sym_keyword_and] = "keyword_and",
+  [sym_keyword_apply] = "keyword_apply",
+  [sym_keyword_as] = "keyword_as",
+  [sym_keyword_asc] = "keyword_asc",
+  [sym_keyword_batch] = "keyword_batch",
+  [sym_keyword_begin] = "keyword_begin",
+  [sym_keyword_by] = "keyword_by",
+  [sym_keyword_called] = "keyword_called",
+  [sym_keyword_cast] = "keyword_cast",
+  [sym_keyword_clustering] = "keyword_clustering",
+  [sym_keyword_columnfamily] = "keyword_columnfamily",
+  [sym_keyword_compact] = "keyword_compact",
+  [sym_keyword_contains] = "keyword_contains",
+  [sym_keyword_counter] = "keyword_counter",
+  [sym_keyword_create] = "keyword_create",
+  [sym_keyword_custom] = "keyword_custom",
+  [sym_keyword_default] = "keyword_default",
+  [sym_keyword_delete] = "keyword_delete",
+  [sym_keyword_desc] = "keyword_desc",
+  [sym_keyword_describe] = "keyword_describe",
+  [sym_keyword_distinct] = "keyword_distinct",
+  [sym_keyword_drop] = "keyword_drop",
+  [sym_keyword_entries] = "keyword_entries",
+  [sym_keyword_exists] = "keyword_exists",
+  [sym_keyword_filtering] = "keyword_filtering",
+  [sym_keyword_finalfunc] = "keyword_finalfunc",
+  [sym_keyword_from] = "keyword_from",
+  [sym_keyword_frozen] = "keyword_frozen",
+  [sym_keyword_full] = "keyword_full",
+  [sym_keyword_function] = "keyword_function",
+  [sym_keyword_functions] = "keyword_functions",
+  [sym_keyword_group] = "keyword_group",
+  [sym_keyword_if] = "keyword_if",
+  [sym_keyword_in] = "keyword_in",
+  [sym_keyword_index] = "keyword_index",
+  [sym_keyword_initcond] = "keyword_initcond",
+  [sym_keyword_input] = "keyword_input",
+  [sym_keyword_insert] = "keyword_insert",
+  [sym_keyword_into] = "keyword_into",
+  [sym_keyword_is] = "keyword_is",
+  [sym_keyword_json] = "keyword_json",
+  [sym_keyword_key] = "keyword_key",
+  [sym_keyword_keys] = "keyword_keys",
+  [sym_keyword_keyspace] = "keyword_keyspace",
+  [sym_keyword_keyspaces] = "keyword_keyspaces",
+  [sym_keyword_language] = "keyword_language",
+  [sym_keyword_like] = "keyword_like",
+  [sym_keyword_limit] = "keyword_limit",
+  [sym_keyword_list] = "keyword_list",
+  [sym_keyword_map] = "keyword_map",
+  [sym_keyword_materialized] = "keyword_materialized",
+  [sym_keyword_not] = "keyword_not",
+  [sym_keyword_null] = "keyword_null",
+  [sym_keyword_on] = "keyword_on",
+  [sym_keyword_options] = "keyword_options",
+  [sym_keyword_or] = "keyword_or",
+  [sym_keyword_order] = "keyword_order",
+  [sym_keyword_partition] = "keyword_partition",
+  [sym_keyword_per] = "keyword_per",
+  [sym_keyword_primary] = "keyword_primary",
+  [sym_keyword_rename] = "keyword_rename",
+  [sym_keyword_replace] = "keyword_replace",
+  [sym_keyword_returns] = "keyword_returns",
+  [sym_keyword_schema] = "keyword_schema",
+  [sym_keyword_select] = "keyword_select",
+  [sym_keyword_set] = "keyword_set",
+  [sym_keyword_sfunc] = "keyword_sfunc",
+  [sym_keyword_static] = "keyword_static",
+  [sym_keyword_storage] = "keyword_storage",
+  [sym_keyword_stype] = "keyword_stype",
+  [sym_keyword_table] = "keyword_table",
+  [sym_keyword_tables] = "keyword_tables",
+  [sym_keyword_to] = "keyword_to",
+  [sym_keyword_token] = "keyword_token",
+  [sym_keyword_truncate] = "keyword_truncate",
+  [sym_keyword_ttl] = "keyword_ttl",
+  [sym_keyword_tuple] = "keyword_tuple",
+  [sym_keyword_type] = "keyword_type",
+  [sym_keyword_types] = "keyword_types",
+  [sym_keyword_unlogged] = "keyword_unlogged",
+  [sym_keyword_unset] = "keyword_unset",
+  [sym_keyword_update] = "keyword_update",
+  [sym_keyword_use] = "keyword_use",
+  [sym_keyword_using] = "keyword_using",
+  [sym_keyword_values] = "keyword_values",
+  [sym_keyword_vector] = "keyword_vector",
+  [sym_keyword_view] = "keyword_view",
+  [sym_keyword_where] = "keyword_where",
+  [sym_keyword_with] = "keyword_with",
+  [sym_keyword_timestamp] = "keyword_timestamp",
+  [sym_source_file] = "source_file",
+  [sym__statement] = "_statement",
+  [sym__dml_statement] = "_dml_statement",
+  [sym_create_keyspace_statement] = "create_keyspace_statement",
+  [sym_alter_keyspace_statement] = "alter_keyspace_statement",
+  [sym_drop_keyspace_statement] = "drop_keyspace_statement",
+  [sym_use_statement] = "use_statement",
+  [sym_create_table_statement] = "create_table_statement",
+  [sym_column_definition] = "column_definition",
+  [sym_primary_key_definition] = "primary_key_definition",
+  [sym_partition_key] = "partition_key",
+  [sym_table_options] = "table_options",
+  [sym__table_option] = "_table_option",
+  [sym_clustering_order] = "clustering_order",
+  [sym_ordering] = "ordering",
+  [sym_compact_storage] = "compact_storage",
+  [sym_alter_table_statement] = "alter_table_statement",
+  [sym__renames] = "_renames",
+  [sym_rename] = "rename",
+  [sym_drop_table_statement] = "drop_table_statement",
+  [sym_truncate_statement] = "truncate_statement",
+  [sym_create_type_statement] = "create_type_statement",
+  [sym_field_definition] = "field_definition",
+  [sym_alter_type_statement] = "alter_type_statement",
+  [sym_drop_type_statement] = "drop_type_statement",
+  [sym_create_index_statement] = "create_index_statement",
+  [sym_index_target] = "index_target",
+  [sym_drop_index_statement] = "drop_index_statement",
+  [sym_create_materialized_view_statement] = "create_materialized_view_statement",
+  [sym_alter_materialized_view_statement] = "alter_materialized_view_statement",
+  [sym_drop_materialized_view_statement] = "drop_materialized_view_statement",
+  [sym_create_function_statement] = "create_function_statement",
+  [sym_parameter] = "parameter",
+  [sym_null_input_behavior] = "null_input_behavior",
+  [sym_drop_function_statement] = "drop_function_statement",
+  [sym_create_aggregate_statement] = "create_aggregate_statement",
+  [sym_drop_aggregate_statement] = "drop_aggregate_statement",
+  [sym_describe_statement] = "describe_statement",
+  [sym_select_statement] = "select_statement",
+  [sym_selection] = "selection",
+  [sym_selector] = "selector",
+  [sym__selector_expression] = "_selector_expression",
+  [sym_column_reference] = "column_reference",
+  [sym_cast_expression] = "cast_expression",
+  [sym_where_clause] = "where_clause",
+  [sym_relation] = "relation",
+  [sym__relation_target] = "_relation_target",
+  [sym_tuple_of_columns] = "tuple_of_columns",
+  [sym_token_call] = "token_call",
+  [sym_operator] = "operator",
+  [sym_group_by_clause] = "group_by_clause",
+  [sym_order_by_clause] = "order_by_clause",
+  [sym_per_partition_limit_clause] = "per_partition_limit_clause",
+  [sym_limit_clause] = "limit_clause",
+  [sym_insert_statement] = "insert_statement",
+  [sym_update_statement] = "update_statement",
+  [sym_assignment] = "assignment",
+  [sym__assignment_target] = "_assignment_target",
+  [sym_element_access] = "element_access",
+  [sym__expression] = "_expression",
+  [sym_binary_expression] = "binary_expression",
+  [sym_delete_statement] = "delete_statement",
+  [sym_using_clause] = "using_clause",
+  [sym_using_option] = "using_option",
+  [sym_if_clause] = "if_clause",
+  [sym_condition] = "condition",
+  [sym_batch_statement] = "batch_statement",
+  [sym_if_not_exists] = "if_not_exists",
+  [sym_if_exists] = "if_exists",
+  [sym__type] = "_type",
+  [sym_native_type] = "native_type",
+  [sym_collection_type] = "collection_type",
+  [sym_frozen_type] = "frozen_type",
+  [sym_tuple_type] = "tuple_type",
+  [sym_vector_type] = "vector_type",
+  [sym_user_type] = "user_type",
+  [sym_custom_type] = "custom_type",
+  [sym__term] = "_term",
+  [sym__literal] = "_literal",
+  [sym_function_call] = "function_call",
+  [sym_type_hint] = "type_hint",
+  [sym_list_literal] = "list_literal",
+  [sym_set_literal] = "set_literal",
+  [sym_map_literal] = "map_literal",
+  [sym_map_entry] = "map_entry",
+  [sym_tuple_literal] = "tuple_literal",
+  [sym_properties] = "properties",
+  [sym_property] = "property",
+  [sym_bind_marker] = "bind_marker",
+  [sym_qualified_name] = "qualified_name",
+  [sym_identifier] = "identifier",
+  [sym_float] = "float",
+  [sym_boolean] = "boolean",
+  [aux_sym_source_file_repeat1] = "source_file_repeat1",
+  [aux_sym_create_table_statement_repeat1] = "create_table_statement_repeat1",
+  [aux_sym_primary_key_definition_repeat1] = "primary_key_definition_repeat1",
+  [aux_sym_partition_key_repeat1] = "partition_key_repeat1",
+  [aux_sym_table_options_repeat1] = "table_options_repeat1",
+  [aux_sym_clustering_order_repeat1] = "clustering_order_repeat1",
+  [aux_sym_alter_table_statement_repeat1] = "alter_table_statement_repeat1",
+  [aux_sym__renames_repeat1] = "_renames_repeat1",
+  [aux_sym_create_type_statement_repeat1] = "create_type_statement_repeat1",
+  [aux_sym_create_function_statement_repeat1] = "create_function_statement_repeat1",
+  [aux_sym_drop_function_statement_repeat1] = "drop_function_statement_repeat1",
+  [aux_sym_selection_repeat1] = "selection_repeat1",
+  [aux_sym_column_reference_repeat1] = "column_reference_repeat1",
+  [aux_sym_where_clause_repeat1] = "where_clause_repeat1",
+  [aux_sym_relation_repeat1] = "relation_repeat1",
+  [aux_sym_update_statement_repeat1] = "update_statement_repeat1",
+  [aux_sym_delete_statement_repeat1] = "delete_statement_repeat1",
+  [aux_sym_using_clause_repeat1] = "using_clause_repeat1",
+  [aux_sym_if_clause_repeat1] = "if_clause_repeat1",
+  [aux_sym_batch_statement_repeat1] = "batch_statement_repeat1",
+  [aux_sym_function_call_repeat1] = "function_call_repeat1",
+  [aux_sym_map_literal_repeat1] = "map_literal_repeat1",
+  [aux_sym_properties_repeat1] = "properties_repeat1",
+  [alias_sym_null] = "null",
+};
+
+static const TSSymbol ts_symbol_map[] = {
+  [ts_builtin_sym_end] = ts_builtin_sym_end,
+  [sym__unquoted_identifier] = sym__unquoted_identifier,
+  [anon_sym_SEMI] = anon_sym_SEMI,
+  [anon_sym_LPAREN] = anon_sym_LPAREN,
+  [anon_sym_COMMA] = anon_sym_COMMA,
+  [anon_sym_RPAREN] = anon_sym_RPAREN,
+  [anon_sym_EQ] = anon_sym_EQ,
+  [anon_sym_STAR] = anon_sym_STAR,
+  [anon_sym_DOT] = anon_sym_DOT,
+  [anon_sym_BANG_EQ] = anon_sym_BANG_EQ,
+  [anon_sym_LT] = anon_sym_LT,
+  [anon_sym_LT_EQ] = anon_sym_LT_EQ,
+  [anon_sym_GT] = anon_sym_GT,
+  [anon_sym_GT_EQ] = anon_sym_GT_EQ,
+  [anon_sym_PLUS_EQ] = anon_sym_PLUS_EQ,
+  [anon_sym_DASH_EQ] = anon_sym_DASH_EQ,
+  [anon_sym_LBRACK] = anon_sym_LBRACK,
+  [anon_sym_RBRACK] = anon_sym_RBRACK,
+  [anon_sym_PLUS] = anon_sym_PLUS,
+  [anon_sym_DASH] = anon_sym_DASH,
+  [aux_sym_native_type_token1] = aux_sym_native_type_token1,
+  [aux_sym_native_type_token2] = aux_sym_native_type_token2,
+  [aux_sym_native_type_token3] = aux_sym_native_type_token3,
+  [aux_sym_native_type_token4] = aux_sym_native_type_token4,
+  [aux_sym_native_type_token5] = aux_sym_native_type_token5,
+  [aux_sym_native_type_token6] = aux_sym_native_type_token6,
+  [aux_sym_native_type_token7] = aux_sym_native_type_token7,
+  [aux_sym_native_type_token8] = aux_sym_native_type_token8,
+  [aux_sym_native_type_token9] = aux_sym_native_type_token9,
+  [aux_sym_native_type_token10] = aux_sym_native_type_token10,
+  [aux_sym_native_type_token11] = aux_sym_native_type_token11,
+  [aux_sym_native_type_token12] = aux_sym_native_type_token12,
+  [aux_sym_native_type_token13] = aux_sym_native_type_token13,
+  [aux_sym_native_type_token14] = aux_sym_native_type_token14,
+  [aux_sym_native_type_token15] = aux_sym_native_type_token15,
+  [aux_sym_native_type_token16] = aux_sym_native_type_token16,
+  [aux_sym_native_type_token17] = aux_sym_native_type_token17,
+  [aux_sym_native_type_token18] = aux_sym_native_type_token18,
+  [aux_sym_native_type_token19] = aux_sym_native_type_token19,
+  [anon_sym_LBRACE] = anon_sym_LBRACE,
+  [anon_sym_RBRACE] = anon_sym_RBRACE,
+  [anon_sym_COLON] = anon_sym_COLON,
+  [anon_sym_QMARK] = anon_sym_QMARK,
+  [sym_quoted_identifier] = sym_quoted_identifier,
+  [sym_interpolation] = sym_interpolation,
+  [sym_string_literal] = sym_string_literal,
+  [sym_dollar_string] = sym_dollar_string,
+  [sym_integer] = sym_integer,
+  [aux_sym_float_token1] = aux_sym_float_token1,
+  [aux_sym_float_token2] = aux_sym_float_token2,
+  [aux_sym_float_token3] = aux_sym_float_token3,
+  [aux_sym_float_token4] = aux_sym_float_token4,
+  [aux_sym_boolean_token1] = aux_sym_boolean_token1,
+  [aux_sym_boolean_token2] = aux_sym_boolean_token2,
+  [sym_uuid] = sym_uuid,
+  [sym_blob] = sym_blob,
+  [sym_duration] = sym_duration,
+  [sym_comment] = sym_comment,
+  [sym_keyword_add] = sym_keyword_add,
+  [sym_keyword_aggregate] = sym_keyword_aggregate,
+  [sym_keyword_allow] = sym_keyword_allow,
+  [sym_keyword_alter] = sym_keyword_alter,
+  [sym_keyword_and] = sym_keyword_and,
+  [sym_keyword_apply] = sym_keyword_apply,
+  [sym_keyword_as] = sym_keyword_as,
+  [sym_keyword_asc] = sym_keyword_asc,
+  [sym_keyword_batch] = sym_keyword_batch,
+  [sym_keyword_begin] = sym_keyword_begin,
+  [sym_keyword_by] = sym_keyword_by,
+  [sym_keyword_called] = sym_keyword_called,
+  [sym_keyword_cast] = sym_keyword_cast,
+  [sym_keyword_clustering] = sym_keyword_clustering,
+  [sym_keyword_columnfamily] = sym_keyword_columnfamily,
+  [sym_keyword_compact] = sym_keyword_compact,
+  [sym_keyword_contains] = sym_keyword_contains,
+  [sym_keyword_counter] = sym_keyword_counter,
+  [sym_keyword_create] = sym_keyword_create,
+  [sym_keyword_custom] = sym_keyword_custom,
+  [sym_keyword_default] = sym_keyword_default,
+  [sym_keyword_delete] = sym_keyword_delete,
+  [sym_keyword_desc] = sym_keyword_desc,
+  [sym_keyword_describe] = sym_keyword_describe,
+  [sym_keyword_distinct] = sym_keyword_distinct,
+  [sym_keyword_drop] = sym_keyword_drop,
+  [sym_keyword_entries] = sym_keyword_entries,
+  [sym_keyword_exists] = sym_keyword_exists,
+  [sym_keyword_filtering] = sym_keyword_filtering,
+  [sym_keyword_finalfunc] = sym_keyword_finalfunc,
+  [sym_keyword_from] = sym_keyword_from,
+  [sym_keyword_frozen] = sym_keyword_frozen,
+  [sym_keyword_full] = sym_keyword_full,
+  [sym_keyword_function] = sym_keyword_function,
+  [sym_keyword_functions] = sym_keyword_functions,
+  [sym_keyword_group] = sym_keyword_group,
+  [sym_keyword_if] = sym_keyword_if,
+  [sym_keyword_in] = sym_keyword_in,
+  [sym_keyword_index] = sym_keyword_index,
+  [sym_keyword_initcond] = sym_keyword_initcond,
+  [sym_keyword_input] = sym_keyword_input,
+  [sym_keyword_insert] = sym_keyword_insert,
+  [sym_keyword_into] = sym_keyword_into,
+  [sym_keyword_is] = sym_keyword_is,
+  [sym_keyword_json] = sym_keyword_json,
+  [sym_keyword_key] = sym_keyword_key,
+  [sym_keyword_keys] = sym_keyword_keys,
+  [sym_keyword_keyspace] = sym_keyword_keyspace,
+  [sym_keyword_keyspaces] = sym_keyword_keyspaces,
+  [sym_keyword_language] = sym_keyword_language,
+  [sym_keyword_like] = sym_keyword_like,
+  [sym_keyword_limit] = sym_keyword_limit,
+  [sym_keyword_list] = sym_keyword_list,
+  [sym_keyword_map] = sym_keyword_map,
+  [sym_keyword_materialized] = sym_keyword_materialized,
+  [sym_keyword_not] = sym_keyword_not,
+  [sym_keyword_null] = sym_keyword_null,
+  [sym_keyword_on] = sym_keyword_on,
+  [sym_keyword_options] = sym_keyword_options,
+  [sym_keyword_or] = sym_keyword_or,
+  [sym_keyword_order] = sym_keyword_order,
+  [sym_keyword_partition] = sym_keyword_partition,
+  [sym_keyword_per] = sym_keyword_per,
+  [sym_keyword_primary] = sym_keyword_primary,
+  [sym_keyword_rename] = sym_keyword_rename,
+  [sym_keyword_replace] = sym_keyword_replace,
+  [sym_keyword_returns] = sym_keyword_returns,
+  [sym_keyword_schema] = sym_keyword_schema,
+  [sym_keyword_select] = sym_keyword_select,
+  [sym_keyword_set] = sym_keyword_set,
+  [sym_keyword_sfunc] = sym_keyword_sfunc,
+  [sym_keyword_static] = sym_keyword_static,
+  [sym_keyword_storage] = sym_keyword_storage,
+  [sym_keyword_stype] = sym_keyword_stype,
+  [sym_keyword_table] = sym_keyword_table,
+  [sym_keyword_tables] = sym_keyword_tables,
+  [sym_keyword_to] = sym_keyword_to,
+  [sym_keyword_token] = sym_keyword_token,
+  [sym_keyword_truncate] = sym_keyword_truncate,
+  [sym_keyword_ttl] = sym_keyword_ttl,
+  [sym_keyword_tuple] = sym_keyword_tuple,
+  [sym_keyword_type] = sym_keyword_type,
+  [sym_keyword_types] = sym_keyword_types,
+  [sym_keyword_unlogged] = sym_keyword_unlogged,
+  [sym_keyword_unset] = sym_keyword_unset,
+  [sym_keyword_update] = sym_keyword_update,
+  [sym_keyword_use] = sym_keyword_use,
+  [sym_keyword_using] = sym_keyword_using,
+  [sym_keyword_values] = sym_keyword_values,
+  [sym_keyword_vector] = sym_keyword_vector,
+  [sym_keyword_view] = sym_keyword_view,
+  [sym_keyword_where] = sym_keyword_where,
+  [sym_keyword_with] = sym_keyword_with,
+  [sym_keyword_timestamp] = sym_keyword_timestamp,
+  [sym_source_file] = sym_source_file,
+  [sym__statement] = sym__statement,
+  [sym__dml_statement] = sym__dml_statement,
+  [sym_create_keyspace_statement] = sym_create_keyspace_statement,
+  [sym_alter_keyspace_statement] = sym_alter_keyspace_statement,
+  [sym_drop_keyspace_statement] = sym_drop_keyspace_statement,
+  [sym_use_statement] = sym_use_statement,
+  [sym_create_table_statement] = sym_create_table_statement,
+  [sym_column_definition] = sym_column_definition,
+  [sym_primary_key_definition] = sym_primary_key_definition,
+  [sym_partition_key] = sym_partition_key,
+  [sym_table_options] = sym_table_options,
+  [sym__table_option] = sym__table_option,
+  [sym_clustering_order] = sym_clustering_order,
+  [sym_ordering] = sym_ordering,
+  [sym_compact_storage] = sym_compact_storage,
+  [sym_alter_table_statement] = sym_alter_table_statement,
+  [sym__renames] = sym__renames,
+  [sym_rename] = sym_rename,
+  [sym_drop_table_statement] = sym_drop_table_statement,
+  [sym_truncate_statement] = sym_truncate_statement,
+  [sym_create_type_statement] = sym_create_type_statement,
+  [sym_field_definition] = sym_field_definition,
+  [sym_alter_type_statement] = sym_alter_type_statement,
+  [sym_drop_type_statement] = sym_drop_type_statement,
+  [sym_create_index_statement] = sym_create_index_statement,
+  [sym_index_target] = sym_index_target,
+  [sym_drop_index_statement] = sym_drop_index_statement,
+  [sym_create_materialized_view_statement] = sym_create_materialized_view_statement,
+  [sym_alter_materialized_view_statement] = sym_alter_materialized_view_statement,
+  [sym_drop_materialized_view_statement] = sym_drop_materialized_view_statement,
+  [sym_create_function_statement] = sym_create_function_statement,
+  [sym_parameter] = sym_parameter,
+  [sym_null_input_behavior] = sym_null_input_behavior,
+  [sym_drop_function_statement] = sym_drop_function_statement,
+  [sym_create_aggregate_statement] = sym_create_aggregate_statement,
+  [sym_drop_aggregate_statement] = sym_drop_aggregate_statement,
+  [sym_describe_statement] = sym_describe_statement,
+  [sym_select_statement] = sym_select_statement,
+  [sym_selection] = sym_selection,
+  [sym_selector] = sym_selector,
+  [sym__selector_expression] = sym__selector_expression,
+  [sym_column_reference] = sym_column_reference,
+  [sym_cast_expression] = sym_cast_expression,
+  [sym_where_clause] = sym_where_clause,
+  [sym_relation] = sym_relation,
+  [sym__relation_target] = sym__relation_target,
+  [sym_tuple_of_columns] = sym_tuple_of_columns,
+  [sym_token_call] = sym_token_call,
+  [sym_operator] = sym_operator,
+  [sym_group_by_clause] = sym_group_by_clause,
+  [sym_order_by_clause] = sym_order_by_clause,
+  [sym_per_partition_limit_clause] = sym_per_partition_limit_clause,
+  [sym_limit_clause] = sym_limit_clause,
+  [sym_insert_statement] = sym_insert_statement,
+  [sym_update_statement] = sym_update_statement,
+  [sym_assignment] = sym_assignment,
+  [sym__assignment_target] = sym__assignment_target,
+  [sym_element_access] = sym_element_access,
+  [sym__expression] = sym__expression,
+  [sym_binary_expression] = sym_binary_expression,
+  [sym_delete_statement] = sym_delete_statement,
+  [sym_using_clause] = sym_using_clause,
+  [sym_using_option] = sym_using_option,
+  [sym_if_clause] = sym_if_clause,
+  [sym_condition] = sym_condition,
+  [sym_batch_statement] = sym_batch_statement,
+  [sym_if_not_exists] = sym_if_not_exists,
+  [sym_if_exists] = sym_if_exists,
+  [sym__type] = sym__type,
+  [sym_native_type] = sym_native_type,
+  [sym_collection_type] = sym_collection_type,
+  [sym_frozen_type] = sym_frozen_type,
+  [sym_tuple_type] = sym_tuple_type,
+  [sym_vector_type] = sym_vector_type,
+  [sym_user_type] = sym_user_type,
+  [sym_custom_type] = sym_custom_type,
+  [sym__term] = sym__term,
+  [sym__literal] = sym__literal,
+  [sym_function_call] = sym_function_call,
+  [sym_type_hint] = sym_type_hint,
+  [sym_list_literal] = sym_list_literal,
+  [sym_set_literal] = sym_set_literal,
+  [sym_map_literal] = sym_map_literal,
+  [sym_map_entry] = sym_map_entry,
+  [sym_tuple_literal] = sym_tuple_literal,
+  [sym_properties] = sym_properties,
+  [sym_property] = sym_property,
+  [sym_bind_marker] = sym_bind_marker,
+  [sym_qualified_name] = sym_qualified_name,
+  [sym_identifier] = sym_identifier,
+  [sym_float] = sym_float,
+  [sym_boolean] = sym_boolean,
+  [aux_sym_source_file_repeat1] = aux_sym_source_file_repeat1,
+  [aux_sym_create_table_statement_repeat1] = aux_sym_create_table_statement_repeat1,
+  [aux_sym_primary_key_definition_repeat1] = aux_sym_primary_key_definition_repeat1,
+  [aux_sym_partition_key_repeat1] = aux_sym_partition_key_repeat1,
+  [aux_sym_table_options_repeat1] = aux_sym_table_options_repeat1,
+  [aux_sym_clustering_order_repeat1] = aux_sym_clustering_order_repeat1,
+  [aux_sym_alter_table_statement_repeat1] = aux_sym_alter_table_statement_repeat1,
+  [aux_sym__renames_repeat1] = aux_sym__renames_repeat1,
+  [aux_sym_create_type_statement_repeat1] = aux_sym_create_type_statement_repeat1,
+  [aux_sym_create_function_statement_repeat1] = aux_sym_create_function_statement_repeat1,
+  [aux_sym_drop_function_statement_repeat1] = aux_sym_drop_function_statement_repeat1,
+  [aux_sym_selection_repeat1] = aux_sym_selection_repeat1,
+  [aux_sym_column_reference_repeat1] = aux_sym_column_reference_repeat1,
+  [aux_sym_where_clause_repeat1] = aux_sym_where_clause_repeat1,
+  [aux_sym_relation_repeat1] = aux_sym_relation_repeat1,
+  [aux_sym_update_statement_repeat1] = aux_sym_update_statement_repeat1,
+  [aux_sym_delete_statement_repeat1] = aux_sym_delete_statement_repeat1,
+  [aux_sym_using_clause_repeat1] = aux_sym_using_clause_repeat1,
+  [aux_sym_if_clause_repeat1] = aux_sym_if_clause_repeat1,
+  [aux_sym_batch_statement_repeat1] = aux_sym_batch_statement_repeat1,
+  [aux_sym_function_call_repeat1] = aux_sym_function_call_repeat1,
+  [aux_sym_map_literal_repeat1] = aux_sym_map_literal_repeat1,
+  [aux_sym_properties_repeat1] = aux_sym_properties_repeat1,
+  [alias_sym_null] = alias_sym_null,
+};
+
+static const TSSymbolMetadata ts_symbol_metadata[] = {
+  [ts_builtin_sym_end] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym__unquoted_identifier] = {
+    .visible = false,
+    .named = true,
+  },
+  [anon_sym_SEMI] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LPAREN] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_COMMA] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RPAREN] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_STAR] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DOT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_BANG_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LT_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_GT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_GT_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_PLUS_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DASH_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LBRACK] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RBRACK] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_PLUS] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DASH] = {
+    .visible = true,
+    .named = false,
+  },
+  [aux_sym_native_type_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_native_type_token2] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_native_type_token3] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_native_type_token4] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_native_type_token5] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_native_type_token6] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_native_type_token7] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_native_type_token8] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_native_type_token9] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_native_type_token10] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_native_type_token11] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_native_type_token12] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_native_type_token13] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_native_type_token14] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_native_type_token15] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_native_type_token16] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_native_type_token17] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_native_type_token18] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_native_type_token19] = {
+    .visible = false,
+    .named = false,
+  },
+  [anon_sym_LBRACE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RBRACE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_COLON] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_QMARK] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_quoted_identifier] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_interpolation] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_string_literal] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_dollar_string] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_integer] = {
+    .visible = true,
+    .named = true,
+  },
+  [aux_sym_float_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_float_token2] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_float_token3] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_float_token4] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_boolean_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_boolean_token2] = {
+    .visible = false,
+    .named = false,
+  },
+  [sym_uuid] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_blob] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_duration] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_comment] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_add] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_aggregate] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_allow] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_alter] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_and] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_apply] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_as] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_asc] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_batch] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_begin] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_by] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_called] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_cast] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_clustering] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_columnfamily] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_compact] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_contains] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_counter] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_create] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_custom] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_default] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_delete] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_desc] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_describe] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_distinct] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_drop] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_entries] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_exists] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_filtering] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_finalfunc] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_from] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_frozen] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_full] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_function] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_functions] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_group] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_if] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_in] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_index] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_initcond] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_input] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_insert] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_into] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_is] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_json] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_key] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_keys] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_keyspace] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_keyspaces] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_language] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_like] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_limit] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_list] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_map] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_materialized] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_not] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_null] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_on] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_options] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_or] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_order] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_partition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_per] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_primary] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_rename] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_replace] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_returns] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_schema] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_select] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_set] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_sfunc] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_static] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_storage] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_stype] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_table] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_tables] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_to] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_token] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_truncate] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_ttl] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_tuple] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_types] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_unlogged] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_unset] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_update] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_use] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_using] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_values] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_vector] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_view] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_where] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_with] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyword_timestamp] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_source_file] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__statement] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym__dml_statement] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_create_keyspace_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_alter_keyspace_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_drop_keyspace_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_use_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_create_table_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_column_definition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_primary_key_definition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_partition_key] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_table_options] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__table_option] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_clustering_order] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_ordering] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_compact_storage] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_alter_table_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__renames] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_rename] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_drop_table_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_truncate_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_create_type_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_field_definition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_alter_type_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_drop_type_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_create_index_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_index_target] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_drop_index_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_create_materialized_view_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_alter_materialized_view_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_drop_materialized_view_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_create_function_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_parameter] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_null_input_behavior] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_drop_function_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_create_aggregate_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_drop_aggregate_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_describe_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_select_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_selection] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_selector] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__selector_expression] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_column_reference] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_cast_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_where_clause] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_relation] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__relation_target] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_tuple_of_columns] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_token_call] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_operator] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_group_by_clause] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_order_by_clause] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_per_partition_limit_clause] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_limit_clause] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_insert_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_update_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_assignment] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__assignment_target] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_element_access] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__expression] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_binary_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_delete_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_using_clause] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_using_option] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_if_clause] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_condition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_batch_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_if_not_exists] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_if_exists] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__type] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_native_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_collection_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_frozen_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_tuple_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_vector_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_user_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_custom_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__term] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym__literal] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_function_call] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_type_hint] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_list_literal] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_set_literal] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_map_literal] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_map_entry] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_tuple_literal] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_properties] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_property] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_bind_marker] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_qualified_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_identifier] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_float] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_boolean] = {
+    .visible = true,
+    .named = true,
+  },
+  [aux_sym_source_file_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_create_table_statement_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_primary_key_definition_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_partition_key_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_table_options_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_clustering_order_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_alter_table_statement_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym__renames_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_create_type_statement_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_create_function_statement_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_drop_function_statement_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_selection_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_column_reference_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_where_clause_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_relation_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_update_statement_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_delete_statement_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_using_clause_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_if_clause_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_batch_statement_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_function_call_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_map_literal_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_properties_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [alias_sym_null] = {
+    .visible = true,
+    .named = true,
+  },
+};
+
+enum ts_field_identifiers {
+  field_alias = 1,
+  field_base_table = 2,
+  field_body = 3,
+  field_class = 4,
+  field_clustering_column = 5,
+  field_column = 6,
+  field_columns = 7,
+  field_dimension = 8,
+  field_direction = 9,
+  field_final_function = 10,
+  field_from = 11,
+  field_index = 12,
+  field_initial_condition = 13,
+  field_key = 14,
+  field_keyspace = 15,
+  field_language = 16,
+  field_left = 17,
+  field_name = 18,
+  field_operator = 19,
+  field_partition_key = 20,
+  field_return_type = 21,
+  field_right = 22,
+  field_state_function = 23,
+  field_state_type = 24,
+  field_table = 25,
+  field_target = 26,
+  field_to = 27,
+  field_type = 28,
+  field_value = 29,
+  field_values = 30,
+};
+
+static const char * const ts_field_names[] = {
+  [0] = NULL,
+  [field_alias] = "alias",
+  [field_base_table] = "base_table",
+  [field_body] = "body",
+  [field_class] = "class",
+  [field_clustering_column] = "clustering_column",
+  [field_column] = "column",
+  [field_columns] = "columns",
+  [field_dimension] = "dimension",
+  [field_direction] = "direction",
+  [field_final_function] = "final_function",
+  [field_from] = "from",
+  [field_index] = "index",
+  [field_initial_condition] = "initial_condition",
+  [field_key] = "key",
+  [field_keyspace] = "keyspace",
+  [field_language] = "language",
+  [field_left] = "left",
+  [field_name] = "name",
+  [field_operator] = "operator",
+  [field_partition_key] = "partition_key",
+  [field_return_type] = "return_type",
+  [field_right] = "right",
+  [field_state_function] = "state_function",
+  [field_state_type] = "state_type",
+  [field_table] = "table",
+  [field_target] = "target",
+  [field_to] = "to",
+  [field_type] = "type",
+  [field_value] = "value",
+  [field_values] = "values",
+};
+
+static const TSMapSlice ts_field_map_slices[PRODUCTION_ID_COUNT] = {
+  [1] = {.index = 0, .length = 1},
+  [2] = {.index = 1, .length = 1},
+  [4] = {.index = 2, .length = 1},
+  [5] = {.index = 3, .length = 1},
+  [6] = {.index = 4, .length = 1},
+  [7] = {.index = 5, .length = 1},
+  [8] = {.index = 6, .length = 2},
+  [9] = {.index = 8, .length = 1},
+  [10] = {.index = 9, .length = 1},
+  [11] = {.index = 10, .length = 2},
+  [12] = {.index = 12, .length = 1},
+  [13] = {.index = 13, .length = 2},
+  [14] = {.index = 15, .length = 1},
+  [15] = {.index = 16, .length = 1},
+  [16] = {.index = 17, .length = 2},
+  [17] = {.index = 19, .length = 1},
+  [18] = {.index = 20, .length = 1},
+  [19] = {.index = 21, .length = 3},
+  [20] = {.index = 24, .length = 3},
+  [21] = {.index = 27, .length = 2},
+  [22] = {.index = 29, .length = 2},
+  [23] = {.index = 31, .length = 2},
+  [24] = {.index = 33, .length = 1},
+  [25] = {.index = 34, .length = 3},
+  [26] = {.index = 37, .length = 3},
+  [27] = {.index = 40, .length = 2},
+  [28] = {.index = 42, .length = 2},
+  [29] = {.index = 44, .length = 1},
+  [30] = {.index = 45, .length = 2},
+  [31] = {.index = 47, .length = 3},
+  [32] = {.index = 50, .length = 1},
+  [33] = {.index = 51, .length = 2},
+  [34] = {.index = 53, .length = 1},
+  [35] = {.index = 54, .length = 2},
+  [36] = {.index = 56, .length = 3},
+  [37] = {.index = 59, .length = 3},
+  [38] = {.index = 62, .length = 1},
+  [39] = {.index = 63, .length = 2},
+  [40] = {.index = 65, .length = 2},
+  [41] = {.index = 67, .length = 2},
+  [42] = {.index = 69, .length = 2},
+  [43] = {.index = 71, .length = 3},
+  [44] = {.index = 74, .length = 3},
+  [45] = {.index = 77, .length = 3},
+  [46] = {.index = 80, .length = 4},
+  [47] = {.index = 84, .length = 4},
+  [48] = {.index = 88, .length = 3},
+  [49] = {.index = 91, .length = 2},
+  [50] = {.index = 93, .length = 3},
+  [51] = {.index = 96, .length = 2},
+  [52] = {.index = 98, .length = 3},
+  [53] = {.index = 101, .length = 4},
+  [54] = {.index = 105, .length = 4},
+  [55] = {.index = 109, .length = 4},
+  [56] = {.index = 113, .length = 4},
+  [57] = {.index = 117, .length = 3},
+  [58] = {.index = 120, .length = 4},
+  [59] = {.index = 124, .length = 4},
+  [60] = {.index = 128, .length = 3},
+  [61] = {.index = 131, .length = 4},
+  [62] = {.index = 135, .length = 2},
+  [63] = {.index = 137, .length = 3},
+  [64] = {.index = 140, .length = 3},
+  [65] = {.index = 143, .length = 5},
+  [66] = {.index = 148, .length = 4},
+  [67] = {.index = 152, .length = 4},
+  [68] = {.index = 156, .length = 5},
+  [69] = {.index = 161, .length = 4},
+  [70] = {.index = 165, .length = 4},
+  [71] = {.index = 169, .length = 4},
+  [72] = {.index = 173, .length = 4},
+  [73] = {.index = 177, .length = 3},
+  [74] = {.index = 180, .length = 4},
+  [75] = {.index = 184, .length = 4},
+  [76] = {.index = 188, .length = 3},
+  [77] = {.index = 191, .length = 5},
+  [78] = {.index = 196, .length = 4},
+  [79] = {.index = 200, .length = 4},
+  [80] = {.index = 204, .length = 5},
+  [81] = {.index = 209, .length = 4},
+  [82] = {.index = 213, .length = 4},
+  [83] = {.index = 217, .length = 4},
+  [84] = {.index = 221, .length = 4},
+  [85] = {.index = 225, .length = 3},
+  [86] = {.index = 228, .length = 4},
+  [87] = {.index = 232, .length = 4},
+  [88] = {.index = 236, .length = 4},
+  [89] = {.index = 240, .length = 5},
+  [90] = {.index = 245, .length = 5},
+  [91] = {.index = 250, .length = 4},
+  [92] = {.index = 254, .length = 4},
+  [93] = {.index = 258, .length = 4},
+  [94] = {.index = 262, .length = 5},
+  [95] = {.index = 267, .length = 4},
+  [96] = {.index = 271, .length = 4},
+  [97] = {.index = 275, .length = 4},
+  [98] = {.index = 279, .length = 4},
+  [99] = {.index = 283, .length = 5},
+  [100] = {.index = 288, .length = 5},
+  [101] = {.index = 293, .length = 4},
+  [102] = {.index = 297, .length = 4},
+  [103] = {.index = 301, .length = 5},
+  [104] = {.index = 306, .length = 4},
+  [105] = {.index = 310, .length = 4},
+  [106] = {.index = 314, .length = 5},
+  [107] = {.index = 319, .length = 5},
+  [108] = {.index = 324, .length = 4},
+  [109] = {.index = 328, .length = 5},
+};
+
+static const TSFieldMapEntry ts_field_map_entries[] = {
+  [0] =
+    {field_name, 1},
+  [1] =
+    {field_name, 0},
+  [2] =
+    {field_keyspace, 1},
+  [3] =
+    {field_name, 2},
+  [4] =
+    {field_table, 2},
+  [5] =
+    {field_name, 3},
+  [6] =
+    {field_keyspace, 0},
+    {field_name, 2},
+  [8] =
+    {field_table, 3},
+  [9] =
+    {field_alias, 2},
+  [10] =
+    {field_column, 0},
+    {field_index, 2},
+  [12] =
+    {field_name, 4},
+  [13] =
+    {field_key, 0},
+    {field_value, 2},
+  [15] =
+    {field_table, 4},
+  [16] =
+    {field_table, 1},
+  [17] =
+    {field_name, 0},
+    {field_type, 1},
+  [19] =
+    {field_column, 0},
+  [20] =
+    {field_table, 5},
+  [21] =
+    {field_operator, 1},
+    {field_target, 0},
+    {field_value, 2},
+  [24] =
+    {field_column, 4},
+    {field_name, 2},
+    {field_type, 6},
+  [27] =
+    {field_from, 0},
+    {field_to, 2},
+  [29] =
+    {field_name, 0},
+    {field_value, 2},
+  [31] =
+    {field_left, 0},
+    {field_right, 2},
+  [33] =
+    {field_left, 0},
+  [34] =
+    {field_left, 0},
+    {field_operator, 1},
+    {field_right, 2},
+  [37] =
+    {field_column, 5},
+    {field_name, 3},
+    {field_type, 7},
+  [40] =
+    {field_name, 2},
+    {field_table, 4},
+  [42] =
+    {field_left, 0},
+    {field_right, 3},
+  [44] =
+    {field_dimension, 4},
+  [45] =
+    {field_column, 0},
+    {field_direction, 1},
+  [47] =
+    {field_name, 2},
+    {field_state_function, 6},
+    {field_state_type, 8},
+  [50] =
+    {field_partition_key, 3},
+  [51] =
+    {field_name, 3},
+    {field_table, 5},
+  [53] =
+    {field_column, 2},
+  [54] =
+    {field_class, 8},
+    {field_table, 3},
+  [56] =
+    {field_name, 3},
+    {field_state_function, 7},
+    {field_state_type, 9},
+  [59] =
+    {field_name, 2},
+    {field_state_function, 7},
+    {field_state_type, 9},
+  [62] =
+    {field_clustering_column, 1},
+  [63] =
+    {field_clustering_column, 4, .inherited = true},
+    {field_partition_key, 3},
+  [65] =
+    {field_clustering_column, 0, .inherited = true},
+    {field_clustering_column, 1, .inherited = true},
+  [67] =
+    {field_class, 9},
+    {field_table, 4},
+  [69] =
+    {field_name, 4},
+    {field_table, 6},
+  [71] =
+    {field_class, 9},
+    {field_name, 2},
+    {field_table, 4},
+  [74] =
+    {field_columns, 4},
+    {field_table, 2},
+    {field_values, 8},
+  [77] =
+    {field_name, 3},
+    {field_state_function, 8},
+    {field_state_type, 10},
+  [80] =
+    {field_final_function, 10},
+    {field_name, 2},
+    {field_state_function, 6},
+    {field_state_type, 8},
+  [84] =
+    {field_initial_condition, 10},
+    {field_name, 2},
+    {field_state_function, 6},
+    {field_state_type, 8},
+  [88] =
+    {field_name, 2},
+    {field_state_function, 8},
+    {field_state_type, 10},
+  [91] =
+    {field_class, 10},
+    {field_table, 5},
+  [93] =
+    {field_class, 10},
+    {field_name, 3},
+    {field_table, 5},
+  [96] =
+    {field_base_table, 8},
+    {field_name, 3},
+  [98] =
+    {field_name, 4},
+    {field_state_function, 8},
+    {field_state_type, 10},
+  [101] =
+    {field_columns, 4},
+    {field_table, 2},
+    {field_values, 8},
+    {field_values, 9},
+  [105] =
+    {field_columns, 4},
+    {field_columns, 5},
+    {field_table, 2},
+    {field_values, 9},
+  [109] =
+    {field_final_function, 11},
+    {field_name, 3},
+    {field_state_function, 7},
+    {field_state_type, 9},
+  [113] =
+    {field_initial_condition, 11},
+    {field_name, 3},
+    {field_state_function, 7},
+    {field_state_type, 9},
+  [117] =
+    {field_name, 3},
+    {field_state_function, 9},
+    {field_state_type, 11},
+  [120] =
+    {field_final_function, 11},
+    {field_name, 2},
+    {field_state_function, 7},
+    {field_state_type, 9},
+  [124] =
+    {field_initial_condition, 11},
+    {field_name, 2},
+    {field_state_function, 7},
+    {field_state_type, 9},
+  [128] =
+    {field_class, 11},
+    {field_name, 4},
+    {field_table, 6},
+  [131] =
+    {field_body, 11},
+    {field_language, 9},
+    {field_name, 2},
+    {field_return_type, 7},
+  [135] =
+    {field_base_table, 9},
+    {field_name, 4},
+  [137] =
+    {field_name, 5},
+    {field_state_function, 9},
+    {field_state_type, 11},
+  [140] =
+    {field_name, 4},
+    {field_state_function, 9},
+    {field_state_type, 11},
+  [143] =
+    {field_columns, 4},
+    {field_columns, 5},
+    {field_table, 2},
+    {field_values, 9},
+    {field_values, 10},
+  [148] =
+    {field_final_function, 12},
+    {field_name, 3},
+    {field_state_function, 8},
+    {field_state_type, 10},
+  [152] =
+    {field_initial_condition, 12},
+    {field_name, 3},
+    {field_state_function, 8},
+    {field_state_type, 10},
+  [156] =
+    {field_final_function, 10},
+    {field_initial_condition, 12},
+    {field_name, 2},
+    {field_state_function, 6},
+    {field_state_type, 8},
+  [161] =
+    {field_final_function, 12},
+    {field_name, 2},
+    {field_state_function, 8},
+    {field_state_type, 10},
+  [165] =
+    {field_initial_condition, 12},
+    {field_name, 2},
+    {field_state_function, 8},
+    {field_state_type, 10},
+  [169] =
+    {field_body, 12},
+    {field_language, 10},
+    {field_name, 3},
+    {field_return_type, 8},
+  [173] =
+    {field_body, 12},
+    {field_language, 10},
+    {field_name, 2},
+    {field_return_type, 8},
+  [177] =
+    {field_name, 5},
+    {field_state_function, 10},
+    {field_state_type, 12},
+  [180] =
+    {field_final_function, 12},
+    {field_name, 4},
+    {field_state_function, 8},
+    {field_state_type, 10},
+  [184] =
+    {field_initial_condition, 12},
+    {field_name, 4},
+    {field_state_function, 8},
+    {field_state_type, 10},
+  [188] =
+    {field_name, 4},
+    {field_state_function, 10},
+    {field_state_type, 12},
+  [191] =
+    {field_final_function, 11},
+    {field_initial_condition, 13},
+    {field_name, 3},
+    {field_state_function, 7},
+    {field_state_type, 9},
+  [196] =
+    {field_final_function, 13},
+    {field_name, 3},
+    {field_state_function, 9},
+    {field_state_type, 11},
+  [200] =
+    {field_initial_condition, 13},
+    {field_name, 3},
+    {field_state_function, 9},
+    {field_state_type, 11},
+  [204] =
+    {field_final_function, 11},
+    {field_initial_condition, 13},
+    {field_name, 2},
+    {field_state_function, 7},
+    {field_state_type, 9},
+  [209] =
+    {field_body, 13},
+    {field_language, 11},
+    {field_name, 3},
+    {field_return_type, 9},
+  [213] =
+    {field_body, 13},
+    {field_language, 11},
+    {field_name, 2},
+    {field_return_type, 9},
+  [217] =
+    {field_final_function, 13},
+    {field_name, 5},
+    {field_state_function, 9},
+    {field_state_type, 11},
+  [221] =
+    {field_initial_condition, 13},
+    {field_name, 5},
+    {field_state_function, 9},
+    {field_state_type, 11},
+  [225] =
+    {field_name, 5},
+    {field_state_function, 11},
+    {field_state_type, 13},
+  [228] =
+    {field_final_function, 13},
+    {field_name, 4},
+    {field_state_function, 9},
+    {field_state_type, 11},
+  [232] =
+    {field_initial_condition, 13},
+    {field_name, 4},
+    {field_state_function, 9},
+    {field_state_type, 11},
+  [236] =
+    {field_body, 13},
+    {field_language, 11},
+    {field_name, 4},
+    {field_return_type, 9},
+  [240] =
+    {field_final_function, 12},
+    {field_initial_condition, 14},
+    {field_name, 3},
+    {field_state_function, 8},
+    {field_state_type, 10},
+  [245] =
+    {field_final_function, 12},
+    {field_initial_condition, 14},
+    {field_name, 2},
+    {field_state_function, 8},
+    {field_state_type, 10},
+  [250] =
+    {field_body, 14},
+    {field_language, 12},
+    {field_name, 3},
+    {field_return_type, 10},
+  [254] =
+    {field_final_function, 14},
+    {field_name, 5},
+    {field_state_function, 10},
+    {field_state_type, 12},
+  [258] =
+    {field_initial_condition, 14},
+    {field_name, 5},
+    {field_state_function, 10},
+    {field_state_type, 12},
+  [262] =
+    {field_final_function, 12},
+    {field_initial_condition, 14},
+    {field_name, 4},
+    {field_state_function, 8},
+    {field_state_type, 10},
+  [267] =
+    {field_final_function, 14},
+    {field_name, 4},
+    {field_state_function, 10},
+    {field_state_type, 12},
+  [271] =
+    {field_initial_condition, 14},
+    {field_name, 4},
+    {field_state_function, 10},
+    {field_state_type, 12},
+  [275] =
+    {field_body, 14},
+    {field_language, 12},
+    {field_name, 5},
+    {field_return_type, 10},
+  [279] =
+    {field_body, 14},
+    {field_language, 12},
+    {field_name, 4},
+    {field_return_type, 10},
+  [283] =
+    {field_final_function, 13},
+    {field_initial_condition, 15},
+    {field_name, 3},
+    {field_state_function, 9},
+    {field_state_type, 11},
+  [288] =
+    {field_final_function, 13},
+    {field_initial_condition, 15},
+    {field_name, 5},
+    {field_state_function, 9},
+    {field_state_type, 11},
+  [293] =
+    {field_final_function, 15},
+    {field_name, 5},
+    {field_state_function, 11},
+    {field_state_type, 13},
+  [297] =
+    {field_initial_condition, 15},
+    {field_name, 5},
+    {field_state_function, 11},
+    {field_state_type, 13},
+  [301] =
+    {field_final_function, 13},
+    {field_initial_condition, 15},
+    {field_name, 4},
+    {field_state_function, 9},
+    {field_state_type, 11},
+  [306] =
+    {field_body, 15},
+    {field_language, 13},
+    {field_name, 5},
+    {field_return_type, 11},
+  [310] =
+    {field_body, 15},
+    {field_language, 13},
+    {field_name, 4},
+    {field_return_type, 11},
+  [314] =
+    {field_final_function, 14},
+    {field_initial_condition, 16},
+    {field_name, 5},
+    {field_state_function, 10},
+    {field_state_type, 12},
+  [319] =
+    {field_final_function, 14},
+    {field_initial_condition, 16},
+    {field_name, 4},
+    {field_state_function, 10},
+    {field_state_type, 12},
+  [324] =
+    {field_body, 16},
+    {field_language, 14},
+    {field_name, 5},
+    {field_return_type, 12},
+  [328] =
+    {field_final_function, 15},
+    {field_initial_condition, 17},
+    {field_name, 5},
+    {field_state_function, 11},
+    {field_state_type, 13},
+};
+
+static const TSSymbol ts_alias_sequences[PRODUCTION_ID_COUNT][MAX_ALIAS_SEQUENCE_LENGTH] = {
+  [0] = {0},
+  [3] = {
+    [0] = alias_sym_null,
+  },
+};
+
+static const uint16_t ts_non_terminal_alias_map[] = {
+  0,
+};
+
+static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
+  [0] = 0,
+  [1] = 1,
+  [2] = 2,
+  [3] = 2,
+  [4] = 2,
+  [5] = 5,
+  [6] = 6,
+  [7] = 7,
+  [8] = 8,
+  [9] = 9,
+  [10] = 10,
+  [11] = 11,
+  [12] = 12,
+  [13] = 13,
+  [14] = 14,
+  [15] = 15,
+  [16] = 16,
+  [17] = 17,
+  [18] = 18,
+  [19] = 19,
+  [20] = 20,
+  [21] = 21,
+  [22] = 22,
+  [23] = 23,
+  [24] = 24,
+  [25] = 25,
+  [26] = 26,
+  [27] = 27,
+  [28] = 28,
+  [29] = 29,
+  [30] = 30,
+  [31] = 31,
+  [32] = 32,
+  [33] = 33,
+  [34] = 34,
+  [35] = 35,
+  [36] = 36,
+  [37] = 37,
+  [38] = 38,
+  [39] = 39,
+  [40] = 40,
+  [41] = 41,
+  [42] = 42,
+  [43] = 43,
+  [44] = 44,
+  [45] = 45,
+  [46] = 46,
+  [47] = 47,
+  [48] = 48,
+  [49] = 49,
+  [50] = 50,
+  [51] = 51,
+  [52] = 52,
+  [53] = 53,
+  [54] = 54,
+  [55] = 55,
+  [56] = 56,
+  [57] = 57,
+  [58] = 58,
+  [59] = 59,
+  [60] = 60,
+  [61] = 61,
+  [62] = 62,
+  [63] = 63,
+  [64] = 64,
+  [65] = 65,
+  [66] = 66,
+  [67] = 67,
+  [68] = 68,
+  [69] = 69,
+  [70] = 70,
+  [71] = 71,
+  [72] = 72,
+  [73] = 73,
+  [74] = 74,
+  [75] = 75,
+  [76] = 76,
+  [77] = 77,
+  [78] = 78,
+  [79] = 79,
+  [80] = 80,
+  [81] = 81,
+  [82] = 82,
+  [83] = 83,
+  [84] = 84,
+  [85] = 85,
+  [86] = 86,
+  [87] = 87,
+  [88] = 88,
+  [89] = 89,
+  [90] = 90,
+  [91] = 91,
+  [92] = 92,
+  [93] = 93,
+  [94] = 94,
+  [95] = 95,
+  [96] = 96,
+  [97] = 97,
+  [98] = 98,
+  [99] = 99,
+  [100] = 100,
+  [101] = 101,
+  [102] = 102,
+  [103] = 103,
+  [104] = 104,
+  [105] = 105,
+  [106] = 106,
+  [107] = 107,
+  [108] = 108,
+  [109] = 109,
+  [110] = 110,
+  [111] = 111,
+  [112] = 95,
+  [113] = 95,
+  [114] = 78,
+  [115] = 115,
+  [116] = 116,
+  [117] = 117,
+  [118] = 118,
+  [119] = 119,
+  [120] = 120,
+  [121] = 121,
+  [122] = 122,
+  [123] = 123,
+  [124] = 124,
+  [125] = 125,
+  [126] = 126,
+  [127] = 127,
+  [128] = 128,
+  [129] = 129,
+  [130] = 130,
+  [131] = 131,
+  [132] = 5,
+  [133] = 6,
+  [134] = 134,
+  [135] = 135,
+  [136] = 136,
+  [137] = 137,
+  [138] = 138,
+  [139] = 111,
+  [140] = 140,
+  [141] = 141,
+  [142] = 142,
+  [143] = 143,
+  [144] = 144,
+  [145] = 145,
+  [146] = 146,
+  [147] = 147,
+  [148] = 148,
+  [149] = 149,
+  [150] = 150,
+  [151] = 151,
+  [152] = 152,
+  [153] = 153,
+  [154] = 154,
+  [155] = 155,
+  [156] = 156,
+  [157] = 157,
+  [158] = 158,
+  [159] = 159,
+  [160] = 160,
+  [161] = 161,
+  [162] = 162,
+  [163] = 163,
+  [164] = 164,
+  [165] = 165,
+  [166] = 166,
+  [167] = 167,
+  [168] = 168,
+  [169] = 169,
+  [170] = 170,
+  [171] = 171,
+  [172] = 172,
+  [173] = 173,
+  [174] = 174,
+  [175] = 175,
+  [176] = 176,
+  [177] = 177,
+  [178] = 178,
+  [179] = 179,
+  [180] = 180,
+  [181] = 181,
+  [182] = 182,
+  [183] = 183,
+  [184] = 184,
+  [185] = 185,
+  [186] = 186,
+  [187] = 187,
+  [188] = 188,
+  [189] = 189,
+  [190] = 190,
+  [191] = 191,
+  [192] = 192,
+  [193] = 193,
+  [194] = 194,
+  [195] = 195,
+  [196] = 196,
+  [197] = 197,
+  [198] = 198,
+  [199] = 199,
+  [200] = 200,
+  [201] = 201,
+  [202] = 5,
+  [203] = 203,
+  [204] = 6,
+  [205] = 205,
+  [206] = 206,
+  [207] = 207,
+  [208] = 208,
+  [209] = 209,
+  [210] = 210,
+  [211] = 211,
+  [212] = 212,
+  [213] = 213,
+  [214] = 214,
+  [215] = 215,
+  [216] = 216,
+  [217] = 217,
+  [218] = 218,
+  [219] = 219,
+  [220] = 220,
+  [221] = 221,
+  [222] = 222,
+  [223] = 223,
+  [224] = 224,
+  [225] = 225,
+  [226] = 226,
+  [227] = 227,
+  [228] = 228,
+  [229] = 229,
+  [230] = 230,
+  [231] = 231,
+  [232] = 232,
+  [233] = 233,
+  [234] = 234,
+  [235] = 235,
+  [236] = 236,
+  [237] = 237,
+  [238] = 238,
+  [239] = 239,
+  [240] = 240,
+  [241] = 241,
+  [242] = 242,
+  [243] = 243,
+  [244] = 244,
+  [245] = 245,
+  [246] = 246,
+  [247] = 247,
+  [248] = 248,
+  [249] = 249,
+  [250] = 250,
+  [251] = 251,
+  [252] = 252,
+  [253] = 253,
+  [254] = 254,
+  [255] = 255,
+  [256] = 256,
+  [257] = 257,
+  [258] = 258,
+  [259] = 259,
+  [260] = 260,
+  [261] = 261,
+  [262] = 262,
+  [263] = 263,
+  [264] = 264,
+  [265] = 265,
+  [266] = 266,
+  [267] = 267,
+  [268] = 268,
+  [269] = 269,
+  [270] = 270,
+  [271] = 271,
+  [272] = 272,
+  [273] = 273,
+  [274] = 274,
+  [275] = 275,
+  [276] = 276,
+  [277] = 277,
+  [278] = 278,
+  [279] = 279,
+  [280] = 280,
+  [281] = 281,
+  [282] = 282,
+  [283] = 283,
+  [284] = 284,
+  [285] = 285,
+  [286] = 286,
+  [287] = 287,
+  [288] = 288,
+  [289] = 289,
+  [290] = 290,
+  [291] = 291,
+  [292] = 292,
+  [293] = 293,
+  [294] = 294,
+  [295] = 295,
+  [296] = 296,
+  [297] = 297,
+  [298] = 298,
+  [299] = 299,
+  [300] = 300,
+  [301] = 301,
+  [302] = 302,
+  [303] = 303,
+  [304] = 304,
+  [305] = 305,
+  [306] = 306,
+  [307] = 307,
+  [308] = 308,
+  [309] = 309,
+  [310] = 310,
+  [311] = 311,
+  [312] = 312,
+  [313] = 313,
+  [314] = 314,
+  [315] = 315,
+  [316] = 316,
+  [317] = 317,
+  [318] = 318,
+  [319] = 319,
+  [320] = 320,
+  [321] = 321,
+  [322] = 322,
+  [323] = 323,
+  [324] = 324,
+  [325] = 325,
+  [326] = 326,
+  [327] = 327,
+  [328] = 328,
+  [329] = 329,
+  [330] = 330,
+  [331] = 331,
+  [332] = 332,
+  [333] = 333,
+  [334] = 334,
+  [335] = 335,
+  [336] = 336,
+  [337] = 337,
+  [338] = 338,
+  [339] = 339,
+  [340] = 340,
+  [341] = 341,
+  [342] = 342,
+  [343] = 343,
+  [344] = 344,
+  [345] = 345,
+  [346] = 346,
+  [347] = 347,
+  [348] = 348,
+  [349] = 349,
+  [350] = 350,
+  [351] = 351,
+  [352] = 352,
+  [353] = 353,
+  [354] = 354,
+  [355] = 355,
+  [356] = 356,
+  [357] = 357,
+  [358] = 358,
+  [359] = 359,
+  [360] = 360,
+  [361] = 361,
+  [362] = 362,
+  [363] = 363,
+  [364] = 364,
+  [365] = 365,
+  [366] = 366,
+  [367] = 367,
+  [368] = 368,
+  [369] = 369,
+  [370] = 370,
+  [371] = 371,
+  [372] = 372,
+  [373] = 373,
+  [374] = 374,
+  [375] = 375,
+  [376] = 376,
+  [377] = 377,
+  [378] = 378,
+  [379] = 379,
+  [380] = 380,
+  [381] = 381,
+  [382] = 382,
+  [383] = 383,
+  [384] = 384,
+  [385] = 385,
+  [386] = 386,
+  [387] = 387,
+  [388] = 388,
+  [389] = 389,
+  [390] = 390,
+  [391] = 391,
+  [392] = 392,
+  [393] = 393,
+  [394] = 394,
+  [395] = 395,
+  [396] = 396,
+  [397] = 397,
+  [398] = 398,
+  [399] = 399,
+  [400] = 400,
+  [401] = 401,
+  [402] = 402,
+  [403] = 403,
+  [404] = 404,
+  [405] = 405,
+  [406] = 406,
+  [407] = 407,
+  [408] = 408,
+  [409] = 409,
+  [410] = 410,
+  [411] = 411,
+  [412] = 412,
+  [413] = 413,
+  [414] = 414,
+  [415] = 415,
+  [416] = 416,
+  [417] = 417,
+  [418] = 418,
+  [419] = 419,
+  [420] = 420,
+  [421] = 421,
+  [422] = 422,
+  [423] = 423,
+  [424] = 424,
+  [425] = 425,
+  [426] = 426,
+  [427] = 427,
+  [428] = 428,
+  [429] = 429,
+  [430] = 430,
+  [431] = 431,
+  [432] = 432,
+  [433] = 433,
+  [434] = 434,
+  [435] = 435,
+  [436] = 436,
+  [437] = 437,
+  [438] = 438,
+  [439] = 439,
+  [440] = 440,
+  [441] = 441,
+  [442] = 442,
+  [443] = 443,
+  [444] = 444,
+  [445] = 445,
+  [446] = 446,
+  [447] = 447,
+  [448] = 448,
+  [449] = 449,
+  [450] = 450,
+  [451] = 451,
+  [452] = 452,
+  [453] = 453,
+  [454] = 454,
+  [455] = 455,
+  [456] = 456,
+  [457] = 457,
+  [458] = 458,
+  [459] = 459,
+  [460] = 460,
+  [461] = 461,
+  [462] = 462,
+  [463] = 463,
+  [464] = 464,
+  [465] = 465,
+  [466] = 466,
+  [467] = 467,
+  [468] = 468,
+  [469] = 469,
+  [470] = 470,
+  [471] = 471,
+  [472] = 472,
+  [473] = 473,
+  [474] = 474,
+  [475] = 475,
+  [476] = 476,
+  [477] = 477,
+  [478] = 478,
+  [479] = 479,
+  [480] = 480,
+  [481] = 481,
+  [482] = 482,
+  [483] = 483,
+  [484] = 484,
+  [485] = 485,
+  [486] = 486,
+  [487] = 487,
+  [488] = 488,
+  [489] = 489,
+  [490] = 490,
+  [491] = 491,
+  [492] = 492,
+  [493] = 493,
+  [494] = 494,
+  [495] = 495,
+  [496] = 496,
+  [497] = 497,
+  [498] = 498,
+  [499] = 499,
+  [500] = 500,
+  [501] = 501,
+  [502] = 502,
+  [503] = 503,
+  [504] = 504,
+  [505] = 505,
+  [506] = 506,
+  [507] = 507,
+  [508] = 508,
+  [509] = 509,
+  [510] = 510,
+  [511] = 511,
+  [512] = 512,
+  [513] = 513,
+  [514] = 514,
+  [515] = 515,
+  [516] = 516,
+  [517] = 517,
+  [518] = 518,
+  [519] = 519,
+  [520] = 111,
+  [521] = 521,
+  [522] = 522,
+  [523] = 523,
+  [524] = 524,
+  [525] = 525,
+  [526] = 526,
+  [527] = 527,
+  [528] = 528,
+  [529] = 529,
+  [530] = 530,
+  [531] = 531,
+  [532] = 532,
+  [533] = 533,
+  [534] = 534,
+  [535] = 535,
+  [536] = 536,
+  [537] = 537,
+  [538] = 538,
+  [539] = 539,
+  [540] = 540,
+  [541] = 541,
+  [542] = 542,
+  [543] = 543,
+  [544] = 544,
+  [545] = 545,
+  [546] = 546,
+  [547] = 547,
+  [548] = 548,
+  [549] = 549,
+  [550] = 550,
+  [551] = 551,
+  [552] = 552,
+  [553] = 553,
+  [554] = 554,
+  [555] = 555,
+  [556] = 556,
+  [557] = 557,
+  [558] = 558,
+  [559] = 559,
+  [560] = 560,
+  [561] = 561,
+  [562] = 562,
+  [563] = 563,
+  [564] = 564,
+  [565] = 565,
+  [566] = 566,
+  [567] = 567,
+  [568] = 568,
+  [569] = 569,
+  [570] = 570,
+  [571] = 571,
+  [572] = 572,
+  [573] = 573,
+  [574] = 574,
+  [575] = 575,
+  [576] = 576,
+  [577] = 577,
+  [578] = 578,
+  [579] = 579,
+  [580] = 580,
+  [581] = 581,
+  [582] = 582,
+  [583] = 583,
+  [584] = 584,
+  [585] = 585,
+  [586] = 586,
+  [587] = 587,
+  [588] = 588,
+  [589] = 589,
+  [590] = 590,
+  [591] = 591,
+  [592] = 592,
+  [593] = 593,
+  [594] = 594,
+  [595] = 595,
+  [596] = 596,
+  [597] = 597,
+  [598] = 598,
+  [599] = 599,
+  [600] = 600,
+  [601] = 601,
+  [602] = 602,
+  [603] = 603,
+  [604] = 604,
+  [605] = 605,
+  [606] = 606,
+  [607] = 607,
+  [608] = 608,
+  [609] = 609,
+  [610] = 610,
+  [611] = 611,
+  [612] = 612,
+  [613] = 613,
+  [614] = 614,
+  [615] = 615,
+  [616] = 616,
+  [617] = 617,
+  [618] = 618,
+  [619] = 619,
+  [620] = 620,
+  [621] = 621,
+  [622] = 622,
+  [623] = 623,
+  [624] = 624,
+  [625] = 625,
+  [626] = 626,
+  [627] = 627,
+  [628] = 628,
+  [629] = 629,
+  [630] = 630,
+  [631] = 631,
+  [632] = 632,
+  [633] = 633,
+  [634] = 634,
+  [635] = 635,
+  [636] = 636,
+  [637] = 637,
+  [638] = 638,
+  [639] = 639,
+  [640] = 640,
+  [641] = 641,
+  [642] = 642,
+  [643] = 643,
+  [644] = 644,
+  [645] = 645,
+  [646] = 646,
+  [647] = 647,
+  [648] = 648,
+  [649] = 649,
+  [650] = 610,
+  [651] = 651,
+  [652] = 652,
+  [653] = 653,
+  [654] = 654,
+  [655] = 655,
+  [656] = 656,
+  [657] = 657,
+  [658] = 658,
+  [659] = 659,
+  [660] = 660,
+  [661] = 661,
+  [662] = 662,
+  [663] = 663,
+  [664] = 664,
+  [665] = 665,
+  [666] = 666,
+  [667] = 667,
+  [668] = 668,
+  [669] = 669,
+  [670] = 670,
+  [671] = 671,
+  [672] = 672,
+  [673] = 673,
+  [674] = 674,
+  [675] = 675,
+  [676] = 676,
+  [677] = 677,
+  [678] = 678,
+  [679] = 679,
+  [680] = 680,
+  [681] = 681,
+  [682] = 682,
+  [683] = 683,
+  [684] = 684,
+  [685] = 685,
+  [686] = 686,
+  [687] = 687,
+  [688] = 688,
+  [689] = 689,
+  [690] = 690,
+  [691] = 691,
+  [692] = 692,
+  [693] = 693,
+  [694] = 694,
+  [695] = 695,
+  [696] = 696,
+  [697] = 697,
+  [698] = 698,
+  [699] = 699,
+  [700] = 700,
+  [701] = 701,
+  [702] = 702,
+  [703] = 703,
+  [704] = 704,
+  [705] = 705,
+  [706] = 706,
+  [707] = 707,
+  [708] = 708,
+  [709] = 709,
+  [710] = 710,
+  [711] = 711,
+  [712] = 712,
+  [713] = 713,
+  [714] = 714,
+  [715] = 715,
+  [716] = 716,
+  [717] = 717,
+  [718] = 718,
+  [719] = 719,
+  [720] = 720,
+  [721] = 721,
+  [722] = 722,
+  [723] = 723,
+  [724] = 724,
+  [725] = 6,
+  [726] = 5,
+  [727] = 178,
+  [728] = 728,
+  [729] = 729,
+  [730] = 730,
+  [731] = 731,
+  [732] = 732,
+  [733] = 733,
+  [734] = 734,
+  [735] = 735,
+  [736] = 736,
+  [737] = 737,
+  [738] = 738,
+  [739] = 739,
+  [740] = 740,
+  [741] = 741,
+  [742] = 742,
+  [743] = 743,
+  [744] = 744,
+  [745] = 745,
+  [746] = 746,
+  [747] = 747,
+  [748] = 748,
+  [749] = 749,
+  [750] = 750,
+  [751] = 751,
+  [752] = 752,
+  [753] = 753,
+  [754] = 754,
+  [755] = 755,
+  [756] = 756,
+  [757] = 757,
+  [758] = 758,
+  [759] = 759,
+  [760] = 760,
+  [761] = 761,
+  [762] = 762,
+  [763] = 763,
+  [764] = 764,
+  [765] = 765,
+  [766] = 766,
+  [767] = 767,
+  [768] = 768,
+  [769] = 769,
+  [770] = 770,
+  [771] = 771,
+  [772] = 772,
+  [773] = 773,
+  [774] = 774,
+  [775] = 775,
+  [776] = 776,
+  [777] = 777,
+  [778] = 778,
+  [779] = 779,
+  [780] = 780,
+  [781] = 781,
+  [782] = 782,
+  [783] = 783,
+  [784] = 784,
+  [785] = 785,
+  [786] = 786,
+  [787] = 787,
+  [788] = 788,
+  [789] = 789,
+  [790] = 790,
+  [791] = 791,
+  [792] = 792,
+  [793] = 793,
+  [794] = 794,
+  [795] = 795,
+  [796] = 796,
+  [797] = 797,
+  [798] = 798,
+  [799] = 799,
+  [800] = 800,
+  [801] = 801,
+  [802] = 802,
+  [803] = 803,
+  [804] = 804,
+  [805] = 730,
+  [806] = 806,
+  [807] = 807,
+  [808] = 808,
+  [809] = 809,
+  [810] = 810,
+  [811] = 811,
+  [812] = 812,
+  [813] = 813,
+  [814] = 814,
+  [815] = 815,
+  [816] = 816,
+  [817] = 817,
+  [818] = 818,
+  [819] = 819,
+  [820] = 820,
+  [821] = 821,
+  [822] = 822,
+  [823] = 823,
+  [824] = 824,
+  [825] = 825,
+  [826] = 826,
+  [827] = 827,
+  [828] = 828,
+  [829] = 829,
+  [830] = 830,
+  [831] = 831,
+  [832] = 832,
+  [833] = 833,
+  [834] = 834,
+  [835] = 835,
+  [836] = 836,
+  [837] = 837,
+  [838] = 838,
+  [839] = 839,
+  [840] = 840,
+  [841] = 841,
+  [842] = 183,
+  [843] = 843,
+  [844] = 844,
+  [845] = 845,
+  [846] = 846,
+  [847] = 847,
+  [848] = 848,
+  [849] = 849,
+  [850] = 850,
+  [851] = 851,
+  [852] = 852,
+  [853] = 853,
+  [854] = 854,
+  [855] = 855,
+  [856] = 856,
+  [857] = 857,
+  [858] = 858,
+  [859] = 859,
+  [860] = 860,
+  [861] = 861,
+  [862] = 862,
+  [863] = 863,
+  [864] = 864,
+  [865] = 865,
+  [866] = 866,
+  [867] = 867,
+  [868] = 868,
+  [869] = 869,
+  [870] = 870,
+  [871] = 871,
+  [872] = 872,
+  [873] = 873,
+  [874] = 874,
+  [875] = 875,
+  [876] = 876,
+  [877] = 877,
+  [878] = 878,
+  [879] = 879,
+  [880] = 880,
+  [881] = 881,
+  [882] = 882,
+  [883] = 883,
+  [884] = 884,
+  [885] = 885,
+  [886] = 886,
+  [887] = 887,
+  [888] = 888,
+  [889] = 889,
+  [890] = 890,
+  [891] = 891,
+  [892] = 892,
+  [893] = 893,
+  [894] = 894,
+  [895] = 895,
+  [896] = 896,
+  [897] = 897,
+  [898] = 898,
+  [899] = 899,
+  [900] = 900,
+  [901] = 901,
+  [902] = 902,
+  [903] = 903,
+  [904] = 904,
+  [905] = 905,
+  [906] = 906,
+  [907] = 907,
+  [908] = 908,
+  [909] = 909,
+  [910] = 910,
+  [911] = 911,
+  [912] = 912,
+  [913] = 913,
+  [914] = 914,
+  [915] = 915,
+  [916] = 916,
+  [917] = 917,
+  [918] = 918,
+  [919] = 919,
+  [920] = 920,
+  [921] = 921,
+  [922] = 922,
+  [923] = 923,
+  [924] = 924,
+  [925] = 925,
+  [926] = 926,
+  [927] = 927,
+  [928] = 928,
+  [929] = 929,
+  [930] = 930,
+  [931] = 931,
+  [932] = 932,
+  [933] = 933,
+  [934] = 934,
+  [935] = 935,
+  [936] = 936,
+  [937] = 937,
+  [938] = 938,
+  [939] = 939,
+  [940] = 940,
+  [941] = 941,
+  [942] = 942,
+  [943] = 943,
+  [944] = 944,
+  [945] = 945,
+  [946] = 946,
+  [947] = 947,
+  [948] = 948,
+  [949] = 949,
+  [950] = 950,
+  [951] = 951,
+  [952] = 952,
+  [953] = 953,
+  [954] = 954,
+  [955] = 955,
+  [956] = 956,
+  [957] = 957,
+  [958] = 958,
+  [959] = 959,
+  [960] = 960,
+  [961] = 961,
+  [962] = 962,
+  [963] = 963,
+  [964] = 964,
+  [965] = 965,
+  [966] = 966,
+  [967] = 967,
+  [968] = 968,
+  [969] = 969,
+  [970] = 970,
+  [971] = 971,
+  [972] = 972,
+  [973] = 973,
+  [974] = 974,
+  [975] = 975,
+  [976] = 976,
+  [977] = 977,
+  [978] = 978,
+  [979] = 979,
+  [980] = 980,
+  [981] = 981,
+  [982] = 982,
+  [983] = 983,
+  [984] = 984,
+  [985] = 985,
+  [986] = 986,
+  [987] = 987,
+  [988] = 988,
+  [989] = 989,
+  [990] = 990,
+  [991] = 991,
+  [992] = 992,
+  [993] = 993,
+  [994] = 994,
+  [995] = 995,
+  [996] = 996,
+  [997] = 997,
+  [998] = 998,
+  [999] = 999,
+  [1000] = 1000,
+  [1001] = 1001,
+  [1002] = 1002,
+  [1003] = 1003,
+  [1004] = 1004,
+  [1005] = 1005,
+  [1006] = 1006,
+  [1007] = 1007,
+  [1008] = 1008,
+  [1009] = 1009,
+  [1010] = 1010,
+  [1011] = 1011,
+  [1012] = 1012,
+  [1013] = 1013,
+  [1014] = 1014,
+  [1015] = 1015,
+  [1016] = 1016,
+  [1017] = 1017,
+  [1018] = 1018,
+  [1019] = 1019,
+  [1020] = 1020,
+  [1021] = 1021,
+  [1022] = 1022,
+  [1023] = 1023,
+  [1024] = 1024,
+  [1025] = 1025,
+  [1026] = 1026,
+  [1027] = 1027,
+  [1028] = 1028,
+  [1029] = 1029,
+  [1030] = 1030,
+  [1031] = 1031,
+  [1032] = 1032,
+  [1033] = 1033,
+  [1034] = 1034,
+  [1035] = 1035,
+  [1036] = 1036,
+  [1037] = 1037,
+  [1038] = 1038,
+  [1039] = 1039,
+  [1040] = 1040,
+  [1041] = 1041,
+  [1042] = 1042,
+  [1043] = 1043,
+  [1044] = 1044,
+  [1045] = 1045,
+  [1046] = 1046,
+  [1047] = 1047,
+  [1048] = 1048,
+  [1049] = 1049,
+  [1050] = 1050,
+  [1051] = 1051,
+  [1052] = 1052,
+  [1053] = 1053,
+  [1054] = 1054,
+  [1055] = 1055,
+  [1056] = 1056,
+  [1057] = 1057,
+  [1058] = 1058,
+  [1059] = 1059,
+  [1060] = 1060,
+  [1061] = 1061,
+  [1062] = 1062,
+  [1063] = 1063,
+  [1064] = 1064,
+  [1065] = 1065,
+  [1066] = 1066,
+  [1067] = 1067,
+  [1068] = 1068,
+  [1069] = 1069,
+  [1070] = 1070,
+  [1071] = 1071,
+  [1072] = 1072,
+  [1073] = 1073,
+  [1074] = 1074,
+  [1075] = 1075,
+  [1076] = 1076,
+  [1077] = 1077,
+  [1078] = 1078,
+  [1079] = 1079,
+  [1080] = 1080,
+  [1081] = 1081,
+  [1082] = 1082,
+  [1083] = 1083,
+  [1084] = 1084,
+  [1085] = 1085,
+  [1086] = 1086,
+  [1087] = 1087,
+  [1088] = 1088,
+  [1089] = 1089,
+  [1090] = 1090,
+  [1091] = 1091,
+  [1092] = 1092,
+  [1093] = 1093,
+  [1094] = 1094,
+  [1095] = 1095,
+  [1096] = 1096,
+  [1097] = 1097,
+  [1098] = 1098,
+  [1099] = 1099,
+  [1100] = 1100,
+  [1101] = 1101,
+  [1102] = 1102,
+  [1103] = 1103,
+  [1104] = 1021,
+  [1105] = 1105,
+  [1106] = 1106,
+  [1107] = 1107,
+  [1108] = 1108,
+  [1109] = 1109,
+  [1110] = 1110,
+  [1111] = 1021,
+  [1112] = 1112,
+  [1113] = 1113,
+};
+
+static bool ts_lex(TSLexer *lexer, TSStateId state) {
+  START_LEXER();
+  eof = lexer->eof(lexer);
+  switch (state) {
+    case 0:
+      if (eof) ADVANCE(74);
+      ADVANCE_MAP(
+        '!', 18,
+        '"', 3,
+        '$', 4,
+        '\'', 7,
+        '(', 76,
+        ')', 78,
+        '*', 80,
+        '+', 92,
+        ',', 77,
+        '-', 95,
+        '.', 81,
+        '/', 8,
+        '0', 116,
+        ':', 99,
+        ';', 75,
+        '<', 83,
+        '=', 79,
+        '>', 85,
+        '?', 100,
+        'P', 102,
+        '[', 89,
+        ']', 90,
+        '{', 97,
+        '}', 98,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ' ||
+          lookahead == '`') SKIP(0);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(117);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(109);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          ('_' <= lookahead && lookahead <= 'z')) ADVANCE(110);
+      END_STATE();
+    case 1:
+      ADVANCE_MAP(
+        '!', 18,
+        '$', 20,
+        '+', 19,
+        ',', 77,
+        '-', 12,
+        '.', 81,
+        '/', 8,
+        ':', 99,
+        '<', 83,
+        '=', 79,
+        '>', 85,
+        '?', 100,
+        '[', 89,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ' ||
+          lookahead == '`') SKIP(1);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(125);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('_' <= lookahead && lookahead <= 'z')) ADVANCE(110);
+      END_STATE();
+    case 2:
+      ADVANCE_MAP(
+        '"', 3,
+        '$', 4,
+        '\'', 7,
+        '(', 76,
+        ')', 78,
+        '*', 80,
+        '-', 96,
+        '/', 8,
+        '0', 116,
+        ':', 99,
+        '?', 100,
+        'P', 102,
+        '[', 89,
+        ']', 90,
+        '{', 97,
+        '}', 98,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ' ||
+          lookahead == '`') SKIP(2);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(117);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(109);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          ('_' <= lookahead && lookahead <= 'z')) ADVANCE(110);
+      END_STATE();
+    case 3:
+      if (lookahead == '"') ADVANCE(111);
+      if (lookahead != 0) ADVANCE(3);
+      END_STATE();
+    case 4:
+      if (lookahead == '$') ADVANCE(6);
+      if (lookahead == '{') ADVANCE(21);
+      END_STATE();
+    case 5:
+      if (lookahead == '$') ADVANCE(114);
+      if (lookahead != 0) ADVANCE(6);
+      END_STATE();
+    case 6:
+      if (lookahead == '$') ADVANCE(5);
+      if (lookahead != 0) ADVANCE(6);
+      END_STATE();
+    case 7:
+      if (lookahead == '\'') ADVANCE(113);
+      if (lookahead != 0) ADVANCE(7);
+      END_STATE();
+    case 8:
+      if (lookahead == '*') ADVANCE(10);
+      if (lookahead == '/') ADVANCE(152);
+      END_STATE();
+    case 9:
+      if (lookahead == '*') ADVANCE(9);
+      if (lookahead == '/') ADVANCE(151);
+      if (lookahead != 0) ADVANCE(10);
+      END_STATE();
+    case 10:
+      if (lookahead == '*') ADVANCE(9);
+      if (lookahead != 0) ADVANCE(10);
+      END_STATE();
+    case 11:
+      if (lookahead == '+') ADVANCE(38);
+      if (lookahead == '-') ADVANCE(40);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(130);
+      END_STATE();
+    case 12:
+      if (lookahead == '-') ADVANCE(152);
+      if (lookahead == '=') ADVANCE(88);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(125);
+      END_STATE();
+    case 13:
+      if (lookahead == '-') ADVANCE(54);
+      END_STATE();
+    case 14:
+      if (lookahead == '-') ADVANCE(60);
+      END_STATE();
+    case 15:
+      if (lookahead == '-') ADVANCE(71);
+      END_STATE();
+    case 16:
+      if (lookahead == '-') ADVANCE(65);
+      END_STATE();
+    case 17:
+      ADVANCE_MAP(
+        '-', 65,
+        0xb5, 37,
+        'M', 142,
+        'm', 142,
+        'N', 37,
+        'n', 37,
+        'U', 37,
+        'u', 37,
+        'D', 144,
+        'H', 144,
+        'S', 144,
+        'W', 144,
+        'Y', 144,
+        'd', 144,
+        'h', 144,
+        's', 144,
+        'w', 144,
+        'y', 144,
+      );
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(28);
+      END_STATE();
+    case 18:
+      if (lookahead == '=') ADVANCE(82);
+      END_STATE();
+    case 19:
+      if (lookahead == '=') ADVANCE(87);
+      END_STATE();
+    case 20:
+      if (lookahead == '{') ADVANCE(21);
+      END_STATE();
+    case 21:
+      if (lookahead == '{') ADVANCE(22);
+      if (lookahead == '}') ADVANCE(112);
+      if (lookahead != 0) ADVANCE(21);
+      END_STATE();
+    case 22:
+      if (lookahead == '}') ADVANCE(21);
+      if (lookahead != 0 &&
+          lookahead != '{') ADVANCE(22);
+      END_STATE();
+    case 23:
+      ADVANCE_MAP(
+        0xb5, 37,
+        'D', 141,
+        'd', 141,
+        'M', 142,
+        'm', 142,
+        'N', 37,
+        'n', 37,
+        'U', 37,
+        'u', 37,
+        'H', 144,
+        'S', 144,
+        'W', 144,
+        'Y', 144,
+        'h', 144,
+        's', 144,
+        'w', 144,
+        'y', 144,
+      );
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(16);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(17);
+      END_STATE();
+    case 24:
+      ADVANCE_MAP(
+        0xb5, 37,
+        'D', 145,
+        'd', 145,
+        'M', 142,
+        'm', 142,
+        'N', 37,
+        'n', 37,
+        'U', 37,
+        'u', 37,
+        'H', 144,
+        'S', 144,
+        'W', 144,
+        'Y', 144,
+        'h', 144,
+        's', 144,
+        'w', 144,
+        'y', 144,
+      );
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(42);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(23);
+      END_STATE();
+    case 25:
+      ADVANCE_MAP(
+        0xb5, 37,
+        'D', 146,
+        'd', 146,
+        'M', 142,
+        'm', 142,
+        'N', 37,
+        'n', 37,
+        'U', 37,
+        'u', 37,
+        'H', 144,
+        'S', 144,
+        'W', 144,
+        'Y', 144,
+        'h', 144,
+        's', 144,
+        'w', 144,
+        'y', 144,
+      );
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(46);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(26);
+      END_STATE();
+    case 26:
+      ADVANCE_MAP(
+        0xb5, 37,
+        'D', 147,
+        'd', 147,
+        'M', 142,
+        'm', 142,
+        'N', 37,
+        'n', 37,
+        'U', 37,
+        'u', 37,
+        'H', 144,
+        'S', 144,
+        'W', 144,
+        'Y', 144,
+        'h', 144,
+        's', 144,
+        'w', 144,
+        'y', 144,
+      );
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(44);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(24);
+      END_STATE();
+    case 27:
+      ADVANCE_MAP(
+        0xb5, 37,
+        'D', 149,
+        'd', 149,
+        'M', 142,
+        'm', 142,
+        'N', 37,
+        'n', 37,
+        'U', 37,
+        'u', 37,
+        'H', 144,
+        'S', 144,
+        'W', 144,
+        'Y', 144,
+        'h', 144,
+        's', 144,
+        'w', 144,
+        'y', 144,
+      );
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(48);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(25);
+      END_STATE();
+    case 28:
+      ADVANCE_MAP(
+        0xb5, 37,
+        'M', 142,
+        'm', 142,
+        'N', 37,
+        'n', 37,
+        'U', 37,
+        'u', 37,
+        'D', 144,
+        'H', 144,
+        'S', 144,
+        'W', 144,
+        'Y', 144,
+        'd', 144,
+        'h', 144,
+        's', 144,
+        'w', 144,
+        'y', 144,
+      );
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(28);
+      END_STATE();
+    case 29:
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(38);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(135);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(50);
+      END_STATE();
+    case 30:
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(38);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(130);
+      END_STATE();
+    case 31:
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(38);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(129);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(16);
+      END_STATE();
+    case 32:
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(38);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(133);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(44);
+      END_STATE();
+    case 33:
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(38);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(131);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(42);
+      END_STATE();
+    case 34:
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(38);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(132);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(46);
+      END_STATE();
+    case 35:
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(38);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(134);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(48);
+      END_STATE();
+    case 36:
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(39);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(127);
+      END_STATE();
+    case 37:
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(144);
+      END_STATE();
+    case 38:
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(130);
+      END_STATE();
+    case 39:
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(127);
+      END_STATE();
+    case 40:
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(137);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(64);
+      END_STATE();
+    case 41:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          lookahead == 'D' ||
+          lookahead == 'H' ||
+          lookahead == 'M' ||
+          lookahead == 'S' ||
+          lookahead == 'T' ||
+          lookahead == 'W' ||
+          lookahead == 'Y') ADVANCE(150);
+      END_STATE();
+    case 42:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(16);
+      END_STATE();
+    case 43:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(139);
+      END_STATE();
+    case 44:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(42);
+      END_STATE();
+    case 45:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(43);
+      END_STATE();
+    case 46:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(44);
+      END_STATE();
+    case 47:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(45);
+      END_STATE();
+    case 48:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(46);
+      END_STATE();
+    case 49:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(47);
+      END_STATE();
+    case 50:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(48);
+      END_STATE();
+    case 51:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(15);
+      END_STATE();
+    case 52:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(51);
+      END_STATE();
+    case 53:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(52);
+      END_STATE();
+    case 54:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(53);
+      END_STATE();
+    case 55:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(49);
+      END_STATE();
+    case 56:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(50);
+      END_STATE();
+    case 57:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(13);
+      END_STATE();
+    case 58:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(57);
+      END_STATE();
+    case 59:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(58);
+      END_STATE();
+    case 60:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(59);
+      END_STATE();
+    case 61:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(55);
+      END_STATE();
+    case 62:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(14);
+      END_STATE();
+    case 63:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(62);
+      END_STATE();
+    case 64:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(63);
+      END_STATE();
+    case 65:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(64);
+      END_STATE();
+    case 66:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(61);
+      END_STATE();
+    case 67:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(66);
+      END_STATE();
+    case 68:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(67);
+      END_STATE();
+    case 69:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(68);
+      END_STATE();
+    case 70:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(69);
+      END_STATE();
+    case 71:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(70);
+      END_STATE();
+    case 72:
+      if (eof) ADVANCE(74);
+      ADVANCE_MAP(
+        '!', 18,
+        '"', 3,
+        '$', 20,
+        '\'', 7,
+        '(', 76,
+        ')', 78,
+        '+', 92,
+        ',', 77,
+        '-', 94,
+        '.', 81,
+        '/', 8,
+        ':', 99,
+        ';', 75,
+        '<', 83,
+        '=', 79,
+        '>', 85,
+        '[', 89,
+        ']', 90,
+        '}', 98,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ' ||
+          lookahead == '`') SKIP(72);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('_' <= lookahead && lookahead <= 'z')) ADVANCE(110);
+      END_STATE();
+    case 73:
+      if (eof) ADVANCE(74);
+      ADVANCE_MAP(
+        '(', 76,
+        ')', 78,
+        '+', 91,
+        ',', 77,
+        '-', 93,
+        '.', 81,
+        '/', 8,
+        ':', 99,
+        ';', 75,
+        ']', 90,
+        '}', 98,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ' ||
+          lookahead == '`') SKIP(73);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('_' <= lookahead && lookahead <= 'z')) ADVANCE(110);
+      END_STATE();
+    case 74:
+      ACCEPT_TOKEN(ts_builtin_sym_end);
+      END_STATE();
+    case 75:
+      ACCEPT_TOKEN(anon_sym_SEMI);
+      END_STATE();
+    case 76:
+      ACCEPT_TOKEN(anon_sym_LPAREN);
+      END_STATE();
+    case 77:
+      ACCEPT_TOKEN(anon_sym_COMMA);
+      END_STATE();
+    case 78:
+      ACCEPT_TOKEN(anon_sym_RPAREN);
+      END_STATE();
+    case 79:
+      ACCEPT_TOKEN(anon_sym_EQ);
+      END_STATE();
+    case 80:
+      ACCEPT_TOKEN(anon_sym_STAR);
+      END_STATE();
+    case 81:
+      ACCEPT_TOKEN(anon_sym_DOT);
+      END_STATE();
+    case 82:
+      ACCEPT_TOKEN(anon_sym_BANG_EQ);
+      END_STATE();
+    case 83:
+      ACCEPT_TOKEN(anon_sym_LT);
+      if (lookahead == '=') ADVANCE(84);
+      END_STATE();
+    case 84:
+      ACCEPT_TOKEN(anon_sym_LT_EQ);
+      END_STATE();
+    case 85:
+      ACCEPT_TOKEN(anon_sym_GT);
+      if (lookahead == '=') ADVANCE(86);
+      END_STATE();
+    case 86:
+      ACCEPT_TOKEN(anon_sym_GT_EQ);
+      END_STATE();
+    case 87:
+      ACCEPT_TOKEN(anon_sym_PLUS_EQ);
+      END_STATE();
+    case 88:
+      ACCEPT_TOKEN(anon_sym_DASH_EQ);
+      END_STATE();
+    case 89:
+      ACCEPT_TOKEN(anon_sym_LBRACK);
+      END_STATE();
+    case 90:
+      ACCEPT_TOKEN(anon_sym_RBRACK);
+      END_STATE();
+    case 91:
+      ACCEPT_TOKEN(anon_sym_PLUS);
+      END_STATE();
+    case 92:
+      ACCEPT_TOKEN(anon_sym_PLUS);
+      if (lookahead == '=') ADVANCE(87);
+      END_STATE();
+    case 93:
+      ACCEPT_TOKEN(anon_sym_DASH);
+      if (lookahead == '-') ADVANCE(152);
+      END_STATE();
+    case 94:
+      ACCEPT_TOKEN(anon_sym_DASH);
+      if (lookahead == '-') ADVANCE(152);
+      if (lookahead == '=') ADVANCE(88);
+      END_STATE();
+    case 95:
+      ACCEPT_TOKEN(anon_sym_DASH);
+      if (lookahead == '-') ADVANCE(152);
+      if (lookahead == '=') ADVANCE(88);
+      if (lookahead == 'P') ADVANCE(41);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(124);
+      END_STATE();
+    case 96:
+      ACCEPT_TOKEN(anon_sym_DASH);
+      if (lookahead == '-') ADVANCE(152);
+      if (lookahead == 'P') ADVANCE(41);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(124);
+      END_STATE();
+    case 97:
+      ACCEPT_TOKEN(anon_sym_LBRACE);
+      END_STATE();
+    case 98:
+      ACCEPT_TOKEN(anon_sym_RBRACE);
+      END_STATE();
+    case 99:
+      ACCEPT_TOKEN(anon_sym_COLON);
+      END_STATE();
+    case 100:
+      ACCEPT_TOKEN(anon_sym_QMARK);
+      END_STATE();
+    case 101:
+      ACCEPT_TOKEN(sym__unquoted_identifier);
+      if (lookahead == '-') ADVANCE(65);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(110);
+      END_STATE();
+    case 102:
+      ACCEPT_TOKEN(sym__unquoted_identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          lookahead == 'D' ||
+          lookahead == 'H' ||
+          lookahead == 'M' ||
+          lookahead == 'S' ||
+          lookahead == 'T' ||
+          lookahead == 'W' ||
+          lookahead == 'Y') ADVANCE(102);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(110);
+      END_STATE();
+    case 103:
+      ACCEPT_TOKEN(sym__unquoted_identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(101);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(110);
+      END_STATE();
+    case 104:
+      ACCEPT_TOKEN(sym__unquoted_identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(103);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(110);
+      END_STATE();
+    case 105:
+      ACCEPT_TOKEN(sym__unquoted_identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(104);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(110);
+      END_STATE();
+    case 106:
+      ACCEPT_TOKEN(sym__unquoted_identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(105);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(110);
+      END_STATE();
+    case 107:
+      ACCEPT_TOKEN(sym__unquoted_identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(106);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(110);
+      END_STATE();
+    case 108:
+      ACCEPT_TOKEN(sym__unquoted_identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(107);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(110);
+      END_STATE();
+    case 109:
+      ACCEPT_TOKEN(sym__unquoted_identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(108);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(110);
+      END_STATE();
+    case 110:
+      ACCEPT_TOKEN(sym__unquoted_identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(110);
+      END_STATE();
+    case 111:
+      ACCEPT_TOKEN(sym_quoted_identifier);
+      if (lookahead == '"') ADVANCE(3);
+      END_STATE();
+    case 112:
+      ACCEPT_TOKEN(sym_interpolation);
+      END_STATE();
+    case 113:
+      ACCEPT_TOKEN(sym_string_literal);
+      if (lookahead == '\'') ADVANCE(7);
+      END_STATE();
+    case 114:
+      ACCEPT_TOKEN(sym_dollar_string);
+      END_STATE();
+    case 115:
+      ACCEPT_TOKEN(sym_integer);
+      ADVANCE_MAP(
+        '-', 65,
+        '.', 126,
+        0xb5, 37,
+        'E', 30,
+        'e', 30,
+        'M', 142,
+        'm', 142,
+        'N', 37,
+        'n', 37,
+        'U', 37,
+        'u', 37,
+        'D', 144,
+        'H', 144,
+        'S', 144,
+        'W', 144,
+        'Y', 144,
+        'd', 144,
+        'h', 144,
+        's', 144,
+        'w', 144,
+        'y', 144,
+      );
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(124);
+      END_STATE();
+    case 116:
+      ACCEPT_TOKEN(sym_integer);
+      ADVANCE_MAP(
+        '.', 126,
+        0xb5, 37,
+        'D', 143,
+        'd', 143,
+        'E', 29,
+        'e', 29,
+        'M', 142,
+        'm', 142,
+        'N', 37,
+        'n', 37,
+        'U', 37,
+        'u', 37,
+        'X', 140,
+        'x', 140,
+        'H', 144,
+        'S', 144,
+        'W', 144,
+        'Y', 144,
+        'h', 144,
+        's', 144,
+        'w', 144,
+        'y', 144,
+      );
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(56);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(122);
+      END_STATE();
+    case 117:
+      ACCEPT_TOKEN(sym_integer);
+      ADVANCE_MAP(
+        '.', 126,
+        0xb5, 37,
+        'D', 143,
+        'd', 143,
+        'E', 29,
+        'e', 29,
+        'M', 142,
+        'm', 142,
+        'N', 37,
+        'n', 37,
+        'U', 37,
+        'u', 37,
+        'H', 144,
+        'S', 144,
+        'W', 144,
+        'Y', 144,
+        'h', 144,
+        's', 144,
+        'w', 144,
+        'y', 144,
+      );
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(56);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(122);
+      END_STATE();
+    case 118:
+      ACCEPT_TOKEN(sym_integer);
+      ADVANCE_MAP(
+        '.', 126,
+        0xb5, 37,
+        'D', 141,
+        'd', 141,
+        'E', 11,
+        'e', 11,
+        'M', 142,
+        'm', 142,
+        'N', 37,
+        'n', 37,
+        'U', 37,
+        'u', 37,
+        'H', 144,
+        'S', 144,
+        'W', 144,
+        'Y', 144,
+        'h', 144,
+        's', 144,
+        'w', 144,
+        'y', 144,
+      );
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(16);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(115);
+      END_STATE();
+    case 119:
+      ACCEPT_TOKEN(sym_integer);
+      ADVANCE_MAP(
+        '.', 126,
+        0xb5, 37,
+        'D', 145,
+        'd', 145,
+        'E', 31,
+        'e', 31,
+        'M', 142,
+        'm', 142,
+        'N', 37,
+        'n', 37,
+        'U', 37,
+        'u', 37,
+        'H', 144,
+        'S', 144,
+        'W', 144,
+        'Y', 144,
+        'h', 144,
+        's', 144,
+        'w', 144,
+        'y', 144,
+      );
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(42);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(118);
+      END_STATE();
+    case 120:
+      ACCEPT_TOKEN(sym_integer);
+      ADVANCE_MAP(
+        '.', 126,
+        0xb5, 37,
+        'D', 146,
+        'd', 146,
+        'E', 32,
+        'e', 32,
+        'M', 142,
+        'm', 142,
+        'N', 37,
+        'n', 37,
+        'U', 37,
+        'u', 37,
+        'H', 144,
+        'S', 144,
+        'W', 144,
+        'Y', 144,
+        'h', 144,
+        's', 144,
+        'w', 144,
+        'y', 144,
+      );
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(46);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(121);
+      END_STATE();
+    case 121:
+      ACCEPT_TOKEN(sym_integer);
+      ADVANCE_MAP(
+        '.', 126,
+        0xb5, 37,
+        'D', 147,
+        'd', 147,
+        'E', 33,
+        'e', 33,
+        'M', 142,
+        'm', 142,
+        'N', 37,
+        'n', 37,
+        'U', 37,
+        'u', 37,
+        'H', 144,
+        'S', 144,
+        'W', 144,
+        'Y', 144,
+        'h', 144,
+        's', 144,
+        'w', 144,
+        'y', 144,
+      );
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(44);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(119);
+      END_STATE();
+    case 122:
+      ACCEPT_TOKEN(sym_integer);
+      ADVANCE_MAP(
+        '.', 126,
+        0xb5, 37,
+        'D', 148,
+        'd', 148,
+        'E', 35,
+        'e', 35,
+        'M', 142,
+        'm', 142,
+        'N', 37,
+        'n', 37,
+        'U', 37,
+        'u', 37,
+        'H', 144,
+        'S', 144,
+        'W', 144,
+        'Y', 144,
+        'h', 144,
+        's', 144,
+        'w', 144,
+        'y', 144,
+      );
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(50);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(123);
+      END_STATE();
+    case 123:
+      ACCEPT_TOKEN(sym_integer);
+      ADVANCE_MAP(
+        '.', 126,
+        0xb5, 37,
+        'D', 149,
+        'd', 149,
+        'E', 34,
+        'e', 34,
+        'M', 142,
+        'm', 142,
+        'N', 37,
+        'n', 37,
+        'U', 37,
+        'u', 37,
+        'H', 144,
+        'S', 144,
+        'W', 144,
+        'Y', 144,
+        'h', 144,
+        's', 144,
+        'w', 144,
+        'y', 144,
+      );
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(48);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(120);
+      END_STATE();
+    case 124:
+      ACCEPT_TOKEN(sym_integer);
+      ADVANCE_MAP(
+        '.', 126,
+        0xb5, 37,
+        'E', 30,
+        'e', 30,
+        'M', 142,
+        'm', 142,
+        'N', 37,
+        'n', 37,
+        'U', 37,
+        'u', 37,
+        'D', 144,
+        'H', 144,
+        'S', 144,
+        'W', 144,
+        'Y', 144,
+        'd', 144,
+        'h', 144,
+        's', 144,
+        'w', 144,
+        'y', 144,
+      );
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(124);
+      END_STATE();
+    case 125:
+      ACCEPT_TOKEN(sym_integer);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(125);
+      END_STATE();
+    case 126:
+      ACCEPT_TOKEN(aux_sym_float_token1);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(36);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(126);
+      END_STATE();
+    case 127:
+      ACCEPT_TOKEN(aux_sym_float_token1);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(127);
+      END_STATE();
+    case 128:
+      ACCEPT_TOKEN(aux_sym_float_token2);
+      if (lookahead == '-') ADVANCE(60);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(130);
+      END_STATE();
+    case 129:
+      ACCEPT_TOKEN(aux_sym_float_token2);
+      if (lookahead == '-') ADVANCE(65);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(130);
+      END_STATE();
+    case 130:
+      ACCEPT_TOKEN(aux_sym_float_token2);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(130);
+      END_STATE();
+    case 131:
+      ACCEPT_TOKEN(aux_sym_float_token2);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(129);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(16);
+      END_STATE();
+    case 132:
+      ACCEPT_TOKEN(aux_sym_float_token2);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(133);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(44);
+      END_STATE();
+    case 133:
+      ACCEPT_TOKEN(aux_sym_float_token2);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(131);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(42);
+      END_STATE();
+    case 134:
+      ACCEPT_TOKEN(aux_sym_float_token2);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(132);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(46);
+      END_STATE();
+    case 135:
+      ACCEPT_TOKEN(aux_sym_float_token2);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(134);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(48);
+      END_STATE();
+    case 136:
+      ACCEPT_TOKEN(aux_sym_float_token2);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(128);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(14);
+      END_STATE();
+    case 137:
+      ACCEPT_TOKEN(aux_sym_float_token2);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(138);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(63);
+      END_STATE();
+    case 138:
+      ACCEPT_TOKEN(aux_sym_float_token2);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(136);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(62);
+      END_STATE();
+    case 139:
+      ACCEPT_TOKEN(sym_uuid);
+      END_STATE();
+    case 140:
+      ACCEPT_TOKEN(sym_blob);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(140);
+      END_STATE();
+    case 141:
+      ACCEPT_TOKEN(sym_duration);
+      if (lookahead == '-') ADVANCE(65);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(28);
+      END_STATE();
+    case 142:
+      ACCEPT_TOKEN(sym_duration);
+      if (lookahead == 'O' ||
+          lookahead == 'S' ||
+          lookahead == 'o' ||
+          lookahead == 's') ADVANCE(144);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(28);
+      END_STATE();
+    case 143:
+      ACCEPT_TOKEN(sym_duration);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(27);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(50);
+      END_STATE();
+    case 144:
+      ACCEPT_TOKEN(sym_duration);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(28);
+      END_STATE();
+    case 145:
+      ACCEPT_TOKEN(sym_duration);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(17);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(16);
+      END_STATE();
+    case 146:
+      ACCEPT_TOKEN(sym_duration);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(24);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(44);
+      END_STATE();
+    case 147:
+      ACCEPT_TOKEN(sym_duration);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(23);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(42);
+      END_STATE();
+    case 148:
+      ACCEPT_TOKEN(sym_duration);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(25);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(48);
+      END_STATE();
+    case 149:
+      ACCEPT_TOKEN(sym_duration);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(26);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(46);
+      END_STATE();
+    case 150:
+      ACCEPT_TOKEN(sym_duration);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          lookahead == 'D' ||
+          lookahead == 'H' ||
+          lookahead == 'M' ||
+          lookahead == 'S' ||
+          lookahead == 'T' ||
+          lookahead == 'W' ||
+          lookahead == 'Y') ADVANCE(150);
+      END_STATE();
+    case 151:
+      ACCEPT_TOKEN(sym_comment);
+      END_STATE();
+    case 152:
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead != 0 &&
+          lookahead != '\n') ADVANCE(152);
+      END_STATE();
+    default:
+      return false;
+  }
+}
+
+static bool ts_lex_keywords(TSLexer *lexer, TSStateId state) {
+  START_LEXER();
+  eof = lexer->eof(lexer);
+  switch (state) {
+    case 0:
+      ADVANCE_MAP(
+        'A', 1,
+        'a', 1,
+        'B', 2,
+        'b', 2,
+        'C', 3,
+        'c', 3,
+        'D', 4,
+        'd', 4,
+        'E', 5,
+        'e', 5,
+        'F', 6,
+        'f', 6,
+        'G', 7,
+        'g', 7,
+        'I', 8,
+        'i', 8,
+        'J', 9,
+        'j', 9,
+        'K', 10,
+        'k', 10,
+        'L', 11,
+        'l', 11,
+        'M', 12,
+        'm', 12,
+        'N', 13,
+        'n', 13,
+        'O', 14,
+        'o', 14,
+        'P', 15,
+        'p', 15,
+        'R', 16,
+        'r', 16,
+        'S', 17,
+        's', 17,
+        'T', 18,
+        't', 18,
+        'U', 19,
+        'u', 19,
+        'V', 20,
+        'v', 20,
+        'W', 21,
+        'w', 21,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ' ||
+          lookahead == '`') SKIP(0);
+      END_STATE();
+    case 1:
+      ADVANCE_MAP(
+        'D', 22,
+        'd', 22,
+        'G', 23,
+        'g', 23,
+        'L', 24,
+        'l', 24,
+        'N', 25,
+        'n', 25,
+        'P', 26,
+        'p', 26,
+        'S', 27,
+        's', 27,
+      );
+      END_STATE();
+    case 2:
+      ADVANCE_MAP(
+        'A', 28,
+        'a', 28,
+        'E', 29,
+        'e', 29,
+        'I', 30,
+        'i', 30,
+        'L', 31,
+        'l', 31,
+        'O', 32,
+        'o', 32,
+        'Y', 33,
+        'y', 33,
+      );
+      END_STATE();
+    case 3:
+      ADVANCE_MAP(
+        'A', 34,
+        'a', 34,
+        'L', 35,
+        'l', 35,
+        'O', 36,
+        'o', 36,
+        'R', 37,
+        'r', 37,
+        'U', 38,
+        'u', 38,
+      );
+      END_STATE();
+    case 4:
+      ADVANCE_MAP(
+        'A', 39,
+        'a', 39,
+        'E', 40,
+        'e', 40,
+        'I', 41,
+        'i', 41,
+        'O', 42,
+        'o', 42,
+        'R', 43,
+        'r', 43,
+        'U', 44,
+        'u', 44,
+      );
+      END_STATE();
+    case 5:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(45);
+      if (lookahead == 'X' ||
+          lookahead == 'x') ADVANCE(46);
+      END_STATE();
+    case 6:
+      ADVANCE_MAP(
+        'A', 47,
+        'a', 47,
+        'I', 48,
+        'i', 48,
+        'L', 49,
+        'l', 49,
+        'R', 50,
+        'r', 50,
+        'U', 51,
+        'u', 51,
+      );
+      END_STATE();
+    case 7:
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(52);
+      END_STATE();
+    case 8:
+      if (lookahead == 'F' ||
+          lookahead == 'f') ADVANCE(53);
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(54);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(55);
+      END_STATE();
+    case 9:
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(56);
+      END_STATE();
+    case 10:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(57);
+      END_STATE();
+    case 11:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(58);
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(59);
+      END_STATE();
+    case 12:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(60);
+      END_STATE();
+    case 13:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(61);
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(62);
+      if (lookahead == 'U' ||
+          lookahead == 'u') ADVANCE(63);
+      END_STATE();
+    case 14:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(64);
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(65);
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(66);
+      END_STATE();
+    case 15:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(67);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(68);
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(69);
+      END_STATE();
+    case 16:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(70);
+      END_STATE();
+    case 17:
+      ADVANCE_MAP(
+        'C', 71,
+        'c', 71,
+        'E', 72,
+        'e', 72,
+        'F', 73,
+        'f', 73,
+        'M', 74,
+        'm', 74,
+        'T', 75,
+        't', 75,
+      );
+      END_STATE();
+    case 18:
+      ADVANCE_MAP(
+        'A', 76,
+        'a', 76,
+        'E', 77,
+        'e', 77,
+        'I', 78,
+        'i', 78,
+        'O', 79,
+        'o', 79,
+        'R', 80,
+        'r', 80,
+        'T', 81,
+        't', 81,
+        'U', 82,
+        'u', 82,
+        'Y', 83,
+        'y', 83,
+      );
+      END_STATE();
+    case 19:
+      ADVANCE_MAP(
+        'N', 84,
+        'n', 84,
+        'P', 85,
+        'p', 85,
+        'S', 86,
+        's', 86,
+        'U', 87,
+        'u', 87,
+      );
+      END_STATE();
+    case 20:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(88);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(89);
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(90);
+      END_STATE();
+    case 21:
+      if (lookahead == 'H' ||
+          lookahead == 'h') ADVANCE(91);
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(92);
+      END_STATE();
+    case 22:
+      if (lookahead == 'D' ||
+          lookahead == 'd') ADVANCE(93);
+      END_STATE();
+    case 23:
+      if (lookahead == 'G' ||
+          lookahead == 'g') ADVANCE(94);
+      END_STATE();
+    case 24:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(95);
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(96);
+      END_STATE();
+    case 25:
+      if (lookahead == 'D' ||
+          lookahead == 'd') ADVANCE(97);
+      END_STATE();
+    case 26:
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(98);
+      END_STATE();
+    case 27:
+      ACCEPT_TOKEN(sym_keyword_as);
+      if (lookahead == 'C' ||
+          lookahead == 'c') ADVANCE(99);
+      END_STATE();
+    case 28:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(100);
+      END_STATE();
+    case 29:
+      if (lookahead == 'G' ||
+          lookahead == 'g') ADVANCE(101);
+      END_STATE();
+    case 30:
+      if (lookahead == 'G' ||
+          lookahead == 'g') ADVANCE(102);
+      END_STATE();
+    case 31:
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(103);
+      END_STATE();
+    case 32:
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(104);
+      END_STATE();
+    case 33:
+      ACCEPT_TOKEN(sym_keyword_by);
+      END_STATE();
+    case 34:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(105);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(106);
+      END_STATE();
+    case 35:
+      if (lookahead == 'U' ||
+          lookahead == 'u') ADVANCE(107);
+      END_STATE();
+    case 36:
+      ADVANCE_MAP(
+        'L', 108,
+        'l', 108,
+        'M', 109,
+        'm', 109,
+        'N', 110,
+        'n', 110,
+        'U', 111,
+        'u', 111,
+      );
+      END_STATE();
+    case 37:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(112);
+      END_STATE();
+    case 38:
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(113);
+      END_STATE();
+    case 39:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(114);
+      END_STATE();
+    case 40:
+      ADVANCE_MAP(
+        'C', 115,
+        'c', 115,
+        'F', 116,
+        'f', 116,
+        'L', 117,
+        'l', 117,
+        'S', 118,
+        's', 118,
+      );
+      END_STATE();
+    case 41:
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(119);
+      END_STATE();
+    case 42:
+      if (lookahead == 'U' ||
+          lookahead == 'u') ADVANCE(120);
+      END_STATE();
+    case 43:
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(121);
+      END_STATE();
+    case 44:
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(122);
+      END_STATE();
+    case 45:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(123);
+      END_STATE();
+    case 46:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(124);
+      END_STATE();
+    case 47:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(125);
+      END_STATE();
+    case 48:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(126);
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(127);
+      END_STATE();
+    case 49:
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(128);
+      END_STATE();
+    case 50:
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(129);
+      END_STATE();
+    case 51:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(130);
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(131);
+      END_STATE();
+    case 52:
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(132);
+      END_STATE();
+    case 53:
+      ACCEPT_TOKEN(sym_keyword_if);
+      END_STATE();
+    case 54:
+      ACCEPT_TOKEN(sym_keyword_in);
+      ADVANCE_MAP(
+        'D', 133,
+        'd', 133,
+        'E', 134,
+        'e', 134,
+        'F', 135,
+        'f', 135,
+        'I', 136,
+        'i', 136,
+        'P', 137,
+        'p', 137,
+        'S', 138,
+        's', 138,
+        'T', 139,
+        't', 139,
+      );
+      END_STATE();
+    case 55:
+      ACCEPT_TOKEN(sym_keyword_is);
+      END_STATE();
+    case 56:
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(140);
+      END_STATE();
+    case 57:
+      if (lookahead == 'Y' ||
+          lookahead == 'y') ADVANCE(141);
+      END_STATE();
+    case 58:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(142);
+      END_STATE();
+    case 59:
+      if (lookahead == 'K' ||
+          lookahead == 'k') ADVANCE(143);
+      if (lookahead == 'M' ||
+          lookahead == 'm') ADVANCE(144);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(145);
+      END_STATE();
+    case 60:
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(146);
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(147);
+      END_STATE();
+    case 61:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(148);
+      END_STATE();
+    case 62:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(149);
+      END_STATE();
+    case 63:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(150);
+      END_STATE();
+    case 64:
+      ACCEPT_TOKEN(sym_keyword_on);
+      END_STATE();
+    case 65:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(151);
+      END_STATE();
+    case 66:
+      ACCEPT_TOKEN(sym_keyword_or);
+      if (lookahead == 'D' ||
+          lookahead == 'd') ADVANCE(152);
+      END_STATE();
+    case 67:
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(153);
+      END_STATE();
+    case 68:
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(154);
+      END_STATE();
+    case 69:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(155);
+      END_STATE();
+    case 70:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(156);
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(157);
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(158);
+      END_STATE();
+    case 71:
+      if (lookahead == 'H' ||
+          lookahead == 'h') ADVANCE(159);
+      END_STATE();
+    case 72:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(160);
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(161);
+      END_STATE();
+    case 73:
+      if (lookahead == 'U' ||
+          lookahead == 'u') ADVANCE(162);
+      END_STATE();
+    case 74:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(163);
+      END_STATE();
+    case 75:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(164);
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(165);
+      if (lookahead == 'Y' ||
+          lookahead == 'y') ADVANCE(166);
+      END_STATE();
+    case 76:
+      if (lookahead == 'B' ||
+          lookahead == 'b') ADVANCE(167);
+      END_STATE();
+    case 77:
+      if (lookahead == 'X' ||
+          lookahead == 'x') ADVANCE(168);
+      END_STATE();
+    case 78:
+      if (lookahead == 'M' ||
+          lookahead == 'm') ADVANCE(169);
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(170);
+      END_STATE();
+    case 79:
+      ACCEPT_TOKEN(sym_keyword_to);
+      if (lookahead == 'K' ||
+          lookahead == 'k') ADVANCE(171);
+      END_STATE();
+    case 80:
+      if (lookahead == 'U' ||
+          lookahead == 'u') ADVANCE(172);
+      END_STATE();
+    case 81:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(173);
+      END_STATE();
+    case 82:
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(174);
+      END_STATE();
+    case 83:
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(175);
+      END_STATE();
+    case 84:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(176);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(177);
+      END_STATE();
+    case 85:
+      if (lookahead == 'D' ||
+          lookahead == 'd') ADVANCE(178);
+      END_STATE();
+    case 86:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(179);
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(180);
+      END_STATE();
+    case 87:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(181);
+      END_STATE();
+    case 88:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(182);
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(183);
+      END_STATE();
+    case 89:
+      if (lookahead == 'C' ||
+          lookahead == 'c') ADVANCE(184);
+      END_STATE();
+    case 90:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(185);
+      END_STATE();
+    case 91:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(186);
+      END_STATE();
+    case 92:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(187);
+      END_STATE();
+    case 93:
+      ACCEPT_TOKEN(sym_keyword_add);
+      END_STATE();
+    case 94:
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(188);
+      END_STATE();
+    case 95:
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(189);
+      END_STATE();
+    case 96:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(190);
+      END_STATE();
+    case 97:
+      ACCEPT_TOKEN(sym_keyword_and);
+      END_STATE();
+    case 98:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(191);
+      END_STATE();
+    case 99:
+      ACCEPT_TOKEN(sym_keyword_asc);
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(192);
+      END_STATE();
+    case 100:
+      if (lookahead == 'C' ||
+          lookahead == 'c') ADVANCE(193);
+      END_STATE();
+    case 101:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(194);
+      END_STATE();
+    case 102:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(195);
+      END_STATE();
+    case 103:
+      if (lookahead == 'B' ||
+          lookahead == 'b') ADVANCE(196);
+      END_STATE();
+    case 104:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(197);
+      END_STATE();
+    case 105:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(198);
+      END_STATE();
+    case 106:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(199);
+      END_STATE();
+    case 107:
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(200);
+      END_STATE();
+    case 108:
+      if (lookahead == 'U' ||
+          lookahead == 'u') ADVANCE(201);
+      END_STATE();
+    case 109:
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(202);
+      END_STATE();
+    case 110:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(203);
+      END_STATE();
+    case 111:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(204);
+      END_STATE();
+    case 112:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(205);
+      END_STATE();
+    case 113:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(206);
+      END_STATE();
+    case 114:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(207);
+      END_STATE();
+    case 115:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(208);
+      END_STATE();
+    case 116:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(209);
+      END_STATE();
+    case 117:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(210);
+      END_STATE();
+    case 118:
+      if (lookahead == 'C' ||
+          lookahead == 'c') ADVANCE(211);
+      END_STATE();
+    case 119:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(212);
+      END_STATE();
+    case 120:
+      if (lookahead == 'B' ||
+          lookahead == 'b') ADVANCE(213);
+      END_STATE();
+    case 121:
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(214);
+      END_STATE();
+    case 122:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(215);
+      END_STATE();
+    case 123:
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(216);
+      END_STATE();
+    case 124:
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(217);
+      END_STATE();
+    case 125:
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(218);
+      END_STATE();
+    case 126:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(219);
+      END_STATE();
+    case 127:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(220);
+      END_STATE();
+    case 128:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(221);
+      END_STATE();
+    case 129:
+      if (lookahead == 'M' ||
+          lookahead == 'm') ADVANCE(222);
+      if (lookahead == 'Z' ||
+          lookahead == 'z') ADVANCE(223);
+      END_STATE();
+    case 130:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(224);
+      END_STATE();
+    case 131:
+      if (lookahead == 'C' ||
+          lookahead == 'c') ADVANCE(225);
+      END_STATE();
+    case 132:
+      if (lookahead == 'U' ||
+          lookahead == 'u') ADVANCE(226);
+      END_STATE();
+    case 133:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(227);
+      END_STATE();
+    case 134:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(228);
+      END_STATE();
+    case 135:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(229);
+      END_STATE();
+    case 136:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(230);
+      END_STATE();
+    case 137:
+      if (lookahead == 'U' ||
+          lookahead == 'u') ADVANCE(231);
+      END_STATE();
+    case 138:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(232);
+      END_STATE();
+    case 139:
+      ACCEPT_TOKEN(aux_sym_native_type_token11);
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(233);
+      END_STATE();
+    case 140:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(234);
+      END_STATE();
+    case 141:
+      ACCEPT_TOKEN(sym_keyword_key);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(235);
+      END_STATE();
+    case 142:
+      if (lookahead == 'G' ||
+          lookahead == 'g') ADVANCE(236);
+      END_STATE();
+    case 143:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(237);
+      END_STATE();
+    case 144:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(238);
+      END_STATE();
+    case 145:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(239);
+      END_STATE();
+    case 146:
+      ACCEPT_TOKEN(sym_keyword_map);
+      END_STATE();
+    case 147:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(240);
+      END_STATE();
+    case 148:
+      ACCEPT_TOKEN(aux_sym_float_token3);
+      END_STATE();
+    case 149:
+      ACCEPT_TOKEN(sym_keyword_not);
+      END_STATE();
+    case 150:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(241);
+      END_STATE();
+    case 151:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(242);
+      END_STATE();
+    case 152:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(243);
+      END_STATE();
+    case 153:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(244);
+      END_STATE();
+    case 154:
+      ACCEPT_TOKEN(sym_keyword_per);
+      END_STATE();
+    case 155:
+      if (lookahead == 'M' ||
+          lookahead == 'm') ADVANCE(245);
+      END_STATE();
+    case 156:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(246);
+      END_STATE();
+    case 157:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(247);
+      END_STATE();
+    case 158:
+      if (lookahead == 'U' ||
+          lookahead == 'u') ADVANCE(248);
+      END_STATE();
+    case 159:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(249);
+      END_STATE();
+    case 160:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(250);
+      END_STATE();
+    case 161:
+      ACCEPT_TOKEN(sym_keyword_set);
+      END_STATE();
+    case 162:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(251);
+      END_STATE();
+    case 163:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(252);
+      END_STATE();
+    case 164:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(253);
+      END_STATE();
+    case 165:
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(254);
+      END_STATE();
+    case 166:
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(255);
+      END_STATE();
+    case 167:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(256);
+      END_STATE();
+    case 168:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(257);
+      END_STATE();
+    case 169:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(258);
+      END_STATE();
+    case 170:
+      if (lookahead == 'Y' ||
+          lookahead == 'y') ADVANCE(259);
+      END_STATE();
+    case 171:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(260);
+      END_STATE();
+    case 172:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(261);
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(262);
+      END_STATE();
+    case 173:
+      ACCEPT_TOKEN(sym_keyword_ttl);
+      END_STATE();
+    case 174:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(263);
+      END_STATE();
+    case 175:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(264);
+      END_STATE();
+    case 176:
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(265);
+      END_STATE();
+    case 177:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(266);
+      END_STATE();
+    case 178:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(267);
+      END_STATE();
+    case 179:
+      ACCEPT_TOKEN(sym_keyword_use);
+      END_STATE();
+    case 180:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(268);
+      END_STATE();
+    case 181:
+      if (lookahead == 'D' ||
+          lookahead == 'd') ADVANCE(269);
+      END_STATE();
+    case 182:
+      if (lookahead == 'U' ||
+          lookahead == 'u') ADVANCE(270);
+      END_STATE();
+    case 183:
+      if (lookahead == 'C' ||
+          lookahead == 'c') ADVANCE(271);
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(272);
+      END_STATE();
+    case 184:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(273);
+      END_STATE();
+    case 185:
+      if (lookahead == 'W' ||
+          lookahead == 'w') ADVANCE(274);
+      END_STATE();
+    case 186:
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(275);
+      END_STATE();
+    case 187:
+      if (lookahead == 'H' ||
+          lookahead == 'h') ADVANCE(276);
+      END_STATE();
+    case 188:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(277);
+      END_STATE();
+    case 189:
+      if (lookahead == 'W' ||
+          lookahead == 'w') ADVANCE(278);
+      END_STATE();
+    case 190:
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(279);
+      END_STATE();
+    case 191:
+      if (lookahead == 'Y' ||
+          lookahead == 'y') ADVANCE(280);
+      END_STATE();
+    case 192:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(281);
+      END_STATE();
+    case 193:
+      if (lookahead == 'H' ||
+          lookahead == 'h') ADVANCE(282);
+      END_STATE();
+    case 194:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(283);
+      END_STATE();
+    case 195:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(284);
+      END_STATE();
+    case 196:
+      ACCEPT_TOKEN(aux_sym_native_type_token3);
+      END_STATE();
+    case 197:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(285);
+      END_STATE();
+    case 198:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(286);
+      END_STATE();
+    case 199:
+      ACCEPT_TOKEN(sym_keyword_cast);
+      END_STATE();
+    case 200:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(287);
+      END_STATE();
+    case 201:
+      if (lookahead == 'M' ||
+          lookahead == 'm') ADVANCE(288);
+      END_STATE();
+    case 202:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(289);
+      END_STATE();
+    case 203:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(290);
+      END_STATE();
+    case 204:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(291);
+      END_STATE();
+    case 205:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(292);
+      END_STATE();
+    case 206:
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(293);
+      END_STATE();
+    case 207:
+      ACCEPT_TOKEN(aux_sym_native_type_token5);
+      END_STATE();
+    case 208:
+      if (lookahead == 'M' ||
+          lookahead == 'm') ADVANCE(294);
+      END_STATE();
+    case 209:
+      if (lookahead == 'U' ||
+          lookahead == 'u') ADVANCE(295);
+      END_STATE();
+    case 210:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(296);
+      END_STATE();
+    case 211:
+      ACCEPT_TOKEN(sym_keyword_desc);
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(297);
+      END_STATE();
+    case 212:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(298);
+      END_STATE();
+    case 213:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(299);
+      END_STATE();
+    case 214:
+      ACCEPT_TOKEN(sym_keyword_drop);
+      END_STATE();
+    case 215:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(300);
+      END_STATE();
+    case 216:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(301);
+      END_STATE();
+    case 217:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(302);
+      END_STATE();
+    case 218:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(303);
+      END_STATE();
+    case 219:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(304);
+      END_STATE();
+    case 220:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(305);
+      END_STATE();
+    case 221:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(306);
+      END_STATE();
+    case 222:
+      ACCEPT_TOKEN(sym_keyword_from);
+      END_STATE();
+    case 223:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(307);
+      END_STATE();
+    case 224:
+      ACCEPT_TOKEN(sym_keyword_full);
+      END_STATE();
+    case 225:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(308);
+      END_STATE();
+    case 226:
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(309);
+      END_STATE();
+    case 227:
+      if (lookahead == 'X' ||
+          lookahead == 'x') ADVANCE(310);
+      END_STATE();
+    case 228:
+      ACCEPT_TOKEN(aux_sym_native_type_token10);
+      END_STATE();
+    case 229:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(311);
+      END_STATE();
+    case 230:
+      if (lookahead == 'C' ||
+          lookahead == 'c') ADVANCE(312);
+      END_STATE();
+    case 231:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(313);
+      END_STATE();
+    case 232:
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(314);
+      END_STATE();
+    case 233:
+      ACCEPT_TOKEN(sym_keyword_into);
+      END_STATE();
+    case 234:
+      ACCEPT_TOKEN(sym_keyword_json);
+      END_STATE();
+    case 235:
+      ACCEPT_TOKEN(sym_keyword_keys);
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(315);
+      END_STATE();
+    case 236:
+      if (lookahead == 'U' ||
+          lookahead == 'u') ADVANCE(316);
+      END_STATE();
+    case 237:
+      ACCEPT_TOKEN(sym_keyword_like);
+      END_STATE();
+    case 238:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(317);
+      END_STATE();
+    case 239:
+      ACCEPT_TOKEN(sym_keyword_list);
+      END_STATE();
+    case 240:
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(318);
+      END_STATE();
+    case 241:
+      ACCEPT_TOKEN(sym_keyword_null);
+      END_STATE();
+    case 242:
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(319);
+      END_STATE();
+    case 243:
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(320);
+      END_STATE();
+    case 244:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(321);
+      END_STATE();
+    case 245:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(322);
+      END_STATE();
+    case 246:
+      if (lookahead == 'M' ||
+          lookahead == 'm') ADVANCE(323);
+      END_STATE();
+    case 247:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(324);
+      END_STATE();
+    case 248:
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(325);
+      END_STATE();
+    case 249:
+      if (lookahead == 'M' ||
+          lookahead == 'm') ADVANCE(326);
+      END_STATE();
+    case 250:
+      if (lookahead == 'C' ||
+          lookahead == 'c') ADVANCE(327);
+      END_STATE();
+    case 251:
+      if (lookahead == 'C' ||
+          lookahead == 'c') ADVANCE(328);
+      END_STATE();
+    case 252:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(329);
+      END_STATE();
+    case 253:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(330);
+      END_STATE();
+    case 254:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(331);
+      END_STATE();
+    case 255:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(332);
+      END_STATE();
+    case 256:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(333);
+      END_STATE();
+    case 257:
+      ACCEPT_TOKEN(aux_sym_native_type_token13);
+      END_STATE();
+    case 258:
+      ACCEPT_TOKEN(aux_sym_native_type_token14);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(334);
+      if (lookahead == 'U' ||
+          lookahead == 'u') ADVANCE(335);
+      END_STATE();
+    case 259:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(336);
+      END_STATE();
+    case 260:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(337);
+      END_STATE();
+    case 261:
+      ACCEPT_TOKEN(aux_sym_boolean_token1);
+      END_STATE();
+    case 262:
+      if (lookahead == 'C' ||
+          lookahead == 'c') ADVANCE(338);
+      END_STATE();
+    case 263:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(339);
+      END_STATE();
+    case 264:
+      ACCEPT_TOKEN(sym_keyword_type);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(340);
+      END_STATE();
+    case 265:
+      if (lookahead == 'G' ||
+          lookahead == 'g') ADVANCE(341);
+      END_STATE();
+    case 266:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(342);
+      END_STATE();
+    case 267:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(343);
+      END_STATE();
+    case 268:
+      if (lookahead == 'G' ||
+          lookahead == 'g') ADVANCE(344);
+      END_STATE();
+    case 269:
+      ACCEPT_TOKEN(aux_sym_native_type_token17);
+      END_STATE();
+    case 270:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(345);
+      END_STATE();
+    case 271:
+      if (lookahead == 'H' ||
+          lookahead == 'h') ADVANCE(346);
+      END_STATE();
+    case 272:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(347);
+      END_STATE();
+    case 273:
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(348);
+      END_STATE();
+    case 274:
+      ACCEPT_TOKEN(sym_keyword_view);
+      END_STATE();
+    case 275:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(349);
+      END_STATE();
+    case 276:
+      ACCEPT_TOKEN(sym_keyword_with);
+      END_STATE();
+    case 277:
+      if (lookahead == 'G' ||
+          lookahead == 'g') ADVANCE(350);
+      END_STATE();
+    case 278:
+      ACCEPT_TOKEN(sym_keyword_allow);
+      END_STATE();
+    case 279:
+      ACCEPT_TOKEN(sym_keyword_alter);
+      END_STATE();
+    case 280:
+      ACCEPT_TOKEN(sym_keyword_apply);
+      END_STATE();
+    case 281:
+      ACCEPT_TOKEN(aux_sym_native_type_token1);
+      END_STATE();
+    case 282:
+      ACCEPT_TOKEN(sym_keyword_batch);
+      END_STATE();
+    case 283:
+      ACCEPT_TOKEN(sym_keyword_begin);
+      END_STATE();
+    case 284:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(351);
+      END_STATE();
+    case 285:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(352);
+      END_STATE();
+    case 286:
+      if (lookahead == 'D' ||
+          lookahead == 'd') ADVANCE(353);
+      END_STATE();
+    case 287:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(354);
+      END_STATE();
+    case 288:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(355);
+      END_STATE();
+    case 289:
+      if (lookahead == 'C' ||
+          lookahead == 'c') ADVANCE(356);
+      END_STATE();
+    case 290:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(357);
+      END_STATE();
+    case 291:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(358);
+      END_STATE();
+    case 292:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(359);
+      END_STATE();
+    case 293:
+      if (lookahead == 'M' ||
+          lookahead == 'm') ADVANCE(360);
+      END_STATE();
+    case 294:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(361);
+      END_STATE();
+    case 295:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(362);
+      END_STATE();
+    case 296:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(363);
+      END_STATE();
+    case 297:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(364);
+      END_STATE();
+    case 298:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(365);
+      END_STATE();
+    case 299:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(366);
+      END_STATE();
+    case 300:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(367);
+      END_STATE();
+    case 301:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(368);
+      END_STATE();
+    case 302:
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(369);
+      END_STATE();
+    case 303:
+      ACCEPT_TOKEN(aux_sym_boolean_token2);
+      END_STATE();
+    case 304:
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(370);
+      END_STATE();
+    case 305:
+      if (lookahead == 'F' ||
+          lookahead == 'f') ADVANCE(371);
+      END_STATE();
+    case 306:
+      ACCEPT_TOKEN(aux_sym_native_type_token9);
+      END_STATE();
+    case 307:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(372);
+      END_STATE();
+    case 308:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(373);
+      END_STATE();
+    case 309:
+      ACCEPT_TOKEN(sym_keyword_group);
+      END_STATE();
+    case 310:
+      ACCEPT_TOKEN(sym_keyword_index);
+      END_STATE();
+    case 311:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(374);
+      END_STATE();
+    case 312:
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(375);
+      END_STATE();
+    case 313:
+      ACCEPT_TOKEN(sym_keyword_input);
+      END_STATE();
+    case 314:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(376);
+      END_STATE();
+    case 315:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(377);
+      END_STATE();
+    case 316:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(378);
+      END_STATE();
+    case 317:
+      ACCEPT_TOKEN(sym_keyword_limit);
+      END_STATE();
+    case 318:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(379);
+      END_STATE();
+    case 319:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(380);
+      END_STATE();
+    case 320:
+      ACCEPT_TOKEN(sym_keyword_order);
+      END_STATE();
+    case 321:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(381);
+      END_STATE();
+    case 322:
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(382);
+      END_STATE();
+    case 323:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(383);
+      END_STATE();
+    case 324:
+      if (lookahead == 'C' ||
+          lookahead == 'c') ADVANCE(384);
+      END_STATE();
+    case 325:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(385);
+      END_STATE();
+    case 326:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(386);
+      END_STATE();
+    case 327:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(387);
+      END_STATE();
+    case 328:
+      ACCEPT_TOKEN(sym_keyword_sfunc);
+      END_STATE();
+    case 329:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(388);
+      END_STATE();
+    case 330:
+      if (lookahead == 'C' ||
+          lookahead == 'c') ADVANCE(389);
+      END_STATE();
+    case 331:
+      if (lookahead == 'G' ||
+          lookahead == 'g') ADVANCE(390);
+      END_STATE();
+    case 332:
+      ACCEPT_TOKEN(sym_keyword_stype);
+      END_STATE();
+    case 333:
+      ACCEPT_TOKEN(sym_keyword_table);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(391);
+      END_STATE();
+    case 334:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(392);
+      END_STATE();
+    case 335:
+      if (lookahead == 'U' ||
+          lookahead == 'u') ADVANCE(393);
+      END_STATE();
+    case 336:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(394);
+      END_STATE();
+    case 337:
+      ACCEPT_TOKEN(sym_keyword_token);
+      END_STATE();
+    case 338:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(395);
+      END_STATE();
+    case 339:
+      ACCEPT_TOKEN(sym_keyword_tuple);
+      END_STATE();
+    case 340:
+      ACCEPT_TOKEN(sym_keyword_types);
+      END_STATE();
+    case 341:
+      if (lookahead == 'G' ||
+          lookahead == 'g') ADVANCE(396);
+      END_STATE();
+    case 342:
+      ACCEPT_TOKEN(sym_keyword_unset);
+      END_STATE();
+    case 343:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(397);
+      END_STATE();
+    case 344:
+      ACCEPT_TOKEN(sym_keyword_using);
+      END_STATE();
+    case 345:
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(398);
+      END_STATE();
+    case 346:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(399);
+      END_STATE();
+    case 347:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(400);
+      END_STATE();
+    case 348:
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(401);
+      END_STATE();
+    case 349:
+      ACCEPT_TOKEN(sym_keyword_where);
+      END_STATE();
+    case 350:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(402);
+      END_STATE();
+    case 351:
+      ACCEPT_TOKEN(aux_sym_native_type_token2);
+      END_STATE();
+    case 352:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(403);
+      END_STATE();
+    case 353:
+      ACCEPT_TOKEN(sym_keyword_called);
+      END_STATE();
+    case 354:
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(404);
+      END_STATE();
+    case 355:
+      if (lookahead == 'F' ||
+          lookahead == 'f') ADVANCE(405);
+      END_STATE();
+    case 356:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(406);
+      END_STATE();
+    case 357:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(407);
+      END_STATE();
+    case 358:
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(408);
+      END_STATE();
+    case 359:
+      ACCEPT_TOKEN(sym_keyword_create);
+      END_STATE();
+    case 360:
+      ACCEPT_TOKEN(sym_keyword_custom);
+      END_STATE();
+    case 361:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(409);
+      END_STATE();
+    case 362:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(410);
+      END_STATE();
+    case 363:
+      ACCEPT_TOKEN(sym_keyword_delete);
+      END_STATE();
+    case 364:
+      if (lookahead == 'B' ||
+          lookahead == 'b') ADVANCE(411);
+      END_STATE();
+    case 365:
+      if (lookahead == 'C' ||
+          lookahead == 'c') ADVANCE(412);
+      END_STATE();
+    case 366:
+      ACCEPT_TOKEN(aux_sym_native_type_token7);
+      END_STATE();
+    case 367:
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(413);
+      END_STATE();
+    case 368:
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(414);
+      END_STATE();
+    case 369:
+      ACCEPT_TOKEN(sym_keyword_exists);
+      END_STATE();
+    case 370:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(415);
+      END_STATE();
+    case 371:
+      if (lookahead == 'U' ||
+          lookahead == 'u') ADVANCE(416);
+      END_STATE();
+    case 372:
+      ACCEPT_TOKEN(sym_keyword_frozen);
+      END_STATE();
+    case 373:
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(417);
+      END_STATE();
+    case 374:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(418);
+      END_STATE();
+    case 375:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(419);
+      END_STATE();
+    case 376:
+      ACCEPT_TOKEN(sym_keyword_insert);
+      END_STATE();
+    case 377:
+      if (lookahead == 'C' ||
+          lookahead == 'c') ADVANCE(420);
+      END_STATE();
+    case 378:
+      if (lookahead == 'G' ||
+          lookahead == 'g') ADVANCE(421);
+      END_STATE();
+    case 379:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(422);
+      END_STATE();
+    case 380:
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(423);
+      END_STATE();
+    case 381:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(424);
+      END_STATE();
+    case 382:
+      if (lookahead == 'Y' ||
+          lookahead == 'y') ADVANCE(425);
+      END_STATE();
+    case 383:
+      ACCEPT_TOKEN(sym_keyword_rename);
+      END_STATE();
+    case 384:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(426);
+      END_STATE();
+    case 385:
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(427);
+      END_STATE();
+    case 386:
+      ACCEPT_TOKEN(sym_keyword_schema);
+      END_STATE();
+    case 387:
+      ACCEPT_TOKEN(sym_keyword_select);
+      END_STATE();
+    case 388:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(428);
+      END_STATE();
+    case 389:
+      ACCEPT_TOKEN(sym_keyword_static);
+      END_STATE();
+    case 390:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(429);
+      END_STATE();
+    case 391:
+      ACCEPT_TOKEN(sym_keyword_tables);
+      END_STATE();
+    case 392:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(430);
+      END_STATE();
+    case 393:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(431);
+      END_STATE();
+    case 394:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(432);
+      END_STATE();
+    case 395:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(433);
+      END_STATE();
+    case 396:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(434);
+      END_STATE();
+    case 397:
+      ACCEPT_TOKEN(sym_keyword_update);
+      END_STATE();
+    case 398:
+      ACCEPT_TOKEN(sym_keyword_values);
+      END_STATE();
+    case 399:
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(435);
+      END_STATE();
+    case 400:
+      ACCEPT_TOKEN(aux_sym_native_type_token19);
+      END_STATE();
+    case 401:
+      ACCEPT_TOKEN(sym_keyword_vector);
+      END_STATE();
+    case 402:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(436);
+      END_STATE();
+    case 403:
+      ACCEPT_TOKEN(aux_sym_native_type_token4);
+      END_STATE();
+    case 404:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(437);
+      END_STATE();
+    case 405:
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(438);
+      END_STATE();
+    case 406:
+      ACCEPT_TOKEN(sym_keyword_compact);
+      END_STATE();
+    case 407:
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(439);
+      END_STATE();
+    case 408:
+      ACCEPT_TOKEN(sym_keyword_counter);
+      END_STATE();
+    case 409:
+      ACCEPT_TOKEN(aux_sym_native_type_token6);
+      END_STATE();
+    case 410:
+      ACCEPT_TOKEN(sym_keyword_default);
+      END_STATE();
+    case 411:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(440);
+      END_STATE();
+    case 412:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(441);
+      END_STATE();
+    case 413:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(442);
+      END_STATE();
+    case 414:
+      ACCEPT_TOKEN(sym_keyword_entries);
+      END_STATE();
+    case 415:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(443);
+      END_STATE();
+    case 416:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(444);
+      END_STATE();
+    case 417:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(445);
+      END_STATE();
+    case 418:
+      if (lookahead == 'Y' ||
+          lookahead == 'y') ADVANCE(446);
+      END_STATE();
+    case 419:
+      if (lookahead == 'D' ||
+          lookahead == 'd') ADVANCE(447);
+      END_STATE();
+    case 420:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(448);
+      END_STATE();
+    case 421:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(449);
+      END_STATE();
+    case 422:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(450);
+      END_STATE();
+    case 423:
+      ACCEPT_TOKEN(sym_keyword_options);
+      END_STATE();
+    case 424:
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(451);
+      END_STATE();
+    case 425:
+      ACCEPT_TOKEN(sym_keyword_primary);
+      END_STATE();
+    case 426:
+      ACCEPT_TOKEN(sym_keyword_replace);
+      END_STATE();
+    case 427:
+      ACCEPT_TOKEN(sym_keyword_returns);
+      END_STATE();
+    case 428:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(452);
+      END_STATE();
+    case 429:
+      ACCEPT_TOKEN(sym_keyword_storage);
+      END_STATE();
+    case 430:
+      if (lookahead == 'M' ||
+          lookahead == 'm') ADVANCE(453);
+      END_STATE();
+    case 431:
+      if (lookahead == 'D' ||
+          lookahead == 'd') ADVANCE(454);
+      END_STATE();
+    case 432:
+      ACCEPT_TOKEN(aux_sym_native_type_token16);
+      END_STATE();
+    case 433:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(455);
+      END_STATE();
+    case 434:
+      if (lookahead == 'D' ||
+          lookahead == 'd') ADVANCE(456);
+      END_STATE();
+    case 435:
+      ACCEPT_TOKEN(aux_sym_native_type_token18);
+      END_STATE();
+    case 436:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(457);
+      END_STATE();
+    case 437:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(458);
+      END_STATE();
+    case 438:
+      if (lookahead == 'M' ||
+          lookahead == 'm') ADVANCE(459);
+      END_STATE();
+    case 439:
+      ACCEPT_TOKEN(sym_keyword_contains);
+      END_STATE();
+    case 440:
+      ACCEPT_TOKEN(sym_keyword_describe);
+      END_STATE();
+    case 441:
+      ACCEPT_TOKEN(sym_keyword_distinct);
+      END_STATE();
+    case 442:
+      ACCEPT_TOKEN(aux_sym_native_type_token8);
+      END_STATE();
+    case 443:
+      if (lookahead == 'G' ||
+          lookahead == 'g') ADVANCE(460);
+      END_STATE();
+    case 444:
+      if (lookahead == 'C' ||
+          lookahead == 'c') ADVANCE(461);
+      END_STATE();
+    case 445:
+      ACCEPT_TOKEN(sym_keyword_function);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(462);
+      END_STATE();
+    case 446:
+      ACCEPT_TOKEN(aux_sym_float_token4);
+      END_STATE();
+    case 447:
+      ACCEPT_TOKEN(sym_keyword_initcond);
+      END_STATE();
+    case 448:
+      ACCEPT_TOKEN(sym_keyword_keyspace);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(463);
+      END_STATE();
+    case 449:
+      ACCEPT_TOKEN(sym_keyword_language);
+      END_STATE();
+    case 450:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(464);
+      END_STATE();
+    case 451:
+      if (lookahead == 'N' ||
+          lookahead == 'n') ADVANCE(465);
+      END_STATE();
+    case 452:
+      ACCEPT_TOKEN(aux_sym_native_type_token12);
+      END_STATE();
+    case 453:
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(466);
+      END_STATE();
+    case 454:
+      ACCEPT_TOKEN(aux_sym_native_type_token15);
+      END_STATE();
+    case 455:
+      ACCEPT_TOKEN(sym_keyword_truncate);
+      END_STATE();
+    case 456:
+      ACCEPT_TOKEN(sym_keyword_unlogged);
+      END_STATE();
+    case 457:
+      ACCEPT_TOKEN(sym_keyword_aggregate);
+      END_STATE();
+    case 458:
+      if (lookahead == 'G' ||
+          lookahead == 'g') ADVANCE(467);
+      END_STATE();
+    case 459:
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(468);
+      END_STATE();
+    case 460:
+      ACCEPT_TOKEN(sym_keyword_filtering);
+      END_STATE();
+    case 461:
+      ACCEPT_TOKEN(sym_keyword_finalfunc);
+      END_STATE();
+    case 462:
+      ACCEPT_TOKEN(sym_keyword_functions);
+      END_STATE();
+    case 463:
+      ACCEPT_TOKEN(sym_keyword_keyspaces);
+      END_STATE();
+    case 464:
+      if (lookahead == 'Z' ||
+          lookahead == 'z') ADVANCE(469);
+      END_STATE();
+    case 465:
+      ACCEPT_TOKEN(sym_keyword_partition);
+      END_STATE();
+    case 466:
+      ACCEPT_TOKEN(sym_keyword_timestamp);
+      END_STATE();
+    case 467:
+      ACCEPT_TOKEN(sym_keyword_clustering);
+      END_STATE();
+    case 468:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(470);
+      END_STATE();
+    case 469:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(471);
+      END_STATE();
+    case 470:
+      if (lookahead == 'Y' ||
+          lookahead == 'y') ADVANCE(472);
+      END_STATE();
+    case 471:
+      if (lookahead == 'D' ||
+          lookahead == 'd') ADVANCE(473);
+      END_STATE();
+    case 472:
+      ACCEPT_TOKEN(sym_keyword_columnfamily);
+      END_STATE();
+    case 473:
+      ACCEPT_TOKEN(sym_keyword_materialized);
+      END_STATE();
+    default:
+      return false;
+  }
+}
+
+static const TSLexerMode ts_lex_modes[STATE_COUNT] = {
+  [0] = {.lex_state = 0},
+  [1] = {.lex_state = 72},
+  [2] = {.lex_state = 2},
+  [3] = {.lex_state = 2},
+  [4] = {.lex_state = 2},
+  [5] = {.lex_state = 72},
+  [6] = {.lex_state = 72},
+  [7] = {.lex_state = 2},
+  [8] = {.lex_state = 2},
+  [9] = {.lex_state = 72},
+  [10] = {.lex_state = 72},
+  [11] = {.lex_state = 2},
+  [12] = {.lex_state = 2},
+  [13] = {.lex_state = 72},
+  [14] = {.lex_state = 72},
+  [15] = {.lex_state = 72},
+  [16] = {.lex_state = 72},
+  [17] = {.lex_state = 72},
+  [18] = {.lex_state = 72},
+  [19] = {.lex_state = 72},
+  [20] = {.lex_state = 2},
+  [21] = {.lex_state = 72},
+  [22] = {.lex_state = 2},
+  [23] = {.lex_state = 72},
+  [24] = {.lex_state = 72},
+  [25] = {.lex_state = 72},
+  [26] = {.lex_state = 2},
+  [27] = {.lex_state = 72},
+  [28] = {.lex_state = 72},
+  [29] = {.lex_state = 72},
+  [30] = {.lex_state = 72},
+  [31] = {.lex_state = 72},
+  [32] = {.lex_state = 72},
+  [33] = {.lex_state = 72},
+  [34] = {.lex_state = 72},
+  [35] = {.lex_state = 72},
+  [36] = {.lex_state = 72},
+  [37] = {.lex_state = 72},
+  [38] = {.lex_state = 72},
+  [39] = {.lex_state = 72},
+  [40] = {.lex_state = 72},
+  [41] = {.lex_state = 72},
+  [42] = {.lex_state = 72},
+  [43] = {.lex_state = 72},
+  [44] = {.lex_state = 72},
+  [45] = {.lex_state = 72},
+  [46] = {.lex_state = 72},
+  [47] = {.lex_state = 72},
+  [48] = {.lex_state = 72},
+  [49] = {.lex_state = 72},
+  [50] = {.lex_state = 72},
+  [51] = {.lex_state = 72},
+  [52] = {.lex_state = 72},
+  [53] = {.lex_state = 72},
+  [54] = {.lex_state = 72},
+  [55] = {.lex_state = 72},
+  [56] = {.lex_state = 72},
+  [57] = {.lex_state = 72},
+  [58] = {.lex_state = 72},
+  [59] = {.lex_state = 72},
+  [60] = {.lex_state = 72},
+  [61] = {.lex_state = 2},
+  [62] = {.lex_state = 2},
+  [63] = {.lex_state = 2},
+  [64] = {.lex_state = 2},
+  [65] = {.lex_state = 2},
+  [66] = {.lex_state = 2},
+  [67] = {.lex_state = 72},
+  [68] = {.lex_state = 2},
+  [69] = {.lex_state = 2},
+  [70] = {.lex_state = 2},
+  [71] = {.lex_state = 2},
+  [72] = {.lex_state = 2},
+  [73] = {.lex_state = 73},
+  [74] = {.lex_state = 2},
+  [75] = {.lex_state = 72},
+  [76] = {.lex_state = 73},
+  [77] = {.lex_state = 2},
+  [78] = {.lex_state = 2},
+  [79] = {.lex_state = 2},
+  [80] = {.lex_state = 2},
+  [81] = {.lex_state = 2},
+  [82] = {.lex_state = 2},
+  [83] = {.lex_state = 2},
+  [84] = {.lex_state = 2},
+  [85] = {.lex_state = 2},
+  [86] = {.lex_state = 2},
+  [87] = {.lex_state = 2},
+  [88] = {.lex_state = 2},
+  [89] = {.lex_state = 2},
+  [90] = {.lex_state = 2},
+  [91] = {.lex_state = 2},
+  [92] = {.lex_state = 2},
+  [93] = {.lex_state = 2},
+  [94] = {.lex_state = 2},
+  [95] = {.lex_state = 2},
+  [96] = {.lex_state = 2},
+  [97] = {.lex_state = 2},
+  [98] = {.lex_state = 2},
+  [99] = {.lex_state = 2},
+  [100] = {.lex_state = 2},
+  [101] = {.lex_state = 2},
+  [102] = {.lex_state = 2},
+  [103] = {.lex_state = 2},
+  [104] = {.lex_state = 2},
+  [105] = {.lex_state = 2},
+  [106] = {.lex_state = 2},
+  [107] = {.lex_state = 2},
+  [108] = {.lex_state = 2},
+  [109] = {.lex_state = 2},
+  [110] = {.lex_state = 2},
+  [111] = {.lex_state = 73},
+  [112] = {.lex_state = 2},
+  [113] = {.lex_state = 2},
+  [114] = {.lex_state = 2},
+  [115] = {.lex_state = 73},
+  [116] = {.lex_state = 73},
+  [117] = {.lex_state = 73},
+  [118] = {.lex_state = 73},
+  [119] = {.lex_state = 73},
+  [120] = {.lex_state = 73},
+  [121] = {.lex_state = 73},
+  [122] = {.lex_state = 73},
+  [123] = {.lex_state = 73},
+  [124] = {.lex_state = 73},
+  [125] = {.lex_state = 73},
+  [126] = {.lex_state = 73},
+  [127] = {.lex_state = 73},
+  [128] = {.lex_state = 73},
+  [129] = {.lex_state = 73},
+  [130] = {.lex_state = 73},
+  [131] = {.lex_state = 73},
+  [132] = {.lex_state = 72},
+  [133] = {.lex_state = 72},
+  [134] = {.lex_state = 72},
+  [135] = {.lex_state = 72},
+  [136] = {.lex_state = 72},
+  [137] = {.lex_state = 72},
+  [138] = {.lex_state = 72},
+  [139] = {.lex_state = 72},
+  [140] = {.lex_state = 72},
+  [141] = {.lex_state = 72},
+  [142] = {.lex_state = 72},
+  [143] = {.lex_state = 72},
+  [144] = {.lex_state = 72},
+  [145] = {.lex_state = 72},
+  [146] = {.lex_state = 72},
+  [147] = {.lex_state = 72},
+  [148] = {.lex_state = 72},
+  [149] = {.lex_state = 72},
+  [150] = {.lex_state = 72},
+  [151] = {.lex_state = 72},
+  [152] = {.lex_state = 72},
+  [153] = {.lex_state = 72},
+  [154] = {.lex_state = 72},
+  [155] = {.lex_state = 72},
+  [156] = {.lex_state = 2},
+  [157] = {.lex_state = 72},
+  [158] = {.lex_state = 72},
+  [159] = {.lex_state = 72},
+  [160] = {.lex_state = 72},
+  [161] = {.lex_state = 72},
+  [162] = {.lex_state = 72},
+  [163] = {.lex_state = 72},
+  [164] = {.lex_state = 72},
+  [165] = {.lex_state = 72},
+  [166] = {.lex_state = 72},
+  [167] = {.lex_state = 72},
+  [168] = {.lex_state = 72},
+  [169] = {.lex_state = 72},
+  [170] = {.lex_state = 72},
+  [171] = {.lex_state = 72},
+  [172] = {.lex_state = 72},
+  [173] = {.lex_state = 72},
+  [174] = {.lex_state = 72},
+  [175] = {.lex_state = 72},
+  [176] = {.lex_state = 72},
+  [177] = {.lex_state = 72},
+  [178] = {.lex_state = 72},
+  [179] = {.lex_state = 72},
+  [180] = {.lex_state = 72},
+  [181] = {.lex_state = 72},
+  [182] = {.lex_state = 72},
+  [183] = {.lex_state = 72},
+  [184] = {.lex_state = 72},
+  [185] = {.lex_state = 72},
+  [186] = {.lex_state = 72},
+  [187] = {.lex_state = 72},
+  [188] = {.lex_state = 72},
+  [189] = {.lex_state = 72},
+  [190] = {.lex_state = 72},
+  [191] = {.lex_state = 72},
+  [192] = {.lex_state = 72},
+  [193] = {.lex_state = 72},
+  [194] = {.lex_state = 72},
+  [195] = {.lex_state = 72},
+  [196] = {.lex_state = 72},
+  [197] = {.lex_state = 72},
+  [198] = {.lex_state = 72},
+  [199] = {.lex_state = 72},
+  [200] = {.lex_state = 72},
+  [201] = {.lex_state = 72},
+  [202] = {.lex_state = 72},
+  [203] = {.lex_state = 72},
+  [204] = {.lex_state = 72},
+  [205] = {.lex_state = 72},
+  [206] = {.lex_state = 72},
+  [207] = {.lex_state = 72},
+  [208] = {.lex_state = 72},
+  [209] = {.lex_state = 72},
+  [210] = {.lex_state = 72},
+  [211] = {.lex_state = 72},
+  [212] = {.lex_state = 72},
+  [213] = {.lex_state = 72},
+  [214] = {.lex_state = 72},
+  [215] = {.lex_state = 72},
+  [216] = {.lex_state = 72},
+  [217] = {.lex_state = 72},
+  [218] = {.lex_state = 72},
+  [219] = {.lex_state = 72},
+  [220] = {.lex_state = 72},
+  [221] = {.lex_state = 72},
+  [222] = {.lex_state = 72},
+  [223] = {.lex_state = 72},
+  [224] = {.lex_state = 72},
+  [225] = {.lex_state = 72},
+  [226] = {.lex_state = 72},
+  [227] = {.lex_state = 72},
+  [228] = {.lex_state = 72},
+  [229] = {.lex_state = 72},
+  [230] = {.lex_state = 72},
+  [231] = {.lex_state = 72},
+  [232] = {.lex_state = 72},
+  [233] = {.lex_state = 72},
+  [234] = {.lex_state = 72},
+  [235] = {.lex_state = 72},
+  [236] = {.lex_state = 72},
+  [237] = {.lex_state = 72},
+  [238] = {.lex_state = 72},
+  [239] = {.lex_state = 72},
+  [240] = {.lex_state = 72},
+  [241] = {.lex_state = 72},
+  [242] = {.lex_state = 72},
+  [243] = {.lex_state = 72},
+  [244] = {.lex_state = 72},
+  [245] = {.lex_state = 72},
+  [246] = {.lex_state = 72},
+  [247] = {.lex_state = 72},
+  [248] = {.lex_state = 72},
+  [249] = {.lex_state = 72},
+  [250] = {.lex_state = 72},
+  [251] = {.lex_state = 72},
+  [252] = {.lex_state = 72},
+  [253] = {.lex_state = 72},
+  [254] = {.lex_state = 72},
+  [255] = {.lex_state = 72},
+  [256] = {.lex_state = 72},
+  [257] = {.lex_state = 72},
+  [258] = {.lex_state = 72},
+  [259] = {.lex_state = 72},
+  [260] = {.lex_state = 72},
+  [261] = {.lex_state = 72},
+  [262] = {.lex_state = 72},
+  [263] = {.lex_state = 72},
+  [264] = {.lex_state = 72},
+  [265] = {.lex_state = 72},
+  [266] = {.lex_state = 72},
+  [267] = {.lex_state = 72},
+  [268] = {.lex_state = 72},
+  [269] = {.lex_state = 72},
+  [270] = {.lex_state = 72},
+  [271] = {.lex_state = 72},
+  [272] = {.lex_state = 72},
+  [273] = {.lex_state = 72},
+  [274] = {.lex_state = 72},
+  [275] = {.lex_state = 72},
+  [276] = {.lex_state = 72},
+  [277] = {.lex_state = 72},
+  [278] = {.lex_state = 72},
+  [279] = {.lex_state = 72},
+  [280] = {.lex_state = 72},
+  [281] = {.lex_state = 72},
+  [282] = {.lex_state = 72},
+  [283] = {.lex_state = 72},
+  [284] = {.lex_state = 72},
+  [285] = {.lex_state = 72},
+  [286] = {.lex_state = 72},
+  [287] = {.lex_state = 72},
+  [288] = {.lex_state = 72},
+  [289] = {.lex_state = 1},
+  [290] = {.lex_state = 72},
+  [291] = {.lex_state = 72},
+  [292] = {.lex_state = 72},
+  [293] = {.lex_state = 72},
+  [294] = {.lex_state = 72},
+  [295] = {.lex_state = 72},
+  [296] = {.lex_state = 72},
+  [297] = {.lex_state = 72},
+  [298] = {.lex_state = 72},
+  [299] = {.lex_state = 72},
+  [300] = {.lex_state = 72},
+  [301] = {.lex_state = 72},
+  [302] = {.lex_state = 72},
+  [303] = {.lex_state = 72},
+  [304] = {.lex_state = 72},
+  [305] = {.lex_state = 72},
+  [306] = {.lex_state = 72},
+  [307] = {.lex_state = 72},
+  [308] = {.lex_state = 72},
+  [309] = {.lex_state = 72},
+  [310] = {.lex_state = 72},
+  [311] = {.lex_state = 72},
+  [312] = {.lex_state = 72},
+  [313] = {.lex_state = 72},
+  [314] = {.lex_state = 72},
+  [315] = {.lex_state = 72},
+  [316] = {.lex_state = 72},
+  [317] = {.lex_state = 72},
+  [318] = {.lex_state = 72},
+  [319] = {.lex_state = 72},
+  [320] = {.lex_state = 72},
+  [321] = {.lex_state = 72},
+  [322] = {.lex_state = 72},
+  [323] = {.lex_state = 72},
+  [324] = {.lex_state = 72},
+  [325] = {.lex_state = 72},
+  [326] = {.lex_state = 72},
+  [327] = {.lex_state = 72},
+  [328] = {.lex_state = 72},
+  [329] = {.lex_state = 72},
+  [330] = {.lex_state = 72},
+  [331] = {.lex_state = 72},
+  [332] = {.lex_state = 72},
+  [333] = {.lex_state = 72},
+  [334] = {.lex_state = 72},
+  [335] = {.lex_state = 72},
+  [336] = {.lex_state = 72},
+  [337] = {.lex_state = 72},
+  [338] = {.lex_state = 72},
+  [339] = {.lex_state = 72},
+  [340] = {.lex_state = 72},
+  [341] = {.lex_state = 72},
+  [342] = {.lex_state = 72},
+  [343] = {.lex_state = 72},
+  [344] = {.lex_state = 72},
+  [345] = {.lex_state = 72},
+  [346] = {.lex_state = 72},
+  [347] = {.lex_state = 72},
+  [348] = {.lex_state = 72},
+  [349] = {.lex_state = 72},
+  [350] = {.lex_state = 72},
+  [351] = {.lex_state = 72},
+  [352] = {.lex_state = 72},
+  [353] = {.lex_state = 72},
+  [354] = {.lex_state = 72},
+  [355] = {.lex_state = 72},
+  [356] = {.lex_state = 72},
+  [357] = {.lex_state = 72},
+  [358] = {.lex_state = 72},
+  [359] = {.lex_state = 72},
+  [360] = {.lex_state = 72},
+  [361] = {.lex_state = 72},
+  [362] = {.lex_state = 72},
+  [363] = {.lex_state = 72},
+  [364] = {.lex_state = 72},
+  [365] = {.lex_state = 72},
+  [366] = {.lex_state = 72},
+  [367] = {.lex_state = 72},
+  [368] = {.lex_state = 72},
+  [369] = {.lex_state = 72},
+  [370] = {.lex_state = 72},
+  [371] = {.lex_state = 72},
+  [372] = {.lex_state = 72},
+  [373] = {.lex_state = 72},
+  [374] = {.lex_state = 72},
+  [375] = {.lex_state = 72},
+  [376] = {.lex_state = 72},
+  [377] = {.lex_state = 72},
+  [378] = {.lex_state = 72},
+  [379] = {.lex_state = 72},
+  [380] = {.lex_state = 72},
+  [381] = {.lex_state = 72},
+  [382] = {.lex_state = 72},
+  [383] = {.lex_state = 72},
+  [384] = {.lex_state = 72},
+  [385] = {.lex_state = 72},
+  [386] = {.lex_state = 72},
+  [387] = {.lex_state = 72},
+  [388] = {.lex_state = 72},
+  [389] = {.lex_state = 72},
+  [390] = {.lex_state = 72},
+  [391] = {.lex_state = 72},
+  [392] = {.lex_state = 72},
+  [393] = {.lex_state = 72},
+  [394] = {.lex_state = 72},
+  [395] = {.lex_state = 72},
+  [396] = {.lex_state = 72},
+  [397] = {.lex_state = 72},
+  [398] = {.lex_state = 72},
+  [399] = {.lex_state = 72},
+  [400] = {.lex_state = 72},
+  [401] = {.lex_state = 72},
+  [402] = {.lex_state = 72},
+  [403] = {.lex_state = 72},
+  [404] = {.lex_state = 72},
+  [405] = {.lex_state = 72},
+  [406] = {.lex_state = 72},
+  [407] = {.lex_state = 72},
+  [408] = {.lex_state = 72},
+  [409] = {.lex_state = 72},
+  [410] = {.lex_state = 72},
+  [411] = {.lex_state = 72},
+  [412] = {.lex_state = 72},
+  [413] = {.lex_state = 72},
+  [414] = {.lex_state = 72},
+  [415] = {.lex_state = 72},
+  [416] = {.lex_state = 72},
+  [417] = {.lex_state = 72},
+  [418] = {.lex_state = 72},
+  [419] = {.lex_state = 72},
+  [420] = {.lex_state = 72},
+  [421] = {.lex_state = 72},
+  [422] = {.lex_state = 72},
+  [423] = {.lex_state = 72},
+  [424] = {.lex_state = 72},
+  [425] = {.lex_state = 72},
+  [426] = {.lex_state = 72},
+  [427] = {.lex_state = 72},
+  [428] = {.lex_state = 72},
+  [429] = {.lex_state = 72},
+  [430] = {.lex_state = 72},
+  [431] = {.lex_state = 72},
+  [432] = {.lex_state = 72},
+  [433] = {.lex_state = 72},
+  [434] = {.lex_state = 72},
+  [435] = {.lex_state = 72},
+  [436] = {.lex_state = 72},
+  [437] = {.lex_state = 72},
+  [438] = {.lex_state = 72},
+  [439] = {.lex_state = 72},
+  [440] = {.lex_state = 72},
+  [441] = {.lex_state = 72},
+  [442] = {.lex_state = 72},
+  [443] = {.lex_state = 72},
+  [444] = {.lex_state = 72},
+  [445] = {.lex_state = 72},
+  [446] = {.lex_state = 72},
+  [447] = {.lex_state = 72},
+  [448] = {.lex_state = 72},
+  [449] = {.lex_state = 72},
+  [450] = {.lex_state = 72},
+  [451] = {.lex_state = 72},
+  [452] = {.lex_state = 72},
+  [453] = {.lex_state = 72},
+  [454] = {.lex_state = 72},
+  [455] = {.lex_state = 72},
+  [456] = {.lex_state = 72},
+  [457] = {.lex_state = 72},
+  [458] = {.lex_state = 72},
+  [459] = {.lex_state = 72},
+  [460] = {.lex_state = 72},
+  [461] = {.lex_state = 72},
+  [462] = {.lex_state = 72},
+  [463] = {.lex_state = 72},
+  [464] = {.lex_state = 72},
+  [465] = {.lex_state = 72},
+  [466] = {.lex_state = 72},
+  [467] = {.lex_state = 72},
+  [468] = {.lex_state = 72},
+  [469] = {.lex_state = 72},
+  [470] = {.lex_state = 72},
+  [471] = {.lex_state = 72},
+  [472] = {.lex_state = 72},
+  [473] = {.lex_state = 72},
+  [474] = {.lex_state = 72},
+  [475] = {.lex_state = 72},
+  [476] = {.lex_state = 72},
+  [477] = {.lex_state = 72},
+  [478] = {.lex_state = 72},
+  [479] = {.lex_state = 72},
+  [480] = {.lex_state = 72},
+  [481] = {.lex_state = 72},
+  [482] = {.lex_state = 72},
+  [483] = {.lex_state = 72},
+  [484] = {.lex_state = 72},
+  [485] = {.lex_state = 1},
+  [486] = {.lex_state = 72},
+  [487] = {.lex_state = 72},
+  [488] = {.lex_state = 72},
+  [489] = {.lex_state = 72},
+  [490] = {.lex_state = 72},
+  [491] = {.lex_state = 72},
+  [492] = {.lex_state = 72},
+  [493] = {.lex_state = 72},
+  [494] = {.lex_state = 72},
+  [495] = {.lex_state = 72},
+  [496] = {.lex_state = 72},
+  [497] = {.lex_state = 72},
+  [498] = {.lex_state = 72},
+  [499] = {.lex_state = 72},
+  [500] = {.lex_state = 72},
+  [501] = {.lex_state = 72},
+  [502] = {.lex_state = 72},
+  [503] = {.lex_state = 72},
+  [504] = {.lex_state = 72},
+  [505] = {.lex_state = 73},
+  [506] = {.lex_state = 72},
+  [507] = {.lex_state = 72},
+  [508] = {.lex_state = 72},
+  [509] = {.lex_state = 72},
+  [510] = {.lex_state = 72},
+  [511] = {.lex_state = 72},
+  [512] = {.lex_state = 72},
+  [513] = {.lex_state = 72},
+  [514] = {.lex_state = 72},
+  [515] = {.lex_state = 72},
+  [516] = {.lex_state = 72},
+  [517] = {.lex_state = 72},
+  [518] = {.lex_state = 72},
+  [519] = {.lex_state = 73},
+  [520] = {.lex_state = 73},
+  [521] = {.lex_state = 72},
+  [522] = {.lex_state = 72},
+  [523] = {.lex_state = 72},
+  [524] = {.lex_state = 72},
+  [525] = {.lex_state = 72},
+  [526] = {.lex_state = 72},
+  [527] = {.lex_state = 72},
+  [528] = {.lex_state = 72},
+  [529] = {.lex_state = 72},
+  [530] = {.lex_state = 72},
+  [531] = {.lex_state = 72},
+  [532] = {.lex_state = 72},
+  [533] = {.lex_state = 72},
+  [534] = {.lex_state = 72},
+  [535] = {.lex_state = 72},
+  [536] = {.lex_state = 72},
+  [537] = {.lex_state = 72},
+  [538] = {.lex_state = 72},
+  [539] = {.lex_state = 72},
+  [540] = {.lex_state = 72},
+  [541] = {.lex_state = 72},
+  [542] = {.lex_state = 72},
+  [543] = {.lex_state = 72},
+  [544] = {.lex_state = 72},
+  [545] = {.lex_state = 72},
+  [546] = {.lex_state = 72},
+  [547] = {.lex_state = 72},
+  [548] = {.lex_state = 72},
+  [549] = {.lex_state = 72},
+  [550] = {.lex_state = 72},
+  [551] = {.lex_state = 72},
+  [552] = {.lex_state = 72},
+  [553] = {.lex_state = 72},
+  [554] = {.lex_state = 72},
+  [555] = {.lex_state = 72},
+  [556] = {.lex_state = 72},
+  [557] = {.lex_state = 72},
+  [558] = {.lex_state = 72},
+  [559] = {.lex_state = 72},
+  [560] = {.lex_state = 72},
+  [561] = {.lex_state = 72},
+  [562] = {.lex_state = 72},
+  [563] = {.lex_state = 72},
+  [564] = {.lex_state = 72},
+  [565] = {.lex_state = 72},
+  [566] = {.lex_state = 72},
+  [567] = {.lex_state = 72},
+  [568] = {.lex_state = 72},
+  [569] = {.lex_state = 72},
+  [570] = {.lex_state = 72},
+  [571] = {.lex_state = 72},
+  [572] = {.lex_state = 72},
+  [573] = {.lex_state = 72},
+  [574] = {.lex_state = 72},
+  [575] = {.lex_state = 72},
+  [576] = {.lex_state = 72},
+  [577] = {.lex_state = 72},
+  [578] = {.lex_state = 72},
+  [579] = {.lex_state = 72},
+  [580] = {.lex_state = 72},
+  [581] = {.lex_state = 72},
+  [582] = {.lex_state = 72},
+  [583] = {.lex_state = 72},
+  [584] = {.lex_state = 72},
+  [585] = {.lex_state = 72},
+  [586] = {.lex_state = 72},
+  [587] = {.lex_state = 72},
+  [588] = {.lex_state = 72},
+  [589] = {.lex_state = 72},
+  [590] = {.lex_state = 72},
+  [591] = {.lex_state = 72},
+  [592] = {.lex_state = 72},
+  [593] = {.lex_state = 72},
+  [594] = {.lex_state = 72},
+  [595] = {.lex_state = 72},
+  [596] = {.lex_state = 72},
+  [597] = {.lex_state = 72},
+  [598] = {.lex_state = 72},
+  [599] = {.lex_state = 72},
+  [600] = {.lex_state = 72},
+  [601] = {.lex_state = 72},
+  [602] = {.lex_state = 72},
+  [603] = {.lex_state = 72},
+  [604] = {.lex_state = 72},
+  [605] = {.lex_state = 72},
+  [606] = {.lex_state = 72},
+  [607] = {.lex_state = 72},
+  [608] = {.lex_state = 72},
+  [609] = {.lex_state = 72},
+  [610] = {.lex_state = 72},
+  [611] = {.lex_state = 72},
+  [612] = {.lex_state = 72},
+  [613] = {.lex_state = 72},
+  [614] = {.lex_state = 72},
+  [615] = {.lex_state = 72},
+  [616] = {.lex_state = 72},
+  [617] = {.lex_state = 0},
+  [618] = {.lex_state = 72},
+  [619] = {.lex_state = 0},
+  [620] = {.lex_state = 72},
+  [621] = {.lex_state = 72},
+  [622] = {.lex_state = 72},
+  [623] = {.lex_state = 72},
+  [624] = {.lex_state = 72},
+  [625] = {.lex_state = 1},
+  [626] = {.lex_state = 72},
+  [627] = {.lex_state = 72},
+  [628] = {.lex_state = 72},
+  [629] = {.lex_state = 72},
+  [630] = {.lex_state = 1},
+  [631] = {.lex_state = 72},
+  [632] = {.lex_state = 72},
+  [633] = {.lex_state = 72},
+  [634] = {.lex_state = 72},
+  [635] = {.lex_state = 72},
+  [636] = {.lex_state = 72},
+  [637] = {.lex_state = 72},
+  [638] = {.lex_state = 72},
+  [639] = {.lex_state = 72},
+  [640] = {.lex_state = 72},
+  [641] = {.lex_state = 72},
+  [642] = {.lex_state = 72},
+  [643] = {.lex_state = 72},
+  [644] = {.lex_state = 1},
+  [645] = {.lex_state = 72},
+  [646] = {.lex_state = 72},
+  [647] = {.lex_state = 72},
+  [648] = {.lex_state = 72},
+  [649] = {.lex_state = 72},
+  [650] = {.lex_state = 72},
+  [651] = {.lex_state = 72},
+  [652] = {.lex_state = 72},
+  [653] = {.lex_state = 72},
+  [654] = {.lex_state = 72},
+  [655] = {.lex_state = 72},
+  [656] = {.lex_state = 72},
+  [657] = {.lex_state = 72},
+  [658] = {.lex_state = 72},
+  [659] = {.lex_state = 72},
+  [660] = {.lex_state = 72},
+  [661] = {.lex_state = 72},
+  [662] = {.lex_state = 72},
+  [663] = {.lex_state = 72},
+  [664] = {.lex_state = 72},
+  [665] = {.lex_state = 72},
+  [666] = {.lex_state = 72},
+  [667] = {.lex_state = 72},
+  [668] = {.lex_state = 72},
+  [669] = {.lex_state = 72},
+  [670] = {.lex_state = 72},
+  [671] = {.lex_state = 72},
+  [672] = {.lex_state = 72},
+  [673] = {.lex_state = 72},
+  [674] = {.lex_state = 72},
+  [675] = {.lex_state = 72},
+  [676] = {.lex_state = 72},
+  [677] = {.lex_state = 72},
+  [678] = {.lex_state = 72},
+  [679] = {.lex_state = 72},
+  [680] = {.lex_state = 72},
+  [681] = {.lex_state = 72},
+  [682] = {.lex_state = 72},
+  [683] = {.lex_state = 72},
+  [684] = {.lex_state = 72},
+  [685] = {.lex_state = 73},
+  [686] = {.lex_state = 72},
+  [687] = {.lex_state = 72},
+  [688] = {.lex_state = 72},
+  [689] = {.lex_state = 72},
+  [690] = {.lex_state = 72},
+  [691] = {.lex_state = 72},
+  [692] = {.lex_state = 72},
+  [693] = {.lex_state = 72},
+  [694] = {.lex_state = 72},
+  [695] = {.lex_state = 72},
+  [696] = {.lex_state = 72},
+  [697] = {.lex_state = 72},
+  [698] = {.lex_state = 72},
+  [699] = {.lex_state = 72},
+  [700] = {.lex_state = 72},
+  [701] = {.lex_state = 72},
+  [702] = {.lex_state = 0},
+  [703] = {.lex_state = 72},
+  [704] = {.lex_state = 72},
+  [705] = {.lex_state = 72},
+  [706] = {.lex_state = 72},
+  [707] = {.lex_state = 72},
+  [708] = {.lex_state = 72},
+  [709] = {.lex_state = 72},
+  [710] = {.lex_state = 72},
+  [711] = {.lex_state = 72},
+  [712] = {.lex_state = 0},
+  [713] = {.lex_state = 72},
+  [714] = {.lex_state = 72},
+  [715] = {.lex_state = 72},
+  [716] = {.lex_state = 72},
+  [717] = {.lex_state = 0},
+  [718] = {.lex_state = 72},
+  [719] = {.lex_state = 72},
+  [720] = {.lex_state = 72},
+  [721] = {.lex_state = 72},
+  [722] = {.lex_state = 72},
+  [723] = {.lex_state = 72},
+  [724] = {.lex_state = 72},
+  [725] = {.lex_state = 72},
+  [726] = {.lex_state = 72},
+  [727] = {.lex_state = 72},
+  [728] = {.lex_state = 72},
+  [729] = {.lex_state = 72},
+  [730] = {.lex_state = 0},
+  [731] = {.lex_state = 72},
+  [732] = {.lex_state = 72},
+  [733] = {.lex_state = 0},
+  [734] = {.lex_state = 0},
+  [735] = {.lex_state = 0},
+  [736] = {.lex_state = 0},
+  [737] = {.lex_state = 0},
+  [738] = {.lex_state = 0},
+  [739] = {.lex_state = 0},
+  [740] = {.lex_state = 0},
+  [741] = {.lex_state = 72},
+  [742] = {.lex_state = 72},
+  [743] = {.lex_state = 0},
+  [744] = {.lex_state = 0},
+  [745] = {.lex_state = 72},
+  [746] = {.lex_state = 0},
+  [747] = {.lex_state = 0},
+  [748] = {.lex_state = 72},
+  [749] = {.lex_state = 0},
+  [750] = {.lex_state = 0},
+  [751] = {.lex_state = 0},
+  [752] = {.lex_state = 0},
+  [753] = {.lex_state = 72},
+  [754] = {.lex_state = 0},
+  [755] = {.lex_state = 72},
+  [756] = {.lex_state = 0},
+  [757] = {.lex_state = 0},
+  [758] = {.lex_state = 0},
+  [759] = {.lex_state = 0},
+  [760] = {.lex_state = 0},
+  [761] = {.lex_state = 72},
+  [762] = {.lex_state = 0},
+  [763] = {.lex_state = 0},
+  [764] = {.lex_state = 0},
+  [765] = {.lex_state = 72},
+  [766] = {.lex_state = 0},
+  [767] = {.lex_state = 0},
+  [768] = {.lex_state = 0},
+  [769] = {.lex_state = 0},
+  [770] = {.lex_state = 72},
+  [771] = {.lex_state = 0},
+  [772] = {.lex_state = 0},
+  [773] = {.lex_state = 0},
+  [774] = {.lex_state = 0},
+  [775] = {.lex_state = 72},
+  [776] = {.lex_state = 72},
+  [777] = {.lex_state = 0},
+  [778] = {.lex_state = 0},
+  [779] = {.lex_state = 72},
+  [780] = {.lex_state = 0},
+  [781] = {.lex_state = 0},
+  [782] = {.lex_state = 0},
+  [783] = {.lex_state = 0},
+  [784] = {.lex_state = 0},
+  [785] = {.lex_state = 0},
+  [786] = {.lex_state = 0},
+  [787] = {.lex_state = 0},
+  [788] = {.lex_state = 0},
+  [789] = {.lex_state = 0},
+  [790] = {.lex_state = 0},
+  [791] = {.lex_state = 72},
+  [792] = {.lex_state = 0},
+  [793] = {.lex_state = 0},
+  [794] = {.lex_state = 72},
+  [795] = {.lex_state = 0},
+  [796] = {.lex_state = 1},
+  [797] = {.lex_state = 0},
+  [798] = {.lex_state = 0},
+  [799] = {.lex_state = 0},
+  [800] = {.lex_state = 72},
+  [801] = {.lex_state = 0},
+  [802] = {.lex_state = 0},
+  [803] = {.lex_state = 0},
+  [804] = {.lex_state = 0},
+  [805] = {.lex_state = 0},
+  [806] = {.lex_state = 0},
+  [807] = {.lex_state = 0},
+  [808] = {.lex_state = 0},
+  [809] = {.lex_state = 0},
+  [810] = {.lex_state = 0},
+  [811] = {.lex_state = 72},
+  [812] = {.lex_state = 72},
+  [813] = {.lex_state = 72},
+  [814] = {.lex_state = 0},
+  [815] = {.lex_state = 0},
+  [816] = {.lex_state = 0},
+  [817] = {.lex_state = 0},
+  [818] = {.lex_state = 0},
+  [819] = {.lex_state = 72},
+  [820] = {.lex_state = 0},
+  [821] = {.lex_state = 72},
+  [822] = {.lex_state = 0},
+  [823] = {.lex_state = 72},
+  [824] = {.lex_state = 0},
+  [825] = {.lex_state = 0},
+  [826] = {.lex_state = 0},
+  [827] = {.lex_state = 72},
+  [828] = {.lex_state = 0},
+  [829] = {.lex_state = 0},
+  [830] = {.lex_state = 72},
+  [831] = {.lex_state = 0},
+  [832] = {.lex_state = 0},
+  [833] = {.lex_state = 0},
+  [834] = {.lex_state = 0},
+  [835] = {.lex_state = 0},
+  [836] = {.lex_state = 0},
+  [837] = {.lex_state = 0},
+  [838] = {.lex_state = 0},
+  [839] = {.lex_state = 0},
+  [840] = {.lex_state = 0},
+  [841] = {.lex_state = 0},
+  [842] = {.lex_state = 0},
+  [843] = {.lex_state = 0},
+  [844] = {.lex_state = 0},
+  [845] = {.lex_state = 0},
+  [846] = {.lex_state = 0},
+  [847] = {.lex_state = 0},
+  [848] = {.lex_state = 0},
+  [849] = {.lex_state = 0},
+  [850] = {.lex_state = 0},
+  [851] = {.lex_state = 0},
+  [852] = {.lex_state = 72},
+  [853] = {.lex_state = 72},
+  [854] = {.lex_state = 72},
+  [855] = {.lex_state = 0},
+  [856] = {.lex_state = 72},
+  [857] = {.lex_state = 0},
+  [858] = {.lex_state = 72},
+  [859] = {.lex_state = 0},
+  [860] = {.lex_state = 0},
+  [861] = {.lex_state = 72},
+  [862] = {.lex_state = 72},
+  [863] = {.lex_state = 72},
+  [864] = {.lex_state = 0},
+  [865] = {.lex_state = 0},
+  [866] = {.lex_state = 72},
+  [867] = {.lex_state = 73},
+  [868] = {.lex_state = 0},
+  [869] = {.lex_state = 0},
+  [870] = {.lex_state = 0},
+  [871] = {.lex_state = 0},
+  [872] = {.lex_state = 0},
+  [873] = {.lex_state = 0},
+  [874] = {.lex_state = 0},
+  [875] = {.lex_state = 72},
+  [876] = {.lex_state = 0},
+  [877] = {.lex_state = 0},
+  [878] = {.lex_state = 0},
+  [879] = {.lex_state = 72},
+  [880] = {.lex_state = 0},
+  [881] = {.lex_state = 72},
+  [882] = {.lex_state = 72},
+  [883] = {.lex_state = 72},
+  [884] = {.lex_state = 0},
+  [885] = {.lex_state = 0},
+  [886] = {.lex_state = 72},
+  [887] = {.lex_state = 0},
+  [888] = {.lex_state = 0},
+  [889] = {.lex_state = 0},
+  [890] = {.lex_state = 0},
+  [891] = {.lex_state = 72},
+  [892] = {.lex_state = 0},
+  [893] = {.lex_state = 72},
+  [894] = {.lex_state = 0},
+  [895] = {.lex_state = 0},
+  [896] = {.lex_state = 72},
+  [897] = {.lex_state = 0},
+  [898] = {.lex_state = 72},
+  [899] = {.lex_state = 72},
+  [900] = {.lex_state = 0},
+  [901] = {.lex_state = 0},
+  [902] = {.lex_state = 72},
+  [903] = {.lex_state = 0},
+  [904] = {.lex_state = 72},
+  [905] = {.lex_state = 0},
+  [906] = {.lex_state = 72},
+  [907] = {.lex_state = 72},
+  [908] = {.lex_state = 0},
+  [909] = {.lex_state = 72},
+  [910] = {.lex_state = 72},
+  [911] = {.lex_state = 72},
+  [912] = {.lex_state = 72},
+  [913] = {.lex_state = 72},
+  [914] = {.lex_state = 72},
+  [915] = {.lex_state = 72},
+  [916] = {.lex_state = 72},
+  [917] = {.lex_state = 72},
+  [918] = {.lex_state = 72},
+  [919] = {.lex_state = 72},
+  [920] = {.lex_state = 72},
+  [921] = {.lex_state = 72},
+  [922] = {.lex_state = 72},
+  [923] = {.lex_state = 0},
+  [924] = {.lex_state = 0},
+  [925] = {.lex_state = 0},
+  [926] = {.lex_state = 0},
+  [927] = {.lex_state = 0},
+  [928] = {.lex_state = 72},
+  [929] = {.lex_state = 0},
+  [930] = {.lex_state = 0},
+  [931] = {.lex_state = 72},
+  [932] = {.lex_state = 72},
+  [933] = {.lex_state = 72},
+  [934] = {.lex_state = 72},
+  [935] = {.lex_state = 72},
+  [936] = {.lex_state = 72},
+  [937] = {.lex_state = 72},
+  [938] = {.lex_state = 0},
+  [939] = {.lex_state = 72},
+  [940] = {.lex_state = 72},
+  [941] = {.lex_state = 0},
+  [942] = {.lex_state = 72},
+  [943] = {.lex_state = 0},
+  [944] = {.lex_state = 72},
+  [945] = {.lex_state = 72},
+  [946] = {.lex_state = 0},
+  [947] = {.lex_state = 72},
+  [948] = {.lex_state = 72},
+  [949] = {.lex_state = 72},
+  [950] = {.lex_state = 72},
+  [951] = {.lex_state = 72},
+  [952] = {.lex_state = 0},
+  [953] = {.lex_state = 72},
+  [954] = {.lex_state = 72},
+  [955] = {.lex_state = 72},
+  [956] = {.lex_state = 0},
+  [957] = {.lex_state = 0},
+  [958] = {.lex_state = 0},
+  [959] = {.lex_state = 0},
+  [960] = {.lex_state = 72},
+  [961] = {.lex_state = 72},
+  [962] = {.lex_state = 72},
+  [963] = {.lex_state = 72},
+  [964] = {.lex_state = 0},
+  [965] = {.lex_state = 72},
+  [966] = {.lex_state = 72},
+  [967] = {.lex_state = 72},
+  [968] = {.lex_state = 72},
+  [969] = {.lex_state = 0},
+  [970] = {.lex_state = 72},
+  [971] = {.lex_state = 72},
+  [972] = {.lex_state = 72},
+  [973] = {.lex_state = 0},
+  [974] = {.lex_state = 72},
+  [975] = {.lex_state = 72},
+  [976] = {.lex_state = 0},
+  [977] = {.lex_state = 72},
+  [978] = {.lex_state = 0},
+  [979] = {.lex_state = 72},
+  [980] = {.lex_state = 0},
+  [981] = {.lex_state = 72},
+  [982] = {.lex_state = 0},
+  [983] = {.lex_state = 72},
+  [984] = {.lex_state = 72},
+  [985] = {.lex_state = 72},
+  [986] = {.lex_state = 72},
+  [987] = {.lex_state = 72},
+  [988] = {.lex_state = 72},
+  [989] = {.lex_state = 0},
+  [990] = {.lex_state = 0},
+  [991] = {.lex_state = 72},
+  [992] = {.lex_state = 0},
+  [993] = {.lex_state = 72},
+  [994] = {.lex_state = 0},
+  [995] = {.lex_state = 72},
+  [996] = {.lex_state = 72},
+  [997] = {.lex_state = 72},
+  [998] = {.lex_state = 72},
+  [999] = {.lex_state = 0},
+  [1000] = {.lex_state = 72},
+  [1001] = {.lex_state = 1},
+  [1002] = {.lex_state = 0},
+  [1003] = {.lex_state = 0},
+  [1004] = {.lex_state = 0},
+  [1005] = {.lex_state = 0},
+  [1006] = {.lex_state = 0},
+  [1007] = {.lex_state = 72},
+  [1008] = {.lex_state = 72},
+  [1009] = {.lex_state = 72},
+  [1010] = {.lex_state = 72},
+  [1011] = {.lex_state = 0},
+  [1012] = {.lex_state = 0},
+  [1013] = {.lex_state = 72},
+  [1014] = {.lex_state = 72},
+  [1015] = {.lex_state = 0},
+  [1016] = {.lex_state = 72},
+  [1017] = {.lex_state = 0},
+  [1018] = {.lex_state = 72},
+  [1019] = {.lex_state = 72},
+  [1020] = {.lex_state = 72},
+  [1021] = {.lex_state = 0},
+  [1022] = {.lex_state = 72},
+  [1023] = {.lex_state = 72},
+  [1024] = {.lex_state = 72},
+  [1025] = {.lex_state = 72},
+  [1026] = {.lex_state = 72},
+  [1027] = {.lex_state = 0},
+  [1028] = {.lex_state = 72},
+  [1029] = {.lex_state = 0},
+  [1030] = {.lex_state = 0},
+  [1031] = {.lex_state = 0},
+  [1032] = {.lex_state = 72},
+  [1033] = {.lex_state = 0},
+  [1034] = {.lex_state = 72},
+  [1035] = {.lex_state = 72},
+  [1036] = {.lex_state = 0},
+  [1037] = {.lex_state = 0},
+  [1038] = {.lex_state = 72},
+  [1039] = {.lex_state = 72},
+  [1040] = {.lex_state = 72},
+  [1041] = {.lex_state = 72},
+  [1042] = {.lex_state = 72},
+  [1043] = {.lex_state = 72},
+  [1044] = {.lex_state = 0},
+  [1045] = {.lex_state = 0},
+  [1046] = {.lex_state = 72},
+  [1047] = {.lex_state = 72},
+  [1048] = {.lex_state = 72},
+  [1049] = {.lex_state = 72},
+  [1050] = {.lex_state = 72},
+  [1051] = {.lex_state = 72},
+  [1052] = {.lex_state = 72},
+  [1053] = {.lex_state = 0},
+  [1054] = {.lex_state = 72},
+  [1055] = {.lex_state = 72},
+  [1056] = {.lex_state = 72},
+  [1057] = {.lex_state = 72},
+  [1058] = {.lex_state = 72},
+  [1059] = {.lex_state = 72},
+  [1060] = {.lex_state = 72},
+  [1061] = {.lex_state = 72},
+  [1062] = {.lex_state = 72},
+  [1063] = {.lex_state = 72},
+  [1064] = {.lex_state = 0},
+  [1065] = {.lex_state = 0},
+  [1066] = {.lex_state = 72},
+  [1067] = {.lex_state = 72},
+  [1068] = {.lex_state = 72},
+  [1069] = {.lex_state = 72},
+  [1070] = {.lex_state = 72},
+  [1071] = {.lex_state = 0},
+  [1072] = {.lex_state = 72},
+  [1073] = {.lex_state = 0},
+  [1074] = {.lex_state = 72},
+  [1075] = {.lex_state = 72},
+  [1076] = {.lex_state = 0},
+  [1077] = {.lex_state = 72},
+  [1078] = {.lex_state = 0},
+  [1079] = {.lex_state = 72},
+  [1080] = {.lex_state = 0},
+  [1081] = {.lex_state = 0},
+  [1082] = {.lex_state = 72},
+  [1083] = {.lex_state = 72},
+  [1084] = {.lex_state = 72},
+  [1085] = {.lex_state = 72},
+  [1086] = {.lex_state = 72},
+  [1087] = {.lex_state = 72},
+  [1088] = {.lex_state = 72},
+  [1089] = {.lex_state = 72},
+  [1090] = {.lex_state = 72},
+  [1091] = {.lex_state = 72},
+  [1092] = {.lex_state = 72},
+  [1093] = {.lex_state = 72},
+  [1094] = {.lex_state = 72},
+  [1095] = {.lex_state = 0},
+  [1096] = {.lex_state = 72},
+  [1097] = {.lex_state = 72},
+  [1098] = {.lex_state = 72},
+  [1099] = {.lex_state = 72},
+  [1100] = {.lex_state = 72},
+  [1101] = {.lex_state = 72},
+  [1102] = {.lex_state = 0},
+  [1103] = {.lex_state = 72},
+  [1104] = {.lex_state = 0},
+  [1105] = {.lex_state = 72},
+  [1106] = {.lex_state = 0},
+  [1107] = {.lex_state = 72},
+  [1108] = {.lex_state = 0},
+  [1109] = {.lex_state = 72},
+  [1110] = {.lex_state = 72},
+  [1111] = {.lex_state = 0},
+  [1112] = {.lex_state = 0},
+  [1113] = {.lex_state = 72},
+};
+
+static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
+  [STATE(0)] = {
+    [ts_builtin_sym_end] = ACTIONS(1),
+    [sym__unquoted_identifier] = ACTIONS(1),
+    [anon_sym_SEMI] = ACTIONS(1),
+    [anon_sym_LPAREN] = ACTIONS(1),
+    [anon_sym_COMMA] = ACTIONS(1),
+    [anon_sym_RPAREN] = ACTIONS(1),
+    [anon_sym_EQ] = ACTIONS(1),
+    [anon_sym_STAR] = ACTIONS(1),
+    [anon_sym_DOT] = ACTIONS(1),
+    [anon_sym_BANG_EQ] = ACTIONS(1),
+    [anon_sym_LT] = ACTIONS(1),
+    [anon_sym_LT_EQ] = ACTIONS(1),
+    [anon_sym_GT] = ACTIONS(1),
+    [anon_sym_GT_EQ] = ACTIONS(1),
+    [anon_sym_PLUS_EQ] = ACTIONS(1),
+    [anon_sym_DASH_EQ] = ACTIONS(1),
+    [anon_sym_LBRACK] = ACTIONS(1),
+    [anon_sym_RBRACK] = ACTIONS(1),
+    [anon_sym_PLUS] = ACTIONS(1),
+    [anon_sym_DASH] = ACTIONS(1),
+    [aux_sym_native_type_token1] = ACTIONS(1),
+    [aux_sym_native_type_token2] = ACTIONS(1),
+    [aux_sym_native_type_token3] = ACTIONS(1),
+    [aux_sym_native_type_token4] = ACTIONS(1),
+    [aux_sym_native_type_token5] = ACTIONS(1),
+    [aux_sym_native_type_token6] = ACTIONS(1),
+    [aux_sym_native_type_token7] = ACTIONS(1),
+    [aux_sym_native_type_token8] = ACTIONS(1),
+    [aux_sym_native_type_token9] = ACTIONS(1),
+    [aux_sym_native_type_token10] = ACTIONS(1),
+    [aux_sym_native_type_token11] = ACTIONS(1),
+    [aux_sym_native_type_token12] = ACTIONS(1),
+    [aux_sym_native_type_token13] = ACTIONS(1),
+    [aux_sym_native_type_token14] = ACTIONS(1),
+    [aux_sym_native_type_token15] = ACTIONS(1),
+    [aux_sym_native_type_token16] = ACTIONS(1),
+    [aux_sym_native_type_token17] = ACTIONS(1),
+    [aux_sym_native_type_token18] = ACTIONS(1),
+    [aux_sym_native_type_token19] = ACTIONS(1),
+    [anon_sym_LBRACE] = ACTIONS(1),
+    [anon_sym_RBRACE] = ACTIONS(1),
+    [anon_sym_COLON] = ACTIONS(1),
+    [anon_sym_QMARK] = ACTIONS(1),
+    [sym_quoted_identifier] = ACTIONS(1),
+    [sym_interpolation] = ACTIONS(1),
+    [sym_string_literal] = ACTIONS(1),
+    [sym_dollar_string] = ACTIONS(1),
+    [sym_integer] = ACTIONS(1),
+    [aux_sym_float_token1] = ACTIONS(1),
+    [aux_sym_float_token2] = ACTIONS(1),
+    [aux_sym_float_token3] = ACTIONS(1),
+    [aux_sym_float_token4] = ACTIONS(1),
+    [aux_sym_boolean_token1] = ACTIONS(1),
+    [aux_sym_boolean_token2] = ACTIONS(1),
+    [sym_uuid] = ACTIONS(1),
+    [sym_blob] = ACTIONS(1),
+    [sym_duration] = ACTIONS(1),
+    [sym_comment] = ACTIONS(3),
+    [sym_keyword_add] = ACTIONS(1),
+    [sym_keyword_aggregate] = ACTIONS(1),
+    [sym_keyword_allow] = ACTIONS(1),
+    [sym_keyword_alter] = ACTIONS(1),
+    [sym_keyword_and] = ACTIONS(1),
+    [sym_keyword_apply] = ACTIONS(1),
+    [sym_keyword_as] = ACTIONS(1),
+    [sym_keyword_asc] = ACTIONS(1),
+    [sym_keyword_batch] = ACTIONS(1),
+    [sym_keyword_begin] = ACTIONS(1),
+    [sym_keyword_by] = ACTIONS(1),
+    [sym_keyword_called] = ACTIONS(1),
+    [sym_keyword_cast] = ACTIONS(1),
+    [sym_keyword_clustering] = ACTIONS(1),
+    [sym_keyword_columnfamily] = ACTIONS(1),
+    [sym_keyword_compact] = ACTIONS(1),
+    [sym_keyword_contains] = ACTIONS(1),
+    [sym_keyword_counter] = ACTIONS(1),
+    [sym_keyword_create] = ACTIONS(1),
+    [sym_keyword_custom] = ACTIONS(1),
+    [sym_keyword_default] = ACTIONS(1),
+    [sym_keyword_delete] = ACTIONS(1),
+    [sym_keyword_desc] = ACTIONS(1),
+    [sym_keyword_describe] = ACTIONS(1),
+    [sym_keyword_distinct] = ACTIONS(1),
+    [sym_keyword_drop] = ACTIONS(1),
+    [sym_keyword_entries] = ACTIONS(1),
+    [sym_keyword_exists] = ACTIONS(1),
+    [sym_keyword_filtering] = ACTIONS(1),
+    [sym_keyword_finalfunc] = ACTIONS(1),
+    [sym_keyword_from] = ACTIONS(1),
+    [sym_keyword_frozen] = ACTIONS(1),
+    [sym_keyword_full] = ACTIONS(1),
+    [sym_keyword_function] = ACTIONS(1),
+    [sym_keyword_functions] = ACTIONS(1),
+    [sym_keyword_group] = ACTIONS(1),
+    [sym_keyword_if] = ACTIONS(1),
+    [sym_keyword_in] = ACTIONS(1),
+    [sym_keyword_index] = ACTIONS(1),
+    [sym_keyword_initcond] = ACTIONS(1),
+    [sym_keyword_input] = ACTIONS(1),
+    [sym_keyword_insert] = ACTIONS(1),
+    [sym_keyword_into] = ACTIONS(1),
+    [sym_keyword_is] = ACTIONS(1),
+    [sym_keyword_json] = ACTIONS(1),
+    [sym_keyword_key] = ACTIONS(1),
+    [sym_keyword_keys] = ACTIONS(1),
+    [sym_keyword_keyspace] = ACTIONS(1),
+    [sym_keyword_keyspaces] = ACTIONS(1),
+    [sym_keyword_language] = ACTIONS(1),
+    [sym_keyword_like] = ACTIONS(1),
+    [sym_keyword_limit] = ACTIONS(1),
+    [sym_keyword_list] = ACTIONS(1),
+    [sym_keyword_map] = ACTIONS(1),
+    [sym_keyword_materialized] = ACTIONS(1),
+    [sym_keyword_not] = ACTIONS(1),
+    [sym_keyword_null] = ACTIONS(1),
+    [sym_keyword_on] = ACTIONS(1),
+    [sym_keyword_options] = ACTIONS(1),
+    [sym_keyword_or] = ACTIONS(1),
+    [sym_keyword_order] = ACTIONS(1),
+    [sym_keyword_partition] = ACTIONS(1),
+    [sym_keyword_per] = ACTIONS(1),
+    [sym_keyword_primary] = ACTIONS(1),
+    [sym_keyword_rename] = ACTIONS(1),
+    [sym_keyword_replace] = ACTIONS(1),
+    [sym_keyword_returns] = ACTIONS(1),
+    [sym_keyword_schema] = ACTIONS(1),
+    [sym_keyword_select] = ACTIONS(1),
+    [sym_keyword_set] = ACTIONS(1),
+    [sym_keyword_sfunc] = ACTIONS(1),
+    [sym_keyword_static] = ACTIONS(1),
+    [sym_keyword_storage] = ACTIONS(1),
+    [sym_keyword_stype] = ACTIONS(1),
+    [sym_keyword_table] = ACTIONS(1),
+    [sym_keyword_tables] = ACTIONS(1),
+    [sym_keyword_to] = ACTIONS(1),
+    [sym_keyword_token] = ACTIONS(1),
+    [sym_keyword_truncate] = ACTIONS(1),
+    [sym_keyword_ttl] = ACTIONS(1),
+    [sym_keyword_tuple] = ACTIONS(1),
+    [sym_keyword_type] = ACTIONS(1),
+    [sym_keyword_types] = ACTIONS(1),
+    [sym_keyword_unlogged] = ACTIONS(1),
+    [sym_keyword_unset] = ACTIONS(1),
+    [sym_keyword_update] = ACTIONS(1),
+    [sym_keyword_use] = ACTIONS(1),
+    [sym_keyword_using] = ACTIONS(1),
+    [sym_keyword_values] = ACTIONS(1),
+    [sym_keyword_vector] = ACTIONS(1),
+    [sym_keyword_view] = ACTIONS(1),
+    [sym_keyword_where] = ACTIONS(1),
+    [sym_keyword_with] = ACTIONS(1),
+    [sym_keyword_timestamp] = ACTIONS(1),
+  },
+  [STATE(1)] = {
+    [sym_source_file] = STATE(1102),
+    [sym__statement] = STATE(9),
+    [sym__dml_statement] = STATE(9),
+    [sym_create_keyspace_statement] = STATE(9),
+    [sym_alter_keyspace_statement] = STATE(9),
+    [sym_drop_keyspace_statement] = STATE(9),
+    [sym_use_statement] = STATE(9),
+    [sym_create_table_statement] = STATE(9),
+    [sym_alter_table_statement] = STATE(9),
+    [sym_drop_table_statement] = STATE(9),
+    [sym_truncate_statement] = STATE(9),
+    [sym_create_type_statement] = STATE(9),
+    [sym_alter_type_statement] = STATE(9),
+    [sym_drop_type_statement] = STATE(9),
+    [sym_create_index_statement] = STATE(9),
+    [sym_drop_index_statement] = STATE(9),
+    [sym_create_materialized_view_statement] = STATE(9),
+    [sym_alter_materialized_view_statement] = STATE(9),
+    [sym_drop_materialized_view_statement] = STATE(9),
+    [sym_create_function_statement] = STATE(9),
+    [sym_drop_function_statement] = STATE(9),
+    [sym_create_aggregate_statement] = STATE(9),
+    [sym_drop_aggregate_statement] = STATE(9),
+    [sym_describe_statement] = STATE(9),
+    [sym_select_statement] = STATE(9),
+    [sym_insert_statement] = STATE(9),
+    [sym_update_statement] = STATE(9),
+    [sym_delete_statement] = STATE(9),
+    [sym_batch_statement] = STATE(9),
+    [aux_sym_source_file_repeat1] = STATE(9),
+    [ts_builtin_sym_end] = ACTIONS(5),
+    [anon_sym_SEMI] = ACTIONS(7),
+    [sym_comment] = ACTIONS(3),
+    [sym_keyword_alter] = ACTIONS(9),
+    [sym_keyword_begin] = ACTIONS(11),
+    [sym_keyword_create] = ACTIONS(13),
+    [sym_keyword_delete] = ACTIONS(15),
+    [sym_keyword_desc] = ACTIONS(17),
+    [sym_keyword_describe] = ACTIONS(19),
+    [sym_keyword_drop] = ACTIONS(21),
+    [sym_keyword_insert] = ACTIONS(23),
+    [sym_keyword_select] = ACTIONS(25),
+    [sym_keyword_truncate] = ACTIONS(27),
+    [sym_keyword_update] = ACTIONS(29),
+    [sym_keyword_use] = ACTIONS(31),
+  },
+  [STATE(2)] = {
+    [sym__type] = STATE(1021),
+    [sym_native_type] = STATE(1021),
+    [sym_collection_type] = STATE(1021),
+    [sym_frozen_type] = STATE(1021),
+    [sym_tuple_type] = STATE(1021),
+    [sym_vector_type] = STATE(1021),
+    [sym_user_type] = STATE(1021),
+    [sym_custom_type] = STATE(1021),
+    [sym__term] = STATE(890),
+    [sym__literal] = STATE(890),
+    [sym_function_call] = STATE(890),
+    [sym_type_hint] = STATE(890),
+    [sym_list_literal] = STATE(890),
+    [sym_set_literal] = STATE(890),
+    [sym_map_literal] = STATE(890),
+    [sym_tuple_literal] = STATE(890),
+    [sym_bind_marker] = STATE(890),
+    [sym_qualified_name] = STATE(895),
+    [sym_identifier] = STATE(67),
+    [sym_float] = STATE(890),
+    [sym_boolean] = STATE(890),
+    [sym__unquoted_identifier] = ACTIONS(33),
+    [anon_sym_LPAREN] = ACTIONS(35),
+    [anon_sym_LBRACK] = ACTIONS(37),
+    [anon_sym_DASH] = ACTIONS(39),
+    [aux_sym_native_type_token1] = ACTIONS(41),
+    [aux_sym_native_type_token2] = ACTIONS(41),
+    [aux_sym_native_type_token3] = ACTIONS(41),
+    [aux_sym_native_type_token4] = ACTIONS(41),
+    [aux_sym_native_type_token5] = ACTIONS(41),
+    [aux_sym_native_type_token6] = ACTIONS(41),
+    [aux_sym_native_type_token7] = ACTIONS(41),
+    [aux_sym_native_type_token8] = ACTIONS(41),
+    [aux_sym_native_type_token9] = ACTIONS(41),
+    [aux_sym_native_type_token10] = ACTIONS(41),
+    [aux_sym_native_type_token11] = ACTIONS(41),
+    [aux_sym_native_type_token12] = ACTIONS(41),
+    [aux_sym_native_type_token13] = ACTIONS(41),
+    [aux_sym_native_type_token14] = ACTIONS(43),
+    [aux_sym_native_type_token15] = ACTIONS(41),
+    [aux_sym_native_type_token16] = ACTIONS(41),
+    [aux_sym_native_type_token17] = ACTIONS(41),
+    [aux_sym_native_type_token18] = ACTIONS(41),
+    [aux_sym_native_type_token19] = ACTIONS(41),
+    [anon_sym_LBRACE] = ACTIONS(45),
+    [anon_sym_COLON] = ACTIONS(47),
+    [anon_sym_QMARK] = ACTIONS(49),
+    [sym_quoted_identifier] = ACTIONS(51),
+    [sym_interpolation] = ACTIONS(53),
+    [sym_string_literal] = ACTIONS(55),
+    [sym_dollar_string] = ACTIONS(57),
+    [sym_integer] = ACTIONS(59),
+    [aux_sym_float_token1] = ACTIONS(61),
+    [aux_sym_float_token2] = ACTIONS(63),
+    [aux_sym_float_token3] = ACTIONS(61),
+    [aux_sym_float_token4] = ACTIONS(61),
+    [aux_sym_boolean_token1] = ACTIONS(65),
+    [aux_sym_boolean_token2] = ACTIONS(65),
+    [sym_uuid] = ACTIONS(57),
+    [sym_blob] = ACTIONS(57),
+    [sym_duration] = ACTIONS(59),
+    [sym_comment] = ACTIONS(3),
+    [sym_keyword_counter] = ACTIONS(41),
+    [sym_keyword_frozen] = ACTIONS(67),
+    [sym_keyword_list] = ACTIONS(69),
+    [sym_keyword_map] = ACTIONS(71),
+    [sym_keyword_null] = ACTIONS(73),
+    [sym_keyword_set] = ACTIONS(69),
+    [sym_keyword_tuple] = ACTIONS(75),
+    [sym_keyword_vector] = ACTIONS(77),
+    [sym_keyword_timestamp] = ACTIONS(41),
+  },
+  [STATE(3)] = {
+    [sym__type] = STATE(1111),
+    [sym_native_type] = STATE(1111),
+    [sym_collection_type] = STATE(1111),
+    [sym_frozen_type] = STATE(1111),
+    [sym_tuple_type] = STATE(1111),
+    [sym_vector_type] = STATE(1111),
+    [sym_user_type] = STATE(1111),
+    [sym_custom_type] = STATE(1111),
+    [sym__term] = STATE(890),
+    [sym__literal] = STATE(890),
+    [sym_function_call] = STATE(890),
+    [sym_type_hint] = STATE(890),
+    [sym_list_literal] = STATE(890),
+    [sym_set_literal] = STATE(890),
+    [sym_map_literal] = STATE(890),
+    [sym_tuple_literal] = STATE(890),
+    [sym_bind_marker] = STATE(890),
+    [sym_qualified_name] = STATE(895),
+    [sym_identifier] = STATE(67),
+    [sym_float] = STATE(890),
+    [sym_boolean] = STATE(890),
+    [sym__unquoted_identifier] = ACTIONS(33),
+    [anon_sym_LPAREN] = ACTIONS(35),
+    [anon_sym_LBRACK] = ACTIONS(37),
+    [anon_sym_DASH] = ACTIONS(39),
+    [aux_sym_native_type_token1] = ACTIONS(41),
+    [aux_sym_native_type_token2] = ACTIONS(41),
+    [aux_sym_native_type_token3] = ACTIONS(41),
+    [aux_sym_native_type_token4] = ACTIONS(41),
+    [aux_sym_native_type_token5] = ACTIONS(41),
+    [aux_sym_native_type_token6] = ACTIONS(41),
+    [aux_sym_native_type_token7] = ACTIONS(41),
+    [aux_sym_native_type_token8] = ACTIONS(41),
+    [aux_sym_native_type_token9] = ACTIONS(41),
+    [aux_sym_native_type_token10] = ACTIONS(41),
+    [aux_sym_native_type_token11] = ACTIONS(41),
+    [aux_sym_native_type_token12] = ACTIONS(41),
+    [aux_sym_native_type_token13] = ACTIONS(41),
+    [aux_sym_native_type_token14] = ACTIONS(43),
+    [aux_sym_native_type_token15] = ACTIONS(41),
+    [aux_sym_native_type_token16] = ACTIONS(41),
+    [aux_sym_native_type_token17] = ACTIONS(41),
+    [aux_sym_native_type_token18] = ACTIONS(41),
+    [aux_sym_native_type_token19] = ACTIONS(41),
+    [anon_sym_LBRACE] = ACTIONS(45),
+    [anon_sym_COLON] = ACTIONS(47),
+    [anon_sym_QMARK] = ACTIONS(49),
+    [sym_quoted_identifier] = ACTIONS(51),
+    [sym_interpolation] = ACTIONS(53),
+    [sym_string_literal] = ACTIONS(55),
+    [sym_dollar_string] = ACTIONS(57),
+    [sym_integer] = ACTIONS(59),
+    [aux_sym_float_token1] = ACTIONS(61),
+    [aux_sym_float_token2] = ACTIONS(63),
+    [aux_sym_float_token3] = ACTIONS(61),
+    [aux_sym_float_token4] = ACTIONS(61),
+    [aux_sym_boolean_token1] = ACTIONS(65),
+    [aux_sym_boolean_token2] = ACTIONS(65),
+    [sym_uuid] = ACTIONS(57),
+    [sym_blob] = ACTIONS(57),
+    [sym_duration] = ACTIONS(59),
+    [sym_comment] = ACTIONS(3),
+    [sym_keyword_counter] = ACTIONS(41),
+    [sym_keyword_frozen] = ACTIONS(67),
+    [sym_keyword_list] = ACTIONS(69),
+    [sym_keyword_map] = ACTIONS(71),
+    [sym_keyword_null] = ACTIONS(73),
+    [sym_keyword_set] = ACTIONS(69),
+    [sym_keyword_tuple] = ACTIONS(75),
+    [sym_keyword_vector] = ACTIONS(77),
+    [sym_keyword_timestamp] = ACTIONS(41),
+  },
+  [STATE(4)] = {
+    [sym__type] = STATE(1104),
+    [sym_native_type] = STATE(1104),
+    [sym_collection_type] = STATE(1104),
+    [sym_frozen_type] = STATE(1104),
+    [sym_tuple_type] = STATE(1104),
+    [sym_vector_type] = STATE(1104),
+    [sym_user_type] = STATE(1104),
+    [sym_custom_type] = STATE(1104),
+    [sym__term] = STATE(890),
+    [sym__literal] = STATE(890),
+    [sym_function_call] = STATE(890),
+    [sym_type_hint] = STATE(890),
+    [sym_list_literal] = STATE(890),
+    [sym_set_literal] = STATE(890),
+    [sym_map_literal] = STATE(890),
+    [sym_tuple_literal] = STATE(890),
+    [sym_bind_marker] = STATE(890),
+    [sym_qualified_name] = STATE(895),
+    [sym_identifier] = STATE(67),
+    [sym_float] = STATE(890),
+    [sym_boolean] = STATE(890),
+    [sym__unquoted_identifier] = ACTIONS(33),
+    [anon_sym_LPAREN] = ACTIONS(35),
+    [anon_sym_LBRACK] = ACTIONS(37),
+    [anon_sym_DASH] = ACTIONS(39),
+    [aux_sym_native_type_token1] = ACTIONS(41),
+    [aux_sym_native_type_token2] = ACTIONS(41),
+    [aux_sym_native_type_token3] = ACTIONS(41),
+    [aux_sym_native_type_token4] = ACTIONS(41),
+    [aux_sym_native_type_token5] = ACTIONS(41),
+    [aux_sym_native_type_token6] = ACTIONS(41),
+    [aux_sym_native_type_token7] = ACTIONS(41),
+    [aux_sym_native_type_token8] = ACTIONS(41),
+    [aux_sym_native_type_token9] = ACTIONS(41),
+    [aux_sym_native_type_token10] = ACTIONS(41),
+    [aux_sym_native_type_token11] = ACTIONS(41),
+    [aux_sym_native_type_token12] = ACTIONS(41),
+    [aux_sym_native_type_token13] = ACTIONS(41),
+    [aux_sym_native_type_token14] = ACTIONS(43),
+    [aux_sym_native_type_token15] = ACTIONS(41),
+    [aux_sym_native_type_token16] = ACTIONS(41),
+    [aux_sym_native_type_token17] = ACTIONS(41),
+    [aux_sym_native_type_token18] = ACTIONS(41),
+    [aux_sym_native_type_token19] = ACTIONS(41),
+    [anon_sym_LBRACE] = ACTIONS(45),
+    [anon_sym_COLON] = ACTIONS(47),
+    [anon_sym_QMARK] = ACTIONS(49),
+    [sym_quoted_identifier] = ACTIONS(51),
+    [sym_interpolation] = ACTIONS(53),
+    [sym_string_literal] = ACTIONS(55),
+    [sym_dollar_string] = ACTIONS(57),
+    [sym_integer] = ACTIONS(59),
+    [aux_sym_float_token1] = ACTIONS(61),
+    [aux_sym_float_token2] = ACTIONS(63),
+    [aux_sym_float_token3] = ACTIONS(61),
+    [aux_sym_float_token4] = ACTIONS(61),
+    [aux_sym_boolean_token1] = ACTIONS(65),
+    [aux_sym_boolean_token2] = ACTIONS(65),
+    [sym_uuid] = ACTIONS(57),
+    [sym_blob] = ACTIONS(57),
+    [sym_duration] = ACTIONS(59),
+    [sym_comment] = ACTIONS(3),
+    [sym_keyword_counter] = ACTIONS(41),
+    [sym_keyword_frozen] = ACTIONS(67),
+    [sym_keyword_list] = ACTIONS(69),
+    [sym_keyword_map] = ACTIONS(71),
+    [sym_keyword_null] = ACTIONS(73),
+    [sym_keyword_set] = ACTIONS(69),
+    [sym_keyword_tuple] = ACTIONS(75),
+    [sym_keyword_vector] = ACTIONS(77),
+    [sym_keyword_timestamp] = ACTIONS(41),
+  },
+};
+
+static const uint16_t ts_small_parse_table[] = {
+  [0] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(81), 6,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      sym_keyword_desc,
+      sym_keyword_in,
+    ACTIONS(79), 57,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_EQ,
+      anon_sym_DOT,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS_EQ,
+      anon_sym_DASH_EQ,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      sym_keyword_add,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_as,
+      sym_keyword_begin,
+      sym_keyword_contains,
+      sym_keyword_create,
+      sym_keyword_default,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_finalfunc,
+      sym_keyword_from,
+      sym_keyword_group,
+      sym_keyword_if,
+      sym_keyword_initcond,
+      sym_keyword_insert,
+      sym_keyword_is,
+      sym_keyword_json,
+      sym_keyword_language,
+      sym_keyword_like,
+      sym_keyword_limit,
+      sym_keyword_on,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_primary,
+      sym_keyword_rename,
+      sym_keyword_select,
+      sym_keyword_set,
+      sym_keyword_static,
+      sym_keyword_stype,
+      sym_keyword_to,
+      sym_keyword_truncate,
+      sym_keyword_type,
+      sym_keyword_update,
+      sym_keyword_use,
+      sym_keyword_using,
+      sym_keyword_where,
+      sym_keyword_with,
+  [71] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(81), 6,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      sym_keyword_desc,
+      sym_keyword_in,
+    ACTIONS(79), 57,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_EQ,
+      anon_sym_DOT,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS_EQ,
+      anon_sym_DASH_EQ,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      sym_keyword_add,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_as,
+      sym_keyword_begin,
+      sym_keyword_contains,
+      sym_keyword_create,
+      sym_keyword_default,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_finalfunc,
+      sym_keyword_from,
+      sym_keyword_group,
+      sym_keyword_if,
+      sym_keyword_initcond,
+      sym_keyword_insert,
+      sym_keyword_is,
+      sym_keyword_json,
+      sym_keyword_language,
+      sym_keyword_like,
+      sym_keyword_limit,
+      sym_keyword_on,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_primary,
+      sym_keyword_rename,
+      sym_keyword_select,
+      sym_keyword_set,
+      sym_keyword_static,
+      sym_keyword_stype,
+      sym_keyword_to,
+      sym_keyword_truncate,
+      sym_keyword_type,
+      sym_keyword_update,
+      sym_keyword_use,
+      sym_keyword_using,
+      sym_keyword_where,
+      sym_keyword_with,
+  [142] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    ACTIONS(83), 1,
+      anon_sym_STAR,
+    ACTIONS(85), 1,
+      sym_interpolation,
+    ACTIONS(91), 1,
+      sym_keyword_cast,
+    ACTIONS(93), 1,
+      sym_keyword_distinct,
+    ACTIONS(95), 1,
+      sym_keyword_json,
+    STATE(505), 1,
+      sym_identifier,
+    STATE(728), 1,
+      sym_function_call,
+    STATE(819), 1,
+      sym_selector,
+    STATE(1053), 1,
+      sym_qualified_name,
+    STATE(1069), 1,
+      sym_selection,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(89), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(87), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(830), 13,
+      sym__selector_expression,
+      sym_column_reference,
+      sym_cast_expression,
+      sym__term,
+      sym__literal,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [240] = 25,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    ACTIONS(83), 1,
+      anon_sym_STAR,
+    ACTIONS(85), 1,
+      sym_interpolation,
+    ACTIONS(91), 1,
+      sym_keyword_cast,
+    ACTIONS(97), 1,
+      sym_keyword_distinct,
+    STATE(505), 1,
+      sym_identifier,
+    STATE(728), 1,
+      sym_function_call,
+    STATE(819), 1,
+      sym_selector,
+    STATE(1053), 1,
+      sym_qualified_name,
+    STATE(1068), 1,
+      sym_selection,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(89), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(87), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(830), 13,
+      sym__selector_expression,
+      sym_column_reference,
+      sym_cast_expression,
+      sym__term,
+      sym__literal,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [335] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(9), 1,
+      sym_keyword_alter,
+    ACTIONS(11), 1,
+      sym_keyword_begin,
+    ACTIONS(13), 1,
+      sym_keyword_create,
+    ACTIONS(15), 1,
+      sym_keyword_delete,
+    ACTIONS(17), 1,
+      sym_keyword_desc,
+    ACTIONS(19), 1,
+      sym_keyword_describe,
+    ACTIONS(21), 1,
+      sym_keyword_drop,
+    ACTIONS(23), 1,
+      sym_keyword_insert,
+    ACTIONS(25), 1,
+      sym_keyword_select,
+    ACTIONS(27), 1,
+      sym_keyword_truncate,
+    ACTIONS(29), 1,
+      sym_keyword_update,
+    ACTIONS(31), 1,
+      sym_keyword_use,
+    ACTIONS(99), 1,
+      ts_builtin_sym_end,
+    ACTIONS(101), 1,
+      anon_sym_SEMI,
+    STATE(10), 29,
+      sym__statement,
+      sym__dml_statement,
+      sym_create_keyspace_statement,
+      sym_alter_keyspace_statement,
+      sym_drop_keyspace_statement,
+      sym_use_statement,
+      sym_create_table_statement,
+      sym_alter_table_statement,
+      sym_drop_table_statement,
+      sym_truncate_statement,
+      sym_create_type_statement,
+      sym_alter_type_statement,
+      sym_drop_type_statement,
+      sym_create_index_statement,
+      sym_drop_index_statement,
+      sym_create_materialized_view_statement,
+      sym_alter_materialized_view_statement,
+      sym_drop_materialized_view_statement,
+      sym_create_function_statement,
+      sym_drop_function_statement,
+      sym_create_aggregate_statement,
+      sym_drop_aggregate_statement,
+      sym_describe_statement,
+      sym_select_statement,
+      sym_insert_statement,
+      sym_update_statement,
+      sym_delete_statement,
+      sym_batch_statement,
+      aux_sym_source_file_repeat1,
+  [412] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(103), 1,
+      ts_builtin_sym_end,
+    ACTIONS(105), 1,
+      anon_sym_SEMI,
+    ACTIONS(108), 1,
+      sym_keyword_alter,
+    ACTIONS(111), 1,
+      sym_keyword_begin,
+    ACTIONS(114), 1,
+      sym_keyword_create,
+    ACTIONS(117), 1,
+      sym_keyword_delete,
+    ACTIONS(120), 1,
+      sym_keyword_desc,
+    ACTIONS(123), 1,
+      sym_keyword_describe,
+    ACTIONS(126), 1,
+      sym_keyword_drop,
+    ACTIONS(129), 1,
+      sym_keyword_insert,
+    ACTIONS(132), 1,
+      sym_keyword_select,
+    ACTIONS(135), 1,
+      sym_keyword_truncate,
+    ACTIONS(138), 1,
+      sym_keyword_update,
+    ACTIONS(141), 1,
+      sym_keyword_use,
+    STATE(10), 29,
+      sym__statement,
+      sym__dml_statement,
+      sym_create_keyspace_statement,
+      sym_alter_keyspace_statement,
+      sym_drop_keyspace_statement,
+      sym_use_statement,
+      sym_create_table_statement,
+      sym_alter_table_statement,
+      sym_drop_table_statement,
+      sym_truncate_statement,
+      sym_create_type_statement,
+      sym_alter_type_statement,
+      sym_drop_type_statement,
+      sym_create_index_statement,
+      sym_drop_index_statement,
+      sym_create_materialized_view_statement,
+      sym_alter_materialized_view_statement,
+      sym_drop_materialized_view_statement,
+      sym_create_function_statement,
+      sym_drop_function_statement,
+      sym_create_aggregate_statement,
+      sym_drop_aggregate_statement,
+      sym_describe_statement,
+      sym_select_statement,
+      sym_insert_statement,
+      sym_update_statement,
+      sym_delete_statement,
+      sym_batch_statement,
+      aux_sym_source_file_repeat1,
+  [489] = 24,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    ACTIONS(83), 1,
+      anon_sym_STAR,
+    ACTIONS(85), 1,
+      sym_interpolation,
+    ACTIONS(91), 1,
+      sym_keyword_cast,
+    STATE(505), 1,
+      sym_identifier,
+    STATE(728), 1,
+      sym_function_call,
+    STATE(819), 1,
+      sym_selector,
+    STATE(1053), 1,
+      sym_qualified_name,
+    STATE(1068), 1,
+      sym_selection,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(89), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(87), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(830), 13,
+      sym__selector_expression,
+      sym_column_reference,
+      sym_cast_expression,
+      sym__term,
+      sym__literal,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [581] = 24,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    ACTIONS(83), 1,
+      anon_sym_STAR,
+    ACTIONS(85), 1,
+      sym_interpolation,
+    ACTIONS(91), 1,
+      sym_keyword_cast,
+    STATE(505), 1,
+      sym_identifier,
+    STATE(728), 1,
+      sym_function_call,
+    STATE(819), 1,
+      sym_selector,
+    STATE(1053), 1,
+      sym_qualified_name,
+    STATE(1101), 1,
+      sym_selection,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(89), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(87), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(830), 13,
+      sym__selector_expression,
+      sym_column_reference,
+      sym_cast_expression,
+      sym__term,
+      sym__literal,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [673] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(144), 1,
+      anon_sym_RPAREN,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(734), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [749] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    ACTIONS(150), 1,
+      anon_sym_RPAREN,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(784), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [825] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    ACTIONS(152), 1,
+      anon_sym_RPAREN,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(747), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [901] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    ACTIONS(154), 1,
+      anon_sym_RPAREN,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(788), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [977] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    ACTIONS(156), 1,
+      anon_sym_RPAREN,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(839), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [1053] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    ACTIONS(158), 1,
+      anon_sym_RPAREN,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(829), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [1129] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    ACTIONS(160), 1,
+      anon_sym_RPAREN,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(798), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [1205] = 24,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    ACTIONS(83), 1,
+      anon_sym_STAR,
+    ACTIONS(85), 1,
+      sym_interpolation,
+    ACTIONS(91), 1,
+      sym_keyword_cast,
+    STATE(505), 1,
+      sym_identifier,
+    STATE(728), 1,
+      sym_function_call,
+    STATE(819), 1,
+      sym_selector,
+    STATE(984), 1,
+      sym_selection,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(89), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(87), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(830), 13,
+      sym__selector_expression,
+      sym_column_reference,
+      sym_cast_expression,
+      sym__term,
+      sym__literal,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [1297] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    ACTIONS(162), 1,
+      anon_sym_RPAREN,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(834), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [1373] = 24,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    ACTIONS(83), 1,
+      anon_sym_STAR,
+    ACTIONS(85), 1,
+      sym_interpolation,
+    ACTIONS(91), 1,
+      sym_keyword_cast,
+    STATE(505), 1,
+      sym_identifier,
+    STATE(728), 1,
+      sym_function_call,
+    STATE(819), 1,
+      sym_selector,
+    STATE(1007), 1,
+      sym_selection,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(89), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(87), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(830), 13,
+      sym__selector_expression,
+      sym_column_reference,
+      sym_cast_expression,
+      sym__term,
+      sym__literal,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [1465] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(968), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [1538] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(205), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [1611] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(865), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [1684] = 23,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    ACTIONS(85), 1,
+      sym_interpolation,
+    ACTIONS(91), 1,
+      sym_keyword_cast,
+    ACTIONS(164), 1,
+      anon_sym_LPAREN,
+    ACTIONS(166), 1,
+      anon_sym_RPAREN,
+    ACTIONS(168), 1,
+      anon_sym_STAR,
+    STATE(505), 1,
+      sym_identifier,
+    STATE(728), 1,
+      sym_function_call,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(172), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(170), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(841), 13,
+      sym__selector_expression,
+      sym_column_reference,
+      sym_cast_expression,
+      sym__term,
+      sym__literal,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [1773] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(1005), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [1846] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(442), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [1919] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(780), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [1992] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(1071), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [2065] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(368), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [2138] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(935), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [2211] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(989), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [2284] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(1003), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [2357] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(1006), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [2430] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(797), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [2503] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(252), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [2576] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(243), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [2649] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(919), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [2722] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(257), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [2795] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(259), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [2868] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(1034), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [2941] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(1042), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [3014] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(1070), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [3087] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(271), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [3160] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(272), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [3233] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(904), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [3306] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(245), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [3379] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(918), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [3452] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(922), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [3525] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(249), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [3598] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(250), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [3671] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(253), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [3744] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(1012), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [3817] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(972), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [3890] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(260), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [3963] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(262), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [4036] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(1016), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [4109] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(265), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [4182] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(43), 1,
+      aux_sym_native_type_token14,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(67), 1,
+      sym_keyword_frozen,
+    ACTIONS(71), 1,
+      sym_keyword_map,
+    ACTIONS(75), 1,
+      sym_keyword_tuple,
+    ACTIONS(77), 1,
+      sym_keyword_vector,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(148), 1,
+      sym_string_literal,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(158), 1,
+      sym_qualified_name,
+    ACTIONS(69), 2,
+      sym_keyword_list,
+      sym_keyword_set,
+    STATE(907), 8,
+      sym__type,
+      sym_native_type,
+      sym_collection_type,
+      sym_frozen_type,
+      sym_tuple_type,
+      sym_vector_type,
+      sym_user_type,
+      sym_custom_type,
+    ACTIONS(41), 20,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_keyword_counter,
+      sym_keyword_timestamp,
+  [4255] = 22,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    ACTIONS(85), 1,
+      sym_interpolation,
+    ACTIONS(91), 1,
+      sym_keyword_cast,
+    STATE(505), 1,
+      sym_identifier,
+    STATE(728), 1,
+      sym_function_call,
+    STATE(891), 1,
+      sym_selector,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(89), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(87), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(830), 13,
+      sym__selector_expression,
+      sym_column_reference,
+      sym_cast_expression,
+      sym__term,
+      sym__literal,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [4341] = 21,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    ACTIONS(85), 1,
+      sym_interpolation,
+    ACTIONS(91), 1,
+      sym_keyword_cast,
+    ACTIONS(164), 1,
+      anon_sym_LPAREN,
+    STATE(505), 1,
+      sym_identifier,
+    STATE(728), 1,
+      sym_function_call,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(176), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(174), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(894), 13,
+      sym__selector_expression,
+      sym_column_reference,
+      sym_cast_expression,
+      sym__term,
+      sym__literal,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [4424] = 21,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    ACTIONS(85), 1,
+      sym_interpolation,
+    ACTIONS(91), 1,
+      sym_keyword_cast,
+    STATE(505), 1,
+      sym_identifier,
+    STATE(728), 1,
+      sym_function_call,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(180), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(178), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(1097), 13,
+      sym__selector_expression,
+      sym_column_reference,
+      sym_cast_expression,
+      sym__term,
+      sym__literal,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [4507] = 21,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(53), 1,
+      sym_interpolation,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    ACTIONS(164), 1,
+      anon_sym_LPAREN,
+    STATE(505), 1,
+      sym_identifier,
+    STATE(867), 1,
+      sym_column_reference,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(184), 2,
+      sym_integer,
+      sym_duration,
+    STATE(893), 2,
+      sym__expression,
+      sym_binary_expression,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(182), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(685), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [4589] = 21,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(53), 1,
+      sym_interpolation,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    ACTIONS(164), 1,
+      anon_sym_LPAREN,
+    ACTIONS(186), 1,
+      anon_sym_RBRACE,
+    STATE(815), 1,
+      sym_map_entry,
+    STATE(816), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(190), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(188), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(717), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [4670] = 21,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    ACTIONS(85), 1,
+      sym_interpolation,
+    ACTIONS(164), 1,
+      anon_sym_LPAREN,
+    ACTIONS(186), 1,
+      anon_sym_RBRACE,
+    STATE(815), 1,
+      sym_map_entry,
+    STATE(816), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(194), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(192), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(1037), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [4751] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(198), 1,
+      anon_sym_DOT,
+    ACTIONS(200), 1,
+      sym_keyword_desc,
+    ACTIONS(196), 35,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_GT,
+      sym_keyword_add,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_as,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_finalfunc,
+      sym_keyword_group,
+      sym_keyword_initcond,
+      sym_keyword_insert,
+      sym_keyword_json,
+      sym_keyword_language,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_primary,
+      sym_keyword_rename,
+      sym_keyword_select,
+      sym_keyword_set,
+      sym_keyword_static,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+      sym_keyword_using,
+      sym_keyword_where,
+      sym_keyword_with,
+  [4798] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    ACTIONS(85), 1,
+      sym_interpolation,
+    STATE(505), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(204), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(202), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(881), 12,
+      sym_column_reference,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [4874] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(53), 1,
+      sym_interpolation,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    ACTIONS(210), 1,
+      sym_keyword_key,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(208), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(206), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(144), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [4952] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    ACTIONS(85), 1,
+      sym_interpolation,
+    ACTIONS(164), 1,
+      anon_sym_LPAREN,
+    STATE(816), 1,
+      sym_identifier,
+    STATE(850), 1,
+      sym_map_entry,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(194), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(192), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(1037), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [5030] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    ACTIONS(164), 1,
+      anon_sym_LPAREN,
+    ACTIONS(212), 1,
+      anon_sym_RPAREN,
+    ACTIONS(214), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(218), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(216), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(760), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [5108] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(53), 1,
+      sym_interpolation,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    ACTIONS(220), 1,
+      anon_sym_RBRACK,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(224), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(222), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(809), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [5186] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(228), 2,
+      anon_sym_DASH,
+      sym_keyword_desc,
+    ACTIONS(226), 34,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_as,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_default,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_from,
+      sym_keyword_group,
+      sym_keyword_if,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_set,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+      sym_keyword_using,
+      sym_keyword_where,
+  [5230] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    ACTIONS(164), 1,
+      anon_sym_LPAREN,
+    ACTIONS(214), 1,
+      sym_interpolation,
+    ACTIONS(230), 1,
+      anon_sym_RPAREN,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(234), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(232), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(836), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [5308] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(238), 1,
+      sym_keyword_desc,
+    ACTIONS(236), 35,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_GT,
+      sym_keyword_add,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_as,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_finalfunc,
+      sym_keyword_group,
+      sym_keyword_initcond,
+      sym_keyword_insert,
+      sym_keyword_json,
+      sym_keyword_language,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_primary,
+      sym_keyword_rename,
+      sym_keyword_select,
+      sym_keyword_set,
+      sym_keyword_static,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+      sym_keyword_using,
+      sym_keyword_where,
+      sym_keyword_with,
+  [5352] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 2,
+      anon_sym_DASH,
+      sym_keyword_desc,
+    ACTIONS(240), 34,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_as,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_default,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_from,
+      sym_keyword_group,
+      sym_keyword_if,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_set,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+      sym_keyword_using,
+      sym_keyword_where,
+  [5396] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(53), 1,
+      sym_interpolation,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(246), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(244), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(432), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [5471] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(53), 1,
+      sym_interpolation,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(250), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(248), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(712), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [5546] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(53), 1,
+      sym_interpolation,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(254), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(252), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(251), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [5621] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(53), 1,
+      sym_interpolation,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(258), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(256), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(870), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [5696] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    ACTIONS(164), 1,
+      anon_sym_LPAREN,
+    ACTIONS(214), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(262), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(260), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(837), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [5771] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    ACTIONS(164), 1,
+      anon_sym_LPAREN,
+    ACTIONS(214), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(266), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(264), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(752), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [5846] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(53), 1,
+      sym_interpolation,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(270), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(268), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(413), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [5921] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    ACTIONS(85), 1,
+      sym_interpolation,
+    ACTIONS(272), 1,
+      anon_sym_LPAREN,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(276), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(274), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(941), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [5996] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(53), 1,
+      sym_interpolation,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(280), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(278), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(483), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [6071] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(53), 1,
+      sym_interpolation,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(284), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(282), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(422), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [6146] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(53), 1,
+      sym_interpolation,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(288), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(286), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(431), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [6221] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(53), 1,
+      sym_interpolation,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(292), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(290), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(146), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [6296] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(53), 1,
+      sym_interpolation,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(296), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(294), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(435), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [6371] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    ACTIONS(85), 1,
+      sym_interpolation,
+    STATE(236), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(300), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(298), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(273), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [6446] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(53), 1,
+      sym_interpolation,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(304), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(302), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(443), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [6521] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(53), 1,
+      sym_interpolation,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(308), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(306), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(447), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [6596] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(53), 1,
+      sym_interpolation,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(312), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(310), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(448), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [6671] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(53), 1,
+      sym_interpolation,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(316), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(314), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(449), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [6746] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(53), 1,
+      sym_interpolation,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(320), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(318), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(116), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [6821] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(53), 1,
+      sym_interpolation,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(324), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(322), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(457), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [6896] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(53), 1,
+      sym_interpolation,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(328), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(326), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(459), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [6971] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(53), 1,
+      sym_interpolation,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(332), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(330), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(461), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [7046] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(53), 1,
+      sym_interpolation,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(336), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(334), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(462), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [7121] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(53), 1,
+      sym_interpolation,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(340), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(338), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(466), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [7196] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(53), 1,
+      sym_interpolation,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(344), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(342), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(467), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [7271] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(53), 1,
+      sym_interpolation,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(348), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(346), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(468), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [7346] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(53), 1,
+      sym_interpolation,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(352), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(350), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(471), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [7421] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(53), 1,
+      sym_interpolation,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(356), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(354), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(473), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [7496] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(53), 1,
+      sym_interpolation,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(360), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(358), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(474), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [7571] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(53), 1,
+      sym_interpolation,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(364), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(362), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(475), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [7646] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(53), 1,
+      sym_interpolation,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(368), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(366), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(478), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [7721] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(53), 1,
+      sym_interpolation,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(372), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(370), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(480), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [7796] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(53), 1,
+      sym_interpolation,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(376), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(374), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(355), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [7871] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(35), 1,
+      anon_sym_LPAREN,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(53), 1,
+      sym_interpolation,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(380), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(378), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(149), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [7946] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(81), 1,
+      anon_sym_DASH,
+    ACTIONS(228), 1,
+      sym_keyword_desc,
+    ACTIONS(79), 5,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DOT,
+      anon_sym_PLUS,
+      anon_sym_COLON,
+    ACTIONS(226), 28,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_RBRACE,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_as,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_from,
+      sym_keyword_group,
+      sym_keyword_if,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+      sym_keyword_where,
+  [7993] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    ACTIONS(85), 1,
+      sym_interpolation,
+    ACTIONS(272), 1,
+      anon_sym_LPAREN,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(320), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(318), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(116), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [8068] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    ACTIONS(164), 1,
+      anon_sym_LPAREN,
+    ACTIONS(214), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(320), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(318), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(116), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [8143] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      anon_sym_DASH,
+    ACTIONS(45), 1,
+      anon_sym_LBRACE,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(49), 1,
+      anon_sym_QMARK,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(63), 1,
+      aux_sym_float_token2,
+    ACTIONS(73), 1,
+      sym_keyword_null,
+    ACTIONS(164), 1,
+      anon_sym_LPAREN,
+    ACTIONS(214), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1053), 1,
+      sym_qualified_name,
+    ACTIONS(65), 2,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+    ACTIONS(250), 2,
+      sym_integer,
+      sym_duration,
+    ACTIONS(61), 3,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+    ACTIONS(248), 4,
+      sym_string_literal,
+      sym_dollar_string,
+      sym_uuid,
+      sym_blob,
+    STATE(712), 11,
+      sym__term,
+      sym__literal,
+      sym_function_call,
+      sym_type_hint,
+      sym_list_literal,
+      sym_set_literal,
+      sym_map_literal,
+      sym_tuple_literal,
+      sym_bind_marker,
+      sym_float,
+      sym_boolean,
+  [8218] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(384), 2,
+      anon_sym_DASH,
+      sym_keyword_desc,
+    ACTIONS(382), 31,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_as,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_from,
+      sym_keyword_group,
+      sym_keyword_if,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+      sym_keyword_where,
+  [8259] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(388), 2,
+      anon_sym_DASH,
+      sym_keyword_desc,
+    ACTIONS(386), 31,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_as,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_from,
+      sym_keyword_group,
+      sym_keyword_if,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+      sym_keyword_where,
+  [8300] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(392), 2,
+      anon_sym_DASH,
+      sym_keyword_desc,
+    ACTIONS(390), 31,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_as,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_from,
+      sym_keyword_group,
+      sym_keyword_if,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+      sym_keyword_where,
+  [8341] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(396), 2,
+      anon_sym_DASH,
+      sym_keyword_desc,
+    ACTIONS(394), 31,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_as,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_from,
+      sym_keyword_group,
+      sym_keyword_if,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+      sym_keyword_where,
+  [8382] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(400), 2,
+      anon_sym_DASH,
+      sym_keyword_desc,
+    ACTIONS(398), 31,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_as,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_from,
+      sym_keyword_group,
+      sym_keyword_if,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+      sym_keyword_where,
+  [8423] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(404), 2,
+      anon_sym_DASH,
+      sym_keyword_desc,
+    ACTIONS(402), 31,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_as,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_from,
+      sym_keyword_group,
+      sym_keyword_if,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+      sym_keyword_where,
+  [8464] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(408), 2,
+      anon_sym_DASH,
+      sym_keyword_desc,
+    ACTIONS(406), 31,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_as,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_from,
+      sym_keyword_group,
+      sym_keyword_if,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+      sym_keyword_where,
+  [8505] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(412), 2,
+      anon_sym_DASH,
+      sym_keyword_desc,
+    ACTIONS(410), 31,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_as,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_from,
+      sym_keyword_group,
+      sym_keyword_if,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+      sym_keyword_where,
+  [8546] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(416), 2,
+      anon_sym_DASH,
+      sym_keyword_desc,
+    ACTIONS(414), 31,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_as,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_from,
+      sym_keyword_group,
+      sym_keyword_if,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+      sym_keyword_where,
+  [8587] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(420), 2,
+      anon_sym_DASH,
+      sym_keyword_desc,
+    ACTIONS(418), 31,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_as,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_from,
+      sym_keyword_group,
+      sym_keyword_if,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+      sym_keyword_where,
+  [8628] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(424), 2,
+      anon_sym_DASH,
+      sym_keyword_desc,
+    ACTIONS(422), 31,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_as,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_from,
+      sym_keyword_group,
+      sym_keyword_if,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+      sym_keyword_where,
+  [8669] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(428), 2,
+      anon_sym_DASH,
+      sym_keyword_desc,
+    ACTIONS(426), 31,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_as,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_from,
+      sym_keyword_group,
+      sym_keyword_if,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+      sym_keyword_where,
+  [8710] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(432), 2,
+      anon_sym_DASH,
+      sym_keyword_desc,
+    ACTIONS(430), 31,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_as,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_from,
+      sym_keyword_group,
+      sym_keyword_if,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+      sym_keyword_where,
+  [8751] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(436), 2,
+      anon_sym_DASH,
+      sym_keyword_desc,
+    ACTIONS(434), 31,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_as,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_from,
+      sym_keyword_group,
+      sym_keyword_if,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+      sym_keyword_where,
+  [8792] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(440), 2,
+      anon_sym_DASH,
+      sym_keyword_desc,
+    ACTIONS(438), 31,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_as,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_from,
+      sym_keyword_group,
+      sym_keyword_if,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+      sym_keyword_where,
+  [8833] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(444), 2,
+      anon_sym_DASH,
+      sym_keyword_desc,
+    ACTIONS(442), 31,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_as,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_from,
+      sym_keyword_group,
+      sym_keyword_if,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+      sym_keyword_where,
+  [8874] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(448), 2,
+      anon_sym_DASH,
+      sym_keyword_desc,
+    ACTIONS(446), 31,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_as,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_from,
+      sym_keyword_group,
+      sym_keyword_if,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+      sym_keyword_where,
+  [8915] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(81), 2,
+      aux_sym_native_type_token14,
+      sym__unquoted_identifier,
+    ACTIONS(79), 29,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_quoted_identifier,
+      sym_interpolation,
+      sym_string_literal,
+      sym_keyword_counter,
+      sym_keyword_frozen,
+      sym_keyword_list,
+      sym_keyword_map,
+      sym_keyword_set,
+      sym_keyword_tuple,
+      sym_keyword_vector,
+      sym_keyword_timestamp,
+  [8954] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(81), 2,
+      aux_sym_native_type_token14,
+      sym__unquoted_identifier,
+    ACTIONS(79), 29,
+      aux_sym_native_type_token1,
+      aux_sym_native_type_token2,
+      aux_sym_native_type_token3,
+      aux_sym_native_type_token4,
+      aux_sym_native_type_token5,
+      aux_sym_native_type_token6,
+      aux_sym_native_type_token7,
+      aux_sym_native_type_token8,
+      aux_sym_native_type_token9,
+      aux_sym_native_type_token10,
+      aux_sym_native_type_token11,
+      aux_sym_native_type_token12,
+      aux_sym_native_type_token13,
+      aux_sym_native_type_token15,
+      aux_sym_native_type_token16,
+      aux_sym_native_type_token17,
+      aux_sym_native_type_token18,
+      aux_sym_native_type_token19,
+      sym_quoted_identifier,
+      sym_interpolation,
+      sym_string_literal,
+      sym_keyword_counter,
+      sym_keyword_frozen,
+      sym_keyword_list,
+      sym_keyword_map,
+      sym_keyword_set,
+      sym_keyword_tuple,
+      sym_keyword_vector,
+      sym_keyword_timestamp,
+  [8993] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(452), 1,
+      sym_keyword_allow,
+    ACTIONS(454), 1,
+      sym_keyword_desc,
+    ACTIONS(456), 1,
+      sym_keyword_group,
+    ACTIONS(458), 1,
+      sym_keyword_limit,
+    ACTIONS(460), 1,
+      sym_keyword_order,
+    ACTIONS(462), 1,
+      sym_keyword_per,
+    ACTIONS(464), 1,
+      sym_keyword_where,
+    STATE(147), 1,
+      sym_where_clause,
+    STATE(165), 1,
+      sym_group_by_clause,
+    STATE(189), 1,
+      sym_order_by_clause,
+    STATE(220), 1,
+      sym_per_partition_limit_clause,
+    STATE(298), 1,
+      sym_limit_clause,
+    ACTIONS(450), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [9048] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(456), 1,
+      sym_keyword_group,
+    ACTIONS(458), 1,
+      sym_keyword_limit,
+    ACTIONS(460), 1,
+      sym_keyword_order,
+    ACTIONS(462), 1,
+      sym_keyword_per,
+    ACTIONS(464), 1,
+      sym_keyword_where,
+    ACTIONS(468), 1,
+      sym_keyword_allow,
+    ACTIONS(470), 1,
+      sym_keyword_desc,
+    STATE(142), 1,
+      sym_where_clause,
+    STATE(163), 1,
+      sym_group_by_clause,
+    STATE(200), 1,
+      sym_order_by_clause,
+    STATE(238), 1,
+      sym_per_partition_limit_clause,
+    STATE(334), 1,
+      sym_limit_clause,
+    ACTIONS(466), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [9103] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(456), 1,
+      sym_keyword_group,
+    ACTIONS(458), 1,
+      sym_keyword_limit,
+    ACTIONS(460), 1,
+      sym_keyword_order,
+    ACTIONS(462), 1,
+      sym_keyword_per,
+    ACTIONS(464), 1,
+      sym_keyword_where,
+    ACTIONS(474), 1,
+      sym_keyword_allow,
+    ACTIONS(476), 1,
+      sym_keyword_desc,
+    STATE(141), 1,
+      sym_where_clause,
+    STATE(171), 1,
+      sym_group_by_clause,
+    STATE(197), 1,
+      sym_order_by_clause,
+    STATE(240), 1,
+      sym_per_partition_limit_clause,
+    STATE(349), 1,
+      sym_limit_clause,
+    ACTIONS(472), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [9158] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(480), 1,
+      sym_keyword_and,
+    ACTIONS(482), 1,
+      sym_keyword_desc,
+    STATE(138), 1,
+      aux_sym_where_clause_repeat1,
+    ACTIONS(478), 21,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_group,
+      sym_keyword_if,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [9194] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(486), 1,
+      sym_keyword_and,
+    ACTIONS(489), 1,
+      sym_keyword_desc,
+    STATE(138), 1,
+      aux_sym_where_clause_repeat1,
+    ACTIONS(484), 21,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_group,
+      sym_keyword_if,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [9230] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(81), 1,
+      sym_keyword_desc,
+    ACTIONS(226), 1,
+      anon_sym_RBRACK,
+    ACTIONS(79), 22,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_as,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_from,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+      sym_keyword_where,
+  [9264] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(480), 1,
+      sym_keyword_and,
+    ACTIONS(493), 1,
+      sym_keyword_desc,
+    STATE(137), 1,
+      aux_sym_where_clause_repeat1,
+    ACTIONS(491), 21,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_group,
+      sym_keyword_if,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [9300] = 12,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(456), 1,
+      sym_keyword_group,
+    ACTIONS(458), 1,
+      sym_keyword_limit,
+    ACTIONS(460), 1,
+      sym_keyword_order,
+    ACTIONS(462), 1,
+      sym_keyword_per,
+    ACTIONS(497), 1,
+      sym_keyword_allow,
+    ACTIONS(499), 1,
+      sym_keyword_desc,
+    STATE(167), 1,
+      sym_group_by_clause,
+    STATE(184), 1,
+      sym_order_by_clause,
+    STATE(242), 1,
+      sym_per_partition_limit_clause,
+    STATE(347), 1,
+      sym_limit_clause,
+    ACTIONS(495), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [9349] = 12,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(456), 1,
+      sym_keyword_group,
+    ACTIONS(458), 1,
+      sym_keyword_limit,
+    ACTIONS(460), 1,
+      sym_keyword_order,
+    ACTIONS(462), 1,
+      sym_keyword_per,
+    ACTIONS(503), 1,
+      sym_keyword_allow,
+    ACTIONS(505), 1,
+      sym_keyword_desc,
+    STATE(170), 1,
+      sym_group_by_clause,
+    STATE(194), 1,
+      sym_order_by_clause,
+    STATE(233), 1,
+      sym_per_partition_limit_clause,
+    STATE(324), 1,
+      sym_limit_clause,
+    ACTIONS(501), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [9398] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(489), 1,
+      sym_keyword_desc,
+    ACTIONS(484), 22,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_group,
+      sym_keyword_if,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [9429] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(509), 1,
+      sym_keyword_desc,
+    ACTIONS(507), 22,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_group,
+      sym_keyword_if,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [9460] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(513), 1,
+      sym_keyword_desc,
+    ACTIONS(511), 22,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_group,
+      sym_keyword_if,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [9491] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(517), 1,
+      sym_keyword_desc,
+    ACTIONS(515), 22,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_group,
+      sym_keyword_if,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [9522] = 12,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(456), 1,
+      sym_keyword_group,
+    ACTIONS(458), 1,
+      sym_keyword_limit,
+    ACTIONS(460), 1,
+      sym_keyword_order,
+    ACTIONS(462), 1,
+      sym_keyword_per,
+    ACTIONS(521), 1,
+      sym_keyword_allow,
+    ACTIONS(523), 1,
+      sym_keyword_desc,
+    STATE(162), 1,
+      sym_group_by_clause,
+    STATE(203), 1,
+      sym_order_by_clause,
+    STATE(241), 1,
+      sym_per_partition_limit_clause,
+    STATE(343), 1,
+      sym_limit_clause,
+    ACTIONS(519), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [9571] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(527), 1,
+      sym_keyword_desc,
+    ACTIONS(525), 22,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_group,
+      sym_keyword_if,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [9602] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(531), 1,
+      sym_keyword_desc,
+    ACTIONS(529), 22,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_group,
+      sym_keyword_if,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [9633] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(535), 1,
+      sym_keyword_desc,
+    ACTIONS(533), 22,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_group,
+      sym_keyword_if,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [9664] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(539), 1,
+      sym_keyword_desc,
+    ACTIONS(537), 22,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_group,
+      sym_keyword_if,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [9695] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(543), 1,
+      sym_keyword_desc,
+    ACTIONS(541), 21,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_GT,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_finalfunc,
+      sym_keyword_initcond,
+      sym_keyword_insert,
+      sym_keyword_language,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_static,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [9725] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(547), 1,
+      sym_keyword_desc,
+    ACTIONS(545), 21,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_GT,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_finalfunc,
+      sym_keyword_initcond,
+      sym_keyword_insert,
+      sym_keyword_language,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_static,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [9755] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(551), 1,
+      sym_keyword_desc,
+    ACTIONS(549), 21,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_GT,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_finalfunc,
+      sym_keyword_initcond,
+      sym_keyword_insert,
+      sym_keyword_language,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_static,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [9785] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(555), 1,
+      sym_keyword_desc,
+    ACTIONS(553), 21,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_GT,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_finalfunc,
+      sym_keyword_initcond,
+      sym_keyword_insert,
+      sym_keyword_language,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_static,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [9815] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(557), 5,
+      anon_sym_DASH,
+      sym__unquoted_identifier,
+      sym_integer,
+      aux_sym_float_token2,
+      sym_duration,
+    ACTIONS(559), 17,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+      anon_sym_LBRACE,
+      anon_sym_COLON,
+      anon_sym_QMARK,
+      sym_quoted_identifier,
+      sym_interpolation,
+      sym_string_literal,
+      sym_dollar_string,
+      aux_sym_float_token1,
+      aux_sym_float_token3,
+      aux_sym_float_token4,
+      aux_sym_boolean_token1,
+      aux_sym_boolean_token2,
+      sym_uuid,
+      sym_blob,
+      sym_keyword_null,
+  [9845] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(563), 1,
+      sym_keyword_desc,
+    ACTIONS(561), 21,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_GT,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_finalfunc,
+      sym_keyword_initcond,
+      sym_keyword_insert,
+      sym_keyword_language,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_static,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [9875] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(567), 1,
+      sym_keyword_desc,
+    ACTIONS(565), 21,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_GT,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_finalfunc,
+      sym_keyword_initcond,
+      sym_keyword_insert,
+      sym_keyword_language,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_static,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [9905] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(571), 1,
+      sym_keyword_desc,
+    ACTIONS(569), 21,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_GT,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_finalfunc,
+      sym_keyword_initcond,
+      sym_keyword_insert,
+      sym_keyword_language,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_static,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [9935] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(575), 1,
+      sym_keyword_desc,
+    ACTIONS(573), 21,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_GT,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_finalfunc,
+      sym_keyword_initcond,
+      sym_keyword_insert,
+      sym_keyword_language,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_static,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [9965] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(579), 1,
+      sym_keyword_desc,
+    ACTIONS(577), 21,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_GT,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_finalfunc,
+      sym_keyword_initcond,
+      sym_keyword_insert,
+      sym_keyword_language,
+      sym_keyword_primary,
+      sym_keyword_select,
+      sym_keyword_static,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [9995] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(458), 1,
+      sym_keyword_limit,
+    ACTIONS(460), 1,
+      sym_keyword_order,
+    ACTIONS(462), 1,
+      sym_keyword_per,
+    ACTIONS(583), 1,
+      sym_keyword_allow,
+    ACTIONS(585), 1,
+      sym_keyword_desc,
+    STATE(195), 1,
+      sym_order_by_clause,
+    STATE(223), 1,
+      sym_per_partition_limit_clause,
+    STATE(352), 1,
+      sym_limit_clause,
+    ACTIONS(581), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [10038] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(458), 1,
+      sym_keyword_limit,
+    ACTIONS(460), 1,
+      sym_keyword_order,
+    ACTIONS(462), 1,
+      sym_keyword_per,
+    ACTIONS(503), 1,
+      sym_keyword_allow,
+    ACTIONS(505), 1,
+      sym_keyword_desc,
+    STATE(194), 1,
+      sym_order_by_clause,
+    STATE(233), 1,
+      sym_per_partition_limit_clause,
+    STATE(324), 1,
+      sym_limit_clause,
+    ACTIONS(501), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [10081] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(589), 1,
+      anon_sym_DOT,
+    STATE(164), 1,
+      aux_sym_column_reference_repeat1,
+    ACTIONS(592), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(587), 15,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS_EQ,
+      anon_sym_DASH_EQ,
+      sym_keyword_as,
+      sym_keyword_contains,
+      sym_keyword_from,
+      sym_keyword_in,
+      sym_keyword_is,
+      sym_keyword_like,
+      sym_keyword_where,
+  [10114] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(458), 1,
+      sym_keyword_limit,
+    ACTIONS(460), 1,
+      sym_keyword_order,
+    ACTIONS(462), 1,
+      sym_keyword_per,
+    ACTIONS(521), 1,
+      sym_keyword_allow,
+    ACTIONS(523), 1,
+      sym_keyword_desc,
+    STATE(203), 1,
+      sym_order_by_clause,
+    STATE(241), 1,
+      sym_per_partition_limit_clause,
+    STATE(343), 1,
+      sym_limit_clause,
+    ACTIONS(519), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [10157] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(596), 1,
+      anon_sym_DOT,
+    STATE(164), 1,
+      aux_sym_column_reference_repeat1,
+    ACTIONS(598), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(594), 15,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS_EQ,
+      anon_sym_DASH_EQ,
+      sym_keyword_as,
+      sym_keyword_contains,
+      sym_keyword_from,
+      sym_keyword_in,
+      sym_keyword_is,
+      sym_keyword_like,
+      sym_keyword_where,
+  [10190] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(458), 1,
+      sym_keyword_limit,
+    ACTIONS(460), 1,
+      sym_keyword_order,
+    ACTIONS(462), 1,
+      sym_keyword_per,
+    ACTIONS(602), 1,
+      sym_keyword_allow,
+    ACTIONS(604), 1,
+      sym_keyword_desc,
+    STATE(181), 1,
+      sym_order_by_clause,
+    STATE(224), 1,
+      sym_per_partition_limit_clause,
+    STATE(340), 1,
+      sym_limit_clause,
+    ACTIONS(600), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [10233] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(608), 1,
+      anon_sym_COMMA,
+    ACTIONS(611), 1,
+      sym_keyword_desc,
+    STATE(168), 1,
+      aux_sym_partition_key_repeat1,
+    ACTIONS(606), 18,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_RPAREN,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [10266] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(615), 2,
+      sym__unquoted_identifier,
+      sym_keyword_desc,
+    ACTIONS(613), 19,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      sym_quoted_identifier,
+      sym_interpolation,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_on,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+      sym_keyword_using,
+  [10295] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(458), 1,
+      sym_keyword_limit,
+    ACTIONS(460), 1,
+      sym_keyword_order,
+    ACTIONS(462), 1,
+      sym_keyword_per,
+    ACTIONS(619), 1,
+      sym_keyword_allow,
+    ACTIONS(621), 1,
+      sym_keyword_desc,
+    STATE(199), 1,
+      sym_order_by_clause,
+    STATE(237), 1,
+      sym_per_partition_limit_clause,
+    STATE(335), 1,
+      sym_limit_clause,
+    ACTIONS(617), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [10338] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(458), 1,
+      sym_keyword_limit,
+    ACTIONS(460), 1,
+      sym_keyword_order,
+    ACTIONS(462), 1,
+      sym_keyword_per,
+    ACTIONS(497), 1,
+      sym_keyword_allow,
+    ACTIONS(499), 1,
+      sym_keyword_desc,
+    STATE(184), 1,
+      sym_order_by_clause,
+    STATE(242), 1,
+      sym_per_partition_limit_clause,
+    STATE(347), 1,
+      sym_limit_clause,
+    ACTIONS(495), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [10381] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(625), 1,
+      anon_sym_COMMA,
+    ACTIONS(627), 1,
+      sym_keyword_desc,
+    STATE(174), 1,
+      aux_sym_partition_key_repeat1,
+    ACTIONS(623), 17,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [10413] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(611), 1,
+      sym_keyword_desc,
+    ACTIONS(606), 19,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [10441] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(625), 1,
+      anon_sym_COMMA,
+    ACTIONS(631), 1,
+      sym_keyword_desc,
+    STATE(168), 1,
+      aux_sym_partition_key_repeat1,
+    ACTIONS(629), 17,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_order,
+      sym_keyword_per,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [10473] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(592), 4,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(587), 16,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_EQ,
+      anon_sym_DOT,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS_EQ,
+      anon_sym_DASH_EQ,
+      sym_keyword_as,
+      sym_keyword_contains,
+      sym_keyword_from,
+      sym_keyword_in,
+      sym_keyword_is,
+      sym_keyword_like,
+      sym_keyword_where,
+  [10501] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(635), 1,
+      sym_keyword_default,
+    ACTIONS(637), 1,
+      sym_keyword_desc,
+    ACTIONS(639), 1,
+      sym_keyword_if,
+    ACTIONS(641), 1,
+      sym_keyword_using,
+    STATE(232), 1,
+      sym_if_not_exists,
+    STATE(311), 1,
+      sym_using_clause,
+    ACTIONS(633), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [10539] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(645), 1,
+      sym_keyword_desc,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(366), 1,
+      sym_qualified_name,
+    ACTIONS(643), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [10576] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(649), 1,
+      sym_keyword_asc,
+    ACTIONS(651), 1,
+      sym_keyword_desc,
+    ACTIONS(647), 17,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_per,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [10605] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(655), 1,
+      anon_sym_COMMA,
+    ACTIONS(657), 1,
+      sym_keyword_desc,
+    STATE(183), 1,
+      aux_sym_clustering_order_repeat1,
+    ACTIONS(653), 16,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_per,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [10636] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(661), 1,
+      sym_keyword_desc,
+    ACTIONS(659), 18,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_per,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [10663] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(458), 1,
+      sym_keyword_limit,
+    ACTIONS(462), 1,
+      sym_keyword_per,
+    ACTIONS(665), 1,
+      sym_keyword_allow,
+    ACTIONS(667), 1,
+      sym_keyword_desc,
+    STATE(226), 1,
+      sym_per_partition_limit_clause,
+    STATE(330), 1,
+      sym_limit_clause,
+    ACTIONS(663), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [10700] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(671), 1,
+      sym_keyword_desc,
+    ACTIONS(669), 18,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_per,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [10727] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(671), 1,
+      sym_keyword_desc,
+    ACTIONS(673), 1,
+      anon_sym_COMMA,
+    STATE(183), 1,
+      aux_sym_clustering_order_repeat1,
+    ACTIONS(669), 16,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_per,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [10758] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(458), 1,
+      sym_keyword_limit,
+    ACTIONS(462), 1,
+      sym_keyword_per,
+    ACTIONS(602), 1,
+      sym_keyword_allow,
+    ACTIONS(604), 1,
+      sym_keyword_desc,
+    STATE(224), 1,
+      sym_per_partition_limit_clause,
+    STATE(340), 1,
+      sym_limit_clause,
+    ACTIONS(600), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [10795] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(678), 1,
+      sym_keyword_desc,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(424), 1,
+      sym_qualified_name,
+    ACTIONS(676), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [10832] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(639), 1,
+      sym_keyword_if,
+    ACTIONS(641), 1,
+      sym_keyword_using,
+    ACTIONS(682), 1,
+      sym_keyword_desc,
+    STATE(227), 1,
+      sym_if_not_exists,
+    STATE(283), 1,
+      sym_using_clause,
+    ACTIONS(680), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [10867] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(686), 1,
+      sym_keyword_and,
+    ACTIONS(689), 1,
+      sym_keyword_desc,
+    STATE(187), 1,
+      aux_sym_using_clause_repeat1,
+    ACTIONS(684), 16,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_set,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+      sym_keyword_where,
+  [10898] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(693), 1,
+      sym_keyword_and,
+    ACTIONS(695), 1,
+      sym_keyword_desc,
+    STATE(187), 1,
+      aux_sym_using_clause_repeat1,
+    ACTIONS(691), 16,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_set,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+      sym_keyword_where,
+  [10929] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(458), 1,
+      sym_keyword_limit,
+    ACTIONS(462), 1,
+      sym_keyword_per,
+    ACTIONS(521), 1,
+      sym_keyword_allow,
+    ACTIONS(523), 1,
+      sym_keyword_desc,
+    STATE(241), 1,
+      sym_per_partition_limit_clause,
+    STATE(343), 1,
+      sym_limit_clause,
+    ACTIONS(519), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [10966] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(639), 1,
+      sym_keyword_if,
+    ACTIONS(641), 1,
+      sym_keyword_using,
+    ACTIONS(699), 1,
+      sym_keyword_desc,
+    STATE(229), 1,
+      sym_if_not_exists,
+    STATE(303), 1,
+      sym_using_clause,
+    ACTIONS(697), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [11001] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(639), 1,
+      sym_keyword_if,
+    ACTIONS(641), 1,
+      sym_keyword_using,
+    ACTIONS(703), 1,
+      sym_keyword_desc,
+    STATE(234), 1,
+      sym_if_not_exists,
+    STATE(316), 1,
+      sym_using_clause,
+    ACTIONS(701), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [11036] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(639), 1,
+      sym_keyword_if,
+    ACTIONS(641), 1,
+      sym_keyword_using,
+    ACTIONS(707), 1,
+      sym_keyword_desc,
+    STATE(230), 1,
+      sym_if_not_exists,
+    STATE(305), 1,
+      sym_using_clause,
+    ACTIONS(705), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [11071] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(639), 1,
+      sym_keyword_if,
+    ACTIONS(641), 1,
+      sym_keyword_using,
+    ACTIONS(711), 1,
+      sym_keyword_desc,
+    STATE(235), 1,
+      sym_if_not_exists,
+    STATE(322), 1,
+      sym_using_clause,
+    ACTIONS(709), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [11106] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(458), 1,
+      sym_keyword_limit,
+    ACTIONS(462), 1,
+      sym_keyword_per,
+    ACTIONS(619), 1,
+      sym_keyword_allow,
+    ACTIONS(621), 1,
+      sym_keyword_desc,
+    STATE(237), 1,
+      sym_per_partition_limit_clause,
+    STATE(335), 1,
+      sym_limit_clause,
+    ACTIONS(617), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [11143] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(458), 1,
+      sym_keyword_limit,
+    ACTIONS(462), 1,
+      sym_keyword_per,
+    ACTIONS(715), 1,
+      sym_keyword_allow,
+    ACTIONS(717), 1,
+      sym_keyword_desc,
+    STATE(219), 1,
+      sym_per_partition_limit_clause,
+    STATE(286), 1,
+      sym_limit_clause,
+    ACTIONS(713), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [11180] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(721), 2,
+      sym__unquoted_identifier,
+      sym_keyword_desc,
+    ACTIONS(719), 17,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      sym_quoted_identifier,
+      sym_interpolation,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [11207] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(458), 1,
+      sym_keyword_limit,
+    ACTIONS(462), 1,
+      sym_keyword_per,
+    ACTIONS(497), 1,
+      sym_keyword_allow,
+    ACTIONS(499), 1,
+      sym_keyword_desc,
+    STATE(242), 1,
+      sym_per_partition_limit_clause,
+    STATE(347), 1,
+      sym_limit_clause,
+    ACTIONS(495), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [11244] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(655), 1,
+      anon_sym_COMMA,
+    ACTIONS(725), 1,
+      sym_keyword_desc,
+    STATE(179), 1,
+      aux_sym_clustering_order_repeat1,
+    ACTIONS(723), 16,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_per,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [11275] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(458), 1,
+      sym_keyword_limit,
+    ACTIONS(462), 1,
+      sym_keyword_per,
+    ACTIONS(729), 1,
+      sym_keyword_allow,
+    ACTIONS(731), 1,
+      sym_keyword_desc,
+    STATE(222), 1,
+      sym_per_partition_limit_clause,
+    STATE(319), 1,
+      sym_limit_clause,
+    ACTIONS(727), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [11312] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(458), 1,
+      sym_keyword_limit,
+    ACTIONS(462), 1,
+      sym_keyword_per,
+    ACTIONS(503), 1,
+      sym_keyword_allow,
+    ACTIONS(505), 1,
+      sym_keyword_desc,
+    STATE(233), 1,
+      sym_per_partition_limit_clause,
+    STATE(324), 1,
+      sym_limit_clause,
+    ACTIONS(501), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [11349] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(693), 1,
+      sym_keyword_and,
+    ACTIONS(735), 1,
+      sym_keyword_desc,
+    STATE(188), 1,
+      aux_sym_using_clause_repeat1,
+    ACTIONS(733), 16,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_set,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+      sym_keyword_where,
+  [11380] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(81), 1,
+      sym_keyword_desc,
+    ACTIONS(79), 18,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_asc,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_per,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [11407] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(458), 1,
+      sym_keyword_limit,
+    ACTIONS(462), 1,
+      sym_keyword_per,
+    ACTIONS(583), 1,
+      sym_keyword_allow,
+    ACTIONS(585), 1,
+      sym_keyword_desc,
+    STATE(223), 1,
+      sym_per_partition_limit_clause,
+    STATE(352), 1,
+      sym_limit_clause,
+    ACTIONS(581), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [11444] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(81), 1,
+      sym_keyword_desc,
+    ACTIONS(79), 18,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_asc,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_per,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [11471] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(739), 1,
+      sym_keyword_desc,
+    ACTIONS(741), 1,
+      sym_keyword_primary,
+    ACTIONS(743), 1,
+      sym_keyword_static,
+    ACTIONS(737), 15,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [11501] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(747), 1,
+      sym_keyword_desc,
+    ACTIONS(749), 1,
+      sym_keyword_if,
+    STATE(344), 2,
+      sym_if_clause,
+      sym_if_exists,
+    ACTIONS(745), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [11531] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(689), 1,
+      sym_keyword_desc,
+    ACTIONS(684), 17,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_set,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+      sym_keyword_where,
+  [11557] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(749), 1,
+      sym_keyword_if,
+    ACTIONS(753), 1,
+      sym_keyword_desc,
+    STATE(345), 2,
+      sym_if_clause,
+      sym_if_exists,
+    ACTIONS(751), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [11587] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(757), 1,
+      sym_keyword_desc,
+    ACTIONS(755), 17,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_set,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+      sym_keyword_where,
+  [11613] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(749), 1,
+      sym_keyword_if,
+    ACTIONS(761), 1,
+      sym_keyword_desc,
+    STATE(301), 2,
+      sym_if_clause,
+      sym_if_exists,
+    ACTIONS(759), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [11643] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(749), 1,
+      sym_keyword_if,
+    ACTIONS(765), 1,
+      sym_keyword_desc,
+    STATE(350), 2,
+      sym_if_clause,
+      sym_if_exists,
+    ACTIONS(763), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [11673] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(749), 1,
+      sym_keyword_if,
+    ACTIONS(769), 1,
+      sym_keyword_desc,
+    STATE(317), 2,
+      sym_if_clause,
+      sym_if_exists,
+    ACTIONS(767), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [11703] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(749), 1,
+      sym_keyword_if,
+    ACTIONS(773), 1,
+      sym_keyword_desc,
+    STATE(310), 2,
+      sym_if_clause,
+      sym_if_exists,
+    ACTIONS(771), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [11733] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(749), 1,
+      sym_keyword_if,
+    ACTIONS(777), 1,
+      sym_keyword_desc,
+    STATE(278), 2,
+      sym_if_clause,
+      sym_if_exists,
+    ACTIONS(775), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [11763] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(749), 1,
+      sym_keyword_if,
+    ACTIONS(781), 1,
+      sym_keyword_desc,
+    STATE(300), 2,
+      sym_if_clause,
+      sym_if_exists,
+    ACTIONS(779), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [11793] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(749), 1,
+      sym_keyword_if,
+    ACTIONS(785), 1,
+      sym_keyword_desc,
+    STATE(313), 2,
+      sym_if_clause,
+      sym_if_exists,
+    ACTIONS(783), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [11823] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(789), 1,
+      sym_keyword_and,
+    ACTIONS(791), 1,
+      sym_keyword_desc,
+    STATE(231), 1,
+      aux_sym_if_clause_repeat1,
+    ACTIONS(787), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [11852] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(795), 1,
+      sym_keyword_desc,
+    ACTIONS(797), 1,
+      sym_keyword_primary,
+    ACTIONS(793), 15,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [11879] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(458), 1,
+      sym_keyword_limit,
+    ACTIONS(801), 1,
+      sym_keyword_allow,
+    ACTIONS(803), 1,
+      sym_keyword_desc,
+    STATE(282), 1,
+      sym_limit_clause,
+    ACTIONS(799), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [11910] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(458), 1,
+      sym_keyword_limit,
+    ACTIONS(521), 1,
+      sym_keyword_allow,
+    ACTIONS(523), 1,
+      sym_keyword_desc,
+    STATE(343), 1,
+      sym_limit_clause,
+    ACTIONS(519), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [11941] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(807), 1,
+      sym_keyword_desc,
+    ACTIONS(805), 16,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+      sym_keyword_with,
+  [11966] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(458), 1,
+      sym_keyword_limit,
+    ACTIONS(811), 1,
+      sym_keyword_allow,
+    ACTIONS(813), 1,
+      sym_keyword_desc,
+    STATE(328), 1,
+      sym_limit_clause,
+    ACTIONS(809), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [11997] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(458), 1,
+      sym_keyword_limit,
+    ACTIONS(715), 1,
+      sym_keyword_allow,
+    ACTIONS(717), 1,
+      sym_keyword_desc,
+    STATE(286), 1,
+      sym_limit_clause,
+    ACTIONS(713), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [12028] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(458), 1,
+      sym_keyword_limit,
+    ACTIONS(665), 1,
+      sym_keyword_allow,
+    ACTIONS(667), 1,
+      sym_keyword_desc,
+    STATE(330), 1,
+      sym_limit_clause,
+    ACTIONS(663), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [12059] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(817), 1,
+      sym_keyword_desc,
+    ACTIONS(815), 16,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+      sym_keyword_with,
+  [12084] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(458), 1,
+      sym_keyword_limit,
+    ACTIONS(821), 1,
+      sym_keyword_allow,
+    ACTIONS(823), 1,
+      sym_keyword_desc,
+    STATE(287), 1,
+      sym_limit_clause,
+    ACTIONS(819), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [12115] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(641), 1,
+      sym_keyword_using,
+    ACTIONS(827), 1,
+      sym_keyword_desc,
+    STATE(302), 1,
+      sym_using_clause,
+    ACTIONS(825), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [12144] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(789), 1,
+      sym_keyword_and,
+    ACTIONS(831), 1,
+      sym_keyword_desc,
+    STATE(217), 1,
+      aux_sym_if_clause_repeat1,
+    ACTIONS(829), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [12173] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(641), 1,
+      sym_keyword_using,
+    ACTIONS(835), 1,
+      sym_keyword_desc,
+    STATE(320), 1,
+      sym_using_clause,
+    ACTIONS(833), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [12202] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(641), 1,
+      sym_keyword_using,
+    ACTIONS(839), 1,
+      sym_keyword_desc,
+    STATE(321), 1,
+      sym_using_clause,
+    ACTIONS(837), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [12231] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(843), 1,
+      sym_keyword_and,
+    ACTIONS(846), 1,
+      sym_keyword_desc,
+    STATE(231), 1,
+      aux_sym_if_clause_repeat1,
+    ACTIONS(841), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [12260] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(641), 1,
+      sym_keyword_using,
+    ACTIONS(850), 1,
+      sym_keyword_desc,
+    STATE(281), 1,
+      sym_using_clause,
+    ACTIONS(848), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [12289] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(458), 1,
+      sym_keyword_limit,
+    ACTIONS(619), 1,
+      sym_keyword_allow,
+    ACTIONS(621), 1,
+      sym_keyword_desc,
+    STATE(335), 1,
+      sym_limit_clause,
+    ACTIONS(617), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [12320] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(641), 1,
+      sym_keyword_using,
+    ACTIONS(854), 1,
+      sym_keyword_desc,
+    STATE(315), 1,
+      sym_using_clause,
+    ACTIONS(852), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [12349] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(641), 1,
+      sym_keyword_using,
+    ACTIONS(858), 1,
+      sym_keyword_desc,
+    STATE(333), 1,
+      sym_using_clause,
+    ACTIONS(856), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [12378] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(196), 1,
+      anon_sym_LPAREN,
+    ACTIONS(198), 1,
+      anon_sym_DOT,
+    ACTIONS(862), 1,
+      sym_keyword_desc,
+    ACTIONS(860), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [12407] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(458), 1,
+      sym_keyword_limit,
+    ACTIONS(729), 1,
+      sym_keyword_allow,
+    ACTIONS(731), 1,
+      sym_keyword_desc,
+    STATE(319), 1,
+      sym_limit_clause,
+    ACTIONS(727), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [12438] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(458), 1,
+      sym_keyword_limit,
+    ACTIONS(503), 1,
+      sym_keyword_allow,
+    ACTIONS(505), 1,
+      sym_keyword_desc,
+    STATE(324), 1,
+      sym_limit_clause,
+    ACTIONS(501), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [12469] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(870), 1,
+      sym_keyword_materialized,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(379), 1,
+      sym_qualified_name,
+    ACTIONS(864), 2,
+      sym_keyword_aggregate,
+      sym_keyword_index,
+    ACTIONS(866), 4,
+      sym_keyword_function,
+      sym_keyword_keyspace,
+      sym_keyword_table,
+      sym_keyword_type,
+    ACTIONS(868), 5,
+      sym_keyword_functions,
+      sym_keyword_keyspaces,
+      sym_keyword_schema,
+      sym_keyword_tables,
+      sym_keyword_types,
+  [12508] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(458), 1,
+      sym_keyword_limit,
+    ACTIONS(497), 1,
+      sym_keyword_allow,
+    ACTIONS(499), 1,
+      sym_keyword_desc,
+    STATE(347), 1,
+      sym_limit_clause,
+    ACTIONS(495), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [12539] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(458), 1,
+      sym_keyword_limit,
+    ACTIONS(583), 1,
+      sym_keyword_allow,
+    ACTIONS(585), 1,
+      sym_keyword_desc,
+    STATE(352), 1,
+      sym_limit_clause,
+    ACTIONS(581), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [12570] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(458), 1,
+      sym_keyword_limit,
+    ACTIONS(602), 1,
+      sym_keyword_allow,
+    ACTIONS(604), 1,
+      sym_keyword_desc,
+    STATE(340), 1,
+      sym_limit_clause,
+    ACTIONS(600), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [12601] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(874), 1,
+      sym_keyword_desc,
+    ACTIONS(872), 15,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [12625] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(878), 1,
+      sym_keyword_desc,
+    ACTIONS(876), 15,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_limit,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [12649] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(882), 1,
+      sym_keyword_desc,
+    ACTIONS(884), 1,
+      sym_keyword_finalfunc,
+    ACTIONS(886), 1,
+      sym_keyword_initcond,
+    ACTIONS(880), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [12677] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(890), 1,
+      sym_keyword_desc,
+    ACTIONS(888), 15,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [12701] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(894), 1,
+      sym_keyword_and,
+    ACTIONS(896), 1,
+      sym_keyword_desc,
+    STATE(264), 1,
+      aux_sym_table_options_repeat1,
+    ACTIONS(892), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [12729] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(900), 1,
+      sym_keyword_and,
+    ACTIONS(903), 1,
+      sym_keyword_desc,
+    STATE(248), 1,
+      aux_sym_table_options_repeat1,
+    ACTIONS(898), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [12757] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(907), 1,
+      sym_keyword_desc,
+    ACTIONS(909), 1,
+      sym_keyword_finalfunc,
+    ACTIONS(911), 1,
+      sym_keyword_initcond,
+    ACTIONS(905), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [12785] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(915), 1,
+      sym_keyword_desc,
+    ACTIONS(917), 1,
+      sym_keyword_finalfunc,
+    ACTIONS(919), 1,
+      sym_keyword_initcond,
+    ACTIONS(913), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [12813] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(923), 1,
+      sym_keyword_desc,
+    ACTIONS(921), 15,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [12837] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(927), 1,
+      sym_keyword_desc,
+    ACTIONS(929), 1,
+      sym_keyword_finalfunc,
+    ACTIONS(931), 1,
+      sym_keyword_initcond,
+    ACTIONS(925), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [12865] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(935), 1,
+      sym_keyword_desc,
+    ACTIONS(937), 1,
+      sym_keyword_finalfunc,
+    ACTIONS(939), 1,
+      sym_keyword_initcond,
+    ACTIONS(933), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [12893] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(943), 1,
+      sym_keyword_desc,
+    ACTIONS(941), 15,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [12917] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(947), 1,
+      sym_keyword_and,
+    ACTIONS(949), 1,
+      sym_keyword_desc,
+    STATE(261), 1,
+      aux_sym__renames_repeat1,
+    ACTIONS(945), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [12945] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(846), 1,
+      sym_keyword_desc,
+    ACTIONS(841), 15,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [12969] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(953), 1,
+      sym_keyword_desc,
+    ACTIONS(955), 1,
+      sym_keyword_finalfunc,
+    ACTIONS(957), 1,
+      sym_keyword_initcond,
+    ACTIONS(951), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [12997] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(961), 1,
+      sym_keyword_and,
+    ACTIONS(963), 1,
+      sym_keyword_desc,
+    STATE(266), 1,
+      aux_sym_properties_repeat1,
+    ACTIONS(959), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13025] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(967), 1,
+      sym_keyword_desc,
+    ACTIONS(969), 1,
+      sym_keyword_finalfunc,
+    ACTIONS(971), 1,
+      sym_keyword_initcond,
+    ACTIONS(965), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13053] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(975), 1,
+      sym_keyword_desc,
+    ACTIONS(977), 1,
+      sym_keyword_finalfunc,
+    ACTIONS(979), 1,
+      sym_keyword_initcond,
+    ACTIONS(973), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13081] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(947), 1,
+      sym_keyword_and,
+    ACTIONS(983), 1,
+      sym_keyword_desc,
+    STATE(270), 1,
+      aux_sym__renames_repeat1,
+    ACTIONS(981), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13109] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(987), 1,
+      sym_keyword_desc,
+    ACTIONS(989), 1,
+      sym_keyword_finalfunc,
+    ACTIONS(991), 1,
+      sym_keyword_initcond,
+    ACTIONS(985), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13137] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(995), 1,
+      sym_keyword_and,
+    ACTIONS(998), 1,
+      sym_keyword_desc,
+    STATE(263), 1,
+      aux_sym_properties_repeat1,
+    ACTIONS(993), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13165] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(894), 1,
+      sym_keyword_and,
+    ACTIONS(1002), 1,
+      sym_keyword_desc,
+    STATE(248), 1,
+      aux_sym_table_options_repeat1,
+    ACTIONS(1000), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13193] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1006), 1,
+      sym_keyword_desc,
+    ACTIONS(1008), 1,
+      sym_keyword_finalfunc,
+    ACTIONS(1010), 1,
+      sym_keyword_initcond,
+    ACTIONS(1004), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13221] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(961), 1,
+      sym_keyword_and,
+    ACTIONS(1014), 1,
+      sym_keyword_desc,
+    STATE(263), 1,
+      aux_sym_properties_repeat1,
+    ACTIONS(1012), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13249] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1018), 1,
+      sym_keyword_desc,
+    ACTIONS(1016), 15,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13273] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1022), 1,
+      sym_keyword_desc,
+    ACTIONS(1020), 15,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13297] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1026), 1,
+      sym_keyword_desc,
+    ACTIONS(1024), 15,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13321] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1030), 1,
+      sym_keyword_and,
+    ACTIONS(1033), 1,
+      sym_keyword_desc,
+    STATE(270), 1,
+      aux_sym__renames_repeat1,
+    ACTIONS(1028), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13349] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1037), 1,
+      sym_keyword_desc,
+    ACTIONS(1039), 1,
+      sym_keyword_finalfunc,
+    ACTIONS(1041), 1,
+      sym_keyword_initcond,
+    ACTIONS(1035), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13377] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1045), 1,
+      sym_keyword_desc,
+    ACTIONS(1047), 1,
+      sym_keyword_finalfunc,
+    ACTIONS(1049), 1,
+      sym_keyword_initcond,
+    ACTIONS(1043), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13405] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(862), 1,
+      sym_keyword_desc,
+    ACTIONS(860), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13428] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1053), 1,
+      sym_keyword_desc,
+    ACTIONS(1055), 1,
+      sym_keyword_with,
+    ACTIONS(1051), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13453] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1059), 1,
+      sym_keyword_desc,
+    ACTIONS(1061), 1,
+      sym_keyword_with,
+    ACTIONS(1057), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13478] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1065), 1,
+      sym_keyword_desc,
+    ACTIONS(1067), 1,
+      sym_keyword_with,
+    ACTIONS(1063), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13503] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1071), 1,
+      sym_keyword_desc,
+    ACTIONS(1073), 1,
+      sym_keyword_using,
+    ACTIONS(1069), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13528] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(785), 1,
+      sym_keyword_desc,
+    ACTIONS(783), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13551] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1077), 1,
+      sym_keyword_desc,
+    ACTIONS(1079), 1,
+      sym_keyword_with,
+    ACTIONS(1075), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13576] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1083), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1085), 1,
+      sym_keyword_desc,
+    ACTIONS(1081), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13601] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(703), 1,
+      sym_keyword_desc,
+    ACTIONS(701), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13624] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1089), 1,
+      sym_keyword_allow,
+    ACTIONS(1091), 1,
+      sym_keyword_desc,
+    ACTIONS(1087), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13649] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(827), 1,
+      sym_keyword_desc,
+    ACTIONS(825), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13672] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1095), 1,
+      sym_keyword_desc,
+    ACTIONS(1093), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13695] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1099), 1,
+      sym_keyword_desc,
+    ACTIONS(1101), 1,
+      sym_keyword_using,
+    ACTIONS(1097), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13720] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(801), 1,
+      sym_keyword_allow,
+    ACTIONS(803), 1,
+      sym_keyword_desc,
+    ACTIONS(799), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13745] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1105), 1,
+      sym_keyword_allow,
+    ACTIONS(1107), 1,
+      sym_keyword_desc,
+    ACTIONS(1103), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13770] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1111), 1,
+      sym_keyword_desc,
+    ACTIONS(1113), 1,
+      sym_keyword_initcond,
+    ACTIONS(1109), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13795] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(596), 1,
+      anon_sym_DOT,
+    ACTIONS(1119), 1,
+      anon_sym_LBRACK,
+    STATE(166), 1,
+      aux_sym_column_reference_repeat1,
+    ACTIONS(1117), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(1115), 10,
+      anon_sym_COMMA,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS_EQ,
+      anon_sym_DASH_EQ,
+      sym_keyword_from,
+      sym_keyword_in,
+      sym_keyword_like,
+  [13824] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1123), 1,
+      sym_keyword_desc,
+    ACTIONS(1125), 1,
+      sym_keyword_with,
+    ACTIONS(1121), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13849] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1129), 1,
+      sym_keyword_desc,
+    ACTIONS(1131), 1,
+      sym_keyword_using,
+    ACTIONS(1127), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13874] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1135), 1,
+      sym_keyword_desc,
+    ACTIONS(1137), 1,
+      sym_keyword_initcond,
+    ACTIONS(1133), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13899] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(903), 1,
+      sym_keyword_desc,
+    ACTIONS(898), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13922] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1141), 1,
+      sym_keyword_desc,
+    ACTIONS(1143), 1,
+      sym_keyword_with,
+    ACTIONS(1139), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13947] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1147), 1,
+      sym_keyword_desc,
+    ACTIONS(1149), 1,
+      sym_keyword_with,
+    ACTIONS(1145), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13972] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1153), 1,
+      sym_keyword_desc,
+    ACTIONS(1155), 1,
+      sym_keyword_with,
+    ACTIONS(1151), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [13997] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(998), 1,
+      sym_keyword_desc,
+    ACTIONS(993), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14020] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(521), 1,
+      sym_keyword_allow,
+    ACTIONS(523), 1,
+      sym_keyword_desc,
+    ACTIONS(519), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14045] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1159), 1,
+      sym_keyword_desc,
+    ACTIONS(1161), 1,
+      sym_keyword_with,
+    ACTIONS(1157), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14070] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1165), 1,
+      sym_keyword_desc,
+    ACTIONS(1163), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14093] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(765), 1,
+      sym_keyword_desc,
+    ACTIONS(763), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14116] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1169), 1,
+      sym_keyword_desc,
+    ACTIONS(1167), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14139] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(835), 1,
+      sym_keyword_desc,
+    ACTIONS(833), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14162] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1173), 1,
+      sym_keyword_desc,
+    ACTIONS(1175), 1,
+      sym_keyword_with,
+    ACTIONS(1171), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14187] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(839), 1,
+      sym_keyword_desc,
+    ACTIONS(837), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14210] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1179), 1,
+      sym_keyword_desc,
+    ACTIONS(1181), 1,
+      sym_keyword_using,
+    ACTIONS(1177), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14235] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1185), 1,
+      sym_keyword_desc,
+    ACTIONS(1183), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14258] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1189), 1,
+      sym_keyword_desc,
+    ACTIONS(1191), 1,
+      sym_keyword_with,
+    ACTIONS(1187), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14283] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1195), 1,
+      sym_keyword_desc,
+    ACTIONS(1197), 1,
+      sym_keyword_initcond,
+    ACTIONS(1193), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14308] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1201), 1,
+      sym_keyword_desc,
+    ACTIONS(1199), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14331] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(850), 1,
+      sym_keyword_desc,
+    ACTIONS(848), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14354] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1205), 1,
+      sym_keyword_desc,
+    ACTIONS(1207), 1,
+      sym_keyword_initcond,
+    ACTIONS(1203), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14379] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1211), 1,
+      sym_keyword_desc,
+    ACTIONS(1209), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14402] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1215), 1,
+      sym_keyword_desc,
+    ACTIONS(1217), 1,
+      sym_keyword_with,
+    ACTIONS(1213), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14427] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1221), 1,
+      sym_keyword_desc,
+    ACTIONS(1219), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14450] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(854), 1,
+      sym_keyword_desc,
+    ACTIONS(852), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14473] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(773), 1,
+      sym_keyword_desc,
+    ACTIONS(771), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14496] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1225), 1,
+      sym_keyword_desc,
+    ACTIONS(1227), 1,
+      sym_keyword_using,
+    ACTIONS(1223), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14521] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(811), 1,
+      sym_keyword_allow,
+    ACTIONS(813), 1,
+      sym_keyword_desc,
+    ACTIONS(809), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14546] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1231), 1,
+      sym_keyword_desc,
+    ACTIONS(1229), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14569] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1235), 1,
+      sym_keyword_desc,
+    ACTIONS(1233), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14592] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(858), 1,
+      sym_keyword_desc,
+    ACTIONS(856), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14615] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1239), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1241), 1,
+      sym_keyword_desc,
+    ACTIONS(1237), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14640] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(619), 1,
+      sym_keyword_allow,
+    ACTIONS(621), 1,
+      sym_keyword_desc,
+    ACTIONS(617), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14665] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1245), 1,
+      sym_keyword_desc,
+    ACTIONS(1247), 1,
+      sym_keyword_initcond,
+    ACTIONS(1243), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14690] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1251), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1253), 1,
+      sym_keyword_desc,
+    ACTIONS(1249), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14715] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1257), 1,
+      sym_keyword_desc,
+    ACTIONS(1259), 1,
+      sym_keyword_initcond,
+    ACTIONS(1255), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14740] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1263), 1,
+      sym_keyword_allow,
+    ACTIONS(1265), 1,
+      sym_keyword_desc,
+    ACTIONS(1261), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14765] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1269), 1,
+      sym_keyword_desc,
+    ACTIONS(1271), 1,
+      sym_keyword_using,
+    ACTIONS(1267), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14790] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(821), 1,
+      sym_keyword_allow,
+    ACTIONS(823), 1,
+      sym_keyword_desc,
+    ACTIONS(819), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14815] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1275), 1,
+      sym_keyword_desc,
+    ACTIONS(1277), 1,
+      sym_keyword_initcond,
+    ACTIONS(1273), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14840] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1281), 1,
+      sym_keyword_desc,
+    ACTIONS(1279), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14863] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1285), 1,
+      sym_keyword_desc,
+    ACTIONS(1283), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14886] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(503), 1,
+      sym_keyword_allow,
+    ACTIONS(505), 1,
+      sym_keyword_desc,
+    ACTIONS(501), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14911] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(729), 1,
+      sym_keyword_allow,
+    ACTIONS(731), 1,
+      sym_keyword_desc,
+    ACTIONS(727), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14936] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1289), 1,
+      sym_keyword_desc,
+    ACTIONS(1291), 1,
+      sym_keyword_initcond,
+    ACTIONS(1287), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14961] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1295), 1,
+      sym_keyword_desc,
+    ACTIONS(1293), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_allow,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [14984] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1299), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1301), 1,
+      sym_keyword_desc,
+    ACTIONS(1297), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15009] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1305), 1,
+      sym_keyword_desc,
+    ACTIONS(1307), 1,
+      sym_keyword_initcond,
+    ACTIONS(1303), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15034] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(665), 1,
+      sym_keyword_allow,
+    ACTIONS(667), 1,
+      sym_keyword_desc,
+    ACTIONS(663), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15059] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1311), 1,
+      sym_keyword_desc,
+    ACTIONS(1313), 1,
+      sym_keyword_initcond,
+    ACTIONS(1309), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15084] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1317), 1,
+      sym_keyword_desc,
+    ACTIONS(1319), 1,
+      sym_keyword_with,
+    ACTIONS(1315), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15109] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(583), 1,
+      sym_keyword_allow,
+    ACTIONS(585), 1,
+      sym_keyword_desc,
+    ACTIONS(581), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15134] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(781), 1,
+      sym_keyword_desc,
+    ACTIONS(779), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15157] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(777), 1,
+      sym_keyword_desc,
+    ACTIONS(775), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15180] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1323), 1,
+      sym_keyword_desc,
+    ACTIONS(1325), 1,
+      sym_keyword_initcond,
+    ACTIONS(1321), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15205] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(602), 1,
+      sym_keyword_allow,
+    ACTIONS(604), 1,
+      sym_keyword_desc,
+    ACTIONS(600), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15230] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1329), 1,
+      sym_keyword_desc,
+    ACTIONS(1331), 1,
+      sym_keyword_with,
+    ACTIONS(1327), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15255] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(497), 1,
+      sym_keyword_allow,
+    ACTIONS(499), 1,
+      sym_keyword_desc,
+    ACTIONS(495), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15280] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1335), 1,
+      sym_keyword_desc,
+    ACTIONS(1333), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_apply,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15303] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1033), 1,
+      sym_keyword_desc,
+    ACTIONS(1028), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15326] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(715), 1,
+      sym_keyword_allow,
+    ACTIONS(717), 1,
+      sym_keyword_desc,
+    ACTIONS(713), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15351] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1339), 1,
+      sym_keyword_desc,
+    ACTIONS(1337), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_and,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15374] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1343), 1,
+      sym_keyword_desc,
+    ACTIONS(1345), 1,
+      sym_keyword_initcond,
+    ACTIONS(1341), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15399] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1349), 1,
+      sym_keyword_desc,
+    ACTIONS(1347), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15421] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1353), 1,
+      sym_keyword_desc,
+    ACTIONS(1351), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15443] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1357), 1,
+      sym_keyword_desc,
+    ACTIONS(1355), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15465] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1361), 1,
+      sym_keyword_desc,
+    ACTIONS(1359), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15487] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1365), 1,
+      sym_keyword_desc,
+    ACTIONS(1363), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15509] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1369), 1,
+      sym_keyword_desc,
+    ACTIONS(1367), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15531] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1373), 1,
+      sym_keyword_desc,
+    ACTIONS(1371), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15553] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(621), 1,
+      sym_keyword_desc,
+    ACTIONS(617), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15575] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1377), 1,
+      sym_keyword_desc,
+    ACTIONS(1375), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15597] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1381), 1,
+      sym_keyword_desc,
+    ACTIONS(1379), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15619] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(717), 1,
+      sym_keyword_desc,
+    ACTIONS(713), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15641] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1385), 1,
+      sym_keyword_desc,
+    ACTIONS(1383), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15663] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1389), 1,
+      sym_keyword_desc,
+    ACTIONS(1387), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15685] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1393), 1,
+      sym_keyword_desc,
+    ACTIONS(1391), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15707] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1397), 1,
+      sym_keyword_desc,
+    ACTIONS(1395), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15729] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1329), 1,
+      sym_keyword_desc,
+    ACTIONS(1327), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15751] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1401), 1,
+      sym_keyword_desc,
+    ACTIONS(1399), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15773] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1405), 1,
+      sym_keyword_desc,
+    ACTIONS(1403), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15795] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1409), 1,
+      sym_keyword_desc,
+    ACTIONS(1407), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15817] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(645), 1,
+      sym_keyword_desc,
+    ACTIONS(643), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15839] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1413), 1,
+      sym_keyword_desc,
+    ACTIONS(1411), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15861] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1417), 1,
+      sym_keyword_desc,
+    ACTIONS(1415), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15883] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1421), 1,
+      sym_keyword_desc,
+    ACTIONS(1419), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15905] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1425), 1,
+      sym_keyword_desc,
+    ACTIONS(1423), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15927] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1429), 1,
+      sym_keyword_desc,
+    ACTIONS(1427), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15949] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(731), 1,
+      sym_keyword_desc,
+    ACTIONS(727), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15971] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1433), 1,
+      sym_keyword_desc,
+    ACTIONS(1431), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [15993] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1437), 1,
+      sym_keyword_desc,
+    ACTIONS(1435), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16015] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(604), 1,
+      sym_keyword_desc,
+    ACTIONS(600), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16037] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1441), 1,
+      sym_keyword_desc,
+    ACTIONS(1439), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16059] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1445), 1,
+      sym_keyword_desc,
+    ACTIONS(1443), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16081] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1449), 1,
+      sym_keyword_desc,
+    ACTIONS(1447), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16103] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(803), 1,
+      sym_keyword_desc,
+    ACTIONS(799), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16125] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1453), 1,
+      sym_keyword_desc,
+    ACTIONS(1451), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16147] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1457), 1,
+      sym_keyword_desc,
+    ACTIONS(1455), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16169] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1159), 1,
+      sym_keyword_desc,
+    ACTIONS(1157), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16191] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1461), 1,
+      sym_keyword_desc,
+    ACTIONS(1459), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16213] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1465), 1,
+      sym_keyword_desc,
+    ACTIONS(1463), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16235] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1469), 1,
+      sym_keyword_desc,
+    ACTIONS(1467), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16257] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1473), 1,
+      sym_keyword_desc,
+    ACTIONS(1471), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16279] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1477), 1,
+      sym_keyword_desc,
+    ACTIONS(1475), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16301] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(813), 1,
+      sym_keyword_desc,
+    ACTIONS(809), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16323] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(667), 1,
+      sym_keyword_desc,
+    ACTIONS(663), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16345] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1091), 1,
+      sym_keyword_desc,
+    ACTIONS(1087), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16367] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1481), 1,
+      sym_keyword_desc,
+    ACTIONS(1479), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16389] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1485), 1,
+      sym_keyword_desc,
+    ACTIONS(1483), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16411] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1489), 1,
+      sym_keyword_desc,
+    ACTIONS(1487), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16433] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1493), 1,
+      sym_keyword_desc,
+    ACTIONS(1491), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16455] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1497), 1,
+      sym_keyword_desc,
+    ACTIONS(1495), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16477] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1501), 1,
+      sym_keyword_desc,
+    ACTIONS(1499), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16499] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1505), 1,
+      sym_keyword_desc,
+    ACTIONS(1503), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16521] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1509), 1,
+      sym_keyword_desc,
+    ACTIONS(1507), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16543] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1513), 1,
+      sym_keyword_desc,
+    ACTIONS(1511), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16565] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1265), 1,
+      sym_keyword_desc,
+    ACTIONS(1261), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16587] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(823), 1,
+      sym_keyword_desc,
+    ACTIONS(819), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16609] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1517), 1,
+      sym_keyword_desc,
+    ACTIONS(1515), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16631] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1521), 1,
+      sym_keyword_desc,
+    ACTIONS(1519), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16653] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1525), 1,
+      sym_keyword_desc,
+    ACTIONS(1523), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16675] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1529), 1,
+      sym_keyword_desc,
+    ACTIONS(1527), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16697] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1533), 1,
+      sym_keyword_desc,
+    ACTIONS(1531), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16719] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1537), 1,
+      sym_keyword_desc,
+    ACTIONS(1535), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16741] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1541), 1,
+      sym_keyword_desc,
+    ACTIONS(1539), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16763] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1545), 1,
+      sym_keyword_desc,
+    ACTIONS(1543), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16785] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1549), 1,
+      sym_keyword_desc,
+    ACTIONS(1547), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16807] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1107), 1,
+      sym_keyword_desc,
+    ACTIONS(1103), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16829] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1553), 1,
+      sym_keyword_desc,
+    ACTIONS(1551), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16851] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1557), 1,
+      sym_keyword_desc,
+    ACTIONS(1555), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16873] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1561), 1,
+      sym_keyword_desc,
+    ACTIONS(1559), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16895] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1565), 1,
+      sym_keyword_desc,
+    ACTIONS(1563), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16917] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1569), 1,
+      sym_keyword_desc,
+    ACTIONS(1567), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16939] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(585), 1,
+      sym_keyword_desc,
+    ACTIONS(581), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16961] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1573), 1,
+      sym_keyword_desc,
+    ACTIONS(1571), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [16983] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1577), 1,
+      sym_keyword_desc,
+    ACTIONS(1575), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17005] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1581), 1,
+      sym_keyword_desc,
+    ACTIONS(1579), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17027] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1585), 1,
+      sym_keyword_desc,
+    ACTIONS(1583), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17049] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1589), 1,
+      sym_keyword_desc,
+    ACTIONS(1587), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17071] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1593), 1,
+      sym_keyword_desc,
+    ACTIONS(1591), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17093] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1597), 1,
+      sym_keyword_desc,
+    ACTIONS(1595), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17115] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1601), 1,
+      sym_keyword_desc,
+    ACTIONS(1599), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17137] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1605), 1,
+      sym_keyword_desc,
+    ACTIONS(1603), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17159] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1609), 1,
+      sym_keyword_desc,
+    ACTIONS(1607), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17181] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1613), 1,
+      sym_keyword_desc,
+    ACTIONS(1611), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17203] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1617), 1,
+      sym_keyword_desc,
+    ACTIONS(1615), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17225] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1621), 1,
+      sym_keyword_desc,
+    ACTIONS(1619), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17247] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1625), 1,
+      sym_keyword_desc,
+    ACTIONS(1623), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17269] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1629), 1,
+      sym_keyword_desc,
+    ACTIONS(1627), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17291] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1633), 1,
+      sym_keyword_desc,
+    ACTIONS(1631), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17313] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1637), 1,
+      sym_keyword_desc,
+    ACTIONS(1635), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17335] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1641), 1,
+      sym_keyword_desc,
+    ACTIONS(1639), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17357] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1645), 1,
+      sym_keyword_desc,
+    ACTIONS(1643), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17379] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1649), 1,
+      sym_keyword_desc,
+    ACTIONS(1647), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17401] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1653), 1,
+      sym_keyword_desc,
+    ACTIONS(1651), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17423] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1657), 1,
+      sym_keyword_desc,
+    ACTIONS(1655), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17445] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1661), 1,
+      sym_keyword_desc,
+    ACTIONS(1659), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17467] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1665), 1,
+      sym_keyword_desc,
+    ACTIONS(1663), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17489] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1669), 1,
+      sym_keyword_desc,
+    ACTIONS(1667), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17511] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1673), 1,
+      sym_keyword_desc,
+    ACTIONS(1671), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17533] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1677), 1,
+      sym_keyword_desc,
+    ACTIONS(1675), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17555] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1681), 1,
+      sym_keyword_desc,
+    ACTIONS(1679), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17577] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1685), 1,
+      sym_keyword_desc,
+    ACTIONS(1683), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17599] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1689), 1,
+      sym_keyword_desc,
+    ACTIONS(1687), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17621] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1693), 1,
+      sym_keyword_desc,
+    ACTIONS(1691), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17643] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1697), 1,
+      sym_keyword_desc,
+    ACTIONS(1695), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17665] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1701), 1,
+      sym_keyword_desc,
+    ACTIONS(1699), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17687] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1705), 1,
+      sym_keyword_desc,
+    ACTIONS(1703), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17709] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1709), 1,
+      sym_keyword_desc,
+    ACTIONS(1707), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17731] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1713), 1,
+      sym_keyword_desc,
+    ACTIONS(1711), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17753] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1717), 1,
+      sym_keyword_desc,
+    ACTIONS(1715), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17775] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1721), 1,
+      sym_keyword_desc,
+    ACTIONS(1719), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17797] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1725), 1,
+      sym_keyword_desc,
+    ACTIONS(1723), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17819] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1729), 1,
+      sym_keyword_desc,
+    ACTIONS(1727), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17841] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1733), 1,
+      sym_keyword_desc,
+    ACTIONS(1731), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17863] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1737), 1,
+      sym_keyword_desc,
+    ACTIONS(1735), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17885] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1741), 1,
+      sym_keyword_desc,
+    ACTIONS(1739), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17907] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1745), 1,
+      sym_keyword_desc,
+    ACTIONS(1743), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17929] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1749), 1,
+      sym_keyword_desc,
+    ACTIONS(1747), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17951] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1753), 1,
+      sym_keyword_desc,
+    ACTIONS(1751), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17973] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1757), 1,
+      sym_keyword_desc,
+    ACTIONS(1755), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [17995] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1761), 1,
+      sym_keyword_desc,
+    ACTIONS(1759), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [18017] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1765), 1,
+      sym_keyword_desc,
+    ACTIONS(1763), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [18039] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1769), 1,
+      sym_keyword_desc,
+    ACTIONS(1767), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [18061] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1773), 1,
+      sym_keyword_desc,
+    ACTIONS(1771), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [18083] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1777), 1,
+      sym_keyword_desc,
+    ACTIONS(1775), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [18105] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1781), 1,
+      sym_keyword_desc,
+    ACTIONS(1779), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [18127] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1785), 1,
+      sym_keyword_desc,
+    ACTIONS(1783), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [18149] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1789), 1,
+      sym_keyword_desc,
+    ACTIONS(1787), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [18171] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1793), 1,
+      sym_keyword_desc,
+    ACTIONS(1791), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [18193] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1797), 1,
+      sym_keyword_desc,
+    ACTIONS(1795), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [18215] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1801), 1,
+      sym_keyword_desc,
+    ACTIONS(1799), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      sym_keyword_alter,
+      sym_keyword_begin,
+      sym_keyword_create,
+      sym_keyword_delete,
+      sym_keyword_describe,
+      sym_keyword_drop,
+      sym_keyword_insert,
+      sym_keyword_select,
+      sym_keyword_truncate,
+      sym_keyword_update,
+      sym_keyword_use,
+  [18237] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(596), 1,
+      anon_sym_DOT,
+    STATE(166), 1,
+      aux_sym_column_reference_repeat1,
+    ACTIONS(1117), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(1115), 8,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_keyword_contains,
+      sym_keyword_in,
+      sym_keyword_is,
+      sym_keyword_like,
+  [18261] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1805), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(1803), 10,
+      anon_sym_COMMA,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS_EQ,
+      anon_sym_DASH_EQ,
+      sym_keyword_from,
+      sym_keyword_in,
+      sym_keyword_like,
+  [18281] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1807), 1,
+      sym_keyword_clustering,
+    ACTIONS(1809), 1,
+      sym_keyword_compact,
+    STATE(390), 1,
+      sym_table_options,
+    STATE(1036), 1,
+      sym_identifier,
+    STATE(247), 4,
+      sym__table_option,
+      sym_clustering_order,
+      sym_compact_storage,
+      sym_property,
+  [18312] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1807), 1,
+      sym_keyword_clustering,
+    ACTIONS(1809), 1,
+      sym_keyword_compact,
+    STATE(404), 1,
+      sym_table_options,
+    STATE(1036), 1,
+      sym_identifier,
+    STATE(247), 4,
+      sym__table_option,
+      sym_clustering_order,
+      sym_compact_storage,
+      sym_property,
+  [18343] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1807), 1,
+      sym_keyword_clustering,
+    ACTIONS(1809), 1,
+      sym_keyword_compact,
+    STATE(393), 1,
+      sym_table_options,
+    STATE(1036), 1,
+      sym_identifier,
+    STATE(247), 4,
+      sym__table_option,
+      sym_clustering_order,
+      sym_compact_storage,
+      sym_property,
+  [18374] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1807), 1,
+      sym_keyword_clustering,
+    ACTIONS(1809), 1,
+      sym_keyword_compact,
+    STATE(356), 1,
+      sym_table_options,
+    STATE(1036), 1,
+      sym_identifier,
+    STATE(247), 4,
+      sym__table_option,
+      sym_clustering_order,
+      sym_compact_storage,
+      sym_property,
+  [18405] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1811), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1813), 1,
+      sym_keyword_token,
+    STATE(140), 1,
+      sym_relation,
+    STATE(484), 1,
+      sym_identifier,
+    STATE(499), 4,
+      sym_column_reference,
+      sym__relation_target,
+      sym_tuple_of_columns,
+      sym_token_call,
+  [18436] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1807), 1,
+      sym_keyword_clustering,
+    ACTIONS(1809), 1,
+      sym_keyword_compact,
+    STATE(450), 1,
+      sym_table_options,
+    STATE(1036), 1,
+      sym_identifier,
+    STATE(247), 4,
+      sym__table_option,
+      sym_clustering_order,
+      sym_compact_storage,
+      sym_property,
+  [18467] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1807), 1,
+      sym_keyword_clustering,
+    ACTIONS(1809), 1,
+      sym_keyword_compact,
+    STATE(455), 1,
+      sym_table_options,
+    STATE(1036), 1,
+      sym_identifier,
+    STATE(247), 4,
+      sym__table_option,
+      sym_clustering_order,
+      sym_compact_storage,
+      sym_property,
+  [18498] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1807), 1,
+      sym_keyword_clustering,
+    ACTIONS(1809), 1,
+      sym_keyword_compact,
+    STATE(439), 1,
+      sym_table_options,
+    STATE(1036), 1,
+      sym_identifier,
+    STATE(247), 4,
+      sym__table_option,
+      sym_clustering_order,
+      sym_compact_storage,
+      sym_property,
+  [18529] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1807), 1,
+      sym_keyword_clustering,
+    ACTIONS(1809), 1,
+      sym_keyword_compact,
+    STATE(391), 1,
+      sym_table_options,
+    STATE(1036), 1,
+      sym_identifier,
+    STATE(247), 4,
+      sym__table_option,
+      sym_clustering_order,
+      sym_compact_storage,
+      sym_property,
+  [18560] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1811), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1813), 1,
+      sym_keyword_token,
+    STATE(143), 1,
+      sym_relation,
+    STATE(484), 1,
+      sym_identifier,
+    STATE(499), 4,
+      sym_column_reference,
+      sym__relation_target,
+      sym_tuple_of_columns,
+      sym_token_call,
+  [18591] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1807), 1,
+      sym_keyword_clustering,
+    ACTIONS(1809), 1,
+      sym_keyword_compact,
+    STATE(401), 1,
+      sym_table_options,
+    STATE(1036), 1,
+      sym_identifier,
+    STATE(247), 4,
+      sym__table_option,
+      sym_clustering_order,
+      sym_compact_storage,
+      sym_property,
+  [18622] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1807), 1,
+      sym_keyword_clustering,
+    ACTIONS(1809), 1,
+      sym_keyword_compact,
+    STATE(370), 1,
+      sym_table_options,
+    STATE(1036), 1,
+      sym_identifier,
+    STATE(247), 4,
+      sym__table_option,
+      sym_clustering_order,
+      sym_compact_storage,
+      sym_property,
+  [18653] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1807), 1,
+      sym_keyword_clustering,
+    ACTIONS(1809), 1,
+      sym_keyword_compact,
+    STATE(414), 1,
+      sym_table_options,
+    STATE(1036), 1,
+      sym_identifier,
+    STATE(247), 4,
+      sym__table_option,
+      sym_clustering_order,
+      sym_compact_storage,
+      sym_property,
+  [18684] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1819), 1,
+      sym_keyword_contains,
+    ACTIONS(1821), 1,
+      sym_keyword_in,
+    ACTIONS(1823), 1,
+      sym_keyword_is,
+    STATE(88), 1,
+      sym_operator,
+    ACTIONS(1817), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(1815), 5,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_keyword_like,
+  [18711] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1825), 1,
+      sym_keyword_aggregate,
+    ACTIONS(1829), 1,
+      sym_keyword_custom,
+    ACTIONS(1831), 1,
+      sym_keyword_function,
+    ACTIONS(1833), 1,
+      sym_keyword_index,
+    ACTIONS(1837), 1,
+      sym_keyword_materialized,
+    ACTIONS(1839), 1,
+      sym_keyword_or,
+    ACTIONS(1841), 1,
+      sym_keyword_type,
+    ACTIONS(1827), 2,
+      sym_keyword_columnfamily,
+      sym_keyword_table,
+    ACTIONS(1835), 2,
+      sym_keyword_keyspace,
+      sym_keyword_schema,
+  [18744] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1807), 1,
+      sym_keyword_clustering,
+    ACTIONS(1809), 1,
+      sym_keyword_compact,
+    STATE(403), 1,
+      sym_table_options,
+    STATE(1036), 1,
+      sym_identifier,
+    STATE(247), 4,
+      sym__table_option,
+      sym_clustering_order,
+      sym_compact_storage,
+      sym_property,
+  [18775] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1845), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(1843), 8,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_keyword_contains,
+      sym_keyword_in,
+      sym_keyword_is,
+      sym_keyword_like,
+  [18793] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1849), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(1847), 8,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_keyword_contains,
+      sym_keyword_in,
+      sym_keyword_is,
+      sym_keyword_like,
+  [18811] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(15), 1,
+      sym_keyword_delete,
+    ACTIONS(23), 1,
+      sym_keyword_insert,
+    ACTIONS(29), 1,
+      sym_keyword_update,
+    ACTIONS(641), 1,
+      sym_keyword_using,
+    ACTIONS(1851), 1,
+      sym_keyword_apply,
+    STATE(530), 1,
+      sym_using_clause,
+    STATE(535), 1,
+      aux_sym_batch_statement_repeat1,
+    STATE(604), 3,
+      sym_insert_statement,
+      sym_update_statement,
+      sym_delete_statement,
+  [18841] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(196), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1117), 1,
+      anon_sym_DASH,
+    ACTIONS(1853), 1,
+      anon_sym_DOT,
+    STATE(166), 1,
+      aux_sym_column_reference_repeat1,
+    ACTIONS(1115), 6,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_PLUS,
+      sym_keyword_as,
+      sym_keyword_from,
+      sym_keyword_where,
+  [18865] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1857), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(1855), 8,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_keyword_contains,
+      sym_keyword_in,
+      sym_keyword_is,
+      sym_keyword_like,
+  [18883] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1807), 1,
+      sym_keyword_clustering,
+    ACTIONS(1809), 1,
+      sym_keyword_compact,
+    STATE(1036), 1,
+      sym_identifier,
+    STATE(293), 4,
+      sym__table_option,
+      sym_clustering_order,
+      sym_compact_storage,
+      sym_property,
+  [18911] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(15), 1,
+      sym_keyword_delete,
+    ACTIONS(23), 1,
+      sym_keyword_insert,
+    ACTIONS(29), 1,
+      sym_keyword_update,
+    ACTIONS(641), 1,
+      sym_keyword_using,
+    ACTIONS(1859), 1,
+      sym_keyword_apply,
+    STATE(527), 1,
+      aux_sym_batch_statement_repeat1,
+    STATE(536), 1,
+      sym_using_clause,
+    STATE(604), 3,
+      sym_insert_statement,
+      sym_update_statement,
+      sym_delete_statement,
+  [18941] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1863), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(1861), 8,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_keyword_contains,
+      sym_keyword_in,
+      sym_keyword_is,
+      sym_keyword_like,
+  [18959] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(978), 1,
+      sym_index_target,
+    STATE(1015), 1,
+      sym_identifier,
+    ACTIONS(1865), 4,
+      sym_keyword_entries,
+      sym_keyword_full,
+      sym_keyword_keys,
+      sym_keyword_values,
+  [18984] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1867), 1,
+      sym_keyword_exists,
+    STATE(228), 1,
+      sym_condition,
+    STATE(289), 1,
+      sym_identifier,
+    STATE(517), 3,
+      sym_column_reference,
+      sym__assignment_target,
+      sym_element_access,
+  [19011] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(1011), 1,
+      sym_index_target,
+    STATE(1015), 1,
+      sym_identifier,
+    ACTIONS(1865), 4,
+      sym_keyword_entries,
+      sym_keyword_full,
+      sym_keyword_keys,
+      sym_keyword_values,
+  [19036] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1869), 1,
+      sym_keyword_aggregate,
+    ACTIONS(1873), 1,
+      sym_keyword_function,
+    ACTIONS(1875), 1,
+      sym_keyword_index,
+    ACTIONS(1879), 1,
+      sym_keyword_materialized,
+    ACTIONS(1881), 1,
+      sym_keyword_type,
+    ACTIONS(1871), 2,
+      sym_keyword_columnfamily,
+      sym_keyword_table,
+    ACTIONS(1877), 2,
+      sym_keyword_keyspace,
+      sym_keyword_schema,
+  [19063] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(903), 1,
+      sym_index_target,
+    STATE(1015), 1,
+      sym_identifier,
+    ACTIONS(1865), 4,
+      sym_keyword_entries,
+      sym_keyword_full,
+      sym_keyword_keys,
+      sym_keyword_values,
+  [19088] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(1015), 1,
+      sym_identifier,
+    STATE(1078), 1,
+      sym_index_target,
+    ACTIONS(1865), 4,
+      sym_keyword_entries,
+      sym_keyword_full,
+      sym_keyword_keys,
+      sym_keyword_values,
+  [19113] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(1015), 1,
+      sym_identifier,
+    STATE(1095), 1,
+      sym_index_target,
+    ACTIONS(1865), 4,
+      sym_keyword_entries,
+      sym_keyword_full,
+      sym_keyword_keys,
+      sym_keyword_values,
+  [19138] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1883), 1,
+      sym_keyword_in,
+    STATE(79), 1,
+      sym_operator,
+    ACTIONS(1817), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(1815), 5,
+      anon_sym_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_keyword_like,
+  [19159] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(1004), 1,
+      sym_index_target,
+    STATE(1015), 1,
+      sym_identifier,
+    ACTIONS(1865), 4,
+      sym_keyword_entries,
+      sym_keyword_full,
+      sym_keyword_keys,
+      sym_keyword_values,
+  [19184] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(236), 1,
+      anon_sym_LPAREN,
+    ACTIONS(592), 1,
+      anon_sym_DASH,
+    ACTIONS(587), 7,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_DOT,
+      anon_sym_PLUS,
+      sym_keyword_as,
+      sym_keyword_from,
+      sym_keyword_where,
+  [19203] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(228), 1,
+      anon_sym_DASH,
+    ACTIONS(79), 2,
+      anon_sym_LPAREN,
+      anon_sym_DOT,
+    ACTIONS(226), 6,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_PLUS,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      sym_keyword_where,
+  [19222] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1885), 1,
+      sym__unquoted_identifier,
+    ACTIONS(1887), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1889), 1,
+      sym_quoted_identifier,
+    ACTIONS(1891), 1,
+      sym_interpolation,
+    ACTIONS(1893), 1,
+      sym_keyword_primary,
+    STATE(24), 1,
+      sym_identifier,
+    STATE(859), 2,
+      sym_column_definition,
+      sym_primary_key_definition,
+  [19248] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1895), 1,
+      sym_keyword_if,
+    STATE(255), 1,
+      sym_rename,
+    STATE(393), 1,
+      sym__renames,
+    STATE(576), 1,
+      sym_if_exists,
+    STATE(1022), 1,
+      sym_identifier,
+  [19276] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1885), 1,
+      sym__unquoted_identifier,
+    ACTIONS(1889), 1,
+      sym_quoted_identifier,
+    ACTIONS(1891), 1,
+      sym_interpolation,
+    ACTIONS(1893), 1,
+      sym_keyword_primary,
+    ACTIONS(1897), 1,
+      anon_sym_RPAREN,
+    STATE(24), 1,
+      sym_identifier,
+    STATE(859), 2,
+      sym_column_definition,
+      sym_primary_key_definition,
+  [19302] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(289), 1,
+      sym_identifier,
+    STATE(854), 1,
+      sym_assignment,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+    STATE(796), 3,
+      sym_column_reference,
+      sym__assignment_target,
+      sym_element_access,
+  [19324] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1895), 1,
+      sym_keyword_if,
+    STATE(255), 1,
+      sym_rename,
+    STATE(402), 1,
+      sym__renames,
+    STATE(584), 1,
+      sym_if_exists,
+    STATE(1022), 1,
+      sym_identifier,
+  [19352] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(256), 1,
+      sym_condition,
+    STATE(289), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+    STATE(517), 3,
+      sym_column_reference,
+      sym__assignment_target,
+      sym_element_access,
+  [19374] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(15), 1,
+      sym_keyword_delete,
+    ACTIONS(23), 1,
+      sym_keyword_insert,
+    ACTIONS(29), 1,
+      sym_keyword_update,
+    ACTIONS(1899), 1,
+      sym_keyword_apply,
+    STATE(532), 1,
+      aux_sym_batch_statement_repeat1,
+    STATE(604), 3,
+      sym_insert_statement,
+      sym_update_statement,
+      sym_delete_statement,
+  [19398] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(289), 1,
+      sym_identifier,
+    STATE(658), 1,
+      sym_assignment,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+    STATE(796), 3,
+      sym_column_reference,
+      sym__assignment_target,
+      sym_element_access,
+  [19420] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1885), 1,
+      sym__unquoted_identifier,
+    ACTIONS(1889), 1,
+      sym_quoted_identifier,
+    ACTIONS(1891), 1,
+      sym_interpolation,
+    ACTIONS(1893), 1,
+      sym_keyword_primary,
+    ACTIONS(1901), 1,
+      anon_sym_RPAREN,
+    STATE(24), 1,
+      sym_identifier,
+    STATE(859), 2,
+      sym_column_definition,
+      sym_primary_key_definition,
+  [19446] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(15), 1,
+      sym_keyword_delete,
+    ACTIONS(23), 1,
+      sym_keyword_insert,
+    ACTIONS(29), 1,
+      sym_keyword_update,
+    ACTIONS(1859), 1,
+      sym_keyword_apply,
+    STATE(527), 1,
+      aux_sym_batch_statement_repeat1,
+    STATE(604), 3,
+      sym_insert_statement,
+      sym_update_statement,
+      sym_delete_statement,
+  [19470] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(639), 1,
+      sym_keyword_if,
+    ACTIONS(1885), 1,
+      sym__unquoted_identifier,
+    ACTIONS(1889), 1,
+      sym_quoted_identifier,
+    ACTIONS(1891), 1,
+      sym_interpolation,
+    ACTIONS(1903), 1,
+      anon_sym_LPAREN,
+    STATE(24), 1,
+      sym_identifier,
+    STATE(356), 1,
+      sym_column_definition,
+    STATE(570), 1,
+      sym_if_not_exists,
+  [19498] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1905), 1,
+      sym_keyword_apply,
+    ACTIONS(1907), 1,
+      sym_keyword_delete,
+    ACTIONS(1910), 1,
+      sym_keyword_insert,
+    ACTIONS(1913), 1,
+      sym_keyword_update,
+    STATE(532), 1,
+      aux_sym_batch_statement_repeat1,
+    STATE(604), 3,
+      sym_insert_statement,
+      sym_update_statement,
+      sym_delete_statement,
+  [19522] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1885), 1,
+      sym__unquoted_identifier,
+    ACTIONS(1889), 1,
+      sym_quoted_identifier,
+    ACTIONS(1891), 1,
+      sym_interpolation,
+    ACTIONS(1893), 1,
+      sym_keyword_primary,
+    ACTIONS(1916), 1,
+      anon_sym_RPAREN,
+    STATE(24), 1,
+      sym_identifier,
+    STATE(859), 2,
+      sym_column_definition,
+      sym_primary_key_definition,
+  [19548] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1895), 1,
+      sym_keyword_if,
+    STATE(255), 1,
+      sym_rename,
+    STATE(356), 1,
+      sym__renames,
+    STATE(579), 1,
+      sym_if_exists,
+    STATE(1022), 1,
+      sym_identifier,
+  [19576] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(15), 1,
+      sym_keyword_delete,
+    ACTIONS(23), 1,
+      sym_keyword_insert,
+    ACTIONS(29), 1,
+      sym_keyword_update,
+    ACTIONS(1859), 1,
+      sym_keyword_apply,
+    STATE(532), 1,
+      aux_sym_batch_statement_repeat1,
+    STATE(604), 3,
+      sym_insert_statement,
+      sym_update_statement,
+      sym_delete_statement,
+  [19600] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(15), 1,
+      sym_keyword_delete,
+    ACTIONS(23), 1,
+      sym_keyword_insert,
+    ACTIONS(29), 1,
+      sym_keyword_update,
+    ACTIONS(1899), 1,
+      sym_keyword_apply,
+    STATE(537), 1,
+      aux_sym_batch_statement_repeat1,
+    STATE(604), 3,
+      sym_insert_statement,
+      sym_update_statement,
+      sym_delete_statement,
+  [19624] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(15), 1,
+      sym_keyword_delete,
+    ACTIONS(23), 1,
+      sym_keyword_insert,
+    ACTIONS(29), 1,
+      sym_keyword_update,
+    ACTIONS(1918), 1,
+      sym_keyword_apply,
+    STATE(532), 1,
+      aux_sym_batch_statement_repeat1,
+    STATE(604), 3,
+      sym_insert_statement,
+      sym_update_statement,
+      sym_delete_statement,
+  [19648] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1895), 1,
+      sym_keyword_if,
+    STATE(255), 1,
+      sym_rename,
+    STATE(363), 1,
+      sym__renames,
+    STATE(586), 1,
+      sym_if_exists,
+    STATE(1022), 1,
+      sym_identifier,
+  [19676] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(289), 1,
+      sym_identifier,
+    STATE(721), 1,
+      sym_assignment,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+    STATE(796), 3,
+      sym_column_reference,
+      sym__assignment_target,
+      sym_element_access,
+  [19698] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(639), 1,
+      sym_keyword_if,
+    ACTIONS(1885), 1,
+      sym__unquoted_identifier,
+    ACTIONS(1889), 1,
+      sym_quoted_identifier,
+    ACTIONS(1891), 1,
+      sym_interpolation,
+    ACTIONS(1920), 1,
+      anon_sym_LPAREN,
+    STATE(24), 1,
+      sym_identifier,
+    STATE(393), 1,
+      sym_column_definition,
+    STATE(568), 1,
+      sym_if_not_exists,
+  [19726] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(639), 1,
+      sym_keyword_if,
+    ACTIONS(1922), 1,
+      sym_keyword_on,
+    STATE(646), 1,
+      sym_if_not_exists,
+    STATE(985), 1,
+      sym_identifier,
+  [19751] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1895), 1,
+      sym_keyword_if,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(280), 1,
+      sym_qualified_name,
+    STATE(632), 1,
+      sym_if_exists,
+  [19776] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1895), 1,
+      sym_keyword_if,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(386), 1,
+      sym_qualified_name,
+    STATE(603), 1,
+      sym_if_exists,
+  [19801] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(639), 1,
+      sym_keyword_if,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(638), 1,
+      sym_if_not_exists,
+    STATE(938), 1,
+      sym_qualified_name,
+  [19826] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1895), 1,
+      sym_keyword_if,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(648), 1,
+      sym_if_exists,
+    STATE(852), 1,
+      sym_qualified_name,
+  [19851] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(639), 1,
+      sym_keyword_if,
+    ACTIONS(1885), 1,
+      sym__unquoted_identifier,
+    ACTIONS(1889), 1,
+      sym_quoted_identifier,
+    ACTIONS(1891), 1,
+      sym_interpolation,
+    STATE(38), 1,
+      sym_identifier,
+    STATE(402), 1,
+      sym_field_definition,
+    STATE(626), 1,
+      sym_if_not_exists,
+  [19876] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1895), 1,
+      sym_keyword_if,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(377), 1,
+      sym_qualified_name,
+    STATE(598), 1,
+      sym_if_exists,
+  [19901] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1895), 1,
+      sym_keyword_if,
+    ACTIONS(1924), 1,
+      anon_sym_LPAREN,
+    STATE(356), 1,
+      sym_identifier,
+    STATE(635), 1,
+      sym_if_exists,
+  [19926] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1895), 1,
+      sym_keyword_if,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(633), 1,
+      sym_if_exists,
+    STATE(634), 1,
+      sym_qualified_name,
+  [19951] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(444), 1,
+      sym_qualified_name,
+    ACTIONS(1926), 2,
+      sym_keyword_columnfamily,
+      sym_keyword_table,
+  [19974] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(639), 1,
+      sym_keyword_if,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(602), 1,
+      sym_if_not_exists,
+    STATE(1108), 1,
+      sym_qualified_name,
+  [19999] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(639), 1,
+      sym_keyword_if,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(613), 1,
+      sym_if_not_exists,
+    STATE(901), 1,
+      sym_qualified_name,
+  [20024] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1885), 1,
+      sym__unquoted_identifier,
+    ACTIONS(1889), 1,
+      sym_quoted_identifier,
+    ACTIONS(1891), 1,
+      sym_interpolation,
+    ACTIONS(1893), 1,
+      sym_keyword_primary,
+    STATE(24), 1,
+      sym_identifier,
+    STATE(859), 2,
+      sym_column_definition,
+      sym_primary_key_definition,
+  [20047] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1885), 1,
+      sym__unquoted_identifier,
+    ACTIONS(1889), 1,
+      sym_quoted_identifier,
+    ACTIONS(1891), 1,
+      sym_interpolation,
+    ACTIONS(1893), 1,
+      sym_keyword_primary,
+    STATE(24), 1,
+      sym_identifier,
+    STATE(758), 2,
+      sym_column_definition,
+      sym_primary_key_definition,
+  [20070] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(639), 1,
+      sym_keyword_if,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(641), 1,
+      sym_if_not_exists,
+    STATE(1072), 1,
+      sym_qualified_name,
+  [20095] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1928), 1,
+      sym_keyword_from,
+    STATE(289), 1,
+      sym_identifier,
+    STATE(811), 2,
+      sym_column_reference,
+      sym_element_access,
+  [20118] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1895), 1,
+      sym_keyword_if,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(434), 1,
+      sym_qualified_name,
+    STATE(647), 1,
+      sym_if_exists,
+  [20143] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(639), 1,
+      sym_keyword_if,
+    ACTIONS(1885), 1,
+      sym__unquoted_identifier,
+    ACTIONS(1889), 1,
+      sym_quoted_identifier,
+    ACTIONS(1891), 1,
+      sym_interpolation,
+    STATE(38), 1,
+      sym_identifier,
+    STATE(363), 1,
+      sym_field_definition,
+    STATE(615), 1,
+      sym_if_not_exists,
+  [20168] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1895), 1,
+      sym_keyword_if,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(338), 1,
+      sym_qualified_name,
+    STATE(594), 1,
+      sym_if_exists,
+  [20193] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(639), 1,
+      sym_keyword_if,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(629), 1,
+      sym_if_not_exists,
+    STATE(1045), 1,
+      sym_qualified_name,
+  [20218] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(639), 1,
+      sym_keyword_if,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(643), 1,
+      sym_if_not_exists,
+    STATE(1064), 1,
+      sym_qualified_name,
+  [20243] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(639), 1,
+      sym_keyword_if,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(599), 1,
+      sym_if_not_exists,
+    STATE(1080), 1,
+      sym_qualified_name,
+  [20268] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1895), 1,
+      sym_keyword_if,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(636), 1,
+      sym_if_exists,
+    STATE(942), 1,
+      sym_qualified_name,
+  [20293] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1895), 1,
+      sym_keyword_if,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(384), 1,
+      sym_qualified_name,
+    STATE(612), 1,
+      sym_if_exists,
+  [20318] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1895), 1,
+      sym_keyword_if,
+    ACTIONS(1930), 1,
+      anon_sym_LPAREN,
+    STATE(393), 1,
+      sym_identifier,
+    STATE(620), 1,
+      sym_if_exists,
+  [20343] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1885), 1,
+      sym__unquoted_identifier,
+    ACTIONS(1889), 1,
+      sym_quoted_identifier,
+    ACTIONS(1891), 1,
+      sym_interpolation,
+    ACTIONS(1893), 1,
+      sym_keyword_primary,
+    STATE(24), 1,
+      sym_identifier,
+    STATE(738), 2,
+      sym_column_definition,
+      sym_primary_key_definition,
+  [20366] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(639), 1,
+      sym_keyword_if,
+    ACTIONS(1932), 1,
+      sym_keyword_on,
+    STATE(608), 1,
+      sym_if_not_exists,
+    STATE(1105), 1,
+      sym_identifier,
+  [20391] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1891), 1,
+      sym_interpolation,
+    ACTIONS(1934), 1,
+      anon_sym_LPAREN,
+    STATE(24), 1,
+      sym_identifier,
+    STATE(433), 1,
+      sym_column_definition,
+    ACTIONS(1889), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [20411] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1895), 1,
+      sym_keyword_if,
+    STATE(385), 1,
+      sym_identifier,
+    STATE(683), 1,
+      sym_if_exists,
+  [20433] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1891), 1,
+      sym_interpolation,
+    ACTIONS(1936), 1,
+      anon_sym_LPAREN,
+    STATE(24), 1,
+      sym_identifier,
+    STATE(395), 1,
+      sym_column_definition,
+    ACTIONS(1889), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [20453] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1891), 1,
+      sym_interpolation,
+    ACTIONS(1938), 1,
+      anon_sym_RPAREN,
+    STATE(25), 1,
+      sym_identifier,
+    STATE(803), 1,
+      sym_parameter,
+    ACTIONS(1889), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [20473] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(258), 1,
+      sym_property,
+    STATE(399), 1,
+      sym_properties,
+    STATE(1036), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [20493] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1891), 1,
+      sym_interpolation,
+    ACTIONS(1940), 1,
+      anon_sym_RPAREN,
+    STATE(38), 1,
+      sym_identifier,
+    STATE(892), 1,
+      sym_field_definition,
+    ACTIONS(1889), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [20513] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(258), 1,
+      sym_property,
+    STATE(371), 1,
+      sym_properties,
+    STATE(1036), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [20533] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(258), 1,
+      sym_property,
+    STATE(410), 1,
+      sym_properties,
+    STATE(1036), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [20553] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(255), 1,
+      sym_rename,
+    STATE(433), 1,
+      sym__renames,
+    STATE(1022), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [20573] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1895), 1,
+      sym_keyword_if,
+    STATE(654), 1,
+      sym_if_exists,
+    STATE(966), 1,
+      sym_identifier,
+  [20595] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1942), 1,
+      anon_sym_LPAREN,
+    STATE(792), 1,
+      sym_partition_key,
+    STATE(876), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [20615] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(255), 1,
+      sym_rename,
+    STATE(395), 1,
+      sym__renames,
+    STATE(1022), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [20635] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(289), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+    STATE(863), 2,
+      sym_column_reference,
+      sym_element_access,
+  [20653] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1891), 1,
+      sym_interpolation,
+    ACTIONS(1944), 1,
+      anon_sym_RPAREN,
+    STATE(38), 1,
+      sym_identifier,
+    STATE(892), 1,
+      sym_field_definition,
+    ACTIONS(1889), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [20673] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(639), 1,
+      sym_keyword_if,
+    STATE(657), 1,
+      sym_if_not_exists,
+    STATE(1048), 1,
+      sym_identifier,
+  [20695] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1891), 1,
+      sym_interpolation,
+    ACTIONS(1946), 1,
+      anon_sym_RPAREN,
+    STATE(25), 1,
+      sym_identifier,
+    STATE(743), 1,
+      sym_parameter,
+    ACTIONS(1889), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [20715] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(255), 1,
+      sym_rename,
+    STATE(456), 1,
+      sym__renames,
+    STATE(1022), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [20735] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1891), 1,
+      sym_interpolation,
+    ACTIONS(1948), 1,
+      anon_sym_RPAREN,
+    STATE(38), 1,
+      sym_identifier,
+    STATE(892), 1,
+      sym_field_definition,
+    ACTIONS(1889), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [20755] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(255), 1,
+      sym_rename,
+    STATE(405), 1,
+      sym__renames,
+    STATE(1022), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [20775] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(258), 1,
+      sym_property,
+    STATE(361), 1,
+      sym_properties,
+    STATE(1036), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [20795] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1891), 1,
+      sym_interpolation,
+    ACTIONS(1950), 1,
+      anon_sym_RPAREN,
+    STATE(38), 1,
+      sym_identifier,
+    STATE(892), 1,
+      sym_field_definition,
+    ACTIONS(1889), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [20815] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1956), 1,
+      sym_keyword_materialized,
+    ACTIONS(1958), 1,
+      sym_keyword_type,
+    ACTIONS(1952), 2,
+      sym_keyword_columnfamily,
+      sym_keyword_table,
+    ACTIONS(1954), 2,
+      sym_keyword_keyspace,
+      sym_keyword_schema,
+  [20833] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1891), 1,
+      sym_interpolation,
+    ACTIONS(1960), 1,
+      anon_sym_RPAREN,
+    STATE(25), 1,
+      sym_identifier,
+    STATE(744), 1,
+      sym_parameter,
+    ACTIONS(1889), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [20853] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1891), 1,
+      sym_interpolation,
+    ACTIONS(1962), 1,
+      anon_sym_RPAREN,
+    STATE(25), 1,
+      sym_identifier,
+    STATE(767), 1,
+      sym_parameter,
+    ACTIONS(1889), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [20873] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1017), 1,
+      sym_qualified_name,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [20890] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(866), 1,
+      sym_qualified_name,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [20907] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(323), 1,
+      sym_qualified_name,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [20924] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(134), 1,
+      sym_qualified_name,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [20941] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1891), 1,
+      sym_interpolation,
+    STATE(24), 1,
+      sym_identifier,
+    STATE(768), 1,
+      sym_column_definition,
+    ACTIONS(1889), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [20958] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1891), 1,
+      sym_interpolation,
+    STATE(38), 1,
+      sym_identifier,
+    STATE(769), 1,
+      sym_field_definition,
+    ACTIONS(1889), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [20975] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(426), 1,
+      sym_qualified_name,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [20992] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(990), 1,
+      sym_qualified_name,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21009] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1076), 1,
+      sym_qualified_name,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21026] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(351), 1,
+      sym_rename,
+    STATE(1022), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21043] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1027), 1,
+      sym_qualified_name,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21060] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(441), 1,
+      sym_qualified_name,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21077] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1964), 1,
+      anon_sym_SEMI,
+    ACTIONS(1966), 4,
+      sym_keyword_apply,
+      sym_keyword_delete,
+      sym_keyword_insert,
+      sym_keyword_update,
+  [21090] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1891), 1,
+      sym_interpolation,
+    STATE(24), 1,
+      sym_identifier,
+    STATE(888), 1,
+      sym_column_definition,
+    ACTIONS(1889), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21107] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1968), 1,
+      sym_keyword_add,
+    ACTIONS(1970), 1,
+      sym_keyword_alter,
+    ACTIONS(1972), 1,
+      sym_keyword_drop,
+    ACTIONS(1974), 1,
+      sym_keyword_rename,
+    ACTIONS(1976), 1,
+      sym_keyword_with,
+  [21126] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(695), 1,
+      sym_qualified_name,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21143] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1922), 1,
+      sym_keyword_on,
+    STATE(985), 1,
+      sym_identifier,
+  [21162] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(697), 1,
+      sym_qualified_name,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21179] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1980), 1,
+      sym_interpolation,
+    STATE(178), 1,
+      sym_identifier,
+    STATE(182), 1,
+      sym_ordering,
+    ACTIONS(1978), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21196] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(980), 1,
+      sym_qualified_name,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21213] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(427), 1,
+      sym_qualified_name,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21230] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1030), 1,
+      sym_qualified_name,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21247] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(858), 1,
+      sym_qualified_name,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21264] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1891), 1,
+      sym_interpolation,
+    STATE(38), 1,
+      sym_identifier,
+    STATE(405), 1,
+      sym_field_definition,
+    ACTIONS(1889), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21281] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(952), 1,
+      sym_qualified_name,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21298] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(1982), 1,
+      anon_sym_LPAREN,
+    STATE(145), 1,
+      sym_bind_marker,
+    ACTIONS(49), 2,
+      anon_sym_QMARK,
+      sym_interpolation,
+  [21315] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(297), 1,
+      sym_property,
+    STATE(1036), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21332] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(1984), 1,
+      sym_string_literal,
+    STATE(176), 1,
+      sym_bind_marker,
+    ACTIONS(49), 2,
+      anon_sym_QMARK,
+      sym_interpolation,
+  [21349] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(1986), 1,
+      anon_sym_LPAREN,
+    STATE(433), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21366] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1891), 1,
+      sym_interpolation,
+    STATE(24), 1,
+      sym_identifier,
+    STATE(759), 1,
+      sym_column_definition,
+    ACTIONS(1889), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21383] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(135), 1,
+      sym_qualified_name,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21400] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1980), 1,
+      sym_interpolation,
+    STATE(178), 1,
+      sym_identifier,
+    STATE(198), 1,
+      sym_ordering,
+    ACTIONS(1978), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21417] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1891), 1,
+      sym_interpolation,
+    STATE(38), 1,
+      sym_identifier,
+    STATE(892), 1,
+      sym_field_definition,
+    ACTIONS(1889), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21434] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(1988), 1,
+      sym_integer,
+    STATE(209), 1,
+      sym_bind_marker,
+    ACTIONS(49), 2,
+      anon_sym_QMARK,
+      sym_interpolation,
+  [21451] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1891), 1,
+      sym_interpolation,
+    STATE(38), 1,
+      sym_identifier,
+    STATE(456), 1,
+      sym_field_definition,
+    ACTIONS(1889), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21468] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(136), 1,
+      sym_qualified_name,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21485] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1891), 1,
+      sym_interpolation,
+    STATE(38), 1,
+      sym_identifier,
+    STATE(777), 1,
+      sym_field_definition,
+    ACTIONS(1889), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21502] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(905), 1,
+      sym_qualified_name,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21519] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(1990), 1,
+      sym_integer,
+    STATE(244), 1,
+      sym_bind_marker,
+    ACTIONS(49), 2,
+      anon_sym_QMARK,
+      sym_interpolation,
+  [21536] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(662), 1,
+      sym_qualified_name,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21553] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(326), 1,
+      sym_qualified_name,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21570] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(606), 1,
+      sym_qualified_name,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21587] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1992), 1,
+      sym_keyword_add,
+    ACTIONS(1994), 1,
+      sym_keyword_alter,
+    ACTIONS(1996), 1,
+      sym_keyword_drop,
+    ACTIONS(1998), 1,
+      sym_keyword_rename,
+    ACTIONS(2000), 1,
+      sym_keyword_with,
+  [21606] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(2002), 1,
+      anon_sym_LPAREN,
+    STATE(395), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21623] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1088), 1,
+      sym_qualified_name,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21640] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1891), 1,
+      sym_interpolation,
+    STATE(25), 1,
+      sym_identifier,
+    STATE(868), 1,
+      sym_parameter,
+    ACTIONS(1889), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21657] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1106), 1,
+      sym_qualified_name,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21674] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(392), 1,
+      sym_qualified_name,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21691] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2006), 1,
+      sym_interpolation,
+    STATE(727), 1,
+      sym_identifier,
+    STATE(787), 1,
+      sym_ordering,
+    ACTIONS(2004), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21708] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(1091), 1,
+      sym_qualified_name,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21725] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(886), 1,
+      sym_qualified_name,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21742] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(924), 1,
+      sym_qualified_name,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21759] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    ACTIONS(2008), 1,
+      sym_integer,
+    STATE(337), 1,
+      sym_bind_marker,
+    ACTIONS(49), 2,
+      anon_sym_QMARK,
+      sym_interpolation,
+  [21776] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(999), 1,
+      sym_qualified_name,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21793] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      sym__unquoted_identifier,
+    ACTIONS(51), 1,
+      sym_quoted_identifier,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    ACTIONS(2010), 1,
+      sym_keyword_on,
+    STATE(1020), 1,
+      sym_identifier,
+  [21812] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(382), 1,
+      sym_qualified_name,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21829] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(879), 1,
+      sym_qualified_name,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21846] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1891), 1,
+      sym_interpolation,
+    STATE(24), 1,
+      sym_identifier,
+    STATE(844), 1,
+      sym_column_definition,
+    ACTIONS(1889), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21863] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2006), 1,
+      sym_interpolation,
+    STATE(182), 1,
+      sym_ordering,
+    STATE(727), 1,
+      sym_identifier,
+    ACTIONS(2004), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21880] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(779), 1,
+      sym_qualified_name,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21897] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1891), 1,
+      sym_interpolation,
+    STATE(24), 1,
+      sym_identifier,
+    STATE(832), 1,
+      sym_column_definition,
+    ACTIONS(1889), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21914] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(67), 1,
+      sym_identifier,
+    STATE(943), 1,
+      sym_qualified_name,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21931] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(977), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21945] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(1083), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21959] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(1093), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21973] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(1025), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [21987] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(464), 1,
+      sym_keyword_where,
+    ACTIONS(2012), 1,
+      anon_sym_COMMA,
+    STATE(208), 1,
+      sym_where_clause,
+    STATE(720), 1,
+      aux_sym_update_statement_repeat1,
+  [22003] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(799), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22017] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(1089), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22031] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(932), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22045] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(464), 1,
+      sym_keyword_where,
+    ACTIONS(641), 1,
+      sym_keyword_using,
+    STATE(206), 1,
+      sym_where_clause,
+    STATE(861), 1,
+      sym_using_clause,
+  [22061] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(936), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22075] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(953), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22089] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(838), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22103] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(175), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22117] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(808), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22131] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(1010), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22145] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1905), 4,
+      sym_keyword_apply,
+      sym_keyword_delete,
+      sym_keyword_insert,
+      sym_keyword_update,
+  [22155] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(869), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22169] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(75), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22183] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(1039), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22197] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(1052), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22211] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(1059), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22225] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(76), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22239] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(173), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22253] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(346), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22267] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(172), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22281] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(902), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22295] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(906), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22309] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(913), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22323] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(917), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22337] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(430), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22351] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(288), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22365] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2016), 1,
+      anon_sym_PLUS,
+    ACTIONS(2018), 1,
+      anon_sym_DASH,
+    ACTIONS(2014), 2,
+      anon_sym_COMMA,
+      sym_keyword_where,
+  [22379] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(464), 1,
+      sym_keyword_where,
+    ACTIONS(2012), 1,
+      anon_sym_COMMA,
+    STATE(216), 1,
+      sym_where_clause,
+    STATE(755), 1,
+      aux_sym_update_statement_repeat1,
+  [22395] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(292), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22409] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(845), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22423] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(951), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22437] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(955), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22451] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(445), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22465] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(962), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22479] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(970), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22493] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(353), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22507] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(464), 1,
+      sym_keyword_where,
+    ACTIONS(641), 1,
+      sym_keyword_using,
+    STATE(210), 1,
+      sym_where_clause,
+    STATE(875), 1,
+      sym_using_clause,
+  [22523] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(309), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22537] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(464), 1,
+      sym_keyword_where,
+    ACTIONS(641), 1,
+      sym_keyword_using,
+    STATE(212), 1,
+      sym_where_clause,
+    STATE(856), 1,
+      sym_using_clause,
+  [22553] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(1040), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22567] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(312), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22581] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(997), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22595] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(915), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22609] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2020), 1,
+      anon_sym_COMMA,
+    STATE(702), 1,
+      aux_sym_drop_function_statement_repeat1,
+    ACTIONS(2023), 2,
+      anon_sym_RPAREN,
+      anon_sym_GT,
+  [22623] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(354), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22637] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(1113), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22651] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(1018), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22665] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(325), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22679] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(958), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22693] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(327), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22707] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(331), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22721] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(1051), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22735] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(1055), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22749] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2025), 4,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_RBRACE,
+  [22759] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(336), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22773] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(790), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22787] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(339), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22801] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(1075), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22815] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2027), 1,
+      anon_sym_COMMA,
+    ACTIONS(2029), 1,
+      anon_sym_RBRACE,
+    ACTIONS(2031), 1,
+      anon_sym_COLON,
+    STATE(828), 1,
+      aux_sym_relation_repeat1,
+  [22831] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(341), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22845] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2033), 4,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      sym_keyword_as,
+      sym_keyword_from,
+  [22855] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(464), 1,
+      sym_keyword_where,
+    ACTIONS(2012), 1,
+      anon_sym_COMMA,
+    STATE(214), 1,
+      sym_where_clause,
+    STATE(755), 1,
+      aux_sym_update_statement_repeat1,
+  [22871] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(464), 1,
+      sym_keyword_where,
+    ACTIONS(2012), 1,
+      anon_sym_COMMA,
+    STATE(214), 1,
+      sym_where_clause,
+    STATE(686), 1,
+      aux_sym_update_statement_repeat1,
+  [22887] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(882), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22901] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(762), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22915] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(519), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22929] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(79), 4,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      sym_keyword_asc,
+      sym_keyword_desc,
+  [22939] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(79), 4,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      sym_keyword_asc,
+      sym_keyword_desc,
+  [22949] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(647), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+    ACTIONS(649), 2,
+      sym_keyword_asc,
+      sym_keyword_desc,
+  [22961] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2035), 4,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      sym_keyword_as,
+      sym_keyword_from,
+  [22971] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(773), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [22985] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2038), 1,
+      anon_sym_COMMA,
+    STATE(730), 1,
+      aux_sym_relation_repeat1,
+    ACTIONS(2025), 2,
+      anon_sym_RBRACK,
+      anon_sym_RBRACE,
+  [22999] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(146), 1,
+      sym_interpolation,
+    STATE(786), 1,
+      sym_identifier,
+    ACTIONS(51), 2,
+      sym__unquoted_identifier,
+      sym_quoted_identifier,
+  [23013] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2041), 1,
+      anon_sym_COMMA,
+    ACTIONS(2043), 1,
+      sym_keyword_from,
+    STATE(821), 1,
+      aux_sym_selection_repeat1,
+  [23026] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(625), 1,
+      anon_sym_COMMA,
+    ACTIONS(2045), 1,
+      anon_sym_RPAREN,
+    STATE(168), 1,
+      aux_sym_partition_key_repeat1,
+  [23039] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2047), 1,
+      anon_sym_COMMA,
+    ACTIONS(2049), 1,
+      anon_sym_RPAREN,
+    STATE(757), 1,
+      aux_sym_drop_function_statement_repeat1,
+  [23052] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2047), 1,
+      anon_sym_COMMA,
+    ACTIONS(2051), 1,
+      anon_sym_GT,
+    STATE(702), 1,
+      aux_sym_drop_function_statement_repeat1,
+  [23065] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2053), 1,
+      anon_sym_COMMA,
+    ACTIONS(2055), 1,
+      anon_sym_RPAREN,
+    STATE(842), 1,
+      aux_sym_clustering_order_repeat1,
+  [23078] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2047), 1,
+      anon_sym_COMMA,
+    ACTIONS(2057), 1,
+      anon_sym_RPAREN,
+    STATE(702), 1,
+      aux_sym_drop_function_statement_repeat1,
+  [23091] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2059), 1,
+      anon_sym_COMMA,
+    ACTIONS(2061), 1,
+      anon_sym_RPAREN,
+    STATE(764), 1,
+      aux_sym_create_table_statement_repeat1,
+  [23104] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(625), 1,
+      anon_sym_COMMA,
+    ACTIONS(2063), 1,
+      anon_sym_RPAREN,
+    STATE(168), 1,
+      aux_sym_partition_key_repeat1,
+  [23117] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2065), 1,
+      anon_sym_COMMA,
+    ACTIONS(2068), 1,
+      anon_sym_RPAREN,
+    STATE(740), 1,
+      aux_sym_primary_key_definition_repeat1,
+  [23130] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2070), 1,
+      sym_keyword_called,
+    ACTIONS(2072), 1,
+      sym_keyword_returns,
+    STATE(940), 1,
+      sym_null_input_behavior,
+  [23143] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2070), 1,
+      sym_keyword_called,
+    ACTIONS(2072), 1,
+      sym_keyword_returns,
+    STATE(965), 1,
+      sym_null_input_behavior,
+  [23156] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2074), 1,
+      anon_sym_COMMA,
+    ACTIONS(2076), 1,
+      anon_sym_RPAREN,
+    STATE(825), 1,
+      aux_sym_create_function_statement_repeat1,
+  [23169] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2074), 1,
+      anon_sym_COMMA,
+    ACTIONS(2078), 1,
+      anon_sym_RPAREN,
+    STATE(771), 1,
+      aux_sym_create_function_statement_repeat1,
+  [23182] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2070), 1,
+      sym_keyword_called,
+    ACTIONS(2072), 1,
+      sym_keyword_returns,
+    STATE(948), 1,
+      sym_null_input_behavior,
+  [23195] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2074), 1,
+      anon_sym_COMMA,
+    ACTIONS(2080), 1,
+      anon_sym_RPAREN,
+    STATE(795), 1,
+      aux_sym_create_function_statement_repeat1,
+  [23208] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2047), 1,
+      anon_sym_COMMA,
+    ACTIONS(2082), 1,
+      anon_sym_RPAREN,
+    STATE(778), 1,
+      aux_sym_drop_function_statement_repeat1,
+  [23221] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2070), 1,
+      sym_keyword_called,
+    ACTIONS(2072), 1,
+      sym_keyword_returns,
+    STATE(920), 1,
+      sym_null_input_behavior,
+  [23234] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2084), 1,
+      anon_sym_COMMA,
+    ACTIONS(2087), 1,
+      anon_sym_RPAREN,
+    STATE(749), 1,
+      aux_sym_alter_table_statement_repeat1,
+  [23247] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2089), 1,
+      anon_sym_COMMA,
+    ACTIONS(2091), 1,
+      anon_sym_RPAREN,
+    STATE(805), 1,
+      aux_sym_relation_repeat1,
+  [23260] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2089), 1,
+      anon_sym_COMMA,
+    ACTIONS(2093), 1,
+      anon_sym_RPAREN,
+    STATE(805), 1,
+      aux_sym_relation_repeat1,
+  [23273] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2089), 1,
+      anon_sym_COMMA,
+    ACTIONS(2095), 1,
+      anon_sym_RPAREN,
+    STATE(774), 1,
+      aux_sym_relation_repeat1,
+  [23286] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(201), 1,
+      sym_using_option,
+    ACTIONS(2097), 2,
+      sym_keyword_ttl,
+      sym_keyword_timestamp,
+  [23297] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2099), 1,
+      anon_sym_COMMA,
+    ACTIONS(2102), 1,
+      anon_sym_RPAREN,
+    STATE(754), 1,
+      aux_sym_function_call_repeat1,
+  [23310] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2104), 1,
+      anon_sym_COMMA,
+    ACTIONS(2107), 1,
+      sym_keyword_where,
+    STATE(755), 1,
+      aux_sym_update_statement_repeat1,
+  [23323] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(625), 1,
+      anon_sym_COMMA,
+    ACTIONS(2109), 1,
+      anon_sym_RPAREN,
+    STATE(168), 1,
+      aux_sym_partition_key_repeat1,
+  [23336] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2047), 1,
+      anon_sym_COMMA,
+    ACTIONS(2111), 1,
+      anon_sym_RPAREN,
+    STATE(702), 1,
+      aux_sym_drop_function_statement_repeat1,
+  [23349] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2113), 1,
+      anon_sym_COMMA,
+    ACTIONS(2115), 1,
+      anon_sym_RPAREN,
+    STATE(785), 1,
+      aux_sym_create_table_statement_repeat1,
+  [23362] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2117), 1,
+      anon_sym_COMMA,
+    ACTIONS(2119), 1,
+      anon_sym_RPAREN,
+    STATE(843), 1,
+      aux_sym_alter_table_statement_repeat1,
+  [23375] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2089), 1,
+      anon_sym_COMMA,
+    ACTIONS(2121), 1,
+      anon_sym_RPAREN,
+    STATE(833), 1,
+      aux_sym_relation_repeat1,
+  [23388] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2123), 1,
+      sym_keyword_batch,
+    ACTIONS(2125), 2,
+      sym_keyword_counter,
+      sym_keyword_unlogged,
+  [23399] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(625), 1,
+      anon_sym_COMMA,
+    ACTIONS(2119), 1,
+      anon_sym_RPAREN,
+    STATE(826), 1,
+      aux_sym_partition_key_repeat1,
+  [23412] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2117), 1,
+      anon_sym_COMMA,
+    ACTIONS(2127), 1,
+      anon_sym_RPAREN,
+    STATE(749), 1,
+      aux_sym_alter_table_statement_repeat1,
+  [23425] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1887), 1,
+      anon_sym_RPAREN,
+    ACTIONS(2129), 1,
+      anon_sym_COMMA,
+    STATE(789), 1,
+      aux_sym_create_table_statement_repeat1,
+  [23438] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2070), 1,
+      sym_keyword_called,
+    ACTIONS(2072), 1,
+      sym_keyword_returns,
+    STATE(983), 1,
+      sym_null_input_behavior,
+  [23451] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2117), 1,
+      anon_sym_COMMA,
+    ACTIONS(2131), 1,
+      anon_sym_RPAREN,
+    STATE(749), 1,
+      aux_sym_alter_table_statement_repeat1,
+  [23464] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2074), 1,
+      anon_sym_COMMA,
+    ACTIONS(2133), 1,
+      anon_sym_RPAREN,
+    STATE(793), 1,
+      aux_sym_create_function_statement_repeat1,
+  [23477] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2117), 1,
+      anon_sym_COMMA,
+    ACTIONS(2131), 1,
+      anon_sym_RPAREN,
+    STATE(763), 1,
+      aux_sym_alter_table_statement_repeat1,
+  [23490] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2135), 1,
+      anon_sym_COMMA,
+    ACTIONS(2137), 1,
+      anon_sym_RPAREN,
+    STATE(783), 1,
+      aux_sym_create_type_statement_repeat1,
+  [23503] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2070), 1,
+      sym_keyword_called,
+    ACTIONS(2072), 1,
+      sym_keyword_returns,
+    STATE(995), 1,
+      sym_null_input_behavior,
+  [23516] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2074), 1,
+      anon_sym_COMMA,
+    ACTIONS(2139), 1,
+      anon_sym_RPAREN,
+    STATE(795), 1,
+      aux_sym_create_function_statement_repeat1,
+  [23529] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(625), 1,
+      anon_sym_COMMA,
+    ACTIONS(2131), 1,
+      anon_sym_RPAREN,
+    STATE(168), 1,
+      aux_sym_partition_key_repeat1,
+  [23542] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(625), 1,
+      anon_sym_COMMA,
+    ACTIONS(2131), 1,
+      anon_sym_RPAREN,
+    STATE(801), 1,
+      aux_sym_partition_key_repeat1,
+  [23555] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2089), 1,
+      anon_sym_COMMA,
+    ACTIONS(2141), 1,
+      anon_sym_RPAREN,
+    STATE(805), 1,
+      aux_sym_relation_repeat1,
+  [23568] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2143), 1,
+      anon_sym_COMMA,
+    ACTIONS(2146), 1,
+      sym_keyword_from,
+    STATE(775), 1,
+      aux_sym_delete_statement_repeat1,
+  [23581] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2148), 1,
+      anon_sym_COMMA,
+    ACTIONS(2150), 1,
+      sym_keyword_from,
+    STATE(775), 1,
+      aux_sym_delete_statement_repeat1,
+  [23594] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2152), 1,
+      anon_sym_COMMA,
+    ACTIONS(2154), 1,
+      anon_sym_RPAREN,
+    STATE(804), 1,
+      aux_sym_create_type_statement_repeat1,
+  [23607] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2047), 1,
+      anon_sym_COMMA,
+    ACTIONS(2156), 1,
+      anon_sym_RPAREN,
+    STATE(702), 1,
+      aux_sym_drop_function_statement_repeat1,
+  [23620] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(641), 1,
+      sym_keyword_using,
+    ACTIONS(2158), 1,
+      sym_keyword_set,
+    STATE(991), 1,
+      sym_using_clause,
+  [23633] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2023), 3,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_GT,
+  [23642] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2117), 1,
+      anon_sym_COMMA,
+    ACTIONS(2160), 1,
+      anon_sym_RPAREN,
+    STATE(749), 1,
+      aux_sym_alter_table_statement_repeat1,
+  [23655] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(625), 1,
+      anon_sym_COMMA,
+    ACTIONS(2160), 1,
+      anon_sym_RPAREN,
+    STATE(168), 1,
+      aux_sym_partition_key_repeat1,
+  [23668] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1940), 1,
+      anon_sym_RPAREN,
+    ACTIONS(2162), 1,
+      anon_sym_COMMA,
+    STATE(806), 1,
+      aux_sym_create_type_statement_repeat1,
+  [23681] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2047), 1,
+      anon_sym_COMMA,
+    ACTIONS(2164), 1,
+      anon_sym_RPAREN,
+    STATE(831), 1,
+      aux_sym_drop_function_statement_repeat1,
+  [23694] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1901), 1,
+      anon_sym_RPAREN,
+    ACTIONS(2166), 1,
+      anon_sym_COMMA,
+    STATE(789), 1,
+      aux_sym_create_table_statement_repeat1,
+  [23707] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(625), 1,
+      anon_sym_COMMA,
+    ACTIONS(2168), 1,
+      anon_sym_RPAREN,
+    STATE(807), 1,
+      aux_sym_partition_key_repeat1,
+  [23720] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2053), 1,
+      anon_sym_COMMA,
+    ACTIONS(2170), 1,
+      anon_sym_RPAREN,
+    STATE(736), 1,
+      aux_sym_clustering_order_repeat1,
+  [23733] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2047), 1,
+      anon_sym_COMMA,
+    ACTIONS(2172), 1,
+      anon_sym_RPAREN,
+    STATE(840), 1,
+      aux_sym_drop_function_statement_repeat1,
+  [23746] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2174), 1,
+      anon_sym_COMMA,
+    ACTIONS(2177), 1,
+      anon_sym_RPAREN,
+    STATE(789), 1,
+      aux_sym_create_table_statement_repeat1,
+  [23759] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(625), 1,
+      anon_sym_COMMA,
+    ACTIONS(2179), 1,
+      anon_sym_RPAREN,
+    STATE(733), 1,
+      aux_sym_partition_key_repeat1,
+  [23772] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2070), 1,
+      sym_keyword_called,
+    ACTIONS(2072), 1,
+      sym_keyword_returns,
+    STATE(1110), 1,
+      sym_null_input_behavior,
+  [23785] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2181), 1,
+      anon_sym_COMMA,
+    ACTIONS(2183), 1,
+      anon_sym_RPAREN,
+    STATE(802), 1,
+      aux_sym_primary_key_definition_repeat1,
+  [23798] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2074), 1,
+      anon_sym_COMMA,
+    ACTIONS(2185), 1,
+      anon_sym_RPAREN,
+    STATE(795), 1,
+      aux_sym_create_function_statement_repeat1,
+  [23811] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2070), 1,
+      sym_keyword_called,
+    ACTIONS(2072), 1,
+      sym_keyword_returns,
+    STATE(950), 1,
+      sym_null_input_behavior,
+  [23824] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2187), 1,
+      anon_sym_COMMA,
+    ACTIONS(2190), 1,
+      anon_sym_RPAREN,
+    STATE(795), 1,
+      aux_sym_create_function_statement_repeat1,
+  [23837] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2192), 3,
+      anon_sym_EQ,
+      anon_sym_PLUS_EQ,
+      anon_sym_DASH_EQ,
+  [23846] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2047), 1,
+      anon_sym_COMMA,
+    ACTIONS(2194), 1,
+      anon_sym_GT,
+    STATE(735), 1,
+      aux_sym_drop_function_statement_repeat1,
+  [23859] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2047), 1,
+      anon_sym_COMMA,
+    ACTIONS(2196), 1,
+      anon_sym_RPAREN,
+    STATE(737), 1,
+      aux_sym_drop_function_statement_repeat1,
+  [23872] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(625), 1,
+      anon_sym_COMMA,
+    ACTIONS(2198), 1,
+      anon_sym_RPAREN,
+    STATE(739), 1,
+      aux_sym_partition_key_repeat1,
+  [23885] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2070), 1,
+      sym_keyword_called,
+    ACTIONS(2072), 1,
+      sym_keyword_returns,
+    STATE(1077), 1,
+      sym_null_input_behavior,
+  [23898] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(625), 1,
+      anon_sym_COMMA,
+    ACTIONS(2127), 1,
+      anon_sym_RPAREN,
+    STATE(168), 1,
+      aux_sym_partition_key_repeat1,
+  [23911] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2181), 1,
+      anon_sym_COMMA,
+    ACTIONS(2200), 1,
+      anon_sym_RPAREN,
+    STATE(740), 1,
+      aux_sym_primary_key_definition_repeat1,
+  [23924] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2074), 1,
+      anon_sym_COMMA,
+    ACTIONS(2202), 1,
+      anon_sym_RPAREN,
+    STATE(746), 1,
+      aux_sym_create_function_statement_repeat1,
+  [23937] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1948), 1,
+      anon_sym_RPAREN,
+    ACTIONS(2204), 1,
+      anon_sym_COMMA,
+    STATE(806), 1,
+      aux_sym_create_type_statement_repeat1,
+  [23950] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2025), 1,
+      anon_sym_RPAREN,
+    ACTIONS(2206), 1,
+      anon_sym_COMMA,
+    STATE(805), 1,
+      aux_sym_relation_repeat1,
+  [23963] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2209), 1,
+      anon_sym_COMMA,
+    ACTIONS(2212), 1,
+      anon_sym_RPAREN,
+    STATE(806), 1,
+      aux_sym_create_type_statement_repeat1,
+  [23976] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(625), 1,
+      anon_sym_COMMA,
+    ACTIONS(2214), 1,
+      anon_sym_RPAREN,
+    STATE(168), 1,
+      aux_sym_partition_key_repeat1,
+  [23989] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(625), 1,
+      anon_sym_COMMA,
+    ACTIONS(2216), 1,
+      anon_sym_RPAREN,
+    STATE(756), 1,
+      aux_sym_partition_key_repeat1,
+  [24002] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2027), 1,
+      anon_sym_COMMA,
+    ACTIONS(2218), 1,
+      anon_sym_RBRACK,
+    STATE(824), 1,
+      aux_sym_relation_repeat1,
+  [24015] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2220), 1,
+      anon_sym_COMMA,
+    ACTIONS(2223), 1,
+      anon_sym_RBRACE,
+    STATE(810), 1,
+      aux_sym_map_literal_repeat1,
+  [24028] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2148), 1,
+      anon_sym_COMMA,
+    ACTIONS(2225), 1,
+      sym_keyword_from,
+    STATE(776), 1,
+      aux_sym_delete_statement_repeat1,
+  [24041] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(207), 1,
+      sym_using_option,
+    ACTIONS(2097), 2,
+      sym_keyword_ttl,
+      sym_keyword_timestamp,
+  [24052] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2070), 1,
+      sym_keyword_called,
+    ACTIONS(2072), 1,
+      sym_keyword_returns,
+    STATE(911), 1,
+      sym_null_input_behavior,
+  [24065] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2047), 1,
+      anon_sym_COMMA,
+    ACTIONS(2227), 1,
+      anon_sym_RPAREN,
+    STATE(702), 1,
+      aux_sym_drop_function_statement_repeat1,
+  [24078] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2229), 1,
+      anon_sym_COMMA,
+    ACTIONS(2231), 1,
+      anon_sym_RBRACE,
+    STATE(835), 1,
+      aux_sym_map_literal_repeat1,
+  [24091] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(196), 1,
+      anon_sym_LPAREN,
+    ACTIONS(198), 1,
+      anon_sym_DOT,
+    ACTIONS(2031), 1,
+      anon_sym_COLON,
+  [24104] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2047), 1,
+      anon_sym_COMMA,
+    ACTIONS(2233), 1,
+      anon_sym_RPAREN,
+    STATE(702), 1,
+      aux_sym_drop_function_statement_repeat1,
+  [24117] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2047), 1,
+      anon_sym_COMMA,
+    ACTIONS(2235), 1,
+      anon_sym_RPAREN,
+    STATE(702), 1,
+      aux_sym_drop_function_statement_repeat1,
+  [24130] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2041), 1,
+      anon_sym_COMMA,
+    ACTIONS(2237), 1,
+      sym_keyword_from,
+    STATE(732), 1,
+      aux_sym_selection_repeat1,
+  [24143] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2239), 1,
+      anon_sym_COMMA,
+    ACTIONS(2241), 1,
+      anon_sym_RPAREN,
+    STATE(754), 1,
+      aux_sym_function_call_repeat1,
+  [24156] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2243), 1,
+      anon_sym_COMMA,
+    ACTIONS(2246), 1,
+      sym_keyword_from,
+    STATE(821), 1,
+      aux_sym_selection_repeat1,
+  [24169] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2089), 1,
+      anon_sym_COMMA,
+    ACTIONS(2248), 1,
+      anon_sym_RPAREN,
+    STATE(805), 1,
+      aux_sym_relation_repeat1,
+  [24182] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2070), 1,
+      sym_keyword_called,
+    ACTIONS(2072), 1,
+      sym_keyword_returns,
+    STATE(1062), 1,
+      sym_null_input_behavior,
+  [24195] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2027), 1,
+      anon_sym_COMMA,
+    ACTIONS(2250), 1,
+      anon_sym_RBRACK,
+    STATE(730), 1,
+      aux_sym_relation_repeat1,
+  [24208] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2074), 1,
+      anon_sym_COMMA,
+    ACTIONS(2252), 1,
+      anon_sym_RPAREN,
+    STATE(795), 1,
+      aux_sym_create_function_statement_repeat1,
+  [24221] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(625), 1,
+      anon_sym_COMMA,
+    ACTIONS(2254), 1,
+      anon_sym_RPAREN,
+    STATE(168), 1,
+      aux_sym_partition_key_repeat1,
+  [24234] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2070), 1,
+      sym_keyword_called,
+    ACTIONS(2072), 1,
+      sym_keyword_returns,
+    STATE(1074), 1,
+      sym_null_input_behavior,
+  [24247] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2027), 1,
+      anon_sym_COMMA,
+    ACTIONS(2256), 1,
+      anon_sym_RBRACE,
+    STATE(730), 1,
+      aux_sym_relation_repeat1,
+  [24260] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2047), 1,
+      anon_sym_COMMA,
+    ACTIONS(2258), 1,
+      anon_sym_RPAREN,
+    STATE(814), 1,
+      aux_sym_drop_function_statement_repeat1,
+  [24273] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2262), 1,
+      sym_keyword_as,
+    ACTIONS(2260), 2,
+      anon_sym_COMMA,
+      sym_keyword_from,
+  [24284] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2047), 1,
+      anon_sym_COMMA,
+    ACTIONS(2264), 1,
+      anon_sym_RPAREN,
+    STATE(702), 1,
+      aux_sym_drop_function_statement_repeat1,
+  [24297] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2117), 1,
+      anon_sym_COMMA,
+    ACTIONS(2266), 1,
+      anon_sym_RPAREN,
+    STATE(766), 1,
+      aux_sym_alter_table_statement_repeat1,
+  [24310] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2089), 1,
+      anon_sym_COMMA,
+    ACTIONS(2268), 1,
+      anon_sym_RPAREN,
+    STATE(805), 1,
+      aux_sym_relation_repeat1,
+  [24323] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2047), 1,
+      anon_sym_COMMA,
+    ACTIONS(2270), 1,
+      anon_sym_RPAREN,
+    STATE(818), 1,
+      aux_sym_drop_function_statement_repeat1,
+  [24336] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2229), 1,
+      anon_sym_COMMA,
+    ACTIONS(2272), 1,
+      anon_sym_RBRACE,
+    STATE(810), 1,
+      aux_sym_map_literal_repeat1,
+  [24349] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2089), 1,
+      anon_sym_COMMA,
+    ACTIONS(2274), 1,
+      anon_sym_RPAREN,
+    STATE(750), 1,
+      aux_sym_relation_repeat1,
+  [24362] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2089), 1,
+      anon_sym_COMMA,
+    ACTIONS(2276), 1,
+      anon_sym_RPAREN,
+    STATE(751), 1,
+      aux_sym_relation_repeat1,
+  [24375] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(625), 1,
+      anon_sym_COMMA,
+    ACTIONS(2266), 1,
+      anon_sym_RPAREN,
+    STATE(772), 1,
+      aux_sym_partition_key_repeat1,
+  [24388] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2047), 1,
+      anon_sym_COMMA,
+    ACTIONS(2278), 1,
+      anon_sym_RPAREN,
+    STATE(817), 1,
+      aux_sym_drop_function_statement_repeat1,
+  [24401] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2047), 1,
+      anon_sym_COMMA,
+    ACTIONS(2280), 1,
+      anon_sym_RPAREN,
+    STATE(702), 1,
+      aux_sym_drop_function_statement_repeat1,
+  [24414] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2239), 1,
+      anon_sym_COMMA,
+    ACTIONS(2282), 1,
+      anon_sym_RPAREN,
+    STATE(820), 1,
+      aux_sym_function_call_repeat1,
+  [24427] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(669), 1,
+      anon_sym_RPAREN,
+    ACTIONS(2284), 1,
+      anon_sym_COMMA,
+    STATE(842), 1,
+      aux_sym_clustering_order_repeat1,
+  [24440] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2117), 1,
+      anon_sym_COMMA,
+    ACTIONS(2254), 1,
+      anon_sym_RPAREN,
+    STATE(749), 1,
+      aux_sym_alter_table_statement_repeat1,
+  [24453] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2117), 1,
+      anon_sym_COMMA,
+    ACTIONS(2254), 1,
+      anon_sym_RPAREN,
+    STATE(781), 1,
+      aux_sym_alter_table_statement_repeat1,
+  [24466] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(625), 1,
+      anon_sym_COMMA,
+    ACTIONS(2254), 1,
+      anon_sym_RPAREN,
+    STATE(782), 1,
+      aux_sym_partition_key_repeat1,
+  [24479] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2287), 2,
+      sym_string_literal,
+      sym_dollar_string,
+  [24487] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2289), 1,
+      anon_sym_LBRACE,
+    STATE(463), 1,
+      sym_map_literal,
+  [24497] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2289), 1,
+      anon_sym_LBRACE,
+    STATE(464), 1,
+      sym_map_literal,
+  [24507] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2291), 2,
+      sym_string_literal,
+      sym_dollar_string,
+  [24515] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2223), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+  [24523] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2293), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [24531] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2295), 1,
+      sym_keyword_add,
+    ACTIONS(2297), 1,
+      sym_keyword_rename,
+  [24541] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2299), 1,
+      sym_keyword_aggregate,
+    ACTIONS(2301), 1,
+      sym_keyword_function,
+  [24551] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2107), 2,
+      anon_sym_COMMA,
+      sym_keyword_where,
+  [24559] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2303), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [24567] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(464), 1,
+      sym_keyword_where,
+    STATE(213), 1,
+      sym_where_clause,
+  [24577] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2305), 2,
+      sym_string_literal,
+      sym_dollar_string,
+  [24585] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(464), 1,
+      sym_keyword_where,
+    STATE(896), 1,
+      sym_where_clause,
+  [24595] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2177), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [24603] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2307), 2,
+      sym_string_literal,
+      sym_dollar_string,
+  [24611] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(464), 1,
+      sym_keyword_where,
+    STATE(215), 1,
+      sym_where_clause,
+  [24621] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2309), 2,
+      sym_keyword_null,
+      sym_keyword_unset,
+  [24629] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2146), 2,
+      anon_sym_COMMA,
+      sym_keyword_from,
+  [24637] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2289), 1,
+      anon_sym_LBRACE,
+    STATE(472), 1,
+      sym_map_literal,
+  [24647] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2311), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [24655] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2313), 1,
+      anon_sym_LPAREN,
+    ACTIONS(2315), 1,
+      sym_keyword_json,
+  [24665] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2016), 1,
+      anon_sym_PLUS,
+    ACTIONS(2018), 1,
+      anon_sym_DASH,
+  [24675] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2190), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [24683] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2317), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [24691] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2319), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+  [24699] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2321), 2,
+      sym_string_literal,
+      sym_dollar_string,
+  [24707] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2323), 2,
+      sym_string_literal,
+      sym_dollar_string,
+  [24715] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2325), 2,
+      sym_string_literal,
+      sym_dollar_string,
+  [24723] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2327), 2,
+      sym_string_literal,
+      sym_dollar_string,
+  [24731] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(464), 1,
+      sym_keyword_where,
+    STATE(211), 1,
+      sym_where_clause,
+  [24741] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2329), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [24749] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2289), 1,
+      anon_sym_LBRACE,
+    STATE(454), 1,
+      sym_map_literal,
+  [24759] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2331), 2,
+      sym_string_literal,
+      sym_dollar_string,
+  [24767] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2333), 1,
+      sym_keyword_add,
+    ACTIONS(2335), 1,
+      sym_keyword_rename,
+  [24777] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(561), 1,
+      anon_sym_RPAREN,
+    ACTIONS(2337), 1,
+      anon_sym_COMMA,
+  [24787] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2339), 2,
+      anon_sym_COMMA,
+      sym_keyword_where,
+  [24795] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2341), 2,
+      anon_sym_COMMA,
+      sym_keyword_from,
+  [24803] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1893), 1,
+      sym_keyword_primary,
+    STATE(295), 1,
+      sym_primary_key_definition,
+  [24813] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2343), 2,
+      sym_string_literal,
+      sym_dollar_string,
+  [24821] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2345), 2,
+      sym_string_literal,
+      sym_dollar_string,
+  [24829] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(464), 1,
+      sym_keyword_where,
+    STATE(883), 1,
+      sym_where_clause,
+  [24839] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2347), 2,
+      sym_string_literal,
+      sym_dollar_string,
+  [24847] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2087), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [24855] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2289), 1,
+      anon_sym_LBRACE,
+    STATE(438), 1,
+      sym_map_literal,
+  [24865] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2089), 1,
+      anon_sym_COMMA,
+    STATE(822), 1,
+      aux_sym_relation_repeat1,
+  [24875] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2246), 2,
+      anon_sym_COMMA,
+      sym_keyword_from,
+  [24883] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2212), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [24891] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2349), 2,
+      anon_sym_COMMA,
+      sym_keyword_where,
+  [24899] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2102), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [24907] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(565), 1,
+      anon_sym_RPAREN,
+    ACTIONS(2351), 1,
+      anon_sym_LPAREN,
+  [24917] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1893), 1,
+      sym_keyword_primary,
+    STATE(276), 1,
+      sym_primary_key_definition,
+  [24927] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2289), 1,
+      anon_sym_LBRACE,
+    STATE(451), 1,
+      sym_map_literal,
+  [24937] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2353), 1,
+      sym_keyword_filtering,
+  [24944] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2355), 1,
+      sym_keyword_exists,
+  [24951] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2357), 1,
+      sym_string_literal,
+  [24958] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2359), 1,
+      anon_sym_LPAREN,
+  [24965] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2361), 1,
+      sym_keyword_as,
+  [24972] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2363), 1,
+      anon_sym_RPAREN,
+  [24979] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2365), 1,
+      sym_keyword_language,
+  [24986] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2367), 1,
+      anon_sym_LPAREN,
+  [24993] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2369), 1,
+      sym_keyword_as,
+  [25000] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2371), 1,
+      sym_keyword_language,
+  [25007] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2373), 1,
+      anon_sym_EQ,
+  [25014] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2375), 1,
+      sym_keyword_options,
+  [25021] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2377), 1,
+      aux_sym_float_token4,
+  [25028] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2379), 1,
+      sym_keyword_returns,
+  [25035] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2381), 1,
+      sym_keyword_returns,
+  [25042] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2383), 1,
+      sym_keyword_stype,
+  [25049] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2385), 1,
+      sym_keyword_input,
+  [25056] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2387), 1,
+      sym_keyword_stype,
+  [25063] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2389), 1,
+      sym_keyword_key,
+  [25070] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2391), 1,
+      sym_keyword_stype,
+  [25077] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2393), 1,
+      sym_keyword_language,
+  [25084] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2395), 1,
+      sym_keyword_language,
+  [25091] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2397), 1,
+      sym_keyword_returns,
+  [25098] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2399), 1,
+      sym_keyword_input,
+  [25105] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2401), 1,
+      sym_keyword_language,
+  [25112] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2403), 1,
+      anon_sym_RPAREN,
+  [25119] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2405), 1,
+      anon_sym_LPAREN,
+  [25126] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2407), 1,
+      sym_string_literal,
+  [25133] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2409), 1,
+      anon_sym_LPAREN,
+  [25140] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2411), 1,
+      anon_sym_LPAREN,
+  [25147] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2413), 1,
+      sym_keyword_null,
+  [25154] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2415), 1,
+      anon_sym_LPAREN,
+  [25161] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2417), 1,
+      anon_sym_LPAREN,
+  [25168] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2419), 1,
+      sym_keyword_by,
+  [25175] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2421), 1,
+      sym_keyword_type,
+  [25182] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2423), 1,
+      sym_keyword_filtering,
+  [25189] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2425), 1,
+      sym_keyword_sfunc,
+  [25196] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2427), 1,
+      sym_keyword_language,
+  [25203] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2429), 1,
+      sym_keyword_stype,
+  [25210] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2431), 1,
+      sym_keyword_index,
+  [25217] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2433), 1,
+      anon_sym_LPAREN,
+  [25224] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2435), 1,
+      sym_keyword_sfunc,
+  [25231] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2437), 1,
+      sym_keyword_returns,
+  [25238] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2439), 1,
+      anon_sym_RBRACK,
+  [25245] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2441), 1,
+      sym_keyword_with,
+  [25252] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2443), 1,
+      anon_sym_LPAREN,
+  [25259] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2445), 1,
+      sym_keyword_view,
+  [25266] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2447), 1,
+      sym_keyword_sfunc,
+  [25273] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2449), 1,
+      anon_sym_EQ,
+  [25280] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2451), 1,
+      sym_keyword_options,
+  [25287] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2453), 1,
+      sym_keyword_returns,
+  [25294] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2455), 1,
+      sym_keyword_options,
+  [25301] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2457), 1,
+      sym_keyword_returns,
+  [25308] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2459), 1,
+      sym_keyword_as,
+  [25315] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2461), 1,
+      anon_sym_LPAREN,
+  [25322] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2463), 1,
+      sym_keyword_stype,
+  [25329] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2465), 1,
+      sym_keyword_batch,
+  [25336] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2467), 1,
+      sym_keyword_as,
+  [25343] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2469), 1,
+      anon_sym_LPAREN,
+  [25350] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2471), 1,
+      anon_sym_EQ,
+  [25357] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2473), 1,
+      anon_sym_RPAREN,
+  [25364] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2475), 1,
+      sym_string_literal,
+  [25371] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2477), 1,
+      sym_keyword_sfunc,
+  [25378] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2479), 1,
+      sym_keyword_on,
+  [25385] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2481), 1,
+      sym_keyword_stype,
+  [25392] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2483), 1,
+      sym_keyword_null,
+  [25399] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2485), 1,
+      anon_sym_LT,
+  [25406] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2487), 1,
+      sym_keyword_returns,
+  [25413] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2489), 1,
+      sym_keyword_with,
+  [25420] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2491), 1,
+      sym_keyword_filtering,
+  [25427] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2493), 1,
+      sym_keyword_language,
+  [25434] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2495), 1,
+      anon_sym_LT,
+  [25441] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2497), 1,
+      sym_keyword_as,
+  [25448] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2499), 1,
+      sym_keyword_filtering,
+  [25455] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2501), 1,
+      sym_keyword_language,
+  [25462] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2503), 1,
+      anon_sym_LT,
+  [25469] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2505), 1,
+      sym_keyword_into,
+  [25476] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2507), 1,
+      sym_keyword_values,
+  [25483] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2509), 1,
+      anon_sym_LT,
+  [25490] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2511), 1,
+      sym_keyword_with,
+  [25497] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2513), 1,
+      anon_sym_RPAREN,
+  [25504] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2515), 1,
+      sym_keyword_filtering,
+  [25511] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2517), 1,
+      anon_sym_LPAREN,
+  [25518] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2519), 1,
+      sym_keyword_filtering,
+  [25525] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2521), 1,
+      anon_sym_LT,
+  [25532] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2523), 1,
+      sym_keyword_returns,
+  [25539] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2525), 1,
+      sym_keyword_from,
+  [25546] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2527), 1,
+      sym_keyword_on,
+  [25553] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2529), 1,
+      sym_keyword_sfunc,
+  [25560] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2531), 1,
+      sym_keyword_null,
+  [25567] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2533), 1,
+      sym_keyword_on,
+  [25574] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2535), 1,
+      anon_sym_GT,
+  [25581] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2537), 1,
+      anon_sym_LPAREN,
+  [25588] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2539), 1,
+      sym_keyword_set,
+  [25595] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2541), 1,
+      anon_sym_EQ,
+  [25602] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2543), 1,
+      sym_keyword_options,
+  [25609] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2545), 1,
+      anon_sym_EQ,
+  [25616] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2547), 1,
+      sym_keyword_returns,
+  [25623] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2549), 1,
+      sym_keyword_replace,
+  [25630] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2551), 1,
+      sym_keyword_as,
+  [25637] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2553), 1,
+      sym_keyword_filtering,
+  [25644] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2555), 1,
+      anon_sym_LPAREN,
+  [25651] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2557), 1,
+      sym_keyword_filtering,
+  [25658] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2559), 1,
+      sym_integer,
+  [25665] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2561), 1,
+      anon_sym_LPAREN,
+  [25672] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2563), 1,
+      anon_sym_GT,
+  [25679] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2565), 1,
+      anon_sym_RPAREN,
+  [25686] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2567), 1,
+      anon_sym_RPAREN,
+  [25693] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2569), 1,
+      anon_sym_COMMA,
+  [25700] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2571), 1,
+      sym_keyword_from,
+  [25707] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2573), 1,
+      sym_keyword_filtering,
+  [25714] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2575), 1,
+      sym_keyword_sfunc,
+  [25721] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2577), 1,
+      sym_keyword_stype,
+  [25728] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2579), 1,
+      anon_sym_RPAREN,
+  [25735] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2581), 1,
+      anon_sym_COMMA,
+  [25742] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2583), 1,
+      sym_keyword_key,
+  [25749] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2585), 1,
+      sym_keyword_options,
+  [25756] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2587), 1,
+      anon_sym_RPAREN,
+  [25763] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2589), 1,
+      sym_keyword_language,
+  [25770] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2591), 1,
+      anon_sym_LPAREN,
+  [25777] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2593), 1,
+      sym_keyword_as,
+  [25784] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2595), 1,
+      sym_keyword_batch,
+  [25791] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2597), 1,
+      sym_keyword_on,
+  [25798] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2599), 1,
+      anon_sym_RPAREN,
+  [25805] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2601), 1,
+      sym_keyword_to,
+  [25812] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2603), 1,
+      sym_keyword_order,
+  [25819] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2605), 1,
+      sym_keyword_select,
+  [25826] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2607), 1,
+      sym_keyword_with,
+  [25833] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2609), 1,
+      sym_keyword_storage,
+  [25840] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2611), 1,
+      anon_sym_LPAREN,
+  [25847] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2613), 1,
+      sym_keyword_sfunc,
+  [25854] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2615), 1,
+      sym_string_literal,
+  [25861] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2617), 1,
+      anon_sym_LPAREN,
+  [25868] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2619), 1,
+      sym_string_literal,
+  [25875] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2621), 1,
+      sym_keyword_filtering,
+  [25882] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2623), 1,
+      anon_sym_EQ,
+  [25889] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2625), 1,
+      sym_keyword_language,
+  [25896] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2627), 1,
+      sym_keyword_not,
+  [25903] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2629), 1,
+      anon_sym_EQ,
+  [25910] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2031), 1,
+      anon_sym_COLON,
+  [25917] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2631), 1,
+      sym_keyword_returns,
+  [25924] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2633), 1,
+      sym_keyword_as,
+  [25931] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2635), 1,
+      sym_keyword_stype,
+  [25938] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(212), 1,
+      sym_keyword_null,
+  [25945] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2637), 1,
+      sym_keyword_language,
+  [25952] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2639), 1,
+      sym_keyword_options,
+  [25959] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2641), 1,
+      anon_sym_LPAREN,
+  [25966] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2643), 1,
+      anon_sym_LPAREN,
+  [25973] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2645), 1,
+      sym_keyword_limit,
+  [25980] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2647), 1,
+      sym_keyword_filtering,
+  [25987] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2649), 1,
+      sym_keyword_with,
+  [25994] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2651), 1,
+      sym_keyword_by,
+  [26001] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2653), 1,
+      sym_keyword_sfunc,
+  [26008] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2655), 1,
+      sym_keyword_as,
+  [26015] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2657), 1,
+      sym_keyword_stype,
+  [26022] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2351), 1,
+      anon_sym_LPAREN,
+  [26029] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2659), 1,
+      sym_keyword_sfunc,
+  [26036] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2661), 1,
+      sym_keyword_as,
+  [26043] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2663), 1,
+      sym_keyword_by,
+  [26050] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2665), 1,
+      sym_keyword_partition,
+  [26057] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2667), 1,
+      sym_keyword_filtering,
+  [26064] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2669), 1,
+      sym_keyword_stype,
+  [26071] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2671), 1,
+      sym_keyword_key,
+  [26078] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2673), 1,
+      sym_keyword_batch,
+  [26085] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2675), 1,
+      sym_keyword_returns,
+  [26092] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2677), 1,
+      sym_keyword_view,
+  [26099] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2679), 1,
+      anon_sym_LPAREN,
+  [26106] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2681), 1,
+      anon_sym_LPAREN,
+  [26113] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2683), 1,
+      sym_keyword_values,
+  [26120] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2685), 1,
+      sym_keyword_sfunc,
+  [26127] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2687), 1,
+      sym_keyword_from,
+  [26134] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2689), 1,
+      sym_keyword_from,
+  [26141] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2691), 1,
+      sym_keyword_language,
+  [26148] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2693), 1,
+      anon_sym_GT,
+  [26155] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2695), 1,
+      sym_keyword_as,
+  [26162] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2697), 1,
+      anon_sym_GT,
+  [26169] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2699), 1,
+      sym_keyword_returns,
+  [26176] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2701), 1,
+      sym_keyword_as,
+  [26183] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2703), 1,
+      anon_sym_LPAREN,
+  [26190] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2705), 1,
+      sym_keyword_returns,
+  [26197] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2707), 1,
+      anon_sym_RPAREN,
+  [26204] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2709), 1,
+      sym_keyword_filtering,
+  [26211] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2711), 1,
+      anon_sym_LPAREN,
+  [26218] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2282), 1,
+      anon_sym_RPAREN,
+  [26225] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2713), 1,
+      sym_keyword_batch,
+  [26232] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2715), 1,
+      sym_keyword_stype,
+  [26239] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2717), 1,
+      sym_keyword_filtering,
+  [26246] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2719), 1,
+      sym_keyword_sfunc,
+  [26253] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2721), 1,
+      sym_keyword_filtering,
+  [26260] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2237), 1,
+      sym_keyword_from,
+  [26267] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2723), 1,
+      sym_keyword_with,
+  [26274] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2725), 1,
+      sym_keyword_type,
+  [26281] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2727), 1,
+      sym_keyword_filtering,
+  [26288] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2729), 1,
+      sym_keyword_as,
+  [26295] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2731), 1,
+      sym_keyword_select,
+  [26302] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2733), 1,
+      sym_keyword_stype,
+  [26309] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2735), 1,
+      sym_keyword_filtering,
+  [26316] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2737), 1,
+      anon_sym_RPAREN,
+  [26323] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2739), 1,
+      sym_keyword_batch,
+  [26330] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2741), 1,
+      sym_keyword_as,
+  [26337] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2743), 1,
+      sym_keyword_view,
+  [26344] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2745), 1,
+      sym_keyword_filtering,
+  [26351] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2747), 1,
+      sym_keyword_view,
+  [26358] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2749), 1,
+      sym_keyword_from,
+  [26365] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2751), 1,
+      ts_builtin_sym_end,
+  [26372] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2753), 1,
+      sym_keyword_not,
+  [26379] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2755), 1,
+      anon_sym_RPAREN,
+  [26386] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2757), 1,
+      sym_keyword_on,
+  [26393] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2759), 1,
+      anon_sym_LPAREN,
+  [26400] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2761), 1,
+      sym_keyword_sfunc,
+  [26407] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2763), 1,
+      anon_sym_LPAREN,
+  [26414] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1867), 1,
+      sym_keyword_exists,
+  [26421] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2765), 1,
+      sym_keyword_returns,
+  [26428] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2767), 1,
+      anon_sym_RPAREN,
+  [26435] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2769), 1,
+      sym_string_literal,
+  [26442] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2771), 1,
+      sym_keyword_as,
+};
+
+static const uint32_t ts_small_parse_table_map[] = {
+  [SMALL_STATE(5)] = 0,
+  [SMALL_STATE(6)] = 71,
+  [SMALL_STATE(7)] = 142,
+  [SMALL_STATE(8)] = 240,
+  [SMALL_STATE(9)] = 335,
+  [SMALL_STATE(10)] = 412,
+  [SMALL_STATE(11)] = 489,
+  [SMALL_STATE(12)] = 581,
+  [SMALL_STATE(13)] = 673,
+  [SMALL_STATE(14)] = 749,
+  [SMALL_STATE(15)] = 825,
+  [SMALL_STATE(16)] = 901,
+  [SMALL_STATE(17)] = 977,
+  [SMALL_STATE(18)] = 1053,
+  [SMALL_STATE(19)] = 1129,
+  [SMALL_STATE(20)] = 1205,
+  [SMALL_STATE(21)] = 1297,
+  [SMALL_STATE(22)] = 1373,
+  [SMALL_STATE(23)] = 1465,
+  [SMALL_STATE(24)] = 1538,
+  [SMALL_STATE(25)] = 1611,
+  [SMALL_STATE(26)] = 1684,
+  [SMALL_STATE(27)] = 1773,
+  [SMALL_STATE(28)] = 1846,
+  [SMALL_STATE(29)] = 1919,
+  [SMALL_STATE(30)] = 1992,
+  [SMALL_STATE(31)] = 2065,
+  [SMALL_STATE(32)] = 2138,
+  [SMALL_STATE(33)] = 2211,
+  [SMALL_STATE(34)] = 2284,
+  [SMALL_STATE(35)] = 2357,
+  [SMALL_STATE(36)] = 2430,
+  [SMALL_STATE(37)] = 2503,
+  [SMALL_STATE(38)] = 2576,
+  [SMALL_STATE(39)] = 2649,
+  [SMALL_STATE(40)] = 2722,
+  [SMALL_STATE(41)] = 2795,
+  [SMALL_STATE(42)] = 2868,
+  [SMALL_STATE(43)] = 2941,
+  [SMALL_STATE(44)] = 3014,
+  [SMALL_STATE(45)] = 3087,
+  [SMALL_STATE(46)] = 3160,
+  [SMALL_STATE(47)] = 3233,
+  [SMALL_STATE(48)] = 3306,
+  [SMALL_STATE(49)] = 3379,
+  [SMALL_STATE(50)] = 3452,
+  [SMALL_STATE(51)] = 3525,
+  [SMALL_STATE(52)] = 3598,
+  [SMALL_STATE(53)] = 3671,
+  [SMALL_STATE(54)] = 3744,
+  [SMALL_STATE(55)] = 3817,
+  [SMALL_STATE(56)] = 3890,
+  [SMALL_STATE(57)] = 3963,
+  [SMALL_STATE(58)] = 4036,
+  [SMALL_STATE(59)] = 4109,
+  [SMALL_STATE(60)] = 4182,
+  [SMALL_STATE(61)] = 4255,
+  [SMALL_STATE(62)] = 4341,
+  [SMALL_STATE(63)] = 4424,
+  [SMALL_STATE(64)] = 4507,
+  [SMALL_STATE(65)] = 4589,
+  [SMALL_STATE(66)] = 4670,
+  [SMALL_STATE(67)] = 4751,
+  [SMALL_STATE(68)] = 4798,
+  [SMALL_STATE(69)] = 4874,
+  [SMALL_STATE(70)] = 4952,
+  [SMALL_STATE(71)] = 5030,
+  [SMALL_STATE(72)] = 5108,
+  [SMALL_STATE(73)] = 5186,
+  [SMALL_STATE(74)] = 5230,
+  [SMALL_STATE(75)] = 5308,
+  [SMALL_STATE(76)] = 5352,
+  [SMALL_STATE(77)] = 5396,
+  [SMALL_STATE(78)] = 5471,
+  [SMALL_STATE(79)] = 5546,
+  [SMALL_STATE(80)] = 5621,
+  [SMALL_STATE(81)] = 5696,
+  [SMALL_STATE(82)] = 5771,
+  [SMALL_STATE(83)] = 5846,
+  [SMALL_STATE(84)] = 5921,
+  [SMALL_STATE(85)] = 5996,
+  [SMALL_STATE(86)] = 6071,
+  [SMALL_STATE(87)] = 6146,
+  [SMALL_STATE(88)] = 6221,
+  [SMALL_STATE(89)] = 6296,
+  [SMALL_STATE(90)] = 6371,
+  [SMALL_STATE(91)] = 6446,
+  [SMALL_STATE(92)] = 6521,
+  [SMALL_STATE(93)] = 6596,
+  [SMALL_STATE(94)] = 6671,
+  [SMALL_STATE(95)] = 6746,
+  [SMALL_STATE(96)] = 6821,
+  [SMALL_STATE(97)] = 6896,
+  [SMALL_STATE(98)] = 6971,
+  [SMALL_STATE(99)] = 7046,
+  [SMALL_STATE(100)] = 7121,
+  [SMALL_STATE(101)] = 7196,
+  [SMALL_STATE(102)] = 7271,
+  [SMALL_STATE(103)] = 7346,
+  [SMALL_STATE(104)] = 7421,
+  [SMALL_STATE(105)] = 7496,
+  [SMALL_STATE(106)] = 7571,
+  [SMALL_STATE(107)] = 7646,
+  [SMALL_STATE(108)] = 7721,
+  [SMALL_STATE(109)] = 7796,
+  [SMALL_STATE(110)] = 7871,
+  [SMALL_STATE(111)] = 7946,
+  [SMALL_STATE(112)] = 7993,
+  [SMALL_STATE(113)] = 8068,
+  [SMALL_STATE(114)] = 8143,
+  [SMALL_STATE(115)] = 8218,
+  [SMALL_STATE(116)] = 8259,
+  [SMALL_STATE(117)] = 8300,
+  [SMALL_STATE(118)] = 8341,
+  [SMALL_STATE(119)] = 8382,
+  [SMALL_STATE(120)] = 8423,
+  [SMALL_STATE(121)] = 8464,
+  [SMALL_STATE(122)] = 8505,
+  [SMALL_STATE(123)] = 8546,
+  [SMALL_STATE(124)] = 8587,
+  [SMALL_STATE(125)] = 8628,
+  [SMALL_STATE(126)] = 8669,
+  [SMALL_STATE(127)] = 8710,
+  [SMALL_STATE(128)] = 8751,
+  [SMALL_STATE(129)] = 8792,
+  [SMALL_STATE(130)] = 8833,
+  [SMALL_STATE(131)] = 8874,
+  [SMALL_STATE(132)] = 8915,
+  [SMALL_STATE(133)] = 8954,
+  [SMALL_STATE(134)] = 8993,
+  [SMALL_STATE(135)] = 9048,
+  [SMALL_STATE(136)] = 9103,
+  [SMALL_STATE(137)] = 9158,
+  [SMALL_STATE(138)] = 9194,
+  [SMALL_STATE(139)] = 9230,
+  [SMALL_STATE(140)] = 9264,
+  [SMALL_STATE(141)] = 9300,
+  [SMALL_STATE(142)] = 9349,
+  [SMALL_STATE(143)] = 9398,
+  [SMALL_STATE(144)] = 9429,
+  [SMALL_STATE(145)] = 9460,
+  [SMALL_STATE(146)] = 9491,
+  [SMALL_STATE(147)] = 9522,
+  [SMALL_STATE(148)] = 9571,
+  [SMALL_STATE(149)] = 9602,
+  [SMALL_STATE(150)] = 9633,
+  [SMALL_STATE(151)] = 9664,
+  [SMALL_STATE(152)] = 9695,
+  [SMALL_STATE(153)] = 9725,
+  [SMALL_STATE(154)] = 9755,
+  [SMALL_STATE(155)] = 9785,
+  [SMALL_STATE(156)] = 9815,
+  [SMALL_STATE(157)] = 9845,
+  [SMALL_STATE(158)] = 9875,
+  [SMALL_STATE(159)] = 9905,
+  [SMALL_STATE(160)] = 9935,
+  [SMALL_STATE(161)] = 9965,
+  [SMALL_STATE(162)] = 9995,
+  [SMALL_STATE(163)] = 10038,
+  [SMALL_STATE(164)] = 10081,
+  [SMALL_STATE(165)] = 10114,
+  [SMALL_STATE(166)] = 10157,
+  [SMALL_STATE(167)] = 10190,
+  [SMALL_STATE(168)] = 10233,
+  [SMALL_STATE(169)] = 10266,
+  [SMALL_STATE(170)] = 10295,
+  [SMALL_STATE(171)] = 10338,
+  [SMALL_STATE(172)] = 10381,
+  [SMALL_STATE(173)] = 10413,
+  [SMALL_STATE(174)] = 10441,
+  [SMALL_STATE(175)] = 10473,
+  [SMALL_STATE(176)] = 10501,
+  [SMALL_STATE(177)] = 10539,
+  [SMALL_STATE(178)] = 10576,
+  [SMALL_STATE(179)] = 10605,
+  [SMALL_STATE(180)] = 10636,
+  [SMALL_STATE(181)] = 10663,
+  [SMALL_STATE(182)] = 10700,
+  [SMALL_STATE(183)] = 10727,
+  [SMALL_STATE(184)] = 10758,
+  [SMALL_STATE(185)] = 10795,
+  [SMALL_STATE(186)] = 10832,
+  [SMALL_STATE(187)] = 10867,
+  [SMALL_STATE(188)] = 10898,
+  [SMALL_STATE(189)] = 10929,
+  [SMALL_STATE(190)] = 10966,
+  [SMALL_STATE(191)] = 11001,
+  [SMALL_STATE(192)] = 11036,
+  [SMALL_STATE(193)] = 11071,
+  [SMALL_STATE(194)] = 11106,
+  [SMALL_STATE(195)] = 11143,
+  [SMALL_STATE(196)] = 11180,
+  [SMALL_STATE(197)] = 11207,
+  [SMALL_STATE(198)] = 11244,
+  [SMALL_STATE(199)] = 11275,
+  [SMALL_STATE(200)] = 11312,
+  [SMALL_STATE(201)] = 11349,
+  [SMALL_STATE(202)] = 11380,
+  [SMALL_STATE(203)] = 11407,
+  [SMALL_STATE(204)] = 11444,
+  [SMALL_STATE(205)] = 11471,
+  [SMALL_STATE(206)] = 11501,
+  [SMALL_STATE(207)] = 11531,
+  [SMALL_STATE(208)] = 11557,
+  [SMALL_STATE(209)] = 11587,
+  [SMALL_STATE(210)] = 11613,
+  [SMALL_STATE(211)] = 11643,
+  [SMALL_STATE(212)] = 11673,
+  [SMALL_STATE(213)] = 11703,
+  [SMALL_STATE(214)] = 11733,
+  [SMALL_STATE(215)] = 11763,
+  [SMALL_STATE(216)] = 11793,
+  [SMALL_STATE(217)] = 11823,
+  [SMALL_STATE(218)] = 11852,
+  [SMALL_STATE(219)] = 11879,
+  [SMALL_STATE(220)] = 11910,
+  [SMALL_STATE(221)] = 11941,
+  [SMALL_STATE(222)] = 11966,
+  [SMALL_STATE(223)] = 11997,
+  [SMALL_STATE(224)] = 12028,
+  [SMALL_STATE(225)] = 12059,
+  [SMALL_STATE(226)] = 12084,
+  [SMALL_STATE(227)] = 12115,
+  [SMALL_STATE(228)] = 12144,
+  [SMALL_STATE(229)] = 12173,
+  [SMALL_STATE(230)] = 12202,
+  [SMALL_STATE(231)] = 12231,
+  [SMALL_STATE(232)] = 12260,
+  [SMALL_STATE(233)] = 12289,
+  [SMALL_STATE(234)] = 12320,
+  [SMALL_STATE(235)] = 12349,
+  [SMALL_STATE(236)] = 12378,
+  [SMALL_STATE(237)] = 12407,
+  [SMALL_STATE(238)] = 12438,
+  [SMALL_STATE(239)] = 12469,
+  [SMALL_STATE(240)] = 12508,
+  [SMALL_STATE(241)] = 12539,
+  [SMALL_STATE(242)] = 12570,
+  [SMALL_STATE(243)] = 12601,
+  [SMALL_STATE(244)] = 12625,
+  [SMALL_STATE(245)] = 12649,
+  [SMALL_STATE(246)] = 12677,
+  [SMALL_STATE(247)] = 12701,
+  [SMALL_STATE(248)] = 12729,
+  [SMALL_STATE(249)] = 12757,
+  [SMALL_STATE(250)] = 12785,
+  [SMALL_STATE(251)] = 12813,
+  [SMALL_STATE(252)] = 12837,
+  [SMALL_STATE(253)] = 12865,
+  [SMALL_STATE(254)] = 12893,
+  [SMALL_STATE(255)] = 12917,
+  [SMALL_STATE(256)] = 12945,
+  [SMALL_STATE(257)] = 12969,
+  [SMALL_STATE(258)] = 12997,
+  [SMALL_STATE(259)] = 13025,
+  [SMALL_STATE(260)] = 13053,
+  [SMALL_STATE(261)] = 13081,
+  [SMALL_STATE(262)] = 13109,
+  [SMALL_STATE(263)] = 13137,
+  [SMALL_STATE(264)] = 13165,
+  [SMALL_STATE(265)] = 13193,
+  [SMALL_STATE(266)] = 13221,
+  [SMALL_STATE(267)] = 13249,
+  [SMALL_STATE(268)] = 13273,
+  [SMALL_STATE(269)] = 13297,
+  [SMALL_STATE(270)] = 13321,
+  [SMALL_STATE(271)] = 13349,
+  [SMALL_STATE(272)] = 13377,
+  [SMALL_STATE(273)] = 13405,
+  [SMALL_STATE(274)] = 13428,
+  [SMALL_STATE(275)] = 13453,
+  [SMALL_STATE(276)] = 13478,
+  [SMALL_STATE(277)] = 13503,
+  [SMALL_STATE(278)] = 13528,
+  [SMALL_STATE(279)] = 13551,
+  [SMALL_STATE(280)] = 13576,
+  [SMALL_STATE(281)] = 13601,
+  [SMALL_STATE(282)] = 13624,
+  [SMALL_STATE(283)] = 13649,
+  [SMALL_STATE(284)] = 13672,
+  [SMALL_STATE(285)] = 13695,
+  [SMALL_STATE(286)] = 13720,
+  [SMALL_STATE(287)] = 13745,
+  [SMALL_STATE(288)] = 13770,
+  [SMALL_STATE(289)] = 13795,
+  [SMALL_STATE(290)] = 13824,
+  [SMALL_STATE(291)] = 13849,
+  [SMALL_STATE(292)] = 13874,
+  [SMALL_STATE(293)] = 13899,
+  [SMALL_STATE(294)] = 13922,
+  [SMALL_STATE(295)] = 13947,
+  [SMALL_STATE(296)] = 13972,
+  [SMALL_STATE(297)] = 13997,
+  [SMALL_STATE(298)] = 14020,
+  [SMALL_STATE(299)] = 14045,
+  [SMALL_STATE(300)] = 14070,
+  [SMALL_STATE(301)] = 14093,
+  [SMALL_STATE(302)] = 14116,
+  [SMALL_STATE(303)] = 14139,
+  [SMALL_STATE(304)] = 14162,
+  [SMALL_STATE(305)] = 14187,
+  [SMALL_STATE(306)] = 14210,
+  [SMALL_STATE(307)] = 14235,
+  [SMALL_STATE(308)] = 14258,
+  [SMALL_STATE(309)] = 14283,
+  [SMALL_STATE(310)] = 14308,
+  [SMALL_STATE(311)] = 14331,
+  [SMALL_STATE(312)] = 14354,
+  [SMALL_STATE(313)] = 14379,
+  [SMALL_STATE(314)] = 14402,
+  [SMALL_STATE(315)] = 14427,
+  [SMALL_STATE(316)] = 14450,
+  [SMALL_STATE(317)] = 14473,
+  [SMALL_STATE(318)] = 14496,
+  [SMALL_STATE(319)] = 14521,
+  [SMALL_STATE(320)] = 14546,
+  [SMALL_STATE(321)] = 14569,
+  [SMALL_STATE(322)] = 14592,
+  [SMALL_STATE(323)] = 14615,
+  [SMALL_STATE(324)] = 14640,
+  [SMALL_STATE(325)] = 14665,
+  [SMALL_STATE(326)] = 14690,
+  [SMALL_STATE(327)] = 14715,
+  [SMALL_STATE(328)] = 14740,
+  [SMALL_STATE(329)] = 14765,
+  [SMALL_STATE(330)] = 14790,
+  [SMALL_STATE(331)] = 14815,
+  [SMALL_STATE(332)] = 14840,
+  [SMALL_STATE(333)] = 14863,
+  [SMALL_STATE(334)] = 14886,
+  [SMALL_STATE(335)] = 14911,
+  [SMALL_STATE(336)] = 14936,
+  [SMALL_STATE(337)] = 14961,
+  [SMALL_STATE(338)] = 14984,
+  [SMALL_STATE(339)] = 15009,
+  [SMALL_STATE(340)] = 15034,
+  [SMALL_STATE(341)] = 15059,
+  [SMALL_STATE(342)] = 15084,
+  [SMALL_STATE(343)] = 15109,
+  [SMALL_STATE(344)] = 15134,
+  [SMALL_STATE(345)] = 15157,
+  [SMALL_STATE(346)] = 15180,
+  [SMALL_STATE(347)] = 15205,
+  [SMALL_STATE(348)] = 15230,
+  [SMALL_STATE(349)] = 15255,
+  [SMALL_STATE(350)] = 15280,
+  [SMALL_STATE(351)] = 15303,
+  [SMALL_STATE(352)] = 15326,
+  [SMALL_STATE(353)] = 15351,
+  [SMALL_STATE(354)] = 15374,
+  [SMALL_STATE(355)] = 15399,
+  [SMALL_STATE(356)] = 15421,
+  [SMALL_STATE(357)] = 15443,
+  [SMALL_STATE(358)] = 15465,
+  [SMALL_STATE(359)] = 15487,
+  [SMALL_STATE(360)] = 15509,
+  [SMALL_STATE(361)] = 15531,
+  [SMALL_STATE(362)] = 15553,
+  [SMALL_STATE(363)] = 15575,
+  [SMALL_STATE(364)] = 15597,
+  [SMALL_STATE(365)] = 15619,
+  [SMALL_STATE(366)] = 15641,
+  [SMALL_STATE(367)] = 15663,
+  [SMALL_STATE(368)] = 15685,
+  [SMALL_STATE(369)] = 15707,
+  [SMALL_STATE(370)] = 15729,
+  [SMALL_STATE(371)] = 15751,
+  [SMALL_STATE(372)] = 15773,
+  [SMALL_STATE(373)] = 15795,
+  [SMALL_STATE(374)] = 15817,
+  [SMALL_STATE(375)] = 15839,
+  [SMALL_STATE(376)] = 15861,
+  [SMALL_STATE(377)] = 15883,
+  [SMALL_STATE(378)] = 15905,
+  [SMALL_STATE(379)] = 15927,
+  [SMALL_STATE(380)] = 15949,
+  [SMALL_STATE(381)] = 15971,
+  [SMALL_STATE(382)] = 15993,
+  [SMALL_STATE(383)] = 16015,
+  [SMALL_STATE(384)] = 16037,
+  [SMALL_STATE(385)] = 16059,
+  [SMALL_STATE(386)] = 16081,
+  [SMALL_STATE(387)] = 16103,
+  [SMALL_STATE(388)] = 16125,
+  [SMALL_STATE(389)] = 16147,
+  [SMALL_STATE(390)] = 16169,
+  [SMALL_STATE(391)] = 16191,
+  [SMALL_STATE(392)] = 16213,
+  [SMALL_STATE(393)] = 16235,
+  [SMALL_STATE(394)] = 16257,
+  [SMALL_STATE(395)] = 16279,
+  [SMALL_STATE(396)] = 16301,
+  [SMALL_STATE(397)] = 16323,
+  [SMALL_STATE(398)] = 16345,
+  [SMALL_STATE(399)] = 16367,
+  [SMALL_STATE(400)] = 16389,
+  [SMALL_STATE(401)] = 16411,
+  [SMALL_STATE(402)] = 16433,
+  [SMALL_STATE(403)] = 16455,
+  [SMALL_STATE(404)] = 16477,
+  [SMALL_STATE(405)] = 16499,
+  [SMALL_STATE(406)] = 16521,
+  [SMALL_STATE(407)] = 16543,
+  [SMALL_STATE(408)] = 16565,
+  [SMALL_STATE(409)] = 16587,
+  [SMALL_STATE(410)] = 16609,
+  [SMALL_STATE(411)] = 16631,
+  [SMALL_STATE(412)] = 16653,
+  [SMALL_STATE(413)] = 16675,
+  [SMALL_STATE(414)] = 16697,
+  [SMALL_STATE(415)] = 16719,
+  [SMALL_STATE(416)] = 16741,
+  [SMALL_STATE(417)] = 16763,
+  [SMALL_STATE(418)] = 16785,
+  [SMALL_STATE(419)] = 16807,
+  [SMALL_STATE(420)] = 16829,
+  [SMALL_STATE(421)] = 16851,
+  [SMALL_STATE(422)] = 16873,
+  [SMALL_STATE(423)] = 16895,
+  [SMALL_STATE(424)] = 16917,
+  [SMALL_STATE(425)] = 16939,
+  [SMALL_STATE(426)] = 16961,
+  [SMALL_STATE(427)] = 16983,
+  [SMALL_STATE(428)] = 17005,
+  [SMALL_STATE(429)] = 17027,
+  [SMALL_STATE(430)] = 17049,
+  [SMALL_STATE(431)] = 17071,
+  [SMALL_STATE(432)] = 17093,
+  [SMALL_STATE(433)] = 17115,
+  [SMALL_STATE(434)] = 17137,
+  [SMALL_STATE(435)] = 17159,
+  [SMALL_STATE(436)] = 17181,
+  [SMALL_STATE(437)] = 17203,
+  [SMALL_STATE(438)] = 17225,
+  [SMALL_STATE(439)] = 17247,
+  [SMALL_STATE(440)] = 17269,
+  [SMALL_STATE(441)] = 17291,
+  [SMALL_STATE(442)] = 17313,
+  [SMALL_STATE(443)] = 17335,
+  [SMALL_STATE(444)] = 17357,
+  [SMALL_STATE(445)] = 17379,
+  [SMALL_STATE(446)] = 17401,
+  [SMALL_STATE(447)] = 17423,
+  [SMALL_STATE(448)] = 17445,
+  [SMALL_STATE(449)] = 17467,
+  [SMALL_STATE(450)] = 17489,
+  [SMALL_STATE(451)] = 17511,
+  [SMALL_STATE(452)] = 17533,
+  [SMALL_STATE(453)] = 17555,
+  [SMALL_STATE(454)] = 17577,
+  [SMALL_STATE(455)] = 17599,
+  [SMALL_STATE(456)] = 17621,
+  [SMALL_STATE(457)] = 17643,
+  [SMALL_STATE(458)] = 17665,
+  [SMALL_STATE(459)] = 17687,
+  [SMALL_STATE(460)] = 17709,
+  [SMALL_STATE(461)] = 17731,
+  [SMALL_STATE(462)] = 17753,
+  [SMALL_STATE(463)] = 17775,
+  [SMALL_STATE(464)] = 17797,
+  [SMALL_STATE(465)] = 17819,
+  [SMALL_STATE(466)] = 17841,
+  [SMALL_STATE(467)] = 17863,
+  [SMALL_STATE(468)] = 17885,
+  [SMALL_STATE(469)] = 17907,
+  [SMALL_STATE(470)] = 17929,
+  [SMALL_STATE(471)] = 17951,
+  [SMALL_STATE(472)] = 17973,
+  [SMALL_STATE(473)] = 17995,
+  [SMALL_STATE(474)] = 18017,
+  [SMALL_STATE(475)] = 18039,
+  [SMALL_STATE(476)] = 18061,
+  [SMALL_STATE(477)] = 18083,
+  [SMALL_STATE(478)] = 18105,
+  [SMALL_STATE(479)] = 18127,
+  [SMALL_STATE(480)] = 18149,
+  [SMALL_STATE(481)] = 18171,
+  [SMALL_STATE(482)] = 18193,
+  [SMALL_STATE(483)] = 18215,
+  [SMALL_STATE(484)] = 18237,
+  [SMALL_STATE(485)] = 18261,
+  [SMALL_STATE(486)] = 18281,
+  [SMALL_STATE(487)] = 18312,
+  [SMALL_STATE(488)] = 18343,
+  [SMALL_STATE(489)] = 18374,
+  [SMALL_STATE(490)] = 18405,
+  [SMALL_STATE(491)] = 18436,
+  [SMALL_STATE(492)] = 18467,
+  [SMALL_STATE(493)] = 18498,
+  [SMALL_STATE(494)] = 18529,
+  [SMALL_STATE(495)] = 18560,
+  [SMALL_STATE(496)] = 18591,
+  [SMALL_STATE(497)] = 18622,
+  [SMALL_STATE(498)] = 18653,
+  [SMALL_STATE(499)] = 18684,
+  [SMALL_STATE(500)] = 18711,
+  [SMALL_STATE(501)] = 18744,
+  [SMALL_STATE(502)] = 18775,
+  [SMALL_STATE(503)] = 18793,
+  [SMALL_STATE(504)] = 18811,
+  [SMALL_STATE(505)] = 18841,
+  [SMALL_STATE(506)] = 18865,
+  [SMALL_STATE(507)] = 18883,
+  [SMALL_STATE(508)] = 18911,
+  [SMALL_STATE(509)] = 18941,
+  [SMALL_STATE(510)] = 18959,
+  [SMALL_STATE(511)] = 18984,
+  [SMALL_STATE(512)] = 19011,
+  [SMALL_STATE(513)] = 19036,
+  [SMALL_STATE(514)] = 19063,
+  [SMALL_STATE(515)] = 19088,
+  [SMALL_STATE(516)] = 19113,
+  [SMALL_STATE(517)] = 19138,
+  [SMALL_STATE(518)] = 19159,
+  [SMALL_STATE(519)] = 19184,
+  [SMALL_STATE(520)] = 19203,
+  [SMALL_STATE(521)] = 19222,
+  [SMALL_STATE(522)] = 19248,
+  [SMALL_STATE(523)] = 19276,
+  [SMALL_STATE(524)] = 19302,
+  [SMALL_STATE(525)] = 19324,
+  [SMALL_STATE(526)] = 19352,
+  [SMALL_STATE(527)] = 19374,
+  [SMALL_STATE(528)] = 19398,
+  [SMALL_STATE(529)] = 19420,
+  [SMALL_STATE(530)] = 19446,
+  [SMALL_STATE(531)] = 19470,
+  [SMALL_STATE(532)] = 19498,
+  [SMALL_STATE(533)] = 19522,
+  [SMALL_STATE(534)] = 19548,
+  [SMALL_STATE(535)] = 19576,
+  [SMALL_STATE(536)] = 19600,
+  [SMALL_STATE(537)] = 19624,
+  [SMALL_STATE(538)] = 19648,
+  [SMALL_STATE(539)] = 19676,
+  [SMALL_STATE(540)] = 19698,
+  [SMALL_STATE(541)] = 19726,
+  [SMALL_STATE(542)] = 19751,
+  [SMALL_STATE(543)] = 19776,
+  [SMALL_STATE(544)] = 19801,
+  [SMALL_STATE(545)] = 19826,
+  [SMALL_STATE(546)] = 19851,
+  [SMALL_STATE(547)] = 19876,
+  [SMALL_STATE(548)] = 19901,
+  [SMALL_STATE(549)] = 19926,
+  [SMALL_STATE(550)] = 19951,
+  [SMALL_STATE(551)] = 19974,
+  [SMALL_STATE(552)] = 19999,
+  [SMALL_STATE(553)] = 20024,
+  [SMALL_STATE(554)] = 20047,
+  [SMALL_STATE(555)] = 20070,
+  [SMALL_STATE(556)] = 20095,
+  [SMALL_STATE(557)] = 20118,
+  [SMALL_STATE(558)] = 20143,
+  [SMALL_STATE(559)] = 20168,
+  [SMALL_STATE(560)] = 20193,
+  [SMALL_STATE(561)] = 20218,
+  [SMALL_STATE(562)] = 20243,
+  [SMALL_STATE(563)] = 20268,
+  [SMALL_STATE(564)] = 20293,
+  [SMALL_STATE(565)] = 20318,
+  [SMALL_STATE(566)] = 20343,
+  [SMALL_STATE(567)] = 20366,
+  [SMALL_STATE(568)] = 20391,
+  [SMALL_STATE(569)] = 20411,
+  [SMALL_STATE(570)] = 20433,
+  [SMALL_STATE(571)] = 20453,
+  [SMALL_STATE(572)] = 20473,
+  [SMALL_STATE(573)] = 20493,
+  [SMALL_STATE(574)] = 20513,
+  [SMALL_STATE(575)] = 20533,
+  [SMALL_STATE(576)] = 20553,
+  [SMALL_STATE(577)] = 20573,
+  [SMALL_STATE(578)] = 20595,
+  [SMALL_STATE(579)] = 20615,
+  [SMALL_STATE(580)] = 20635,
+  [SMALL_STATE(581)] = 20653,
+  [SMALL_STATE(582)] = 20673,
+  [SMALL_STATE(583)] = 20695,
+  [SMALL_STATE(584)] = 20715,
+  [SMALL_STATE(585)] = 20735,
+  [SMALL_STATE(586)] = 20755,
+  [SMALL_STATE(587)] = 20775,
+  [SMALL_STATE(588)] = 20795,
+  [SMALL_STATE(589)] = 20815,
+  [SMALL_STATE(590)] = 20833,
+  [SMALL_STATE(591)] = 20853,
+  [SMALL_STATE(592)] = 20873,
+  [SMALL_STATE(593)] = 20890,
+  [SMALL_STATE(594)] = 20907,
+  [SMALL_STATE(595)] = 20924,
+  [SMALL_STATE(596)] = 20941,
+  [SMALL_STATE(597)] = 20958,
+  [SMALL_STATE(598)] = 20975,
+  [SMALL_STATE(599)] = 20992,
+  [SMALL_STATE(600)] = 21009,
+  [SMALL_STATE(601)] = 21026,
+  [SMALL_STATE(602)] = 21043,
+  [SMALL_STATE(603)] = 21060,
+  [SMALL_STATE(604)] = 21077,
+  [SMALL_STATE(605)] = 21090,
+  [SMALL_STATE(606)] = 21107,
+  [SMALL_STATE(607)] = 21126,
+  [SMALL_STATE(608)] = 21143,
+  [SMALL_STATE(609)] = 21162,
+  [SMALL_STATE(610)] = 21179,
+  [SMALL_STATE(611)] = 21196,
+  [SMALL_STATE(612)] = 21213,
+  [SMALL_STATE(613)] = 21230,
+  [SMALL_STATE(614)] = 21247,
+  [SMALL_STATE(615)] = 21264,
+  [SMALL_STATE(616)] = 21281,
+  [SMALL_STATE(617)] = 21298,
+  [SMALL_STATE(618)] = 21315,
+  [SMALL_STATE(619)] = 21332,
+  [SMALL_STATE(620)] = 21349,
+  [SMALL_STATE(621)] = 21366,
+  [SMALL_STATE(622)] = 21383,
+  [SMALL_STATE(623)] = 21400,
+  [SMALL_STATE(624)] = 21417,
+  [SMALL_STATE(625)] = 21434,
+  [SMALL_STATE(626)] = 21451,
+  [SMALL_STATE(627)] = 21468,
+  [SMALL_STATE(628)] = 21485,
+  [SMALL_STATE(629)] = 21502,
+  [SMALL_STATE(630)] = 21519,
+  [SMALL_STATE(631)] = 21536,
+  [SMALL_STATE(632)] = 21553,
+  [SMALL_STATE(633)] = 21570,
+  [SMALL_STATE(634)] = 21587,
+  [SMALL_STATE(635)] = 21606,
+  [SMALL_STATE(636)] = 21623,
+  [SMALL_STATE(637)] = 21640,
+  [SMALL_STATE(638)] = 21657,
+  [SMALL_STATE(639)] = 21674,
+  [SMALL_STATE(640)] = 21691,
+  [SMALL_STATE(641)] = 21708,
+  [SMALL_STATE(642)] = 21725,
+  [SMALL_STATE(643)] = 21742,
+  [SMALL_STATE(644)] = 21759,
+  [SMALL_STATE(645)] = 21776,
+  [SMALL_STATE(646)] = 21793,
+  [SMALL_STATE(647)] = 21812,
+  [SMALL_STATE(648)] = 21829,
+  [SMALL_STATE(649)] = 21846,
+  [SMALL_STATE(650)] = 21863,
+  [SMALL_STATE(651)] = 21880,
+  [SMALL_STATE(652)] = 21897,
+  [SMALL_STATE(653)] = 21914,
+  [SMALL_STATE(654)] = 21931,
+  [SMALL_STATE(655)] = 21945,
+  [SMALL_STATE(656)] = 21959,
+  [SMALL_STATE(657)] = 21973,
+  [SMALL_STATE(658)] = 21987,
+  [SMALL_STATE(659)] = 22003,
+  [SMALL_STATE(660)] = 22017,
+  [SMALL_STATE(661)] = 22031,
+  [SMALL_STATE(662)] = 22045,
+  [SMALL_STATE(663)] = 22061,
+  [SMALL_STATE(664)] = 22075,
+  [SMALL_STATE(665)] = 22089,
+  [SMALL_STATE(666)] = 22103,
+  [SMALL_STATE(667)] = 22117,
+  [SMALL_STATE(668)] = 22131,
+  [SMALL_STATE(669)] = 22145,
+  [SMALL_STATE(670)] = 22155,
+  [SMALL_STATE(671)] = 22169,
+  [SMALL_STATE(672)] = 22183,
+  [SMALL_STATE(673)] = 22197,
+  [SMALL_STATE(674)] = 22211,
+  [SMALL_STATE(675)] = 22225,
+  [SMALL_STATE(676)] = 22239,
+  [SMALL_STATE(677)] = 22253,
+  [SMALL_STATE(678)] = 22267,
+  [SMALL_STATE(679)] = 22281,
+  [SMALL_STATE(680)] = 22295,
+  [SMALL_STATE(681)] = 22309,
+  [SMALL_STATE(682)] = 22323,
+  [SMALL_STATE(683)] = 22337,
+  [SMALL_STATE(684)] = 22351,
+  [SMALL_STATE(685)] = 22365,
+  [SMALL_STATE(686)] = 22379,
+  [SMALL_STATE(687)] = 22395,
+  [SMALL_STATE(688)] = 22409,
+  [SMALL_STATE(689)] = 22423,
+  [SMALL_STATE(690)] = 22437,
+  [SMALL_STATE(691)] = 22451,
+  [SMALL_STATE(692)] = 22465,
+  [SMALL_STATE(693)] = 22479,
+  [SMALL_STATE(694)] = 22493,
+  [SMALL_STATE(695)] = 22507,
+  [SMALL_STATE(696)] = 22523,
+  [SMALL_STATE(697)] = 22537,
+  [SMALL_STATE(698)] = 22553,
+  [SMALL_STATE(699)] = 22567,
+  [SMALL_STATE(700)] = 22581,
+  [SMALL_STATE(701)] = 22595,
+  [SMALL_STATE(702)] = 22609,
+  [SMALL_STATE(703)] = 22623,
+  [SMALL_STATE(704)] = 22637,
+  [SMALL_STATE(705)] = 22651,
+  [SMALL_STATE(706)] = 22665,
+  [SMALL_STATE(707)] = 22679,
+  [SMALL_STATE(708)] = 22693,
+  [SMALL_STATE(709)] = 22707,
+  [SMALL_STATE(710)] = 22721,
+  [SMALL_STATE(711)] = 22735,
+  [SMALL_STATE(712)] = 22749,
+  [SMALL_STATE(713)] = 22759,
+  [SMALL_STATE(714)] = 22773,
+  [SMALL_STATE(715)] = 22787,
+  [SMALL_STATE(716)] = 22801,
+  [SMALL_STATE(717)] = 22815,
+  [SMALL_STATE(718)] = 22831,
+  [SMALL_STATE(719)] = 22845,
+  [SMALL_STATE(720)] = 22855,
+  [SMALL_STATE(721)] = 22871,
+  [SMALL_STATE(722)] = 22887,
+  [SMALL_STATE(723)] = 22901,
+  [SMALL_STATE(724)] = 22915,
+  [SMALL_STATE(725)] = 22929,
+  [SMALL_STATE(726)] = 22939,
+  [SMALL_STATE(727)] = 22949,
+  [SMALL_STATE(728)] = 22961,
+  [SMALL_STATE(729)] = 22971,
+  [SMALL_STATE(730)] = 22985,
+  [SMALL_STATE(731)] = 22999,
+  [SMALL_STATE(732)] = 23013,
+  [SMALL_STATE(733)] = 23026,
+  [SMALL_STATE(734)] = 23039,
+  [SMALL_STATE(735)] = 23052,
+  [SMALL_STATE(736)] = 23065,
+  [SMALL_STATE(737)] = 23078,
+  [SMALL_STATE(738)] = 23091,
+  [SMALL_STATE(739)] = 23104,
+  [SMALL_STATE(740)] = 23117,
+  [SMALL_STATE(741)] = 23130,
+  [SMALL_STATE(742)] = 23143,
+  [SMALL_STATE(743)] = 23156,
+  [SMALL_STATE(744)] = 23169,
+  [SMALL_STATE(745)] = 23182,
+  [SMALL_STATE(746)] = 23195,
+  [SMALL_STATE(747)] = 23208,
+  [SMALL_STATE(748)] = 23221,
+  [SMALL_STATE(749)] = 23234,
+  [SMALL_STATE(750)] = 23247,
+  [SMALL_STATE(751)] = 23260,
+  [SMALL_STATE(752)] = 23273,
+  [SMALL_STATE(753)] = 23286,
+  [SMALL_STATE(754)] = 23297,
+  [SMALL_STATE(755)] = 23310,
+  [SMALL_STATE(756)] = 23323,
+  [SMALL_STATE(757)] = 23336,
+  [SMALL_STATE(758)] = 23349,
+  [SMALL_STATE(759)] = 23362,
+  [SMALL_STATE(760)] = 23375,
+  [SMALL_STATE(761)] = 23388,
+  [SMALL_STATE(762)] = 23399,
+  [SMALL_STATE(763)] = 23412,
+  [SMALL_STATE(764)] = 23425,
+  [SMALL_STATE(765)] = 23438,
+  [SMALL_STATE(766)] = 23451,
+  [SMALL_STATE(767)] = 23464,
+  [SMALL_STATE(768)] = 23477,
+  [SMALL_STATE(769)] = 23490,
+  [SMALL_STATE(770)] = 23503,
+  [SMALL_STATE(771)] = 23516,
+  [SMALL_STATE(772)] = 23529,
+  [SMALL_STATE(773)] = 23542,
+  [SMALL_STATE(774)] = 23555,
+  [SMALL_STATE(775)] = 23568,
+  [SMALL_STATE(776)] = 23581,
+  [SMALL_STATE(777)] = 23594,
+  [SMALL_STATE(778)] = 23607,
+  [SMALL_STATE(779)] = 23620,
+  [SMALL_STATE(780)] = 23633,
+  [SMALL_STATE(781)] = 23642,
+  [SMALL_STATE(782)] = 23655,
+  [SMALL_STATE(783)] = 23668,
+  [SMALL_STATE(784)] = 23681,
+  [SMALL_STATE(785)] = 23694,
+  [SMALL_STATE(786)] = 23707,
+  [SMALL_STATE(787)] = 23720,
+  [SMALL_STATE(788)] = 23733,
+  [SMALL_STATE(789)] = 23746,
+  [SMALL_STATE(790)] = 23759,
+  [SMALL_STATE(791)] = 23772,
+  [SMALL_STATE(792)] = 23785,
+  [SMALL_STATE(793)] = 23798,
+  [SMALL_STATE(794)] = 23811,
+  [SMALL_STATE(795)] = 23824,
+  [SMALL_STATE(796)] = 23837,
+  [SMALL_STATE(797)] = 23846,
+  [SMALL_STATE(798)] = 23859,
+  [SMALL_STATE(799)] = 23872,
+  [SMALL_STATE(800)] = 23885,
+  [SMALL_STATE(801)] = 23898,
+  [SMALL_STATE(802)] = 23911,
+  [SMALL_STATE(803)] = 23924,
+  [SMALL_STATE(804)] = 23937,
+  [SMALL_STATE(805)] = 23950,
+  [SMALL_STATE(806)] = 23963,
+  [SMALL_STATE(807)] = 23976,
+  [SMALL_STATE(808)] = 23989,
+  [SMALL_STATE(809)] = 24002,
+  [SMALL_STATE(810)] = 24015,
+  [SMALL_STATE(811)] = 24028,
+  [SMALL_STATE(812)] = 24041,
+  [SMALL_STATE(813)] = 24052,
+  [SMALL_STATE(814)] = 24065,
+  [SMALL_STATE(815)] = 24078,
+  [SMALL_STATE(816)] = 24091,
+  [SMALL_STATE(817)] = 24104,
+  [SMALL_STATE(818)] = 24117,
+  [SMALL_STATE(819)] = 24130,
+  [SMALL_STATE(820)] = 24143,
+  [SMALL_STATE(821)] = 24156,
+  [SMALL_STATE(822)] = 24169,
+  [SMALL_STATE(823)] = 24182,
+  [SMALL_STATE(824)] = 24195,
+  [SMALL_STATE(825)] = 24208,
+  [SMALL_STATE(826)] = 24221,
+  [SMALL_STATE(827)] = 24234,
+  [SMALL_STATE(828)] = 24247,
+  [SMALL_STATE(829)] = 24260,
+  [SMALL_STATE(830)] = 24273,
+  [SMALL_STATE(831)] = 24284,
+  [SMALL_STATE(832)] = 24297,
+  [SMALL_STATE(833)] = 24310,
+  [SMALL_STATE(834)] = 24323,
+  [SMALL_STATE(835)] = 24336,
+  [SMALL_STATE(836)] = 24349,
+  [SMALL_STATE(837)] = 24362,
+  [SMALL_STATE(838)] = 24375,
+  [SMALL_STATE(839)] = 24388,
+  [SMALL_STATE(840)] = 24401,
+  [SMALL_STATE(841)] = 24414,
+  [SMALL_STATE(842)] = 24427,
+  [SMALL_STATE(843)] = 24440,
+  [SMALL_STATE(844)] = 24453,
+  [SMALL_STATE(845)] = 24466,
+  [SMALL_STATE(846)] = 24479,
+  [SMALL_STATE(847)] = 24487,
+  [SMALL_STATE(848)] = 24497,
+  [SMALL_STATE(849)] = 24507,
+  [SMALL_STATE(850)] = 24515,
+  [SMALL_STATE(851)] = 24523,
+  [SMALL_STATE(852)] = 24531,
+  [SMALL_STATE(853)] = 24541,
+  [SMALL_STATE(854)] = 24551,
+  [SMALL_STATE(855)] = 24559,
+  [SMALL_STATE(856)] = 24567,
+  [SMALL_STATE(857)] = 24577,
+  [SMALL_STATE(858)] = 24585,
+  [SMALL_STATE(859)] = 24595,
+  [SMALL_STATE(860)] = 24603,
+  [SMALL_STATE(861)] = 24611,
+  [SMALL_STATE(862)] = 24621,
+  [SMALL_STATE(863)] = 24629,
+  [SMALL_STATE(864)] = 24637,
+  [SMALL_STATE(865)] = 24647,
+  [SMALL_STATE(866)] = 24655,
+  [SMALL_STATE(867)] = 24665,
+  [SMALL_STATE(868)] = 24675,
+  [SMALL_STATE(869)] = 24683,
+  [SMALL_STATE(870)] = 24691,
+  [SMALL_STATE(871)] = 24699,
+  [SMALL_STATE(872)] = 24707,
+  [SMALL_STATE(873)] = 24715,
+  [SMALL_STATE(874)] = 24723,
+  [SMALL_STATE(875)] = 24731,
+  [SMALL_STATE(876)] = 24741,
+  [SMALL_STATE(877)] = 24749,
+  [SMALL_STATE(878)] = 24759,
+  [SMALL_STATE(879)] = 24767,
+  [SMALL_STATE(880)] = 24777,
+  [SMALL_STATE(881)] = 24787,
+  [SMALL_STATE(882)] = 24795,
+  [SMALL_STATE(883)] = 24803,
+  [SMALL_STATE(884)] = 24813,
+  [SMALL_STATE(885)] = 24821,
+  [SMALL_STATE(886)] = 24829,
+  [SMALL_STATE(887)] = 24839,
+  [SMALL_STATE(888)] = 24847,
+  [SMALL_STATE(889)] = 24855,
+  [SMALL_STATE(890)] = 24865,
+  [SMALL_STATE(891)] = 24875,
+  [SMALL_STATE(892)] = 24883,
+  [SMALL_STATE(893)] = 24891,
+  [SMALL_STATE(894)] = 24899,
+  [SMALL_STATE(895)] = 24907,
+  [SMALL_STATE(896)] = 24917,
+  [SMALL_STATE(897)] = 24927,
+  [SMALL_STATE(898)] = 24937,
+  [SMALL_STATE(899)] = 24944,
+  [SMALL_STATE(900)] = 24951,
+  [SMALL_STATE(901)] = 24958,
+  [SMALL_STATE(902)] = 24965,
+  [SMALL_STATE(903)] = 24972,
+  [SMALL_STATE(904)] = 24979,
+  [SMALL_STATE(905)] = 24986,
+  [SMALL_STATE(906)] = 24993,
+  [SMALL_STATE(907)] = 25000,
+  [SMALL_STATE(908)] = 25007,
+  [SMALL_STATE(909)] = 25014,
+  [SMALL_STATE(910)] = 25021,
+  [SMALL_STATE(911)] = 25028,
+  [SMALL_STATE(912)] = 25035,
+  [SMALL_STATE(913)] = 25042,
+  [SMALL_STATE(914)] = 25049,
+  [SMALL_STATE(915)] = 25056,
+  [SMALL_STATE(916)] = 25063,
+  [SMALL_STATE(917)] = 25070,
+  [SMALL_STATE(918)] = 25077,
+  [SMALL_STATE(919)] = 25084,
+  [SMALL_STATE(920)] = 25091,
+  [SMALL_STATE(921)] = 25098,
+  [SMALL_STATE(922)] = 25105,
+  [SMALL_STATE(923)] = 25112,
+  [SMALL_STATE(924)] = 25119,
+  [SMALL_STATE(925)] = 25126,
+  [SMALL_STATE(926)] = 25133,
+  [SMALL_STATE(927)] = 25140,
+  [SMALL_STATE(928)] = 25147,
+  [SMALL_STATE(929)] = 25154,
+  [SMALL_STATE(930)] = 25161,
+  [SMALL_STATE(931)] = 25168,
+  [SMALL_STATE(932)] = 25175,
+  [SMALL_STATE(933)] = 25182,
+  [SMALL_STATE(934)] = 25189,
+  [SMALL_STATE(935)] = 25196,
+  [SMALL_STATE(936)] = 25203,
+  [SMALL_STATE(937)] = 25210,
+  [SMALL_STATE(938)] = 25217,
+  [SMALL_STATE(939)] = 25224,
+  [SMALL_STATE(940)] = 25231,
+  [SMALL_STATE(941)] = 25238,
+  [SMALL_STATE(942)] = 25245,
+  [SMALL_STATE(943)] = 25252,
+  [SMALL_STATE(944)] = 25259,
+  [SMALL_STATE(945)] = 25266,
+  [SMALL_STATE(946)] = 25273,
+  [SMALL_STATE(947)] = 25280,
+  [SMALL_STATE(948)] = 25287,
+  [SMALL_STATE(949)] = 25294,
+  [SMALL_STATE(950)] = 25301,
+  [SMALL_STATE(951)] = 25308,
+  [SMALL_STATE(952)] = 25315,
+  [SMALL_STATE(953)] = 25322,
+  [SMALL_STATE(954)] = 25329,
+  [SMALL_STATE(955)] = 25336,
+  [SMALL_STATE(956)] = 25343,
+  [SMALL_STATE(957)] = 25350,
+  [SMALL_STATE(958)] = 25357,
+  [SMALL_STATE(959)] = 25364,
+  [SMALL_STATE(960)] = 25371,
+  [SMALL_STATE(961)] = 25378,
+  [SMALL_STATE(962)] = 25385,
+  [SMALL_STATE(963)] = 25392,
+  [SMALL_STATE(964)] = 25399,
+  [SMALL_STATE(965)] = 25406,
+  [SMALL_STATE(966)] = 25413,
+  [SMALL_STATE(967)] = 25420,
+  [SMALL_STATE(968)] = 25427,
+  [SMALL_STATE(969)] = 25434,
+  [SMALL_STATE(970)] = 25441,
+  [SMALL_STATE(971)] = 25448,
+  [SMALL_STATE(972)] = 25455,
+  [SMALL_STATE(973)] = 25462,
+  [SMALL_STATE(974)] = 25469,
+  [SMALL_STATE(975)] = 25476,
+  [SMALL_STATE(976)] = 25483,
+  [SMALL_STATE(977)] = 25490,
+  [SMALL_STATE(978)] = 25497,
+  [SMALL_STATE(979)] = 25504,
+  [SMALL_STATE(980)] = 25511,
+  [SMALL_STATE(981)] = 25518,
+  [SMALL_STATE(982)] = 25525,
+  [SMALL_STATE(983)] = 25532,
+  [SMALL_STATE(984)] = 25539,
+  [SMALL_STATE(985)] = 25546,
+  [SMALL_STATE(986)] = 25553,
+  [SMALL_STATE(987)] = 25560,
+  [SMALL_STATE(988)] = 25567,
+  [SMALL_STATE(989)] = 25574,
+  [SMALL_STATE(990)] = 25581,
+  [SMALL_STATE(991)] = 25588,
+  [SMALL_STATE(992)] = 25595,
+  [SMALL_STATE(993)] = 25602,
+  [SMALL_STATE(994)] = 25609,
+  [SMALL_STATE(995)] = 25616,
+  [SMALL_STATE(996)] = 25623,
+  [SMALL_STATE(997)] = 25630,
+  [SMALL_STATE(998)] = 25637,
+  [SMALL_STATE(999)] = 25644,
+  [SMALL_STATE(1000)] = 25651,
+  [SMALL_STATE(1001)] = 25658,
+  [SMALL_STATE(1002)] = 25665,
+  [SMALL_STATE(1003)] = 25672,
+  [SMALL_STATE(1004)] = 25679,
+  [SMALL_STATE(1005)] = 25686,
+  [SMALL_STATE(1006)] = 25693,
+  [SMALL_STATE(1007)] = 25700,
+  [SMALL_STATE(1008)] = 25707,
+  [SMALL_STATE(1009)] = 25714,
+  [SMALL_STATE(1010)] = 25721,
+  [SMALL_STATE(1011)] = 25728,
+  [SMALL_STATE(1012)] = 25735,
+  [SMALL_STATE(1013)] = 25742,
+  [SMALL_STATE(1014)] = 25749,
+  [SMALL_STATE(1015)] = 25756,
+  [SMALL_STATE(1016)] = 25763,
+  [SMALL_STATE(1017)] = 25770,
+  [SMALL_STATE(1018)] = 25777,
+  [SMALL_STATE(1019)] = 25784,
+  [SMALL_STATE(1020)] = 25791,
+  [SMALL_STATE(1021)] = 25798,
+  [SMALL_STATE(1022)] = 25805,
+  [SMALL_STATE(1023)] = 25812,
+  [SMALL_STATE(1024)] = 25819,
+  [SMALL_STATE(1025)] = 25826,
+  [SMALL_STATE(1026)] = 25833,
+  [SMALL_STATE(1027)] = 25840,
+  [SMALL_STATE(1028)] = 25847,
+  [SMALL_STATE(1029)] = 25854,
+  [SMALL_STATE(1030)] = 25861,
+  [SMALL_STATE(1031)] = 25868,
+  [SMALL_STATE(1032)] = 25875,
+  [SMALL_STATE(1033)] = 25882,
+  [SMALL_STATE(1034)] = 25889,
+  [SMALL_STATE(1035)] = 25896,
+  [SMALL_STATE(1036)] = 25903,
+  [SMALL_STATE(1037)] = 25910,
+  [SMALL_STATE(1038)] = 25917,
+  [SMALL_STATE(1039)] = 25924,
+  [SMALL_STATE(1040)] = 25931,
+  [SMALL_STATE(1041)] = 25938,
+  [SMALL_STATE(1042)] = 25945,
+  [SMALL_STATE(1043)] = 25952,
+  [SMALL_STATE(1044)] = 25959,
+  [SMALL_STATE(1045)] = 25966,
+  [SMALL_STATE(1046)] = 25973,
+  [SMALL_STATE(1047)] = 25980,
+  [SMALL_STATE(1048)] = 25987,
+  [SMALL_STATE(1049)] = 25994,
+  [SMALL_STATE(1050)] = 26001,
+  [SMALL_STATE(1051)] = 26008,
+  [SMALL_STATE(1052)] = 26015,
+  [SMALL_STATE(1053)] = 26022,
+  [SMALL_STATE(1054)] = 26029,
+  [SMALL_STATE(1055)] = 26036,
+  [SMALL_STATE(1056)] = 26043,
+  [SMALL_STATE(1057)] = 26050,
+  [SMALL_STATE(1058)] = 26057,
+  [SMALL_STATE(1059)] = 26064,
+  [SMALL_STATE(1060)] = 26071,
+  [SMALL_STATE(1061)] = 26078,
+  [SMALL_STATE(1062)] = 26085,
+  [SMALL_STATE(1063)] = 26092,
+  [SMALL_STATE(1064)] = 26099,
+  [SMALL_STATE(1065)] = 26106,
+  [SMALL_STATE(1066)] = 26113,
+  [SMALL_STATE(1067)] = 26120,
+  [SMALL_STATE(1068)] = 26127,
+  [SMALL_STATE(1069)] = 26134,
+  [SMALL_STATE(1070)] = 26141,
+  [SMALL_STATE(1071)] = 26148,
+  [SMALL_STATE(1072)] = 26155,
+  [SMALL_STATE(1073)] = 26162,
+  [SMALL_STATE(1074)] = 26169,
+  [SMALL_STATE(1075)] = 26176,
+  [SMALL_STATE(1076)] = 26183,
+  [SMALL_STATE(1077)] = 26190,
+  [SMALL_STATE(1078)] = 26197,
+  [SMALL_STATE(1079)] = 26204,
+  [SMALL_STATE(1080)] = 26211,
+  [SMALL_STATE(1081)] = 26218,
+  [SMALL_STATE(1082)] = 26225,
+  [SMALL_STATE(1083)] = 26232,
+  [SMALL_STATE(1084)] = 26239,
+  [SMALL_STATE(1085)] = 26246,
+  [SMALL_STATE(1086)] = 26253,
+  [SMALL_STATE(1087)] = 26260,
+  [SMALL_STATE(1088)] = 26267,
+  [SMALL_STATE(1089)] = 26274,
+  [SMALL_STATE(1090)] = 26281,
+  [SMALL_STATE(1091)] = 26288,
+  [SMALL_STATE(1092)] = 26295,
+  [SMALL_STATE(1093)] = 26302,
+  [SMALL_STATE(1094)] = 26309,
+  [SMALL_STATE(1095)] = 26316,
+  [SMALL_STATE(1096)] = 26323,
+  [SMALL_STATE(1097)] = 26330,
+  [SMALL_STATE(1098)] = 26337,
+  [SMALL_STATE(1099)] = 26344,
+  [SMALL_STATE(1100)] = 26351,
+  [SMALL_STATE(1101)] = 26358,
+  [SMALL_STATE(1102)] = 26365,
+  [SMALL_STATE(1103)] = 26372,
+  [SMALL_STATE(1104)] = 26379,
+  [SMALL_STATE(1105)] = 26386,
+  [SMALL_STATE(1106)] = 26393,
+  [SMALL_STATE(1107)] = 26400,
+  [SMALL_STATE(1108)] = 26407,
+  [SMALL_STATE(1109)] = 26414,
+  [SMALL_STATE(1110)] = 26421,
+  [SMALL_STATE(1111)] = 26428,
+  [SMALL_STATE(1112)] = 26435,
+  [SMALL_STATE(1113)] = 26442,
+};
+
+static const TSParseActionEntry ts_parse_actions[] = {
+  [0] = {.entry = {.count = 0, .reusable = false}},
+  [1] = {.entry = {.count = 1, .reusable = false}}, RECOVER(),
+  [3] = {.entry = {.count = 1, .reusable = true}}, SHIFT_EXTRA(),
+  [5] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 0, 0, 0),
+  [7] = {.entry = {.count = 1, .reusable = true}}, SHIFT(9),
+  [9] = {.entry = {.count = 1, .reusable = true}}, SHIFT(589),
+  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(761),
+  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT(500),
+  [15] = {.entry = {.count = 1, .reusable = true}}, SHIFT(556),
+  [17] = {.entry = {.count = 1, .reusable = false}}, SHIFT(239),
+  [19] = {.entry = {.count = 1, .reusable = true}}, SHIFT(239),
+  [21] = {.entry = {.count = 1, .reusable = true}}, SHIFT(513),
+  [23] = {.entry = {.count = 1, .reusable = true}}, SHIFT(974),
+  [25] = {.entry = {.count = 1, .reusable = true}}, SHIFT(7),
+  [27] = {.entry = {.count = 1, .reusable = true}}, SHIFT(550),
+  [29] = {.entry = {.count = 1, .reusable = true}}, SHIFT(651),
+  [31] = {.entry = {.count = 1, .reusable = true}}, SHIFT(691),
+  [33] = {.entry = {.count = 1, .reusable = false}}, SHIFT(6),
+  [35] = {.entry = {.count = 1, .reusable = true}}, SHIFT(2),
+  [37] = {.entry = {.count = 1, .reusable = true}}, SHIFT(72),
+  [39] = {.entry = {.count = 1, .reusable = false}}, SHIFT(910),
+  [41] = {.entry = {.count = 1, .reusable = true}}, SHIFT(154),
+  [43] = {.entry = {.count = 1, .reusable = false}}, SHIFT(154),
+  [45] = {.entry = {.count = 1, .reusable = true}}, SHIFT(65),
+  [47] = {.entry = {.count = 1, .reusable = true}}, SHIFT(675),
+  [49] = {.entry = {.count = 1, .reusable = true}}, SHIFT(73),
+  [51] = {.entry = {.count = 1, .reusable = true}}, SHIFT(6),
+  [53] = {.entry = {.count = 1, .reusable = true}}, SHIFT(111),
+  [55] = {.entry = {.count = 1, .reusable = true}}, SHIFT(880),
+  [57] = {.entry = {.count = 1, .reusable = true}}, SHIFT(890),
+  [59] = {.entry = {.count = 1, .reusable = false}}, SHIFT(890),
+  [61] = {.entry = {.count = 1, .reusable = true}}, SHIFT(129),
+  [63] = {.entry = {.count = 1, .reusable = false}}, SHIFT(129),
+  [65] = {.entry = {.count = 1, .reusable = true}}, SHIFT(130),
+  [67] = {.entry = {.count = 1, .reusable = true}}, SHIFT(964),
+  [69] = {.entry = {.count = 1, .reusable = true}}, SHIFT(969),
+  [71] = {.entry = {.count = 1, .reusable = true}}, SHIFT(973),
+  [73] = {.entry = {.count = 1, .reusable = true}}, SHIFT(128),
+  [75] = {.entry = {.count = 1, .reusable = true}}, SHIFT(976),
+  [77] = {.entry = {.count = 1, .reusable = true}}, SHIFT(982),
+  [79] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_identifier, 1, 0, 0),
+  [81] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_identifier, 1, 0, 0),
+  [83] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1087),
+  [85] = {.entry = {.count = 1, .reusable = true}}, SHIFT(139),
+  [87] = {.entry = {.count = 1, .reusable = true}}, SHIFT(830),
+  [89] = {.entry = {.count = 1, .reusable = false}}, SHIFT(830),
+  [91] = {.entry = {.count = 1, .reusable = true}}, SHIFT(927),
+  [93] = {.entry = {.count = 1, .reusable = true}}, SHIFT(11),
+  [95] = {.entry = {.count = 1, .reusable = true}}, SHIFT(8),
+  [97] = {.entry = {.count = 1, .reusable = true}}, SHIFT(12),
+  [99] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 1, 0, 0),
+  [101] = {.entry = {.count = 1, .reusable = true}}, SHIFT(10),
+  [103] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0),
+  [105] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(10),
+  [108] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(589),
+  [111] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(761),
+  [114] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(500),
+  [117] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(556),
+  [120] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(239),
+  [123] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(239),
+  [126] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(513),
+  [129] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(974),
+  [132] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(7),
+  [135] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(550),
+  [138] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(651),
+  [141] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(691),
+  [144] = {.entry = {.count = 1, .reusable = true}}, SHIFT(986),
+  [146] = {.entry = {.count = 1, .reusable = true}}, SHIFT(5),
+  [148] = {.entry = {.count = 1, .reusable = true}}, SHIFT(157),
+  [150] = {.entry = {.count = 1, .reusable = true}}, SHIFT(378),
+  [152] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1085),
+  [154] = {.entry = {.count = 1, .reusable = true}}, SHIFT(381),
+  [156] = {.entry = {.count = 1, .reusable = true}}, SHIFT(417),
+  [158] = {.entry = {.count = 1, .reusable = true}}, SHIFT(415),
+  [160] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1009),
+  [162] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1028),
+  [164] = {.entry = {.count = 1, .reusable = true}}, SHIFT(3),
+  [166] = {.entry = {.count = 1, .reusable = true}}, SHIFT(131),
+  [168] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1081),
+  [170] = {.entry = {.count = 1, .reusable = true}}, SHIFT(841),
+  [172] = {.entry = {.count = 1, .reusable = false}}, SHIFT(841),
+  [174] = {.entry = {.count = 1, .reusable = true}}, SHIFT(894),
+  [176] = {.entry = {.count = 1, .reusable = false}}, SHIFT(894),
+  [178] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1097),
+  [180] = {.entry = {.count = 1, .reusable = false}}, SHIFT(1097),
+  [182] = {.entry = {.count = 1, .reusable = true}}, SHIFT(685),
+  [184] = {.entry = {.count = 1, .reusable = false}}, SHIFT(685),
+  [186] = {.entry = {.count = 1, .reusable = true}}, SHIFT(127),
+  [188] = {.entry = {.count = 1, .reusable = true}}, SHIFT(717),
+  [190] = {.entry = {.count = 1, .reusable = false}}, SHIFT(717),
+  [192] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1037),
+  [194] = {.entry = {.count = 1, .reusable = false}}, SHIFT(1037),
+  [196] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_qualified_name, 1, 0, 2),
+  [198] = {.entry = {.count = 1, .reusable = true}}, SHIFT(671),
+  [200] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_qualified_name, 1, 0, 2),
+  [202] = {.entry = {.count = 1, .reusable = true}}, SHIFT(881),
+  [204] = {.entry = {.count = 1, .reusable = false}}, SHIFT(881),
+  [206] = {.entry = {.count = 1, .reusable = true}}, SHIFT(144),
+  [208] = {.entry = {.count = 1, .reusable = false}}, SHIFT(144),
+  [210] = {.entry = {.count = 1, .reusable = true}}, SHIFT(110),
+  [212] = {.entry = {.count = 1, .reusable = true}}, SHIFT(148),
+  [214] = {.entry = {.count = 1, .reusable = true}}, SHIFT(520),
+  [216] = {.entry = {.count = 1, .reusable = true}}, SHIFT(760),
+  [218] = {.entry = {.count = 1, .reusable = false}}, SHIFT(760),
+  [220] = {.entry = {.count = 1, .reusable = true}}, SHIFT(125),
+  [222] = {.entry = {.count = 1, .reusable = true}}, SHIFT(809),
+  [224] = {.entry = {.count = 1, .reusable = false}}, SHIFT(809),
+  [226] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_bind_marker, 1, 0, 0),
+  [228] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_bind_marker, 1, 0, 0),
+  [230] = {.entry = {.count = 1, .reusable = true}}, SHIFT(269),
+  [232] = {.entry = {.count = 1, .reusable = true}}, SHIFT(836),
+  [234] = {.entry = {.count = 1, .reusable = false}}, SHIFT(836),
+  [236] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_qualified_name, 3, 0, 8),
+  [238] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_qualified_name, 3, 0, 8),
+  [240] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_bind_marker, 2, 0, 0),
+  [242] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_bind_marker, 2, 0, 0),
+  [244] = {.entry = {.count = 1, .reusable = true}}, SHIFT(432),
+  [246] = {.entry = {.count = 1, .reusable = false}}, SHIFT(432),
+  [248] = {.entry = {.count = 1, .reusable = true}}, SHIFT(712),
+  [250] = {.entry = {.count = 1, .reusable = false}}, SHIFT(712),
+  [252] = {.entry = {.count = 1, .reusable = true}}, SHIFT(251),
+  [254] = {.entry = {.count = 1, .reusable = false}}, SHIFT(251),
+  [256] = {.entry = {.count = 1, .reusable = true}}, SHIFT(870),
+  [258] = {.entry = {.count = 1, .reusable = false}}, SHIFT(870),
+  [260] = {.entry = {.count = 1, .reusable = true}}, SHIFT(837),
+  [262] = {.entry = {.count = 1, .reusable = false}}, SHIFT(837),
+  [264] = {.entry = {.count = 1, .reusable = true}}, SHIFT(752),
+  [266] = {.entry = {.count = 1, .reusable = false}}, SHIFT(752),
+  [268] = {.entry = {.count = 1, .reusable = true}}, SHIFT(413),
+  [270] = {.entry = {.count = 1, .reusable = false}}, SHIFT(413),
+  [272] = {.entry = {.count = 1, .reusable = true}}, SHIFT(4),
+  [274] = {.entry = {.count = 1, .reusable = true}}, SHIFT(941),
+  [276] = {.entry = {.count = 1, .reusable = false}}, SHIFT(941),
+  [278] = {.entry = {.count = 1, .reusable = true}}, SHIFT(483),
+  [280] = {.entry = {.count = 1, .reusable = false}}, SHIFT(483),
+  [282] = {.entry = {.count = 1, .reusable = true}}, SHIFT(422),
+  [284] = {.entry = {.count = 1, .reusable = false}}, SHIFT(422),
+  [286] = {.entry = {.count = 1, .reusable = true}}, SHIFT(431),
+  [288] = {.entry = {.count = 1, .reusable = false}}, SHIFT(431),
+  [290] = {.entry = {.count = 1, .reusable = true}}, SHIFT(146),
+  [292] = {.entry = {.count = 1, .reusable = false}}, SHIFT(146),
+  [294] = {.entry = {.count = 1, .reusable = true}}, SHIFT(435),
+  [296] = {.entry = {.count = 1, .reusable = false}}, SHIFT(435),
+  [298] = {.entry = {.count = 1, .reusable = true}}, SHIFT(273),
+  [300] = {.entry = {.count = 1, .reusable = false}}, SHIFT(273),
+  [302] = {.entry = {.count = 1, .reusable = true}}, SHIFT(443),
+  [304] = {.entry = {.count = 1, .reusable = false}}, SHIFT(443),
+  [306] = {.entry = {.count = 1, .reusable = true}}, SHIFT(447),
+  [308] = {.entry = {.count = 1, .reusable = false}}, SHIFT(447),
+  [310] = {.entry = {.count = 1, .reusable = true}}, SHIFT(448),
+  [312] = {.entry = {.count = 1, .reusable = false}}, SHIFT(448),
+  [314] = {.entry = {.count = 1, .reusable = true}}, SHIFT(449),
+  [316] = {.entry = {.count = 1, .reusable = false}}, SHIFT(449),
+  [318] = {.entry = {.count = 1, .reusable = true}}, SHIFT(116),
+  [320] = {.entry = {.count = 1, .reusable = false}}, SHIFT(116),
+  [322] = {.entry = {.count = 1, .reusable = true}}, SHIFT(457),
+  [324] = {.entry = {.count = 1, .reusable = false}}, SHIFT(457),
+  [326] = {.entry = {.count = 1, .reusable = true}}, SHIFT(459),
+  [328] = {.entry = {.count = 1, .reusable = false}}, SHIFT(459),
+  [330] = {.entry = {.count = 1, .reusable = true}}, SHIFT(461),
+  [332] = {.entry = {.count = 1, .reusable = false}}, SHIFT(461),
+  [334] = {.entry = {.count = 1, .reusable = true}}, SHIFT(462),
+  [336] = {.entry = {.count = 1, .reusable = false}}, SHIFT(462),
+  [338] = {.entry = {.count = 1, .reusable = true}}, SHIFT(466),
+  [340] = {.entry = {.count = 1, .reusable = false}}, SHIFT(466),
+  [342] = {.entry = {.count = 1, .reusable = true}}, SHIFT(467),
+  [344] = {.entry = {.count = 1, .reusable = false}}, SHIFT(467),
+  [346] = {.entry = {.count = 1, .reusable = true}}, SHIFT(468),
+  [348] = {.entry = {.count = 1, .reusable = false}}, SHIFT(468),
+  [350] = {.entry = {.count = 1, .reusable = true}}, SHIFT(471),
+  [352] = {.entry = {.count = 1, .reusable = false}}, SHIFT(471),
+  [354] = {.entry = {.count = 1, .reusable = true}}, SHIFT(473),
+  [356] = {.entry = {.count = 1, .reusable = false}}, SHIFT(473),
+  [358] = {.entry = {.count = 1, .reusable = true}}, SHIFT(474),
+  [360] = {.entry = {.count = 1, .reusable = false}}, SHIFT(474),
+  [362] = {.entry = {.count = 1, .reusable = true}}, SHIFT(475),
+  [364] = {.entry = {.count = 1, .reusable = false}}, SHIFT(475),
+  [366] = {.entry = {.count = 1, .reusable = true}}, SHIFT(478),
+  [368] = {.entry = {.count = 1, .reusable = false}}, SHIFT(478),
+  [370] = {.entry = {.count = 1, .reusable = true}}, SHIFT(480),
+  [372] = {.entry = {.count = 1, .reusable = false}}, SHIFT(480),
+  [374] = {.entry = {.count = 1, .reusable = true}}, SHIFT(355),
+  [376] = {.entry = {.count = 1, .reusable = false}}, SHIFT(355),
+  [378] = {.entry = {.count = 1, .reusable = true}}, SHIFT(149),
+  [380] = {.entry = {.count = 1, .reusable = false}}, SHIFT(149),
+  [382] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_call, 5, 0, 2),
+  [384] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_function_call, 5, 0, 2),
+  [386] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_hint, 4, 0, 0),
+  [388] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_hint, 4, 0, 0),
+  [390] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_list_literal, 3, 0, 0),
+  [392] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_list_literal, 3, 0, 0),
+  [394] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_tuple_literal, 4, 0, 0),
+  [396] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_tuple_literal, 4, 0, 0),
+  [398] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_list_literal, 4, 0, 0),
+  [400] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_list_literal, 4, 0, 0),
+  [402] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_map_literal, 3, 0, 0),
+  [404] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_map_literal, 3, 0, 0),
+  [406] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_set_literal, 4, 0, 0),
+  [408] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_set_literal, 4, 0, 0),
+  [410] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_map_literal, 4, 0, 0),
+  [412] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_map_literal, 4, 0, 0),
+  [414] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_set_literal, 3, 0, 0),
+  [416] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_set_literal, 3, 0, 0),
+  [418] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_call, 4, 0, 2),
+  [420] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_function_call, 4, 0, 2),
+  [422] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_list_literal, 2, 0, 0),
+  [424] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_list_literal, 2, 0, 0),
+  [426] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_float, 2, 0, 0),
+  [428] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_float, 2, 0, 0),
+  [430] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_map_literal, 2, 0, 0),
+  [432] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_map_literal, 2, 0, 0),
+  [434] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__literal, 1, 0, 3),
+  [436] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__literal, 1, 0, 3),
+  [438] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_float, 1, 0, 0),
+  [440] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_float, 1, 0, 0),
+  [442] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_boolean, 1, 0, 0),
+  [444] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_boolean, 1, 0, 0),
+  [446] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_call, 3, 0, 2),
+  [448] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_function_call, 3, 0, 2),
+  [450] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 4, 0, 9),
+  [452] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1047),
+  [454] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_select_statement, 4, 0, 9),
+  [456] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1049),
+  [458] = {.entry = {.count = 1, .reusable = true}}, SHIFT(644),
+  [460] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1056),
+  [462] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1057),
+  [464] = {.entry = {.count = 1, .reusable = true}}, SHIFT(490),
+  [466] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 5, 0, 14),
+  [468] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1008),
+  [470] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_select_statement, 5, 0, 14),
+  [472] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 18),
+  [474] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1084),
+  [476] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_select_statement, 6, 0, 18),
+  [478] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_where_clause, 3, 0, 0),
+  [480] = {.entry = {.count = 1, .reusable = true}}, SHIFT(495),
+  [482] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_where_clause, 3, 0, 0),
+  [484] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_where_clause_repeat1, 2, 0, 0),
+  [486] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_where_clause_repeat1, 2, 0, 0), SHIFT_REPEAT(495),
+  [489] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_where_clause_repeat1, 2, 0, 0),
+  [491] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_where_clause, 2, 0, 0),
+  [493] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_where_clause, 2, 0, 0),
+  [495] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 18),
+  [497] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1000),
+  [499] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_select_statement, 7, 0, 18),
+  [501] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 14),
+  [503] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1079),
+  [505] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_select_statement, 6, 0, 14),
+  [507] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_relation, 3, 0, 23),
+  [509] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_relation, 3, 0, 23),
+  [511] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_relation, 3, 0, 24),
+  [513] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_relation, 3, 0, 24),
+  [515] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_relation, 3, 0, 25),
+  [517] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_relation, 3, 0, 25),
+  [519] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 5, 0, 9),
+  [521] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1058),
+  [523] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_select_statement, 5, 0, 9),
+  [525] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_relation, 4, 0, 24),
+  [527] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_relation, 4, 0, 24),
+  [529] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_relation, 4, 0, 28),
+  [531] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_relation, 4, 0, 28),
+  [533] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_relation, 6, 0, 24),
+  [535] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_relation, 6, 0, 24),
+  [537] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_relation, 5, 0, 24),
+  [539] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_relation, 5, 0, 24),
+  [541] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_tuple_type, 5, 0, 0),
+  [543] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_tuple_type, 5, 0, 0),
+  [545] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_collection_type, 6, 0, 0),
+  [547] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_collection_type, 6, 0, 0),
+  [549] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_native_type, 1, 0, 0),
+  [551] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_native_type, 1, 0, 0),
+  [553] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_vector_type, 6, 0, 29),
+  [555] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_vector_type, 6, 0, 29),
+  [557] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_operator, 1, 0, 0),
+  [559] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_operator, 1, 0, 0),
+  [561] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_custom_type, 1, 0, 0),
+  [563] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_custom_type, 1, 0, 0),
+  [565] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_user_type, 1, 0, 0),
+  [567] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_user_type, 1, 0, 0),
+  [569] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_frozen_type, 4, 0, 0),
+  [571] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_frozen_type, 4, 0, 0),
+  [573] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_collection_type, 4, 0, 0),
+  [575] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_collection_type, 4, 0, 0),
+  [577] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_tuple_type, 4, 0, 0),
+  [579] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_tuple_type, 4, 0, 0),
+  [581] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 6, 0, 9),
+  [583] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1099),
+  [585] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_select_statement, 6, 0, 9),
+  [587] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_column_reference_repeat1, 2, 0, 0),
+  [589] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_column_reference_repeat1, 2, 0, 0), SHIFT_REPEAT(666),
+  [592] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_column_reference_repeat1, 2, 0, 0),
+  [594] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_column_reference, 2, 0, 0),
+  [596] = {.entry = {.count = 1, .reusable = true}}, SHIFT(666),
+  [598] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_column_reference, 2, 0, 0),
+  [600] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 18),
+  [602] = {.entry = {.count = 1, .reusable = true}}, SHIFT(979),
+  [604] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_select_statement, 8, 0, 18),
+  [606] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_partition_key_repeat1, 2, 0, 0),
+  [608] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_partition_key_repeat1, 2, 0, 0), SHIFT_REPEAT(676),
+  [611] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_partition_key_repeat1, 2, 0, 0),
+  [613] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_not_exists, 3, 0, 0),
+  [615] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_not_exists, 3, 0, 0),
+  [617] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 14),
+  [619] = {.entry = {.count = 1, .reusable = true}}, SHIFT(967),
+  [621] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_select_statement, 7, 0, 14),
+  [623] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_group_by_clause, 3, 0, 0),
+  [625] = {.entry = {.count = 1, .reusable = true}}, SHIFT(676),
+  [627] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_group_by_clause, 3, 0, 0),
+  [629] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_group_by_clause, 4, 0, 0),
+  [631] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_group_by_clause, 4, 0, 0),
+  [633] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_insert_statement, 5, 0, 6),
+  [635] = {.entry = {.count = 1, .reusable = true}}, SHIFT(862),
+  [637] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_insert_statement, 5, 0, 6),
+  [639] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1035),
+  [641] = {.entry = {.count = 1, .reusable = true}}, SHIFT(753),
+  [643] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_describe_statement, 2, 0, 0),
+  [645] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_describe_statement, 2, 0, 0),
+  [647] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ordering, 1, 0, 17),
+  [649] = {.entry = {.count = 1, .reusable = true}}, SHIFT(180),
+  [651] = {.entry = {.count = 1, .reusable = false}}, SHIFT(180),
+  [653] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_order_by_clause, 4, 0, 0),
+  [655] = {.entry = {.count = 1, .reusable = true}}, SHIFT(610),
+  [657] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_order_by_clause, 4, 0, 0),
+  [659] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ordering, 2, 0, 30),
+  [661] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_ordering, 2, 0, 30),
+  [663] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 18),
+  [665] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1090),
+  [667] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_select_statement, 9, 0, 18),
+  [669] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_clustering_order_repeat1, 2, 0, 0),
+  [671] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_clustering_order_repeat1, 2, 0, 0),
+  [673] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_clustering_order_repeat1, 2, 0, 0), SHIFT_REPEAT(610),
+  [676] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_describe_statement, 3, 0, 0),
+  [678] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_describe_statement, 3, 0, 0),
+  [680] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_insert_statement, 10, 0, 44),
+  [682] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_insert_statement, 10, 0, 44),
+  [684] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_using_clause_repeat1, 2, 0, 0),
+  [686] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_using_clause_repeat1, 2, 0, 0), SHIFT_REPEAT(812),
+  [689] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_using_clause_repeat1, 2, 0, 0),
+  [691] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_using_clause, 3, 0, 0),
+  [693] = {.entry = {.count = 1, .reusable = true}}, SHIFT(812),
+  [695] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_using_clause, 3, 0, 0),
+  [697] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_insert_statement, 11, 0, 53),
+  [699] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_insert_statement, 11, 0, 53),
+  [701] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_insert_statement, 7, 0, 6),
+  [703] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_insert_statement, 7, 0, 6),
+  [705] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_insert_statement, 11, 0, 54),
+  [707] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_insert_statement, 11, 0, 54),
+  [709] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_insert_statement, 12, 0, 65),
+  [711] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_insert_statement, 12, 0, 65),
+  [713] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 7, 0, 9),
+  [715] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1032),
+  [717] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_select_statement, 7, 0, 9),
+  [719] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_exists, 2, 0, 0),
+  [721] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_exists, 2, 0, 0),
+  [723] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_order_by_clause, 3, 0, 0),
+  [725] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_order_by_clause, 3, 0, 0),
+  [727] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 14),
+  [729] = {.entry = {.count = 1, .reusable = true}}, SHIFT(971),
+  [731] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_select_statement, 8, 0, 14),
+  [733] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_using_clause, 2, 0, 0),
+  [735] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_using_clause, 2, 0, 0),
+  [737] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_column_definition, 2, 0, 16),
+  [739] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_column_definition, 2, 0, 16),
+  [741] = {.entry = {.count = 1, .reusable = true}}, SHIFT(916),
+  [743] = {.entry = {.count = 1, .reusable = true}}, SHIFT(218),
+  [745] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_delete_statement, 4, 0, 6),
+  [747] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_delete_statement, 4, 0, 6),
+  [749] = {.entry = {.count = 1, .reusable = true}}, SHIFT(511),
+  [751] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_update_statement, 5, 0, 15),
+  [753] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_update_statement, 5, 0, 15),
+  [755] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_using_option, 2, 0, 0),
+  [757] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_using_option, 2, 0, 0),
+  [759] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_delete_statement, 5, 0, 9),
+  [761] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_delete_statement, 5, 0, 9),
+  [763] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_delete_statement, 6, 0, 9),
+  [765] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_delete_statement, 6, 0, 9),
+  [767] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_delete_statement, 6, 0, 14),
+  [769] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_delete_statement, 6, 0, 14),
+  [771] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_delete_statement, 7, 0, 14),
+  [773] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_delete_statement, 7, 0, 14),
+  [775] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_update_statement, 6, 0, 15),
+  [777] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_update_statement, 6, 0, 15),
+  [779] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_delete_statement, 5, 0, 6),
+  [781] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_delete_statement, 5, 0, 6),
+  [783] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_update_statement, 7, 0, 15),
+  [785] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_update_statement, 7, 0, 15),
+  [787] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_clause, 3, 0, 0),
+  [789] = {.entry = {.count = 1, .reusable = true}}, SHIFT(526),
+  [791] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_clause, 3, 0, 0),
+  [793] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_column_definition, 3, 0, 16),
+  [795] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_column_definition, 3, 0, 16),
+  [797] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1060),
+  [799] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 8, 0, 9),
+  [801] = {.entry = {.count = 1, .reusable = true}}, SHIFT(998),
+  [803] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_select_statement, 8, 0, 9),
+  [805] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primary_key_definition, 5, 0, 32),
+  [807] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_primary_key_definition, 5, 0, 32),
+  [809] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 14),
+  [811] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1086),
+  [813] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_select_statement, 9, 0, 14),
+  [815] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primary_key_definition, 6, 0, 39),
+  [817] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_primary_key_definition, 6, 0, 39),
+  [819] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 18),
+  [821] = {.entry = {.count = 1, .reusable = true}}, SHIFT(933),
+  [823] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_select_statement, 10, 0, 18),
+  [825] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_insert_statement, 11, 0, 44),
+  [827] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_insert_statement, 11, 0, 44),
+  [829] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_clause, 2, 0, 0),
+  [831] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_clause, 2, 0, 0),
+  [833] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_insert_statement, 12, 0, 53),
+  [835] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_insert_statement, 12, 0, 53),
+  [837] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_insert_statement, 12, 0, 54),
+  [839] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_insert_statement, 12, 0, 54),
+  [841] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_if_clause_repeat1, 2, 0, 0),
+  [843] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_if_clause_repeat1, 2, 0, 0), SHIFT_REPEAT(526),
+  [846] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_if_clause_repeat1, 2, 0, 0),
+  [848] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_insert_statement, 6, 0, 6),
+  [850] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_insert_statement, 6, 0, 6),
+  [852] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_insert_statement, 8, 0, 6),
+  [854] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_insert_statement, 8, 0, 6),
+  [856] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_insert_statement, 13, 0, 65),
+  [858] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_insert_statement, 13, 0, 65),
+  [860] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_property, 3, 0, 22),
+  [862] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_property, 3, 0, 22),
+  [864] = {.entry = {.count = 1, .reusable = true}}, SHIFT(177),
+  [866] = {.entry = {.count = 1, .reusable = false}}, SHIFT(177),
+  [868] = {.entry = {.count = 1, .reusable = true}}, SHIFT(374),
+  [870] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1098),
+  [872] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field_definition, 2, 0, 16),
+  [874] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_field_definition, 2, 0, 16),
+  [876] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_per_partition_limit_clause, 4, 0, 0),
+  [878] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_per_partition_limit_clause, 4, 0, 0),
+  [880] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 11, 0, 52),
+  [882] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 11, 0, 52),
+  [884] = {.entry = {.count = 1, .reusable = true}}, SHIFT(703),
+  [886] = {.entry = {.count = 1, .reusable = true}}, SHIFT(91),
+  [888] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_condition, 6, 0, 24),
+  [890] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_condition, 6, 0, 24),
+  [892] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_table_options, 1, 0, 0),
+  [894] = {.entry = {.count = 1, .reusable = true}}, SHIFT(507),
+  [896] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_table_options, 1, 0, 0),
+  [898] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_table_options_repeat1, 2, 0, 0),
+  [900] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_table_options_repeat1, 2, 0, 0), SHIFT_REPEAT(507),
+  [903] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_table_options_repeat1, 2, 0, 0),
+  [905] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 12, 0, 57),
+  [907] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 12, 0, 57),
+  [909] = {.entry = {.count = 1, .reusable = true}}, SHIFT(706),
+  [911] = {.entry = {.count = 1, .reusable = true}}, SHIFT(93),
+  [913] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 12, 0, 63),
+  [915] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 12, 0, 63),
+  [917] = {.entry = {.count = 1, .reusable = true}}, SHIFT(708),
+  [919] = {.entry = {.count = 1, .reusable = true}}, SHIFT(96),
+  [921] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_condition, 3, 0, 25),
+  [923] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_condition, 3, 0, 25),
+  [925] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 9, 0, 31),
+  [927] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 9, 0, 31),
+  [929] = {.entry = {.count = 1, .reusable = true}}, SHIFT(677),
+  [931] = {.entry = {.count = 1, .reusable = true}}, SHIFT(83),
+  [933] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 12, 0, 64),
+  [935] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 12, 0, 64),
+  [937] = {.entry = {.count = 1, .reusable = true}}, SHIFT(709),
+  [939] = {.entry = {.count = 1, .reusable = true}}, SHIFT(97),
+  [941] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_column_definition, 5, 0, 16),
+  [943] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_column_definition, 5, 0, 16),
+  [945] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__renames, 1, 0, 0),
+  [947] = {.entry = {.count = 1, .reusable = true}}, SHIFT(601),
+  [949] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__renames, 1, 0, 0),
+  [951] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 10, 0, 36),
+  [953] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 10, 0, 36),
+  [955] = {.entry = {.count = 1, .reusable = true}}, SHIFT(684),
+  [957] = {.entry = {.count = 1, .reusable = true}}, SHIFT(85),
+  [959] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_properties, 1, 0, 0),
+  [961] = {.entry = {.count = 1, .reusable = true}}, SHIFT(618),
+  [963] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_properties, 1, 0, 0),
+  [965] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 10, 0, 37),
+  [967] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 10, 0, 37),
+  [969] = {.entry = {.count = 1, .reusable = true}}, SHIFT(687),
+  [971] = {.entry = {.count = 1, .reusable = true}}, SHIFT(86),
+  [973] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 13, 0, 73),
+  [975] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 13, 0, 73),
+  [977] = {.entry = {.count = 1, .reusable = true}}, SHIFT(713),
+  [979] = {.entry = {.count = 1, .reusable = true}}, SHIFT(100),
+  [981] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__renames, 2, 0, 0),
+  [983] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__renames, 2, 0, 0),
+  [985] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 13, 0, 76),
+  [987] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 13, 0, 76),
+  [989] = {.entry = {.count = 1, .reusable = true}}, SHIFT(715),
+  [991] = {.entry = {.count = 1, .reusable = true}}, SHIFT(102),
+  [993] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_properties_repeat1, 2, 0, 0),
+  [995] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_properties_repeat1, 2, 0, 0), SHIFT_REPEAT(618),
+  [998] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_properties_repeat1, 2, 0, 0),
+  [1000] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_table_options, 2, 0, 0),
+  [1002] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_table_options, 2, 0, 0),
+  [1004] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 14, 0, 85),
+  [1006] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 14, 0, 85),
+  [1008] = {.entry = {.count = 1, .reusable = true}}, SHIFT(718),
+  [1010] = {.entry = {.count = 1, .reusable = true}}, SHIFT(105),
+  [1012] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_properties, 2, 0, 0),
+  [1014] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_properties, 2, 0, 0),
+  [1016] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_column_definition, 4, 0, 16),
+  [1018] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_column_definition, 4, 0, 16),
+  [1020] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_condition, 5, 0, 24),
+  [1022] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_condition, 5, 0, 24),
+  [1024] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_condition, 4, 0, 24),
+  [1026] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_condition, 4, 0, 24),
+  [1028] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym__renames_repeat1, 2, 0, 0),
+  [1030] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__renames_repeat1, 2, 0, 0), SHIFT_REPEAT(601),
+  [1033] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym__renames_repeat1, 2, 0, 0),
+  [1035] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 11, 0, 45),
+  [1037] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 11, 0, 45),
+  [1039] = {.entry = {.count = 1, .reusable = true}}, SHIFT(696),
+  [1041] = {.entry = {.count = 1, .reusable = true}}, SHIFT(87),
+  [1043] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 11, 0, 48),
+  [1045] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 11, 0, 48),
+  [1047] = {.entry = {.count = 1, .reusable = true}}, SHIFT(699),
+  [1049] = {.entry = {.count = 1, .reusable = true}}, SHIFT(89),
+  [1051] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_index_statement, 11, 0, 49),
+  [1053] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_index_statement, 11, 0, 49),
+  [1055] = {.entry = {.count = 1, .reusable = true}}, SHIFT(947),
+  [1057] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_index_statement, 11, 0, 50),
+  [1059] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_index_statement, 11, 0, 50),
+  [1061] = {.entry = {.count = 1, .reusable = true}}, SHIFT(949),
+  [1063] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_materialized_view_statement, 11, 0, 51),
+  [1065] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_materialized_view_statement, 11, 0, 51),
+  [1067] = {.entry = {.count = 1, .reusable = true}}, SHIFT(493),
+  [1069] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_index_statement, 8, 0, 14),
+  [1071] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_index_statement, 8, 0, 14),
+  [1073] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1112),
+  [1075] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_table_statement, 6, 0, 5),
+  [1077] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_table_statement, 6, 0, 5),
+  [1079] = {.entry = {.count = 1, .reusable = true}}, SHIFT(497),
+  [1081] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_drop_aggregate_statement, 3, 0, 5),
+  [1083] = {.entry = {.count = 1, .reusable = true}}, SHIFT(14),
+  [1085] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_drop_aggregate_statement, 3, 0, 5),
+  [1087] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 9, 0, 9),
+  [1089] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1094),
+  [1091] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_select_statement, 9, 0, 9),
+  [1093] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_clustering_order, 6, 0, 0),
+  [1095] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_clustering_order, 6, 0, 0),
+  [1097] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_index_statement, 8, 0, 27),
+  [1099] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_index_statement, 8, 0, 27),
+  [1101] = {.entry = {.count = 1, .reusable = true}}, SHIFT(925),
+  [1103] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 18),
+  [1105] = {.entry = {.count = 1, .reusable = true}}, SHIFT(981),
+  [1107] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_select_statement, 11, 0, 18),
+  [1109] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 12, 0, 55),
+  [1111] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 12, 0, 55),
+  [1113] = {.entry = {.count = 1, .reusable = true}}, SHIFT(92),
+  [1115] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_column_reference, 1, 0, 0),
+  [1117] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_column_reference, 1, 0, 0),
+  [1119] = {.entry = {.count = 1, .reusable = true}}, SHIFT(84),
+  [1121] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_table_statement, 7, 0, 7),
+  [1123] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_table_statement, 7, 0, 7),
+  [1125] = {.entry = {.count = 1, .reusable = true}}, SHIFT(486),
+  [1127] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_index_statement, 7, 0, 9),
+  [1129] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_index_statement, 7, 0, 9),
+  [1131] = {.entry = {.count = 1, .reusable = true}}, SHIFT(959),
+  [1133] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 12, 0, 58),
+  [1135] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 12, 0, 58),
+  [1137] = {.entry = {.count = 1, .reusable = true}}, SHIFT(94),
+  [1139] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_index_statement, 12, 0, 60),
+  [1141] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_index_statement, 12, 0, 60),
+  [1143] = {.entry = {.count = 1, .reusable = true}}, SHIFT(993),
+  [1145] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_materialized_view_statement, 12, 0, 62),
+  [1147] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_materialized_view_statement, 12, 0, 62),
+  [1149] = {.entry = {.count = 1, .reusable = true}}, SHIFT(492),
+  [1151] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_index_statement, 9, 0, 35),
+  [1153] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_index_statement, 9, 0, 35),
+  [1155] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1043),
+  [1157] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_table_statement, 9, 0, 7),
+  [1159] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_table_statement, 9, 0, 7),
+  [1161] = {.entry = {.count = 1, .reusable = true}}, SHIFT(498),
+  [1163] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_delete_statement, 6, 0, 6),
+  [1165] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_delete_statement, 6, 0, 6),
+  [1167] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_insert_statement, 12, 0, 44),
+  [1169] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_insert_statement, 12, 0, 44),
+  [1171] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_index_statement, 10, 0, 41),
+  [1173] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_index_statement, 10, 0, 41),
+  [1175] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1014),
+  [1177] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_index_statement, 10, 0, 42),
+  [1179] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_index_statement, 10, 0, 42),
+  [1181] = {.entry = {.count = 1, .reusable = true}}, SHIFT(900),
+  [1183] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_compact_storage, 2, 0, 0),
+  [1185] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_compact_storage, 2, 0, 0),
+  [1187] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_index_statement, 10, 0, 43),
+  [1189] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_index_statement, 10, 0, 43),
+  [1191] = {.entry = {.count = 1, .reusable = true}}, SHIFT(909),
+  [1193] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 13, 0, 66),
+  [1195] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 13, 0, 66),
+  [1197] = {.entry = {.count = 1, .reusable = true}}, SHIFT(98),
+  [1199] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_delete_statement, 8, 0, 14),
+  [1201] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_delete_statement, 8, 0, 14),
+  [1203] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 13, 0, 69),
+  [1205] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 13, 0, 69),
+  [1207] = {.entry = {.count = 1, .reusable = true}}, SHIFT(99),
+  [1209] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_update_statement, 8, 0, 15),
+  [1211] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_update_statement, 8, 0, 15),
+  [1213] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_table_statement, 7, 0, 5),
+  [1215] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_table_statement, 7, 0, 5),
+  [1217] = {.entry = {.count = 1, .reusable = true}}, SHIFT(494),
+  [1219] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_insert_statement, 9, 0, 6),
+  [1221] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_insert_statement, 9, 0, 6),
+  [1223] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_index_statement, 9, 0, 18),
+  [1225] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_index_statement, 9, 0, 18),
+  [1227] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1029),
+  [1229] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_insert_statement, 13, 0, 53),
+  [1231] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_insert_statement, 13, 0, 53),
+  [1233] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_insert_statement, 13, 0, 54),
+  [1235] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_insert_statement, 13, 0, 54),
+  [1237] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_drop_function_statement, 4, 0, 7),
+  [1239] = {.entry = {.count = 1, .reusable = true}}, SHIFT(17),
+  [1241] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_drop_function_statement, 4, 0, 7),
+  [1243] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 14, 0, 78),
+  [1245] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 14, 0, 78),
+  [1247] = {.entry = {.count = 1, .reusable = true}}, SHIFT(103),
+  [1249] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_drop_aggregate_statement, 4, 0, 7),
+  [1251] = {.entry = {.count = 1, .reusable = true}}, SHIFT(18),
+  [1253] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_drop_aggregate_statement, 4, 0, 7),
+  [1255] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 14, 0, 83),
+  [1257] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 14, 0, 83),
+  [1259] = {.entry = {.count = 1, .reusable = true}}, SHIFT(104),
+  [1261] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 14),
+  [1263] = {.entry = {.count = 1, .reusable = true}}, SHIFT(898),
+  [1265] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_select_statement, 10, 0, 14),
+  [1267] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_index_statement, 9, 0, 33),
+  [1269] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_index_statement, 9, 0, 33),
+  [1271] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1031),
+  [1273] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 14, 0, 86),
+  [1275] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 14, 0, 86),
+  [1277] = {.entry = {.count = 1, .reusable = true}}, SHIFT(106),
+  [1279] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_clustering_order, 7, 0, 0),
+  [1281] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_clustering_order, 7, 0, 0),
+  [1283] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_insert_statement, 14, 0, 65),
+  [1285] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_insert_statement, 14, 0, 65),
+  [1287] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 15, 0, 92),
+  [1289] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 15, 0, 92),
+  [1291] = {.entry = {.count = 1, .reusable = true}}, SHIFT(107),
+  [1293] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_limit_clause, 2, 0, 0),
+  [1295] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_limit_clause, 2, 0, 0),
+  [1297] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_drop_function_statement, 3, 0, 5),
+  [1299] = {.entry = {.count = 1, .reusable = true}}, SHIFT(16),
+  [1301] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_drop_function_statement, 3, 0, 5),
+  [1303] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 15, 0, 95),
+  [1305] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 15, 0, 95),
+  [1307] = {.entry = {.count = 1, .reusable = true}}, SHIFT(108),
+  [1309] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 16, 0, 101),
+  [1311] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 16, 0, 101),
+  [1313] = {.entry = {.count = 1, .reusable = true}}, SHIFT(109),
+  [1315] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_table_statement, 8, 0, 7),
+  [1317] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_table_statement, 8, 0, 7),
+  [1319] = {.entry = {.count = 1, .reusable = true}}, SHIFT(501),
+  [1321] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 11, 0, 46),
+  [1323] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 11, 0, 46),
+  [1325] = {.entry = {.count = 1, .reusable = true}}, SHIFT(77),
+  [1327] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_table_statement, 8, 0, 5),
+  [1329] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_table_statement, 8, 0, 5),
+  [1331] = {.entry = {.count = 1, .reusable = true}}, SHIFT(487),
+  [1333] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_delete_statement, 7, 0, 9),
+  [1335] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_delete_statement, 7, 0, 9),
+  [1337] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_rename, 3, 0, 21),
+  [1339] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_rename, 3, 0, 21),
+  [1341] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 13, 0, 74),
+  [1343] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 13, 0, 74),
+  [1345] = {.entry = {.count = 1, .reusable = true}}, SHIFT(101),
+  [1347] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 18, 0, 109),
+  [1349] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 18, 0, 109),
+  [1351] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_alter_table_statement, 5, 0, 5),
+  [1353] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_alter_table_statement, 5, 0, 5),
+  [1355] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_drop_aggregate_statement, 7, 0, 7),
+  [1357] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_drop_aggregate_statement, 7, 0, 7),
+  [1359] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_drop_aggregate_statement, 7, 0, 5),
+  [1361] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_drop_aggregate_statement, 7, 0, 5),
+  [1363] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_drop_function_statement, 7, 0, 7),
+  [1365] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_drop_function_statement, 7, 0, 7),
+  [1367] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_drop_function_statement, 7, 0, 5),
+  [1369] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_drop_function_statement, 7, 0, 5),
+  [1371] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_alter_keyspace_statement, 5, 0, 5),
+  [1373] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_alter_keyspace_statement, 5, 0, 5),
+  [1375] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_alter_type_statement, 5, 0, 5),
+  [1377] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_alter_type_statement, 5, 0, 5),
+  [1379] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_batch_statement, 5, 0, 0),
+  [1381] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_batch_statement, 5, 0, 0),
+  [1383] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_describe_statement, 3, 0, 5),
+  [1385] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_describe_statement, 3, 0, 5),
+  [1387] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_alter_table_statement, 8, 0, 7),
+  [1389] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_alter_table_statement, 8, 0, 7),
+  [1391] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_alter_table_statement, 8, 0, 26),
+  [1393] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_alter_table_statement, 8, 0, 26),
+  [1395] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_alter_table_statement, 8, 0, 5),
+  [1397] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_alter_table_statement, 8, 0, 5),
+  [1399] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_keyspace_statement, 5, 0, 5),
+  [1401] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_keyspace_statement, 5, 0, 5),
+  [1403] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_type_statement, 8, 0, 7),
+  [1405] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_type_statement, 8, 0, 7),
+  [1407] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_type_statement, 8, 0, 5),
+  [1409] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_type_statement, 8, 0, 5),
+  [1411] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_drop_aggregate_statement, 8, 0, 7),
+  [1413] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_drop_aggregate_statement, 8, 0, 7),
+  [1415] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_drop_function_statement, 8, 0, 7),
+  [1417] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_drop_function_statement, 8, 0, 7),
+  [1419] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_drop_table_statement, 3, 0, 5),
+  [1421] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_drop_table_statement, 3, 0, 5),
+  [1423] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_drop_aggregate_statement, 5, 0, 5),
+  [1425] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_drop_aggregate_statement, 5, 0, 5),
+  [1427] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_describe_statement, 2, 0, 1),
+  [1429] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_describe_statement, 2, 0, 1),
+  [1431] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_drop_function_statement, 5, 0, 5),
+  [1433] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_drop_function_statement, 5, 0, 5),
+  [1435] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_drop_materialized_view_statement, 5, 0, 12),
+  [1437] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_drop_materialized_view_statement, 5, 0, 12),
+  [1439] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_drop_index_statement, 3, 0, 5),
+  [1441] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_drop_index_statement, 3, 0, 5),
+  [1443] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_drop_keyspace_statement, 3, 0, 5),
+  [1445] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_drop_keyspace_statement, 3, 0, 5),
+  [1447] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_drop_type_statement, 3, 0, 5),
+  [1449] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_drop_type_statement, 3, 0, 5),
+  [1451] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_alter_table_statement, 9, 0, 7),
+  [1453] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_alter_table_statement, 9, 0, 7),
+  [1455] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_alter_table_statement, 9, 0, 5),
+  [1457] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_alter_table_statement, 9, 0, 5),
+  [1459] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_table_statement, 9, 0, 5),
+  [1461] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_table_statement, 9, 0, 5),
+  [1463] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_truncate_statement, 3, 0, 5),
+  [1465] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_truncate_statement, 3, 0, 5),
+  [1467] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_alter_table_statement, 6, 0, 7),
+  [1469] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_alter_table_statement, 6, 0, 7),
+  [1471] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_type_statement, 9, 0, 7),
+  [1473] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_type_statement, 9, 0, 7),
+  [1475] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_alter_table_statement, 6, 0, 5),
+  [1477] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_alter_table_statement, 6, 0, 5),
+  [1479] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_alter_keyspace_statement, 6, 0, 7),
+  [1481] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_alter_keyspace_statement, 6, 0, 7),
+  [1483] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_alter_table_statement, 10, 0, 7),
+  [1485] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_alter_table_statement, 10, 0, 7),
+  [1487] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_alter_materialized_view_statement, 6, 0, 7),
+  [1489] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_alter_materialized_view_statement, 6, 0, 7),
+  [1491] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_alter_type_statement, 6, 0, 7),
+  [1493] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_alter_type_statement, 6, 0, 7),
+  [1495] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_table_statement, 10, 0, 7),
+  [1497] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_table_statement, 10, 0, 7),
+  [1499] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_table_statement, 10, 0, 5),
+  [1501] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_table_statement, 10, 0, 5),
+  [1503] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_alter_type_statement, 6, 0, 5),
+  [1505] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_alter_type_statement, 6, 0, 5),
+  [1507] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_batch_statement, 6, 0, 0),
+  [1509] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_batch_statement, 6, 0, 0),
+  [1511] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_batch_statement, 4, 0, 0),
+  [1513] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_batch_statement, 4, 0, 0),
+  [1515] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_keyspace_statement, 6, 0, 7),
+  [1517] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_keyspace_statement, 6, 0, 7),
+  [1519] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 10, 0, 9),
+  [1521] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_select_statement, 10, 0, 9),
+  [1523] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_type_statement, 6, 0, 5),
+  [1525] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_type_statement, 6, 0, 5),
+  [1527] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 11, 0, 47),
+  [1529] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 11, 0, 47),
+  [1531] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_table_statement, 11, 0, 7),
+  [1533] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_table_statement, 11, 0, 7),
+  [1535] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_drop_aggregate_statement, 6, 0, 7),
+  [1537] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_drop_aggregate_statement, 6, 0, 7),
+  [1539] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_drop_aggregate_statement, 6, 0, 5),
+  [1541] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_drop_aggregate_statement, 6, 0, 5),
+  [1543] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_drop_function_statement, 6, 0, 7),
+  [1545] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_drop_function_statement, 6, 0, 7),
+  [1547] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 14),
+  [1549] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_select_statement, 11, 0, 14),
+  [1551] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_drop_function_statement, 6, 0, 5),
+  [1553] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_drop_function_statement, 6, 0, 5),
+  [1555] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 11, 0, 9),
+  [1557] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_select_statement, 11, 0, 9),
+  [1559] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 12, 0, 59),
+  [1561] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 12, 0, 59),
+  [1563] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_function_statement, 12, 0, 61),
+  [1565] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_function_statement, 12, 0, 61),
+  [1567] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_describe_statement, 4, 0, 7),
+  [1569] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_describe_statement, 4, 0, 7),
+  [1571] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_drop_table_statement, 4, 0, 7),
+  [1573] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_drop_table_statement, 4, 0, 7),
+  [1575] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_drop_index_statement, 4, 0, 7),
+  [1577] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_drop_index_statement, 4, 0, 7),
+  [1579] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 12, 0, 14),
+  [1581] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_select_statement, 12, 0, 14),
+  [1583] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 12, 0, 18),
+  [1585] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_select_statement, 12, 0, 18),
+  [1587] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_drop_keyspace_statement, 4, 0, 7),
+  [1589] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_drop_keyspace_statement, 4, 0, 7),
+  [1591] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 13, 0, 67),
+  [1593] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 13, 0, 67),
+  [1595] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 13, 0, 68),
+  [1597] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 13, 0, 68),
+  [1599] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_alter_table_statement, 7, 0, 7),
+  [1601] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_alter_table_statement, 7, 0, 7),
+  [1603] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_drop_materialized_view_statement, 4, 0, 7),
+  [1605] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_drop_materialized_view_statement, 4, 0, 7),
+  [1607] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 13, 0, 70),
+  [1609] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 13, 0, 70),
+  [1611] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_function_statement, 13, 0, 71),
+  [1613] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_function_statement, 13, 0, 71),
+  [1615] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_function_statement, 13, 0, 72),
+  [1617] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_function_statement, 13, 0, 72),
+  [1619] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_index_statement, 13, 0, 35),
+  [1621] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_index_statement, 13, 0, 35),
+  [1623] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_materialized_view_statement, 13, 0, 51),
+  [1625] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_materialized_view_statement, 13, 0, 51),
+  [1627] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_alter_table_statement, 7, 0, 5),
+  [1629] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_alter_table_statement, 7, 0, 5),
+  [1631] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_drop_type_statement, 4, 0, 7),
+  [1633] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_drop_type_statement, 4, 0, 7),
+  [1635] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_alter_table_statement, 7, 0, 20),
+  [1637] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_alter_table_statement, 7, 0, 20),
+  [1639] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 13, 0, 75),
+  [1641] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 13, 0, 75),
+  [1643] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_truncate_statement, 2, 0, 1),
+  [1645] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_truncate_statement, 2, 0, 1),
+  [1647] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_use_statement, 2, 0, 4),
+  [1649] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_use_statement, 2, 0, 4),
+  [1651] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_statement, 13, 0, 18),
+  [1653] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_select_statement, 13, 0, 18),
+  [1655] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 14, 0, 77),
+  [1657] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 14, 0, 77),
+  [1659] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 14, 0, 79),
+  [1661] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 14, 0, 79),
+  [1663] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 14, 0, 80),
+  [1665] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 14, 0, 80),
+  [1667] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_alter_materialized_view_statement, 7, 0, 12),
+  [1669] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_alter_materialized_view_statement, 7, 0, 12),
+  [1671] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_index_statement, 14, 0, 41),
+  [1673] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_index_statement, 14, 0, 41),
+  [1675] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_function_statement, 14, 0, 81),
+  [1677] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_function_statement, 14, 0, 81),
+  [1679] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_function_statement, 14, 0, 82),
+  [1681] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_function_statement, 14, 0, 82),
+  [1683] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_index_statement, 14, 0, 43),
+  [1685] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_index_statement, 14, 0, 43),
+  [1687] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_materialized_view_statement, 14, 0, 62),
+  [1689] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_materialized_view_statement, 14, 0, 62),
+  [1691] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_alter_type_statement, 7, 0, 7),
+  [1693] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_alter_type_statement, 7, 0, 7),
+  [1695] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 14, 0, 84),
+  [1697] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 14, 0, 84),
+  [1699] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_batch_statement, 7, 0, 0),
+  [1701] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_batch_statement, 7, 0, 0),
+  [1703] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 14, 0, 87),
+  [1705] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 14, 0, 87),
+  [1707] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_function_statement, 14, 0, 88),
+  [1709] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_function_statement, 14, 0, 88),
+  [1711] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 15, 0, 89),
+  [1713] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 15, 0, 89),
+  [1715] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 15, 0, 90),
+  [1717] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 15, 0, 90),
+  [1719] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_index_statement, 15, 0, 49),
+  [1721] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_index_statement, 15, 0, 49),
+  [1723] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_index_statement, 15, 0, 50),
+  [1725] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_index_statement, 15, 0, 50),
+  [1727] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_function_statement, 15, 0, 91),
+  [1729] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_function_statement, 15, 0, 91),
+  [1731] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 15, 0, 93),
+  [1733] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 15, 0, 93),
+  [1735] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 15, 0, 94),
+  [1737] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 15, 0, 94),
+  [1739] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 15, 0, 96),
+  [1741] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 15, 0, 96),
+  [1743] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_function_statement, 15, 0, 97),
+  [1745] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_function_statement, 15, 0, 97),
+  [1747] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_function_statement, 15, 0, 98),
+  [1749] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_function_statement, 15, 0, 98),
+  [1751] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 16, 0, 99),
+  [1753] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 16, 0, 99),
+  [1755] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_index_statement, 16, 0, 60),
+  [1757] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_index_statement, 16, 0, 60),
+  [1759] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 16, 0, 100),
+  [1761] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 16, 0, 100),
+  [1763] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 16, 0, 102),
+  [1765] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 16, 0, 102),
+  [1767] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 16, 0, 103),
+  [1769] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 16, 0, 103),
+  [1771] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_function_statement, 16, 0, 104),
+  [1773] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_function_statement, 16, 0, 104),
+  [1775] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_function_statement, 16, 0, 105),
+  [1777] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_function_statement, 16, 0, 105),
+  [1779] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 17, 0, 106),
+  [1781] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 17, 0, 106),
+  [1783] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_type_statement, 7, 0, 7),
+  [1785] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_type_statement, 7, 0, 7),
+  [1787] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 17, 0, 107),
+  [1789] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 17, 0, 107),
+  [1791] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_function_statement, 17, 0, 108),
+  [1793] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_function_statement, 17, 0, 108),
+  [1795] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_type_statement, 7, 0, 5),
+  [1797] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_type_statement, 7, 0, 5),
+  [1799] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_create_aggregate_statement, 12, 0, 56),
+  [1801] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_create_aggregate_statement, 12, 0, 56),
+  [1803] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element_access, 4, 0, 11),
+  [1805] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element_access, 4, 0, 11),
+  [1807] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1023),
+  [1809] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1026),
+  [1811] = {.entry = {.count = 1, .reusable = true}}, SHIFT(731),
+  [1813] = {.entry = {.count = 1, .reusable = true}}, SHIFT(926),
+  [1815] = {.entry = {.count = 1, .reusable = true}}, SHIFT(156),
+  [1817] = {.entry = {.count = 1, .reusable = false}}, SHIFT(156),
+  [1819] = {.entry = {.count = 1, .reusable = true}}, SHIFT(69),
+  [1821] = {.entry = {.count = 1, .reusable = true}}, SHIFT(617),
+  [1823] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1103),
+  [1825] = {.entry = {.count = 1, .reusable = true}}, SHIFT(560),
+  [1827] = {.entry = {.count = 1, .reusable = true}}, SHIFT(561),
+  [1829] = {.entry = {.count = 1, .reusable = true}}, SHIFT(937),
+  [1831] = {.entry = {.count = 1, .reusable = true}}, SHIFT(562),
+  [1833] = {.entry = {.count = 1, .reusable = true}}, SHIFT(567),
+  [1835] = {.entry = {.count = 1, .reusable = true}}, SHIFT(582),
+  [1837] = {.entry = {.count = 1, .reusable = true}}, SHIFT(944),
+  [1839] = {.entry = {.count = 1, .reusable = true}}, SHIFT(996),
+  [1841] = {.entry = {.count = 1, .reusable = true}}, SHIFT(544),
+  [1843] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_tuple_of_columns, 3, 0, 0),
+  [1845] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_tuple_of_columns, 3, 0, 0),
+  [1847] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_token_call, 4, 0, 0),
+  [1849] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_token_call, 4, 0, 0),
+  [1851] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1061),
+  [1853] = {.entry = {.count = 1, .reusable = true}}, SHIFT(724),
+  [1855] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_token_call, 5, 0, 0),
+  [1857] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_token_call, 5, 0, 0),
+  [1859] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1096),
+  [1861] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_tuple_of_columns, 4, 0, 0),
+  [1863] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_tuple_of_columns, 4, 0, 0),
+  [1865] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1002),
+  [1867] = {.entry = {.count = 1, .reusable = true}}, SHIFT(196),
+  [1869] = {.entry = {.count = 1, .reusable = true}}, SHIFT(542),
+  [1871] = {.entry = {.count = 1, .reusable = true}}, SHIFT(547),
+  [1873] = {.entry = {.count = 1, .reusable = true}}, SHIFT(559),
+  [1875] = {.entry = {.count = 1, .reusable = true}}, SHIFT(564),
+  [1877] = {.entry = {.count = 1, .reusable = true}}, SHIFT(569),
+  [1879] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1063),
+  [1881] = {.entry = {.count = 1, .reusable = true}}, SHIFT(543),
+  [1883] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1044),
+  [1885] = {.entry = {.count = 1, .reusable = false}}, SHIFT(133),
+  [1887] = {.entry = {.count = 1, .reusable = true}}, SHIFT(314),
+  [1889] = {.entry = {.count = 1, .reusable = true}}, SHIFT(133),
+  [1891] = {.entry = {.count = 1, .reusable = true}}, SHIFT(132),
+  [1893] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1013),
+  [1895] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1109),
+  [1897] = {.entry = {.count = 1, .reusable = true}}, SHIFT(299),
+  [1899] = {.entry = {.count = 1, .reusable = true}}, SHIFT(954),
+  [1901] = {.entry = {.count = 1, .reusable = true}}, SHIFT(342),
+  [1903] = {.entry = {.count = 1, .reusable = true}}, SHIFT(652),
+  [1905] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_batch_statement_repeat1, 2, 0, 0),
+  [1907] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_batch_statement_repeat1, 2, 0, 0), SHIFT_REPEAT(556),
+  [1910] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_batch_statement_repeat1, 2, 0, 0), SHIFT_REPEAT(974),
+  [1913] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_batch_statement_repeat1, 2, 0, 0), SHIFT_REPEAT(651),
+  [1916] = {.entry = {.count = 1, .reusable = true}}, SHIFT(348),
+  [1918] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1082),
+  [1920] = {.entry = {.count = 1, .reusable = true}}, SHIFT(621),
+  [1922] = {.entry = {.count = 1, .reusable = true}}, SHIFT(592),
+  [1924] = {.entry = {.count = 1, .reusable = true}}, SHIFT(665),
+  [1926] = {.entry = {.count = 1, .reusable = true}}, SHIFT(639),
+  [1928] = {.entry = {.count = 1, .reusable = true}}, SHIFT(631),
+  [1930] = {.entry = {.count = 1, .reusable = true}}, SHIFT(723),
+  [1932] = {.entry = {.count = 1, .reusable = true}}, SHIFT(645),
+  [1934] = {.entry = {.count = 1, .reusable = true}}, SHIFT(649),
+  [1936] = {.entry = {.count = 1, .reusable = true}}, SHIFT(596),
+  [1938] = {.entry = {.count = 1, .reusable = true}}, SHIFT(800),
+  [1940] = {.entry = {.count = 1, .reusable = true}}, SHIFT(482),
+  [1942] = {.entry = {.count = 1, .reusable = true}}, SHIFT(659),
+  [1944] = {.entry = {.count = 1, .reusable = true}}, SHIFT(394),
+  [1946] = {.entry = {.count = 1, .reusable = true}}, SHIFT(741),
+  [1948] = {.entry = {.count = 1, .reusable = true}}, SHIFT(372),
+  [1950] = {.entry = {.count = 1, .reusable = true}}, SHIFT(373),
+  [1952] = {.entry = {.count = 1, .reusable = true}}, SHIFT(549),
+  [1954] = {.entry = {.count = 1, .reusable = true}}, SHIFT(577),
+  [1956] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1100),
+  [1958] = {.entry = {.count = 1, .reusable = true}}, SHIFT(545),
+  [1960] = {.entry = {.count = 1, .reusable = true}}, SHIFT(742),
+  [1962] = {.entry = {.count = 1, .reusable = true}}, SHIFT(765),
+  [1964] = {.entry = {.count = 1, .reusable = true}}, SHIFT(669),
+  [1966] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_batch_statement_repeat1, 1, 0, 0),
+  [1968] = {.entry = {.count = 1, .reusable = true}}, SHIFT(540),
+  [1970] = {.entry = {.count = 1, .reusable = true}}, SHIFT(660),
+  [1972] = {.entry = {.count = 1, .reusable = true}}, SHIFT(565),
+  [1974] = {.entry = {.count = 1, .reusable = true}}, SHIFT(522),
+  [1976] = {.entry = {.count = 1, .reusable = true}}, SHIFT(488),
+  [1978] = {.entry = {.count = 1, .reusable = true}}, SHIFT(204),
+  [1980] = {.entry = {.count = 1, .reusable = true}}, SHIFT(202),
+  [1982] = {.entry = {.count = 1, .reusable = true}}, SHIFT(71),
+  [1984] = {.entry = {.count = 1, .reusable = true}}, SHIFT(176),
+  [1986] = {.entry = {.count = 1, .reusable = true}}, SHIFT(688),
+  [1988] = {.entry = {.count = 1, .reusable = true}}, SHIFT(209),
+  [1990] = {.entry = {.count = 1, .reusable = true}}, SHIFT(244),
+  [1992] = {.entry = {.count = 1, .reusable = true}}, SHIFT(531),
+  [1994] = {.entry = {.count = 1, .reusable = true}}, SHIFT(661),
+  [1996] = {.entry = {.count = 1, .reusable = true}}, SHIFT(548),
+  [1998] = {.entry = {.count = 1, .reusable = true}}, SHIFT(534),
+  [2000] = {.entry = {.count = 1, .reusable = true}}, SHIFT(489),
+  [2002] = {.entry = {.count = 1, .reusable = true}}, SHIFT(729),
+  [2004] = {.entry = {.count = 1, .reusable = true}}, SHIFT(725),
+  [2006] = {.entry = {.count = 1, .reusable = true}}, SHIFT(726),
+  [2008] = {.entry = {.count = 1, .reusable = true}}, SHIFT(337),
+  [2010] = {.entry = {.count = 1, .reusable = true}}, SHIFT(653),
+  [2012] = {.entry = {.count = 1, .reusable = true}}, SHIFT(524),
+  [2014] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__expression, 1, 0, 0),
+  [2016] = {.entry = {.count = 1, .reusable = true}}, SHIFT(68),
+  [2018] = {.entry = {.count = 1, .reusable = false}}, SHIFT(68),
+  [2020] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_drop_function_statement_repeat1, 2, 0, 0), SHIFT_REPEAT(29),
+  [2023] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_drop_function_statement_repeat1, 2, 0, 0),
+  [2025] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_relation_repeat1, 2, 0, 0),
+  [2027] = {.entry = {.count = 1, .reusable = true}}, SHIFT(78),
+  [2029] = {.entry = {.count = 1, .reusable = true}}, SHIFT(123),
+  [2031] = {.entry = {.count = 1, .reusable = true}}, SHIFT(80),
+  [2033] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cast_expression, 6, 0, 0),
+  [2035] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym__selector_expression, 1, 0, 0), REDUCE(sym__term, 1, 0, 0),
+  [2038] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_relation_repeat1, 2, 0, 0), SHIFT_REPEAT(78),
+  [2041] = {.entry = {.count = 1, .reusable = true}}, SHIFT(61),
+  [2043] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_selection, 2, 0, 0),
+  [2045] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1066),
+  [2047] = {.entry = {.count = 1, .reusable = true}}, SHIFT(29),
+  [2049] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1107),
+  [2051] = {.entry = {.count = 1, .reusable = true}}, SHIFT(152),
+  [2053] = {.entry = {.count = 1, .reusable = true}}, SHIFT(650),
+  [2055] = {.entry = {.count = 1, .reusable = true}}, SHIFT(332),
+  [2057] = {.entry = {.count = 1, .reusable = true}}, SHIFT(939),
+  [2059] = {.entry = {.count = 1, .reusable = true}}, SHIFT(521),
+  [2061] = {.entry = {.count = 1, .reusable = true}}, SHIFT(279),
+  [2063] = {.entry = {.count = 1, .reusable = true}}, SHIFT(855),
+  [2065] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_primary_key_definition_repeat1, 2, 0, 40), SHIFT_REPEAT(670),
+  [2068] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_primary_key_definition_repeat1, 2, 0, 40),
+  [2070] = {.entry = {.count = 1, .reusable = true}}, SHIFT(961),
+  [2072] = {.entry = {.count = 1, .reusable = true}}, SHIFT(963),
+  [2074] = {.entry = {.count = 1, .reusable = true}}, SHIFT(637),
+  [2076] = {.entry = {.count = 1, .reusable = true}}, SHIFT(823),
+  [2078] = {.entry = {.count = 1, .reusable = true}}, SHIFT(770),
+  [2080] = {.entry = {.count = 1, .reusable = true}}, SHIFT(827),
+  [2082] = {.entry = {.count = 1, .reusable = true}}, SHIFT(945),
+  [2084] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_alter_table_statement_repeat1, 2, 0, 0), SHIFT_REPEAT(605),
+  [2087] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_alter_table_statement_repeat1, 2, 0, 0),
+  [2089] = {.entry = {.count = 1, .reusable = true}}, SHIFT(114),
+  [2091] = {.entry = {.count = 1, .reusable = true}}, SHIFT(246),
+  [2093] = {.entry = {.count = 1, .reusable = true}}, SHIFT(190),
+  [2095] = {.entry = {.count = 1, .reusable = true}}, SHIFT(192),
+  [2097] = {.entry = {.count = 1, .reusable = true}}, SHIFT(625),
+  [2099] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_function_call_repeat1, 2, 0, 0), SHIFT_REPEAT(62),
+  [2102] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_function_call_repeat1, 2, 0, 0),
+  [2104] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_update_statement_repeat1, 2, 0, 0), SHIFT_REPEAT(524),
+  [2107] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_update_statement_repeat1, 2, 0, 0),
+  [2109] = {.entry = {.count = 1, .reusable = true}}, SHIFT(506),
+  [2111] = {.entry = {.count = 1, .reusable = true}}, SHIFT(960),
+  [2113] = {.entry = {.count = 1, .reusable = true}}, SHIFT(529),
+  [2115] = {.entry = {.count = 1, .reusable = true}}, SHIFT(290),
+  [2117] = {.entry = {.count = 1, .reusable = true}}, SHIFT(605),
+  [2119] = {.entry = {.count = 1, .reusable = true}}, SHIFT(367),
+  [2121] = {.entry = {.count = 1, .reusable = true}}, SHIFT(151),
+  [2123] = {.entry = {.count = 1, .reusable = true}}, SHIFT(504),
+  [2125] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1019),
+  [2127] = {.entry = {.count = 1, .reusable = true}}, SHIFT(389),
+  [2129] = {.entry = {.count = 1, .reusable = true}}, SHIFT(533),
+  [2131] = {.entry = {.count = 1, .reusable = true}}, SHIFT(369),
+  [2133] = {.entry = {.count = 1, .reusable = true}}, SHIFT(791),
+  [2135] = {.entry = {.count = 1, .reusable = true}}, SHIFT(573),
+  [2137] = {.entry = {.count = 1, .reusable = true}}, SHIFT(412),
+  [2139] = {.entry = {.count = 1, .reusable = true}}, SHIFT(794),
+  [2141] = {.entry = {.count = 1, .reusable = true}}, SHIFT(193),
+  [2143] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_delete_statement_repeat1, 2, 0, 0), SHIFT_REPEAT(580),
+  [2146] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_delete_statement_repeat1, 2, 0, 0),
+  [2148] = {.entry = {.count = 1, .reusable = true}}, SHIFT(580),
+  [2150] = {.entry = {.count = 1, .reusable = true}}, SHIFT(609),
+  [2152] = {.entry = {.count = 1, .reusable = true}}, SHIFT(585),
+  [2154] = {.entry = {.count = 1, .reusable = true}}, SHIFT(479),
+  [2156] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1067),
+  [2158] = {.entry = {.count = 1, .reusable = true}}, SHIFT(528),
+  [2160] = {.entry = {.count = 1, .reusable = true}}, SHIFT(400),
+  [2162] = {.entry = {.count = 1, .reusable = true}}, SHIFT(588),
+  [2164] = {.entry = {.count = 1, .reusable = true}}, SHIFT(416),
+  [2166] = {.entry = {.count = 1, .reusable = true}}, SHIFT(523),
+  [2168] = {.entry = {.count = 1, .reusable = true}}, SHIFT(502),
+  [2170] = {.entry = {.count = 1, .reusable = true}}, SHIFT(284),
+  [2172] = {.entry = {.count = 1, .reusable = true}}, SHIFT(420),
+  [2174] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_create_table_statement_repeat1, 2, 0, 0), SHIFT_REPEAT(553),
+  [2177] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_create_table_statement_repeat1, 2, 0, 0),
+  [2179] = {.entry = {.count = 1, .reusable = true}}, SHIFT(975),
+  [2181] = {.entry = {.count = 1, .reusable = true}}, SHIFT(670),
+  [2183] = {.entry = {.count = 1, .reusable = true}}, SHIFT(221),
+  [2185] = {.entry = {.count = 1, .reusable = true}}, SHIFT(813),
+  [2187] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_create_function_statement_repeat1, 2, 0, 0), SHIFT_REPEAT(637),
+  [2190] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_create_function_statement_repeat1, 2, 0, 0),
+  [2192] = {.entry = {.count = 1, .reusable = true}}, SHIFT(64),
+  [2194] = {.entry = {.count = 1, .reusable = true}}, SHIFT(161),
+  [2196] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1050),
+  [2198] = {.entry = {.count = 1, .reusable = true}}, SHIFT(851),
+  [2200] = {.entry = {.count = 1, .reusable = true}}, SHIFT(225),
+  [2202] = {.entry = {.count = 1, .reusable = true}}, SHIFT(745),
+  [2204] = {.entry = {.count = 1, .reusable = true}}, SHIFT(581),
+  [2206] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_relation_repeat1, 2, 0, 0), SHIFT_REPEAT(114),
+  [2209] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_create_type_statement_repeat1, 2, 0, 0), SHIFT_REPEAT(624),
+  [2212] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_create_type_statement_repeat1, 2, 0, 0),
+  [2214] = {.entry = {.count = 1, .reusable = true}}, SHIFT(509),
+  [2216] = {.entry = {.count = 1, .reusable = true}}, SHIFT(503),
+  [2218] = {.entry = {.count = 1, .reusable = true}}, SHIFT(117),
+  [2220] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_map_literal_repeat1, 2, 0, 0), SHIFT_REPEAT(70),
+  [2223] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_map_literal_repeat1, 2, 0, 0),
+  [2225] = {.entry = {.count = 1, .reusable = true}}, SHIFT(607),
+  [2227] = {.entry = {.count = 1, .reusable = true}}, SHIFT(375),
+  [2229] = {.entry = {.count = 1, .reusable = true}}, SHIFT(70),
+  [2231] = {.entry = {.count = 1, .reusable = true}}, SHIFT(120),
+  [2233] = {.entry = {.count = 1, .reusable = true}}, SHIFT(376),
+  [2235] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1054),
+  [2237] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_selection, 1, 0, 0),
+  [2239] = {.entry = {.count = 1, .reusable = true}}, SHIFT(62),
+  [2241] = {.entry = {.count = 1, .reusable = true}}, SHIFT(115),
+  [2243] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_selection_repeat1, 2, 0, 0), SHIFT_REPEAT(61),
+  [2246] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_selection_repeat1, 2, 0, 0),
+  [2248] = {.entry = {.count = 1, .reusable = true}}, SHIFT(118),
+  [2250] = {.entry = {.count = 1, .reusable = true}}, SHIFT(119),
+  [2252] = {.entry = {.count = 1, .reusable = true}}, SHIFT(748),
+  [2254] = {.entry = {.count = 1, .reusable = true}}, SHIFT(388),
+  [2256] = {.entry = {.count = 1, .reusable = true}}, SHIFT(121),
+  [2258] = {.entry = {.count = 1, .reusable = true}}, SHIFT(357),
+  [2260] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_selector, 1, 0, 0),
+  [2262] = {.entry = {.count = 1, .reusable = true}}, SHIFT(722),
+  [2264] = {.entry = {.count = 1, .reusable = true}}, SHIFT(358),
+  [2266] = {.entry = {.count = 1, .reusable = true}}, SHIFT(440),
+  [2268] = {.entry = {.count = 1, .reusable = true}}, SHIFT(150),
+  [2270] = {.entry = {.count = 1, .reusable = true}}, SHIFT(934),
+  [2272] = {.entry = {.count = 1, .reusable = true}}, SHIFT(122),
+  [2274] = {.entry = {.count = 1, .reusable = true}}, SHIFT(268),
+  [2276] = {.entry = {.count = 1, .reusable = true}}, SHIFT(186),
+  [2278] = {.entry = {.count = 1, .reusable = true}}, SHIFT(359),
+  [2280] = {.entry = {.count = 1, .reusable = true}}, SHIFT(360),
+  [2282] = {.entry = {.count = 1, .reusable = true}}, SHIFT(124),
+  [2284] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_clustering_order_repeat1, 2, 0, 0), SHIFT_REPEAT(650),
+  [2287] = {.entry = {.count = 1, .reusable = true}}, SHIFT(476),
+  [2289] = {.entry = {.count = 1, .reusable = true}}, SHIFT(66),
+  [2291] = {.entry = {.count = 1, .reusable = true}}, SHIFT(465),
+  [2293] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_partition_key, 3, 0, 0),
+  [2295] = {.entry = {.count = 1, .reusable = true}}, SHIFT(558),
+  [2297] = {.entry = {.count = 1, .reusable = true}}, SHIFT(538),
+  [2299] = {.entry = {.count = 1, .reusable = true}}, SHIFT(551),
+  [2301] = {.entry = {.count = 1, .reusable = true}}, SHIFT(552),
+  [2303] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_partition_key, 4, 0, 0),
+  [2305] = {.entry = {.count = 1, .reusable = true}}, SHIFT(469),
+  [2307] = {.entry = {.count = 1, .reusable = true}}, SHIFT(470),
+  [2309] = {.entry = {.count = 1, .reusable = true}}, SHIFT(191),
+  [2311] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parameter, 2, 0, 16),
+  [2313] = {.entry = {.count = 1, .reusable = true}}, SHIFT(714),
+  [2315] = {.entry = {.count = 1, .reusable = true}}, SHIFT(619),
+  [2317] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_primary_key_definition_repeat1, 2, 0, 38),
+  [2319] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_map_entry, 3, 0, 13),
+  [2321] = {.entry = {.count = 1, .reusable = true}}, SHIFT(477),
+  [2323] = {.entry = {.count = 1, .reusable = true}}, SHIFT(452),
+  [2325] = {.entry = {.count = 1, .reusable = true}}, SHIFT(423),
+  [2327] = {.entry = {.count = 1, .reusable = true}}, SHIFT(453),
+  [2329] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_partition_key, 1, 0, 0),
+  [2331] = {.entry = {.count = 1, .reusable = true}}, SHIFT(481),
+  [2333] = {.entry = {.count = 1, .reusable = true}}, SHIFT(546),
+  [2335] = {.entry = {.count = 1, .reusable = true}}, SHIFT(525),
+  [2337] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__literal, 1, 0, 0),
+  [2339] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_binary_expression, 3, 0, 25),
+  [2341] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_selector, 3, 0, 10),
+  [2343] = {.entry = {.count = 1, .reusable = true}}, SHIFT(436),
+  [2345] = {.entry = {.count = 1, .reusable = true}}, SHIFT(460),
+  [2347] = {.entry = {.count = 1, .reusable = true}}, SHIFT(437),
+  [2349] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_assignment, 3, 0, 19),
+  [2351] = {.entry = {.count = 1, .reusable = true}}, SHIFT(26),
+  [2353] = {.entry = {.count = 1, .reusable = true}}, SHIFT(428),
+  [2355] = {.entry = {.count = 1, .reusable = true}}, SHIFT(169),
+  [2357] = {.entry = {.count = 1, .reusable = true}}, SHIFT(294),
+  [2359] = {.entry = {.count = 1, .reusable = true}}, SHIFT(571),
+  [2361] = {.entry = {.count = 1, .reusable = true}}, SHIFT(884),
+  [2363] = {.entry = {.count = 1, .reusable = true}}, SHIFT(306),
+  [2365] = {.entry = {.count = 1, .reusable = true}}, SHIFT(700),
+  [2367] = {.entry = {.count = 1, .reusable = true}}, SHIFT(15),
+  [2369] = {.entry = {.count = 1, .reusable = true}}, SHIFT(887),
+  [2371] = {.entry = {.count = 1, .reusable = true}}, SHIFT(679),
+  [2373] = {.entry = {.count = 1, .reusable = true}}, SHIFT(889),
+  [2375] = {.entry = {.count = 1, .reusable = true}}, SHIFT(957),
+  [2377] = {.entry = {.count = 1, .reusable = true}}, SHIFT(126),
+  [2379] = {.entry = {.count = 1, .reusable = true}}, SHIFT(47),
+  [2381] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_null_input_behavior, 4, 0, 0),
+  [2383] = {.entry = {.count = 1, .reusable = true}}, SHIFT(56),
+  [2385] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1038),
+  [2387] = {.entry = {.count = 1, .reusable = true}}, SHIFT(41),
+  [2389] = {.entry = {.count = 1, .reusable = true}}, SHIFT(267),
+  [2391] = {.entry = {.count = 1, .reusable = true}}, SHIFT(57),
+  [2393] = {.entry = {.count = 1, .reusable = true}}, SHIFT(704),
+  [2395] = {.entry = {.count = 1, .reusable = true}}, SHIFT(680),
+  [2397] = {.entry = {.count = 1, .reusable = true}}, SHIFT(58),
+  [2399] = {.entry = {.count = 1, .reusable = true}}, SHIFT(912),
+  [2401] = {.entry = {.count = 1, .reusable = true}}, SHIFT(705),
+  [2403] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_index_target, 4, 0, 34),
+  [2405] = {.entry = {.count = 1, .reusable = true}}, SHIFT(554),
+  [2407] = {.entry = {.count = 1, .reusable = true}}, SHIFT(308),
+  [2409] = {.entry = {.count = 1, .reusable = true}}, SHIFT(667),
+  [2411] = {.entry = {.count = 1, .reusable = true}}, SHIFT(63),
+  [2413] = {.entry = {.count = 1, .reusable = true}}, SHIFT(914),
+  [2415] = {.entry = {.count = 1, .reusable = true}}, SHIFT(578),
+  [2417] = {.entry = {.count = 1, .reusable = true}}, SHIFT(640),
+  [2419] = {.entry = {.count = 1, .reusable = true}}, SHIFT(930),
+  [2421] = {.entry = {.count = 1, .reusable = true}}, SHIFT(28),
+  [2423] = {.entry = {.count = 1, .reusable = true}}, SHIFT(429),
+  [2425] = {.entry = {.count = 1, .reusable = true}}, SHIFT(681),
+  [2427] = {.entry = {.count = 1, .reusable = true}}, SHIFT(672),
+  [2429] = {.entry = {.count = 1, .reusable = true}}, SHIFT(48),
+  [2431] = {.entry = {.count = 1, .reusable = true}}, SHIFT(541),
+  [2433] = {.entry = {.count = 1, .reusable = true}}, SHIFT(597),
+  [2435] = {.entry = {.count = 1, .reusable = true}}, SHIFT(682),
+  [2437] = {.entry = {.count = 1, .reusable = true}}, SHIFT(49),
+  [2439] = {.entry = {.count = 1, .reusable = true}}, SHIFT(485),
+  [2441] = {.entry = {.count = 1, .reusable = true}}, SHIFT(496),
+  [2443] = {.entry = {.count = 1, .reusable = true}}, SHIFT(515),
+  [2445] = {.entry = {.count = 1, .reusable = true}}, SHIFT(555),
+  [2447] = {.entry = {.count = 1, .reusable = true}}, SHIFT(655),
+  [2449] = {.entry = {.count = 1, .reusable = true}}, SHIFT(897),
+  [2451] = {.entry = {.count = 1, .reusable = true}}, SHIFT(992),
+  [2453] = {.entry = {.count = 1, .reusable = true}}, SHIFT(50),
+  [2455] = {.entry = {.count = 1, .reusable = true}}, SHIFT(994),
+  [2457] = {.entry = {.count = 1, .reusable = true}}, SHIFT(43),
+  [2459] = {.entry = {.count = 1, .reusable = true}}, SHIFT(872),
+  [2461] = {.entry = {.count = 1, .reusable = true}}, SHIFT(516),
+  [2463] = {.entry = {.count = 1, .reusable = true}}, SHIFT(37),
+  [2465] = {.entry = {.count = 1, .reusable = true}}, SHIFT(406),
+  [2467] = {.entry = {.count = 1, .reusable = true}}, SHIFT(874),
+  [2469] = {.entry = {.count = 1, .reusable = true}}, SHIFT(82),
+  [2471] = {.entry = {.count = 1, .reusable = true}}, SHIFT(877),
+  [2473] = {.entry = {.count = 1, .reusable = true}}, SHIFT(923),
+  [2475] = {.entry = {.count = 1, .reusable = true}}, SHIFT(296),
+  [2477] = {.entry = {.count = 1, .reusable = true}}, SHIFT(656),
+  [2479] = {.entry = {.count = 1, .reusable = true}}, SHIFT(987),
+  [2481] = {.entry = {.count = 1, .reusable = true}}, SHIFT(59),
+  [2483] = {.entry = {.count = 1, .reusable = true}}, SHIFT(988),
+  [2485] = {.entry = {.count = 1, .reusable = true}}, SHIFT(33),
+  [2487] = {.entry = {.count = 1, .reusable = true}}, SHIFT(32),
+  [2489] = {.entry = {.count = 1, .reusable = true}}, SHIFT(587),
+  [2491] = {.entry = {.count = 1, .reusable = true}}, SHIFT(396),
+  [2493] = {.entry = {.count = 1, .reusable = true}}, SHIFT(710),
+  [2495] = {.entry = {.count = 1, .reusable = true}}, SHIFT(34),
+  [2497] = {.entry = {.count = 1, .reusable = true}}, SHIFT(885),
+  [2499] = {.entry = {.count = 1, .reusable = true}}, SHIFT(408),
+  [2501] = {.entry = {.count = 1, .reusable = true}}, SHIFT(711),
+  [2503] = {.entry = {.count = 1, .reusable = true}}, SHIFT(35),
+  [2505] = {.entry = {.count = 1, .reusable = true}}, SHIFT(593),
+  [2507] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1065),
+  [2509] = {.entry = {.count = 1, .reusable = true}}, SHIFT(36),
+  [2511] = {.entry = {.count = 1, .reusable = true}}, SHIFT(572),
+  [2513] = {.entry = {.count = 1, .reusable = true}}, SHIFT(277),
+  [2515] = {.entry = {.count = 1, .reusable = true}}, SHIFT(409),
+  [2517] = {.entry = {.count = 1, .reusable = true}}, SHIFT(514),
+  [2519] = {.entry = {.count = 1, .reusable = true}}, SHIFT(446),
+  [2521] = {.entry = {.count = 1, .reusable = true}}, SHIFT(54),
+  [2523] = {.entry = {.count = 1, .reusable = true}}, SHIFT(60),
+  [2525] = {.entry = {.count = 1, .reusable = true}}, SHIFT(642),
+  [2527] = {.entry = {.count = 1, .reusable = true}}, SHIFT(616),
+  [2529] = {.entry = {.count = 1, .reusable = true}}, SHIFT(664),
+  [2531] = {.entry = {.count = 1, .reusable = true}}, SHIFT(921),
+  [2533] = {.entry = {.count = 1, .reusable = true}}, SHIFT(928),
+  [2535] = {.entry = {.count = 1, .reusable = true}}, SHIFT(159),
+  [2537] = {.entry = {.count = 1, .reusable = true}}, SHIFT(591),
+  [2539] = {.entry = {.count = 1, .reusable = true}}, SHIFT(539),
+  [2541] = {.entry = {.count = 1, .reusable = true}}, SHIFT(847),
+  [2543] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1033),
+  [2545] = {.entry = {.count = 1, .reusable = true}}, SHIFT(848),
+  [2547] = {.entry = {.count = 1, .reusable = true}}, SHIFT(39),
+  [2549] = {.entry = {.count = 1, .reusable = true}}, SHIFT(853),
+  [2551] = {.entry = {.count = 1, .reusable = true}}, SHIFT(849),
+  [2553] = {.entry = {.count = 1, .reusable = true}}, SHIFT(411),
+  [2555] = {.entry = {.count = 1, .reusable = true}}, SHIFT(512),
+  [2557] = {.entry = {.count = 1, .reusable = true}}, SHIFT(397),
+  [2559] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1073),
+  [2561] = {.entry = {.count = 1, .reusable = true}}, SHIFT(707),
+  [2563] = {.entry = {.count = 1, .reusable = true}}, SHIFT(160),
+  [2565] = {.entry = {.count = 1, .reusable = true}}, SHIFT(285),
+  [2567] = {.entry = {.count = 1, .reusable = true}}, SHIFT(719),
+  [2569] = {.entry = {.count = 1, .reusable = true}}, SHIFT(30),
+  [2571] = {.entry = {.count = 1, .reusable = true}}, SHIFT(614),
+  [2573] = {.entry = {.count = 1, .reusable = true}}, SHIFT(362),
+  [2575] = {.entry = {.count = 1, .reusable = true}}, SHIFT(663),
+  [2577] = {.entry = {.count = 1, .reusable = true}}, SHIFT(51),
+  [2579] = {.entry = {.count = 1, .reusable = true}}, SHIFT(291),
+  [2581] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1001),
+  [2583] = {.entry = {.count = 1, .reusable = true}}, SHIFT(929),
+  [2585] = {.entry = {.count = 1, .reusable = true}}, SHIFT(946),
+  [2587] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_index_target, 1, 0, 17),
+  [2589] = {.entry = {.count = 1, .reusable = true}}, SHIFT(716),
+  [2591] = {.entry = {.count = 1, .reusable = true}}, SHIFT(510),
+  [2593] = {.entry = {.count = 1, .reusable = true}}, SHIFT(860),
+  [2595] = {.entry = {.count = 1, .reusable = true}}, SHIFT(508),
+  [2597] = {.entry = {.count = 1, .reusable = true}}, SHIFT(611),
+  [2599] = {.entry = {.count = 1, .reusable = true}}, SHIFT(95),
+  [2601] = {.entry = {.count = 1, .reusable = true}}, SHIFT(694),
+  [2603] = {.entry = {.count = 1, .reusable = true}}, SHIFT(931),
+  [2605] = {.entry = {.count = 1, .reusable = true}}, SHIFT(20),
+  [2607] = {.entry = {.count = 1, .reusable = true}}, SHIFT(575),
+  [2609] = {.entry = {.count = 1, .reusable = true}}, SHIFT(307),
+  [2611] = {.entry = {.count = 1, .reusable = true}}, SHIFT(21),
+  [2613] = {.entry = {.count = 1, .reusable = true}}, SHIFT(673),
+  [2615] = {.entry = {.count = 1, .reusable = true}}, SHIFT(274),
+  [2617] = {.entry = {.count = 1, .reusable = true}}, SHIFT(583),
+  [2619] = {.entry = {.count = 1, .reusable = true}}, SHIFT(275),
+  [2621] = {.entry = {.count = 1, .reusable = true}}, SHIFT(398),
+  [2623] = {.entry = {.count = 1, .reusable = true}}, SHIFT(864),
+  [2625] = {.entry = {.count = 1, .reusable = true}}, SHIFT(689),
+  [2627] = {.entry = {.count = 1, .reusable = true}}, SHIFT(899),
+  [2629] = {.entry = {.count = 1, .reusable = true}}, SHIFT(90),
+  [2631] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_null_input_behavior, 5, 0, 0),
+  [2633] = {.entry = {.count = 1, .reusable = true}}, SHIFT(873),
+  [2635] = {.entry = {.count = 1, .reusable = true}}, SHIFT(40),
+  [2637] = {.entry = {.count = 1, .reusable = true}}, SHIFT(690),
+  [2639] = {.entry = {.count = 1, .reusable = true}}, SHIFT(908),
+  [2641] = {.entry = {.count = 1, .reusable = true}}, SHIFT(74),
+  [2643] = {.entry = {.count = 1, .reusable = true}}, SHIFT(13),
+  [2645] = {.entry = {.count = 1, .reusable = true}}, SHIFT(630),
+  [2647] = {.entry = {.count = 1, .reusable = true}}, SHIFT(425),
+  [2649] = {.entry = {.count = 1, .reusable = true}}, SHIFT(574),
+  [2651] = {.entry = {.count = 1, .reusable = true}}, SHIFT(678),
+  [2653] = {.entry = {.count = 1, .reusable = true}}, SHIFT(674),
+  [2655] = {.entry = {.count = 1, .reusable = true}}, SHIFT(846),
+  [2657] = {.entry = {.count = 1, .reusable = true}}, SHIFT(52),
+  [2659] = {.entry = {.count = 1, .reusable = true}}, SHIFT(692),
+  [2661] = {.entry = {.count = 1, .reusable = true}}, SHIFT(871),
+  [2663] = {.entry = {.count = 1, .reusable = true}}, SHIFT(623),
+  [2665] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1046),
+  [2667] = {.entry = {.count = 1, .reusable = true}}, SHIFT(365),
+  [2669] = {.entry = {.count = 1, .reusable = true}}, SHIFT(53),
+  [2671] = {.entry = {.count = 1, .reusable = true}}, SHIFT(254),
+  [2673] = {.entry = {.count = 1, .reusable = true}}, SHIFT(407),
+  [2675] = {.entry = {.count = 1, .reusable = true}}, SHIFT(23),
+  [2677] = {.entry = {.count = 1, .reusable = true}}, SHIFT(557),
+  [2679] = {.entry = {.count = 1, .reusable = true}}, SHIFT(566),
+  [2681] = {.entry = {.count = 1, .reusable = true}}, SHIFT(81),
+  [2683] = {.entry = {.count = 1, .reusable = true}}, SHIFT(956),
+  [2685] = {.entry = {.count = 1, .reusable = true}}, SHIFT(668),
+  [2687] = {.entry = {.count = 1, .reusable = true}}, SHIFT(622),
+  [2689] = {.entry = {.count = 1, .reusable = true}}, SHIFT(595),
+  [2691] = {.entry = {.count = 1, .reusable = true}}, SHIFT(693),
+  [2693] = {.entry = {.count = 1, .reusable = true}}, SHIFT(153),
+  [2695] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1092),
+  [2697] = {.entry = {.count = 1, .reusable = true}}, SHIFT(155),
+  [2699] = {.entry = {.count = 1, .reusable = true}}, SHIFT(55),
+  [2701] = {.entry = {.count = 1, .reusable = true}}, SHIFT(878),
+  [2703] = {.entry = {.count = 1, .reusable = true}}, SHIFT(518),
+  [2705] = {.entry = {.count = 1, .reusable = true}}, SHIFT(44),
+  [2707] = {.entry = {.count = 1, .reusable = true}}, SHIFT(318),
+  [2709] = {.entry = {.count = 1, .reusable = true}}, SHIFT(380),
+  [2711] = {.entry = {.count = 1, .reusable = true}}, SHIFT(590),
+  [2713] = {.entry = {.count = 1, .reusable = true}}, SHIFT(458),
+  [2715] = {.entry = {.count = 1, .reusable = true}}, SHIFT(45),
+  [2717] = {.entry = {.count = 1, .reusable = true}}, SHIFT(383),
+  [2719] = {.entry = {.count = 1, .reusable = true}}, SHIFT(698),
+  [2721] = {.entry = {.count = 1, .reusable = true}}, SHIFT(418),
+  [2723] = {.entry = {.count = 1, .reusable = true}}, SHIFT(491),
+  [2725] = {.entry = {.count = 1, .reusable = true}}, SHIFT(31),
+  [2727] = {.entry = {.count = 1, .reusable = true}}, SHIFT(419),
+  [2729] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1024),
+  [2731] = {.entry = {.count = 1, .reusable = true}}, SHIFT(22),
+  [2733] = {.entry = {.count = 1, .reusable = true}}, SHIFT(46),
+  [2735] = {.entry = {.count = 1, .reusable = true}}, SHIFT(421),
+  [2737] = {.entry = {.count = 1, .reusable = true}}, SHIFT(329),
+  [2739] = {.entry = {.count = 1, .reusable = true}}, SHIFT(364),
+  [2741] = {.entry = {.count = 1, .reusable = true}}, SHIFT(27),
+  [2743] = {.entry = {.count = 1, .reusable = true}}, SHIFT(185),
+  [2745] = {.entry = {.count = 1, .reusable = true}}, SHIFT(387),
+  [2747] = {.entry = {.count = 1, .reusable = true}}, SHIFT(563),
+  [2749] = {.entry = {.count = 1, .reusable = true}}, SHIFT(627),
+  [2751] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
+  [2753] = {.entry = {.count = 1, .reusable = true}}, SHIFT(1041),
+  [2755] = {.entry = {.count = 1, .reusable = true}}, SHIFT(112),
+  [2757] = {.entry = {.count = 1, .reusable = true}}, SHIFT(600),
+  [2759] = {.entry = {.count = 1, .reusable = true}}, SHIFT(628),
+  [2761] = {.entry = {.count = 1, .reusable = true}}, SHIFT(701),
+  [2763] = {.entry = {.count = 1, .reusable = true}}, SHIFT(19),
+  [2765] = {.entry = {.count = 1, .reusable = true}}, SHIFT(42),
+  [2767] = {.entry = {.count = 1, .reusable = true}}, SHIFT(113),
+  [2769] = {.entry = {.count = 1, .reusable = true}}, SHIFT(304),
+  [2771] = {.entry = {.count = 1, .reusable = true}}, SHIFT(857),
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+#ifdef TREE_SITTER_HIDE_SYMBOLS
+#define TS_PUBLIC
+#elif defined(_WIN32)
+#define TS_PUBLIC __declspec(dllexport)
+#else
+#define TS_PUBLIC __attribute__((visibility("default")))
+#endif
+
+TS_PUBLIC const TSLanguage *tree_sitter_cql(void) {
+  static const TSLanguage language = {
+    .abi_version = LANGUAGE_VERSION,
+    .symbol_count = SYMBOL_COUNT,
+    .alias_count = ALIAS_COUNT,
+    .token_count = TOKEN_COUNT,
+    .external_token_count = EXTERNAL_TOKEN_COUNT,
+    .state_count = STATE_COUNT,
+    .large_state_count = LARGE_STATE_COUNT,
+    .production_id_count = PRODUCTION_ID_COUNT,
+    .supertype_count = SUPERTYPE_COUNT,
+    .field_count = FIELD_COUNT,
+    .max_alias_sequence_length = MAX_ALIAS_SEQUENCE_LENGTH,
+    .parse_table = &ts_parse_table[0][0],
+    .small_parse_table = ts_small_parse_table,
+    .small_parse_table_map = ts_small_parse_table_map,
+    .parse_actions = ts_parse_actions,
+    .symbol_names = ts_symbol_names,
+    .field_names = ts_field_names,
+    .field_map_slices = ts_field_map_slices,
+    .field_map_entries = ts_field_map_entries,
+    .symbol_metadata = ts_symbol_metadata,
+    .public_symbol_map = ts_symbol_map,
+    .alias_map = ts_non_terminal_alias_map,
+    .alias_sequences = &ts_alias_sequences[0][0],
+    .lex_modes = (const void*)ts_lex_modes,
+    .lex_fn = ts_lex,
+    .keyword_lex_fn = ts_lex_keywords,
+    .keyword_capture_token = sym__unquoted_identifier,
+    .primary_state_ids = ts_primary_state_ids,
+    .name = "cql",
+    .max_reserved_word_set_size = 0,
+    .metadata = {
+      .major_version = 0,
+      .minor_version = 1,
+      .patch_version = 0,
+    },
+  };
+  return &language;
+}
+#ifdef __cplusplus
+}
+#endif
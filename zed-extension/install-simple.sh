@@ -16,7 +16,7 @@ mkdir -p "$ZED_EXTENSIONS_DIR"
 # Copy files
 echo "📦 Installing extension files..."
 cp extension-simple.toml "$ZED_EXTENSIONS_DIR/extension.toml"
-cp -r grammars "$ZED_EXTENSIONS_DIR/" 2>/dev/null || echo "⚠️  Grammar files not found"
+cp -r languages snippets "$ZED_EXTENSIONS_DIR/"
 
 echo "✅ CassandraORM Zed Extension installed successfully!"
 echo "🔄 Restart Zed to activate the extension."
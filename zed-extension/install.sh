@@ -18,7 +18,8 @@ fi
 
 # Build the extension
 echo "🔨 Building extension..."
-cargo build --release
+rustup target add wasm32-wasip2
+cargo build --release --target wasm32-wasip2
 
 # Create extension directory
 ZED_EXTENSIONS_DIR="$HOME/.config/zed/extensions/cassandraorm"
@@ -27,8 +28,8 @@ mkdir -p "$ZED_EXTENSIONS_DIR"
 # Copy files
 echo "📦 Installing extension files..."
 cp extension.toml "$ZED_EXTENSIONS_DIR/"
-cp -r grammars "$ZED_EXTENSIONS_DIR/"
-cp target/wasm32-wasi/release/cassandraorm_zed.wasm "$ZED_EXTENSIONS_DIR/cassandraorm.wasm" 2>/dev/null || echo "⚠️  WASM file not found, using basic extension"
+cp -r languages snippets "$ZED_EXTENSIONS_DIR/"
+cp target/wasm32-wasip2/release/cassandraorm_zed.wasm "$ZED_EXTENSIONS_DIR/extension.wasm" 2>/dev/null || echo "⚠️  WASM file not found, using basic extension"
 
 echo "✅ CassandraORM Zed Extension installed successfully!"
 echo "🔄 Restart Zed to activate the extension."
@@ -0,0 +1,224 @@
+; Schema files are TypeScript, so the first half of this file highlights the
+; language itself. The CassandraORM patterns come last so they take priority.
+
+; Variables and properties
+
+(identifier) @variable
+(shorthand_property_identifier) @property
+(shorthand_property_identifier_pattern) @variable
+(property_identifier) @property
+(private_property_identifier) @property
+
+((identifier) @constant
+  (#match? @constant "^_*[A-Z][A-Z\\d_]+$"))
+
+((identifier) @type
+  (#match? @type "^[A-Z]"))
+
+[
+  (this)
+  (super)
+] @variable.special
+
+; Functions
+
+(function_declaration name: (identifier) @function)
+(function_expression name: (identifier) @function)
+(generator_function_declaration name: (identifier) @function)
+(method_definition name: (property_identifier) @function.method)
+
+(variable_declarator
+  name: (identifier) @function
+  value: [(function_expression) (arrow_function)])
+
+(call_expression function: (identifier) @function)
+(call_expression
+  function: (member_expression
+    property: (property_identifier) @function.method))
+
+(new_expression constructor: (identifier) @constructor)
+
+; Types
+
+(type_identifier) @type
+(predefined_type) @type.builtin
+
+(type_parameters ["<" ">"] @punctuation.bracket)
+(type_arguments ["<" ">"] @punctuation.bracket)
+
+; Literals
+
+(comment) @comment
+(string) @string
+(template_string) @string
+(escape_sequence) @string.escape
+(regex) @string.regex
+(number) @number
+
+[
+  (true)
+  (false)
+] @boolean
+
+[
+  (null)
+  (undefined)
+] @constant.builtin
+
+(template_substitution
+  "${" @punctuation.special
+  "}" @punctuation.special) @embedded
+
+; Keywords
+
+[
+  "abstract"
+  "as"
+  "async"
+  "await"
+  "break"
+  "case"
+  "catch"
+  "class"
+  "const"
+  "continue"
+  "declare"
+  "default"
+  "delete"
+  "do"
+  "else"
+  "enum"
+  "export"
+  "extends"
+  "finally"
+  "for"
+  "from"
+  "function"
+  "get"
+  "if"
+  "implements"
+  "import"
+  "in"
+  "instanceof"
+  "interface"
+  "keyof"
+  "let"
+  "new"
+  "of"
+  "private"
+  "protected"
+  "public"
+  "readonly"
+  "return"
+  "satisfies"
+  "set"
+  "static"
+  "switch"
+  "throw"
+  "try"
+  "type"
+  "typeof"
+  "var"
+  "void"
+  "while"
+  "with"
+  "yield"
+] @keyword
+
+; Operators and punctuation
+
+[
+  "="
+  "=>"
+  "+"
+  "-"
+  "*"
+  "/"
+  "%"
+  "=="
+  "==="
+  "!="
+  "!=="
+  "<"
+  "<="
+  ">"
+  ">="
+  "&&"
+  "||"
+  "??"
+  "!"
+  "?."
+  "..."
+  "+="
+  "-="
+] @operator
+
+["(" ")" "[" "]" "{" "}"] @punctuation.bracket
+["," ";" "." ":"] @punctuation.delimiter
+
+; CassandraORM schema definitions
+
+(pair
+  key: (property_identifier) @keyword.schema
+  (#match? @keyword.schema "^(fields|key|unique|clustering_order|relations|indexes|materialized_views|options|table_name|methods|validate)$"))
+
+; Field types, both `email: 'text'` and `email: { type: 'text' }`
+(pair
+  key: (property_identifier) @_fields
+  value: (object
+    (pair
+      value: (string (string_fragment) @type.cassandra)))
+  (#eq? @_fields "fields"))
+
+(pair
+  key: (property_identifier) @_fields
+  value: (object
+    (pair
+      value: (object
+        (pair
+          key: (property_identifier) @_type
+          value: (string (string_fragment) @type.cassandra)))))
+  (#eq? @_fields "fields")
+  (#eq? @_type "type"))
+
+; Clustering directions
+(pair
+  key: (property_identifier) @_order
+  value: (object
+    (pair
+      value: (string (string_fragment) @constant.builtin)))
+  (#eq? @_order "clustering_order"))
+
+; Validation rules
+(pair
+  key: (property_identifier) @_validate
+  value: (object
+    (pair
+      key: (property_identifier) @keyword.validation))
+  (#eq? @_validate "validate"))
+
+; CassandraORM methods
+(call_expression
+  function: (member_expression
+    property: (property_identifier) @function.cassandra
+    (#match? @function.cassandra "^(find|findOne|save|update|delete|execute|batch|loadSchema|connect|shutdown|createTable|syncSchema)$")))
+
+(call_expression
+  function: (identifier) @function.cassandra
+  (#match? @function.cassandra "^(createClient|createEnhancedClient|loadSchema)$"))
+
+; AI/ML methods
+(call_expression
+  function: (member_expression
+    property: (property_identifier) @function.ai
+    (#match? @function.ai "^(generateEmbedding|optimizeQueryWithAI|vectorSimilaritySearch|getPerformanceReport|getSemanticCacheStats)$")))
+
+; Distributed methods
+(call_expression
+  function: (member_expression
+    property: (property_identifier) @function.distributed
+    (#match? @function.distributed "^(withDistributedLock|acquireDistributedLock|releaseDistributedLock|discoverServices|setDistributedConfig|getDistributedConfig|getSystemHealth)$")))
+
+; CQL queries that aren't injected (see injections.scm) keep a distinct color.
+((template_string) @string.cql
+  (#match? @string.cql "^`\\s*(SELECT|INSERT|UPDATE|DELETE|CREATE|DROP|ALTER)"))
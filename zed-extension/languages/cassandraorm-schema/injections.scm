@@ -0,0 +1,72 @@
+; Template strings are injected whole, backticks and `${}` interpolations
+; included, so a statement split by interpolations is parsed as one; the CQL
+; grammar reads an interpolation as a name or a value. They are combined into
+; one CQL document per file, where the backticks keep the statements apart.
+
+; CQL passed to the cassandra-driver client:
+; client.execute(`SELECT * FROM users WHERE id = ?`, [id])
+(call_expression
+  function: (member_expression
+    property: (property_identifier) @_method)
+  arguments: (arguments
+    .
+    (template_string) @injection.content)
+  (#any-of? @_method "execute" "eachRow" "stream")
+  (#set! injection.language "cql")
+  (#set! injection.include-children)
+  (#set! injection.combined))
+
+(call_expression
+  function: (member_expression
+    property: (property_identifier) @_method)
+  arguments: (arguments
+    .
+    (string (string_fragment) @injection.content))
+  (#any-of? @_method "execute" "eachRow" "stream")
+  (#set! injection.language "cql"))
+
+; Statements handed to a migration manager, e.g.
+; migrationManager.execute(`ALTER TABLE users ADD phone text`). Only strings
+; that start with a CQL statement are injected since most of its methods take
+; table and column names.
+(call_expression
+  function: (member_expression
+    object: [
+      (identifier) @_receiver
+      (member_expression property: (property_identifier) @_receiver)
+    ]
+    property: (property_identifier) @_method)
+  arguments: (arguments
+    (template_string) @injection.content)
+  (#match? @_receiver "(?i)migration(s|Manager)?$")
+  (#not-any-of? @_method "execute" "eachRow" "stream")
+  (#match? @injection.content "^`\\s*(?i:alter|apply|begin|create|delete|drop|insert|select|truncate|update|use)\\s")
+  (#set! injection.language "cql")
+  (#set! injection.include-children)
+  (#set! injection.combined))
+
+(call_expression
+  function: (member_expression
+    object: [
+      (identifier) @_receiver
+      (member_expression property: (property_identifier) @_receiver)
+    ]
+    property: (property_identifier) @_method)
+  arguments: (arguments
+    (string (string_fragment) @injection.content))
+  (#match? @_receiver "(?i)migration(s|Manager)?$")
+  (#not-any-of? @_method "execute" "eachRow" "stream")
+  (#match? @injection.content "^\\s*(?i:alter|apply|begin|create|delete|drop|insert|select|truncate|update|use)\\s")
+  (#set! injection.language "cql"))
+
+; Tagged templates: cql`SELECT * FROM users`
+(call_expression
+  function: [
+    (identifier) @_tag
+    (member_expression property: (property_identifier) @_tag)
+  ]
+  arguments: (template_string) @injection.content
+  (#eq? @_tag "cql")
+  (#set! injection.language "cql")
+  (#set! injection.include-children)
+  (#set! injection.combined))
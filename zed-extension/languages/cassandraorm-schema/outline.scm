@@ -0,0 +1,97 @@
+; Models: client.loadSchema('users', { ... })
+(call_expression
+  function: [
+    (identifier) @context
+    (member_expression property: (property_identifier) @context)
+  ]
+  arguments: (arguments
+    .
+    (string (string_fragment) @name))
+  (#eq? @context "loadSchema")) @item
+
+; Schemas declared on their own: const userSchema = { fields: { ... } }
+(variable_declarator
+  name: (identifier) @name
+  value: (object
+    (pair
+      key: (property_identifier) @_fields)
+    (#eq? @_fields "fields"))) @item
+
+; Sections of a schema. `fields` anchors the match so that unrelated objects
+; with a `key` or `indexes` property stay out of the outline.
+(object
+  (pair
+    key: (property_identifier) @name
+    (#eq? @name "fields")) @item)
+
+(object
+  (pair
+    key: (property_identifier) @_fields
+    (#eq? @_fields "fields"))
+  (pair
+    key: (property_identifier) @name
+    (#any-of? @name "key" "clustering_order" "relations" "indexes" "materialized_views")) @item)
+
+; Fields: `email: 'text'` and `email: { type: 'text', ... }`
+(pair
+  key: (property_identifier) @_fields
+  value: (object
+    (pair
+      key: [
+        (property_identifier) @name
+        (string (string_fragment) @name)
+      ]
+      value: (string (string_fragment) @context)) @item)
+  (#eq? @_fields "fields"))
+
+(pair
+  key: (property_identifier) @_fields
+  value: (object
+    (pair
+      key: [
+        (property_identifier) @name
+        (string (string_fragment) @name)
+      ]
+      value: (object
+        (pair
+          key: (property_identifier) @_type
+          value: (string (string_fragment) @context)))) @item)
+  (#eq? @_fields "fields")
+  (#eq? @_type "type"))
+
+; Key parts: 'id', ['id', 'created_at'] and [['tenant', 'id'], 'created_at']
+(object
+  (pair
+    key: (property_identifier) @_fields
+    (#eq? @_fields "fields"))
+  (pair
+    key: (property_identifier) @_key
+    value: [
+      (string (string_fragment) @name) @item
+      (array (string (string_fragment) @name) @item)
+      (array (array (string (string_fragment) @name) @item))
+    ])
+  (#eq? @_key "key"))
+
+; Clustering columns with their order
+(pair
+  key: (property_identifier) @_order
+  value: (object
+    (pair
+      key: [
+        (property_identifier) @name
+        (string (string_fragment) @name)
+      ]
+      value: (string (string_fragment) @context)) @item)
+  (#eq? @_order "clustering_order"))
+
+; Relations, indexes and materialized views by name
+(pair
+  key: (property_identifier) @_section
+  value: (object
+    (pair
+      key: [
+        (property_identifier) @name
+        (string (string_fragment) @name)
+      ]) @item)
+  (#any-of? @_section "relations" "indexes" "materialized_views"))
@@ -0,0 +1,15 @@
+; Run buttons for the CassandraORM CLI. Each tag matches a task in tasks.json,
+; which reads the other captures as $ZED_CUSTOM_<capture>. Migrations get no
+; button until `cassandraorm migrate` can run a single migration.
+
+; Models: client.loadSchema('users', { ... })
+(call_expression
+  function: [
+    (identifier) @run @_function
+    (member_expression property: (property_identifier) @run @_function)
+  ]
+  arguments: (arguments
+    .
+    (string (string_fragment) @model))
+  (#eq? @_function "loadSchema")
+  (#set! tag cassandraorm-generate))
@@ -0,0 +1,31 @@
+; Models are classes (`ac`/`ic`): the `loadSchema` call or the declaration of
+; a standalone schema around, the schema object inside.
+(call_expression
+  function: [
+    (identifier) @_function
+    (member_expression property: (property_identifier) @_function)
+  ]
+  arguments: (arguments
+    .
+    (string)
+    .
+    (object) @class.inside)
+  (#eq? @_function "loadSchema")) @class.around
+
+(lexical_declaration
+  (variable_declarator
+    value: (object
+      (pair
+        key: (property_identifier) @_fields)
+      (#eq? @_fields "fields")) @class.inside)) @class.around
+
+; Fields are functions (`af`/`if`): the whole `name: { type, validate }` pair
+; around, its definition inside.
+(pair
+  key: (property_identifier) @_fields
+  value: (object
+    (pair
+      value: (_) @function.inside) @function.around)
+  (#eq? @_fields "fields"))
+
+(comment)+ @comment.around
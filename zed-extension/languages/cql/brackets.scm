@@ -0,0 +1,7 @@
+("(" @open ")" @close)
+("[" @open "]" @close)
+("{" @open "}" @close)
+(collection_type "<" @open ">" @close)
+(frozen_type "<" @open ">" @close)
+(tuple_type "<" @open ">" @close)
+(vector_type "<" @open ">" @close)
@@ -0,0 +1,126 @@
+; Keywords. Type names such as `counter`, `timestamp`, `set` and `frozen` are
+; also keywords, so they are only highlighted as keywords where they can't be
+; part of a type.
+[
+  (keyword_add) (keyword_aggregate) (keyword_allow) (keyword_alter)
+  (keyword_and) (keyword_apply) (keyword_as) (keyword_asc) (keyword_batch)
+  (keyword_begin) (keyword_by) (keyword_called)
+  (keyword_clustering) (keyword_columnfamily) (keyword_compact)
+  (keyword_contains) (keyword_create) (keyword_custom)
+  (keyword_default) (keyword_delete) (keyword_desc) (keyword_describe)
+  (keyword_distinct) (keyword_drop) (keyword_entries) (keyword_exists)
+  (keyword_filtering) (keyword_finalfunc) (keyword_from)
+  (keyword_full) (keyword_function) (keyword_functions) (keyword_group)
+  (keyword_if) (keyword_in) (keyword_index) (keyword_initcond) (keyword_input)
+  (keyword_insert) (keyword_into) (keyword_is) (keyword_json) (keyword_key)
+  (keyword_keys) (keyword_keyspace) (keyword_keyspaces) (keyword_language)
+  (keyword_like) (keyword_limit)
+  (keyword_materialized) (keyword_not) (keyword_on)
+  (keyword_options) (keyword_or) (keyword_order) (keyword_partition)
+  (keyword_per) (keyword_primary) (keyword_rename) (keyword_replace)
+  (keyword_returns) (keyword_schema) (keyword_select)
+  (keyword_sfunc) (keyword_static) (keyword_storage) (keyword_stype)
+  (keyword_table) (keyword_tables) (keyword_to)
+  (keyword_truncate) (keyword_ttl)
+  (keyword_type) (keyword_types) (keyword_unlogged) (keyword_unset)
+  (keyword_update) (keyword_use) (keyword_using) (keyword_values)
+  (keyword_view) (keyword_where) (keyword_with)
+] @keyword
+
+(batch_statement (keyword_counter) @keyword)
+(using_option (keyword_timestamp) @keyword)
+(update_statement (keyword_set) @keyword)
+(keyword_null) @keyword
+
+; Types
+
+(native_type) @type.builtin
+
+(collection_type
+  [(keyword_list) (keyword_set) (keyword_map)] @type.builtin)
+(frozen_type (keyword_frozen) @type.builtin)
+(tuple_type (keyword_tuple) @type.builtin)
+(vector_type (keyword_vector) @type.builtin)
+
+(user_type
+  (qualified_name
+    keyspace: (identifier) @namespace
+    name: (identifier) @type))
+(user_type
+  (qualified_name
+    !keyspace
+    name: (identifier) @type))
+(custom_type) @type
+
+; Names
+
+(qualified_name
+  keyspace: (identifier) @namespace)
+
+(create_keyspace_statement name: (identifier) @namespace)
+(alter_keyspace_statement name: (identifier) @namespace)
+(drop_keyspace_statement name: (identifier) @namespace)
+(use_statement keyspace: (identifier) @namespace)
+
+(column_definition name: (identifier) @property)
+(field_definition name: (identifier) @property)
+(ordering column: (identifier) @property)
+(index_target column: (identifier) @property)
+(partition_key (identifier) @property)
+(primary_key_definition clustering_column: (identifier) @property)
+(element_access column: (identifier) @property)
+(column_reference (identifier) @property)
+(insert_statement (identifier) @property)
+(rename (identifier) @property)
+(property name: (identifier) @property)
+(map_entry key: (identifier) @property)
+
+(parameter name: (identifier) @variable.parameter)
+(selector alias: (identifier) @variable)
+
+(function_call
+  name: (qualified_name
+    name: (identifier) @function))
+(token_call (keyword_token) @function.builtin)
+(cast_expression (keyword_cast) @function.builtin)
+(create_function_statement
+  name: (qualified_name
+    name: (identifier) @function))
+(create_aggregate_statement
+  name: (qualified_name
+    name: (identifier) @function))
+(create_aggregate_statement
+  state_function: (identifier) @function)
+(create_aggregate_statement
+  final_function: (identifier) @function)
+(create_function_statement language: (identifier) @keyword)
+
+; Literals
+
+(string_literal) @string
+(dollar_string) @string.special
+(integer) @number
+(float) @number
+(duration) @number
+(blob) @number
+(uuid) @constant
+(boolean) @boolean
+(null) @constant.builtin
+(bind_marker) @variable.special
+(interpolation) @embedded
+
+(comment) @comment
+
+; Punctuation
+
+(operator) @operator
+(assignment operator: _ @operator)
+(binary_expression operator: _ @operator)
+"*" @operator
+
+["(" ")" "[" "]" "{" "}"] @punctuation.bracket
+(collection_type ["<" ">"] @punctuation.bracket)
+(frozen_type ["<" ">"] @punctuation.bracket)
+(tuple_type ["<" ">"] @punctuation.bracket)
+(vector_type ["<" ">"] @punctuation.bracket)
+["," "." ";" ":"] @punctuation.delimiter
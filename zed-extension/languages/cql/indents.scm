@@ -0,0 +1,18 @@
+(_ "(" ")" @end) @indent
+(_ "[" "]" @end) @indent
+(_ "{" "}" @end) @indent
+
+; Clauses continue on indented lines until the statement ends.
+[
+  (create_keyspace_statement)
+  (create_table_statement)
+  (create_materialized_view_statement)
+  (create_function_statement)
+  (create_aggregate_statement)
+  (select_statement)
+  (insert_statement)
+  (update_statement)
+  (delete_statement)
+] @indent
+
+(batch_statement (keyword_apply) @end) @indent
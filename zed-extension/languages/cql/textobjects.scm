@@ -0,0 +1,40 @@
+; A single statement is a function (`af`/`if`). Statements have no body of
+; their own, so both objects select the statement without its semicolon.
+[
+  (create_keyspace_statement)
+  (alter_keyspace_statement)
+  (drop_keyspace_statement)
+  (use_statement)
+  (create_table_statement)
+  (alter_table_statement)
+  (drop_table_statement)
+  (truncate_statement)
+  (create_type_statement)
+  (alter_type_statement)
+  (drop_type_statement)
+  (create_index_statement)
+  (drop_index_statement)
+  (create_materialized_view_statement)
+  (alter_materialized_view_statement)
+  (drop_materialized_view_statement)
+  (create_function_statement)
+  (drop_function_statement)
+  (create_aggregate_statement)
+  (drop_aggregate_statement)
+  (describe_statement)
+  (select_statement)
+  (insert_statement)
+  (update_statement)
+  (delete_statement)
+] @function.around @function.inside
+
+; A batch is a class (`ac`/`ic`) whose inside is the statements it applies.
+(batch_statement
+  ([
+    (insert_statement)
+    (update_statement)
+    (delete_statement)
+  ] @class.inside
+  ";"?)+) @class.around
+
+(comment)+ @comment.around
@@ -0,0 +1,494 @@
+//! Checks a parsed model schema for mistakes Cassandra would otherwise only
+//! report when `loadSchema` creates the table against a live cluster.
+//! `/cql-ddl` runs these checks before generating DDL; cassandraorm-lsp
+//! reports the same problems in the editor from its port in `analysis.ts`.
+//!
+//! Every problem is reported on the property that causes it: a key column on
+//! its entry in `key`, an ordering on its name in `clustering_order`, and a
+//! type on the string that spells it.
+//!
+//! Tables with a `counter` column follow Cassandra's counter table rules:
+//! every column outside the primary key is a counter, and counters have no
+//! default, secondary index or TTL.
+//!
+//! User-defined types may be declared where the extension can't see them, so
+//! [`unknown_types`] is kept apart from the problems [`check`] finds: the
+//! editor shows them as warnings and `/cql-ddl` as notes under the DDL.
+
+use crate::cql_type::{self, CqlType};
+use crate::literal::{Error, Spanned};
+use crate::schema::{ClusteringOrder, ModelSchema, PrimaryKey};
+
+/// Returns every problem found in `schema`, in source order.
+pub fn check(schema: &ModelSchema) -> Vec<Error> {
+    let mut errors = Vec::new();
+    let columns = Columns::new(schema, &mut errors);
+    if let Some(key) = &schema.key {
+        check_key(key, &schema.clustering_order, &columns, &mut errors);
+    }
+    for view in &schema.materialized_views {
+        check_key(&view.key, &view.clustering_order, &columns, &mut errors);
+    }
+    check_counters(schema, &columns, &mut errors);
+    errors.sort_by_key(|err| err.span.start);
+    errors
+}
+
+/// Returns the field types that name user-defined types missing from
+/// `user_types`, in source order.
+pub fn unknown_types(schema: &ModelSchema, user_types: &[&str]) -> Vec<Error> {
+    schema
+        .fields
+        .iter()
+        .filter_map(|field| field.parse_type().ok())
+        .flat_map(|ty| cql_type::unknown_user_types(&ty, user_types))
+        .collect()
+}
+
+/// The columns of the model's table, with the types of those declared as
+/// fields.
+struct Columns<'a> {
+    schema: &'a ModelSchema,
+    types: Vec<(&'a str, CqlType)>,
+}
+
+impl<'a> Columns<'a> {
+    /// Parses the field types, reporting those that are invalid.
+    fn new(schema: &'a ModelSchema, errors: &mut Vec<Error>) -> Self {
+        let mut types = Vec::new();
+        for field in &schema.fields {
+            match field.parse_type() {
+                Ok(ty) => {
+                    errors.extend(cql_type::validate(&ty));
+                    types.push((field.name.value.as_str(), ty));
+                }
+                Err(err) => errors.push(err),
+            }
+        }
+        Self { schema, types }
+    }
+
+    fn ty(&self, name: &str) -> Option<&CqlType> {
+        self.types
+            .iter()
+            .find(|(column, _)| *column == name)
+            .map(|(_, ty)| ty)
+    }
+
+    /// Explains why `name` can't be used in a key, if it isn't a stored column.
+    fn missing(&self, name: &str) -> Option<String> {
+        match self.schema.field(name) {
+            Some(field) if !field.is_stored() => Some(format!(
+                "`{name}` is a virtual field, which isn't stored, so it can't be in a key"
+            )),
+            Some(_) => None,
+            None if self
+                .schema
+                .generated_columns()
+                .iter()
+                .any(|(column, _)| column == name) =>
+            {
+                None
+            }
+            None => Some(format!("`{name}` isn't a field of this model")),
+        }
+    }
+}
+
+fn check_key(
+    key: &PrimaryKey,
+    orders: &[ClusteringOrder],
+    columns: &Columns,
+    errors: &mut Vec<Error>,
+) {
+    for (part, key_columns) in [
+        ("partition key", &key.partition),
+        ("clustering", &key.clustering),
+    ] {
+        for column in key_columns {
+            check_key_column(column, part, columns, errors);
+        }
+    }
+
+    for order in orders {
+        let name = &order.column.value;
+        let message = if key.partition.iter().any(|column| column.value == *name) {
+            format!("`{name}` is in the partition key; only clustering columns have an order")
+        } else if key.clustering.iter().any(|column| column.value == *name) {
+            continue;
+        } else if key.clustering.is_empty() {
+            format!("`{name}` isn't a clustering column; the key has no clustering columns")
+        } else {
+            let clustering = key
+                .clustering
+                .iter()
+                .map(|column| format!("`{}`", column.value))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("`{name}` isn't a clustering column; the clustering columns are {clustering}")
+        };
+        errors.push(Error::new(message, order.column.span));
+    }
+}
+
+fn check_key_column(
+    column: &Spanned<String>,
+    part: &str,
+    columns: &Columns,
+    errors: &mut Vec<Error>,
+) {
+    if let Some(message) = columns.missing(&column.value) {
+        errors.push(Error::new(message, column.span));
+        return;
+    }
+    let Some(ty) = columns.ty(&column.value) else {
+        return;
+    };
+    let message = if ty.is_counter() {
+        format!(
+            "`{}` is a counter; {part} columns can't be counters",
+            column.value
+        )
+    } else if ty.is_collection() {
+        format!(
+            "`{}` is a {ty}; {part} columns can't be collections unless frozen, as in `frozen<{ty}>`",
+            column.value
+        )
+    } else {
+        return;
+    };
+    errors.push(Error::new(message, column.span));
+}
+
+/// Reports what a counter table can't have, if the model has counters.
+fn check_counters(schema: &ModelSchema, columns: &Columns, errors: &mut Vec<Error>) {
+    let counters = schema.counter_fields();
+    let Some(first) = counters.first() else {
+        return;
+    };
+    let counter_table = format!("`{}` makes this a counter table", first.name.value);
+    let is_key = |name: &str| {
+        schema
+            .key
+            .iter()
+            .flat_map(PrimaryKey::columns)
+            .any(|column| column.value == name)
+    };
+
+    for field in schema.fields.iter().filter(|field| field.is_stored()) {
+        let name = &field.name.value;
+        let Some(ty) = columns.ty(name).filter(|ty| !ty.is_counter()) else {
+            continue;
+        };
+        if !is_key(name) {
+            errors.push(Error::new(
+                format!(
+                    "`{name}` is a {ty}, but {counter_table}, where every column outside \
+                     the primary key must be a counter"
+                ),
+                field.cql_type.span,
+            ));
+        }
+    }
+    if let Some(options) = &schema.options {
+        let generated = schema.generated_columns();
+        let mut generated = generated.iter();
+        for (option, span, count) in [
+            (
+                "timestamps",
+                options
+                    .timestamps
+                    .as_ref()
+                    .filter(|t| t.enabled)
+                    .map(|t| t.span),
+                2,
+            ),
+            (
+                "versions",
+                options
+                    .versions
+                    .as_ref()
+                    .filter(|v| v.enabled)
+                    .map(|v| v.span),
+                1,
+            ),
+        ] {
+            let Some(span) = span else {
+                continue;
+            };
+            let added = generated
+                .by_ref()
+                .take(count)
+                .map(|(name, ty)| format!("`{name}` {ty}"))
+                .collect::<Vec<_>>()
+                .join(" and ");
+            errors.push(Error::new(
+                format!(
+                    "`{option}` adds {added}, but {counter_table}, which can only add counters"
+                ),
+                span,
+            ));
+        }
+        if let Some(ttl) = options
+            .default_time_to_live
+            .as_ref()
+            .filter(|ttl| ttl.value > 0.0)
+        {
+            errors.push(Error::new(
+                format!("{counter_table}, and counters can't expire, so it can't have a `default_time_to_live`"),
+                ttl.span,
+            ));
+        }
+    }
+
+    for counter in &counters {
+        if let Some(default) = &counter.default {
+            errors.push(Error::new(
+                format!(
+                    "`{}` is a counter, which can't have a default; counters only change by being incremented",
+                    counter.name.value
+                ),
+                default.span,
+            ));
+        }
+    }
+    for target in schema.indexes.iter().flat_map(|index| &index.target) {
+        let column = indexed_column(&target.value);
+        if counters.iter().any(|counter| counter.name.value == column) {
+            errors.push(Error::new(
+                format!("`{column}` is a counter, which can't have a secondary index"),
+                target.span,
+            ));
+        }
+    }
+}
+
+/// The column of an index target, unwrapping `keys(info)` and the like.
+pub fn indexed_column(target: &str) -> &str {
+    target
+        .strip_suffix(')')
+        .and_then(|call| call.split_once('('))
+        .map_or(target, |(_, column)| column)
+        .trim()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::literal::Span;
+    use crate::schema::parse_schema;
+
+    fn diagnostics(source: &str) -> Vec<(String, &str)> {
+        let schema = parse_schema(source, 0).unwrap_or_else(|err| panic!("{err}"));
+        check(&schema)
+            .into_iter()
+            .map(|err| (err.message, text(source, err.span)))
+            .collect()
+    }
+
+    fn text(source: &str, span: Span) -> &str {
+        &source[span.start..span.end]
+    }
+
+    #[test]
+    fn valid_schemas_have_no_diagnostics() {
+        let source = "{
+  fields: { tenant: 'uuid', day: 'date', at: 'timeuuid', tags: 'frozen<set<text>>' },
+  key: [['tenant', 'tags'], 'day', 'at'],
+  clustering_order: { day: 'DESC', at: 'asc' },
+  materialized_views: {
+    by_day: { select: ['*'], key: ['day', 'tenant', 'tags', 'at'], clustering_order: { at: 'desc' } }
+  },
+  options: { timestamps: true }
+}";
+        assert_eq!(diagnostics(source), []);
+
+        let source =
+            "{ fields: { id: 'uuid' }, key: ['createdAt', 'id'], options: { timestamps: true } }";
+        assert_eq!(diagnostics(source), []);
+
+        let source = "{
+  fields: { page: 'text', day: 'date', views: 'counter', label: { type: 'text', virtual: true } },
+  key: ['page', 'day'],
+  options: { timestamps: false, default_time_to_live: 0 }
+}";
+        assert_eq!(diagnostics(source), []);
+    }
+
+    #[test]
+    fn keys_must_name_stored_fields() {
+        let source = "{
+  fields: { id: 'uuid', name: 'text', label: { type: 'text', virtual: true } },
+  key: [['id', 'tenant'], 'label'],
+  materialized_views: { by_name: { select: ['*'], key: ['nme', 'id'] } }
+}";
+        assert_eq!(
+            diagnostics(source),
+            [
+                ("`tenant` isn't a field of this model".to_string(), "tenant"),
+                (
+                    "`label` is a virtual field, which isn't stored, so it can't be in a key"
+                        .to_string(),
+                    "label"
+                ),
+                ("`nme` isn't a field of this model".to_string(), "nme"),
+            ]
+        );
+    }
+
+    #[test]
+    fn clustering_order_only_names_clustering_columns() {
+        let source = "{
+  fields: { tenant: 'uuid', day: 'date', at: 'timeuuid', name: 'text' },
+  key: [['tenant'], 'day', 'at'],
+  clustering_order: { tenant: 'ASC', name: 'DESC', at: 'DESC' },
+  materialized_views: {
+    by_name: { select: ['*'], key: ['name', 'tenant', 'day', 'at'], clustering_order: { name: 'ASC' } },
+    flat: { select: ['*'], key: [['tenant', 'day', 'at']], clustering_order: { name: 'ASC' } }
+  }
+}";
+        assert_eq!(
+            diagnostics(source),
+            [
+                (
+                    "`tenant` is in the partition key; only clustering columns have an order"
+                        .to_string(),
+                    "tenant"
+                ),
+                (
+                    "`name` isn't a clustering column; the clustering columns are `day`, `at`"
+                        .to_string(),
+                    "name"
+                ),
+                (
+                    "`name` is in the partition key; only clustering columns have an order"
+                        .to_string(),
+                    "name"
+                ),
+                (
+                    "`name` isn't a clustering column; the key has no clustering columns"
+                        .to_string(),
+                    "name"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn key_columns_cant_be_collections_or_counters() {
+        let source = "{
+  fields: { tags: { type: 'set', typeDef: '<text>' }, hits: 'counter', at: 'list<int>', id: 'uuid' },
+  key: [['tags', 'hits'], 'at', 'id']
+}";
+        assert_eq!(
+            diagnostics(source),
+            [
+                (
+                    "`tags` is a set<text>; partition key columns can't be collections unless frozen, as in `frozen<set<text>>`"
+                        .to_string(),
+                    "tags"
+                ),
+                (
+                    "`hits` is a counter; partition key columns can't be counters".to_string(),
+                    "hits"
+                ),
+                (
+                    "`at` is a list<int>; clustering columns can't be collections unless frozen, as in `frozen<list<int>>`"
+                        .to_string(),
+                    "at"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_field_types_are_reported_on_the_type() {
+        let source =
+            "{ fields: { id: 'uuid', tags: 'list<lst<int>>', nested: 'map<text, set<int>>', \
+                      home: 'frozen<address>', name: 'txt' }, key: 'id' }";
+        assert_eq!(
+            diagnostics(source),
+            [
+                ("unknown type `lst`".to_string(), "lst"),
+                (
+                    "collections inside collections must be frozen, as in `frozen<set<int>>`"
+                        .to_string(),
+                    "set<int>"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_user_types_are_reported_apart() {
+        let source = "{ fields: { id: 'uuid', home: 'frozen<address>', name: 'txt', \
+                      tags: 'list<lst<int>>' }, key: 'id' }";
+        let schema = parse_schema(source, 0).unwrap();
+        let unknown = unknown_types(&schema, &["address"])
+            .into_iter()
+            .map(|err| text(source, err.span).to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(unknown, ["txt"]);
+        assert_eq!(unknown_types(&schema, &["address", "txt"]), []);
+    }
+
+    #[test]
+    fn counter_tables_only_have_counters_outside_the_key() {
+        let source = "{
+  fields: { page: 'text', views: 'counter', title: 'text', tags: { type: 'set', typeDef: '<text>' }, hits: 'counter' },
+  key: ['page'],
+  options: { timestamps: true, versions: { key: 'rev' } }
+}";
+        let counter_table = "but `views` makes this a counter table";
+        assert_eq!(
+            diagnostics(source),
+            [
+                (
+                    format!("`title` is a text, {counter_table}, where every column outside the primary key must be a counter"),
+                    "text"
+                ),
+                (
+                    format!("`tags` is a set<text>, {counter_table}, where every column outside the primary key must be a counter"),
+                    "set"
+                ),
+                (
+                    format!("`timestamps` adds `createdAt` timestamp and `updatedAt` timestamp, {counter_table}, which can only add counters"),
+                    "true"
+                ),
+                (
+                    format!("`versions` adds `rev` timeuuid, {counter_table}, which can only add counters"),
+                    "{ key: 'rev' }"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn counters_have_no_defaults_indexes_or_ttl() {
+        let source = "{
+  fields: { page: 'text', views: { type: 'counter', default: 0 }, hits: 'counter' },
+  key: ['page'],
+  indexes: ['views', 'page'],
+  options: { default_time_to_live: 86400 }
+}";
+        assert_eq!(
+            diagnostics(source),
+            [
+                (
+                    "`views` is a counter, which can't have a default; counters only change by being incremented"
+                        .to_string(),
+                    "0"
+                ),
+                (
+                    "`views` is a counter, which can't have a secondary index".to_string(),
+                    "views"
+                ),
+                (
+                    "`views` makes this a counter table, and counters can't expire, so it can't have a `default_time_to_live`"
+                        .to_string(),
+                    "86400"
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,215 @@
+// Checks a parsed model schema for mistakes Cassandra would otherwise only
+// report when `loadSchema` creates the table against a live cluster, ported
+// from the extension's `analysis.rs`. The server publishes them as
+// diagnostics.
+//
+// Every problem is reported on the property that causes it: a key column on
+// its entry in `key`, an ordering on its name in `clustering_order`, and a type
+// on the string that spells it.
+//
+// Tables with a `counter` column follow Cassandra's counter table rules: every
+// column outside the primary key is a counter, and counters have no default,
+// secondary index or TTL.
+//
+// User-defined types may be declared where the server can't see them, so
+// `unknownTypes` is kept apart from the problems `check` finds and published
+// as warnings.
+
+import { CqlType, formatType, isCollection, isCounter, unknownUserTypes, validateType } from './cql-type';
+import { SchemaError, Spanned } from './literal';
+import {
+    ClusteringOrder, ModelSchema, PrimaryKey, counterFields, field, generatedColumns, isStored, parseFieldType,
+    primaryKeyColumns
+} from './schema';
+
+// Returns every problem found in `schema`, in source order.
+export function check(schema: ModelSchema): SchemaError[] {
+    const errors: SchemaError[] = [];
+    const columns = new Columns(schema, errors);
+    if (schema.key) {
+        checkKey(schema.key, schema.clusteringOrder, columns, errors);
+    }
+    for (const view of schema.materializedViews) {
+        checkKey(view.key, view.clusteringOrder, columns, errors);
+    }
+    checkCounters(schema, columns, errors);
+    return errors.sort((a, b) => a.span.start - b.span.start);
+}
+
+// Returns the field types that name user-defined types missing from
+// `userTypes`, in source order.
+export function unknownTypes(schema: ModelSchema, userTypes: string[]): SchemaError[] {
+    return schema.fields.flatMap(definition => {
+        try {
+            return unknownUserTypes(parseFieldType(definition), userTypes);
+        } catch (err) {
+            if (!(err instanceof SchemaError)) {
+                throw err;
+            }
+            return [];
+        }
+    });
+}
+
+// The columns of the model's table, with the types of those declared as fields
+class Columns {
+    private schema: ModelSchema;
+    private types = new Map<string, CqlType>();
+
+    // Parses the field types, reporting those that are invalid.
+    constructor(schema: ModelSchema, errors: SchemaError[]) {
+        this.schema = schema;
+        for (const definition of schema.fields) {
+            try {
+                const ty = parseFieldType(definition);
+                errors.push(...validateType(ty));
+                if (!this.types.has(definition.name.value)) {
+                    this.types.set(definition.name.value, ty);
+                }
+            } catch (err) {
+                if (!(err instanceof SchemaError)) {
+                    throw err;
+                }
+                errors.push(err);
+            }
+        }
+    }
+
+    type(name: string): CqlType | undefined {
+        return this.types.get(name);
+    }
+
+    // Explains why `name` can't be used in a key, if it isn't a stored column.
+    missing(name: string): string | undefined {
+        const definition = field(this.schema, name);
+        if (definition) {
+            return isStored(definition)
+                ? undefined
+                : `\`${name}\` is a virtual field, which isn't stored, so it can't be in a key`;
+        }
+        if (generatedColumns(this.schema).some(([column]) => column === name)) {
+            return undefined;
+        }
+        return `\`${name}\` isn't a field of this model`;
+    }
+}
+
+function checkKey(key: PrimaryKey, orders: ClusteringOrder[], columns: Columns, errors: SchemaError[]): void {
+    for (const column of key.partition) {
+        checkKeyColumn(column, 'partition key', columns, errors);
+    }
+    for (const column of key.clustering) {
+        checkKeyColumn(column, 'clustering', columns, errors);
+    }
+
+    for (const order of orders) {
+        const name = order.column.value;
+        let message: string;
+        if (key.partition.some(column => column.value === name)) {
+            message = `\`${name}\` is in the partition key; only clustering columns have an order`;
+        } else if (key.clustering.some(column => column.value === name)) {
+            continue;
+        } else if (key.clustering.length === 0) {
+            message = `\`${name}\` isn't a clustering column; the key has no clustering columns`;
+        } else {
+            const clustering = key.clustering.map(column => `\`${column.value}\``).join(', ');
+            message = `\`${name}\` isn't a clustering column; the clustering columns are ${clustering}`;
+        }
+        errors.push(new SchemaError(message, order.column.span));
+    }
+}
+
+function checkKeyColumn(column: Spanned<string>, part: string, columns: Columns, errors: SchemaError[]): void {
+    const missing = columns.missing(column.value);
+    if (missing) {
+        errors.push(new SchemaError(missing, column.span));
+        return;
+    }
+    const ty = columns.type(column.value);
+    if (!ty) {
+        return;
+    }
+    if (isCounter(ty)) {
+        errors.push(new SchemaError(`\`${column.value}\` is a counter; ${part} columns can't be counters`, column.span));
+    } else if (isCollection(ty)) {
+        const text = formatType(ty);
+        errors.push(new SchemaError(
+            `\`${column.value}\` is a ${text}; ${part} columns can't be collections unless frozen, as in \`frozen<${text}>\``,
+            column.span
+        ));
+    }
+}
+
+// The code of the problems that moving the counters to a companion model fixes
+export const counterTableCode = 'counter-table';
+
+// Reports what a counter table can't have, if the model has counters.
+function checkCounters(schema: ModelSchema, columns: Columns, errors: SchemaError[]): void {
+    const counters = counterFields(schema);
+    if (counters.length === 0) {
+        return;
+    }
+    const counterTable = `\`${counters[0].name.value}\` makes this a counter table`;
+    const keyColumns = schema.key ? primaryKeyColumns(schema.key) : [];
+    const isKey = (name: string) => keyColumns.some(column => column.value === name);
+
+    for (const definition of schema.fields.filter(isStored)) {
+        const name = definition.name.value;
+        const ty = columns.type(name);
+        if (ty && !isCounter(ty) && !isKey(name)) {
+            errors.push(new SchemaError(
+                `\`${name}\` is a ${formatType(ty)}, but ${counterTable}, where every column outside `
+                    + 'the primary key must be a counter',
+                definition.cqlType.span,
+                counterTableCode
+            ));
+        }
+    }
+    const options = schema.options;
+    if (options) {
+        const generated = generatedColumns(schema);
+        for (const [option, setting, count] of [
+            ['timestamps', options.timestamps, 2],
+            ['versions', options.versions, 1]
+        ] as const) {
+            if (!setting?.enabled) {
+                continue;
+            }
+            const added = generated.splice(0, count).map(([name, ty]) => `\`${name}\` ${ty}`).join(' and ');
+            errors.push(new SchemaError(
+                `\`${option}\` adds ${added}, but ${counterTable}, which can only add counters`,
+                setting.span,
+                counterTableCode
+            ));
+        }
+        const ttl = options.defaultTimeToLive;
+        if (ttl && ttl.value > 0) {
+            errors.push(new SchemaError(
+                `${counterTable}, and counters can't expire, so it can't have a \`default_time_to_live\``,
+                ttl.span,
+                counterTableCode
+            ));
+        }
+    }
+
+    for (const counter of counters) {
+        if (counter.default) {
+            errors.push(new SchemaError(
+                `\`${counter.name.value}\` is a counter, which can't have a default; counters only change by being incremented`,
+                counter.default.span
+            ));
+        }
+    }
+    for (const target of schema.indexes.flatMap(index => index.target)) {
+        const column = indexedColumn(target.value);
+        if (counters.some(counter => counter.name.value === column)) {
+            errors.push(new SchemaError(`\`${column}\` is a counter, which can't have a secondary index`, target.span));
+        }
+    }
+}
+
+// The column of an index target, unwrapping `keys(info)` and the like.
+export function indexedColumn(target: string): string {
+    const call = /^[^(]*\((.*)\)$/s.exec(target);
+    return (call ? call[1] : target).trim();
+}
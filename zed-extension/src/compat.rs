@@ -0,0 +1,231 @@
+use serde_json::Value;
+use std::{fmt, ops::Range};
+
+/// The npm package whose installed version decides which server to run.
+pub const ORM_PACKAGE: &str = "cassandraorm-js";
+
+/// The version of this extension.
+pub const EXTENSION_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The `cassandraorm-js` releases the latest `cassandraorm-lsp` understands,
+/// upper bounds exclusive.
+const SUPPORTED_ORM_VERSIONS: &[Range<Version>] = &[Version(1, 0, 0)..Version(2, 0, 0)];
+
+type LockfileParser = fn(&str) -> Option<Version>;
+
+/// Lockfiles in the order they are checked.
+const LOCKFILES: [(&str, LockfileParser); 4] = [
+    ("package-lock.json", from_package_lock),
+    ("pnpm-lock.yaml", from_inline_lock),
+    ("bun.lock", from_inline_lock),
+    ("yarn.lock", from_yarn_lock),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Version(pub u64, pub u64, pub u64);
+
+impl Version {
+    /// Parses `1.2.3`, also accepting the range operators and prerelease
+    /// suffixes found in `package.json` (`^1.2.3`, `~1.2`, `>=1.0.0-beta.1`).
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text
+            .trim()
+            .trim_start_matches(['^', '~', '>', '=', 'v', ' ']);
+        let core = text.split(['-', '+', ' ']).next()?;
+        let mut parts = core.split('.').map(|part| part.parse::<u64>());
+        let major = parts.next()?.ok()?;
+        let minor = parts.next().map_or(Some(0), Result::ok)?;
+        let patch = parts.next().map_or(Some(0), Result::ok)?;
+        Some(Self(major, minor, patch))
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.0, self.1, self.2)
+    }
+}
+
+/// The `cassandraorm-js` version a project uses and where it was read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrmVersion {
+    pub version: Version,
+    pub source: &'static str,
+}
+
+/// What the extension should do for the project's ORM version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ServerChoice {
+    /// Install the latest `cassandraorm-lsp`.
+    Latest,
+    /// The ORM version isn't in the table; run the latest server but warn.
+    Unsupported(String),
+}
+
+/// Finds the installed `cassandraorm-js` version through `read_file`, which
+/// returns the text of a file at the package root if it exists.
+///
+/// Lockfiles are preferred since they record the resolved version; the
+/// `package.json` range is only used as a fallback.
+pub fn installed_orm_version(read_file: impl Fn(&str) -> Option<String>) -> Option<OrmVersion> {
+    for (name, parse) in LOCKFILES {
+        if let Some(version) = read_file(name).as_deref().and_then(parse) {
+            return Some(OrmVersion {
+                version,
+                source: name,
+            });
+        }
+    }
+
+    let package: Value = serde_json::from_str(&read_file("package.json")?).ok()?;
+    let version = if package["name"] == ORM_PACKAGE {
+        package["version"].as_str()
+    } else {
+        ["dependencies", "devDependencies", "peerDependencies"]
+            .iter()
+            .find_map(|section| package[section][ORM_PACKAGE].as_str())
+    };
+    Some(OrmVersion {
+        version: Version::parse(version?)?,
+        source: "package.json",
+    })
+}
+
+/// Checks the ORM version against the versions the server supports.
+pub fn server_choice(orm: &OrmVersion) -> ServerChoice {
+    if SUPPORTED_ORM_VERSIONS
+        .iter()
+        .any(|versions| versions.contains(&orm.version))
+    {
+        ServerChoice::Latest
+    } else {
+        ServerChoice::Unsupported(format!(
+            "{ORM_PACKAGE} {} (from {}) is not supported by the CassandraORM extension {EXTENSION_VERSION}; \
+             completions and diagnostics may not match the installed ORM",
+            orm.version, orm.source
+        ))
+    }
+}
+
+fn from_package_lock(text: &str) -> Option<Version> {
+    let lock: Value = serde_json::from_str(text).ok()?;
+    let version = lock["packages"][format!("node_modules/{ORM_PACKAGE}")]["version"]
+        .as_str()
+        .or_else(|| lock["dependencies"][ORM_PACKAGE]["version"].as_str())?;
+    Version::parse(version)
+}
+
+/// pnpm and bun record resolved packages as `cassandraorm-js@1.2.3`, which
+/// older pnpm lockfiles prefix with `/`.
+fn from_inline_lock(text: &str) -> Option<Version> {
+    text.split(|c: char| c.is_whitespace() || "\"':,()[]{}".contains(c))
+        .find_map(|entry| {
+            let version = entry
+                .strip_prefix('/')
+                .unwrap_or(entry)
+                .strip_prefix(ORM_PACKAGE)?
+                .strip_prefix('@')?;
+            version
+                .starts_with(|c: char| c.is_ascii_digit())
+                .then(|| Version::parse(version))?
+        })
+}
+
+/// yarn records the range in the entry header and the resolved version on a
+/// `version` line below it.
+fn from_yarn_lock(text: &str) -> Option<Version> {
+    let mut in_entry = false;
+    for line in text.lines() {
+        if !line.starts_with(' ') {
+            let header = line.trim_start_matches('"');
+            in_entry = header.starts_with(&format!("{ORM_PACKAGE}@"));
+        } else if in_entry {
+            if let Some(version) = line.trim().strip_prefix("version") {
+                return Version::parse(version.trim_start_matches(':').trim().trim_matches('"'));
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn only(name: &'static str, text: &'static str) -> impl Fn(&str) -> Option<String> {
+        move |file| (file == name).then(|| text.to_string())
+    }
+
+    #[test]
+    fn reads_the_version_from_each_lockfile_format() {
+        let package_lock =
+            r#"{ "packages": { "node_modules/cassandraorm-js": { "version": "1.0.3" } } }"#;
+        let yarn_lock = "\"cassandraorm-js@^1.0.0\":\n  version \"1.2.0\"\n  resolved \"x\"\n";
+        let berry_lock = "\"cassandraorm-js@npm:^1.0.0\":\n  version: 1.4.1\n";
+        let pnpm_lock = "packages:\n\n  cassandraorm-js@1.1.0:\n    resolution: {}\n";
+        let bun_lock =
+            r#"{ "packages": { "cassandraorm-js": ["cassandraorm-js@1.0.5", "", {}] } }"#;
+
+        let cases = [
+            ("package-lock.json", package_lock, Version(1, 0, 3)),
+            ("yarn.lock", yarn_lock, Version(1, 2, 0)),
+            ("yarn.lock", berry_lock, Version(1, 4, 1)),
+            ("pnpm-lock.yaml", pnpm_lock, Version(1, 1, 0)),
+            ("bun.lock", bun_lock, Version(1, 0, 5)),
+        ];
+        for (name, text, expected) in cases {
+            let found = installed_orm_version(only(name, text)).unwrap();
+            assert_eq!(found.version, expected, "{name}");
+            assert_eq!(found.source, name);
+        }
+    }
+
+    #[test]
+    fn lockfile_entries_match_the_exact_package_name() {
+        let pnpm_lock = "packages:\n\n  cassandraorm-js-extra@3.0.0:\n    resolution: {}\n\n  \
+                         /@acme/cassandraorm-js@3.1.0:\n    resolution: {}\n\n  \
+                         /cassandraorm-js@1.1.0(cassandra-driver@4.7.2):\n    resolution: {}\n";
+        let bun_lock = r#"{ "packages": {
+            "my-cassandraorm-js": ["my-cassandraorm-js@3.0.0", "", {}],
+            "cassandraorm-js": ["cassandraorm-js@1.0.5", "", {}]
+        } }"#;
+        let found = installed_orm_version(only("pnpm-lock.yaml", pnpm_lock)).unwrap();
+        assert_eq!(found.version, Version(1, 1, 0));
+        let found = installed_orm_version(only("bun.lock", bun_lock)).unwrap();
+        assert_eq!(found.version, Version(1, 0, 5));
+
+        let others = "packages:\n\n  cassandraorm-js-extra@3.0.0:\n    resolution: {}\n";
+        assert_eq!(installed_orm_version(only("pnpm-lock.yaml", others)), None);
+    }
+
+    #[test]
+    fn falls_back_to_the_package_json_range() {
+        let package = r#"{ "dependencies": { "cassandraorm-js": "^1.0.2" } }"#;
+        let found = installed_orm_version(only("package.json", package)).unwrap();
+        assert_eq!(found.version, Version(1, 0, 2));
+        assert_eq!(found.source, "package.json");
+
+        let own = r#"{ "name": "cassandraorm-js", "version": "1.0.0" }"#;
+        let found = installed_orm_version(only("package.json", own)).unwrap();
+        assert_eq!(server_choice(&found), ServerChoice::Latest);
+
+        assert_eq!(installed_orm_version(|_| None), None);
+    }
+
+    #[test]
+    fn warns_about_versions_outside_the_table() {
+        for version in [Version(2, 1, 0), Version(0, 9, 2)] {
+            let orm = OrmVersion {
+                version,
+                source: "package-lock.json",
+            };
+            let ServerChoice::Unsupported(message) = server_choice(&orm) else {
+                panic!("{version} should be unsupported");
+            };
+            assert!(
+                message.contains(&format!("{version} (from package-lock.json)")),
+                "{message}"
+            );
+        }
+    }
+}
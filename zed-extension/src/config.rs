@@ -0,0 +1,531 @@
+use crate::json;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use zed_extension_api::{self as zed, Result};
+
+/// Project configuration files, looked up in the package folder in this order.
+pub const CONFIG_FILES: [&str; 2] = ["cassandraorm.config.json", "cassandraorm.config.jsonc"];
+
+/// The `package.json` key that may hold the configuration instead.
+const PACKAGE_JSON_KEY: &str = "cassandraorm";
+
+/// A validated project configuration and the file it was read from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProjectConfig {
+    /// The file name, or `package.json#cassandraorm`.
+    pub source: String,
+    pub config: ClientConfig,
+}
+
+/// Mirrors `EnhancedClientConfig` from `src/core/enhanced-client.ts`, whose
+/// `clientOptions` and `ormOptions` are those of `CassandraClientOptions`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ClientConfig {
+    pub client_options: ClientOptions,
+    pub orm_options: Option<OrmOptions>,
+    pub aiml: Option<AimlConfig>,
+    pub performance: Option<PerformanceConfig>,
+    pub distributed: Option<DistributedConfig>,
+}
+
+/// The cassandra-driver `ClientOptions`, passed to the driver as they are.
+/// Only the keys the extension reads are checked; the driver's many others,
+/// such as `protocolOptions`, `pooling` or `sslOptions`, are accepted.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientOptions {
+    pub contact_points: Vec<String>,
+    pub local_data_center: String,
+    pub keyspace: Option<String>,
+    pub credentials: Option<Credentials>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Credentials {
+    pub username: String,
+    pub password: String,
+}
+
+/// The ORM's `ormOptions`. Only the keys the extension reads are checked;
+/// others, such as the express-cassandra `udfs` and `udas`, are accepted.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrmOptions {
+    pub create_keyspace: Option<bool>,
+    pub migration: Option<MigrationMode>,
+    pub default_replication_strategy: Option<ReplicationStrategy>,
+    /// User-defined types by name, with their fields.
+    pub udts: Option<serde_json::Map<String, Value>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MigrationMode {
+    Safe,
+    Alter,
+    Drop,
+}
+
+impl MigrationMode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Safe => "safe",
+            Self::Alter => "alter",
+            Self::Drop => "drop",
+        }
+    }
+}
+
+/// The keyspace `replication` map. Besides `class`, it holds a replication
+/// factor per data center for `NetworkTopologyStrategy`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ReplicationStrategy {
+    pub class: String,
+    pub replication_factor: Option<u32>,
+}
+
+/// Mirrors `AIMLConfig` from `src/ai-ml/real-integration.ts`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AimlConfig {
+    pub openai: Option<OpenAiConfig>,
+    pub vector_db: Option<VectorDbConfig>,
+    pub semantic_cache: Option<SemanticCacheConfig>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct OpenAiConfig {
+    pub api_key: String,
+    pub model: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct VectorDbConfig {
+    pub provider: VectorDbProvider,
+    pub api_key: Option<String>,
+    pub environment: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VectorDbProvider {
+    Pinecone,
+    Weaviate,
+    Cassandra,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SemanticCacheConfig {
+    pub enabled: bool,
+    pub threshold: f64,
+}
+
+/// Mirrors `PerformanceConfig` from `src/performance/advanced-optimization.ts`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct PerformanceConfig {
+    pub query_cache: QueryCacheConfig,
+    pub connection_pool: ConnectionPoolConfig,
+    pub query_optimization: QueryOptimizationConfig,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct QueryCacheConfig {
+    pub enabled: bool,
+    pub max_size: u64,
+    pub ttl: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ConnectionPoolConfig {
+    pub min_connections: u32,
+    pub max_connections: u32,
+    pub acquire_timeout: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct QueryOptimizationConfig {
+    pub enabled: bool,
+    pub analyze_slow_queries: bool,
+    pub slow_query_threshold: u64,
+}
+
+/// Mirrors `DistributedConfig` from `src/distributed/distributed-manager.ts`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DistributedConfig {
+    pub redis: Option<RedisConfig>,
+    pub consul: Option<ConsulConfig>,
+    pub service: Option<ServiceConfig>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct RedisConfig {
+    pub host: Option<String>,
+    pub port: Option<u16>,
+    pub password: Option<String>,
+    pub db: Option<u32>,
+    pub key_prefix: Option<String>,
+    pub ttl: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConsulConfig {
+    pub host: Option<String>,
+    pub port: Option<u16>,
+    pub secure: Option<bool>,
+    pub token: Option<String>,
+    pub datacenter: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ServiceConfig {
+    pub name: String,
+    pub id: Option<String>,
+    pub address: Option<String>,
+    pub port: Option<u16>,
+    pub tags: Option<Vec<String>>,
+}
+
+/// Finds and validates the configuration at the root of the worktree.
+///
+/// `cassandraorm.config.json` and `cassandraorm.config.jsonc` take precedence
+/// over the `cassandraorm` key in `package.json`. Syntax errors, unknown keys
+/// and wrong types are reported with the file name and key path.
+pub fn read_project_config(worktree: &zed::Worktree) -> Result<Option<ProjectConfig>> {
+    discover(|name| worktree.read_text_file(name).ok())
+}
+
+/// Looks the configuration up through `read_file`, which returns the text of
+/// a file in the package folder if it exists.
+pub fn discover(read_file: impl Fn(&str) -> Option<String>) -> Result<Option<ProjectConfig>> {
+    for name in CONFIG_FILES {
+        if let Some(text) = read_file(name) {
+            return parse_config_file(name, &text).map(Some);
+        }
+    }
+
+    let Some(text) = read_file("package.json") else {
+        return Ok(None);
+    };
+    let package: Value =
+        serde_json::from_str(&text).map_err(|err| format!("package.json: {err}"))?;
+    let Some(section) = package.get(PACKAGE_JSON_KEY) else {
+        return Ok(None);
+    };
+    let config = json::from_value(section)
+        .map_err(|err| format!("package.json: {PACKAGE_JSON_KEY}.{err}"))?;
+    Ok(Some(ProjectConfig {
+        source: format!("package.json#{PACKAGE_JSON_KEY}"),
+        config,
+    }))
+}
+
+fn parse_config_file(name: &str, text: &str) -> Result<ProjectConfig> {
+    let value: Value =
+        serde_json::from_str(&json::strip_jsonc(text)).map_err(|err| format!("{name}: {err}"))?;
+    let config = json::from_value(&value).map_err(|err| format!("{name}: {err}"))?;
+    Ok(ProjectConfig {
+        source: name.to_string(),
+        config,
+    })
+}
+
+/// Builds the initialization options sent to `cassandraorm-lsp`.
+///
+/// Only the connection settings the server needs to analyze schemas are taken
+/// from the project config; credentials are never forwarded. A config that
+/// couldn't be read is left out, and its error is passed on as
+/// `project.configError` for the server to show, like a warning that the
+/// project's ORM version isn't supported in `project.compatibilityWarning`.
+/// The options from `lsp.cassandraorm-lsp.initialization_options` are merged
+/// on top, and also passed on as `project.userOptions` for the server to merge
+/// on top of the configs of the packages below `root_path`.
+pub fn initialization_options(
+    root_path: &str,
+    project: &Result<Option<ProjectConfig>>,
+    compatibility_warning: Option<&str>,
+    user: Option<Value>,
+) -> Value {
+    let (project, config_error) = match project {
+        Ok(project) => (project.as_ref(), None),
+        Err(err) => (None, Some(err)),
+    };
+    let client = project.map(|project| &project.config.client_options);
+    let orm = project.and_then(|project| project.config.orm_options.as_ref());
+
+    let mut options = json!({
+        "project": {
+            "root": root_path,
+            "configFile": project.map(|project| &project.source),
+        },
+        "clientOptions": without_nulls(json!({
+            "contactPoints": client.map(|client| &client.contact_points),
+            "localDataCenter": client.map(|client| &client.local_data_center),
+            "keyspace": client.and_then(|client| client.keyspace.as_ref()),
+        })),
+        "ormOptions": without_nulls(json!({
+            "createKeyspace": orm.and_then(|orm| orm.create_keyspace),
+            "migration": orm.and_then(|orm| orm.migration).map(MigrationMode::as_str),
+            "udts": orm.and_then(|orm| orm.udts.as_ref()),
+        })),
+    });
+    if let Some(err) = config_error {
+        options["project"]["configError"] = json!(err);
+    }
+    if let Some(warning) = compatibility_warning {
+        options["project"]["compatibilityWarning"] = json!(warning);
+    }
+    if let Some(user) = user {
+        merge(&mut options, user.clone());
+        options["project"]["userOptions"] = user;
+    }
+    options
+}
+
+/// Builds the `cassandraorm` workspace configuration section.
+///
+/// The keys and defaults match the `cassandraorm.*` settings declared by the
+/// VS Code extension; values from `lsp.cassandraorm-lsp.settings.cassandraorm`
+/// are merged on top.
+pub fn workspace_configuration(user: Option<&Value>) -> Value {
+    let mut section = json!({
+        "enableIntelliSense": true,
+        "dashboardPort": 3000,
+        "autoValidation": true,
+    });
+    if let Some(user) = user.and_then(|settings| settings.get("cassandraorm")) {
+        merge(&mut section, user.clone());
+    }
+    json!({ "cassandraorm": section })
+}
+
+fn without_nulls(mut value: Value) -> Value {
+    if let Value::Object(entries) = &mut value {
+        entries.retain(|_, value| !value.is_null());
+    }
+    value
+}
+
+/// Recursively merges `overrides` into `base`; non-object values replace.
+fn merge(base: &mut Value, overrides: Value) {
+    match (base, overrides) {
+        (Value::Object(base), Value::Object(overrides)) => {
+            for (key, value) in overrides {
+                merge(base.entry(key).or_insert(Value::Null), value);
+            }
+        }
+        (base, overrides) => *base = overrides,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project(config: Value) -> ProjectConfig {
+        ProjectConfig {
+            source: "cassandraorm.config.json".to_string(),
+            config: json::from_value(&config).unwrap(),
+        }
+    }
+
+    #[test]
+    fn forwards_connection_settings_without_credentials() {
+        let project = project(json!({
+            "clientOptions": {
+                "contactPoints": ["127.0.0.1"],
+                "localDataCenter": "datacenter1",
+                "keyspace": "myapp",
+                "credentials": { "username": "cassandra", "password": "secret" }
+            },
+            "ormOptions": { "migration": "safe", "udts": { "address": { "street": "text" } } }
+        }));
+
+        let options = initialization_options("/repo", &Ok(Some(project)), None, None);
+        assert_eq!(
+            options,
+            json!({
+                "project": { "root": "/repo", "configFile": "cassandraorm.config.json" },
+                "clientOptions": {
+                    "contactPoints": ["127.0.0.1"],
+                    "localDataCenter": "datacenter1",
+                    "keyspace": "myapp"
+                },
+                "ormOptions": { "migration": "safe", "udts": { "address": { "street": "text" } } }
+            })
+        );
+    }
+
+    #[test]
+    fn user_settings_override_project_config() {
+        let project = project(json!({
+            "clientOptions": {
+                "contactPoints": ["127.0.0.1"],
+                "localDataCenter": "dc1",
+                "keyspace": "myapp"
+            },
+            "ormOptions": { "migration": "safe" }
+        }));
+        let user = json!({
+            "clientOptions": { "keyspace": "myapp_dev" },
+            "ormOptions": { "migration": "alter" }
+        });
+
+        let options = initialization_options("/repo", &Ok(Some(project)), None, Some(user));
+        assert_eq!(options["clientOptions"]["keyspace"], "myapp_dev");
+        assert_eq!(options["clientOptions"]["localDataCenter"], "dc1");
+        assert_eq!(options["ormOptions"]["migration"], "alter");
+        assert_eq!(
+            options["project"]["userOptions"]["clientOptions"]["keyspace"],
+            "myapp_dev"
+        );
+    }
+
+    #[test]
+    fn problems_are_reported_to_the_server() {
+        let project = Err("cassandraorm.config.json: expected value at line 1".to_string());
+        let options = initialization_options("/repo", &project, Some("unsupported ORM"), None);
+        assert_eq!(
+            options,
+            json!({
+                "project": {
+                    "root": "/repo",
+                    "configFile": null,
+                    "configError": "cassandraorm.config.json: expected value at line 1",
+                    "compatibilityWarning": "unsupported ORM"
+                },
+                "clientOptions": {},
+                "ormOptions": {}
+            })
+        );
+    }
+
+    #[test]
+    fn discovers_config_files_in_order() {
+        let jsonc = r#"{
+            // local cluster
+            "clientOptions": { "contactPoints": ["127.0.0.1"], "localDataCenter": "dc1", },
+        }"#;
+        let package = r#"{
+            "name": "service",
+            "cassandraorm": { "clientOptions": { "contactPoints": [], "localDataCenter": "dc2" } }
+        }"#;
+
+        let found = discover(|name| match name {
+            "cassandraorm.config.jsonc" => Some(jsonc.to_string()),
+            "package.json" => Some(package.to_string()),
+            _ => None,
+        })
+        .unwrap()
+        .unwrap();
+        assert_eq!(found.source, "cassandraorm.config.jsonc");
+        assert_eq!(found.config.client_options.local_data_center, "dc1");
+
+        let found = discover(|name| (name == "package.json").then(|| package.to_string()))
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.source, "package.json#cassandraorm");
+        assert_eq!(found.config.client_options.local_data_center, "dc2");
+
+        assert_eq!(discover(|_| None).unwrap(), None);
+    }
+
+    #[test]
+    fn driver_options_pass_through() {
+        let config = r#"{
+            "clientOptions": {
+                "contactPoints": ["10.0.0.1"],
+                "localDataCenter": "dc1",
+                "protocolOptions": { "port": 9042 },
+                "pooling": { "coreConnectionsPerHost": { "0": 2 } },
+                "socketOptions": { "readTimeout": 12000 },
+                "sslOptions": { "rejectUnauthorized": true },
+                "authProvider": null
+            },
+            "ormOptions": {
+                "defaultReplicationStrategy": { "class": "NetworkTopologyStrategy", "dc1": 3 },
+                "udfs": { "avgState": { "language": "java", "code": "return state;" } }
+            }
+        }"#;
+        let found =
+            discover(|name| (name == "cassandraorm.config.json").then(|| config.to_string()))
+                .unwrap()
+                .unwrap();
+        assert_eq!(found.config.client_options.contact_points, ["10.0.0.1"]);
+
+        let config = r#"{
+            "clientOptions": { "contactPoints": [], "localDataCenter": "dc1" },
+            "ormOptions": { "udfs": {}, "createKeyspace": "yes" }
+        }"#;
+        let err = discover(|name| (name == "cassandraorm.config.json").then(|| config.to_string()))
+            .unwrap_err();
+        assert!(
+            err.starts_with("cassandraorm.config.json: ormOptions.createKeyspace: invalid type"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn reports_unknown_keys_and_wrong_types_with_their_path() {
+        let config = r#"{
+            "clientOptions": { "contactPoints": ["127.0.0.1"], "localDataCenter": "dc1" },
+            "ormOptions": { "migration": "recreate" }
+        }"#;
+        let err = discover(|name| (name == "cassandraorm.config.json").then(|| config.to_string()))
+            .unwrap_err();
+        assert!(
+            err.starts_with(
+                "cassandraorm.config.json: ormOptions.migration: unknown variant `recreate`"
+            ),
+            "{err}"
+        );
+
+        let package = r#"{
+            "cassandraorm": {
+                "clientOptions": { "contactPoints": ["127.0.0.1"], "localDataCenter": "dc1" },
+                "distributed": { "redis": { "port": "6379" } }
+            }
+        }"#;
+        let err =
+            discover(|name| (name == "package.json").then(|| package.to_string())).unwrap_err();
+        assert!(
+            err.starts_with("package.json: cassandraorm.distributed.redis.port: invalid type"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn workspace_configuration_defaults_match_vscode() {
+        assert_eq!(
+            workspace_configuration(None),
+            json!({
+                "cassandraorm": {
+                    "enableIntelliSense": true,
+                    "dashboardPort": 3000,
+                    "autoValidation": true
+                }
+            })
+        );
+
+        let user = json!({
+            "node": { "path": "/usr/bin/node" },
+            "cassandraorm": { "autoValidation": false, "dashboardPort": 4000 }
+        });
+        let configuration = workspace_configuration(Some(&user));
+        assert_eq!(configuration["cassandraorm"]["autoValidation"], false);
+        assert_eq!(configuration["cassandraorm"]["dashboardPort"], 4000);
+        assert_eq!(configuration["cassandraorm"]["enableIntelliSense"], true);
+        assert!(configuration.get("node").is_none());
+    }
+}
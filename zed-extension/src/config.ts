@@ -0,0 +1,353 @@
+// Reads the CassandraORM config of a package below the folder the server was
+// started in, ported from the extension's `config.rs`, which only reads the
+// config of that folder. Configs are validated against the same shapes, so a
+// sub-package's config fails the same way the root's does, and they become
+// initialization options in the same order: the config first, then the
+// user's `lsp.cassandraorm-lsp.initialization_options` on top.
+
+// Project settings sent by the editor extension as initialization options
+export interface ProjectOptions {
+    project?: {
+        root?: string;
+        configFile?: string | null;
+        configError?: string;
+        compatibilityWarning?: string;
+        // The user's initialization options, for the server to apply to the
+        // configs of sub-packages
+        userOptions?: unknown;
+    };
+    clientOptions?: { contactPoints?: string[]; localDataCenter?: string; keyspace?: string };
+    ormOptions?: { createKeyspace?: boolean; migration?: 'safe' | 'alter' | 'drop'; udts?: Record<string, unknown> };
+}
+
+// A validated project configuration and the file it was read from
+export interface ProjectConfig {
+    // The file name, or `package.json#cassandraorm`
+    source: string;
+    config: any;
+}
+
+// Project configuration files, looked up in the package folder in this order
+export const configFiles = ['cassandraorm.config.json', 'cassandraorm.config.jsonc'];
+
+// The `package.json` key that may hold the configuration instead
+const packageJsonKey = 'cassandraorm';
+
+// The shape of a config value. Objects list their fields, which are optional
+// unless `required`; `open` objects accept fields they don't list.
+type Shape =
+    | 'string' | 'bool' | 'number' | 'map'
+    | { integer: number }
+    | { variants: string[] }
+    | { array: Shape }
+    | { fields: Record<string, Shape | { required: Shape }>; open?: boolean };
+
+const u16: Shape = { integer: 0xffff };
+const u32: Shape = { integer: 0xffffffff };
+const u64: Shape = { integer: Number.MAX_SAFE_INTEGER };
+
+// Mirrors `ClientConfig` and the structs below it in `config.rs`.
+const clientConfig: Shape = {
+    fields: {
+        clientOptions: {
+            required: {
+                fields: {
+                    contactPoints: { required: { array: 'string' } },
+                    localDataCenter: { required: 'string' },
+                    keyspace: 'string',
+                    credentials: {
+                        fields: { username: { required: 'string' }, password: { required: 'string' } },
+                        open: true
+                    }
+                },
+                open: true
+            }
+        },
+        ormOptions: {
+            fields: {
+                createKeyspace: 'bool',
+                migration: { variants: ['safe', 'alter', 'drop'] },
+                defaultReplicationStrategy: {
+                    fields: { class: { required: 'string' }, replication_factor: u32 },
+                    open: true
+                },
+                udts: 'map'
+            },
+            open: true
+        },
+        aiml: {
+            fields: {
+                openai: { fields: { apiKey: { required: 'string' }, model: 'string' } },
+                vectorDb: {
+                    fields: {
+                        provider: { required: { variants: ['pinecone', 'weaviate', 'cassandra'] } },
+                        apiKey: 'string',
+                        environment: 'string'
+                    }
+                },
+                semanticCache: { fields: { enabled: { required: 'bool' }, threshold: { required: 'number' } } }
+            }
+        },
+        performance: {
+            fields: {
+                queryCache: {
+                    required: {
+                        fields: { enabled: { required: 'bool' }, maxSize: { required: u64 }, ttl: { required: u64 } }
+                    }
+                },
+                connectionPool: {
+                    required: {
+                        fields: {
+                            minConnections: { required: u32 },
+                            maxConnections: { required: u32 },
+                            acquireTimeout: { required: u64 }
+                        }
+                    }
+                },
+                queryOptimization: {
+                    required: {
+                        fields: {
+                            enabled: { required: 'bool' },
+                            analyzeSlowQueries: { required: 'bool' },
+                            slowQueryThreshold: { required: u64 }
+                        }
+                    }
+                }
+            }
+        },
+        distributed: {
+            fields: {
+                redis: {
+                    fields: { host: 'string', port: u16, password: 'string', db: u32, keyPrefix: 'string', ttl: u64 }
+                },
+                consul: {
+                    fields: { host: 'string', port: u16, secure: 'bool', token: 'string', datacenter: 'string' }
+                },
+                service: {
+                    fields: {
+                        name: { required: 'string' },
+                        id: 'string',
+                        address: 'string',
+                        port: u16,
+                        tags: { array: 'string' }
+                    }
+                }
+            }
+        }
+    }
+};
+
+// Finds and validates the configuration of a package through `readFile`,
+// which returns the text of a file in the package folder if it exists.
+// `cassandraorm.config.json` and `cassandraorm.config.jsonc` take precedence
+// over the `cassandraorm` key in `package.json`. Throws an `Error` naming the
+// file and key path of the first problem.
+export function discover(readFile: (name: string) => string | undefined): ProjectConfig | undefined {
+    for (const name of configFiles) {
+        const text = readFile(name);
+        if (text !== undefined) {
+            return { source: name, config: validated(name, parseJson(name, stripJsonComments(text)), '') };
+        }
+    }
+
+    const text = readFile('package.json');
+    if (text === undefined) {
+        return undefined;
+    }
+    const section = parseJson('package.json', text)?.[packageJsonKey];
+    if (section === undefined) {
+        return undefined;
+    }
+    return { source: `package.json#${packageJsonKey}`, config: validated('package.json', section, packageJsonKey) };
+}
+
+// Whether a package uses CassandraORM: its `package.json` depends on
+// `cassandraorm-js` or it has a CassandraORM config. A config that can't be
+// read still counts, so the problem gets reported.
+export function usesCassandraOrm(readFile: (name: string) => string | undefined): boolean {
+    if (configFiles.some(name => readFile(name) !== undefined)) {
+        return true;
+    }
+    let manifest: any;
+    try {
+        manifest = JSON.parse(readFile('package.json') ?? '{}');
+    } catch {
+        return false;
+    }
+    return manifest?.[packageJsonKey] !== undefined
+        || ['dependencies', 'devDependencies', 'optionalDependencies', 'peerDependencies']
+            .some(section => isObject(manifest?.[section]) && 'cassandraorm-js' in manifest[section]);
+}
+
+function parseJson(name: string, text: string): any {
+    try {
+        return JSON.parse(text);
+    } catch (err) {
+        throw new Error(`${name}: ${(err as Error).message}`);
+    }
+}
+
+function validated(name: string, value: unknown, path: string): unknown {
+    const problem = validate(value, clientConfig, path);
+    if (problem !== undefined) {
+        throw new Error(`${name}: ${problem}`);
+    }
+    return value;
+}
+
+// Describes the first way `value` doesn't match `shape`, prefixed with its key
+// path, such as `clientOptions.contactPoints[1]: invalid type: ...`.
+function validate(value: unknown, shape: Shape, path = ''): string | undefined {
+    const at = (message: string) => (path === '' ? message : `${path}: ${message}`);
+    const invalid = (expected: string) => at(`invalid type: ${describeValue(value)}, expected ${expected}`);
+    const child = (key: string) => (path === '' ? key : `${path}.${key}`);
+
+    if (shape === 'string') {
+        return typeof value === 'string' ? undefined : invalid('a string');
+    }
+    if (shape === 'bool') {
+        return typeof value === 'boolean' ? undefined : invalid('a boolean');
+    }
+    if (shape === 'number') {
+        return typeof value === 'number' ? undefined : invalid('a number');
+    }
+    if (shape === 'map') {
+        return isObject(value) ? undefined : invalid('a map');
+    }
+    if ('integer' in shape) {
+        if (typeof value !== 'number' || !Number.isInteger(value)) {
+            return invalid('an integer');
+        }
+        return value >= 0 && value <= shape.integer
+            ? undefined
+            : at(`invalid value: ${value}, expected an integer from 0 to ${shape.integer}`);
+    }
+    if ('variants' in shape) {
+        if (typeof value !== 'string') {
+            return invalid('a string');
+        }
+        return shape.variants.includes(value)
+            ? undefined
+            : at(`unknown variant \`${value}\`, expected one of ${shape.variants.map(v => `\`${v}\``).join(', ')}`);
+    }
+    if ('array' in shape) {
+        if (!Array.isArray(value)) {
+            return invalid('a sequence');
+        }
+        for (const [i, item] of value.entries()) {
+            const problem = validate(item, shape.array, `${path}[${i}]`);
+            if (problem !== undefined) {
+                return problem;
+            }
+        }
+        return undefined;
+    }
+
+    if (!isObject(value)) {
+        return invalid('a map');
+    }
+    for (const [key, field] of Object.entries(value)) {
+        const fieldShape = shape.fields[key];
+        if (fieldShape === undefined) {
+            if (!shape.open) {
+                const expected = Object.keys(shape.fields).map(name => `\`${name}\``).join(', ');
+                return `${child(key)}: unknown field \`${key}\`, expected one of ${expected}`;
+            }
+            continue;
+        }
+        const required = typeof fieldShape === 'object' && 'required' in fieldShape;
+        // Optional fields may be `null`, like an `Option` on the Rust side.
+        if (field === null && !required) {
+            continue;
+        }
+        const problem = validate(field, required ? fieldShape.required : fieldShape, child(key));
+        if (problem !== undefined) {
+            return problem;
+        }
+    }
+    for (const [key, fieldShape] of Object.entries(shape.fields)) {
+        if (typeof fieldShape === 'object' && 'required' in fieldShape && !(key in value)) {
+            return at(`missing field \`${key}\``);
+        }
+    }
+    return undefined;
+}
+
+function isObject(value: unknown): value is Record<string, unknown> {
+    return typeof value === 'object' && value !== null && !Array.isArray(value);
+}
+
+function describeValue(value: unknown): string {
+    if (value === null) {
+        return 'null';
+    }
+    if (Array.isArray(value)) {
+        return 'sequence';
+    }
+    switch (typeof value) {
+        case 'string': return `string ${JSON.stringify(value)}`;
+        case 'number': return `${Number.isInteger(value) ? 'integer' : 'floating point'} \`${value}\``;
+        case 'boolean': return `boolean \`${value}\``;
+        default: return 'map';
+    }
+}
+
+// Builds the options for a package from its config, like
+// `initialization_options` in `config.rs`: only the connection settings the
+// server needs are taken from the config, a config that couldn't be read is
+// left out and reported as `project.configError`, and `user` is merged on top.
+export function packageOptions(
+    root: string,
+    project: ProjectConfig | Error | undefined,
+    user: unknown
+): ProjectOptions {
+    const config = project instanceof Error ? undefined : project?.config;
+    const client = config?.clientOptions;
+    const orm = config?.ormOptions;
+    const options: ProjectOptions = {
+        project: {
+            root,
+            configFile: project instanceof Error ? null : project?.source ?? null
+        },
+        clientOptions: withoutNulls({
+            contactPoints: client?.contactPoints,
+            localDataCenter: client?.localDataCenter,
+            keyspace: client?.keyspace
+        }),
+        ormOptions: withoutNulls({
+            createKeyspace: orm?.createKeyspace,
+            migration: orm?.migration,
+            udts: orm?.udts
+        })
+    };
+    if (project instanceof Error) {
+        options.project!.configError = project.message;
+    }
+    return merge(options, user) as ProjectOptions;
+}
+
+function withoutNulls<T extends object>(value: T): T {
+    return Object.fromEntries(Object.entries(value).filter(([, v]) => v !== undefined && v !== null)) as T;
+}
+
+// Recursively merges `overrides` into `base`; non-object values replace.
+export function merge(base: unknown, overrides: unknown): unknown {
+    if (overrides === undefined) {
+        return base;
+    }
+    if (!isObject(base) || !isObject(overrides)) {
+        return overrides;
+    }
+    const merged: Record<string, unknown> = { ...base };
+    for (const [key, value] of Object.entries(overrides)) {
+        merged[key] = merge(merged[key], value);
+    }
+    return merged;
+}
+
+// Blanks out comments and trailing commas outside strings
+function stripJsonComments(text: string): string {
+    return text
+        .replace(/("(?:[^"\\]|\\.)*")|\/\/[^\n]*|\/\*[\s\S]*?\*\//g, (match, string) => string ?? ' ')
+        .replace(/("(?:[^"\\]|\\.)*")|,(\s*[}\]])/g, (match, string, close) => string ?? close);
+}
@@ -0,0 +1,428 @@
+//! The `/cql-counters <model> [file]` slash command, which moves a model's
+//! counters out to a companion `<table>_counters` model.
+//!
+//! Cassandra only allows counters in tables where every other column is part
+//! of the primary key, so a model mixing counters with other columns has to
+//! be split: the companion model repeats the primary key and takes the
+//! counters, which are then removed from the original model along with the
+//! indexes on them. cassandraorm-lsp makes the same split as a quick fix.
+
+use crate::analysis;
+use crate::ddl;
+use crate::literal::{Error, Span, Spanned};
+use crate::schema::{Declaration, ModelSchema, Order};
+use zed_extension_api::{SlashCommandOutput, SlashCommandOutputSection};
+
+/// Runs `/cql-counters <model> [file]`, finding the model the same way
+/// `/cql-ddl` does. The output has the original schema without its counters,
+/// then the companion model.
+pub fn run_slash_command(
+    args: &[String],
+    read_file: impl Fn(&str) -> Option<String>,
+) -> Result<SlashCommandOutput, String> {
+    let found = ddl::find_model("cql-counters", args, read_file)?;
+    let schema = found.schema()?;
+    let table = schema.table_name(&found.model.name.value);
+    let companion = companion_model(table, schema, &found.model.declaration, &found.source)
+        .map_err(|err| found.locate(err))?;
+    let trimmed = trimmed_schema(schema, &found.source);
+
+    let counters = schema
+        .counter_fields()
+        .iter()
+        .map(|field| format!("`{}`", field.name.value))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let mut text = format!(
+        "Move {counters} from `{table}` in {} to a `{table}_counters` model with the same \
+         primary key. Replace the schema of `{table}` with\n\n```ts\n",
+        found.path
+    );
+    let mut sections = Vec::new();
+    for (label, code, next) in [
+        (table.to_string(), trimmed, "\n```\n\nand add\n\n```ts\n"),
+        (format!("{table}_counters"), companion, "\n```\n"),
+    ] {
+        let start = text.len();
+        text.push_str(&code);
+        sections.push(SlashCommandOutputSection {
+            range: (start..text.len()).into(),
+            label,
+        });
+        text.push_str(next);
+    }
+    Ok(SlashCommandOutput { text, sections })
+}
+
+/// Declares the `<table>_counters` model holding the counters of `schema`,
+/// keyed like it, the way the original model is declared: with a `loadSchema`
+/// call on the same receiver, or as an exported schema object. Key fields keep
+/// their definitions from `source`.
+pub fn companion_model(
+    table: &str,
+    schema: &ModelSchema,
+    declaration: &Declaration,
+    source: &str,
+) -> Result<String, Error> {
+    let key = schema
+        .key
+        .as_ref()
+        .ok_or_else(|| Error::new("missing `key`", schema.span))?;
+    let counters = schema.counter_fields();
+    if counters.is_empty() {
+        return Err(Error::new(
+            format!("`{table}` has no counters to move"),
+            schema.span,
+        ));
+    }
+    let is_key = |name: &str| key.columns().any(|column| column.value == name);
+    let generated = schema.generated_columns();
+    let mixed =
+        schema.fields.iter().any(|field| {
+            field.is_stored() && !is_key(&field.name.value) && !counters.contains(&field)
+        }) || generated.iter().any(|(name, _)| !is_key(name));
+    if !mixed {
+        return Err(Error::new(
+            format!("`{table}` only has counters outside its primary key, so it can keep them"),
+            schema.span,
+        ));
+    }
+
+    let mut fields = Vec::new();
+    for column in key.columns() {
+        if let Some(field) = schema.field(&column.value) {
+            fields.push(source[field.span.start..field.span.end].to_string());
+        } else if let Some((name, ty)) = generated.iter().find(|(name, _)| *name == column.value) {
+            fields.push(format!("{}: '{ty}'", property_name(name)));
+        }
+    }
+    for counter in &counters {
+        if !is_key(&counter.name.value) {
+            fields.push(format!("{}: 'counter'", property_name(&counter.name.value)));
+        }
+    }
+
+    let mut text = format!(
+        "{{\n  fields: {{\n    {}\n  }},\n  key: {}",
+        fields.join(",\n    "),
+        &source[key.span.start..key.span.end]
+    );
+    if !schema.clustering_order.is_empty() {
+        let orders = schema
+            .clustering_order
+            .iter()
+            .map(|order| {
+                let direction = match order.order.value {
+                    Order::Asc => "ASC",
+                    Order::Desc => "DESC",
+                };
+                format!("{}: '{direction}'", property_name(&order.column.value))
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        text.push_str(&format!(",\n  clustering_order: {{ {orders} }}"));
+    }
+
+    // The head of the original declaration, with the variable renamed
+    let head = |head: Span, binding: Option<&Spanned<String>>| match binding {
+        Some(binding) => format!(
+            "{}{}{}",
+            &source[head.start..binding.span.start],
+            counters_binding(&binding.value),
+            &source[binding.span.end..head.end]
+        ),
+        None => source[head.start..head.end].to_string(),
+    };
+    Ok(match declaration {
+        Declaration::LoadSchema {
+            head: span,
+            binding,
+        } => format!(
+            "{}loadSchema('{table}_counters', {text}\n}});",
+            head(*span, binding.as_ref())
+        ),
+        // Nothing else names the table of the new schema object.
+        Declaration::Export {
+            head: span,
+            binding,
+        } => format!(
+            "{}{text},\n  table_name: '{table}_counters'\n}};",
+            head(*span, Some(binding))
+        ),
+    })
+}
+
+/// The schema object of the model as written in `source`, without the
+/// counters [`companion_model`] moves out.
+pub fn trimmed_schema(schema: &ModelSchema, source: &str) -> String {
+    let mut text = String::new();
+    let mut pos = schema.span.start;
+    for span in moved_spans(schema, source) {
+        text.push_str(&source[pos..span.start]);
+        pos = span.end;
+    }
+    text.push_str(&source[pos..schema.span.end]);
+    text
+}
+
+/// The spans of `source` to delete when the counters outside the key move to
+/// the companion model: their fields, and the indexes on nothing but them.
+pub fn moved_spans(schema: &ModelSchema, source: &str) -> Vec<Span> {
+    let is_key = |name: &str| {
+        schema
+            .key
+            .iter()
+            .flat_map(|key| key.columns())
+            .any(|column| column.value == name)
+    };
+    let moved = schema
+        .counter_fields()
+        .into_iter()
+        .filter(|field| !is_key(&field.name.value))
+        .map(|field| field.name.value.as_str())
+        .collect::<Vec<_>>();
+
+    let fields = schema
+        .fields
+        .iter()
+        .map(|field| field.span)
+        .collect::<Vec<_>>();
+    let kept = schema
+        .fields
+        .iter()
+        .map(|field| !moved.contains(&field.name.value.as_str()))
+        .collect::<Vec<_>>();
+    let mut spans = deletions(&fields, &kept, source);
+
+    let indexes = schema
+        .indexes
+        .iter()
+        .map(|index| index.span)
+        .collect::<Vec<_>>();
+    let kept = schema
+        .indexes
+        .iter()
+        .map(|index| {
+            !index
+                .target
+                .iter()
+                .all(|target| moved.contains(&analysis::indexed_column(&target.value)))
+        })
+        .collect::<Vec<_>>();
+    spans.extend(deletions(&indexes, &kept, source));
+    spans.sort_by_key(|span| span.start);
+    spans
+}
+
+/// The spans to delete from a comma-separated list so that only the `kept`
+/// items remain. An item on a line of its own takes the line with it, and
+/// trailing items take the comma before them.
+fn deletions(items: &[Span], kept: &[bool], source: &str) -> Vec<Span> {
+    let line_start = |span: Span| {
+        let line = source[..span.start].rfind('\n').map_or(0, |ix| ix + 1);
+        match source[line..span.start].trim().is_empty() {
+            true => line,
+            false => span.start,
+        }
+    };
+    let trailing = kept.iter().rposition(|kept| *kept).map_or(0, |ix| ix + 1);
+    let mut spans = (0..trailing)
+        .filter(|&ix| !kept[ix])
+        .map(|ix| Span::new(line_start(items[ix]), line_start(items[ix + 1])))
+        .collect::<Vec<_>>();
+    if let Some(last) = items.get(trailing..).and_then(<[Span]>::last) {
+        let start = match trailing {
+            0 => items[0].start,
+            _ => items[trailing - 1].end,
+        };
+        spans.push(Span::new(start, last.end));
+    }
+    spans
+}
+
+/// Quotes a property name unless it's a JavaScript identifier.
+fn property_name(name: &str) -> String {
+    let identifier = name
+        .chars()
+        .next()
+        .is_some_and(|first| !first.is_ascii_digit())
+        && name
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '$');
+    if identifier {
+        name.to_string()
+    } else {
+        format!("'{}'", name.replace('\'', "\\'"))
+    }
+}
+
+/// `Pages` becomes `PagesCounters`, and `pageSchema` `pageCountersSchema`.
+fn counters_binding(binding: &str) -> String {
+    match binding.strip_suffix("Schema") {
+        Some(stem) if !stem.is_empty() => format!("{stem}CountersSchema"),
+        _ => format!("{binding}Counters"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema;
+
+    const PAGES: &str = r#"
+export const Pages = await client.loadSchema('pages', {
+  fields: {
+    site: 'text',
+    path: { type: 'text', rule: { required: true } },
+    title: 'text',
+    views: { type: 'counter', default: 0 },
+    shares: 'counter'
+  },
+  key: [['site'], 'path'],
+  clustering_order: { path: 'asc' },
+  indexes: ['views']
+});
+"#;
+
+    fn companion(source: &str) -> Result<String, String> {
+        let model = schema::find_models(source).remove(0);
+        let schema = model.schema.unwrap();
+        companion_model(
+            schema.table_name(&model.name.value),
+            &schema,
+            &model.declaration,
+            source,
+        )
+        .map_err(|err| err.message)
+    }
+
+    #[test]
+    fn counters_move_to_a_model_with_the_same_key() {
+        assert_eq!(
+            companion(PAGES).unwrap(),
+            "export const PagesCounters = await client.loadSchema('pages_counters', {
+  fields: {
+    site: 'text',
+    path: { type: 'text', rule: { required: true } },
+    views: 'counter',
+    shares: 'counter'
+  },
+  key: [['site'], 'path'],
+  clustering_order: { path: 'ASC' }
+});"
+        );
+
+        let source = "const stats = this.orm.loadSchema('page_stats', { fields: { id: 'uuid', \
+                      hits: 'counter' }, key: ['id', 'createdAt'], options: { timestamps: true } })";
+        assert_eq!(
+            companion(source).unwrap(),
+            "const statsCounters = this.orm.loadSchema('page_stats_counters', {
+  fields: {
+    id: 'uuid',
+    createdAt: 'timestamp',
+    hits: 'counter'
+  },
+  key: ['id', 'createdAt']
+});"
+        );
+    }
+
+    #[test]
+    fn companion_models_are_declared_like_the_original() {
+        let source = "await loadSchema('pages', { fields: { id: 'uuid', title: 'text', \
+                      views: 'counter' }, key: 'id' });";
+        assert!(companion(source)
+            .unwrap()
+            .starts_with("await loadSchema('pages_counters', {\n"));
+
+        let source = "export const pageSchema: ModelSchema = {
+  fields: { id: 'uuid', title: 'text', views: 'counter' },
+  key: 'id',
+  table_name: 'pages'
+};";
+        assert_eq!(
+            companion(source).unwrap(),
+            "export const pageCountersSchema: ModelSchema = {
+  fields: {
+    id: 'uuid',
+    views: 'counter'
+  },
+  key: 'id',
+  table_name: 'pages_counters'
+};"
+        );
+    }
+
+    #[test]
+    fn only_models_mixing_counters_are_split() {
+        assert_eq!(
+            companion(
+                "loadSchema('pages', { fields: { id: 'uuid', title: 'text' }, key: ['id'] })"
+            ),
+            Err("`pages` has no counters to move".to_string())
+        );
+        assert_eq!(
+            companion(
+                "loadSchema('pages', { fields: { id: 'uuid', views: 'counter' }, key: ['id'] })"
+            ),
+            Err(
+                "`pages` only has counters outside its primary key, so it can keep them"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn counters_and_their_indexes_leave_the_original_model() {
+        let model = schema::find_models(PAGES).remove(0);
+        assert_eq!(
+            trimmed_schema(&model.schema.unwrap(), PAGES),
+            "{
+  fields: {
+    site: 'text',
+    path: { type: 'text', rule: { required: true } },
+    title: 'text'
+  },
+  key: [['site'], 'path'],
+  clustering_order: { path: 'asc' },
+  indexes: []
+}"
+        );
+
+        let source =
+            "loadSchema('t', { fields: { id: 'uuid', hits: 'counter', name: 'text' }, key: 'id', \
+                      indexes: { by_hits: { target: 'hits' }, by_name: { target: 'name' } } })";
+        let model = schema::find_models(source).remove(0);
+        assert_eq!(
+            trimmed_schema(&model.schema.unwrap(), source),
+            "{ fields: { id: 'uuid', name: 'text' }, key: 'id', \
+             indexes: { by_name: { target: 'name' } } }"
+        );
+    }
+
+    #[test]
+    fn the_slash_command_shows_both_models() {
+        let read_file = |path: &str| (path == "src/models/page.ts").then(|| PAGES.to_string());
+        let output = run_slash_command(&["pages".to_string()], read_file).unwrap();
+        assert!(output.text.starts_with(
+            "Move `views`, `shares` from `pages` in src/models/page.ts to a `pages_counters` model \
+             with the same primary key. Replace the schema of `pages` with\n\n```ts\n{\n  fields"
+        ));
+        assert!(output
+            .text
+            .contains("indexes: []\n}\n```\n\nand add\n\n```ts\nexport const PagesCounters"));
+        assert!(output.text.ends_with("});\n```\n"));
+        let labels = output
+            .sections
+            .iter()
+            .map(|section| section.label.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(labels, ["pages", "pages_counters"]);
+        assert!(output.text[output.sections[0].range.start as usize..].starts_with('{'));
+
+        assert_eq!(
+            run_slash_command(&[], read_file).err(),
+            Some("usage: /cql-counters <model> [file]".to_string())
+        );
+    }
+}
@@ -0,0 +1,137 @@
+// Splits a model mixing counters with other columns, ported from the
+// extension's `counters.rs` for the server's quick fix.
+//
+// Cassandra only allows counters in tables where every other column is part of
+// the primary key, so the companion `<table>_counters` model repeats the
+// primary key and takes the counters, which are then removed from the original
+// model along with the indexes on them.
+
+import { indexedColumn } from './analysis';
+import { SchemaError, Span, Spanned, span } from './literal';
+import { Declaration, ModelSchema, counterFields, field, generatedColumns, isStored, primaryKeyColumns } from './schema';
+
+// Declares the `<table>_counters` model holding the counters of `schema`,
+// keyed like it, the way the original model is declared: with a `loadSchema`
+// call on the same receiver, or as an exported schema object. Key fields keep
+// their definitions from `source`.
+export function companionModel(
+    table: string,
+    schema: ModelSchema,
+    declaration: Declaration,
+    source: string
+): string {
+    const key = schema.key;
+    if (!key) {
+        throw new SchemaError('missing `key`', schema.span);
+    }
+    const counters = counterFields(schema);
+    if (counters.length === 0) {
+        throw new SchemaError(`\`${table}\` has no counters to move`, schema.span);
+    }
+    const isKey = (name: string) => primaryKeyColumns(key).some(column => column.value === name);
+    const generated = generatedColumns(schema);
+    const mixed = schema.fields.some(f => isStored(f) && !isKey(f.name.value) && !counters.includes(f))
+        || generated.some(([name]) => !isKey(name));
+    if (!mixed) {
+        throw new SchemaError(
+            `\`${table}\` only has counters outside its primary key, so it can keep them`,
+            schema.span
+        );
+    }
+
+    const fields: string[] = [];
+    for (const column of primaryKeyColumns(key)) {
+        const definition = field(schema, column.value);
+        const generatedColumn = generated.find(([name]) => name === column.value);
+        if (definition) {
+            fields.push(source.slice(definition.span.start, definition.span.end));
+        } else if (generatedColumn) {
+            fields.push(`${propertyName(generatedColumn[0])}: '${generatedColumn[1]}'`);
+        }
+    }
+    for (const counter of counters) {
+        if (!isKey(counter.name.value)) {
+            fields.push(`${propertyName(counter.name.value)}: 'counter'`);
+        }
+    }
+
+    let text = `{\n  fields: {\n    ${fields.join(',\n    ')}\n  },\n`
+        + `  key: ${source.slice(key.span.start, key.span.end)}`;
+    if (schema.clusteringOrder.length > 0) {
+        const orders = schema.clusteringOrder
+            .map(order => `${propertyName(order.column.value)}: '${order.order.value.toUpperCase()}'`)
+            .join(', ');
+        text += `,\n  clustering_order: { ${orders} }`;
+    }
+
+    // The head of the original declaration, with the variable renamed
+    const head = (binding: Spanned<string> | undefined) => {
+        const { start, end } = declaration.head;
+        if (!binding) {
+            return source.slice(start, end);
+        }
+        return source.slice(start, binding.span.start) + countersBinding(binding.value)
+            + source.slice(binding.span.end, end);
+    };
+    if (declaration.kind === 'loadSchema') {
+        return `${head(declaration.binding)}loadSchema('${table}_counters', ${text}\n});`;
+    }
+    // Nothing else names the table of the new schema object.
+    return `${head(declaration.binding)}${text},\n  table_name: '${table}_counters'\n};`;
+}
+
+// The spans of `source` to delete when the counters outside the key move to
+// the companion model: their fields, and the indexes on nothing but them.
+export function movedSpans(schema: ModelSchema, source: string): Span[] {
+    const keyColumns = schema.key ? primaryKeyColumns(schema.key) : [];
+    const moved = counterFields(schema)
+        .map(counter => counter.name.value)
+        .filter(name => !keyColumns.some(column => column.value === name));
+
+    const spans = [
+        ...deletions(
+            schema.fields.map(f => f.span),
+            schema.fields.map(f => !moved.includes(f.name.value)),
+            source
+        ),
+        ...deletions(
+            schema.indexes.map(index => index.span),
+            schema.indexes.map(index => !index.target.every(target => moved.includes(indexedColumn(target.value)))),
+            source
+        )
+    ];
+    return spans.sort((a, b) => a.start - b.start);
+}
+
+// The spans to delete from a comma-separated list so that only the `kept`
+// items remain. An item on a line of its own takes the line with it, and
+// trailing items take the comma before them.
+function deletions(items: Span[], kept: boolean[], source: string): Span[] {
+    const lineStart = (item: Span) => {
+        const line = source.lastIndexOf('\n', item.start - 1) + 1;
+        return source.slice(line, item.start).trim() === '' ? line : item.start;
+    };
+    const trailing = kept.lastIndexOf(true) + 1;
+    const spans: Span[] = [];
+    for (let i = 0; i < trailing; i++) {
+        if (!kept[i]) {
+            spans.push(span(lineStart(items[i]), lineStart(items[i + 1])));
+        }
+    }
+    if (trailing < items.length) {
+        const start = trailing === 0 ? items[0].start : items[trailing - 1].end;
+        spans.push(span(start, items[items.length - 1].end));
+    }
+    return spans;
+}
+
+// Quotes a property name unless it's a JavaScript identifier.
+function propertyName(name: string): string {
+    return /^[A-Za-z_$][A-Za-z0-9_$]*$/.test(name) ? name : `'${name.replace(/'/g, "\\'")}'`;
+}
+
+// `Pages` becomes `PagesCounters`, and `pageSchema` `pageCountersSchema`.
+function countersBinding(binding: string): string {
+    const stem = binding.endsWith('Schema') ? binding.slice(0, -'Schema'.length) : '';
+    return stem ? `${stem}CountersSchema` : `${binding}Counters`;
+}
@@ -0,0 +1,397 @@
+// CQL type expressions such as `frozen<map<text, list<int>>>`, ported from the
+// extension's `cql_type.rs`.
+//
+// `parseType` accepts the whole type grammar of CQL: native types,
+// collections, tuples, `vector<float, 1536>`, references to user-defined types
+// (optionally keyspace-qualified or quoted) and quoted custom types. Keywords
+// are case-insensitive and aliases are normalized, so `VARCHAR` parses as
+// `text`. `validateType` then applies the rules Cassandra checks when a column
+// is created, such as freezing collections nested in other collections, and
+// `unknownUserTypes` finds names missing from the known user-defined types.
+
+import { SchemaError, Span, span } from './literal';
+
+// Native types, in their canonical spelling
+const nativeTypes = [
+    'ascii', 'bigint', 'blob', 'boolean', 'counter', 'date', 'decimal', 'double', 'duration', 'float',
+    'inet', 'int', 'smallint', 'text', 'time', 'timestamp', 'timeuuid', 'tinyint', 'uuid', 'varint'
+];
+
+// Other names for native types. `json` is the ORM's own type, which it stores
+// as text.
+const aliases: Record<string, string> = { varchar: 'text', json: 'text' };
+
+// Types that take type arguments, with an example for error messages
+const genericTypes: Record<string, string> = {
+    list: 'list<text>',
+    set: 'set<text>',
+    map: 'map<text, int>',
+    tuple: 'tuple<int, text>',
+    frozen: 'frozen<list<text>>',
+    vector: 'vector<float, 3>'
+};
+
+export type CqlType = { span: Span } & (
+    | { kind: 'native'; name: string }
+    | { kind: 'list' | 'set' | 'frozen'; element: CqlType }
+    | { kind: 'map'; key: CqlType; value: CqlType }
+    | { kind: 'tuple'; items: CqlType[] }
+    | { kind: 'vector'; element: CqlType; dimension: number }
+    // Unquoted names are lowercased, as Cassandra does.
+    | { kind: 'udt'; keyspace?: string; name: string }
+    // A custom type given by its Java class name, such as `'org.example.T'`
+    | { kind: 'custom'; className: string }
+);
+
+// Whether this is a `list`, `set` or `map` that isn't frozen
+export function isCollection(ty: CqlType): boolean {
+    return ty.kind === 'list' || ty.kind === 'set' || ty.kind === 'map';
+}
+
+export function isCounter(ty: CqlType): boolean {
+    return ty.kind === 'native' && ty.name === 'counter';
+}
+
+export function formatType(ty: CqlType): string {
+    switch (ty.kind) {
+        case 'native': return ty.name;
+        case 'list':
+        case 'set':
+        case 'frozen':
+            return `${ty.kind}<${formatType(ty.element)}>`;
+        case 'map': return `map<${formatType(ty.key)}, ${formatType(ty.value)}>`;
+        case 'tuple': return `tuple<${ty.items.map(formatType).join(', ')}>`;
+        case 'vector': return `vector<${formatType(ty.element)}, ${ty.dimension}>`;
+        case 'udt': return ty.keyspace === undefined ? name(ty.name) : `${name(ty.keyspace)}.${name(ty.name)}`;
+        case 'custom': return `'${ty.className.replace(/'/g, "''")}'`;
+    }
+}
+
+// Quotes a name unless it reads the same unquoted.
+function name(text: string): string {
+    const plain = /^[a-z][a-z0-9_]*$/.test(text);
+    return plain && !nativeType(text) && !(text in genericTypes) ? text : `"${text.replace(/"/g, '""')}"`;
+}
+
+function nativeType(text: string): string | undefined {
+    return nativeTypes.includes(text) ? text : aliases[text];
+}
+
+// Parses a type written across several strings, such as the ORM's
+// `{ type: 'map', typeDef: '<text, int>' }`. Each piece is given with the span
+// of its contents in the document, and spans point into the piece they came
+// from. A piece longer in the document than its text had escapes, which
+// unescaping always shortens; its offsets can't be mapped back, so spans
+// inside it cover the whole piece.
+export function parseType(pieces: [text: string, span: Span][]): CqlType {
+    const parser = new Parser(pieces);
+    const ty = parser.ty();
+    parser.skipWhitespace();
+    const c = parser.peek();
+    if (c !== undefined) {
+        throw parser.error(`unexpected \`${c}\``, parser.pos, parser.pos + 1);
+    }
+    return ty;
+}
+
+class Parser {
+    private text: string;
+    private pieces: [string, Span][];
+    pos = 0;
+
+    constructor(pieces: [string, Span][]) {
+        this.text = pieces.map(([text]) => text).join('');
+        this.pieces = pieces;
+    }
+
+    peek(): string | undefined {
+        return this.text[this.pos];
+    }
+
+    skipWhitespace(): void {
+        while (/\s/.test(this.peek() ?? '')) {
+            this.pos++;
+        }
+    }
+
+    private eat(c: string): boolean {
+        this.skipWhitespace();
+        const found = this.peek() === c;
+        if (found) {
+            this.pos++;
+        }
+        return found;
+    }
+
+    // Maps a range of the joined text back to the document.
+    private span(start: number, end: number): Span {
+        const locate = (pos: number, isEnd: boolean) => {
+            let base = 0;
+            for (let i = 0; i < this.pieces.length; i++) {
+                const [text, piece] = this.pieces[i];
+                const pieceEnd = base + text.length;
+                if (pos < pieceEnd || (isEnd && pos === pieceEnd) || i + 1 === this.pieces.length) {
+                    if (piece.end - piece.start === text.length) {
+                        return piece.start + pos - base;
+                    }
+                    return isEnd ? piece.end : piece.start;
+                }
+                base = pieceEnd;
+            }
+            return pos;
+        };
+        const startAt = locate(start, false);
+        return start === end ? span(startAt, startAt) : span(startAt, locate(end, true));
+    }
+
+    error(message: string, start: number, end: number): SchemaError {
+        return new SchemaError(message, this.span(start, end));
+    }
+
+    // Reports what was found where `expected` should be.
+    private expected(expected: string): SchemaError {
+        const c = this.peek();
+        return c === undefined
+            ? this.error(`expected ${expected}`, this.pos, this.pos)
+            : this.error(`expected ${expected}, found \`${c}\``, this.pos, this.pos + 1);
+    }
+
+    ty(): CqlType {
+        this.skipWhitespace();
+        const start = this.pos;
+        const c = this.peek();
+        if (c === "'") {
+            const className = this.quoted("'");
+            return { kind: 'custom', className, span: this.span(start, this.pos) };
+        }
+        if (c === '"' || /[A-Za-z]/.test(c ?? '')) {
+            return this.named();
+        }
+        throw this.expected('a type');
+    }
+
+    // Reads a `'string'` or `"quoted name"`, where a doubled quote stands for
+    // the quote itself.
+    private quoted(quote: string): string {
+        const start = this.pos++;
+        let value = '';
+        for (;;) {
+            const c = this.peek();
+            if (c === undefined) {
+                throw this.error('unterminated quote', start, this.pos);
+            }
+            this.pos++;
+            if (c !== quote) {
+                value += c;
+            } else if (this.peek() === quote) {
+                this.pos++;
+                value += quote;
+            } else {
+                return value;
+            }
+        }
+    }
+
+    // Reads a name, lowercased unless it is quoted, and whether it was quoted.
+    private name(): [string, boolean] {
+        if (this.peek() === '"') {
+            return [this.quoted('"'), true];
+        }
+        const start = this.pos;
+        while (/\w/.test(this.peek() ?? '')) {
+            this.pos++;
+        }
+        if (this.pos === start) {
+            throw this.expected('a name');
+        }
+        return [this.text.slice(start, this.pos).toLowerCase(), false];
+    }
+
+    private named(): CqlType {
+        const start = this.pos;
+        let [typeName, quoted] = this.name();
+        const nameEnd = this.pos;
+        let keyspace: string | undefined;
+        if (this.peek() === '.') {
+            this.pos++;
+            keyspace = typeName;
+            typeName = this.name()[0];
+        }
+        const end = this.pos;
+
+        const plain = !quoted && keyspace === undefined;
+        this.skipWhitespace();
+        const hasArguments = this.peek() === '<';
+        const native = plain ? nativeType(typeName) : undefined;
+        if (native) {
+            if (hasArguments) {
+                throw this.error(`\`${native}\` doesn't take type arguments`, this.pos, this.pos + 1);
+            }
+            this.pos = end;
+            return { kind: 'native', name: native, span: this.span(start, end) };
+        }
+        const example = plain ? genericTypes[typeName] : undefined;
+        if (example === undefined) {
+            if (hasArguments) {
+                throw this.error(`unknown type \`${typeName}\``, start, end);
+            }
+            this.pos = end;
+            return { kind: 'udt', keyspace, name: typeName, span: this.span(start, end) };
+        }
+        if (!hasArguments) {
+            throw this.error(`\`${typeName}\` needs type arguments, as in \`${example}\``, start, nameEnd);
+        }
+
+        const open = this.pos++;
+        let ty: CqlType;
+        if (typeName === 'vector') {
+            const element = this.ty();
+            if (!this.eat(',')) {
+                throw this.expected('`,`');
+            }
+            ty = { kind: 'vector', element, dimension: this.dimension(), span: span(0, 0) };
+        } else {
+            const items = [this.ty()];
+            while (this.eat(',')) {
+                items.push(this.ty());
+            }
+            const arity = typeName === 'map' ? 2 : typeName === 'tuple' ? items.length : 1;
+            if (items.length !== arity) {
+                this.skipWhitespace();
+                const close = this.pos + (this.peek() === '>' ? 1 : 0);
+                throw this.error(
+                    `\`${typeName}\` takes ${arity} type argument${arity === 1 ? '' : 's'}, found ${items.length}`,
+                    open,
+                    close
+                );
+            }
+            if (typeName === 'map') {
+                ty = { kind: 'map', key: items[0], value: items[1], span: span(0, 0) };
+            } else if (typeName === 'tuple') {
+                ty = { kind: 'tuple', items, span: span(0, 0) };
+            } else {
+                ty = { kind: typeName as 'list' | 'set' | 'frozen', element: items[0], span: span(0, 0) };
+            }
+        }
+        if (!this.eat('>')) {
+            throw this.expected('`,` or `>`');
+        }
+        ty.span = this.span(start, this.pos);
+        return ty;
+    }
+
+    private dimension(): number {
+        this.skipWhitespace();
+        const start = this.pos;
+        while (/[0-9]/.test(this.peek() ?? '')) {
+            this.pos++;
+        }
+        if (this.pos === start) {
+            throw this.expected('the number of dimensions');
+        }
+        const dimension = Number(this.text.slice(start, this.pos));
+        if (dimension === 0 || dimension > 0xffffffff) {
+            throw this.error('a vector needs between 1 and 4294967295 dimensions', start, this.pos);
+        }
+        return dimension;
+    }
+}
+
+// Where a type is used, which decides what may appear there unfrozen
+type Nesting = 'column' | 'collection' | 'frozen';
+
+// Checks a column type against the rules Cassandra applies when creating a
+// table. Names that aren't native types are taken to be user-defined types.
+export function validateType(ty: CqlType): SchemaError[] {
+    const errors: SchemaError[] = [];
+    check(ty, 'column', errors);
+    return errors;
+}
+
+// Reports the user-defined types in `ty` that aren't among `userTypes`, which
+// match by name or by keyspace-qualified name.
+export function unknownUserTypes(ty: CqlType, userTypes: string[]): SchemaError[] {
+    const errors: SchemaError[] = [];
+    visit(ty, inner => {
+        const text = formatType(inner);
+        if (inner.kind === 'udt' && !userTypes.includes(inner.name) && !userTypes.includes(text)) {
+            errors.push(new SchemaError(
+                `unknown type \`${text}\`; declare user-defined types in \`ormOptions.udts\``,
+                inner.span
+            ));
+        }
+    });
+    return errors;
+}
+
+// Calls `f` on `ty` and every type inside it.
+function visit(ty: CqlType, f: (ty: CqlType) => void): void {
+    f(ty);
+    switch (ty.kind) {
+        case 'list':
+        case 'set':
+        case 'vector':
+        case 'frozen':
+            visit(ty.element, f);
+            break;
+        case 'map':
+            visit(ty.key, f);
+            visit(ty.value, f);
+            break;
+        case 'tuple':
+            ty.items.forEach(item => visit(item, f));
+            break;
+    }
+}
+
+function check(ty: CqlType, nesting: Nesting, errors: SchemaError[]): void {
+    const error = (message: string) => errors.push(new SchemaError(message, ty.span));
+    const inner: Nesting = nesting === 'frozen' ? 'frozen' : 'collection';
+    const text = formatType(ty);
+    if (nesting === 'collection' && isCollection(ty)) {
+        error(`collections inside collections must be frozen, as in \`frozen<${text}>\``);
+    }
+    const isDuration = (element: CqlType) => element.kind === 'native' && element.name === 'duration';
+    switch (ty.kind) {
+        case 'native':
+        case 'custom':
+            if (isCounter(ty) && nesting !== 'column') {
+                error("counters can't be used inside other types");
+            }
+            break;
+        case 'list':
+        case 'vector':
+            check(ty.element, inner, errors);
+            break;
+        case 'set':
+            if (isDuration(ty.element)) {
+                errors.push(new SchemaError("sets can't contain durations", ty.element.span));
+            }
+            check(ty.element, inner, errors);
+            break;
+        case 'map':
+            if (isDuration(ty.key)) {
+                errors.push(new SchemaError("durations can't be map keys", ty.key.span));
+            }
+            check(ty.key, inner, errors);
+            check(ty.value, inner, errors);
+            break;
+        // Tuples are always frozen, and so is everything inside them.
+        case 'tuple':
+            for (const item of ty.items) {
+                check(item, 'frozen', errors);
+            }
+            break;
+        case 'frozen':
+            if (['native', 'custom', 'frozen'].includes(ty.element.kind)) {
+                error(`\`${formatType(ty.element)}\` can't be frozen; only collections, tuples and user-defined types can`);
+            }
+            check(ty.element, 'frozen', errors);
+            break;
+        case 'udt':
+            if (nesting === 'collection') {
+                error(`user-defined types inside collections must be frozen, as in \`frozen<${text}>\``);
+            }
+            break;
+    }
+}
@@ -0,0 +1,760 @@
+//! CQL type expressions such as `frozen<map<text, list<int>>>`.
+//!
+//! [`parse_pieces`] accepts the whole type grammar of CQL: native types,
+//! collections, tuples, `vector<float, 1536>`, references to user-defined
+//! types (optionally keyspace-qualified or quoted) and quoted custom types.
+//! Keywords are case-insensitive and aliases are normalized, so `VARCHAR`
+//! parses as `text`.
+//! [`validate`] then applies the rules Cassandra checks when a column is
+//! created, such as freezing collections nested in other collections, and
+//! [`unknown_user_types`] finds names missing from the known user-defined
+//! types.
+
+use crate::literal::{Error, Span, Spanned};
+use std::fmt;
+
+/// Native types, in their canonical spelling.
+const NATIVE_TYPES: &[&str] = &[
+    "ascii",
+    "bigint",
+    "blob",
+    "boolean",
+    "counter",
+    "date",
+    "decimal",
+    "double",
+    "duration",
+    "float",
+    "inet",
+    "int",
+    "smallint",
+    "text",
+    "time",
+    "timestamp",
+    "timeuuid",
+    "tinyint",
+    "uuid",
+    "varint",
+];
+
+/// Other names for native types. `json` is the ORM's own type, which it
+/// stores as text.
+const ALIASES: &[(&str, &str)] = &[("varchar", "text"), ("json", "text")];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CqlType {
+    pub kind: TypeKind,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypeKind {
+    /// A native type, by its canonical name.
+    Native(&'static str),
+    List(Box<CqlType>),
+    Set(Box<CqlType>),
+    Map(Box<CqlType>, Box<CqlType>),
+    Tuple(Vec<CqlType>),
+    Vector(Box<CqlType>, Spanned<u32>),
+    Frozen(Box<CqlType>),
+    /// A user-defined type. Unquoted names are lowercased, as Cassandra does.
+    UserDefined {
+        keyspace: Option<String>,
+        name: String,
+    },
+    /// A custom type given by its Java class name, such as `'org.example.T'`.
+    Custom(String),
+}
+
+impl CqlType {
+    /// Whether this is a `list`, `set` or `map` that isn't frozen.
+    pub fn is_collection(&self) -> bool {
+        matches!(
+            self.kind,
+            TypeKind::List(_) | TypeKind::Set(_) | TypeKind::Map(..)
+        )
+    }
+
+    pub fn is_counter(&self) -> bool {
+        self.kind == TypeKind::Native("counter")
+    }
+}
+
+impl fmt::Display for CqlType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            TypeKind::Native(name) => f.write_str(name),
+            TypeKind::List(element) => write!(f, "list<{element}>"),
+            TypeKind::Set(element) => write!(f, "set<{element}>"),
+            TypeKind::Map(key, value) => write!(f, "map<{key}, {value}>"),
+            TypeKind::Tuple(items) => {
+                f.write_str("tuple<")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                f.write_str(">")
+            }
+            TypeKind::Vector(element, dimension) => {
+                write!(f, "vector<{element}, {}>", dimension.value)
+            }
+            TypeKind::Frozen(inner) => write!(f, "frozen<{inner}>"),
+            TypeKind::UserDefined { keyspace, name } => {
+                if let Some(keyspace) = keyspace {
+                    write_name(f, keyspace)?;
+                    f.write_str(".")?;
+                }
+                write_name(f, name)
+            }
+            TypeKind::Custom(class) => write!(f, "'{}'", class.replace('\'', "''")),
+        }
+    }
+}
+
+/// Writes a name, quoting it unless it reads the same unquoted.
+fn write_name(f: &mut fmt::Formatter<'_>, name: &str) -> fmt::Result {
+    let plain = name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if plain && !is_keyword(name) {
+        f.write_str(name)
+    } else {
+        write!(f, "\"{}\"", name.replace('"', "\"\""))
+    }
+}
+
+fn is_keyword(name: &str) -> bool {
+    native(name).is_some() || generic(name).is_some()
+}
+
+fn native(name: &str) -> Option<&'static str> {
+    NATIVE_TYPES
+        .iter()
+        .copied()
+        .find(|native| *native == name)
+        .or_else(|| {
+            ALIASES
+                .iter()
+                .find(|(alias, _)| *alias == name)
+                .map(|(_, native)| *native)
+        })
+}
+
+/// Types that take type arguments, with an example for error messages.
+const GENERIC_TYPES: &[(&str, &str)] = &[
+    ("list", "list<text>"),
+    ("set", "set<text>"),
+    ("map", "map<text, int>"),
+    ("tuple", "tuple<int, text>"),
+    ("frozen", "frozen<list<text>>"),
+    ("vector", "vector<float, 3>"),
+];
+
+fn generic(name: &str) -> Option<(&'static str, &'static str)> {
+    GENERIC_TYPES
+        .iter()
+        .copied()
+        .find(|(generic, _)| *generic == name)
+}
+
+/// Parses a type written across several strings, such as the ORM's
+/// `{ type: 'map', typeDef: '<text, int>' }`. Each piece is given with the
+/// span of its contents in the document, and spans point into the piece they
+/// came from. A piece longer in the document than its text had escapes, which
+/// unescaping always shortens; its offsets can't be mapped back, so spans
+/// inside it cover the whole piece.
+pub fn parse_pieces(pieces: &[(&str, Span)]) -> Result<CqlType, Error> {
+    let mut parser = Parser {
+        text: pieces.iter().map(|(text, _)| *text).collect(),
+        pieces,
+        pos: 0,
+    };
+    let ty = parser.ty()?;
+    parser.skip_whitespace();
+    match parser.peek() {
+        None => Ok(ty),
+        Some(c) => Err(parser.error(
+            &format!("unexpected `{c}`"),
+            parser.pos,
+            parser.pos + c.len_utf8(),
+        )),
+    }
+}
+
+struct Parser<'a> {
+    text: String,
+    pieces: &'a [(&'a str, Span)],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.text[self.pos..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek().filter(|c| c.is_whitespace()) {
+            self.pos += c.len_utf8();
+        }
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        self.skip_whitespace();
+        let found = self.peek() == Some(c);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    /// Maps a range of the joined text back to the document.
+    fn span(&self, start: usize, end: usize) -> Span {
+        let locate = |pos: usize, is_end: bool| {
+            let mut base = 0;
+            for (i, (text, span)) in self.pieces.iter().enumerate() {
+                let piece_end = base + text.len();
+                if pos < piece_end || (is_end && pos == piece_end) || i + 1 == self.pieces.len() {
+                    return match span.end - span.start == text.len() {
+                        true => span.start + pos - base,
+                        false if is_end => span.end,
+                        false => span.start,
+                    };
+                }
+                base = piece_end;
+            }
+            pos
+        };
+        let start_at = locate(start, false);
+        if start == end {
+            return Span::new(start_at, start_at);
+        }
+        Span::new(start_at, locate(end, true))
+    }
+
+    fn error(&self, message: &str, start: usize, end: usize) -> Error {
+        Error::new(message, self.span(start, end))
+    }
+
+    /// Reports what was found where `expected` should be.
+    fn expected(&self, expected: &str) -> Error {
+        match self.peek() {
+            Some(c) => self.error(
+                &format!("expected {expected}, found `{c}`"),
+                self.pos,
+                self.pos + c.len_utf8(),
+            ),
+            None => self.error(&format!("expected {expected}"), self.pos, self.pos),
+        }
+    }
+
+    fn ty(&mut self) -> Result<CqlType, Error> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let kind = match self.peek() {
+            Some('\'') => TypeKind::Custom(self.quoted('\'')?),
+            Some(c) if c == '"' || c.is_ascii_alphabetic() => return self.named(),
+            _ => return Err(self.expected("a type")),
+        };
+        Ok(CqlType {
+            kind,
+            span: self.span(start, self.pos),
+        })
+    }
+
+    /// Reads a `'string'` or `"quoted name"`, where a doubled quote stands
+    /// for the quote itself.
+    fn quoted(&mut self, quote: char) -> Result<String, Error> {
+        let start = self.pos;
+        self.pos += 1;
+        let mut value = String::new();
+        loop {
+            match self.peek() {
+                None => return Err(self.error("unterminated quote", start, self.pos)),
+                Some(c) if c == quote => {
+                    self.pos += 1;
+                    if self.peek() != Some(quote) {
+                        return Ok(value);
+                    }
+                    self.pos += 1;
+                    value.push(quote);
+                }
+                Some(c) => {
+                    self.pos += c.len_utf8();
+                    value.push(c);
+                }
+            }
+        }
+    }
+
+    /// Reads a name, lowercased unless it is quoted, and whether it was quoted.
+    fn name(&mut self) -> Result<(String, bool), Error> {
+        if self.peek() == Some('"') {
+            return Ok((self.quoted('"')?, true));
+        }
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+        {
+            self.pos += 1;
+        }
+        if self.pos == start {
+            return Err(self.expected("a name"));
+        }
+        Ok((self.text[start..self.pos].to_ascii_lowercase(), false))
+    }
+
+    fn named(&mut self) -> Result<CqlType, Error> {
+        let start = self.pos;
+        let (mut name, quoted) = self.name()?;
+        let name_end = self.pos;
+        let mut keyspace = None;
+        if self.peek() == Some('.') {
+            self.pos += 1;
+            keyspace = Some(std::mem::replace(&mut name, self.name()?.0));
+        }
+        let end = self.pos;
+
+        let plain = !quoted && keyspace.is_none();
+        self.skip_whitespace();
+        let arguments = self.peek() == Some('<');
+        if let Some(native) = native(&name).filter(|_| plain) {
+            if arguments {
+                return Err(self.error(
+                    &format!("`{native}` doesn't take type arguments"),
+                    self.pos,
+                    self.pos + 1,
+                ));
+            }
+            self.pos = end;
+            return Ok(CqlType {
+                kind: TypeKind::Native(native),
+                span: self.span(start, end),
+            });
+        }
+        let Some((generic, example)) = generic(&name).filter(|_| plain) else {
+            if arguments {
+                return Err(self.error(&format!("unknown type `{name}`"), start, end));
+            }
+            self.pos = end;
+            return Ok(CqlType {
+                kind: TypeKind::UserDefined { keyspace, name },
+                span: self.span(start, end),
+            });
+        };
+        if !arguments {
+            return Err(self.error(
+                &format!("`{generic}` needs type arguments, as in `{example}`"),
+                start,
+                name_end,
+            ));
+        }
+
+        let open = self.pos;
+        self.pos += 1;
+        let kind = if generic == "vector" {
+            let element = self.ty()?;
+            if !self.eat(',') {
+                return Err(self.expected("`,`"));
+            }
+            let dimension = self.dimension()?;
+            TypeKind::Vector(Box::new(element), dimension)
+        } else {
+            let mut arguments = vec![self.ty()?];
+            while self.eat(',') {
+                arguments.push(self.ty()?);
+            }
+            let arity = match generic {
+                "map" => 2,
+                "tuple" => arguments.len(),
+                _ => 1,
+            };
+            if arguments.len() != arity {
+                self.skip_whitespace();
+                let close = self.pos + usize::from(self.peek() == Some('>'));
+                return Err(self.error(
+                    &format!(
+                        "`{generic}` takes {arity} type argument{}, found {}",
+                        if arity == 1 { "" } else { "s" },
+                        arguments.len()
+                    ),
+                    open,
+                    close,
+                ));
+            }
+            let mut arguments = arguments.into_iter().map(Box::new);
+            let mut next = || arguments.next().unwrap();
+            match generic {
+                "list" => TypeKind::List(next()),
+                "set" => TypeKind::Set(next()),
+                "map" => TypeKind::Map(next(), next()),
+                "frozen" => TypeKind::Frozen(next()),
+                _ => TypeKind::Tuple(arguments.map(|argument| *argument).collect()),
+            }
+        };
+        if !self.eat('>') {
+            return Err(self.expected("`,` or `>`"));
+        }
+        Ok(CqlType {
+            kind,
+            span: self.span(start, self.pos),
+        })
+    }
+
+    fn dimension(&mut self) -> Result<Spanned<u32>, Error> {
+        self.skip_whitespace();
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        if self.pos == start {
+            return Err(self.expected("the number of dimensions"));
+        }
+        match self.text[start..self.pos].parse() {
+            Ok(0) | Err(_) => Err(self.error(
+                "a vector needs between 1 and 4294967295 dimensions",
+                start,
+                self.pos,
+            )),
+            Ok(dimension) => Ok(Spanned::new(dimension, self.span(start, self.pos))),
+        }
+    }
+}
+
+/// Where a type is used, which decides what may appear there unfrozen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Nesting {
+    Column,
+    Collection,
+    Frozen,
+}
+
+/// Checks a column type against the rules Cassandra applies when creating a
+/// table. Names that aren't native types are taken to be user-defined types.
+pub fn validate(ty: &CqlType) -> Vec<Error> {
+    let mut errors = Vec::new();
+    check(ty, Nesting::Column, &mut errors);
+    errors
+}
+
+/// Reports the user-defined types in `ty` that aren't among `user_types`,
+/// which match by name or by keyspace-qualified name.
+pub fn unknown_user_types(ty: &CqlType, user_types: &[&str]) -> Vec<Error> {
+    let mut errors = Vec::new();
+    visit(ty, &mut |ty| {
+        let TypeKind::UserDefined { name, .. } = &ty.kind else {
+            return;
+        };
+        if !user_types.contains(&name.as_str()) && !user_types.contains(&ty.to_string().as_str()) {
+            errors.push(Error::new(
+                format!("unknown type `{ty}`; declare user-defined types in `ormOptions.udts`"),
+                ty.span,
+            ));
+        }
+    });
+    errors
+}
+
+/// Calls `f` on `ty` and every type inside it.
+fn visit(ty: &CqlType, f: &mut impl FnMut(&CqlType)) {
+    f(ty);
+    match &ty.kind {
+        TypeKind::List(element)
+        | TypeKind::Set(element)
+        | TypeKind::Vector(element, _)
+        | TypeKind::Frozen(element) => visit(element, f),
+        TypeKind::Map(key, value) => {
+            visit(key, f);
+            visit(value, f);
+        }
+        TypeKind::Tuple(items) => items.iter().for_each(|item| visit(item, f)),
+        TypeKind::Native(_) | TypeKind::UserDefined { .. } | TypeKind::Custom(_) => {}
+    }
+}
+
+fn check(ty: &CqlType, nesting: Nesting, errors: &mut Vec<Error>) {
+    let error = |message: String| Error::new(message, ty.span);
+    let inner = match nesting {
+        Nesting::Frozen => Nesting::Frozen,
+        _ => Nesting::Collection,
+    };
+    if nesting == Nesting::Collection && ty.is_collection() {
+        errors.push(error(format!(
+            "collections inside collections must be frozen, as in `frozen<{ty}>`"
+        )));
+    }
+    match &ty.kind {
+        TypeKind::Native(_) | TypeKind::Custom(_) => {
+            if ty.is_counter() && nesting != Nesting::Column {
+                errors.push(error("counters can't be used inside other types".into()));
+            }
+        }
+        TypeKind::List(element) => check(element, inner, errors),
+        TypeKind::Set(element) => {
+            if element.kind == TypeKind::Native("duration") {
+                errors.push(Error::new("sets can't contain durations", element.span));
+            }
+            check(element, inner, errors);
+        }
+        TypeKind::Map(key, value) => {
+            if key.kind == TypeKind::Native("duration") {
+                errors.push(Error::new("durations can't be map keys", key.span));
+            }
+            check(key, inner, errors);
+            check(value, inner, errors);
+        }
+        TypeKind::Vector(element, _) => check(element, inner, errors),
+        // Tuples are always frozen, and so is everything inside them.
+        TypeKind::Tuple(items) => {
+            for item in items {
+                check(item, Nesting::Frozen, errors);
+            }
+        }
+        TypeKind::Frozen(inner) => {
+            if matches!(
+                inner.kind,
+                TypeKind::Native(_) | TypeKind::Custom(_) | TypeKind::Frozen(_)
+            ) {
+                errors.push(error(format!(
+                    "`{inner}` can't be frozen; only collections, tuples and user-defined types can"
+                )));
+            }
+            check(inner, Nesting::Frozen, errors);
+        }
+        TypeKind::UserDefined { .. } => {
+            if nesting == Nesting::Collection {
+                errors.push(error(format!(
+                    "user-defined types inside collections must be frozen, as in `frozen<{ty}>`"
+                )));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str, offset: usize) -> Result<CqlType, Error> {
+        parse_pieces(&[(text, Span::new(offset, offset + text.len()))])
+    }
+
+    fn parsed(text: &str) -> CqlType {
+        parse(text, 0).unwrap_or_else(|err| panic!("{text}: {err} at {:?}", err.span))
+    }
+
+    fn at(text: &str, span: Span) -> &str {
+        &text[span.start..span.end]
+    }
+
+    #[test]
+    fn types_are_parsed_and_normalized() {
+        let cases = [
+            ("int", "int"),
+            ("VARCHAR", "text"),
+            ("json", "text"),
+            ("list<text>", "list<text>"),
+            ("Set < TimeUUID >", "set<timeuuid>"),
+            ("map<varchar,int>", "map<text, int>"),
+            (
+                "frozen<map<text, list<int>>>",
+                "frozen<map<text, list<int>>>",
+            ),
+            (
+                "tuple<int,text,frozen<list<blob>>>",
+                "tuple<int, text, frozen<list<blob>>>",
+            ),
+            ("vector<float, 1536>", "vector<float, 1536>"),
+            ("frozen<Address>", "frozen<address>"),
+            (
+                "frozen<shop.\"PostalAddress\">",
+                "frozen<shop.\"PostalAddress\">",
+            ),
+            ("\"text\"", "\"text\""),
+            ("'org.example.Point'", "'org.example.Point'"),
+        ];
+        for (text, normalized) in cases {
+            assert_eq!(parsed(text).to_string(), normalized, "{text}");
+        }
+    }
+
+    #[test]
+    fn every_node_keeps_its_span() {
+        let text = "frozen<map<text, list<int>>>";
+        let ty = parsed(text);
+        assert_eq!(ty.span, Span::new(0, text.len()));
+        let TypeKind::Frozen(map) = &ty.kind else {
+            panic!("expected frozen, got {ty}");
+        };
+        let TypeKind::Map(key, value) = &map.kind else {
+            panic!("expected a map, got {map}");
+        };
+        assert_eq!(at(text, map.span), "map<text, list<int>>");
+        assert_eq!(at(text, key.span), "text");
+        assert_eq!(at(text, value.span), "list<int>");
+
+        let text = "vector<float, 1536>";
+        let TypeKind::Vector(element, dimension) = parsed(text).kind else {
+            panic!("expected a vector");
+        };
+        assert_eq!(element.kind, TypeKind::Native("float"));
+        assert_eq!((dimension.value, at(text, dimension.span)), (1536, "1536"));
+
+        let TypeKind::UserDefined { keyspace, name } = parsed("Shop.Address").kind else {
+            panic!("expected a user-defined type");
+        };
+        assert_eq!(
+            (keyspace.as_deref(), name.as_str()),
+            (Some("shop"), "address")
+        );
+    }
+
+    #[test]
+    fn types_split_across_strings_map_back_to_each_string() {
+        let source = "{ type: 'map', typeDef: '<text, lst<int>>' }";
+        let ty = source.find("map").unwrap();
+        let type_def = source.find('<').unwrap();
+        let piece = |text: &'static str, start: usize| (text, Span::new(start, start + text.len()));
+        let pieces = [piece("map", ty), piece("<text, lst<int>>", type_def)];
+        let err = parse_pieces(&pieces).unwrap_err();
+        assert_eq!(err.message, "unknown type `lst`");
+        assert_eq!(at(source, err.span), "lst");
+
+        let pieces = [piece("map", ty), piece("<text, int>", type_def)];
+        let map = parse_pieces(&pieces).unwrap();
+        assert_eq!(map.to_string(), "map<text, int>");
+        assert_eq!(map.span, Span::new(ty, type_def + "<text, int>".len()));
+    }
+
+    #[test]
+    fn syntax_errors_point_at_the_problem() {
+        let cases = [
+            ("", "expected a type", ""),
+            ("lst<int>", "unknown type `lst`", "lst"),
+            ("int<text>", "`int` doesn't take type arguments", "<"),
+            (
+                "list",
+                "`list` needs type arguments, as in `list<text>`",
+                "list",
+            ),
+            (
+                "map<text>",
+                "`map` takes 2 type arguments, found 1",
+                "<text>",
+            ),
+            (
+                "list<int, text>",
+                "`list` takes 1 type argument, found 2",
+                "<int, text>",
+            ),
+            ("list<int", "expected `,` or `>`", ""),
+            ("list<int>>", "unexpected `>`", ">"),
+            ("map<text, >", "expected a type, found `>`", ">"),
+            ("tuple<>", "expected a type, found `>`", ">"),
+            ("vector<float>", "expected `,`, found `>`", ">"),
+            (
+                "vector<float, 0>",
+                "a vector needs between 1 and 4294967295 dimensions",
+                "0",
+            ),
+            ("list<'org.T>", "unterminated quote", "'org.T>"),
+            ("text;", "unexpected `;`", ";"),
+        ];
+        for (text, message, span) in cases {
+            let err = parse(text, 0).unwrap_err();
+            assert_eq!(
+                (err.message.as_str(), at(text, err.span)),
+                (message, span),
+                "{text}"
+            );
+        }
+    }
+
+    #[test]
+    fn validation_reports_nesting_and_freezing_mistakes() {
+        let cases = [
+            (
+                "list<list<int>>",
+                "collections inside collections must be frozen, as in `frozen<list<int>>`",
+                "list<int>",
+            ),
+            (
+                "map<text, set<int>>",
+                "collections inside collections must be frozen, as in `frozen<set<int>>`",
+                "set<int>",
+            ),
+            (
+                "vector<list<float>, 3>",
+                "collections inside collections must be frozen, as in `frozen<list<float>>`",
+                "list<float>",
+            ),
+            (
+                "list<address>",
+                "user-defined types inside collections must be frozen, as in `frozen<address>`",
+                "address",
+            ),
+            (
+                "frozen<int>",
+                "`int` can't be frozen; only collections, tuples and user-defined types can",
+                "frozen<int>",
+            ),
+            (
+                "list<counter>",
+                "counters can't be used inside other types",
+                "counter",
+            ),
+            ("set<duration>", "sets can't contain durations", "duration"),
+            (
+                "map<duration, int>",
+                "durations can't be map keys",
+                "duration",
+            ),
+        ];
+        for (text, message, span) in cases {
+            let errors = validate(&parsed(text));
+            let errors = errors
+                .iter()
+                .map(|err| (err.message.as_str(), at(text, err.span)))
+                .collect::<Vec<_>>();
+            assert_eq!(errors, [(message, span)], "{text}");
+        }
+
+        for text in [
+            "counter",
+            "address",
+            "frozen<list<frozen<map<text, int>>>>",
+            "list<frozen<address>>",
+            "tuple<int, list<int>>",
+            "frozen<tuple<int, text>>",
+            "vector<float, 1536>",
+        ] {
+            assert_eq!(validate(&parsed(text)), [], "{text}");
+        }
+    }
+
+    #[test]
+    fn unknown_user_types_are_found_anywhere_in_a_type() {
+        let known = ["address", "shop.phone"];
+        let text = "map<frozen<address>, frozen<adress>>";
+        let errors = unknown_user_types(&parsed(text), &known);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].message,
+            "unknown type `adress`; declare user-defined types in `ormOptions.udts`"
+        );
+        assert_eq!(at(text, errors[0].span), "adress");
+
+        assert_eq!(
+            unknown_user_types(&parsed("frozen<shop.phone>"), &known),
+            []
+        );
+        assert_eq!(
+            unknown_user_types(&parsed("tuple<int, phone>"), &known).len(),
+            1
+        );
+        assert_eq!(unknown_user_types(&parsed("list<int>"), &[]), []);
+    }
+}
@@ -0,0 +1,906 @@
+//! Generates the CQL that creates a model's table, secondary indexes and
+//! materialized views, and the `/cql-ddl <model>` slash command showing it.
+//!
+//! The statements follow what express-cassandra, which the ORM's schemas come
+//! from, would create: index names default to `<table>_<column>_idx`, and
+//! materialized views require every key column to be `IS NOT NULL`.
+
+use crate::analysis;
+use crate::cql_type;
+use crate::literal::{Error, Property, Value, ValueKind};
+use crate::schema::{
+    self, ClusteringOrder, IndexDefinition, MaterializedViewDefinition, Model, ModelSchema, Order,
+    PrimaryKey,
+};
+use zed_extension_api::{SlashCommandOutput, SlashCommandOutputSection};
+
+/// Words CQL reserves, which have to be quoted when used as names.
+const RESERVED_KEYWORDS: &[&str] = &[
+    "add",
+    "allow",
+    "alter",
+    "and",
+    "apply",
+    "asc",
+    "authorize",
+    "batch",
+    "begin",
+    "by",
+    "columnfamily",
+    "create",
+    "delete",
+    "desc",
+    "describe",
+    "drop",
+    "entries",
+    "execute",
+    "from",
+    "full",
+    "grant",
+    "if",
+    "in",
+    "index",
+    "infinity",
+    "insert",
+    "into",
+    "is",
+    "keyspace",
+    "limit",
+    "materialized",
+    "modify",
+    "nan",
+    "norecursive",
+    "not",
+    "null",
+    "of",
+    "on",
+    "or",
+    "order",
+    "primary",
+    "rename",
+    "replace",
+    "revoke",
+    "schema",
+    "select",
+    "set",
+    "table",
+    "to",
+    "token",
+    "truncate",
+    "unlogged",
+    "update",
+    "use",
+    "using",
+    "view",
+    "where",
+    "with",
+];
+
+/// Folders models are usually declared in, relative to the package root.
+/// `cassandraorm generate model` writes to `src/models/`.
+const MODEL_DIRS: &[&str] = &[
+    "src/models/",
+    "models/",
+    "src/schemas/",
+    "schemas/",
+    "src/",
+    "",
+];
+
+const MODEL_EXTENSIONS: &[&str] = &[".cassandra.ts", ".cassandra.js", ".ts", ".js"];
+
+/// A generated statement, with a label such as `CREATE TABLE users`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Statement {
+    pub label: String,
+    pub text: String,
+}
+
+/// Runs `/cql-ddl <model> [file]`, listing the problems of schemas that have
+/// any instead of their DDL. Types missing from the user-defined types in
+/// `user_types`, from the project config, and those the model's file declares
+/// are noted under the DDL, since they may be declared elsewhere.
+pub fn run_slash_command(
+    args: &[String],
+    keyspace: Option<&str>,
+    user_types: &[String],
+    read_file: impl Fn(&str) -> Option<String>,
+) -> Result<SlashCommandOutput, String> {
+    let found = find_model("cql-ddl", args, read_file)?;
+    let schema = found.schema()?;
+    let declared = schema::find_user_types(&found.source);
+    let user_types = user_types
+        .iter()
+        .chain(&declared)
+        .map(String::as_str)
+        .collect::<Vec<_>>();
+    let errors = analysis::check(schema);
+    if !errors.is_empty() {
+        return Err(errors
+            .into_iter()
+            .map(|err| found.locate(err))
+            .collect::<Vec<_>>()
+            .join("\n"));
+    }
+    let statements =
+        statements(&found.model.name.value, schema, keyspace).map_err(|err| found.locate(err))?;
+    let notes = analysis::unknown_types(schema, &user_types)
+        .into_iter()
+        .map(|err| found.locate(err))
+        .collect::<Vec<_>>();
+    Ok(output(&statements, &notes))
+}
+
+/// A model a slash command was run on, with the file declaring it.
+pub struct FoundModel {
+    pub path: String,
+    pub source: String,
+    pub model: Model,
+}
+
+impl FoundModel {
+    /// The parsed schema, or where it stops being valid.
+    pub fn schema(&self) -> Result<&ModelSchema, String> {
+        self.model
+            .schema
+            .as_ref()
+            .map_err(|err| self.locate(err.clone()))
+    }
+
+    /// Formats `err` as `path:line:column: message`.
+    pub fn locate(&self, err: Error) -> String {
+        let (line, column) = line_column(&self.source, err.span.start);
+        format!("{}:{line}:{column}: {}", self.path, err.message)
+    }
+}
+
+/// Finds the model named by the `<model> [file]` arguments of `/{command}`.
+/// Without a file, the model is looked up in the files named after it in the
+/// usual model folders, such as `src/models/user.ts` for `users`. `read_file`
+/// returns the text of a file in the package folder if it exists.
+pub fn find_model(
+    command: &str,
+    args: &[String],
+    read_file: impl Fn(&str) -> Option<String>,
+) -> Result<FoundModel, String> {
+    let (name, file) = match args {
+        [name] => (name, None),
+        [name, file] => (name, Some(file)),
+        _ => return Err(format!("usage: /{command} <model> [file]")),
+    };
+    let files = match file {
+        Some(file) => vec![file.clone()],
+        None => candidate_files(name),
+    };
+
+    for path in files {
+        let Some(source) = read_file(&path) else {
+            continue;
+        };
+        let Some(model) = schema::find_models(&source).into_iter().find(|model| {
+            model.name.value == *name
+                || model
+                    .schema
+                    .as_ref()
+                    .is_ok_and(|schema| schema.table_name(&model.name.value) == name.as_str())
+        }) else {
+            continue;
+        };
+        return Ok(FoundModel {
+            path,
+            source,
+            model,
+        });
+    }
+
+    Err(match file {
+        Some(file) => format!("{file} doesn't declare a model named `{name}`"),
+        None => format!(
+            "no model named `{name}` was found in the usual model files; \
+             pass the file that declares it, as in `/{command} {name} src/db/{name}.ts`"
+        ),
+    })
+}
+
+/// The files a model named `name` is looked for in, most specific first.
+fn candidate_files(name: &str) -> Vec<String> {
+    let singular = name.strip_suffix('s').filter(|stem| !stem.is_empty());
+    let mut files = Vec::new();
+    for dir in MODEL_DIRS {
+        for stem in [Some(name), singular, Some("index")].into_iter().flatten() {
+            for extension in MODEL_EXTENSIONS {
+                let file = format!("{dir}{stem}{extension}");
+                if !files.contains(&file) {
+                    files.push(file);
+                }
+            }
+        }
+    }
+    files
+}
+
+/// Returns the 1-based line and column of a byte offset, counting an offset
+/// inside a character as that character.
+fn line_column(source: &str, offset: usize) -> (usize, usize) {
+    let mut offset = offset.min(source.len());
+    while !source.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    let before = &source[..offset];
+    let line_start = before.rfind('\n').map_or(0, |ix| ix + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+/// Puts the statements in a `cql` code block, with a section per statement,
+/// followed by `notes`.
+fn output(statements: &[Statement], notes: &[String]) -> SlashCommandOutput {
+    let mut text = String::from("```cql\n");
+    let mut sections = Vec::new();
+    for (i, statement) in statements.iter().enumerate() {
+        if i > 0 {
+            text.push('\n');
+        }
+        let start = text.len();
+        text.push_str(&statement.text);
+        sections.push(SlashCommandOutputSection {
+            range: (start..text.len()).into(),
+            label: statement.label.clone(),
+        });
+        text.push('\n');
+    }
+    text.push_str("```\n");
+    for note in notes {
+        text.push_str(&format!("\nNote: {note}\n"));
+    }
+    SlashCommandOutput { text, sections }
+}
+
+/// Generates the statements creating the model named `name`: its table, then
+/// its indexes and materialized views.
+pub fn statements(
+    name: &str,
+    schema: &ModelSchema,
+    keyspace: Option<&str>,
+) -> Result<Vec<Statement>, Error> {
+    let table = schema.table_name(name);
+    let mut statements = vec![create_table(table, schema, keyspace)?];
+    for index in &schema.indexes {
+        statements.extend(create_indexes(table, index, keyspace)?);
+    }
+    for view in &schema.materialized_views {
+        statements.push(create_view(table, view, keyspace)?);
+    }
+    Ok(statements)
+}
+
+fn create_table(
+    table: &str,
+    schema: &ModelSchema,
+    keyspace: Option<&str>,
+) -> Result<Statement, Error> {
+    let key = schema
+        .key
+        .as_ref()
+        .ok_or_else(|| Error::new("missing `key`", schema.span))?;
+
+    let mut definitions = Vec::new();
+    for field in &schema.fields {
+        if !field.is_stored() {
+            continue;
+        }
+        let in_field = |err: Error| {
+            Error::new(
+                format!("fields.{}: {}", field.name.value, err.message),
+                err.span,
+            )
+        };
+        let ty = field.parse_type().map_err(in_field)?;
+        if let Some(err) = cql_type::validate(&ty).into_iter().next() {
+            return Err(in_field(err));
+        }
+        definitions.push(format!("{} {ty}", identifier(&field.name.value)));
+    }
+    for (column, ty) in schema.generated_columns() {
+        if schema.field(&column).is_none() {
+            definitions.push(format!("{} {ty}", identifier(&column)));
+        }
+    }
+    definitions.push(format!("PRIMARY KEY {}", primary_key(key)));
+
+    let name = qualified(keyspace, table);
+    let mut text = format!(
+        "CREATE TABLE IF NOT EXISTS {name} (\n  {}\n)",
+        definitions.join(",\n  ")
+    );
+    let mut properties = Vec::new();
+    properties.extend(clustering_order(key, &schema.clustering_order));
+    if let Some(options) = &schema.options {
+        for (property, value) in [
+            ("compaction", &options.compaction),
+            ("compression", &options.compression),
+            ("caching", &options.caching),
+        ] {
+            if let Some(value) = value {
+                let path = format!("options.{property}");
+                properties.push(format!("{property} = {}", map(&value.value, &path)?));
+            }
+        }
+        if let Some(seconds) = &options.gc_grace_seconds {
+            properties.push(format!("gc_grace_seconds = {}", number(seconds.value)));
+        }
+        if let Some(seconds) = &options.default_time_to_live {
+            properties.push(format!("default_time_to_live = {}", number(seconds.value)));
+        }
+        if let Some(chance) = &options.bloom_filter_fp_chance {
+            properties.push(format!("bloom_filter_fp_chance = {}", number(chance.value)));
+        }
+        if let Some(comment) = &options.comment {
+            properties.push(format!("comment = {}", string(&comment.value)));
+        }
+    }
+    push_properties(&mut text, &properties, " WITH ");
+    text.push(';');
+    Ok(Statement {
+        label: format!("CREATE TABLE {table}"),
+        text,
+    })
+}
+
+/// Formats `((tenant, id), created_at)`, or `(id, created_at)` when the
+/// partition key has a single column.
+fn primary_key(key: &PrimaryKey) -> String {
+    let partition = key
+        .partition
+        .iter()
+        .map(|column| identifier(&column.value))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let partition = match key.partition.len() {
+        1 => partition,
+        _ => format!("({partition})"),
+    };
+    let columns = std::iter::once(partition)
+        .chain(
+            key.clustering
+                .iter()
+                .map(|column| identifier(&column.value)),
+        )
+        .collect::<Vec<_>>();
+    format!("({})", columns.join(", "))
+}
+
+/// Lists every clustering column in key order, as Cassandra requires, taking
+/// the direction from `clustering_order` and defaulting to `ASC`.
+fn clustering_order(key: &PrimaryKey, orders: &[ClusteringOrder]) -> Option<String> {
+    if orders.is_empty() || key.clustering.is_empty() {
+        return None;
+    }
+    let columns = key
+        .clustering
+        .iter()
+        .map(|column| {
+            let order = orders
+                .iter()
+                .rev()
+                .find(|order| order.column.value == column.value)
+                .map_or(Order::Asc, |order| order.order.value);
+            let order = match order {
+                Order::Asc => "ASC",
+                Order::Desc => "DESC",
+            };
+            format!("{} {order}", identifier(&column.value))
+        })
+        .collect::<Vec<_>>();
+    Some(format!("CLUSTERING ORDER BY ({})", columns.join(", ")))
+}
+
+/// Appends table properties, the first after `with` and the rest on lines of
+/// their own.
+fn push_properties(text: &mut String, properties: &[String], with: &str) {
+    for (i, property) in properties.iter().enumerate() {
+        text.push_str(if i == 0 { with } else { "\n  AND " });
+        text.push_str(property);
+    }
+}
+
+/// Creates one index per target. Indexes listed as bare columns are named
+/// `<table>_<column>_idx`, and a `using` option makes a custom or SAI index.
+fn create_indexes(
+    table: &str,
+    index: &IndexDefinition,
+    keyspace: Option<&str>,
+) -> Result<Vec<Statement>, Error> {
+    let path = match &index.name {
+        Some(name) => format!("indexes.{}", name.value),
+        None => "indexes".to_string(),
+    };
+    let mut using = None;
+    let mut options = Vec::new();
+    for option in &index.options {
+        match (option.key.value.as_str(), &option.value.kind) {
+            ("using", ValueKind::String(class)) => using = Some(class.as_str()),
+            _ => options.push(option.clone()),
+        }
+    }
+
+    let mut statements = Vec::new();
+    for target in &index.target {
+        let suffix = target
+            .value
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect::<String>();
+        let suffix = suffix.trim_matches('_');
+        let name = match &index.name {
+            Some(name) if index.target.len() == 1 => name.value.clone(),
+            Some(name) => format!("{}_{suffix}", name.value),
+            None => format!("{table}_{suffix}_idx"),
+        };
+        let custom = using.is_some_and(|class| class.contains('.'));
+        let mut text = format!(
+            "CREATE {}INDEX IF NOT EXISTS {} ON {} ({})",
+            if custom { "CUSTOM " } else { "" },
+            identifier(&name),
+            qualified(keyspace, table),
+            index_target(&target.value)
+        );
+        if let Some(class) = using {
+            text.push_str(&format!(" USING {}", string(class)));
+        }
+        if !options.is_empty() {
+            text.push_str(&format!(
+                " WITH OPTIONS = {}",
+                map(&options, &format!("{path}.options"))?
+            ));
+        }
+        text.push(';');
+        statements.push(Statement {
+            label: format!("CREATE INDEX {name}"),
+            text,
+        });
+    }
+    Ok(statements)
+}
+
+/// Formats an index target: a column, or `keys(info)`, `values(info)`,
+/// `entries(info)` and `full(info)` for collections.
+fn index_target(target: &str) -> String {
+    let call = target
+        .strip_suffix(')')
+        .and_then(|call| call.split_once('('));
+    match call {
+        Some((function, column))
+            if ["keys", "values", "entries", "full"]
+                .contains(&function.trim().to_ascii_lowercase().as_str()) =>
+        {
+            format!(
+                "{}({})",
+                function.trim().to_ascii_uppercase(),
+                identifier(column.trim())
+            )
+        }
+        _ => identifier(target),
+    }
+}
+
+fn create_view(
+    table: &str,
+    view: &MaterializedViewDefinition,
+    keyspace: Option<&str>,
+) -> Result<Statement, Error> {
+    let path = format!("materialized_views.{}", view.name.value);
+    let select = match view.select.as_slice() {
+        [all] if all.value == "*" => "*".to_string(),
+        columns => columns
+            .iter()
+            .map(|column| identifier(&column.value))
+            .collect::<Vec<_>>()
+            .join(", "),
+    };
+
+    let mut conditions = view
+        .key
+        .columns()
+        .map(|column| format!("{} IS NOT NULL", identifier(&column.value)))
+        .collect::<Vec<_>>();
+    if let Some(filters) = &view.filters {
+        for condition in view_conditions(filters, &format!("{path}.filters"))? {
+            if !conditions.contains(&condition) {
+                conditions.push(condition);
+            }
+        }
+    }
+
+    let mut text = format!(
+        "CREATE MATERIALIZED VIEW IF NOT EXISTS {} AS\n  SELECT {select} FROM {}\n  WHERE {}\n  PRIMARY KEY {}",
+        qualified(keyspace, &view.name.value),
+        qualified(keyspace, table),
+        conditions.join("\n    AND "),
+        primary_key(&view.key)
+    );
+    let properties = clustering_order(&view.key, &view.clustering_order)
+        .into_iter()
+        .collect::<Vec<_>>();
+    push_properties(&mut text, &properties, "\n  WITH ");
+    text.push(';');
+    Ok(Statement {
+        label: format!("CREATE MATERIALIZED VIEW {}", view.name.value),
+        text,
+    })
+}
+
+/// Translates express-cassandra view filters such as
+/// `{ age: { $gte: 18, $isnt: null } }` into `WHERE` conditions.
+fn view_conditions(filters: &Value, path: &str) -> Result<Vec<String>, Error> {
+    let columns = filters
+        .as_properties()
+        .ok_or_else(|| Error::new(format!("{path}: expected an object"), filters.span))?;
+    let mut conditions = Vec::new();
+    for column in columns {
+        let name = identifier(&column.key.value);
+        let path = format!("{path}.{}", column.key.value);
+        let Some(operators) = column.value.as_properties() else {
+            conditions.push(format!("{name} = {}", literal(&column.value, &path)?));
+            continue;
+        };
+        for operator in operators {
+            let path = format!("{path}.{}", operator.key.value);
+            let condition = match (operator.key.value.as_str(), &operator.value.kind) {
+                ("$isnt", ValueKind::Null) => format!("{name} IS NOT NULL"),
+                ("$in", ValueKind::Array(items)) => {
+                    let items = items
+                        .iter()
+                        .map(|item| literal(item, &path))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    format!("{name} IN ({})", items.join(", "))
+                }
+                (op, _) => {
+                    let symbol = match op {
+                        "$eq" => "=",
+                        "$gt" => ">",
+                        "$gte" => ">=",
+                        "$lt" => "<",
+                        "$lte" => "<=",
+                        _ => {
+                            return Err(Error::new(
+                                format!("{path}: materialized views can't filter with `{op}`"),
+                                column.value.span,
+                            ))
+                        }
+                    };
+                    format!("{name} {symbol} {}", literal(&operator.value, &path)?)
+                }
+            };
+            conditions.push(condition);
+        }
+    }
+    Ok(conditions)
+}
+
+/// Formats a map of options such as `{'class': 'LeveledCompactionStrategy'}`.
+fn map(properties: &[Property], path: &str) -> Result<String, Error> {
+    let entries = properties
+        .iter()
+        .map(|property| {
+            let path = format!("{path}.{}", property.key.value);
+            Ok(format!(
+                "{}: {}",
+                string(&property.key.value),
+                literal(&property.value, &path)?
+            ))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+    Ok(format!("{{{}}}", entries.join(", ")))
+}
+
+fn literal(value: &Value, path: &str) -> Result<String, Error> {
+    match &value.kind {
+        ValueKind::String(text) => Ok(string(text)),
+        ValueKind::Number(value) => Ok(number(*value)),
+        ValueKind::Bool(value) => Ok(value.to_string()),
+        _ => Err(Error::new(
+            format!("{path}: expected a string, number or boolean"),
+            value.span,
+        )),
+    }
+}
+
+fn string(text: &str) -> String {
+    format!("'{}'", text.replace('\'', "''"))
+}
+
+fn number(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        format!("{}", value as i64)
+    } else {
+        value.to_string()
+    }
+}
+
+/// Quotes a name unless Cassandra would read it the same unquoted. Unquoted
+/// names are case-insensitive, so `userID` has to be written `"userID"`.
+fn identifier(name: &str) -> String {
+    let plain = name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if plain && !RESERVED_KEYWORDS.contains(&name) {
+        name.to_string()
+    } else {
+        format!("\"{}\"", name.replace('"', "\"\""))
+    }
+}
+
+fn qualified(keyspace: Option<&str>, name: &str) -> String {
+    match keyspace {
+        Some(keyspace) => format!("{}.{}", identifier(keyspace), identifier(name)),
+        None => identifier(name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USERS: &str = r#"
+export const Users = await client.loadSchema('users', {
+  fields: {
+    tenant: 'uuid',
+    id: 'timeuuid',
+    email: 'varchar',
+    userID: 'int',
+    tags: { type: 'set', typeDef: '<text>' },
+    info: { type: 'map', typeDef: '<text, frozen<list<int>>>' },
+    full_name: { type: 'text', virtual: true },
+    createdAt: 'timestamp'
+  },
+  key: [['tenant', 'userID'], 'id', 'email'],
+  clustering_order: { email: 'desc' },
+  indexes: ['tags', 'keys(info)'],
+  materialized_views: {
+    users_by_email: {
+      select: ['email', 'id'],
+      key: ['email', 'tenant', 'userID', 'id'],
+      clustering_order: { id: 'DESC' },
+      filters: { email: { $gte: 'a', $isnt: null }, id: { $isnt: null } }
+    }
+  },
+  options: {
+    timestamps: true,
+    versions: { key: 'version' },
+    compaction: { class: 'LeveledCompactionStrategy', sstable_size_in_mb: 160 },
+    caching: { keys: 'ALL', rows_per_partition: 'NONE' },
+    gc_grace_seconds: 864000,
+    default_time_to_live: 0,
+    comment: "Users' accounts"
+  }
+});
+"#;
+
+    fn generate(source: &str, keyspace: Option<&str>) -> Vec<Statement> {
+        let model = schema::find_models(source).remove(0);
+        statements(&model.name.value, &model.schema.unwrap(), keyspace).unwrap()
+    }
+
+    #[test]
+    fn tables_are_created_with_keys_and_options() {
+        let statements = generate(USERS, Some("myapp"));
+        assert_eq!(statements[0].label, "CREATE TABLE users");
+        assert_eq!(
+            statements[0].text,
+            r#"CREATE TABLE IF NOT EXISTS myapp.users (
+  tenant uuid,
+  id timeuuid,
+  email text,
+  "userID" int,
+  tags set<text>,
+  info map<text, frozen<list<int>>>,
+  "createdAt" timestamp,
+  "updatedAt" timestamp,
+  version timeuuid,
+  PRIMARY KEY ((tenant, "userID"), id, email)
+) WITH CLUSTERING ORDER BY (id ASC, email DESC)
+  AND compaction = {'class': 'LeveledCompactionStrategy', 'sstable_size_in_mb': 160}
+  AND caching = {'keys': 'ALL', 'rows_per_partition': 'NONE'}
+  AND gc_grace_seconds = 864000
+  AND default_time_to_live = 0
+  AND comment = 'Users'' accounts';"#
+        );
+    }
+
+    #[test]
+    fn indexes_and_views_follow_the_table() {
+        let statements = generate(USERS, None);
+        let labels = statements
+            .iter()
+            .map(|statement| statement.label.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            labels,
+            [
+                "CREATE TABLE users",
+                "CREATE INDEX users_tags_idx",
+                "CREATE INDEX users_keys_info_idx",
+                "CREATE MATERIALIZED VIEW users_by_email",
+            ]
+        );
+        assert_eq!(
+            statements[1].text,
+            "CREATE INDEX IF NOT EXISTS users_tags_idx ON users (tags);"
+        );
+        assert_eq!(
+            statements[2].text,
+            "CREATE INDEX IF NOT EXISTS users_keys_info_idx ON users (KEYS(info));"
+        );
+        assert_eq!(
+            statements[3].text,
+            r#"CREATE MATERIALIZED VIEW IF NOT EXISTS users_by_email AS
+  SELECT email, id FROM users
+  WHERE email IS NOT NULL
+    AND tenant IS NOT NULL
+    AND "userID" IS NOT NULL
+    AND id IS NOT NULL
+    AND email >= 'a'
+  PRIMARY KEY (email, tenant, "userID", id)
+  WITH CLUSTERING ORDER BY (tenant ASC, "userID" ASC, id DESC);"#
+        );
+    }
+
+    #[test]
+    fn named_indexes_keep_their_name_and_options() {
+        let source = "{
+  fields: { id: 'uuid', name: 'text', bio: 'text' },
+  key: 'id',
+  table_name: 'people',
+  indexes: {
+    people_name: { target: 'name', options: { using: 'org.apache.cassandra.index.sasi.SASIIndex', mode: 'CONTAINS' } },
+    people_text: { target: ['name', 'bio'], options: { using: 'sai' } }
+  }
+}";
+        let schema = schema::parse_schema(source, 0).unwrap();
+        let statements = statements("person", &schema, None).unwrap();
+        let texts = statements
+            .iter()
+            .map(|statement| statement.text.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            texts,
+            [
+                "CREATE TABLE IF NOT EXISTS people (\n  id uuid,\n  name text,\n  bio text,\n  PRIMARY KEY (id)\n);",
+                "CREATE CUSTOM INDEX IF NOT EXISTS people_name ON people (name) USING 'org.apache.cassandra.index.sasi.SASIIndex' WITH OPTIONS = {'mode': 'CONTAINS'};",
+                "CREATE INDEX IF NOT EXISTS people_text_name ON people (name) USING 'sai';",
+                "CREATE INDEX IF NOT EXISTS people_text_bio ON people (bio) USING 'sai';",
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_types_and_options_are_reported() {
+        let cases = [
+            ("{ fields: { id: 'uuid' } }", "missing `key`"),
+            (
+                "{ fields: { id: 'uuid', tags: 'list<list<int>>' }, key: 'id' }",
+                "fields.tags: collections inside collections must be frozen, as in `frozen<list<int>>`",
+            ),
+            (
+                "{ fields: { id: 'uuid', tags: { type: 'lst', typeDef: '<int>' } }, key: 'id' }",
+                "fields.tags: unknown type `lst`",
+            ),
+            (
+                "{ fields: { id: 'uuid' }, key: 'id', options: { compaction: { class: Strategy } } }",
+                "options.compaction.class: expected a string, number or boolean",
+            ),
+        ];
+        for (source, message) in cases {
+            let schema = schema::parse_schema(source, 0).unwrap();
+            let err = statements("t", &schema, None).unwrap_err();
+            assert_eq!(err.message, message, "{source}");
+        }
+    }
+
+    #[test]
+    fn the_slash_command_finds_models_in_the_usual_files() {
+        let read_file = |path: &str| (path == "src/models/user.ts").then(|| USERS.to_string());
+        let output =
+            run_slash_command(&["users".to_string()], Some("myapp"), &[], read_file).unwrap();
+        assert!(output
+            .text
+            .starts_with("```cql\nCREATE TABLE IF NOT EXISTS myapp.users (\n"));
+        assert!(output.text.ends_with(";\n```\n"));
+        assert_eq!(output.sections.len(), 4);
+        let section = &output.sections[1];
+        assert_eq!(section.label, "CREATE INDEX users_tags_idx");
+        assert_eq!(
+            &output.text[section.range.start as usize..section.range.end as usize],
+            "CREATE INDEX IF NOT EXISTS users_tags_idx ON myapp.users (tags);"
+        );
+
+        let err = run_slash_command(&["posts".to_string()], None, &[], read_file).unwrap_err();
+        assert!(err.starts_with("no model named `posts`"), "{err}");
+    }
+
+    #[test]
+    fn the_slash_command_reads_an_explicit_file_and_reports_locations() {
+        let source = "import { client } from './db';\n\nclient.loadSchema('events', {\n  fields: { day: 'date' },\n  key: 'day'\n});\n";
+        let read_file = |path: &str| (path == "db/events.ts").then(|| source.to_string());
+        let args = ["events".to_string(), "db/events.ts".to_string()];
+        let output = run_slash_command(&args, None, &[], read_file).unwrap();
+        assert!(output.text.contains("  day date,\n"), "{}", output.text);
+
+        let source = source.replace("'date'", "'list<lst<int>>'");
+        let read_file = |path: &str| (path == "db/events.ts").then(|| source.clone());
+        let err = run_slash_command(&args, None, &[], read_file).unwrap_err();
+        assert_eq!(err, "db/events.ts:4:24: unknown type `lst`");
+
+        let source = source.replace("key: 'day'", "key: ['id', 'day']");
+        let read_file = |path: &str| (path == "db/events.ts").then(|| source.clone());
+        let err = run_slash_command(&args, None, &[], read_file).unwrap_err();
+        assert_eq!(
+            err,
+            "db/events.ts:4:24: unknown type `lst`\n\
+             db/events.ts:5:10: `id` isn't a field of this model"
+        );
+
+        // Escapes make the type string shorter than its source, so the
+        // error covers the whole string rather than drifting.
+        let source = source.replace("'list<lst<int>>'", r"'\tlst<int>é'");
+        let read_file = |path: &str| (path == "db/events.ts").then(|| source.clone());
+        let err = run_slash_command(&args, None, &[], read_file).unwrap_err();
+        assert!(
+            err.starts_with("db/events.ts:4:19: unknown type `lst`"),
+            "{err}"
+        );
+
+        // User-defined types come from the config and the model's file, and
+        // others are only noted since they may be declared elsewhere.
+        let source = source
+            .replace(r"'\tlst<int>é'", "'frozen<address>'")
+            .replace("key: ['id', 'day']", "key: 'day'");
+        let read_file = |path: &str| (path == "db/events.ts").then(|| source.clone());
+        let output = run_slash_command(&args, None, &[], read_file).unwrap();
+        assert!(
+            output.text.contains("  day frozen<address>,\n"),
+            "{}",
+            output.text
+        );
+        assert!(
+            output.text.ends_with(
+                "```\n\nNote: db/events.ts:4:26: unknown type `address`; \
+                 declare user-defined types in `ormOptions.udts`\n"
+            ),
+            "{}",
+            output.text
+        );
+        let output = run_slash_command(&args, None, &["address".to_string()], read_file).unwrap();
+        assert!(output.text.ends_with("```\n"), "{}", output.text);
+        let source =
+            format!("createClient({{ ormOptions: {{ udts: {{ address: {{}} }} }} }});\n{source}");
+        let read_file = |path: &str| (path == "db/events.ts").then(|| source.clone());
+        let output = run_slash_command(&args, None, &[], read_file).unwrap();
+        assert!(output.text.ends_with("```\n"), "{}", output.text);
+
+        let err = run_slash_command(&[], None, &[], |_| None).unwrap_err();
+        assert_eq!(err, "usage: /cql-ddl <model> [file]");
+    }
+
+    #[test]
+    fn line_column_counts_characters() {
+        let source = "a\nbé c";
+        assert_eq!(line_column(source, 0), (1, 1));
+        assert_eq!(line_column(source, source.find('c').unwrap()), (2, 4));
+        // Inside `é`.
+        assert_eq!(line_column(source, 4), (2, 2));
+        assert_eq!(line_column(source, 99), (2, 5));
+    }
+
+    #[test]
+    fn candidate_files_start_with_the_generated_model_path() {
+        let files = candidate_files("users");
+        assert_eq!(files[0], "src/models/users.cassandra.ts");
+        assert!(files.contains(&"src/models/user.ts".to_string()));
+        assert!(files.contains(&"users.cassandra.js".to_string()));
+    }
+}
@@ -0,0 +1,288 @@
+// Generates the CQL that creates a model's table, secondary indexes and
+// materialized views, ported from the extension's `ddl.rs` for the server's
+// "Generate CQL DDL" code action.
+//
+// The statements follow what express-cassandra, which the ORM's schemas come
+// from, would create: index names default to `<table>_<column>_idx`, and
+// materialized views require every key column to be `IS NOT NULL`.
+
+import { CqlType, formatType, validateType } from './cql-type';
+import { Property, SchemaError, Value } from './literal';
+import {
+    ClusteringOrder, IndexDefinition, MaterializedViewDefinition, ModelSchema, PrimaryKey, field, generatedColumns,
+    isStored, parseFieldType, primaryKeyColumns, tableName
+} from './schema';
+
+// Words CQL reserves, which have to be quoted when used as names
+const reservedKeywords = [
+    'add', 'allow', 'alter', 'and', 'apply', 'asc', 'authorize', 'batch', 'begin', 'by', 'columnfamily', 'create',
+    'delete', 'desc', 'describe', 'drop', 'entries', 'execute', 'from', 'full', 'grant', 'if', 'in', 'index',
+    'infinity', 'insert', 'into', 'is', 'keyspace', 'limit', 'materialized', 'modify', 'nan', 'norecursive', 'not',
+    'null', 'of', 'on', 'or', 'order', 'primary', 'rename', 'replace', 'revoke', 'schema', 'select', 'set', 'table',
+    'to', 'token', 'truncate', 'unlogged', 'update', 'use', 'using', 'view', 'where', 'with'
+];
+
+// A generated statement, with a label such as `CREATE TABLE users`
+export interface Statement {
+    label: string;
+    text: string;
+}
+
+// Generates the statements creating the model named `name`: its table, then
+// its indexes and materialized views. Throws a `SchemaError` for a schema the
+// statements can't be written for.
+export function statements(
+    name: string,
+    schema: ModelSchema,
+    keyspace: string | undefined
+): Statement[] {
+    const table = tableName(schema, name);
+    const result = [createTable(table, schema, keyspace)];
+    for (const index of schema.indexes) {
+        result.push(...createIndexes(table, index, keyspace));
+    }
+    for (const view of schema.materializedViews) {
+        result.push(createView(table, view, keyspace));
+    }
+    return result;
+}
+
+function createTable(
+    table: string,
+    schema: ModelSchema,
+    keyspace: string | undefined
+): Statement {
+    const key = schema.key;
+    if (!key) {
+        throw new SchemaError('missing `key`', schema.span);
+    }
+
+    const definitions: string[] = [];
+    for (const definition of schema.fields) {
+        if (!isStored(definition)) {
+            continue;
+        }
+        const inField = (err: SchemaError) =>
+            new SchemaError(`fields.${definition.name.value}: ${err.message}`, err.span);
+        let ty: CqlType;
+        try {
+            ty = parseFieldType(definition);
+        } catch (err) {
+            throw err instanceof SchemaError ? inField(err) : err;
+        }
+        const [err] = validateType(ty);
+        if (err) {
+            throw inField(err);
+        }
+        definitions.push(`${identifier(definition.name.value)} ${formatType(ty)}`);
+    }
+    for (const [column, ty] of generatedColumns(schema)) {
+        if (!field(schema, column)) {
+            definitions.push(`${identifier(column)} ${ty}`);
+        }
+    }
+    definitions.push(`PRIMARY KEY ${primaryKey(key)}`);
+
+    let text = `CREATE TABLE IF NOT EXISTS ${qualified(keyspace, table)} (\n  ${definitions.join(',\n  ')}\n)`;
+    const properties: string[] = [];
+    const order = clusteringOrder(key, schema.clusteringOrder);
+    if (order) {
+        properties.push(order);
+    }
+    const options = schema.options;
+    if (options) {
+        for (const [property, value] of [
+            ['compaction', options.compaction],
+            ['compression', options.compression],
+            ['caching', options.caching]
+        ] as const) {
+            if (value) {
+                properties.push(`${property} = ${map(value.value, `options.${property}`)}`);
+            }
+        }
+        if (options.gcGraceSeconds) {
+            properties.push(`gc_grace_seconds = ${number(options.gcGraceSeconds.value)}`);
+        }
+        if (options.defaultTimeToLive) {
+            properties.push(`default_time_to_live = ${number(options.defaultTimeToLive.value)}`);
+        }
+        if (options.bloomFilterFpChance) {
+            properties.push(`bloom_filter_fp_chance = ${number(options.bloomFilterFpChance.value)}`);
+        }
+        if (options.comment) {
+            properties.push(`comment = ${string(options.comment.value)}`);
+        }
+    }
+    text += withProperties(properties, ' WITH ') + ';';
+    return { label: `CREATE TABLE ${table}`, text };
+}
+
+// Formats `((tenant, id), created_at)`, or `(id, created_at)` when the
+// partition key has a single column.
+function primaryKey(key: PrimaryKey): string {
+    const partition = key.partition.map(column => identifier(column.value)).join(', ');
+    const columns = [
+        key.partition.length === 1 ? partition : `(${partition})`,
+        ...key.clustering.map(column => identifier(column.value))
+    ];
+    return `(${columns.join(', ')})`;
+}
+
+// Lists every clustering column in key order, as Cassandra requires, taking
+// the direction from `clustering_order` and defaulting to `ASC`.
+function clusteringOrder(key: PrimaryKey, orders: ClusteringOrder[]): string | undefined {
+    if (orders.length === 0 || key.clustering.length === 0) {
+        return undefined;
+    }
+    const columns = key.clustering.map(column => {
+        const order = [...orders].reverse().find(o => o.column.value === column.value)?.order.value ?? 'asc';
+        return `${identifier(column.value)} ${order.toUpperCase()}`;
+    });
+    return `CLUSTERING ORDER BY (${columns.join(', ')})`;
+}
+
+// Formats table properties, the first after `first` and the rest on lines of
+// their own.
+function withProperties(properties: string[], first: string): string {
+    return properties.map((property, i) => (i === 0 ? first : '\n  AND ') + property).join('');
+}
+
+// Creates one index per target. Indexes listed as bare columns are named
+// `<table>_<column>_idx`, and a `using` option makes a custom or SAI index.
+function createIndexes(table: string, index: IndexDefinition, keyspace: string | undefined): Statement[] {
+    const path = index.name ? `indexes.${index.name.value}` : 'indexes';
+    let className: string | undefined;
+    const options: Property[] = [];
+    for (const option of index.options) {
+        if (option.key.value === 'using' && option.value.kind === 'string') {
+            className = option.value.value;
+        } else {
+            options.push(option);
+        }
+    }
+
+    return index.target.map(target => {
+        const suffix = target.value.replace(/[^A-Za-z0-9]/g, '_').replace(/^_+|_+$/g, '');
+        let name = `${table}_${suffix}_idx`;
+        if (index.name) {
+            name = index.target.length === 1 ? index.name.value : `${index.name.value}_${suffix}`;
+        }
+        const custom = className?.includes('.') ?? false;
+        let text = `CREATE ${custom ? 'CUSTOM ' : ''}INDEX IF NOT EXISTS ${identifier(name)} ON ${
+            qualified(keyspace, table)} (${indexTarget(target.value)})`;
+        if (className !== undefined) {
+            text += ` USING ${string(className)}`;
+        }
+        if (options.length > 0) {
+            text += ` WITH OPTIONS = ${map(options, `${path}.options`)}`;
+        }
+        return { label: `CREATE INDEX ${name}`, text: text + ';' };
+    });
+}
+
+// Formats an index target: a column, or `keys(info)`, `values(info)`,
+// `entries(info)` and `full(info)` for collections.
+function indexTarget(target: string): string {
+    const call = /^([^(]*)\((.*)\)$/s.exec(target);
+    if (call && ['keys', 'values', 'entries', 'full'].includes(call[1].trim().toLowerCase())) {
+        return `${call[1].trim().toUpperCase()}(${identifier(call[2].trim())})`;
+    }
+    return identifier(target);
+}
+
+function createView(table: string, view: MaterializedViewDefinition, keyspace: string | undefined): Statement {
+    const path = `materialized_views.${view.name.value}`;
+    const select = view.select.length === 1 && view.select[0].value === '*'
+        ? '*'
+        : view.select.map(column => identifier(column.value)).join(', ');
+
+    const conditions = primaryKeyColumns(view.key).map(column => `${identifier(column.value)} IS NOT NULL`);
+    if (view.filters) {
+        for (const condition of viewConditions(view.filters, `${path}.filters`)) {
+            if (!conditions.includes(condition)) {
+                conditions.push(condition);
+            }
+        }
+    }
+
+    let text = `CREATE MATERIALIZED VIEW IF NOT EXISTS ${qualified(keyspace, view.name.value)} AS\n`
+        + `  SELECT ${select} FROM ${qualified(keyspace, table)}\n`
+        + `  WHERE ${conditions.join('\n    AND ')}\n`
+        + `  PRIMARY KEY ${primaryKey(view.key)}`;
+    const order = clusteringOrder(view.key, view.clusteringOrder);
+    text += withProperties(order ? [order] : [], '\n  WITH ') + ';';
+    return { label: `CREATE MATERIALIZED VIEW ${view.name.value}`, text };
+}
+
+const operators: Record<string, string> = { $eq: '=', $gt: '>', $gte: '>=', $lt: '<', $lte: '<=' };
+
+// Translates express-cassandra view filters such as
+// `{ age: { $gte: 18, $isnt: null } }` into `WHERE` conditions.
+function viewConditions(filters: Value, path: string): string[] {
+    if (filters.kind !== 'object') {
+        throw new SchemaError(`${path}: expected an object`, filters.span);
+    }
+    const conditions: string[] = [];
+    for (const column of filters.properties) {
+        const name = identifier(column.key.value);
+        const columnPath = `${path}.${column.key.value}`;
+        if (column.value.kind !== 'object') {
+            conditions.push(`${name} = ${literal(column.value, columnPath)}`);
+            continue;
+        }
+        for (const operator of column.value.properties) {
+            const op = operator.key.value;
+            const operatorPath = `${columnPath}.${op}`;
+            const value = operator.value;
+            if (op === '$isnt' && value.kind === 'null') {
+                conditions.push(`${name} IS NOT NULL`);
+            } else if (op === '$in' && value.kind === 'array') {
+                conditions.push(`${name} IN (${value.items.map(item => literal(item, operatorPath)).join(', ')})`);
+            } else if (op in operators) {
+                conditions.push(`${name} ${operators[op]} ${literal(value, operatorPath)}`);
+            } else {
+                throw new SchemaError(
+                    `${operatorPath}: materialized views can't filter with \`${op}\``,
+                    column.value.span
+                );
+            }
+        }
+    }
+    return conditions;
+}
+
+// Formats a map of options such as `{'class': 'LeveledCompactionStrategy'}`.
+function map(properties: Property[], path: string): string {
+    const entries = properties.map(property =>
+        `${string(property.key.value)}: ${literal(property.value, `${path}.${property.key.value}`)}`);
+    return `{${entries.join(', ')}}`;
+}
+
+function literal(value: Value, path: string): string {
+    switch (value.kind) {
+        case 'string': return string(value.value);
+        case 'number': return number(value.value);
+        case 'bool': return String(value.value);
+        default: throw new SchemaError(`${path}: expected a string, number or boolean`, value.span);
+    }
+}
+
+function string(text: string): string {
+    return `'${text.replace(/'/g, "''")}'`;
+}
+
+function number(value: number): string {
+    return Number.isInteger(value) && Math.abs(value) < 1e15 ? value.toFixed(0) : String(value);
+}
+
+// Quotes a name unless Cassandra would read it the same unquoted. Unquoted
+// names are case-insensitive, so `userID` has to be written `"userID"`.
+function identifier(name: string): string {
+    return /^[a-z][a-z0-9_]*$/.test(name) && !reservedKeywords.includes(name)
+        ? name
+        : `"${name.replace(/"/g, '""')}"`;
+}
+
+function qualified(keyspace: string | undefined, name: string): string {
+    return keyspace === undefined ? identifier(name) : `${identifier(keyspace)}.${identifier(name)}`;
+}
@@ -0,0 +1,319 @@
+//! JSON helpers for reading project files: comment stripping for JSONC and
+//! deserialization errors that name the offending key.
+
+use serde::de::{
+    self, value::StrDeserializer, DeserializeOwned, DeserializeSeed, Deserializer, MapAccess,
+    SeqAccess, Visitor,
+};
+use serde_json::Value;
+use std::fmt;
+
+/// A deserialization error together with the key path it occurred at, such as
+/// `clientOptions.contactPoints[1]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    /// Path segments, innermost first; indices are stored as `[n]`.
+    segments: Vec<String>,
+    message: String,
+}
+
+impl Error {
+    /// Returns the dotted key path, or an empty string for the document root.
+    pub fn path(&self) -> String {
+        let mut path = String::new();
+        for segment in self.segments.iter().rev() {
+            if !path.is_empty() && !segment.starts_with('[') {
+                path.push('.');
+            }
+            path.push_str(segment);
+        }
+        path
+    }
+
+    fn at(mut self, segment: impl Into<String>) -> Self {
+        self.segments.push(segment.into());
+        self
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.path().as_str() {
+            "" => write!(f, "{}", self.message),
+            path => write!(f, "{path}: {}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl de::Error for Error {
+    fn custom<T: fmt::Display>(message: T) -> Self {
+        Self {
+            segments: Vec::new(),
+            message: message.to_string(),
+        }
+    }
+}
+
+/// Deserializes `value` into `T`, reporting the key path of the first error.
+pub fn from_value<T: DeserializeOwned>(value: &Value) -> Result<T, Error> {
+    T::deserialize(ValueDeserializer(value))
+}
+
+/// Blanks out `//` and `/* */` comments and trailing commas so the text can be
+/// parsed as plain JSON. Line and column numbers are preserved.
+pub fn strip_jsonc(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            output.push(c);
+            match c {
+                '\\' => output.extend(chars.next()),
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match (c, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+                output.push(c);
+            }
+            ('/', Some('/')) => {
+                output.push(' ');
+                while let Some(&next) = chars.peek() {
+                    if next == '\n' {
+                        break;
+                    }
+                    output.push(' ');
+                    chars.next();
+                }
+            }
+            ('/', Some('*')) => {
+                output.push(' ');
+                let mut previous = None;
+                for next in chars.by_ref() {
+                    output.push(if next == '\n' { '\n' } else { ' ' });
+                    if previous == Some('*') && next == '/' {
+                        break;
+                    }
+                    previous = Some(next);
+                }
+            }
+            _ => output.push(c),
+        }
+    }
+
+    remove_trailing_commas(output)
+}
+
+fn remove_trailing_commas(text: String) -> String {
+    let mut bytes = text.into_bytes();
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut last_comma = None;
+
+    for ix in 0..bytes.len() {
+        let byte = bytes[ix];
+        if in_string {
+            match (escaped, byte) {
+                (true, _) => escaped = false,
+                (false, b'\\') => escaped = true,
+                (false, b'"') => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match byte {
+            b'"' => {
+                in_string = true;
+                last_comma = None;
+            }
+            b',' => last_comma = Some(ix),
+            b'}' | b']' => {
+                if let Some(comma) = last_comma.take() {
+                    bytes[comma] = b' ';
+                }
+            }
+            byte if byte.is_ascii_whitespace() => {}
+            _ => last_comma = None,
+        }
+    }
+
+    // Only ASCII commas were replaced by spaces, so the text is still UTF-8.
+    String::from_utf8(bytes).unwrap_or_default()
+}
+
+struct ValueDeserializer<'a>(&'a Value);
+
+impl<'de> Deserializer<'de> for ValueDeserializer<'_> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.0 {
+            Value::Null => visitor.visit_unit(),
+            Value::Bool(value) => visitor.visit_bool(*value),
+            Value::Number(number) => {
+                if let Some(value) = number.as_u64() {
+                    visitor.visit_u64(value)
+                } else if let Some(value) = number.as_i64() {
+                    visitor.visit_i64(value)
+                } else {
+                    visitor.visit_f64(number.as_f64().unwrap_or(f64::NAN))
+                }
+            }
+            Value::String(value) => visitor.visit_str(value),
+            Value::Array(items) => visitor.visit_seq(SeqDeserializer {
+                items: items.iter().enumerate(),
+            }),
+            Value::Object(entries) => visitor.visit_map(MapDeserializer {
+                entries: entries.iter(),
+                pending: None,
+            }),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.0 {
+            Value::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match self.0 {
+            Value::String(variant) => visitor.visit_enum(StrDeserializer::<Error>::new(variant)),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+struct SeqDeserializer<'a, I: Iterator<Item = (usize, &'a Value)>> {
+    items: I,
+}
+
+impl<'de, 'a, I: Iterator<Item = (usize, &'a Value)>> SeqAccess<'de> for SeqDeserializer<'a, I> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        let Some((ix, item)) = self.items.next() else {
+            return Ok(None);
+        };
+        seed.deserialize(ValueDeserializer(item))
+            .map(Some)
+            .map_err(|err| err.at(format!("[{ix}]")))
+    }
+}
+
+struct MapDeserializer<'a, I: Iterator<Item = (&'a String, &'a Value)>> {
+    entries: I,
+    pending: Option<(&'a String, &'a Value)>,
+}
+
+impl<'de, 'a, I: Iterator<Item = (&'a String, &'a Value)>> MapAccess<'de>
+    for MapDeserializer<'a, I>
+{
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        let Some((key, value)) = self.entries.next() else {
+            return Ok(None);
+        };
+        self.pending = Some((key, value));
+        seed.deserialize(StrDeserializer::<Error>::new(key))
+            .map(Some)
+            .map_err(|err| err.at(key.as_str()))
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        let (key, value) = self
+            .pending
+            .take()
+            .ok_or_else(|| <Error as de::Error>::custom("value requested before key"))?;
+        seed.deserialize(ValueDeserializer(value))
+            .map_err(|err| err.at(key.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    #[allow(dead_code)]
+    struct Outer {
+        inner: Inner,
+        #[serde(default)]
+        hosts: Vec<String>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    #[allow(dead_code)]
+    struct Inner {
+        port: u16,
+    }
+
+    #[test]
+    fn errors_name_the_nested_key() {
+        let err = from_value::<Outer>(&json!({ "inner": { "port": "9042" } })).unwrap_err();
+        assert_eq!(err.path(), "inner.port");
+
+        let err = from_value::<Outer>(&json!({ "inner": { "port": 1, "host": "x" } })).unwrap_err();
+        assert_eq!(err.path(), "inner.host");
+        assert!(err.to_string().contains("unknown field `host`"), "{err}");
+
+        let err =
+            from_value::<Outer>(&json!({ "inner": { "port": 1 }, "hosts": ["a", 2] })).unwrap_err();
+        assert_eq!(err.path(), "hosts[1]");
+    }
+
+    #[test]
+    fn strips_comments_and_trailing_commas() {
+        let text = r#"{
+            // keyspace used by the dev cluster
+            "keyspace": "app", /* "//" inside a comment */
+            "url": "http://localhost",
+            "points": ["a", "b",],
+        }"#;
+        let value: Value = serde_json::from_str(&strip_jsonc(text)).unwrap();
+        assert_eq!(
+            value,
+            json!({ "keyspace": "app", "url": "http://localhost", "points": ["a", "b"] })
+        );
+        assert_eq!(strip_jsonc(text).lines().count(), text.lines().count());
+    }
+}
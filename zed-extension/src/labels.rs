@@ -0,0 +1,409 @@
+use zed_extension_api::{
+    lsp::{Completion, CompletionKind, Symbol, SymbolKind},
+    CodeLabel, CodeLabelSpan,
+};
+
+/// CQL types that `highlights.scm` of the CQL language marks as builtin.
+const BUILTIN_TYPES: &[&str] = &[
+    "ascii",
+    "bigint",
+    "blob",
+    "boolean",
+    "counter",
+    "date",
+    "decimal",
+    "double",
+    "duration",
+    "float",
+    "inet",
+    "int",
+    "smallint",
+    "text",
+    "time",
+    "timestamp",
+    "timeuuid",
+    "tinyint",
+    "uuid",
+    "varchar",
+    "varint",
+    "frozen",
+    "list",
+    "map",
+    "set",
+    "tuple",
+    "vector",
+];
+
+/// Builds the label for a workspace symbol reported by `cassandraorm-lsp`.
+///
+/// The server names models `users`, fields `users.email: text`, indexes
+/// `users.email_idx` and materialized views `users_by_email`. Only the
+/// qualified name is used for filtering, so searching for a type doesn't match
+/// every field that has it.
+pub fn symbol_label(symbol: &Symbol) -> Option<CodeLabel> {
+    let mut label = Label::default();
+    match symbol.kind {
+        SymbolKind::Class => {
+            label.push(&symbol.name, Some("type"));
+            label.filter_all();
+        }
+        SymbolKind::Interface => {
+            label.push(&symbol.name, Some("type"));
+            label.filter_all();
+            label.push(" (materialized view)", Some("comment"));
+        }
+        SymbolKind::Field | SymbolKind::Key => {
+            let (name, cql_type) = match symbol.name.split_once(": ") {
+                Some((name, cql_type)) => (name, Some(cql_type)),
+                None => (symbol.name.as_str(), None),
+            };
+            let (model, member) = name.split_once('.')?;
+            label.push(model, Some("type"));
+            label.push(".", Some("punctuation.delimiter"));
+            label.push(member, Some("property"));
+            label.filter_all();
+            if let Some(cql_type) = cql_type {
+                label.push(": ", Some("punctuation.delimiter"));
+                label.push(cql_type, Some("type"));
+            }
+        }
+        _ => return None,
+    }
+    Some(label.into())
+}
+
+/// Builds the label for a completion offered by `cassandraorm-lsp`.
+///
+/// Fields are shown with their CQL type (`email  text`), colored like the CQL
+/// language colors it. Query methods show their signature and snippets their
+/// description. Only the completion's own label is used for filtering.
+pub fn completion_label(completion: &Completion) -> Option<CodeLabel> {
+    let details = completion.label_details.as_ref();
+    CompletionParts {
+        kind: completion.kind?,
+        label: &completion.label,
+        detail: completion.detail.as_deref(),
+        signature: details.and_then(|details| details.detail.as_deref()),
+        description: details
+            .and_then(|details| details.description.as_deref())
+            .filter(|description| !description.is_empty()),
+    }
+    .label()
+}
+
+/// The parts of a completion its label is built from. `signature` and
+/// `description` come from the LSP `labelDetails`, which the extension API
+/// doesn't export a constructor for.
+struct CompletionParts<'a> {
+    kind: CompletionKind,
+    label: &'a str,
+    detail: Option<&'a str>,
+    signature: Option<&'a str>,
+    description: Option<&'a str>,
+}
+
+impl CompletionParts<'_> {
+    fn label(&self) -> Option<CodeLabel> {
+        let mut label = Label::default();
+        match self.kind {
+            CompletionKind::Class => {
+                label.push(self.label, Some("type"));
+                label.filter_all();
+                label.push(" model", Some("comment"));
+            }
+            CompletionKind::Field => {
+                label.push(self.label, Some("property"));
+                label.filter_all();
+                if let Some(cql_type) = self.detail {
+                    label.push("  ", None);
+                    label.push_cql_type(cql_type);
+                }
+                if let Some(model) = self.description {
+                    label.push(" ", None);
+                    label.push(model, Some("comment"));
+                }
+            }
+            CompletionKind::Method | CompletionKind::Function => {
+                label.push(self.label, Some("function.method"));
+                label.filter_all();
+                if let Some(parameters) = self.signature {
+                    label.push_signature(parameters);
+                }
+                if let Some(returns) = self.description {
+                    label.push(": ", Some("punctuation.delimiter"));
+                    label.push(returns, Some("type"));
+                }
+            }
+            CompletionKind::Snippet => {
+                label.push(self.label, Some("keyword"));
+                label.filter_all();
+                if let Some(detail) = self.detail {
+                    label.push(" ", None);
+                    label.push(detail, Some("comment"));
+                }
+            }
+            _ => return None,
+        }
+        Some(label.into())
+    }
+}
+
+/// A label made of literal spans, tracking how much of it is filterable.
+#[derive(Default)]
+struct Label {
+    spans: Vec<CodeLabelSpan>,
+    len: usize,
+    filter_len: usize,
+}
+
+impl Label {
+    fn push(&mut self, text: &str, highlight: Option<&str>) {
+        self.len += text.len();
+        self.spans
+            .push(CodeLabelSpan::literal(text, highlight.map(str::to_string)));
+    }
+
+    /// Pushes a CQL type such as `frozen<map<text, list<int>>>`, one span per
+    /// token, using the captures of the CQL language's `highlights.scm`.
+    fn push_cql_type(&mut self, cql_type: &str) {
+        for token in tokens(cql_type) {
+            let highlight = match token.chars().next() {
+                Some('<' | '>') => Some("punctuation.bracket"),
+                Some(',') => Some("punctuation.delimiter"),
+                Some(c) if c.is_ascii_digit() => Some("number"),
+                Some(c) if c.is_whitespace() => None,
+                _ if BUILTIN_TYPES.contains(&token.to_ascii_lowercase().as_str()) => {
+                    Some("type.builtin")
+                }
+                _ => Some("type"),
+            };
+            self.push(token, highlight);
+        }
+    }
+
+    /// Pushes a TypeScript parameter list such as `(query?: FindQuery)`.
+    fn push_signature(&mut self, signature: &str) {
+        let mut in_type = false;
+        for token in tokens(signature) {
+            let highlight = match token.chars().next() {
+                Some('(' | ')' | '<' | '>' | '[' | ']' | '{' | '}') => Some("punctuation.bracket"),
+                Some(',') => {
+                    in_type = false;
+                    Some("punctuation.delimiter")
+                }
+                Some(':') => {
+                    in_type = true;
+                    Some("punctuation.delimiter")
+                }
+                Some('?' | '|' | '=') => Some("operator"),
+                Some(c) if c.is_whitespace() || c == '.' => None,
+                _ if in_type => Some("type"),
+                _ => Some("variable.parameter"),
+            };
+            self.push(token, highlight);
+        }
+    }
+
+    /// Makes everything pushed so far part of the filter range.
+    fn filter_all(&mut self) {
+        self.filter_len = self.len;
+    }
+}
+
+/// Splits text into runs of word characters, runs of whitespace and single
+/// punctuation characters.
+fn tokens(text: &str) -> impl Iterator<Item = &str> {
+    let class = |c: char| {
+        if c.is_alphanumeric() || c == '_' {
+            0
+        } else if c.is_whitespace() {
+            1
+        } else {
+            2
+        }
+    };
+    let mut rest = text;
+    std::iter::from_fn(move || {
+        let first = rest.chars().next()?;
+        let end = match class(first) {
+            2 => first.len_utf8(),
+            kind => rest.find(|c: char| class(c) != kind).unwrap_or(rest.len()),
+        };
+        let (token, tail) = rest.split_at(end);
+        rest = tail;
+        Some(token)
+    })
+}
+
+impl From<Label> for CodeLabel {
+    fn from(label: Label) -> Self {
+        CodeLabel {
+            code: String::new(),
+            spans: label.spans,
+            filter_range: (0..label.filter_len).into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn symbol(kind: SymbolKind, name: &str) -> Symbol {
+        Symbol {
+            kind,
+            name: name.to_string(),
+        }
+    }
+
+    fn completion<'a>(
+        kind: CompletionKind,
+        label: &'a str,
+        detail: Option<&'a str>,
+    ) -> CompletionParts<'a> {
+        CompletionParts {
+            kind,
+            label,
+            detail,
+            signature: None,
+            description: None,
+        }
+    }
+
+    /// Returns the highlight of the span showing `text`.
+    fn highlight<'a>(label: &'a CodeLabel, text: &str) -> Option<&'a str> {
+        label.spans.iter().find_map(|span| match span {
+            CodeLabelSpan::Literal(literal) if literal.text == text => {
+                Some(literal.highlight_name.as_deref())
+            }
+            _ => None,
+        })?
+    }
+
+    /// Returns the displayed text of a label.
+    fn text(label: &CodeLabel) -> String {
+        label
+            .spans
+            .iter()
+            .map(|span| match span {
+                CodeLabelSpan::Literal(literal) => literal.text.as_str(),
+                CodeLabelSpan::CodeRange(range) => {
+                    &label.code[range.start as usize..range.end as usize]
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn fields_show_the_model_and_cql_type() {
+        let label = symbol_label(&symbol(SymbolKind::Field, "users.email: text")).unwrap();
+        assert_eq!(text(&label), "users.email: text");
+        assert_eq!(
+            (label.filter_range.start, label.filter_range.end),
+            (0, "users.email".len() as u32)
+        );
+
+        let label = symbol_label(&symbol(SymbolKind::Field, "users.tags: set<text>")).unwrap();
+        assert_eq!(text(&label), "users.tags: set<text>");
+
+        let label = symbol_label(&symbol(SymbolKind::Key, "users.email_idx")).unwrap();
+        assert_eq!(text(&label), "users.email_idx");
+        assert_eq!(label.filter_range.end, "users.email_idx".len() as u32);
+    }
+
+    #[test]
+    fn models_and_views_are_labelled() {
+        let label = symbol_label(&symbol(SymbolKind::Class, "users")).unwrap();
+        assert_eq!(text(&label), "users");
+
+        let label = symbol_label(&symbol(SymbolKind::Interface, "users_by_email")).unwrap();
+        assert_eq!(text(&label), "users_by_email (materialized view)");
+        assert_eq!(label.filter_range.end, "users_by_email".len() as u32);
+    }
+
+    #[test]
+    fn other_symbols_keep_the_default_label() {
+        assert!(symbol_label(&symbol(SymbolKind::Function, "connect")).is_none());
+        assert!(symbol_label(&symbol(SymbolKind::Field, "email")).is_none());
+    }
+
+    #[test]
+    fn field_completions_show_a_highlighted_cql_type() {
+        let field = CompletionParts {
+            description: Some("users"),
+            ..completion(
+                CompletionKind::Field,
+                "scores",
+                Some("frozen<map<text, list<int>>>"),
+            )
+        };
+        let label = field.label().unwrap();
+        assert_eq!(text(&label), "scores  frozen<map<text, list<int>>> users");
+        assert_eq!(label.filter_range.end, "scores".len() as u32);
+        assert_eq!(highlight(&label, "scores"), Some("property"));
+        assert_eq!(highlight(&label, "frozen"), Some("type.builtin"));
+        assert_eq!(highlight(&label, "<"), Some("punctuation.bracket"));
+        assert_eq!(highlight(&label, ","), Some("punctuation.delimiter"));
+        assert_eq!(highlight(&label, "users"), Some("comment"));
+
+        let label = completion(
+            CompletionKind::Field,
+            "embedding",
+            Some("vector<float, 1536>"),
+        )
+        .label()
+        .unwrap();
+        assert_eq!(highlight(&label, "1536"), Some("number"));
+
+        let label = completion(CompletionKind::Field, "home", Some("address"))
+            .label()
+            .unwrap();
+        assert_eq!(text(&label), "home  address");
+        assert_eq!(highlight(&label, "address"), Some("type"));
+    }
+
+    #[test]
+    fn method_completions_show_their_signature() {
+        let find = CompletionParts {
+            signature: Some("(query?: FindQuery, options?: QueryOptions)"),
+            description: Some("Promise<T[]>"),
+            ..completion(CompletionKind::Method, "find", None)
+        };
+        let label = find.label().unwrap();
+        assert_eq!(
+            text(&label),
+            "find(query?: FindQuery, options?: QueryOptions): Promise<T[]>"
+        );
+        assert_eq!(label.filter_range.end, "find".len() as u32);
+        assert_eq!(highlight(&label, "find"), Some("function.method"));
+        assert_eq!(highlight(&label, "query"), Some("variable.parameter"));
+        assert_eq!(highlight(&label, "FindQuery"), Some("type"));
+        assert_eq!(highlight(&label, "Promise<T[]>"), Some("type"));
+    }
+
+    #[test]
+    fn models_and_snippets_are_labelled() {
+        let label = completion(CompletionKind::Class, "users", None)
+            .label()
+            .unwrap();
+        assert_eq!(text(&label), "users model");
+        assert_eq!(label.filter_range.end, "users".len() as u32);
+
+        let label = completion(
+            CompletionKind::Snippet,
+            "createEnhancedClient",
+            Some("Create CassandraORM enhanced client"),
+        )
+        .label()
+        .unwrap();
+        assert_eq!(
+            text(&label),
+            "createEnhancedClient Create CassandraORM enhanced client"
+        );
+        assert_eq!(highlight(&label, "createEnhancedClient"), Some("keyword"));
+
+        assert!(completion(CompletionKind::Keyword, "SELECT", None)
+            .label()
+            .is_none());
+    }
+}
@@ -1,26 +1,235 @@
-use zed_extension_api::{self as zed, Result};
+mod analysis;
+mod compat;
+mod config;
+mod counters;
+mod cql_type;
+mod ddl;
+mod json;
+mod labels;
+mod literal;
+#[cfg(test)]
+mod manifest;
+mod node;
+mod npm;
+#[cfg(test)]
+mod queries;
+mod schema;
+mod server;
+#[cfg(test)]
+mod snippets;
+mod workspace;
 
-struct CassandraOrmExtension;
+use compat::ServerChoice;
+use npm::NpmServer;
+use server::ServerBinary;
+use zed_extension_api::{self as zed, settings::LspSettings, Result};
+
+/// The CassandraORM language server built from `src/lsp-server.ts`.
+const CASSANDRAORM_SERVER_ID: &str = "cassandraorm-lsp";
+/// The TypeScript server that backs the "CassandraORM Schema" language.
+const TYPESCRIPT_SERVER_ID: &str = "cassandraorm-typescript";
+
+const CASSANDRAORM_SERVER_BINARY: &str = "cassandraorm-lsp";
+const TYPESCRIPT_SERVER_BINARY: &str = "typescript-language-server";
+
+/// The assistant slash command that shows the CQL creating a model.
+const CQL_DDL_COMMAND: &str = "cql-ddl";
+/// The assistant slash command that moves a model's counters to a companion
+/// model.
+const CQL_COUNTERS_COMMAND: &str = "cql-counters";
+
+struct CassandraOrmExtension {
+    cassandraorm_server: NpmServer,
+    typescript_server: NpmServer,
+}
+
+impl CassandraOrmExtension {
+    /// Prefers the project's own copy of the server and falls back to the one
+    /// installed into the extension's working directory.
+    fn server_binary(
+        server: &mut NpmServer,
+        binary_name: &str,
+        language_server_id: &zed::LanguageServerId,
+        worktree: &zed::Worktree,
+    ) -> Result<ServerBinary> {
+        match server::locate_node_bin(worktree, binary_name) {
+            Ok(binary) => Ok(binary),
+            Err(lookup_err) => server
+                .entry_point(language_server_id)
+                .map(ServerBinary::NodeScript)
+                .map_err(|install_err| {
+                    format!("{lookup_err}\nautomatic installation failed: {install_err}")
+                }),
+        }
+    }
+
+    /// Checks the project's `cassandraorm-js` version against the versions the
+    /// server supports. Projects without the ORM get the latest server.
+    fn server_choice(worktree: &zed::Worktree) -> ServerChoice {
+        compat::installed_orm_version(|name| worktree.read_text_file(name).ok())
+            .map_or(ServerChoice::Latest, |orm| compat::server_choice(&orm))
+    }
+}
 
 impl zed::Extension for CassandraOrmExtension {
     fn new() -> Self {
-        Self
+        Self {
+            cassandraorm_server: NpmServer::new(
+                CASSANDRAORM_SERVER_BINARY,
+                &[],
+                "node_modules/cassandraorm-lsp/dist/lsp-server.js",
+            ),
+            typescript_server: NpmServer::new(
+                TYPESCRIPT_SERVER_BINARY,
+                &["typescript"],
+                "node_modules/typescript-language-server/lib/cli.mjs",
+            ),
+        }
     }
 
     fn language_server_command(
         &mut self,
-        _language_server_id: &zed::LanguageServerId,
-        _worktree: &zed::Worktree,
+        language_server_id: &zed::LanguageServerId,
+        worktree: &zed::Worktree,
     ) -> Result<zed::Command> {
+        let binary_settings = LspSettings::for_worktree(language_server_id.as_ref(), worktree)
+            .ok()
+            .and_then(|settings| settings.binary);
+
+        let configured_path = binary_settings
+            .as_ref()
+            .and_then(|binary| binary.path.clone());
+        let binary = match (configured_path, language_server_id.as_ref()) {
+            (Some(path), _) => ServerBinary::from_configured_path(path),
+            (None, CASSANDRAORM_SERVER_ID) => Self::server_binary(
+                &mut self.cassandraorm_server,
+                CASSANDRAORM_SERVER_BINARY,
+                language_server_id,
+                worktree,
+            )?,
+            (None, TYPESCRIPT_SERVER_ID) => Self::server_binary(
+                &mut self.typescript_server,
+                TYPESCRIPT_SERVER_BINARY,
+                language_server_id,
+                worktree,
+            )?,
+            (None, id) => return Err(format!("unknown language server: {id}")),
+        };
+
+        // Executables found on the `$PATH` are often version manager shims
+        // that need the worktree's shell environment to resolve.
+        let (command, mut args, default_env) = match binary {
+            ServerBinary::NodeScript(path) => (
+                node::node_binary(language_server_id.as_ref(), worktree)?,
+                vec![path],
+                Vec::new(),
+            ),
+            ServerBinary::Executable(path) => (path, Vec::new(), worktree.shell_env()),
+        };
+
+        let (arguments, env) = binary_settings
+            .map(|binary| (binary.arguments, binary.env))
+            .unwrap_or_default();
+        args.extend(arguments.unwrap_or_else(|| vec!["--stdio".to_string()]));
+
         Ok(zed::Command {
-            command: "node".to_string(),
-            args: vec![
-                "node_modules/.bin/typescript-language-server".to_string(),
-                "--stdio".to_string(),
-            ],
-            env: Default::default(),
+            command,
+            args,
+            env: server::merge_env(default_env, env.unwrap_or_default()),
         })
     }
+
+    fn language_server_initialization_options(
+        &mut self,
+        language_server_id: &zed::LanguageServerId,
+        worktree: &zed::Worktree,
+    ) -> Result<Option<zed::serde_json::Value>> {
+        let user_options = LspSettings::for_worktree(language_server_id.as_ref(), worktree)
+            .ok()
+            .and_then(|settings| settings.initialization_options);
+        if language_server_id.as_ref() != CASSANDRAORM_SERVER_ID {
+            return Ok(user_options);
+        }
+
+        // An invalid config mustn't keep the server from starting; it shows
+        // the error instead.
+        let project = config::read_project_config(worktree);
+        let compatibility_warning = match Self::server_choice(worktree) {
+            ServerChoice::Unsupported(warning) => Some(warning),
+            ServerChoice::Latest => None,
+        };
+        Ok(Some(config::initialization_options(
+            &worktree.root_path(),
+            &project,
+            compatibility_warning.as_deref(),
+            user_options,
+        )))
+    }
+
+    fn language_server_workspace_configuration(
+        &mut self,
+        language_server_id: &zed::LanguageServerId,
+        worktree: &zed::Worktree,
+    ) -> Result<Option<zed::serde_json::Value>> {
+        let settings = LspSettings::for_worktree(language_server_id.as_ref(), worktree)
+            .ok()
+            .and_then(|settings| settings.settings);
+        if language_server_id.as_ref() != CASSANDRAORM_SERVER_ID {
+            return Ok(settings);
+        }
+
+        Ok(Some(config::workspace_configuration(settings.as_ref())))
+    }
+
+    fn label_for_completion(
+        &self,
+        language_server_id: &zed::LanguageServerId,
+        completion: zed::lsp::Completion,
+    ) -> Option<zed::CodeLabel> {
+        if language_server_id.as_ref() != CASSANDRAORM_SERVER_ID {
+            return None;
+        }
+        labels::completion_label(&completion)
+    }
+
+    fn label_for_symbol(
+        &self,
+        language_server_id: &zed::LanguageServerId,
+        symbol: zed::lsp::Symbol,
+    ) -> Option<zed::CodeLabel> {
+        if language_server_id.as_ref() != CASSANDRAORM_SERVER_ID {
+            return None;
+        }
+        labels::symbol_label(&symbol)
+    }
+
+    fn run_slash_command(
+        &self,
+        command: zed::SlashCommand,
+        args: Vec<String>,
+        worktree: Option<&zed::Worktree>,
+    ) -> Result<zed::SlashCommandOutput> {
+        if ![CQL_DDL_COMMAND, CQL_COUNTERS_COMMAND].contains(&command.name.as_str()) {
+            return Err(format!("unknown slash command: {}", command.name));
+        }
+        let worktree = worktree
+            .ok_or_else(|| format!("/{} needs a project to look the model up in", command.name))?;
+        let read_file = |path: &str| worktree.read_text_file(path).ok();
+        if command.name == CQL_COUNTERS_COMMAND {
+            return counters::run_slash_command(&args, read_file);
+        }
+        // The DDL is still useful unqualified when the config can't be read.
+        let project = config::read_project_config(worktree).ok().flatten();
+        let keyspace = project
+            .as_ref()
+            .and_then(|project| project.config.client_options.keyspace.clone());
+        let user_types = project
+            .and_then(|project| project.config.orm_options)
+            .and_then(|options| options.udts)
+            .map(|udts| udts.keys().cloned().collect::<Vec<_>>())
+            .unwrap_or_default();
+        ddl::run_slash_command(&args, keyspace.as_deref(), &user_types, read_file)
+    }
 }
 
 zed::register_extension!(CassandraOrmExtension);
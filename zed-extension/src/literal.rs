@@ -0,0 +1,905 @@
+//! A parser for the JavaScript object literals schemas are written as.
+//!
+//! Only literal values are parsed: objects, arrays, strings, numbers, booleans,
+//! `null` and identifiers. Anything else (functions, calls, regexes, template
+//! strings with substitutions) is skipped over and kept as an opaque
+//! [`ValueKind::Expression`], which is all a schema needs for `default`,
+//! `validate.custom` and hooks. TypeScript's `as const`, `satisfies T` and `!`
+//! are dropped. Every value keeps the byte range it was parsed from.
+
+use std::fmt;
+
+/// A byte range in the parsed source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    /// Shrinks the span by `n` bytes on each side, such as a string's quotes.
+    pub fn shrink(self, n: usize) -> Self {
+        let start = (self.start + n).min(self.end);
+        Self::new(start, self.end.saturating_sub(n).max(start))
+    }
+
+    /// Returns the span covering both `self` and `other`.
+    pub fn to(self, other: Span) -> Self {
+        Self::new(self.start.min(other.start), self.end.max(other.end))
+    }
+}
+
+/// A value together with the span it was parsed from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Spanned<T> {
+    pub value: T,
+    pub span: Span,
+}
+
+impl<T> Spanned<T> {
+    pub fn new(value: T, span: Span) -> Self {
+        Self { value, span }
+    }
+}
+
+/// A parsed value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Value {
+    pub kind: ValueKind,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValueKind {
+    String(String),
+    Number(f64),
+    Bool(bool),
+    /// `null` or `undefined`.
+    Null,
+    Object(Vec<Property>),
+    Array(Vec<Value>),
+    /// A reference such as `userSchema` or `Types.uuid`.
+    Identifier(String),
+    /// Any other expression, such as `() => new Date()`.
+    Expression,
+}
+
+impl Value {
+    pub fn as_str(&self) -> Option<&str> {
+        match &self.kind {
+            ValueKind::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_properties(&self) -> Option<&[Property]> {
+        match &self.kind {
+            ValueKind::Object(properties) => Some(properties),
+            _ => None,
+        }
+    }
+
+    /// Returns the last property named `key`, which is the one JavaScript
+    /// keeps when a key is repeated.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.as_properties()?
+            .iter()
+            .rev()
+            .find(|property| property.key.value == key)
+            .map(|property| &property.value)
+    }
+}
+
+/// An object property. Shorthand properties (`{ email }`) get an
+/// [`ValueKind::Identifier`] value and methods an [`ValueKind::Expression`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Property {
+    pub key: Spanned<String>,
+    pub value: Value,
+}
+
+impl Property {
+    pub fn span(&self) -> Span {
+        self.key.span.to(self.value.span)
+    }
+}
+
+/// A syntax error, or a value of the wrong shape.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    pub message: String,
+    pub span: Span,
+}
+
+impl Error {
+    pub fn new(message: impl Into<String>, span: Span) -> Self {
+        Self {
+            message: message.into(),
+            span,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Parses the value starting at byte `offset` of `source`. Text after the
+/// value is not looked at.
+pub fn parse_value(source: &str, offset: usize) -> Result<Value, Error> {
+    Parser::new(source, offset).value()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenKind {
+    Identifier(String),
+    String(String),
+    /// A template string, with its text if it has no substitutions.
+    Template(Option<String>),
+    Number(f64),
+    Regex,
+    /// `=>`, `...` and `?.`, or a single punctuation character.
+    Punct(&'static str),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub span: Span,
+}
+
+impl Token {
+    pub fn is_punct(&self, punct: &str) -> bool {
+        matches!(self.kind, TokenKind::Punct(p) if p == punct)
+    }
+
+    pub fn is_identifier(&self, name: &str) -> bool {
+        matches!(&self.kind, TokenKind::Identifier(n) if n == name)
+    }
+}
+
+const PUNCTUATION: &[&str] = &[
+    "=>", "...", "?.", "{", "}", "(", ")", "[", "]", ";", ",", "<", ">", "+", "-", "*", "/", "%",
+    "&", "|", "^", "!", "~", "?", ":", "=", ".", "@", "#",
+];
+
+/// Splits JavaScript source into tokens, skipping whitespace and comments.
+pub struct Lexer<'a> {
+    source: &'a str,
+    pos: usize,
+    /// Whether a `/` starts a regex rather than a division.
+    regex_allowed: bool,
+    /// The text of each comment skipped so far, without its delimiters.
+    comments: Vec<Span>,
+}
+
+impl<'a> Lexer<'a> {
+    pub fn new(source: &'a str, offset: usize) -> Self {
+        Self {
+            source,
+            pos: offset,
+            regex_allowed: true,
+            comments: Vec::new(),
+        }
+    }
+
+    /// The comments skipped so far, without their `//`, `/*` and `*/`.
+    pub fn comments(&self) -> &[Span] {
+        &self.comments
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.source[self.pos..]
+    }
+
+    fn peek_char(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek_char()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn error(&self, message: &str, start: usize) -> Error {
+        Error::new(message, Span::new(start, self.pos))
+    }
+
+    fn skip_trivia(&mut self) -> Result<(), Error> {
+        loop {
+            let rest = self.rest();
+            if rest.starts_with("//") {
+                let end = self.pos + rest.find('\n').unwrap_or(rest.len());
+                self.comments.push(Span::new(self.pos + 2, end));
+                self.pos = end;
+            } else if let Some(comment) = rest.strip_prefix("/*") {
+                let end = comment
+                    .find("*/")
+                    .ok_or_else(|| self.error("unterminated comment", self.pos))?;
+                self.comments
+                    .push(Span::new(self.pos + 2, self.pos + 2 + end));
+                self.pos += end + 4;
+            } else if self.peek_char().is_some_and(char::is_whitespace) {
+                self.bump();
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    pub fn next_token(&mut self) -> Result<Option<Token>, Error> {
+        self.skip_trivia()?;
+        let start = self.pos;
+        let Some(c) = self.peek_char() else {
+            return Ok(None);
+        };
+        let kind = match c {
+            '\'' | '"' => TokenKind::String(self.string(c)?),
+            '`' => TokenKind::Template(self.template()?),
+            '/' if self.regex_allowed => {
+                self.regex()?;
+                TokenKind::Regex
+            }
+            '0'..='9' => TokenKind::Number(self.number()),
+            '.' if self.rest()[1..].starts_with(|c: char| c.is_ascii_digit()) => {
+                TokenKind::Number(self.number())
+            }
+            c if is_identifier_start(c) => {
+                while self.peek_char().is_some_and(is_identifier_char) {
+                    self.bump();
+                }
+                TokenKind::Identifier(self.source[start..self.pos].to_string())
+            }
+            _ => {
+                let punct = PUNCTUATION
+                    .iter()
+                    .find(|punct| self.rest().starts_with(**punct))
+                    .ok_or_else(|| self.error(&format!("unexpected character {c:?}"), start))?;
+                self.pos += punct.len();
+                TokenKind::Punct(punct)
+            }
+        };
+        self.regex_allowed = match &kind {
+            TokenKind::Punct(punct) => !matches!(*punct, ")" | "]" | "}"),
+            TokenKind::Identifier(name) => matches!(
+                name.as_str(),
+                "return" | "typeof" | "case" | "in" | "of" | "new" | "delete" | "void" | "yield"
+            ),
+            _ => false,
+        };
+        Ok(Some(Token {
+            kind,
+            span: Span::new(start, self.pos),
+        }))
+    }
+
+    fn string(&mut self, quote: char) -> Result<String, Error> {
+        let start = self.pos;
+        self.bump();
+        let mut value = String::new();
+        loop {
+            match self.bump() {
+                None | Some('\n') => return Err(self.error("unterminated string", start)),
+                Some('\\') => self.escape(&mut value),
+                Some(c) if c == quote => return Ok(value),
+                Some(c) => value.push(c),
+            }
+        }
+    }
+
+    fn escape(&mut self, value: &mut String) {
+        let Some(c) = self.bump() else {
+            return;
+        };
+        let code = |lexer: &mut Self, len: usize| {
+            let digits = lexer.rest().get(..len)?;
+            let code = u32::from_str_radix(digits, 16).ok()?;
+            lexer.pos += len;
+            char::from_u32(code)
+        };
+        match c {
+            'n' => value.push('\n'),
+            't' => value.push('\t'),
+            'r' => value.push('\r'),
+            'b' => value.push('\u{8}'),
+            'f' => value.push('\u{c}'),
+            'v' => value.push('\u{b}'),
+            '0' => value.push('\0'),
+            '\n' => {}
+            'x' => value.extend(code(self, 2)),
+            'u' if self.rest().starts_with('{') => {
+                let len = self.rest().find('}').unwrap_or(0);
+                let code = u32::from_str_radix(&self.rest()[1..len.max(1)], 16).ok();
+                self.pos += len + 1;
+                value.extend(code.and_then(char::from_u32));
+            }
+            'u' => value.extend(code(self, 4)),
+            c => value.push(c),
+        }
+    }
+
+    /// Lexes a template string, returning its text if it has no
+    /// substitutions. Substitutions are skipped token by token so that braces
+    /// and backticks inside them don't end the template early.
+    fn template(&mut self) -> Result<Option<String>, Error> {
+        let start = self.pos;
+        self.bump();
+        let mut value = Some(String::new());
+        loop {
+            match self.bump() {
+                None => return Err(self.error("unterminated template string", start)),
+                Some('`') => return Ok(value),
+                Some('\\') => {
+                    let mut escaped = String::new();
+                    self.escape(&mut escaped);
+                    if let Some(value) = &mut value {
+                        value.push_str(&escaped);
+                    }
+                }
+                Some('$') if self.peek_char() == Some('{') => {
+                    self.bump();
+                    value = None;
+                    self.regex_allowed = true;
+                    let mut depth = 0;
+                    loop {
+                        let token = self
+                            .next_token()?
+                            .ok_or_else(|| self.error("unterminated template string", start))?;
+                        match token.kind {
+                            TokenKind::Punct("{") => depth += 1,
+                            TokenKind::Punct("}") if depth == 0 => break,
+                            TokenKind::Punct("}") => depth -= 1,
+                            _ => {}
+                        }
+                    }
+                }
+                Some(c) => {
+                    if let Some(value) = &mut value {
+                        value.push(c);
+                    }
+                }
+            }
+        }
+    }
+
+    fn regex(&mut self) -> Result<(), Error> {
+        let start = self.pos;
+        self.bump();
+        let mut in_class = false;
+        loop {
+            match self.bump() {
+                None | Some('\n') => return Err(self.error("unterminated regex", start)),
+                Some('\\') => {
+                    self.bump();
+                }
+                Some('[') => in_class = true,
+                Some(']') => in_class = false,
+                Some('/') if !in_class => break,
+                Some(_) => {}
+            }
+        }
+        while self.peek_char().is_some_and(is_identifier_char) {
+            self.bump();
+        }
+        Ok(())
+    }
+
+    fn number(&mut self) -> f64 {
+        let start = self.pos;
+        while self
+            .peek_char()
+            .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+            || (self.source[start..self.pos].ends_with(['e', 'E'])
+                && !self.source[start..].starts_with("0x")
+                && self.peek_char().is_some_and(|c| c == '+' || c == '-'))
+        {
+            self.bump();
+        }
+        let text = self.source[start..self.pos]
+            .replace('_', "")
+            .trim_end_matches('n')
+            .to_ascii_lowercase();
+        let radix = |prefix: &str, radix: u32| {
+            text.strip_prefix(prefix)
+                .and_then(|digits| i64::from_str_radix(digits, radix).ok())
+                .map(|n| n as f64)
+        };
+        radix("0x", 16)
+            .or_else(|| radix("0o", 8))
+            .or_else(|| radix("0b", 2))
+            .or_else(|| text.parse().ok())
+            .unwrap_or(f64::NAN)
+    }
+}
+
+fn is_identifier_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_' || c == '$'
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$'
+}
+
+struct Parser<'a> {
+    lexer: Lexer<'a>,
+    peeked: Option<Option<Token>>,
+    /// The end of the last token taken.
+    end: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(source: &'a str, offset: usize) -> Self {
+        Self {
+            lexer: Lexer::new(source, offset),
+            peeked: None,
+            end: offset,
+        }
+    }
+
+    fn peek(&mut self) -> Result<Option<&Token>, Error> {
+        if self.peeked.is_none() {
+            self.peeked = Some(self.lexer.next_token()?);
+        }
+        Ok(self.peeked.as_ref().unwrap().as_ref())
+    }
+
+    fn next(&mut self) -> Result<Option<Token>, Error> {
+        self.peek()?;
+        let token = self.peeked.take().unwrap();
+        if let Some(token) = &token {
+            self.end = token.span.end;
+        }
+        Ok(token)
+    }
+
+    fn peek_is(&mut self, punct: &str) -> Result<bool, Error> {
+        Ok(self.peek()?.is_some_and(|token| token.is_punct(punct)))
+    }
+
+    fn eat(&mut self, punct: &str) -> Result<bool, Error> {
+        let found = self.peek_is(punct)?;
+        if found {
+            self.next()?;
+        }
+        Ok(found)
+    }
+
+    fn expect(&mut self, punct: &str) -> Result<Token, Error> {
+        match self.next()? {
+            Some(token) if token.is_punct(punct) => Ok(token),
+            Some(token) => Err(Error::new(format!("expected `{punct}`"), token.span)),
+            None => Err(Error::new(
+                format!("expected `{punct}`"),
+                Span::new(self.end, self.end),
+            )),
+        }
+    }
+
+    /// Whether the next token ends the current value.
+    fn at_terminator(&mut self) -> Result<bool, Error> {
+        Ok(match self.peek()? {
+            None => true,
+            Some(token) => [",", "}", "]", ")", ";"]
+                .iter()
+                .any(|punct| token.is_punct(punct)),
+        })
+    }
+
+    fn value(&mut self) -> Result<Value, Error> {
+        let Some(token) = self.peek()?.cloned() else {
+            return Err(Error::new(
+                "expected a value",
+                Span::new(self.end, self.end),
+            ));
+        };
+        let start = token.span.start;
+        let value = match token.kind {
+            TokenKind::Punct("{") => self.object()?,
+            TokenKind::Punct("[") => self.array()?,
+            TokenKind::String(value) => self.literal(ValueKind::String(value))?,
+            TokenKind::Template(Some(value)) => self.literal(ValueKind::String(value))?,
+            TokenKind::Number(value) => self.literal(ValueKind::Number(value))?,
+            TokenKind::Punct("-") => {
+                self.next()?;
+                match self.peek()?.map(|token| &token.kind) {
+                    Some(&TokenKind::Number(value)) => {
+                        self.next()?;
+                        Value {
+                            kind: ValueKind::Number(-value),
+                            span: Span::new(start, self.end),
+                        }
+                    }
+                    _ => return self.opaque(start),
+                }
+            }
+            TokenKind::Identifier(name) => match name.as_str() {
+                "true" | "false" => self.literal(ValueKind::Bool(name == "true"))?,
+                "null" | "undefined" => self.literal(ValueKind::Null)?,
+                "function" | "async" | "new" | "class" | "typeof" | "void" | "await" => {
+                    return self.opaque(start)
+                }
+                _ => self.identifier()?,
+            },
+            _ => return self.opaque(start),
+        };
+
+        // Type assertions and non-null assertions don't change the value.
+        while let Some(token) = self.peek()? {
+            if token.is_identifier("as") || token.is_identifier("satisfies") {
+                self.next()?;
+                self.skip_expression(true)?;
+            } else if token.is_punct("!") {
+                self.next()?;
+            } else {
+                break;
+            }
+        }
+        // An operator, call or arrow turns the value into an expression.
+        if self.at_terminator()? {
+            return Ok(value);
+        }
+        match self.peek()? {
+            Some(Token {
+                kind: TokenKind::Punct(_),
+                ..
+            }) => self.opaque(start),
+            Some(token) => Err(Error::new("expected `,`", token.span)),
+            None => Ok(value),
+        }
+    }
+
+    fn literal(&mut self, kind: ValueKind) -> Result<Value, Error> {
+        let token = self.next()?.unwrap();
+        Ok(Value {
+            kind,
+            span: token.span,
+        })
+    }
+
+    /// Parses `name` or a member path such as `Types.uuid`.
+    fn identifier(&mut self) -> Result<Value, Error> {
+        let first = self.next()?.unwrap();
+        let TokenKind::Identifier(mut path) = first.kind else {
+            unreachable!("identifier() is only called on identifiers")
+        };
+        while self.peek_is(".")? || self.peek_is("?.")? {
+            let dot = self.next()?.unwrap();
+            match self.peek()?.map(|token| &token.kind) {
+                Some(TokenKind::Identifier(name)) => {
+                    path.push('.');
+                    path.push_str(name);
+                    self.next()?;
+                }
+                _ => return Err(Error::new("expected a property name", dot.span)),
+            }
+        }
+        Ok(Value {
+            kind: ValueKind::Identifier(path),
+            span: Span::new(first.span.start, self.end),
+        })
+    }
+
+    /// Skips the rest of an expression starting at `start`.
+    fn opaque(&mut self, start: usize) -> Result<Value, Error> {
+        self.skip_expression(false)?;
+        if self.end <= start {
+            let span = self.peek()?.map_or(Span::new(start, start), |t| t.span);
+            return Err(Error::new("expected a value", span));
+        }
+        Ok(Value {
+            kind: ValueKind::Expression,
+            span: Span::new(start, self.end),
+        })
+    }
+
+    /// Skips tokens up to the end of the current value, keeping track of
+    /// brackets. Type arguments like `Record<string, number>` contain commas,
+    /// so `angles` also tracks `<` and `>` when skipping a type.
+    fn skip_expression(&mut self, angles: bool) -> Result<(), Error> {
+        let mut depth = 0usize;
+        loop {
+            if depth == 0 && self.at_terminator()? {
+                return Ok(());
+            }
+            let Some(token) = self.next()? else {
+                return Ok(());
+            };
+            match token.kind {
+                TokenKind::Punct("(" | "[" | "{") => depth += 1,
+                TokenKind::Punct("<") if angles => depth += 1,
+                TokenKind::Punct(")" | "]" | "}") => {
+                    depth = depth
+                        .checked_sub(1)
+                        .ok_or_else(|| Error::new("unbalanced bracket", token.span))?
+                }
+                TokenKind::Punct(">") if angles && depth > 0 => depth -= 1,
+                _ => {}
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<Value, Error> {
+        let open = self.expect("{")?;
+        let mut properties = Vec::new();
+        while !self.eat("}")? {
+            if self.eat("...")? {
+                self.skip_expression(false)?;
+            } else {
+                properties.push(self.property()?);
+            }
+            if !self.eat(",")? {
+                self.expect("}")?;
+                break;
+            }
+        }
+        Ok(Value {
+            kind: ValueKind::Object(properties),
+            span: Span::new(open.span.start, self.end),
+        })
+    }
+
+    fn property(&mut self) -> Result<Property, Error> {
+        let mut key = self.property_key()?;
+
+        // `async name() {}`, `get name() {}` and `*name() {}` are methods.
+        let modifier = matches!(key.value.as_str(), "async" | "get" | "set" | "static" | "*");
+        if modifier && !self.peek_is(":")? && !self.peek_is("(")? && !self.at_terminator()? {
+            key = self.property_key()?;
+        }
+
+        let value = if self.eat(":")? {
+            self.value()?
+        } else if self.peek_is("(")? || self.peek_is("<")? {
+            let start = self.peek()?.unwrap().span.start;
+            self.skip_method()?;
+            Value {
+                kind: ValueKind::Expression,
+                span: Span::new(start, self.end),
+            }
+        } else if self.at_terminator()? {
+            Value {
+                kind: ValueKind::Identifier(key.value.clone()),
+                span: key.span,
+            }
+        } else {
+            let span = self.peek()?.unwrap().span;
+            return Err(Error::new("expected `:`", span));
+        };
+        Ok(Property { key, value })
+    }
+
+    fn property_key(&mut self) -> Result<Spanned<String>, Error> {
+        let token = self
+            .next()?
+            .ok_or_else(|| Error::new("expected a property", Span::new(self.end, self.end)))?;
+        let name = match token.kind {
+            TokenKind::Identifier(name) | TokenKind::String(name) => name,
+            TokenKind::Template(Some(name)) => name,
+            TokenKind::Number(value) => value.to_string(),
+            TokenKind::Punct("*") => "*".to_string(),
+            TokenKind::Punct("[") => {
+                self.skip_expression(false)?;
+                self.expect("]")?;
+                let span = Span::new(token.span.start, self.end);
+                let text = &self.lexer.source[span.start..span.end];
+                return Ok(Spanned::new(text.to_string(), span));
+            }
+            _ => return Err(Error::new("expected a property", token.span)),
+        };
+        Ok(Spanned::new(name, token.span))
+    }
+
+    /// Skips a method's parameters, return type and body.
+    fn skip_method(&mut self) -> Result<(), Error> {
+        let mut depth = 0usize;
+        loop {
+            let token = self
+                .next()?
+                .ok_or_else(|| Error::new("unterminated method", Span::new(self.end, self.end)))?;
+            match token.kind {
+                TokenKind::Punct("(" | "[" | "<") => depth += 1,
+                TokenKind::Punct(")" | "]" | ">") => depth = depth.saturating_sub(1),
+                TokenKind::Punct("{") if depth == 0 => {
+                    let mut braces = 1usize;
+                    while braces > 0 {
+                        let token = self.next()?.ok_or_else(|| {
+                            Error::new("unterminated method", Span::new(self.end, self.end))
+                        })?;
+                        match token.kind {
+                            TokenKind::Punct("{") => braces += 1,
+                            TokenKind::Punct("}") => braces -= 1,
+                            _ => {}
+                        }
+                    }
+                    return Ok(());
+                }
+                TokenKind::Punct("{") => depth += 1,
+                TokenKind::Punct("}") => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Value, Error> {
+        let open = self.expect("[")?;
+        let mut items = Vec::new();
+        loop {
+            if self.eat("]")? {
+                break;
+            }
+            if self.eat(",")? {
+                continue;
+            }
+            if self.peek_is("...")? {
+                let start = self.next()?.unwrap().span.start;
+                items.push(self.opaque(start)?);
+            } else {
+                items.push(self.value()?);
+            }
+            if !self.eat(",")? {
+                self.expect("]")?;
+                break;
+            }
+        }
+        Ok(Value {
+            kind: ValueKind::Array(items),
+            span: Span::new(open.span.start, self.end),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(source: &str) -> Value {
+        parse_value(source, 0).unwrap_or_else(|err| panic!("{err} at {:?}", err.span))
+    }
+
+    fn text(source: &str, span: Span) -> &str {
+        &source[span.start..span.end]
+    }
+
+    #[test]
+    fn literals_are_parsed_with_their_spans() {
+        let source = r#"{ id: 'uuid', "count": 42, ok: true, none: null, neg: -1.5e3, hex: 0xff, list: [1, , 'a',], }"#;
+        let value = parse(source);
+        assert_eq!(value.span, Span::new(0, source.len()));
+        assert_eq!(value.get("id").unwrap().as_str(), Some("uuid"));
+        assert_eq!(text(source, value.get("id").unwrap().span), "'uuid'");
+        assert_eq!(value.get("count").unwrap().kind, ValueKind::Number(42.0));
+        assert_eq!(value.get("ok").unwrap().kind, ValueKind::Bool(true));
+        assert_eq!(value.get("none").unwrap().kind, ValueKind::Null);
+        assert_eq!(value.get("neg").unwrap().kind, ValueKind::Number(-1500.0));
+        assert_eq!(value.get("hex").unwrap().kind, ValueKind::Number(255.0));
+        let ValueKind::Array(items) = &value.get("list").unwrap().kind else {
+            panic!("list is an array");
+        };
+        assert_eq!(items.len(), 2);
+
+        let key = &value.as_properties().unwrap()[1].key;
+        assert_eq!(
+            (key.value.as_str(), text(source, key.span)),
+            ("count", "\"count\"")
+        );
+    }
+
+    #[test]
+    fn strings_are_unescaped() {
+        let value = parse(
+            r#"['it\'s', "a\tb", `multi
+line`, 'A\u{1F600}\x42']"#,
+        );
+        let ValueKind::Array(items) = value.kind else {
+            panic!("expected an array");
+        };
+        let strings = items
+            .iter()
+            .map(|item| item.as_str().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(strings, ["it's", "a\tb", "multi\nline", "A😀B"]);
+    }
+
+    #[test]
+    fn code_is_kept_as_opaque_expressions() {
+        let source = r#"{
+            default: () => new Date(),
+            uuid: Uuid.random(),
+            pattern: /^[a-z\/]+$/i,
+            custom: (value) => value.length > 2 && value !== '}',
+            label: `${first} ${last}`,
+            compute(a, b) { return { a, b }; },
+            async load() { await x; },
+            ref: Types.uuid,
+            n: total / count,
+            after: 'still parsed',
+        }"#;
+        let value = parse(source);
+        for key in [
+            "default", "uuid", "pattern", "custom", "label", "compute", "load", "n",
+        ] {
+            assert_eq!(value.get(key).unwrap().kind, ValueKind::Expression, "{key}");
+        }
+        assert_eq!(
+            text(source, value.get("default").unwrap().span),
+            "() => new Date()"
+        );
+        assert_eq!(
+            text(source, value.get("compute").unwrap().span),
+            "(a, b) { return { a, b }; }"
+        );
+        assert_eq!(
+            value.get("ref").unwrap().kind,
+            ValueKind::Identifier("Types.uuid".to_string())
+        );
+        assert_eq!(value.get("after").unwrap().as_str(), Some("still parsed"));
+    }
+
+    #[test]
+    fn typescript_assertions_keep_the_value() {
+        let source = "{ order: 'DESC' as const, opts: { a: 1 } satisfies Record<string, number>, key: KEY!, }";
+        let value = parse(source);
+        assert_eq!(value.get("order").unwrap().as_str(), Some("DESC"));
+        assert_eq!(text(source, value.get("order").unwrap().span), "'DESC'");
+        assert!(value.get("opts").unwrap().get("a").is_some());
+        assert_eq!(
+            value.get("key").unwrap().kind,
+            ValueKind::Identifier("KEY".to_string())
+        );
+    }
+
+    #[test]
+    fn comments_shorthand_and_spreads() {
+        let source = "{
+            // the id
+            id, /* inline */ ...base,
+            [computed]: 1,
+            'quoted-key': 2,
+        }";
+        let value = parse(source);
+        let keys = value
+            .as_properties()
+            .unwrap()
+            .iter()
+            .map(|property| property.key.value.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(keys, ["id", "[computed]", "quoted-key"]);
+        assert_eq!(
+            value.get("id").unwrap().kind,
+            ValueKind::Identifier("id".to_string())
+        );
+    }
+
+    #[test]
+    fn parsing_starts_at_the_offset_and_stops_after_the_value() {
+        let source = "loadSchema('users', { key: ['id'] });\nconst other = {";
+        let offset = source.find('{').unwrap();
+        let value = parse_value(source, offset).unwrap();
+        assert_eq!(text(source, value.span), "{ key: ['id'] }");
+    }
+
+    #[test]
+    fn syntax_errors_point_at_the_problem() {
+        let source = "{ id: 'uuid' name: 'text' }";
+        let err = parse_value(source, 0).unwrap_err();
+        assert_eq!(err.message, "expected `,`");
+        assert_eq!(text(source, err.span), "name");
+
+        let source = "{ id: 'uuid, }";
+        let err = parse_value(source, 0).unwrap_err();
+        assert_eq!(err.message, "unterminated string");
+        assert_eq!(err.span.start, 6);
+
+        let err = parse_value("{ id: }", 0).unwrap_err();
+        assert_eq!(err.message, "expected a value");
+    }
+}
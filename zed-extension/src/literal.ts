@@ -0,0 +1,653 @@
+// A parser for the JavaScript object literals schemas are written as, ported
+// from the extension's `literal.rs` so that the server reads schemas the same
+// way the `/cql-ddl` command does.
+//
+// Only literal values are parsed: objects, arrays, strings, numbers, booleans,
+// `null` and identifiers. Anything else (functions, calls, regexes, template
+// strings with substitutions) is skipped over and kept as an opaque
+// `expression`. Offsets are indices into the document text, as
+// `TextDocument.positionAt` takes them.
+
+export interface Span { start: number; end: number }
+export interface Spanned<T> { value: T; span: Span }
+
+export type Value = { span: Span } & (
+    | { kind: 'string'; value: string }
+    | { kind: 'number'; value: number }
+    | { kind: 'bool'; value: boolean }
+    // `null` or `undefined`
+    | { kind: 'null' }
+    | { kind: 'object'; properties: Property[] }
+    | { kind: 'array'; items: Value[] }
+    // A reference such as `userSchema` or `Types.uuid`
+    | { kind: 'identifier'; name: string }
+    // Any other expression, such as `() => new Date()`
+    | { kind: 'expression' }
+);
+
+// Shorthand properties (`{ email }`) get an `identifier` value and methods an
+// `expression`.
+export interface Property { key: Spanned<string>; value: Value }
+
+// A syntax error, or a value of the wrong shape
+export class SchemaError extends Error {
+    span: Span;
+    // Identifies problems the server offers a fix for
+    code: string | undefined;
+
+    constructor(message: string, span: Span, code?: string) {
+        super(message);
+        this.span = span;
+        this.code = code;
+    }
+}
+
+export function span(start: number, end: number): Span {
+    return { start, end };
+}
+
+// Shrinks a span by `n` on each side, such as a string's quotes.
+export function shrink(s: Span, n: number): Span {
+    const start = Math.min(s.start + n, s.end);
+    return span(start, Math.max(s.end - n, start));
+}
+
+export function propertySpan(property: Property): Span {
+    return span(property.key.span.start, property.value.span.end);
+}
+
+// The last property named `key`, which is the one JavaScript keeps when a key
+// is repeated.
+export function get(value: Value, key: string): Value | undefined {
+    if (value.kind !== 'object') {
+        return undefined;
+    }
+    for (let i = value.properties.length - 1; i >= 0; i--) {
+        if (value.properties[i].key.value === key) {
+            return value.properties[i].value;
+        }
+    }
+    return undefined;
+}
+
+// Parses the value starting at `offset` of `source`. Text after the value is
+// not looked at.
+export function parseValue(source: string, offset: number): Value {
+    return new Parser(source, offset).value();
+}
+
+export type Token = { span: Span } & (
+    | { kind: 'identifier'; value: string }
+    | { kind: 'string'; value: string }
+    // A template string, with its text if it has no substitutions
+    | { kind: 'template'; value: string | null }
+    | { kind: 'number'; value: number }
+    | { kind: 'regex' }
+    // `=>`, `...` and `?.`, or a single punctuation character
+    | { kind: 'punct'; value: string }
+);
+
+export function isPunct(token: Token | undefined, punct: string): boolean {
+    return token?.kind === 'punct' && token.value === punct;
+}
+
+export function isIdentifier(token: Token | undefined, name: string): boolean {
+    return token?.kind === 'identifier' && token.value === name;
+}
+
+// Lexes as much of `source` as possible. Schemas are usually near the top of a
+// file, so text the lexer can't handle (such as JSX) just ends the scan.
+export function tokens(source: string): Token[] {
+    return lex(source).tokens;
+}
+
+// Like `tokens`, for the part of `source` from `start` to `end`, also
+// returning the text of the comments among them without their delimiters.
+export function lex(source: string, start = 0, end = source.length): { tokens: Token[]; comments: Span[] } {
+    const lexer = new Lexer(source.slice(0, end), start);
+    const result: Token[] = [];
+    try {
+        for (let token = lexer.next(); token; token = lexer.next()) {
+            result.push(token);
+        }
+    } catch {
+        // Keep the tokens before the error.
+    }
+    return { tokens: result, comments: lexer.comments };
+}
+
+const punctuation = [
+    '=>', '...', '?.', '{', '}', '(', ')', '[', ']', ';', ',', '<', '>', '+', '-', '*', '/', '%',
+    '&', '|', '^', '!', '~', '?', ':', '=', '.', '@', '#'
+];
+
+const identifierStart = /[\p{L}_$]/u;
+const identifierChar = /[\p{L}\p{N}_$]/u;
+
+// Splits JavaScript source into tokens, skipping whitespace and comments.
+class Lexer {
+    private source: string;
+    private pos: number;
+    // Whether a `/` starts a regex rather than a division
+    private regexAllowed = true;
+    // The text of each comment skipped so far, without its delimiters
+    comments: Span[] = [];
+
+    constructor(source: string, offset: number) {
+        this.source = source;
+        this.pos = offset;
+    }
+
+    private peek(): string | undefined {
+        return this.source[this.pos];
+    }
+
+    private error(message: string, start: number): SchemaError {
+        return new SchemaError(message, span(start, this.pos));
+    }
+
+    private skipTrivia(): void {
+        for (;;) {
+            if (this.source.startsWith('//', this.pos)) {
+                const end = this.source.indexOf('\n', this.pos);
+                const stop = end === -1 ? this.source.length : end;
+                this.comments.push(span(this.pos + 2, stop));
+                this.pos = stop;
+            } else if (this.source.startsWith('/*', this.pos)) {
+                const end = this.source.indexOf('*/', this.pos + 2);
+                if (end === -1) {
+                    throw this.error('unterminated comment', this.pos);
+                }
+                this.comments.push(span(this.pos + 2, end));
+                this.pos = end + 2;
+            } else if (/\s/.test(this.peek() ?? '')) {
+                this.pos++;
+            } else {
+                return;
+            }
+        }
+    }
+
+    next(): Token | undefined {
+        this.skipTrivia();
+        const start = this.pos;
+        const c = this.peek();
+        if (c === undefined) {
+            return undefined;
+        }
+        let token: Token;
+        if (c === "'" || c === '"') {
+            token = { kind: 'string', value: this.string(c), span: span(start, start) };
+        } else if (c === '`') {
+            token = { kind: 'template', value: this.template(), span: span(start, start) };
+        } else if (c === '/' && this.regexAllowed) {
+            this.regex();
+            token = { kind: 'regex', span: span(start, start) };
+        } else if (/[0-9]/.test(c) || (c === '.' && /[0-9]/.test(this.source[this.pos + 1] ?? ''))) {
+            token = { kind: 'number', value: this.number(), span: span(start, start) };
+        } else if (identifierStart.test(c)) {
+            while (identifierChar.test(this.peek() ?? '')) {
+                this.pos++;
+            }
+            token = { kind: 'identifier', value: this.source.slice(start, this.pos), span: span(start, start) };
+        } else {
+            const punct = punctuation.find(p => this.source.startsWith(p, this.pos));
+            if (!punct) {
+                this.pos++;
+                throw this.error(`unexpected character ${JSON.stringify(c)}`, start);
+            }
+            this.pos += punct.length;
+            token = { kind: 'punct', value: punct, span: span(start, start) };
+        }
+        token.span = span(start, this.pos);
+        if (token.kind === 'punct') {
+            this.regexAllowed = ![')', ']', '}'].includes(token.value);
+        } else if (token.kind === 'identifier') {
+            this.regexAllowed = ['return', 'typeof', 'case', 'in', 'of', 'new', 'delete', 'void', 'yield']
+                .includes(token.value);
+        } else {
+            this.regexAllowed = false;
+        }
+        return token;
+    }
+
+    private string(quote: string): string {
+        const start = this.pos++;
+        let value = '';
+        for (;;) {
+            const c = this.source[this.pos++];
+            if (c === undefined || c === '\n') {
+                throw this.error('unterminated string', start);
+            } else if (c === '\\') {
+                value += this.escape();
+            } else if (c === quote) {
+                return value;
+            } else {
+                value += c;
+            }
+        }
+    }
+
+    private escape(): string {
+        const c = this.source[this.pos++];
+        const code = (digits: string) => {
+            const point = /^[0-9a-fA-F]+$/.test(digits) ? parseInt(digits, 16) : NaN;
+            return point <= 0x10ffff ? String.fromCodePoint(point) : '';
+        };
+        switch (c) {
+            case undefined: return '';
+            case 'n': return '\n';
+            case 't': return '\t';
+            case 'r': return '\r';
+            case 'b': return '\b';
+            case 'f': return '\f';
+            case 'v': return '\v';
+            case '0': return '\0';
+            case '\n': return '';
+            case 'x': {
+                const digits = this.source.slice(this.pos, this.pos + 2);
+                this.pos += 2;
+                return code(digits);
+            }
+            case 'u': {
+                if (this.peek() === '{') {
+                    const end = this.source.indexOf('}', this.pos);
+                    const digits = end === -1 ? '' : this.source.slice(this.pos + 1, end);
+                    this.pos = end === -1 ? this.pos + 1 : end + 1;
+                    return code(digits);
+                }
+                const digits = this.source.slice(this.pos, this.pos + 4);
+                this.pos += 4;
+                return code(digits);
+            }
+            default: return c;
+        }
+    }
+
+    // Lexes a template string, returning its text if it has no substitutions.
+    // Substitutions are skipped token by token so that braces and backticks
+    // inside them don't end the template early.
+    private template(): string | null {
+        const start = this.pos++;
+        let value: string | null = '';
+        for (;;) {
+            const c = this.source[this.pos++];
+            if (c === undefined) {
+                throw this.error('unterminated template string', start);
+            } else if (c === '`') {
+                return value;
+            } else if (c === '\\') {
+                const escaped = this.escape();
+                if (value !== null) {
+                    value += escaped;
+                }
+            } else if (c === '$' && this.peek() === '{') {
+                this.pos++;
+                value = null;
+                this.regexAllowed = true;
+                let depth = 0;
+                for (;;) {
+                    const token = this.next();
+                    if (!token) {
+                        throw this.error('unterminated template string', start);
+                    }
+                    if (isPunct(token, '{')) {
+                        depth++;
+                    } else if (isPunct(token, '}')) {
+                        if (depth === 0) {
+                            break;
+                        }
+                        depth--;
+                    }
+                }
+            } else if (value !== null) {
+                value += c;
+            }
+        }
+    }
+
+    private regex(): void {
+        const start = this.pos++;
+        let inClass = false;
+        for (;;) {
+            const c = this.source[this.pos++];
+            if (c === undefined || c === '\n') {
+                throw this.error('unterminated regex', start);
+            } else if (c === '\\') {
+                this.pos++;
+            } else if (c === '[') {
+                inClass = true;
+            } else if (c === ']') {
+                inClass = false;
+            } else if (c === '/' && !inClass) {
+                break;
+            }
+        }
+        while (identifierChar.test(this.peek() ?? '')) {
+            this.pos++;
+        }
+    }
+
+    private number(): number {
+        const start = this.pos;
+        for (;;) {
+            const c = this.peek() ?? '';
+            const exponentSign = /[eE]$/.test(this.source.slice(start, this.pos))
+                && !this.source.startsWith('0x', start)
+                && (c === '+' || c === '-');
+            if (!/[0-9A-Za-z_.]/.test(c) && !exponentSign) {
+                break;
+            }
+            this.pos++;
+        }
+        const text = this.source.slice(start, this.pos).replace(/_/g, '').replace(/n$/, '').toLowerCase();
+        for (const [prefix, radix] of [['0x', 16], ['0o', 8], ['0b', 2]] as const) {
+            if (text.startsWith(prefix)) {
+                const digits = text.slice(2);
+                return /^[0-9a-f]+$/.test(digits) ? parseInt(digits, radix) : NaN;
+            }
+        }
+        return text === '' || !/^[0-9.]/.test(text) ? NaN : Number(text);
+    }
+}
+
+class Parser {
+    private source: string;
+    private lexer: Lexer;
+    private peeked: Token | undefined | null = null;
+    // The end of the last token taken
+    private end: number;
+
+    constructor(source: string, offset: number) {
+        this.source = source;
+        this.lexer = new Lexer(source, offset);
+        this.end = offset;
+    }
+
+    private peek(): Token | undefined {
+        if (this.peeked === null) {
+            this.peeked = this.lexer.next();
+        }
+        return this.peeked;
+    }
+
+    private next(): Token | undefined {
+        const token = this.peek();
+        this.peeked = null;
+        if (token) {
+            this.end = token.span.end;
+        }
+        return token;
+    }
+
+    private eat(punct: string): boolean {
+        const found = isPunct(this.peek(), punct);
+        if (found) {
+            this.next();
+        }
+        return found;
+    }
+
+    private expect(punct: string): Token {
+        const token = this.next();
+        if (!isPunct(token, punct)) {
+            throw new SchemaError(`expected \`${punct}\``, token?.span ?? span(this.end, this.end));
+        }
+        return token!;
+    }
+
+    // Whether the next token ends the current value
+    private atTerminator(): boolean {
+        const token = this.peek();
+        return !token || [',', '}', ']', ')', ';'].some(punct => isPunct(token, punct));
+    }
+
+    value(): Value {
+        const token = this.peek();
+        if (!token) {
+            throw new SchemaError('expected a value', span(this.end, this.end));
+        }
+        const start = token.span.start;
+        let value: Value;
+        if (isPunct(token, '{')) {
+            value = this.object();
+        } else if (isPunct(token, '[')) {
+            value = this.array();
+        } else if (token.kind === 'string' || (token.kind === 'template' && token.value !== null)) {
+            this.next();
+            value = { kind: 'string', value: token.value!, span: token.span };
+        } else if (token.kind === 'number') {
+            this.next();
+            value = { kind: 'number', value: token.value, span: token.span };
+        } else if (isPunct(token, '-')) {
+            this.next();
+            const number = this.peek();
+            if (number?.kind !== 'number') {
+                return this.opaque(start);
+            }
+            this.next();
+            value = { kind: 'number', value: -number.value, span: span(start, this.end) };
+        } else if (token.kind === 'identifier') {
+            const name = token.value;
+            if (name === 'true' || name === 'false') {
+                this.next();
+                value = { kind: 'bool', value: name === 'true', span: token.span };
+            } else if (name === 'null' || name === 'undefined') {
+                this.next();
+                value = { kind: 'null', span: token.span };
+            } else if (['function', 'async', 'new', 'class', 'typeof', 'void', 'await'].includes(name)) {
+                return this.opaque(start);
+            } else {
+                value = this.identifier();
+            }
+        } else {
+            return this.opaque(start);
+        }
+
+        // Type assertions and non-null assertions don't change the value.
+        for (;;) {
+            const next = this.peek();
+            if (isIdentifier(next, 'as') || isIdentifier(next, 'satisfies')) {
+                this.next();
+                this.skipExpression(true);
+            } else if (isPunct(next, '!')) {
+                this.next();
+            } else {
+                break;
+            }
+        }
+        // An operator, call or arrow turns the value into an expression.
+        if (this.atTerminator()) {
+            return value;
+        }
+        const next = this.peek()!;
+        if (next.kind === 'punct') {
+            return this.opaque(start);
+        }
+        throw new SchemaError('expected `,`', next.span);
+    }
+
+    // Parses `name` or a member path such as `Types.uuid`.
+    private identifier(): Value {
+        const first = this.next()!;
+        let path = first.kind === 'identifier' ? first.value : '';
+        while (isPunct(this.peek(), '.') || isPunct(this.peek(), '?.')) {
+            const dot = this.next()!;
+            const name = this.peek();
+            if (name?.kind !== 'identifier') {
+                throw new SchemaError('expected a property name', dot.span);
+            }
+            path += `.${name.value}`;
+            this.next();
+        }
+        return { kind: 'identifier', name: path, span: span(first.span.start, this.end) };
+    }
+
+    // Skips the rest of an expression starting at `start`.
+    private opaque(start: number): Value {
+        this.skipExpression(false);
+        if (this.end <= start) {
+            throw new SchemaError('expected a value', this.peek()?.span ?? span(start, start));
+        }
+        return { kind: 'expression', span: span(start, this.end) };
+    }
+
+    // Skips tokens up to the end of the current value, keeping track of
+    // brackets. Type arguments like `Record<string, number>` contain commas, so
+    // `angles` also tracks `<` and `>` when skipping a type.
+    private skipExpression(angles: boolean): void {
+        let depth = 0;
+        for (;;) {
+            if (depth === 0 && this.atTerminator()) {
+                return;
+            }
+            const token = this.next();
+            if (!token) {
+                return;
+            }
+            if (token.kind !== 'punct') {
+                continue;
+            }
+            if (['(', '[', '{'].includes(token.value) || (angles && token.value === '<')) {
+                depth++;
+            } else if ([')', ']', '}'].includes(token.value)) {
+                if (depth === 0) {
+                    throw new SchemaError('unbalanced bracket', token.span);
+                }
+                depth--;
+            } else if (angles && token.value === '>' && depth > 0) {
+                depth--;
+            }
+        }
+    }
+
+    private object(): Value {
+        const open = this.expect('{');
+        const properties: Property[] = [];
+        while (!this.eat('}')) {
+            if (this.eat('...')) {
+                this.skipExpression(false);
+            } else {
+                properties.push(this.property());
+            }
+            if (!this.eat(',')) {
+                this.expect('}');
+                break;
+            }
+        }
+        return { kind: 'object', properties, span: span(open.span.start, this.end) };
+    }
+
+    private property(): Property {
+        let key = this.propertyKey();
+
+        // `async name() {}`, `get name() {}` and `*name() {}` are methods.
+        const modifier = ['async', 'get', 'set', 'static', '*'].includes(key.value);
+        if (modifier && !isPunct(this.peek(), ':') && !isPunct(this.peek(), '(') && !this.atTerminator()) {
+            key = this.propertyKey();
+        }
+
+        let value: Value;
+        if (this.eat(':')) {
+            value = this.value();
+        } else if (isPunct(this.peek(), '(') || isPunct(this.peek(), '<')) {
+            const start = this.peek()!.span.start;
+            this.skipMethod();
+            value = { kind: 'expression', span: span(start, this.end) };
+        } else if (this.atTerminator()) {
+            value = { kind: 'identifier', name: key.value, span: key.span };
+        } else {
+            throw new SchemaError('expected `:`', this.peek()!.span);
+        }
+        return { key, value };
+    }
+
+    private propertyKey(): Spanned<string> {
+        const token = this.next();
+        if (!token) {
+            throw new SchemaError('expected a property', span(this.end, this.end));
+        }
+        switch (token.kind) {
+            case 'identifier':
+            case 'string':
+                return { value: token.value, span: token.span };
+            case 'template':
+                if (token.value !== null) {
+                    return { value: token.value, span: token.span };
+                }
+                break;
+            case 'number':
+                return { value: String(token.value), span: token.span };
+            case 'punct':
+                if (token.value === '*') {
+                    return { value: '*', span: token.span };
+                }
+                if (token.value === '[') {
+                    this.skipExpression(false);
+                    this.expect(']');
+                    const computed = span(token.span.start, this.end);
+                    return { value: this.source.slice(computed.start, computed.end), span: computed };
+                }
+                break;
+        }
+        throw new SchemaError('expected a property', token.span);
+    }
+
+    // Skips a method's parameters, return type and body.
+    private skipMethod(): void {
+        let depth = 0;
+        const next = () => {
+            const token = this.next();
+            if (!token) {
+                throw new SchemaError('unterminated method', span(this.end, this.end));
+            }
+            return token;
+        };
+        for (;;) {
+            const token = next();
+            if (token.kind !== 'punct') {
+                continue;
+            }
+            if (token.value === '{' && depth === 0) {
+                let braces = 1;
+                while (braces > 0) {
+                    const inner = next();
+                    if (isPunct(inner, '{')) {
+                        braces++;
+                    } else if (isPunct(inner, '}')) {
+                        braces--;
+                    }
+                }
+                return;
+            }
+            if (['(', '[', '<', '{'].includes(token.value)) {
+                depth++;
+            } else if ([')', ']', '>', '}'].includes(token.value)) {
+                depth = Math.max(depth - 1, 0);
+            }
+        }
+    }
+
+    private array(): Value {
+        const open = this.expect('[');
+        const items: Value[] = [];
+        for (;;) {
+            if (this.eat(']')) {
+                break;
+            }
+            if (this.eat(',')) {
+                continue;
+            }
+            if (isPunct(this.peek(), '...')) {
+                items.push(this.opaque(this.next()!.span.start));
+            } else {
+                items.push(this.value());
+            }
+            if (!this.eat(',')) {
+                this.expect(']');
+                break;
+            }
+        }
+        return { kind: 'array', items, span: span(open.span.start, this.end) };
+    }
+}
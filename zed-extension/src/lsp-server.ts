@@ -1,3 +1,4 @@
+#!/usr/bin/env node
 import {
     createConnection,
     TextDocuments,
@@ -6,16 +7,36 @@ import {
     ProposedFeatures,
     InitializeParams,
     DidChangeConfigurationNotification,
+    DidChangeWatchedFilesNotification,
     CompletionItem,
     CompletionItemKind,
+    InsertTextFormat,
     TextDocumentPositionParams,
     TextDocumentSyncKind,
     InitializeResult,
     Hover,
-    MarkupKind
+    MarkupKind,
+    SymbolInformation,
+    SymbolKind,
+    WorkspaceSymbolParams,
+    CodeAction,
+    CodeActionKind,
+    CodeActionParams,
+    ExecuteCommandParams,
+    TextEdit
 } from 'vscode-languageserver/node';
 
 import { TextDocument } from 'vscode-languageserver-textdocument';
+import { URI } from 'vscode-uri';
+import * as fs from 'fs';
+import * as os from 'os';
+import * as path from 'path';
+import * as schema from './schema';
+import { ProjectOptions, discover, packageOptions as configOptions, usesCassandraOrm } from './config';
+import { SchemaError, Span } from './literal';
+import { statements } from './ddl';
+import { check, counterTableCode, unknownTypes } from './analysis';
+import { companionModel, movedSpans } from './counters';
 
 // Create a connection for the server
 const connection = createConnection(ProposedFeatures.all);
@@ -25,10 +46,28 @@ const documents: TextDocuments<TextDocument> = new TextDocuments(TextDocument);
 
 let hasConfigurationCapability = false;
 let hasWorkspaceFolderCapability = false;
-let hasDiagnosticRelatedInformationCapability = false;
+let hasWatchedFilesCapability = false;
+let hasShowDocumentCapability = false;
+
+let projectOptions: ProjectOptions = {};
+
+// The `cassandraorm` settings section, shared with the VS Code extension
+interface CassandraOrmSettings {
+    enableIntelliSense: boolean;
+    dashboardPort: number;
+    autoValidation: boolean;
+}
+
+const defaultSettings: CassandraOrmSettings = {
+    enableIntelliSense: true,
+    dashboardPort: 3000,
+    autoValidation: true
+};
+let globalSettings: CassandraOrmSettings = defaultSettings;
 
 connection.onInitialize((params: InitializeParams) => {
     const capabilities = params.capabilities;
+    projectOptions = (params.initializationOptions as ProjectOptions) ?? {};
 
     hasConfigurationCapability = !!(
         capabilities.workspace && !!capabilities.workspace.configuration
@@ -36,11 +75,8 @@ connection.onInitialize((params: InitializeParams) => {
     hasWorkspaceFolderCapability = !!(
         capabilities.workspace && !!capabilities.workspace.workspaceFolders
     );
-    hasDiagnosticRelatedInformationCapability = !!(
-        capabilities.textDocument &&
-        capabilities.textDocument.publishDiagnostics &&
-        capabilities.textDocument.publishDiagnostics.relatedInformation
-    );
+    hasWatchedFilesCapability = !!capabilities.workspace?.didChangeWatchedFiles?.dynamicRegistration;
+    hasShowDocumentCapability = !!capabilities.window?.showDocument?.support;
 
     const result: InitializeResult = {
         capabilities: {
@@ -49,7 +85,14 @@ connection.onInitialize((params: InitializeParams) => {
                 resolveProvider: true,
                 triggerCharacters: ['.', '"', "'"]
             },
-            hoverProvider: true
+            hoverProvider: true,
+            workspaceSymbolProvider: true,
+            codeActionProvider: {
+                codeActionKinds: [CodeActionKind.Source, CodeActionKind.QuickFix]
+            },
+            executeCommandProvider: {
+                commands: [generateDdlCommand]
+            }
         }
     };
 
@@ -65,9 +108,23 @@ connection.onInitialize((params: InitializeParams) => {
 });
 
 connection.onInitialized(() => {
+    // Problems the extension found while starting the server
+    const configError = projectOptions.project?.configError;
+    if (configError) {
+        connection.window.showWarningMessage(`CassandraORM config ignored: ${configError}`);
+    }
+    const compatibilityWarning = projectOptions.project?.compatibilityWarning;
+    if (compatibilityWarning) {
+        connection.window.showWarningMessage(compatibilityWarning);
+    }
     if (hasConfigurationCapability) {
         connection.client.register(DidChangeConfigurationNotification.type, undefined);
     }
+    if (hasWatchedFilesCapability) {
+        connection.client.register(DidChangeWatchedFilesNotification.type, {
+            watchers: [{ globPattern: '**/{package.json,cassandraorm.config.json,cassandraorm.config.jsonc}' }]
+        });
+    }
     if (hasWorkspaceFolderCapability) {
         connection.workspace.onDidChangeWorkspaceFolders(_event => {
             connection.console.log('Workspace folder change event received.');
@@ -77,27 +134,36 @@ connection.onInitialized(() => {
 
 // Completion provider
 connection.onCompletion(
-    (_textDocumentPosition: TextDocumentPositionParams): CompletionItem[] => {
+    async (textDocumentPosition: TextDocumentPositionParams): Promise<CompletionItem[]> => {
+        const settings = await getSettings();
+        const document = documents.get(textDocumentPosition.textDocument.uri);
+        if (!settings.enableIntelliSense || !document || !isModelDocument(document)) {
+            return [];
+        }
         return [
             {
                 label: 'createEnhancedClient',
-                kind: CompletionItemKind.Function,
+                kind: CompletionItemKind.Snippet,
                 data: 1,
                 detail: 'Create CassandraORM enhanced client',
-                insertText: 'createEnhancedClient({\n  clientOptions: {\n    contactPoints: [\'127.0.0.1\'],\n    localDataCenter: \'datacenter1\',\n    keyspace: \'myapp\'\n  }\n})'
+                insertTextFormat: InsertTextFormat.Snippet,
+                insertText: 'createEnhancedClient({\n  clientOptions: {\n    contactPoints: [\'${1:127.0.0.1}\'],\n    localDataCenter: \'${2:datacenter1}\',\n    keyspace: \'${3:myapp}\'\n  }\n})'
             },
             {
                 label: 'loadSchema',
                 kind: CompletionItemKind.Method,
                 data: 2,
                 detail: 'Load schema definition',
-                insertText: 'loadSchema(\'tableName\', schema)'
+                labelDetails: { detail: '(name: string, schema: ModelSchema)', description: 'Promise<ModelStatic>' },
+                insertTextFormat: InsertTextFormat.Snippet,
+                insertText: 'loadSchema(\'${1:tableName}\', ${2:schema})'
             },
             {
                 label: 'generateEmbedding',
                 kind: CompletionItemKind.Method,
                 data: 3,
                 detail: 'Generate AI embedding',
+                labelDetails: { detail: '(text: string)', description: 'Promise<number[]>' },
                 insertText: 'generateEmbedding(text)'
             },
             {
@@ -105,12 +171,65 @@ connection.onCompletion(
                 kind: CompletionItemKind.Method,
                 data: 4,
                 detail: 'Execute with distributed lock',
+                labelDetails: { detail: '(resource: string, callback: () => Promise<T>)', description: 'Promise<T>' },
                 insertText: 'withDistributedLock(resource, callback)'
-            }
+            },
+            ...queryMethodCompletions(),
+            ...modelCompletions(document)
         ];
     }
 );
 
+// Query methods of a loaded model, from `ModelStatic` in src/core/types.ts
+const queryMethods: [name: string, parameters: string, returns: string][] = [
+    ['find', '(query?: FindQuery, options?: QueryOptions)', 'Promise<T[]>'],
+    ['findOne', '(query?: FindQuery, options?: QueryOptions)', 'Promise<T | null>'],
+    ['create', '(data: Partial<T>, options?: { upsert?: boolean })', 'Promise<T>'],
+    ['createMany', '(dataArray: Partial<T>[], options?: { ignoreDuplicates?: boolean })', 'Promise<T[]>'],
+    ['update', '(query: FindQuery, updateValues: Partial<T>, options?: QueryOptions)', 'Promise<any>'],
+    ['delete', '(query: FindQuery, options?: QueryOptions)', 'Promise<any>'],
+    ['stream', '(query?: FindQuery, options?: StreamOptions)', 'NodeJS.ReadableStream'],
+    ['truncate', '(callback?: (err?: Error) => void)', 'void']
+];
+
+function queryMethodCompletions(): CompletionItem[] {
+    return queryMethods.map(([name, parameters, returns]) => ({
+        label: name,
+        kind: CompletionItemKind.Method,
+        detail: `${name}${parameters}: ${returns}`,
+        labelDetails: { detail: parameters, description: returns }
+    }));
+}
+
+// Models and their fields from the open documents of the same package. Field
+// items carry the CQL type as `detail` and the model name as the label
+// description.
+function modelCompletions(current: TextDocument): CompletionItem[] {
+    const items: CompletionItem[] = [];
+    const root = packageRoot(current.uri);
+    for (const document of documents.all()) {
+        if (!isModelDocument(document) || packageRoot(document.uri) !== root) {
+            continue;
+        }
+        for (const model of schema.findModels(document.getText())) {
+            const name = model.name.value;
+            items.push({ label: name, kind: CompletionItemKind.Class, detail: 'model' });
+            if (model.schema instanceof SchemaError) {
+                continue;
+            }
+            for (const field of model.schema.fields) {
+                items.push({
+                    label: field.name.value,
+                    kind: CompletionItemKind.Field,
+                    detail: fieldType(field),
+                    labelDetails: { description: name }
+                });
+            }
+        }
+    }
+    return items;
+}
+
 // Completion resolve
 connection.onCompletionResolve(
     (item: CompletionItem): CompletionItem => {
@@ -133,7 +252,12 @@ connection.onCompletionResolve(
 
 // Hover provider
 connection.onHover(
-    (_textDocumentPosition: TextDocumentPositionParams): Hover => {
+    (textDocumentPosition: TextDocumentPositionParams): Hover | null => {
+        const document = documents.get(textDocumentPosition.textDocument.uri);
+        if (!document || !isModelDocument(document)) {
+            return null;
+        }
+        const options = packageOptions(document.uri);
         return {
             contents: {
                 kind: MarkupKind.Markdown,
@@ -145,54 +269,392 @@ connection.onHover(
                     '- **AI/ML Integration**: OpenAI embeddings and semantic search',
                     '- **Performance Optimization**: Intelligent caching and query optimization',
                     '- **Distributed Systems**: Redis caching and Consul service discovery',
-                    '- **Developer Tools**: CLI, Dashboard, and IDE extensions'
+                    '- **Developer Tools**: CLI, Dashboard, and IDE extensions',
+                    '',
+                    `**Keyspace**: ${options.clientOptions?.keyspace ?? 'not configured'}`,
+                    `**Migration mode**: ${options.ormOptions?.migration ?? 'safe'}`
                 ].join('\n')
             }
         };
     }
 );
 
+// Workspace symbols for the models in open documents. Field symbols are named
+// `users.email: text` so that editors can show the model and CQL type.
+connection.onWorkspaceSymbol((params: WorkspaceSymbolParams): SymbolInformation[] => {
+    const query = params.query.toLowerCase();
+    const symbols: SymbolInformation[] = [];
+    for (const document of documents.all()) {
+        if (!isModelDocument(document)) {
+            continue;
+        }
+        const symbol = (name: string, kind: SymbolKind, span: Span, containerName?: string) => {
+            if (name.toLowerCase().includes(query)) {
+                symbols.push({
+                    name,
+                    kind,
+                    containerName,
+                    location: {
+                        uri: document.uri,
+                        range: {
+                            start: document.positionAt(span.start),
+                            end: document.positionAt(span.end)
+                        }
+                    }
+                });
+            }
+        };
+        for (const model of schema.findModels(document.getText())) {
+            const name = model.name.value;
+            symbol(name, SymbolKind.Class, model.name.span);
+            if (model.schema instanceof SchemaError) {
+                continue;
+            }
+            for (const field of model.schema.fields) {
+                symbol(`${name}.${field.name.value}: ${fieldType(field)}`, SymbolKind.Field, field.name.span, name);
+            }
+            for (const index of model.schema.indexes) {
+                if (index.name) {
+                    symbol(`${name}.${index.name.value}`, SymbolKind.Key, index.name.span, name);
+                }
+            }
+            for (const view of model.schema.materializedViews) {
+                symbol(view.name.value, SymbolKind.Interface, view.name.span, name);
+            }
+        }
+    }
+    return symbols;
+});
+
+// "Generate CQL DDL" for the model under the cursor, and the quick fix moving
+// the counters of a model that mixes them with other columns to a companion
+// model. The command writes the statements `/cql-ddl` would show to a `.cql`
+// file and opens it. The files go in a temporary folder of this server, under
+// the package the model belongs to, and are removed when the server exits.
+const generateDdlCommand = 'cassandraorm.generateDdl';
+let ddlFolder: string | undefined;
+
+connection.onCodeAction((params: CodeActionParams): CodeAction[] => {
+    const document = documents.get(params.textDocument.uri);
+    if (!document || !isModelDocument(document)) {
+        return [];
+    }
+    const text = document.getText();
+    const model = modelAt(text, document.offsetAt(params.range.start));
+    if (!model) {
+        return [];
+    }
+    const title = `Generate CQL DDL for \`${model.name.value}\``;
+    const actions: CodeAction[] = [{
+        title,
+        kind: CodeActionKind.Source,
+        command: { title, command: generateDdlCommand, arguments: [document.uri, model.name.span.start] }
+    }];
+    if (model.schema instanceof SchemaError) {
+        return actions;
+    }
+
+    const table = schema.tableName(model.schema, model.name.value);
+    let companion: string;
+    try {
+        companion = companionModel(table, model.schema, model.declaration, text);
+    } catch (err) {
+        if (err instanceof SchemaError) {
+            return actions;
+        }
+        throw err;
+    }
+    const range = (span: Span) => ({ start: document.positionAt(span.start), end: document.positionAt(span.end) });
+    const edits: TextEdit[] = movedSpans(model.schema, text).map(span => ({ range: range(span), newText: '' }));
+    // The companion model goes after the line the original one ends on.
+    const end = Math.max(model.name.span.end, model.schema.span.end);
+    const newline = text.indexOf('\n', end);
+    const lineEnd = newline === -1 ? text.length : newline;
+    edits.push({ range: range({ start: lineEnd, end: lineEnd }), newText: `\n\n${companion}` });
+    actions.push({
+        title: `Move counters to a \`${table}_counters\` model`,
+        kind: CodeActionKind.QuickFix,
+        diagnostics: params.context.diagnostics.filter(diagnostic =>
+            diagnostic.source === 'cassandraorm-lsp'
+                && diagnostic.code === counterTableCode
+                && (diagnostic.data as { model?: string } | undefined)?.model === model.name.value),
+        isPreferred: true,
+        edit: { changes: { [document.uri]: edits } }
+    });
+    return actions;
+});
+
+connection.onExecuteCommand(async (params: ExecuteCommandParams) => {
+    if (params.command !== generateDdlCommand) {
+        return;
+    }
+    const [uri, nameOffset] = params.arguments as [string, number];
+    const document = documents.get(uri);
+    const model = document && schema.findModels(document.getText()).find(m => m.name.span.start === nameOffset);
+    if (!document || !model) {
+        connection.window.showErrorMessage('The model changed before its DDL could be generated; try again.');
+        return;
+    }
+    const locate = (err: SchemaError) => {
+        const { line, character } = document.positionAt(err.span.start);
+        const file = path.relative(packageRoot(uri), URI.parse(uri).fsPath);
+        return `${file}:${line + 1}:${character + 1}: ${err.message}`;
+    };
+    if (model.schema instanceof SchemaError) {
+        connection.window.showErrorMessage(locate(model.schema));
+        return;
+    }
+
+    const errors = check(model.schema);
+    if (errors.length > 0) {
+        connection.window.showErrorMessage(errors.map(locate).join('\n'));
+        return;
+    }
+    let text: string;
+    try {
+        text = statements(model.name.value, model.schema, packageOptions(uri).clientOptions?.keyspace)
+            .map(statement => statement.text)
+            .join('\n\n');
+    } catch (err) {
+        if (err instanceof SchemaError) {
+            connection.window.showErrorMessage(locate(err));
+            return;
+        }
+        throw err;
+    }
+
+    if (!hasShowDocumentCapability) {
+        connection.window.showInformationMessage(text);
+        return;
+    }
+    if (!ddlFolder) {
+        ddlFolder = fs.mkdtempSync(path.join(os.tmpdir(), 'cassandraorm-ddl-'));
+    }
+    const root = packageRoot(uri);
+    const table = schema.tableName(model.schema, model.name.value);
+    const file = path.join(
+        ddlFolder,
+        path.relative(projectOptions.project?.root ?? root, root) || path.basename(root),
+        `${table.replace(/[^\w.-]/g, '_')}.cql`
+    );
+    fs.mkdirSync(path.dirname(file), { recursive: true });
+    fs.writeFileSync(file, `${text}\n`);
+    await connection.window.showDocument({ uri: URI.file(file).toString(), takeFocus: true });
+});
+
+// User-defined types are those in the package config's `ormOptions.udts` and
+// those the document declares.
+function documentUserTypes(document: TextDocument): string[] {
+    const udts = packageOptions(document.uri).ormOptions?.udts ?? {};
+    return [...Object.keys(udts), ...schema.findUserTypes(document.getText())];
+}
+
+// The model whose `loadSchema` name or schema object contains `offset`
+function modelAt(text: string, offset: number): schema.Model | undefined {
+    const contains = (span: Span) => span.start <= offset && offset <= span.end;
+    return schema.findModels(text).find(model =>
+        contains(model.name.span) || (!(model.schema instanceof SchemaError) && contains(model.schema.span)));
+}
+
+// A field's type as written, such as `set<text>` for
+// `{ type: 'set', typeDef: '<text>' }`
+function fieldType(field: schema.FieldDefinition): string {
+    return field.cqlType.value + (field.typeDef?.value ?? '');
+}
+
+// Only documents inside the folder the server was started for are analyzed.
+function isInProject(uri: string): boolean {
+    const root = projectOptions.project?.root;
+    return !root || isInside(root, URI.parse(uri).fsPath);
+}
+
+function isInside(dir: string, file: string): boolean {
+    const relative = path.relative(dir, file);
+    return !relative.startsWith('..') && !path.isAbsolute(relative);
+}
+
+// In a monorepo opened at its root, each service is its own package. Every
+// document belongs to the nearest folder above it with a `package.json`, whose
+// config applies to it, and models from other packages never mix into its
+// completions.
+const packageRoots = new Map<string, string>();
+const packageConfigs = new Map<string, ProjectOptions>();
+const ormPackages = new Map<string, boolean>();
+
+function packageRoot(uri: string): string {
+    const start = path.dirname(URI.parse(uri).fsPath);
+    let root = packageRoots.get(start);
+    if (root === undefined) {
+        root = findPackageRoot(start);
+        packageRoots.set(start, root);
+    }
+    return root;
+}
+
+function findPackageRoot(start: string): string {
+    const top = projectOptions.project?.root;
+    for (let dir = start; ; dir = path.dirname(dir)) {
+        if (fs.existsSync(path.join(dir, 'package.json'))) {
+            return dir;
+        }
+        if (dir === top || path.dirname(dir) === dir) {
+            return top ?? start;
+        }
+    }
+}
+
+// The settings for a document: those the extension read for the folder the
+// server was started in, or those of the document's own package, read the
+// same way with the user's initialization options applied on top. A package
+// config that can't be read is reported once and left out.
+function packageOptions(uri: string): ProjectOptions {
+    const root = packageRoot(uri);
+    if (root === projectOptions.project?.root) {
+        return projectOptions;
+    }
+    let options = packageConfigs.get(root);
+    if (!options) {
+        options = configOptions(root, readPackageConfig(root), projectOptions.project?.userOptions);
+        const configError = options.project?.configError;
+        if (configError) {
+            connection.window.showWarningMessage(`CassandraORM config in ${root} ignored: ${configError}`);
+        }
+        packageConfigs.set(root, options);
+    }
+    return options;
+}
+
+function readPackageConfig(root: string) {
+    try {
+        return discover(packageFile(root));
+    } catch (err) {
+        return err as Error;
+    }
+}
+
+// Zed starts the server in every TypeScript and JavaScript worktree, so it
+// stays out of packages that don't use CassandraORM.
+function isOrmPackage(root: string): boolean {
+    let uses = ormPackages.get(root);
+    if (uses === undefined) {
+        uses = usesCassandraOrm(packageFile(root));
+        ormPackages.set(root, uses);
+    }
+    return uses;
+}
+
+function packageFile(root: string) {
+    return (name: string) => {
+        try {
+            return fs.readFileSync(path.join(root, name), 'utf8');
+        } catch {
+            return undefined;
+        }
+    };
+}
+
+// The server runs alongside the TypeScript server in every TypeScript and
+// JavaScript buffer, but only works on model files of CassandraORM packages:
+// schema files matched by the CassandraORM Schema language, and any other file
+// that loads a schema, creates a client or imports `ModelSchema`.
+const schemaFileName = /\.cassandra\.[jt]s$/;
+const schemaFirstLine = /^\/\/\s*@cassandraorm\b/;
+const modelFileContent = [
+    /\bloadSchema\s*\(/,
+    /\bcreate(?:Enhanced)?Client\s*\(/,
+    /\bimport\s+(?:type\s+)?\{[^}]*\bModelSchema\b[^}]*\}\s*from\b/,
+    /\b(?:const|let|var)\s*\{[^}]*\bModelSchema\b[^}]*\}\s*=\s*require\s*\(/
+];
+
+function isModelDocument(document: TextDocument): boolean {
+    if (!isInProject(document.uri) || !isOrmPackage(packageRoot(document.uri))) {
+        return false;
+    }
+    if (schemaFileName.test(document.uri)) {
+        return true;
+    }
+    const text = document.getText();
+    return schemaFirstLine.test(text) || modelFileContent.some(pattern => pattern.test(text));
+}
+
+async function getSettings(): Promise<CassandraOrmSettings> {
+    if (!hasConfigurationCapability) {
+        return globalSettings;
+    }
+    const section = await connection.workspace.getConfiguration('cassandraorm');
+    return { ...defaultSettings, ...section };
+}
+
+// Pick up setting changes without restarting the server
+connection.onDidChangeConfiguration(change => {
+    if (!hasConfigurationCapability) {
+        globalSettings = { ...defaultSettings, ...(change.settings?.cassandraorm ?? {}) };
+    }
+    documents.all().forEach(validateTextDocument);
+});
+
 // Document validation
 documents.onDidChangeContent(change => {
     validateTextDocument(change.document);
 });
 
+// Publishes the schema problems of every model in the document, on the
+// properties that cause them, and warns about user-defined types declared
+// neither in the config nor in the document. Schemas that can't be read are
+// left alone.
 async function validateTextDocument(textDocument: TextDocument): Promise<void> {
-    const text = textDocument.getText();
-    const pattern = /\b(createClient|loadSchema)\b/g;
-    let m: RegExpExecArray | null;
+    const settings = await getSettings();
+    if (!settings.autoValidation || !isModelDocument(textDocument)) {
+        connection.sendDiagnostics({ uri: textDocument.uri, diagnostics: [] });
+        return;
+    }
 
+    const text = textDocument.getText();
+    const userTypes = documentUserTypes(textDocument);
     const diagnostics: Diagnostic[] = [];
-    while ((m = pattern.exec(text))) {
-        const diagnostic: Diagnostic = {
-            severity: DiagnosticSeverity.Information,
-            range: {
-                start: textDocument.positionAt(m.index),
-                end: textDocument.positionAt(m.index + m[0].length)
-            },
-            message: `CassandraORM method detected: ${m[0]}`,
-            source: 'cassandraorm-lsp'
-        };
-
-        if (hasDiagnosticRelatedInformationCapability) {
-            diagnostic.relatedInformation = [
-                {
-                    location: {
-                        uri: textDocument.uri,
-                        range: Object.assign({}, diagnostic.range)
-                    },
-                    message: 'CassandraORM provides advanced features like AI/ML integration'
-                }
-            ];
+    for (const model of schema.findModels(text)) {
+        if (model.schema instanceof SchemaError) {
+            continue;
         }
-        diagnostics.push(diagnostic);
+        // Problems with a fix keep their code and the model, for the code
+        // action to find them by.
+        const name = model.name.value;
+        const report = (severity: DiagnosticSeverity) => (err: SchemaError) => {
+            const diagnostic: Diagnostic = {
+                severity,
+                range: {
+                    start: textDocument.positionAt(err.span.start),
+                    end: textDocument.positionAt(err.span.end)
+                },
+                message: err.message,
+                source: 'cassandraorm-lsp'
+            };
+            if (err.code) {
+                diagnostic.code = err.code;
+                diagnostic.data = { model: name };
+            }
+            diagnostics.push(diagnostic);
+        };
+        check(model.schema).forEach(report(DiagnosticSeverity.Error));
+        unknownTypes(model.schema, userTypes).forEach(report(DiagnosticSeverity.Warning));
     }
 
     connection.sendDiagnostics({ uri: textDocument.uri, diagnostics });
 }
 
+// A new `package.json` or config can move documents to another package, or
+// make a package a CassandraORM one
 connection.onDidChangeWatchedFiles(_change => {
-    connection.console.log('We received a file change event');
+    packageRoots.clear();
+    packageConfigs.clear();
+    ormPackages.clear();
+    documents.all().forEach(validateTextDocument);
+});
+
+connection.onExit(() => {
+    if (ddlFolder) {
+        fs.rmSync(ddlFolder, { recursive: true, force: true });
+    }
 });
 
 // Make the text document manager listen on the connection
@@ -0,0 +1,441 @@
+//! Checks that `extension.toml` and the `languages/` folders form an extension
+//! Zed can load. Zed silently skips a language whose config doesn't parse, so
+//! these mistakes would otherwise only show up as missing highlighting.
+
+use crate::{
+    compat::EXTENSION_VERSION, CASSANDRAORM_SERVER_ID, CQL_COUNTERS_COMMAND, CQL_DDL_COMMAND,
+    TYPESCRIPT_SERVER_ID,
+};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Languages built into Zed that a language server may attach to.
+const BUILTIN_LANGUAGES: &[&str] = &["TypeScript", "JavaScript", "TSX"];
+
+/// Grammars built into Zed that a language may use without declaring them.
+const BUILTIN_GRAMMARS: &[&str] = &["typescript", "tsx"];
+
+/// Query files every language folder must provide.
+const REQUIRED_QUERIES: &[&str] = &[
+    "highlights.scm",
+    "brackets.scm",
+    "indents.scm",
+    "textobjects.scm",
+];
+
+/// The fields of Zed's `extension.toml` the extension uses. Zed reads the
+/// extension API version from the compiled wasm, not from the manifest.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+struct Manifest {
+    id: String,
+    name: String,
+    description: String,
+    version: String,
+    schema_version: u32,
+    authors: Vec<String>,
+    repository: String,
+    #[serde(default)]
+    snippets: Vec<String>,
+    #[serde(default)]
+    grammars: BTreeMap<String, Grammar>,
+    #[serde(default)]
+    language_servers: BTreeMap<String, LanguageServer>,
+    #[serde(default)]
+    slash_commands: BTreeMap<String, SlashCommand>,
+    #[serde(default)]
+    capabilities: Vec<Capability>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+struct Grammar {
+    repository: String,
+    rev: String,
+    path: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+struct LanguageServer {
+    name: String,
+    languages: Vec<String>,
+    #[serde(default)]
+    language_ids: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SlashCommand {
+    description: String,
+    requires_argument: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Capability {
+    kind: String,
+    command: String,
+    args: Vec<String>,
+}
+
+/// A task template from a language's `tasks.json`.
+#[derive(Debug, Deserialize)]
+struct TaskTemplate {
+    label: String,
+    command: String,
+    #[serde(default)]
+    args: Vec<String>,
+    cwd: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+/// The subset of a language `config.toml` the extension relies on.
+#[derive(Debug, Deserialize)]
+pub(crate) struct LanguageConfig {
+    pub(crate) name: String,
+    pub(crate) grammar: String,
+    #[serde(default)]
+    path_suffixes: Vec<String>,
+    first_line_pattern: Option<String>,
+}
+
+pub(crate) fn extension_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn read_toml<T: for<'de> Deserialize<'de>>(path: &Path) -> T {
+    let text = fs::read_to_string(path)
+        .unwrap_or_else(|err| panic!("failed to read {}: {err}", path.display()));
+    toml::from_str(&text).unwrap_or_else(|err| panic!("{} is invalid: {err}", path.display()))
+}
+
+fn read_manifest(name: &str) -> Manifest {
+    read_toml(&extension_dir().join(name))
+}
+
+/// Returns each language folder with its parsed `config.toml`.
+pub(crate) fn languages() -> Vec<(PathBuf, LanguageConfig)> {
+    let mut languages = fs::read_dir(extension_dir().join("languages"))
+        .expect("the extension has a languages folder")
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_dir())
+        .map(|dir| {
+            let config = read_toml(&dir.join("config.toml"));
+            (dir, config)
+        })
+        .collect::<Vec<_>>();
+    languages.sort_by(|a, b| a.0.cmp(&b.0));
+    languages
+}
+
+#[test]
+fn manifests_parse_and_match_the_crate() {
+    let manifest = read_manifest("extension.toml");
+    assert_eq!(manifest.id, "cassandraorm");
+    assert_eq!(manifest.version, EXTENSION_VERSION);
+    assert_eq!(manifest.schema_version, 1);
+
+    let simple = read_manifest("extension-simple.toml");
+    assert_eq!(simple.id, manifest.id);
+    assert_eq!(simple.version, manifest.version);
+    assert_eq!(simple.snippets, manifest.snippets);
+    assert!(simple.language_servers.is_empty());
+    assert!(simple.slash_commands.is_empty());
+    assert_eq!(
+        simple.grammars.keys().collect::<Vec<_>>(),
+        manifest.grammars.keys().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn builtin_grammars_are_not_redeclared() {
+    // Zed registers extension grammars by name, so declaring `typescript`
+    // would replace the built-in grammar for every TypeScript buffer.
+    for name in ["extension.toml", "extension-simple.toml"] {
+        for grammar in read_manifest(name).grammars.keys() {
+            assert!(
+                !BUILTIN_GRAMMARS.contains(&grammar.as_str()),
+                "{name} redeclares Zed's built-in {grammar} grammar"
+            );
+        }
+    }
+}
+
+#[test]
+fn language_servers_match_the_extension_code() {
+    let manifest = read_manifest("extension.toml");
+    assert_eq!(
+        manifest.language_servers.keys().collect::<Vec<_>>(),
+        vec![CASSANDRAORM_SERVER_ID, TYPESCRIPT_SERVER_ID]
+    );
+
+    let names = languages()
+        .into_iter()
+        .map(|(_, config)| config.name)
+        .collect::<Vec<_>>();
+    for (id, server) in &manifest.language_servers {
+        for language in server.languages.iter().chain(server.language_ids.keys()) {
+            assert!(
+                names.contains(language) || BUILTIN_LANGUAGES.contains(&language.as_str()),
+                "{id} refers to unknown language {language:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn slash_commands_match_the_extension_code() {
+    let manifest = read_manifest("extension.toml");
+    assert_eq!(
+        manifest.slash_commands.keys().collect::<Vec<_>>(),
+        vec![CQL_COUNTERS_COMMAND, CQL_DDL_COMMAND]
+    );
+    for (name, command) in &manifest.slash_commands {
+        assert!(
+            !command.description.is_empty(),
+            "/{name} has no description"
+        );
+        assert!(command.requires_argument, "/{name} needs a model name");
+    }
+}
+
+#[test]
+fn only_version_checks_may_run_processes() {
+    let manifest = read_manifest("extension.toml");
+    assert_eq!(manifest.capabilities.len(), 1);
+    let capability = &manifest.capabilities[0];
+    assert_eq!(capability.kind, "process:exec");
+    assert_eq!(capability.command, "*");
+    assert_eq!(capability.args, ["--version"]);
+    assert!(read_manifest("extension-simple.toml")
+        .capabilities
+        .is_empty());
+}
+
+#[test]
+fn model_files_keep_the_typescript_language() {
+    // Ordinary model files are recognized by the server from their content, so
+    // the extension must attach to the built-in languages rather than claim
+    // `.ts` and `.js` files for the schema language.
+    let manifest = read_manifest("extension.toml");
+    let server = &manifest.language_servers[CASSANDRAORM_SERVER_ID];
+    for language in ["TypeScript", "JavaScript"] {
+        assert!(server.languages.iter().any(|name| name == language));
+    }
+    assert!(manifest.language_servers[TYPESCRIPT_SERVER_ID]
+        .languages
+        .iter()
+        .all(|language| !BUILTIN_LANGUAGES.contains(&language.as_str())));
+
+    for (_, config) in languages() {
+        for suffix in &config.path_suffixes {
+            assert!(
+                !["ts", "js", "tsx", "jsx", "mts", "cts", "mjs", "cjs"].contains(&suffix.as_str()),
+                "{} takes over every .{suffix} file",
+                config.name
+            );
+        }
+    }
+}
+
+#[test]
+fn languages_use_declared_grammars_and_ship_their_queries() {
+    let manifest = read_manifest("extension.toml");
+    let languages = languages();
+    assert!(!languages.is_empty());
+
+    for (dir, config) in &languages {
+        assert!(
+            manifest.grammars.contains_key(&config.grammar)
+                || BUILTIN_GRAMMARS.contains(&config.grammar.as_str()),
+            "{} uses undeclared grammar {}",
+            config.name,
+            config.grammar
+        );
+        assert!(
+            !config.path_suffixes.is_empty() || config.first_line_pattern.is_some(),
+            "{} matches no files",
+            config.name
+        );
+        assert!(
+            config
+                .path_suffixes
+                .iter()
+                .all(|suffix| !suffix.starts_with('.')),
+            "{}: Zed path suffixes are written without a leading dot",
+            config.name
+        );
+        for query in REQUIRED_QUERIES {
+            assert!(
+                dir.join(query).is_file(),
+                "{} is missing {query}",
+                config.name
+            );
+        }
+    }
+}
+
+#[test]
+fn the_schema_language_is_typescript() {
+    let (dir, schema) = languages()
+        .into_iter()
+        .find(|(_, config)| config.name == "CassandraORM Schema")
+        .expect("the schema language is defined");
+    assert_eq!(schema.grammar, "typescript");
+    assert_eq!(schema.path_suffixes, ["cassandra.ts", "cassandra.js"]);
+    for query in [
+        "injections.scm",
+        "outline.scm",
+        "runnables.scm",
+        "tasks.json",
+    ] {
+        assert!(
+            dir.join(query).is_file(),
+            "the schema language is missing {query}"
+        );
+    }
+}
+
+#[test]
+fn grammars_are_pinned_to_a_commit() {
+    // A branch would be refetched on every build and could drift away from
+    // the queries in `languages/`.
+    for name in ["extension.toml", "extension-simple.toml"] {
+        for (id, grammar) in read_manifest(name).grammars {
+            assert!(
+                grammar.rev.len() == 40 && grammar.rev.chars().all(|c| c.is_ascii_hexdigit()),
+                "{name}: grammar {id} must be pinned to a commit, got {}",
+                grammar.rev
+            );
+        }
+    }
+}
+
+#[test]
+fn grammar_pins_are_on_the_current_branch() {
+    // Zed clones the repository at `rev`, so the commit has to survive on the
+    // published branch. A rebase or squash that drops it, or a change to the
+    // grammar after it, needs a new pin.
+    let repo = extension_dir().join("..");
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .arg("-C")
+            .arg(&repo)
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    // Packaged sources and shallow clones lack the history to check.
+    if git(&["rev-parse", "--is-shallow-repository"]).as_deref() != Some("false") {
+        return;
+    }
+
+    let repository = read_manifest("extension.toml").repository;
+    for name in ["extension.toml", "extension-simple.toml"] {
+        for (id, grammar) in read_manifest(name).grammars {
+            let Some(path) = grammar.path.filter(|_| grammar.repository == repository) else {
+                continue;
+            };
+            let rev = grammar.rev.as_str();
+            assert!(
+                git(&["merge-base", "--is-ancestor", rev, "HEAD"]).is_some(),
+                "{name}: grammar {id} is pinned to {rev}, which isn't on the current branch"
+            );
+            assert!(
+                git(&["diff", "--quiet", rev, "HEAD", "--", &path]).is_some(),
+                "{name}: {path} changed after {rev}, the commit grammar {id} is pinned to"
+            );
+        }
+    }
+}
+
+#[test]
+fn runnables_have_matching_tasks() {
+    let dir = extension_dir().join("languages/cassandraorm-schema");
+    let runnables = fs::read_to_string(dir.join("runnables.scm")).unwrap();
+    let tasks: Vec<TaskTemplate> =
+        serde_json::from_str(&fs::read_to_string(dir.join("tasks.json")).unwrap())
+            .expect("tasks.json is a list of task templates");
+
+    let tags = runnables
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("(#set! tag "))
+        .map(|tag| tag.trim_end_matches(')'))
+        .collect::<Vec<_>>();
+    assert!(!tags.is_empty());
+    for tag in &tags {
+        assert!(
+            tasks.iter().any(|task| task.tags.iter().any(|t| t == tag)),
+            "no task runs the {tag} runnable"
+        );
+    }
+
+    for task in &tasks {
+        assert!(!task.command.is_empty(), "{} has no command", task.label);
+        // The worktree root isn't the package in a monorepo; the CLI finds
+        // the package from the file's directory.
+        assert_eq!(
+            task.cwd.as_deref(),
+            Some("$ZED_DIRNAME"),
+            "{} must run next to the file",
+            task.label
+        );
+        for tag in &task.tags {
+            assert!(
+                tags.contains(&tag.as_str()),
+                "{} uses unknown tag {tag}",
+                task.label
+            );
+        }
+        for text in task.args.iter().chain([&task.label]) {
+            for (start, prefix) in text.match_indices("$ZED_CUSTOM_") {
+                let capture = text[start + prefix.len()..]
+                    .split(|c: char| !c.is_alphanumeric() && c != '_')
+                    .next()
+                    .unwrap();
+                assert!(
+                    runnables.contains(&format!("@{capture}")),
+                    "{} reads ${{ZED_CUSTOM_{capture}}}, which runnables.scm doesn't capture",
+                    task.label
+                );
+                assert!(
+                    !task.tags.is_empty(),
+                    "{} reads a capture but isn't tied to a runnable",
+                    task.label
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn snippet_files_are_named_after_their_language() {
+    let manifest = read_manifest("extension.toml");
+    let names = languages()
+        .into_iter()
+        .map(|(_, config)| config.name.to_lowercase())
+        .chain(BUILTIN_LANGUAGES.iter().map(|name| name.to_lowercase()))
+        .collect::<Vec<_>>();
+    assert!(!manifest.snippets.is_empty());
+    for path in &manifest.snippets {
+        let path = extension_dir().join(path);
+        assert!(path.is_file(), "{} doesn't exist", path.display());
+        // Zed scopes a snippet file to the language named by its file stem.
+        let stem = path.file_stem().unwrap().to_str().unwrap();
+        assert!(
+            names.iter().any(|name| name == stem),
+            "{} isn't named after a language",
+            path.display()
+        );
+    }
+}
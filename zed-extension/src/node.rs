@@ -0,0 +1,119 @@
+use zed_extension_api::{self as zed, settings::LspSettings, Result};
+
+/// The oldest Node.js release both language servers run on.
+const MIN_NODE_VERSION: NodeVersion = NodeVersion(18, 0, 0);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct NodeVersion(u32, u32, u32);
+
+impl std::fmt::Display for NodeVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "v{}.{}.{}", self.0, self.1, self.2)
+    }
+}
+
+/// Returns the Node binary used to run the given language server.
+///
+/// Zed's managed Node runtime is used unless `lsp.<server>.settings.node.path`
+/// names another binary. The binary's `--version` is checked against
+/// [`MIN_NODE_VERSION`] before it's used.
+pub fn node_binary(language_server_id: &str, worktree: &zed::Worktree) -> Result<String> {
+    let configured = LspSettings::for_worktree(language_server_id, worktree)
+        .ok()
+        .and_then(|settings| settings.settings)
+        .and_then(|settings| {
+            settings
+                .pointer("/node/path")
+                .and_then(|path| path.as_str())
+                .map(str::to_string)
+        });
+
+    let (path, source) = match configured {
+        Some(path) => (path, format!("lsp.{language_server_id}.settings.node.path")),
+        None => (
+            zed::node_binary_path()?,
+            "Zed's managed Node runtime".to_string(),
+        ),
+    };
+
+    let output = zed::process::Command::new(&path)
+        .arg("--version")
+        .output()
+        .and_then(|output| match output.status {
+            Some(0) => Ok(String::from_utf8_lossy(&output.stdout).into_owned()),
+            _ => Err(String::from_utf8_lossy(&output.stderr).trim().to_string()),
+        });
+    check_version(output, &path, &source, language_server_id)?;
+    Ok(path)
+}
+
+/// Checks the output of `<path> --version`.
+fn check_version(
+    output: Result<String>,
+    path: &str,
+    source: &str,
+    language_server_id: &str,
+) -> Result<()> {
+    let fix = format!("Set lsp.{language_server_id}.settings.node.path to a Node binary.");
+    let output = output
+        .map_err(|err| format!("{source} ({path}) failed to report its version: {err}. {fix}"))?;
+    let version = parse_version(&output).ok_or_else(|| {
+        format!(
+            "{source} ({path}) reported an unexpected version {:?}. {fix}",
+            output.trim()
+        )
+    })?;
+    if version < MIN_NODE_VERSION {
+        return Err(format!(
+            "{language_server_id} needs Node.js {MIN_NODE_VERSION} or newer, but {source} \
+             points at Node.js {version} ({path}). Set lsp.{language_server_id}.settings.node.path \
+             to a newer Node binary."
+        ));
+    }
+    Ok(())
+}
+
+/// Parses the `v22.5.1` printed by `node --version`.
+fn parse_version(output: &str) -> Option<NodeVersion> {
+    let mut parts = output
+        .trim()
+        .strip_prefix('v')?
+        .split('.')
+        .map(|part| part.parse::<u32>().ok());
+    let version = NodeVersion(parts.next()??, parts.next()??, parts.next()??);
+    parts.next().is_none().then_some(version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_node_version_output() {
+        assert_eq!(parse_version("v16.20.2\n"), Some(NodeVersion(16, 20, 2)));
+        assert_eq!(parse_version("v22.5.1"), Some(NodeVersion(22, 5, 1)));
+        assert_eq!(parse_version("Welcome to Node.js"), None);
+        assert_eq!(parse_version("v18.0"), None);
+    }
+
+    #[test]
+    fn rejects_node_older_than_the_minimum() {
+        // Volta and Homebrew paths don't name the version, so only the
+        // binary's own output counts.
+        let path = "/home/dev/.volta/tools/image/node/16.20.2/bin/node";
+        let check =
+            |output: Result<String>| check_version(output, path, "the test", "cassandraorm-lsp");
+
+        let err = check(Ok("v16.20.2\n".to_string())).unwrap_err();
+        assert!(err.contains("v18.0.0"), "{err}");
+        assert!(err.contains("v16.20.2"), "{err}");
+        assert!(check(Ok("v18.17.0\n".to_string())).is_ok());
+
+        let err = check(Err("exec format error".to_string())).unwrap_err();
+        assert!(
+            err.contains("failed to report its version: exec format error"),
+            "{err}"
+        );
+        assert!(check(Ok("nope".to_string())).is_err());
+    }
+}
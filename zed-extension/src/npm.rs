@@ -0,0 +1,109 @@
+use std::{env, fs};
+use zed_extension_api::{self as zed, LanguageServerId, LanguageServerInstallationStatus, Result};
+
+/// A language server distributed as an npm package and installed into the
+/// extension's working directory.
+pub struct NpmServer {
+    /// The package that provides the server entry point.
+    package: &'static str,
+    /// Packages the server needs next to it at runtime.
+    peer_packages: &'static [&'static str],
+    /// The server entry point, relative to the extension's working directory.
+    entry_point: &'static str,
+    /// Set once the server has been installed or verified during this session.
+    cached_entry_point: Option<String>,
+}
+
+impl NpmServer {
+    pub const fn new(
+        package: &'static str,
+        peer_packages: &'static [&'static str],
+        entry_point: &'static str,
+    ) -> Self {
+        Self {
+            package,
+            peer_packages,
+            entry_point,
+            cached_entry_point: None,
+        }
+    }
+
+    /// Returns the absolute path of the server entry point, installing or
+    /// updating the package first when needed.
+    ///
+    /// The latest version is only looked up once per session. If the registry
+    /// can't be reached, an already installed version keeps being used.
+    pub fn entry_point(&mut self, language_server_id: &LanguageServerId) -> Result<String> {
+        if let Some(path) = &self.cached_entry_point {
+            if self.is_installed() {
+                return Ok(path.clone());
+            }
+        }
+
+        let result = self.install_or_update(language_server_id);
+        let status = match &result {
+            Ok(_) => LanguageServerInstallationStatus::None,
+            Err(err) => LanguageServerInstallationStatus::Failed(err.clone()),
+        };
+        zed::set_language_server_installation_status(language_server_id, &status);
+
+        let path = result?;
+        self.cached_entry_point = Some(path.clone());
+        Ok(path)
+    }
+
+    fn install_or_update(&self, language_server_id: &LanguageServerId) -> Result<String> {
+        zed::set_language_server_installation_status(
+            language_server_id,
+            &LanguageServerInstallationStatus::CheckingForUpdate,
+        );
+
+        for package in std::iter::once(&self.package).chain(self.peer_packages) {
+            let latest_version = match zed::npm_package_latest_version(package) {
+                Ok(version) => version,
+                Err(err) if self.is_installed() => {
+                    eprintln!(
+                        "failed to check for {package} updates, keeping installed version: {err}"
+                    );
+                    continue;
+                }
+                Err(err) => return Err(format!("failed to look up {package} on npm: {err}")),
+            };
+
+            let installed_version = zed::npm_package_installed_version(package)?;
+            if installed_version.as_deref() == Some(latest_version.as_str()) {
+                continue;
+            }
+
+            zed::set_language_server_installation_status(
+                language_server_id,
+                &LanguageServerInstallationStatus::Downloading,
+            );
+            if let Err(err) = zed::npm_install_package(package, &latest_version) {
+                if installed_version.is_none() {
+                    return Err(format!(
+                        "failed to install {package}@{latest_version}: {err}"
+                    ));
+                }
+                eprintln!("failed to update {package} to {latest_version}, keeping installed version: {err}");
+            }
+        }
+
+        if !self.is_installed() {
+            return Err(format!(
+                "installed package {} does not contain {}",
+                self.package, self.entry_point
+            ));
+        }
+
+        Ok(env::current_dir()
+            .map_err(|err| format!("failed to read the extension directory: {err}"))?
+            .join(self.entry_point)
+            .to_string_lossy()
+            .into_owned())
+    }
+
+    fn is_installed(&self) -> bool {
+        fs::metadata(self.entry_point).is_ok_and(|metadata| metadata.is_file())
+    }
+}
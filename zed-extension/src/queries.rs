@@ -0,0 +1,305 @@
+//! Runs every query in `languages/` over the fixtures in `tests/fixtures/` and
+//! compares the captures with the snapshots in `tests/snapshots/`. Zed ignores
+//! a query that fails to compile and a pattern that stops matching just loses
+//! its highlight, so both only show up here.
+//!
+//! After an intended change, rerun with `UPDATE_SNAPSHOTS=1` and review the
+//! snapshot diff.
+
+use crate::manifest::{extension_dir, languages};
+use std::cmp::Reverse;
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tree_sitter::{Language, Node, Parser, Query, QueryCursor, Range, StreamingIterator, Tree};
+
+/// Captured text longer than this is cut in snapshots.
+const MAX_TEXT_LEN: usize = 48;
+
+fn grammar(name: &str) -> Language {
+    match name {
+        "typescript" => tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
+        "cql" => tree_sitter_cql::LANGUAGE.into(),
+        _ => panic!("no tree-sitter grammar for {name}"),
+    }
+}
+
+fn parse(language: &Language, source: &str) -> Tree {
+    let mut parser = Parser::new();
+    parser.set_language(language).unwrap();
+    parser.parse(source, None).unwrap()
+}
+
+/// Returns the files in `dir` with the given extension, sorted by name.
+fn files(dir: &Path, extension: Option<&str>) -> Vec<PathBuf> {
+    let mut files = fs::read_dir(dir)
+        .unwrap_or_else(|err| panic!("failed to read {}: {err}", dir.display()))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_file())
+        .filter(|path| {
+            extension.is_none() || path.extension().and_then(|e| e.to_str()) == extension
+        })
+        .collect::<Vec<_>>();
+    files.sort();
+    files
+}
+
+fn file_name(path: &Path) -> &str {
+    path.file_name().unwrap().to_str().unwrap()
+}
+
+/// Renders the captures of `query` one per line, in document order. Captures
+/// of the same node keep the order of their patterns, since Zed lets the last
+/// one win. Captures starting with `_` only feed predicates and are left out.
+fn render(query: &Query, tree: &Tree, source: &str) -> String {
+    let mut captures = Vec::new();
+    let mut cursor = QueryCursor::new();
+    let mut matches = cursor.matches(query, tree.root_node(), source.as_bytes());
+    while let Some(m) = matches.next() {
+        let properties = query
+            .property_settings(m.pattern_index)
+            .iter()
+            .map(|property| match &property.value {
+                Some(value) => format!(" ({} {value})", property.key),
+                None => format!(" ({})", property.key),
+            })
+            .collect::<String>();
+        for capture in m.captures {
+            let name = &query.capture_names()[capture.index as usize];
+            if name.starts_with('_') {
+                continue;
+            }
+            let node = capture.node;
+            let (start, end) = (node.start_position(), node.end_position());
+            let mut text = source[node.byte_range()].to_string();
+            if let Some(line_end) = text.find('\n') {
+                text.truncate(line_end);
+                text.push('…');
+            }
+            if text.chars().count() > MAX_TEXT_LEN {
+                text = text.chars().take(MAX_TEXT_LEN).collect::<String>() + "…";
+            }
+            let line = format!(
+                "{}:{}-{}:{} @{name} {text:?}{properties}",
+                start.row + 1,
+                start.column + 1,
+                end.row + 1,
+                end.column + 1,
+            );
+            captures.push(((node.start_byte(), node.end_byte()), m.pattern_index, line));
+        }
+    }
+    captures.sort_by_key(|&((start, end), pattern, _)| (start, Reverse(end), pattern));
+    captures.dedup_by(|a, b| a.2 == b.2);
+    captures
+        .into_iter()
+        .fold(String::new(), |mut output, (_, _, line)| {
+            writeln!(output, "{line}").unwrap();
+            output
+        })
+}
+
+/// Returns what `injections.scm` hands to the CQL grammar: the text of each
+/// separate injection, and the ranges of the combined ones, which Zed parses
+/// together as one document. Content nodes are leaves or include their
+/// children, so their whole range is injected.
+fn injected_cql(query: &Query, tree: &Tree, source: &str) -> (Vec<String>, Vec<Range>) {
+    let content = query.capture_index_for_name("injection.content").unwrap();
+    let mut separate = Vec::new();
+    let mut combined = Vec::new();
+    let mut cursor = QueryCursor::new();
+    let mut matches = cursor.matches(query, tree.root_node(), source.as_bytes());
+    while let Some(m) = matches.next() {
+        let properties = query.property_settings(m.pattern_index);
+        let language = properties
+            .iter()
+            .find(|property| &*property.key == "injection.language")
+            .and_then(|property| property.value.as_deref());
+        if language != Some("cql") {
+            continue;
+        }
+        let is_combined = properties
+            .iter()
+            .any(|property| &*property.key == "injection.combined");
+        for capture in m.captures.iter().filter(|c| c.index == content) {
+            if is_combined {
+                combined.push(capture.node.range());
+            } else {
+                separate.push(source[capture.node.byte_range()].to_string());
+            }
+        }
+    }
+    combined.sort_by_key(|range| range.start_byte);
+    combined.dedup();
+    (separate, combined)
+}
+
+/// Renders the statements of the combined CQL document one per line, each
+/// followed by the interpolations in it.
+fn render_combined(tree: &Tree) -> String {
+    fn position(node: Node) -> String {
+        let (start, end) = (node.start_position(), node.end_position());
+        format!(
+            "{}:{}-{}:{}",
+            start.row + 1,
+            start.column + 1,
+            end.row + 1,
+            end.column + 1
+        )
+    }
+    fn interpolations<'a>(node: Node<'a>, found: &mut Vec<Node<'a>>) {
+        if node.kind() == "interpolation" {
+            found.push(node);
+        }
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            interpolations(child, found);
+        }
+    }
+
+    let mut output = String::new();
+    let root = tree.root_node();
+    let mut cursor = root.walk();
+    for statement in root.named_children(&mut cursor) {
+        writeln!(output, "{} {}", position(statement), statement.kind()).unwrap();
+        let mut found = Vec::new();
+        interpolations(statement, &mut found);
+        for interpolation in found {
+            writeln!(output, "  {} interpolation", position(interpolation)).unwrap();
+        }
+    }
+    output
+}
+
+#[test]
+fn queries_match_their_snapshots() {
+    let update = std::env::var_os("UPDATE_SNAPSHOTS").is_some();
+    let fixtures_dir = extension_dir().join("tests/fixtures");
+    let snapshots_dir = extension_dir().join("tests/snapshots");
+    let mut failures = Vec::new();
+    let mut snapshots = BTreeSet::new();
+
+    for (dir, config) in languages() {
+        let language = grammar(&config.grammar);
+        let dir_name = file_name(&dir).to_string();
+        let fixtures = files(&fixtures_dir.join(&dir_name), None);
+        assert!(!fixtures.is_empty(), "{} has no fixtures", config.name);
+
+        let queries = files(&dir, Some("scm"))
+            .into_iter()
+            .map(|path| {
+                let source = fs::read_to_string(&path).unwrap();
+                let query = Query::new(&language, &source)
+                    .unwrap_or_else(|err| panic!("{}: {err}", path.display()));
+                (file_name(&path).trim_end_matches(".scm").to_string(), query)
+            })
+            .collect::<Vec<_>>();
+
+        let mut unused = queries
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect::<BTreeSet<_>>();
+        for fixture in &fixtures {
+            let source = fs::read_to_string(fixture).unwrap();
+            let tree = parse(&language, &source);
+            assert!(
+                !tree.root_node().has_error(),
+                "{} doesn't parse: {}",
+                fixture.display(),
+                tree.root_node().to_sexp()
+            );
+
+            for (name, query) in &queries {
+                let rendered = render(query, &tree, &source);
+                if !rendered.is_empty() {
+                    unused.remove(name);
+                }
+                let path = snapshots_dir
+                    .join(&dir_name)
+                    .join(format!("{}.{name}.snap", file_name(fixture)));
+                snapshots.insert(path.clone());
+                check_snapshot(&path, &rendered, update, &mut failures);
+            }
+
+            if let Some((_, injections)) = queries.iter().find(|(name, _)| name == "injections") {
+                let cql = grammar("cql");
+                let (separate, combined) = injected_cql(injections, &tree, &source);
+                for text in separate {
+                    let injected = parse(&cql, &text);
+                    assert!(
+                        !injected.root_node().has_error(),
+                        "{} injects text the CQL grammar can't parse: {text:?}",
+                        fixture.display()
+                    );
+                }
+                if !combined.is_empty() {
+                    let mut parser = Parser::new();
+                    parser.set_language(&cql).unwrap();
+                    parser.set_included_ranges(&combined).unwrap();
+                    let injected = parser.parse(&source, None).unwrap();
+                    assert!(
+                        !injected.root_node().has_error(),
+                        "{} combines CQL the CQL grammar can't parse: {}",
+                        fixture.display(),
+                        injected.root_node().to_sexp()
+                    );
+                    let path = snapshots_dir
+                        .join(&dir_name)
+                        .join(format!("{}.injections.cql.snap", file_name(fixture)));
+                    snapshots.insert(path.clone());
+                    check_snapshot(&path, &render_combined(&injected), update, &mut failures);
+                }
+            }
+        }
+        assert!(
+            unused.is_empty(),
+            "{} queries capture nothing in the fixtures: {unused:?}",
+            config.name
+        );
+    }
+
+    // Snapshots of removed fixtures or queries would otherwise linger.
+    for dir in files_recursive(&snapshots_dir) {
+        if !snapshots.contains(&dir) {
+            if update {
+                fs::remove_file(&dir).unwrap();
+            } else {
+                failures.push(format!("{} is stale", dir.display()));
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "captures don't match their snapshots; rerun with UPDATE_SNAPSHOTS=1 if the change is intended\n\n{}",
+        failures.join("\n")
+    );
+}
+
+/// Compares `rendered` with the snapshot at `path`, or writes it there when
+/// updating.
+fn check_snapshot(path: &Path, rendered: &str, update: bool, failures: &mut Vec<String>) {
+    if update {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, rendered).unwrap();
+    } else if fs::read_to_string(path).ok().as_deref() != Some(rendered) {
+        failures.push(format!("{}:\n{rendered}", path.display()));
+    }
+}
+
+fn files_recursive(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .map(|entry| entry.unwrap().path())
+        .flat_map(|path| {
+            if path.is_dir() {
+                files_recursive(&path)
+            } else {
+                vec![path]
+            }
+        })
+        .collect()
+}
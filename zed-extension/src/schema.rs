@@ -0,0 +1,1213 @@
+//! Typed model schemas, mirroring `ModelSchema` and the definitions it uses in
+//! `src/core/types.ts`. Schemas are read from the `loadSchema` calls and
+//! exported schema objects of a document with [`find_models`].
+//!
+//! Besides the shapes in `types.ts`, the forms the ORM still accepts from
+//! express-cassandra are read too: `typeDef` on collection fields and
+//! `indexes` given as a list of columns. Keys the ORM doesn't know are ignored,
+//! and every definition keeps the span of the source it came from.
+
+use crate::cql_type::{self, CqlType};
+use crate::literal::{
+    self, Error, Lexer, Property, Span, Spanned, Token, TokenKind, Value, ValueKind,
+};
+
+/// A model found in a document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Model {
+    /// The name passed to `loadSchema`, or the exported schema's table name.
+    pub name: Spanned<String>,
+    pub schema: Result<ModelSchema, Error>,
+    pub declaration: Declaration,
+}
+
+/// How a model is declared, which code adding a model next to it follows.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Declaration {
+    /// A `loadSchema` call. `head` is the source before `loadSchema`, from
+    /// the variable its result is assigned to, if any, through the receiver,
+    /// as in `export const Users = await client.`.
+    LoadSchema {
+        head: Span,
+        binding: Option<Spanned<String>>,
+    },
+    /// An exported schema object. `head` is the source before the object, as
+    /// in `export const usersSchema = `.
+    Export {
+        head: Span,
+        binding: Spanned<String>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelSchema {
+    pub span: Span,
+    pub fields: Vec<FieldDefinition>,
+    pub key: Option<PrimaryKey>,
+    pub unique: Vec<Spanned<String>>,
+    pub clustering_order: Vec<ClusteringOrder>,
+    pub relations: Vec<RelationDefinition>,
+    pub indexes: Vec<IndexDefinition>,
+    pub materialized_views: Vec<MaterializedViewDefinition>,
+    pub options: Option<ModelOptions>,
+    pub table_name: Option<Spanned<String>>,
+    pub methods: Vec<Spanned<String>>,
+}
+
+impl ModelSchema {
+    pub fn field(&self, name: &str) -> Option<&FieldDefinition> {
+        self.fields.iter().find(|field| field.name.value == name)
+    }
+
+    /// The table the model is stored in: `table_name`, `options.table_name`,
+    /// or else the model name.
+    pub fn table_name<'a>(&'a self, model_name: &'a str) -> &'a str {
+        self.table_name
+            .as_ref()
+            .or_else(|| self.options.as_ref()?.table_name.as_ref())
+            .map_or(model_name, |name| &name.value)
+    }
+
+    /// The stored fields whose type is `counter`. Cassandra calls a table with
+    /// any of them a counter table.
+    pub fn counter_fields(&self) -> Vec<&FieldDefinition> {
+        self.fields
+            .iter()
+            .filter(|field| field.is_stored() && field.parse_type().is_ok_and(|ty| ty.is_counter()))
+            .collect()
+    }
+
+    /// The columns `timestamps` and `versions` add besides the fields, with
+    /// express-cassandra's default names, as `(name, CQL type)`.
+    pub fn generated_columns(&self) -> Vec<(String, &'static str)> {
+        let Some(options) = &self.options else {
+            return Vec::new();
+        };
+        let mut columns = Vec::new();
+        if let Some(timestamps) = options.timestamps.as_ref().filter(|t| t.enabled) {
+            for (name, default) in [
+                (&timestamps.created_at, "createdAt"),
+                (&timestamps.updated_at, "updatedAt"),
+            ] {
+                let name = name.as_ref().map_or(default, |name| &name.value);
+                columns.push((name.to_string(), "timestamp"));
+            }
+        }
+        if let Some(versions) = options.versions.as_ref().filter(|v| v.enabled) {
+            let key = versions.key.as_ref().map_or("__v", |key| &key.value);
+            columns.push((key.to_string(), "timeuuid"));
+        }
+        columns
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldDefinition {
+    pub name: Spanned<String>,
+    /// The whole `name: definition` property.
+    pub span: Span,
+    /// The `type`, or the string of a shorthand field such as `id: 'uuid'`.
+    pub cql_type: Spanned<String>,
+    /// Type arguments given separately, as in `{ type: 'set', typeDef: '<text>' }`.
+    pub type_def: Option<Spanned<String>>,
+    pub unique: Option<Spanned<bool>>,
+    pub required: Option<Spanned<bool>>,
+    pub default: Option<Value>,
+    pub is_virtual: Option<Spanned<bool>>,
+    pub validate: Option<Validation>,
+}
+
+impl FieldDefinition {
+    /// Parses the CQL type, together with its `typeDef` if it has one. Spans
+    /// point into the `type` and `typeDef` strings, or cover a whole string
+    /// that has escape sequences.
+    pub fn parse_type(&self) -> Result<CqlType, Error> {
+        let mut pieces = vec![(self.cql_type.value.as_str(), self.cql_type.span)];
+        if let Some(type_def) = &self.type_def {
+            pieces.push((type_def.value.as_str(), type_def.span));
+        }
+        cql_type::parse_pieces(&pieces)
+    }
+
+    /// Whether the field is a column of the table, rather than `virtual`.
+    pub fn is_stored(&self) -> bool {
+        !self.is_virtual.as_ref().is_some_and(|flag| flag.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Validation {
+    pub span: Span,
+    pub required: Option<Spanned<bool>>,
+    pub is_email: Option<Spanned<bool>>,
+    pub min_length: Option<Spanned<f64>>,
+    pub max_length: Option<Spanned<f64>>,
+    pub min: Option<Spanned<f64>>,
+    pub max: Option<Spanned<f64>>,
+    pub pattern: Option<Value>,
+    pub custom: Option<Value>,
+}
+
+/// A primary key. `key: 'id'` and `key: ['id', 'created_at']` have a single
+/// partition column; `key: [['tenant', 'id'], 'created_at']` has two.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimaryKey {
+    pub span: Span,
+    pub partition: Vec<Spanned<String>>,
+    pub clustering: Vec<Spanned<String>>,
+}
+
+impl PrimaryKey {
+    pub fn columns(&self) -> impl Iterator<Item = &Spanned<String>> {
+        self.partition.iter().chain(&self.clustering)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Order {
+    Asc,
+    Desc,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClusteringOrder {
+    pub column: Spanned<String>,
+    pub order: Spanned<Order>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelationKind {
+    HasOne,
+    HasMany,
+    BelongsTo,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RelationDefinition {
+    pub name: Spanned<String>,
+    pub span: Span,
+    pub model: Spanned<String>,
+    pub foreign_key: Spanned<String>,
+    pub kind: Spanned<RelationKind>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexDefinition {
+    /// The index name, unless the index was listed as a bare column.
+    pub name: Option<Spanned<String>>,
+    pub span: Span,
+    pub target: Vec<Spanned<String>>,
+    pub options: Vec<Property>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaterializedViewDefinition {
+    pub name: Spanned<String>,
+    pub span: Span,
+    pub select: Vec<Spanned<String>>,
+    pub key: PrimaryKey,
+    pub clustering_order: Vec<ClusteringOrder>,
+    pub filters: Option<Value>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelOptions {
+    pub span: Span,
+    pub timestamps: Option<Timestamps>,
+    pub versions: Option<Versions>,
+    pub compaction: Option<Spanned<Vec<Property>>>,
+    pub compression: Option<Spanned<Vec<Property>>>,
+    pub gc_grace_seconds: Option<Spanned<f64>>,
+    pub default_time_to_live: Option<Spanned<f64>>,
+    pub bloom_filter_fp_chance: Option<Spanned<f64>>,
+    pub caching: Option<Spanned<Vec<Property>>>,
+    pub comment: Option<Spanned<String>>,
+    pub table_name: Option<Spanned<String>>,
+}
+
+/// `timestamps: true` or `timestamps: { createdAt, updatedAt }`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timestamps {
+    pub span: Span,
+    pub enabled: bool,
+    pub created_at: Option<Spanned<String>>,
+    pub updated_at: Option<Spanned<String>>,
+}
+
+/// `versions: true` or `versions: { key }`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Versions {
+    pub span: Span,
+    pub enabled: bool,
+    pub key: Option<Spanned<String>>,
+}
+
+/// Finds the models in a document: `loadSchema('name', { ... })` calls, with
+/// the schema inline or declared as a variable in the same document, and
+/// exported schema objects such as `export const usersSchema = { fields }`.
+///
+/// An exported schema is named after the `loadSchema` call that loads it,
+/// which may be a usage example in a comment like the one
+/// `cassandraorm generate model` writes, or else after its `tableName`.
+/// Schemas with neither are left out since their table is decided elsewhere.
+pub fn find_models(source: &str) -> Vec<Model> {
+    let (tokens, comments) = lex(source);
+    let variables = variables(&tokens).collect::<Vec<_>>();
+    let objects = || {
+        variables
+            .iter()
+            .filter(|variable| variable.value.is_punct("{"))
+    };
+
+    let mut models = Vec::new();
+    let mut loaded = Vec::new();
+    for (call, name, schema) in load_schema_calls(&tokens) {
+        let offset = match &schema.kind {
+            TokenKind::Punct("{") => Some(schema.span.start),
+            TokenKind::Identifier(binding) => {
+                loaded.push(binding.clone());
+                objects()
+                    .find(|variable| variable.binding.value == *binding)
+                    .map(|variable| variable.value.span.start)
+            }
+            _ => None,
+        };
+        let Some(offset) = offset else {
+            continue;
+        };
+        models.push(Model {
+            name,
+            schema: parse_schema(source, offset),
+            declaration: load_schema_declaration(&tokens, call, &variables),
+        });
+    }
+
+    let examples = comments
+        .iter()
+        .flat_map(|comment| {
+            let mut lexer = Lexer::new(&source[..comment.end], comment.start);
+            std::iter::from_fn(|| lexer.next_token().ok().flatten()).collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let example_name = |binding: &str| {
+        load_schema_calls(&examples)
+            .find_map(|(_, name, schema)| schema.is_identifier(binding).then_some(name))
+    };
+
+    for variable in objects() {
+        let binding = &variable.binding;
+        if !variable.exported || loaded.contains(&binding.value) {
+            continue;
+        }
+        let Ok(value) = literal::parse_value(source, variable.value.span.start) else {
+            continue;
+        };
+        if value.get("fields").is_none() {
+            continue;
+        }
+        let schema = ModelSchema::from_value(&value);
+        let table_name = schema.as_ref().ok().and_then(|schema| {
+            schema
+                .table_name
+                .as_ref()
+                .or_else(|| schema.options.as_ref()?.table_name.as_ref())
+        });
+        let Some(name) = example_name(&binding.value).or_else(|| table_name.cloned()) else {
+            continue;
+        };
+        models.push(Model {
+            name,
+            schema,
+            declaration: Declaration::Export {
+                head: Span::new(variable.start, variable.value.span.start),
+                binding: binding.clone(),
+            },
+        });
+    }
+    models.sort_by_key(|model| model.name.span.start);
+    models
+}
+
+/// Yields the index of the `loadSchema` token, the model name and the token
+/// starting the schema of each `loadSchema('name', schema)` call, type
+/// arguments such as `loadSchema<User>(...)` included.
+fn load_schema_calls(tokens: &[Token]) -> impl Iterator<Item = (usize, Spanned<String>, &Token)> {
+    tokens.iter().enumerate().filter_map(|(i, token)| {
+        if !token.is_identifier("loadSchema") {
+            return None;
+        }
+        let mut rest = &tokens[i + 1..];
+        if rest.first()?.is_punct("<") {
+            let mut depth = 0;
+            let close = rest.iter().position(|token| {
+                if token.is_punct("<") {
+                    depth += 1;
+                } else if token.is_punct(">") {
+                    depth -= 1;
+                }
+                depth == 0
+            })?;
+            rest = &rest[close + 1..];
+        }
+        let [open, name, comma, schema, ..] = rest else {
+            return None;
+        };
+        let TokenKind::String(model) = &name.kind else {
+            return None;
+        };
+        if !open.is_punct("(") || !comma.is_punct(",") {
+            return None;
+        }
+        Some((i, Spanned::new(model.clone(), name.span.shrink(1)), schema))
+    })
+}
+
+/// The declaration of the `loadSchema` call at `tokens[call]`: its receiver,
+/// such as `this.client.`, an `await`, and the variable it's assigned to.
+fn load_schema_declaration(tokens: &[Token], call: usize, variables: &[Variable]) -> Declaration {
+    let mut start = call;
+    while start >= 2
+        && tokens[start - 1].is_punct(".")
+        && matches!(tokens[start - 2].kind, TokenKind::Identifier(_))
+    {
+        start -= 2;
+    }
+    if start >= 1 && tokens[start - 1].is_identifier("await") {
+        start -= 1;
+    }
+    let end = tokens[call].span.start;
+    let variable = variables
+        .iter()
+        .find(|variable| start < call && variable.value.span == tokens[start].span);
+    Declaration::LoadSchema {
+        head: Span::new(
+            variable.map_or(tokens[start].span.start, |variable| variable.start),
+            end,
+        ),
+        binding: variable.map(|variable| variable.binding.clone()),
+    }
+}
+
+/// Finds the user-defined types a document declares in `udts` objects, such
+/// as the `ormOptions` of `createClient({ ormOptions: { udts: { address } } })`.
+pub fn find_user_types(source: &str) -> Vec<String> {
+    let tokens = tokens(source);
+    let mut names = Vec::new();
+    for (i, token) in tokens.iter().enumerate() {
+        let [colon, open, ..] = &tokens[i + 1..] else {
+            continue;
+        };
+        if !token.is_identifier("udts") || !colon.is_punct(":") || !open.is_punct("{") {
+            continue;
+        }
+        let Ok(value) = literal::parse_value(source, open.span.start) else {
+            continue;
+        };
+        for property in value.as_properties().unwrap_or_default() {
+            names.push(property.key.value.clone());
+        }
+    }
+    names
+}
+
+/// Parses the schema object starting at byte `offset` of `source`.
+pub fn parse_schema(source: &str, offset: usize) -> Result<ModelSchema, Error> {
+    ModelSchema::from_value(&literal::parse_value(source, offset)?)
+}
+
+/// Lexes as much of `source` as possible. Schemas are usually near the top of
+/// a file, so text the lexer can't handle (such as JSX) just ends the scan.
+fn tokens(source: &str) -> Vec<Token> {
+    lex(source).0
+}
+
+/// Like [`tokens`], also returning the comments among them.
+fn lex(source: &str) -> (Vec<Token>, Vec<Span>) {
+    let mut lexer = Lexer::new(source, 0);
+    let tokens = std::iter::from_fn(|| lexer.next_token().ok().flatten()).collect();
+    (tokens, lexer.comments().to_vec())
+}
+
+/// A `const name = value` declaration, with an optional type annotation and
+/// `export`.
+struct Variable<'a> {
+    /// Where `export`, or else `const`, starts
+    start: usize,
+    binding: Spanned<String>,
+    /// The first token of the value
+    value: &'a Token,
+    exported: bool,
+}
+
+/// Yields the variable declarations among `tokens`.
+fn variables(tokens: &[Token]) -> impl Iterator<Item = Variable<'_>> {
+    tokens.iter().enumerate().filter_map(|(i, token)| {
+        if !["const", "let", "var"]
+            .iter()
+            .any(|k| token.is_identifier(k))
+        {
+            return None;
+        }
+        let TokenKind::Identifier(name) = &tokens.get(i + 1)?.kind else {
+            return None;
+        };
+        let mut rest = tokens[i + 2..].iter();
+        let mut next = rest.next()?;
+        if next.is_punct(":") {
+            // Skip a type annotation such as `ModelSchema` or `Schema<User>`.
+            next = rest.find(|token| token.is_punct("="))?;
+        }
+        if !next.is_punct("=") {
+            return None;
+        }
+        let exported = i > 0 && tokens[i - 1].is_identifier("export");
+        Some(Variable {
+            start: tokens[if exported { i - 1 } else { i }].span.start,
+            binding: Spanned::new(name.clone(), tokens[i + 1].span),
+            value: rest.next()?,
+            exported,
+        })
+    })
+}
+
+impl ModelSchema {
+    pub fn from_value(value: &Value) -> Result<Self, Error> {
+        let schema = Fields::new(value, "")?;
+        Ok(Self {
+            span: value.span,
+            fields: schema.required("fields", |fields, path| {
+                object(fields, path)?
+                    .iter()
+                    .map(FieldDefinition::from_property)
+                    .collect()
+            })?,
+            key: schema.optional("key", PrimaryKey::from_value)?,
+            unique: schema.optional("unique", strings)?.unwrap_or_default(),
+            clustering_order: schema
+                .optional("clustering_order", clustering_order)?
+                .unwrap_or_default(),
+            relations: schema
+                .optional("relations", |relations, path| {
+                    object(relations, path)?
+                        .iter()
+                        .map(RelationDefinition::from_property)
+                        .collect()
+                })?
+                .unwrap_or_default(),
+            indexes: schema
+                .optional("indexes", IndexDefinition::list)?
+                .unwrap_or_default(),
+            materialized_views: schema
+                .optional("materialized_views", |views, path| {
+                    object(views, path)?
+                        .iter()
+                        .map(MaterializedViewDefinition::from_property)
+                        .collect()
+                })?
+                .unwrap_or_default(),
+            options: schema.optional("options", ModelOptions::from_value)?,
+            table_name: schema.optional("table_name", string)?,
+            methods: schema
+                .optional("methods", |methods, path| {
+                    Ok(object(methods, path)?
+                        .iter()
+                        .map(|method| method.key.clone())
+                        .collect())
+                })?
+                .unwrap_or_default(),
+        })
+    }
+}
+
+impl FieldDefinition {
+    fn from_property(property: &Property) -> Result<Self, Error> {
+        let name = &property.key.value;
+        let path = format!("fields.{name}");
+        let mut field = Self {
+            name: property.key.clone(),
+            span: property.span(),
+            cql_type: Spanned::new(String::new(), property.value.span),
+            type_def: None,
+            unique: None,
+            required: None,
+            default: None,
+            is_virtual: None,
+            validate: None,
+        };
+        match &property.value.kind {
+            ValueKind::String(cql_type) => {
+                field.cql_type = Spanned::new(cql_type.clone(), property.value.span.shrink(1));
+            }
+            ValueKind::Object(_) => {
+                let definition = Fields::new(&property.value, &path)?;
+                field.cql_type = definition.required("type", string)?;
+                field.type_def = definition.optional("typeDef", string)?;
+                field.unique = definition.optional("unique", boolean)?;
+                field.required = definition.optional("required", boolean)?;
+                field.default = definition.value("default").cloned();
+                field.is_virtual = definition.optional("virtual", boolean)?;
+                field.validate = definition.optional("validate", Validation::from_value)?;
+            }
+            _ => {
+                return Err(invalid(
+                    &path,
+                    "expected a CQL type or a field definition",
+                    property.value.span,
+                ))
+            }
+        }
+        Ok(field)
+    }
+}
+
+impl Validation {
+    fn from_value(value: &Value, path: &str) -> Result<Self, Error> {
+        let rules = Fields::new(value, path)?;
+        Ok(Self {
+            span: value.span,
+            required: rules.optional("required", boolean)?,
+            is_email: rules.optional("isEmail", boolean)?,
+            min_length: rules.optional("minLength", number)?,
+            max_length: rules.optional("maxLength", number)?,
+            min: rules.optional("min", number)?,
+            max: rules.optional("max", number)?,
+            pattern: rules.value("pattern").cloned(),
+            custom: rules.value("custom").cloned(),
+        })
+    }
+}
+
+impl PrimaryKey {
+    fn from_value(value: &Value, path: &str) -> Result<Self, Error> {
+        let mut key = Self {
+            span: value.span,
+            partition: Vec::new(),
+            clustering: Vec::new(),
+        };
+        match &value.kind {
+            ValueKind::String(_) => key.partition.push(string(value, path)?),
+            ValueKind::Array(items) => {
+                let Some((first, rest)) = items.split_first() else {
+                    return Err(invalid(path, "the key is empty", value.span));
+                };
+                key.partition = match first.kind {
+                    ValueKind::Array(_) => strings(first, &format!("{path}[0]"))?,
+                    _ => vec![string(first, &format!("{path}[0]"))?],
+                };
+                key.clustering = rest
+                    .iter()
+                    .enumerate()
+                    .map(|(i, item)| string(item, &format!("{path}[{}]", i + 1)))
+                    .collect::<Result<_, _>>()?;
+            }
+            _ => {
+                return Err(invalid(
+                    path,
+                    "expected a column or a list of columns",
+                    value.span,
+                ))
+            }
+        }
+        Ok(key)
+    }
+}
+
+impl RelationDefinition {
+    fn from_property(property: &Property) -> Result<Self, Error> {
+        let path = format!("relations.{}", property.key.value);
+        let relation = Fields::new(&property.value, &path)?;
+        Ok(Self {
+            name: property.key.clone(),
+            span: property.span(),
+            model: relation.required("model", string)?,
+            foreign_key: relation.required("foreignKey", string)?,
+            kind: relation.required("type", |value, path| {
+                let kind = string(value, path)?;
+                let relation = match kind.value.as_str() {
+                    "hasOne" => RelationKind::HasOne,
+                    "hasMany" => RelationKind::HasMany,
+                    "belongsTo" => RelationKind::BelongsTo,
+                    _ => {
+                        return Err(invalid(
+                            path,
+                            "expected 'hasOne', 'hasMany' or 'belongsTo'",
+                            kind.span,
+                        ))
+                    }
+                };
+                Ok(Spanned::new(relation, kind.span))
+            })?,
+        })
+    }
+}
+
+impl IndexDefinition {
+    /// Reads `indexes: { name: { target } }` or `indexes: ['column', ...]`.
+    fn list(value: &Value, path: &str) -> Result<Vec<Self>, Error> {
+        if let ValueKind::Array(items) = &value.kind {
+            return items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| {
+                    Ok(Self {
+                        name: None,
+                        span: item.span,
+                        target: vec![string(item, &format!("{path}[{i}]"))?],
+                        options: Vec::new(),
+                    })
+                })
+                .collect();
+        }
+        object(value, path)?
+            .iter()
+            .map(|property| {
+                let path = format!("{path}.{}", property.key.value);
+                let index = Fields::new(&property.value, &path)?;
+                Ok(Self {
+                    name: Some(property.key.clone()),
+                    span: property.span(),
+                    target: index.required("target", |value, path| match value.kind {
+                        ValueKind::Array(_) => strings(value, path),
+                        _ => Ok(vec![string(value, path)?]),
+                    })?,
+                    options: index
+                        .optional("options", |value, path| Ok(object(value, path)?.to_vec()))?
+                        .unwrap_or_default(),
+                })
+            })
+            .collect()
+    }
+}
+
+impl MaterializedViewDefinition {
+    fn from_property(property: &Property) -> Result<Self, Error> {
+        let path = format!("materialized_views.{}", property.key.value);
+        let view = Fields::new(&property.value, &path)?;
+        Ok(Self {
+            name: property.key.clone(),
+            span: property.span(),
+            select: view.required("select", strings)?,
+            key: view.required("key", PrimaryKey::from_value)?,
+            clustering_order: view
+                .optional("clustering_order", clustering_order)?
+                .unwrap_or_default(),
+            filters: view.value("filters").cloned(),
+        })
+    }
+}
+
+impl ModelOptions {
+    fn from_value(value: &Value, path: &str) -> Result<Self, Error> {
+        let options = Fields::new(value, path)?;
+        let map =
+            |value: &Value, path: &str| Ok(Spanned::new(object(value, path)?.to_vec(), value.span));
+        Ok(Self {
+            span: value.span,
+            timestamps: options.optional("timestamps", |value, path| {
+                let (enabled, fields) = toggle(value, path)?;
+                Ok(Timestamps {
+                    span: value.span,
+                    enabled,
+                    created_at: fields
+                        .as_ref()
+                        .map(|fields| fields.optional("createdAt", string))
+                        .transpose()?
+                        .flatten(),
+                    updated_at: fields
+                        .as_ref()
+                        .map(|fields| fields.optional("updatedAt", string))
+                        .transpose()?
+                        .flatten(),
+                })
+            })?,
+            versions: options.optional("versions", |value, path| {
+                let (enabled, fields) = toggle(value, path)?;
+                Ok(Versions {
+                    span: value.span,
+                    enabled,
+                    key: fields
+                        .as_ref()
+                        .map(|fields| fields.optional("key", string))
+                        .transpose()?
+                        .flatten(),
+                })
+            })?,
+            compaction: options.optional("compaction", map)?,
+            compression: options.optional("compression", map)?,
+            gc_grace_seconds: options.optional("gc_grace_seconds", number)?,
+            default_time_to_live: options.optional("default_time_to_live", number)?,
+            bloom_filter_fp_chance: options.optional("bloom_filter_fp_chance", number)?,
+            caching: options.optional("caching", map)?,
+            comment: options.optional("comment", string)?,
+            table_name: options.optional("table_name", string)?,
+        })
+    }
+}
+
+/// The properties of an object being read, with the path used in errors.
+struct Fields<'a> {
+    value: &'a Value,
+    path: &'a str,
+}
+
+impl<'a> Fields<'a> {
+    fn new(value: &'a Value, path: &'a str) -> Result<Self, Error> {
+        object(value, path)?;
+        Ok(Self { value, path })
+    }
+
+    fn value(&self, key: &str) -> Option<&'a Value> {
+        self.value.get(key)
+    }
+
+    fn optional<T>(
+        &self,
+        key: &str,
+        read: impl FnOnce(&'a Value, &str) -> Result<T, Error>,
+    ) -> Result<Option<T>, Error> {
+        self.value(key)
+            .map(|value| match self.path {
+                "" => read(value, key),
+                path => read(value, &format!("{path}.{key}")),
+            })
+            .transpose()
+    }
+
+    fn required<T>(
+        &self,
+        key: &str,
+        read: impl FnOnce(&'a Value, &str) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        self.optional(key, read)?
+            .ok_or_else(|| invalid(self.path, &format!("missing `{key}`"), self.value.span))
+    }
+}
+
+/// Creates an error about the value at `path`, such as `fields.email.type`.
+fn invalid(path: &str, message: &str, span: Span) -> Error {
+    match path {
+        "" => Error::new(message, span),
+        path => Error::new(format!("{path}: {message}"), span),
+    }
+}
+
+fn object<'a>(value: &'a Value, path: &str) -> Result<&'a [Property], Error> {
+    value
+        .as_properties()
+        .ok_or_else(|| invalid(path, "expected an object", value.span))
+}
+
+fn string(value: &Value, path: &str) -> Result<Spanned<String>, Error> {
+    match value.as_str() {
+        Some(text) => Ok(Spanned::new(text.to_string(), value.span.shrink(1))),
+        None => Err(invalid(path, "expected a string", value.span)),
+    }
+}
+
+fn strings(value: &Value, path: &str) -> Result<Vec<Spanned<String>>, Error> {
+    match &value.kind {
+        ValueKind::Array(items) => items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| string(item, &format!("{path}[{i}]")))
+            .collect(),
+        _ => Err(invalid(path, "expected a list of strings", value.span)),
+    }
+}
+
+fn boolean(value: &Value, path: &str) -> Result<Spanned<bool>, Error> {
+    match value.kind {
+        ValueKind::Bool(flag) => Ok(Spanned::new(flag, value.span)),
+        _ => Err(invalid(path, "expected true or false", value.span)),
+    }
+}
+
+fn number(value: &Value, path: &str) -> Result<Spanned<f64>, Error> {
+    match value.kind {
+        ValueKind::Number(number) => Ok(Spanned::new(number, value.span)),
+        _ => Err(invalid(path, "expected a number", value.span)),
+    }
+}
+
+/// Reads `true`, `false` or an object of settings, which enables the option.
+fn toggle<'a>(value: &'a Value, path: &'a str) -> Result<(bool, Option<Fields<'a>>), Error> {
+    match value.kind {
+        ValueKind::Bool(enabled) => Ok((enabled, None)),
+        ValueKind::Object(_) => Ok((true, Some(Fields::new(value, path)?))),
+        _ => Err(invalid(
+            path,
+            "expected true, false or an object",
+            value.span,
+        )),
+    }
+}
+
+fn clustering_order(value: &Value, path: &str) -> Result<Vec<ClusteringOrder>, Error> {
+    object(value, path)?
+        .iter()
+        .map(|property| {
+            let path = format!("{path}.{}", property.key.value);
+            let order = string(&property.value, &path)?;
+            let direction = match order.value.to_ascii_lowercase().as_str() {
+                "asc" => Order::Asc,
+                "desc" => Order::Desc,
+                _ => return Err(invalid(&path, "expected 'ASC' or 'DESC'", order.span)),
+            };
+            Ok(ClusteringOrder {
+                column: property.key.clone(),
+                order: Spanned::new(direction, order.span),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(source: &str, span: Span) -> &str {
+        &source[span.start..span.end]
+    }
+
+    fn names(items: &[Spanned<String>]) -> Vec<&str> {
+        items.iter().map(|item| item.value.as_str()).collect()
+    }
+
+    const USERS: &str = r#"
+const client = createClient({ clientOptions: { keyspace: 'myapp' } });
+
+export const Users = await client.loadSchema('users', {
+  fields: {
+    tenant: 'uuid',
+    id: 'timeuuid',
+    email: {
+      type: 'text',
+      unique: true,
+      validate: { required: true, isEmail: true, maxLength: 255, pattern: /^\S+@\S+$/ }
+    },
+    tags: { type: 'set', typeDef: '<text>' },
+    created_at: { type: 'timestamp', default: () => new Date() },
+    full_name: { type: 'text', virtual: true }
+  },
+  key: [['tenant'], 'id'],
+  clustering_order: { id: 'DESC' as const },
+  relations: {
+    posts: { model: 'posts', foreignKey: 'user_id', type: 'hasMany' }
+  },
+  indexes: {
+    users_email_idx: { target: 'email', options: { mode: 'CONTAINS' } }
+  },
+  materialized_views: {
+    users_by_email: { select: ['*'], key: ['email', 'tenant', 'id'], clustering_order: { id: 'asc' } }
+  },
+  options: {
+    timestamps: { createdAt: 'created_at' },
+    versions: true,
+    compaction: { class: 'LeveledCompactionStrategy' },
+    gc_grace_seconds: 864000,
+    comment: 'Application users'
+  },
+  methods: {
+    greet() { return `hi ${this.email}`; }
+  },
+  before_save: (instance) => true
+});
+"#;
+
+    #[test]
+    fn load_schema_calls_are_parsed_into_typed_schemas() {
+        let models = find_models(USERS);
+        assert_eq!(models.len(), 1);
+        let model = &models[0];
+        assert_eq!(model.name.value, "users");
+        assert_eq!(text(USERS, model.name.span), "users");
+
+        let schema = model.schema.as_ref().unwrap();
+        assert!(text(USERS, schema.span).starts_with("{\n  fields"));
+        assert_eq!(schema.table_name("users"), "users");
+
+        let fields = schema
+            .fields
+            .iter()
+            .map(|field| {
+                (
+                    field.name.value.as_str(),
+                    field.parse_type().unwrap().to_string(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            fields,
+            [
+                ("tenant", "uuid".to_string()),
+                ("id", "timeuuid".to_string()),
+                ("email", "text".to_string()),
+                ("tags", "set<text>".to_string()),
+                ("created_at", "timestamp".to_string()),
+                ("full_name", "text".to_string()),
+            ]
+        );
+
+        let tenant = schema.field("tenant").unwrap();
+        assert_eq!(text(USERS, tenant.span), "tenant: 'uuid'");
+        assert_eq!(text(USERS, tenant.cql_type.span), "uuid");
+
+        let email = schema.field("email").unwrap();
+        assert_eq!(text(USERS, email.cql_type.span), "text");
+        assert_eq!(email.unique.as_ref().map(|unique| unique.value), Some(true));
+        let validate = email.validate.as_ref().unwrap();
+        assert!(validate.is_email.as_ref().unwrap().value);
+        assert_eq!(validate.max_length.as_ref().unwrap().value, 255.0);
+        assert_eq!(
+            validate.pattern.as_ref().unwrap().kind,
+            ValueKind::Expression
+        );
+
+        let created_at = schema.field("created_at").unwrap();
+        assert_eq!(
+            text(USERS, created_at.default.as_ref().unwrap().span),
+            "() => new Date()"
+        );
+        assert!(
+            schema
+                .field("full_name")
+                .unwrap()
+                .is_virtual
+                .as_ref()
+                .unwrap()
+                .value
+        );
+
+        let key = schema.key.as_ref().unwrap();
+        assert_eq!(names(&key.partition), ["tenant"]);
+        assert_eq!(names(&key.clustering), ["id"]);
+        assert_eq!(text(USERS, key.clustering[0].span), "id");
+
+        assert_eq!(schema.clustering_order.len(), 1);
+        assert_eq!(schema.clustering_order[0].order.value, Order::Desc);
+        assert_eq!(text(USERS, schema.clustering_order[0].order.span), "DESC");
+
+        let relation = &schema.relations[0];
+        assert_eq!(relation.name.value, "posts");
+        assert_eq!(relation.kind.value, RelationKind::HasMany);
+        assert_eq!(relation.foreign_key.value, "user_id");
+
+        let index = &schema.indexes[0];
+        assert_eq!(index.name.as_ref().unwrap().value, "users_email_idx");
+        assert_eq!(names(&index.target), ["email"]);
+        assert_eq!(index.options[0].key.value, "mode");
+
+        let view = &schema.materialized_views[0];
+        assert_eq!(view.name.value, "users_by_email");
+        assert_eq!(names(&view.select), ["*"]);
+        assert_eq!(names(&view.key.partition), ["email"]);
+        assert_eq!(names(&view.key.clustering), ["tenant", "id"]);
+        assert_eq!(view.clustering_order[0].order.value, Order::Asc);
+
+        let options = schema.options.as_ref().unwrap();
+        let timestamps = options.timestamps.as_ref().unwrap();
+        assert!(timestamps.enabled);
+        assert_eq!(timestamps.created_at.as_ref().unwrap().value, "created_at");
+        assert!(timestamps.updated_at.is_none());
+        assert!(options.versions.as_ref().unwrap().enabled);
+        assert_eq!(
+            options.compaction.as_ref().unwrap().value[0].key.value,
+            "class"
+        );
+        assert_eq!(options.gc_grace_seconds.as_ref().unwrap().value, 864000.0);
+        assert_eq!(options.comment.as_ref().unwrap().value, "Application users");
+
+        assert_eq!(names(&schema.methods), ["greet"]);
+    }
+
+    #[test]
+    fn schemas_declared_as_variables_and_exports_are_found() {
+        let source = r#"
+import type { ModelSchema } from 'cassandraorm-js';
+
+const postSchema: ModelSchema = { fields: { id: 'uuid' }, key: 'id' };
+export const Posts = await client.loadSchema('posts', postSchema);
+
+export const eventSchema = {
+  fields: { day: 'date', at: 'timestamp' },
+  key: ['day', 'at'],
+  table_name: 'events'
+};
+export const settings = { theme: 'dark' };
+"#;
+        let models = find_models(source);
+        let model_names = models
+            .iter()
+            .map(|model| model.name.value.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(model_names, ["posts", "events"]);
+
+        let posts = models[0].schema.as_ref().unwrap();
+        assert_eq!(posts.fields[0].name.value, "id");
+        assert_eq!(
+            text(source, posts.span),
+            "{ fields: { id: 'uuid' }, key: 'id' }"
+        );
+        assert_eq!(names(&posts.key.as_ref().unwrap().partition), ["id"]);
+
+        let events = models[1].schema.as_ref().unwrap();
+        assert_eq!(events.table_name("eventSchema"), "events");
+        assert_eq!(text(source, models[1].name.span), "events");
+    }
+
+    #[test]
+    fn exported_schemas_are_named_after_the_load_schema_call_using_them() {
+        // What `cassandraorm generate model users` writes.
+        let source = r#"import { createClient } from 'cassandraorm-js';
+
+export interface Users {
+  id: string;
+  name: string;
+  created_at: Date;
+}
+
+export const usersSchema = {
+  fields: {
+    id: 'uuid',
+    name: 'text',
+    created_at: 'timestamp'
+  },
+  key: ['id']
+};
+
+// Usage example:
+// const client = createClient(config);
+// const UsersModel = await client.loadSchema<Users>('users', usersSchema);
+"#;
+        let models = find_models(source);
+        assert_eq!(models.len(), 1);
+        assert_eq!(models[0].name.value, "users");
+        assert_eq!(text(source, models[0].name.span), "users");
+        let schema = models[0].schema.as_ref().unwrap();
+        assert_eq!(schema.table_name(&models[0].name.value), "users");
+
+        let source = "
+export const Users = await client.loadSchema<User>('users', usersSchema);
+export const usersSchema = { fields: { id: 'uuid' }, key: ['id'] };
+export const auditSchema = { fields: { id: 'uuid' }, key: ['id'] };
+";
+        let models = find_models(source);
+        let model_names = models
+            .iter()
+            .map(|model| model.name.value.as_str())
+            .collect::<Vec<_>>();
+        // The table of an unreferenced schema without `tableName` is unknown.
+        assert_eq!(model_names, ["users"]);
+    }
+
+    #[test]
+    fn express_cassandra_index_lists_are_read() {
+        let source = "{ fields: { name: 'text' }, key: ['name'], indexes: ['name', 'keys(info)'] }";
+        let schema = parse_schema(source, 0).unwrap();
+        let targets = schema
+            .indexes
+            .iter()
+            .map(|index| (index.name.is_none(), index.target[0].value.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(targets, [(true, "name"), (true, "keys(info)")]);
+    }
+
+    #[test]
+    fn field_types_are_parsed_from_type_and_type_def() {
+        let source = "{ fields: { tags: { type: 'set', typeDef: '<varchar>' }, info: { type: 'map', typeDef: '<text, lst<int>>' } } }";
+        let schema = parse_schema(source, 0).unwrap();
+        let tags = schema.field("tags").unwrap().parse_type().unwrap();
+        assert_eq!(tags.to_string(), "set<text>");
+        assert_eq!(text(source, tags.span), "set', typeDef: '<varchar>");
+
+        let err = schema.field("info").unwrap().parse_type().unwrap_err();
+        assert_eq!(err.message, "unknown type `lst`");
+        assert_eq!(text(source, err.span), "lst");
+
+        // Offsets in a string with escapes can't be mapped back to the source.
+        let source = r"{ fields: { tags: '\tlst<int>é' } }";
+        let schema = parse_schema(source, 0).unwrap();
+        let err = schema.field("tags").unwrap().parse_type().unwrap_err();
+        assert_eq!(err.message, "unknown type `lst`");
+        assert_eq!(text(source, err.span), r"\tlst<int>é");
+    }
+
+    #[test]
+    fn user_types_are_found_in_udts_objects() {
+        let source = "
+const client = createClient({
+  ormOptions: {
+    udts: { address: { fields: { city: 'text' } }, 'phone_number': { fields: {} } }
+  }
+});
+const other = { udts: {} };
+";
+        assert_eq!(find_user_types(source), ["address", "phone_number"]);
+    }
+
+    #[test]
+    fn errors_name_the_path_and_point_at_the_value() {
+        let cases = [
+            ("{ key: 'id' }", "missing `fields`", "{ key: 'id' }"),
+            (
+                "{ fields: { id: 42 } }",
+                "fields.id: expected a CQL type or a field definition",
+                "42",
+            ),
+            (
+                "{ fields: { id: { required: true } } }",
+                "fields.id: missing `type`",
+                "{ required: true }",
+            ),
+            (
+                "{ fields: { id: 'uuid' }, key: [] }",
+                "key: the key is empty",
+                "[]",
+            ),
+            (
+                "{ fields: { id: 'uuid' }, key: [['a'], 3] }",
+                "key[1]: expected a string",
+                "3",
+            ),
+            (
+                "{ fields: {}, clustering_order: { id: 'down' } }",
+                "clustering_order.id: expected 'ASC' or 'DESC'",
+                "down",
+            ),
+            (
+                "{ fields: {}, relations: { a: { model: 'b', foreignKey: 'c', type: 'many' } } }",
+                "relations.a.type: expected 'hasOne', 'hasMany' or 'belongsTo'",
+                "many",
+            ),
+            (
+                "{ fields: {}, options: { gc_grace_seconds: '10' } }",
+                "options.gc_grace_seconds: expected a number",
+                "'10'",
+            ),
+            (
+                "{ fields: {}, options: { timestamps: 'yes' } }",
+                "options.timestamps: expected true, false or an object",
+                "'yes'",
+            ),
+            (
+                "{ fields: { id: 'uuid' } key: 'id' }",
+                "expected `,`",
+                "key",
+            ),
+        ];
+        for (source, message, at) in cases {
+            let err = parse_schema(source, 0).unwrap_err();
+            assert_eq!(err.message, message, "{source}");
+            assert_eq!(text(source, err.span), at, "{source}");
+        }
+    }
+
+    #[test]
+    fn unparsable_schemas_are_still_listed() {
+        let source = "client.loadSchema('broken', { fields: { id: 42 } });";
+        let models = find_models(source);
+        assert_eq!(models.len(), 1);
+        assert_eq!(models[0].name.value, "broken");
+        assert!(models[0].schema.is_err());
+    }
+}
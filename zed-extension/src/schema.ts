@@ -0,0 +1,635 @@
+// Typed model schemas, ported from the extension's `schema.rs`. Schemas are
+// read from the `loadSchema` calls and exported schema objects of a document
+// with `findModels`.
+//
+// Besides the shapes in `types.ts`, the forms the ORM still accepts from
+// express-cassandra are read too: `typeDef` on collection fields and `indexes`
+// given as a list of columns. Keys the ORM doesn't know are ignored, and every
+// definition keeps the span of the source it came from.
+
+import { CqlType, parseType } from './cql-type';
+import {
+    Property, SchemaError, Span, Spanned, Token, Value, get, isIdentifier, isPunct, lex, parseValue,
+    propertySpan, shrink, span, tokens
+} from './literal';
+
+// A model found in a document. `schema` is the error that stopped it from
+// being read, if any.
+export interface Model {
+    // The name passed to `loadSchema`, or the exported schema's table name
+    name: Spanned<string>;
+    schema: ModelSchema | SchemaError;
+    declaration: Declaration;
+}
+
+// How a model is declared, which code adding a model next to it follows. The
+// `head` of a `loadSchema` call is the source before `loadSchema`, from the
+// variable its result is assigned to, if any, through the receiver, as in
+// `export const Users = await client.`. That of an exported schema object is
+// the source before the object, as in `export const usersSchema = `.
+export type Declaration =
+    | { kind: 'loadSchema'; head: Span; binding?: Spanned<string> }
+    | { kind: 'export'; head: Span; binding: Spanned<string> };
+
+export interface ModelSchema {
+    span: Span;
+    fields: FieldDefinition[];
+    key?: PrimaryKey;
+    unique: Spanned<string>[];
+    clusteringOrder: ClusteringOrder[];
+    relations: RelationDefinition[];
+    indexes: IndexDefinition[];
+    materializedViews: MaterializedViewDefinition[];
+    options?: ModelOptions;
+    tableName?: Spanned<string>;
+    methods: Spanned<string>[];
+}
+
+export interface FieldDefinition {
+    name: Spanned<string>;
+    // The whole `name: definition` property
+    span: Span;
+    // The `type`, or the string of a shorthand field such as `id: 'uuid'`
+    cqlType: Spanned<string>;
+    // Type arguments given separately, as in `{ type: 'set', typeDef: '<text>' }`
+    typeDef?: Spanned<string>;
+    unique?: Spanned<boolean>;
+    required?: Spanned<boolean>;
+    default?: Value;
+    isVirtual?: Spanned<boolean>;
+    validate?: Validation;
+}
+
+export interface Validation {
+    span: Span;
+    required?: Spanned<boolean>;
+    isEmail?: Spanned<boolean>;
+    minLength?: Spanned<number>;
+    maxLength?: Spanned<number>;
+    min?: Spanned<number>;
+    max?: Spanned<number>;
+    pattern?: Value;
+    custom?: Value;
+}
+
+// A primary key. `key: 'id'` and `key: ['id', 'created_at']` have a single
+// partition column; `key: [['tenant', 'id'], 'created_at']` has two.
+export interface PrimaryKey {
+    span: Span;
+    partition: Spanned<string>[];
+    clustering: Spanned<string>[];
+}
+
+export type Order = 'asc' | 'desc';
+
+export interface ClusteringOrder {
+    column: Spanned<string>;
+    order: Spanned<Order>;
+}
+
+export type RelationKind = 'hasOne' | 'hasMany' | 'belongsTo';
+
+export interface RelationDefinition {
+    name: Spanned<string>;
+    span: Span;
+    model: Spanned<string>;
+    foreignKey: Spanned<string>;
+    kind: Spanned<RelationKind>;
+}
+
+export interface IndexDefinition {
+    // The index name, unless the index was listed as a bare column
+    name?: Spanned<string>;
+    span: Span;
+    target: Spanned<string>[];
+    options: Property[];
+}
+
+export interface MaterializedViewDefinition {
+    name: Spanned<string>;
+    span: Span;
+    select: Spanned<string>[];
+    key: PrimaryKey;
+    clusteringOrder: ClusteringOrder[];
+    filters?: Value;
+}
+
+export interface ModelOptions {
+    span: Span;
+    timestamps?: Timestamps;
+    versions?: Versions;
+    compaction?: Spanned<Property[]>;
+    compression?: Spanned<Property[]>;
+    gcGraceSeconds?: Spanned<number>;
+    defaultTimeToLive?: Spanned<number>;
+    bloomFilterFpChance?: Spanned<number>;
+    caching?: Spanned<Property[]>;
+    comment?: Spanned<string>;
+    tableName?: Spanned<string>;
+}
+
+// `timestamps: true` or `timestamps: { createdAt, updatedAt }`
+export interface Timestamps {
+    span: Span;
+    enabled: boolean;
+    createdAt?: Spanned<string>;
+    updatedAt?: Spanned<string>;
+}
+
+// `versions: true` or `versions: { key }`
+export interface Versions {
+    span: Span;
+    enabled: boolean;
+    key?: Spanned<string>;
+}
+
+export function primaryKeyColumns(key: PrimaryKey): Spanned<string>[] {
+    return [...key.partition, ...key.clustering];
+}
+
+export function field(schema: ModelSchema, name: string): FieldDefinition | undefined {
+    return schema.fields.find(f => f.name.value === name);
+}
+
+// The table the model is stored in: `table_name`, `options.table_name`, or
+// else the model name.
+export function tableName(schema: ModelSchema, modelName: string): string {
+    return (schema.tableName ?? schema.options?.tableName)?.value ?? modelName;
+}
+
+// Parses the CQL type of a field, together with its `typeDef` if it has one.
+// Spans point into the `type` and `typeDef` strings, or cover a whole string
+// that has escape sequences.
+export function parseFieldType(definition: FieldDefinition): CqlType {
+    const pieces: [string, Span][] = [[definition.cqlType.value, definition.cqlType.span]];
+    if (definition.typeDef) {
+        pieces.push([definition.typeDef.value, definition.typeDef.span]);
+    }
+    return parseType(pieces);
+}
+
+// Whether the field is a column of the table, rather than `virtual`.
+export function isStored(definition: FieldDefinition): boolean {
+    return !definition.isVirtual?.value;
+}
+
+// The stored fields whose type is `counter`. Cassandra calls a table with any
+// of them a counter table.
+export function counterFields(schema: ModelSchema): FieldDefinition[] {
+    return schema.fields.filter(definition => {
+        if (!isStored(definition)) {
+            return false;
+        }
+        try {
+            const ty = parseFieldType(definition);
+            return ty.kind === 'native' && ty.name === 'counter';
+        } catch {
+            return false;
+        }
+    });
+}
+
+// The columns `timestamps` and `versions` add besides the fields, with
+// express-cassandra's default names, as `[name, CQL type]`.
+export function generatedColumns(schema: ModelSchema): [string, string][] {
+    const columns: [string, string][] = [];
+    const timestamps = schema.options?.timestamps;
+    if (timestamps?.enabled) {
+        columns.push([timestamps.createdAt?.value ?? 'createdAt', 'timestamp']);
+        columns.push([timestamps.updatedAt?.value ?? 'updatedAt', 'timestamp']);
+    }
+    const versions = schema.options?.versions;
+    if (versions?.enabled) {
+        columns.push([versions.key?.value ?? '__v', 'timeuuid']);
+    }
+    return columns;
+}
+
+// Finds the models in a document: `loadSchema('name', { ... })` calls, with the
+// schema inline or declared as a variable in the same document, and exported
+// schema objects such as `export const usersSchema = { fields }`.
+//
+// An exported schema is named after the `loadSchema` call that loads it, which
+// may be a usage example in a comment like the one `cassandraorm generate
+// model` writes, or else after its `tableName`. Schemas with neither are left
+// out since their table is decided elsewhere.
+export function findModels(source: string): Model[] {
+    const { tokens: all, comments } = lex(source);
+    const declared = variables(all);
+    const objects = declared.filter(variable => isPunct(variable.value, '{'));
+
+    const models: Model[] = [];
+    const loaded: string[] = [];
+    for (const { call, name, schema } of loadSchemaCalls(all)) {
+        let offset: number | undefined;
+        if (isPunct(schema, '{')) {
+            offset = schema.span.start;
+        } else if (schema.kind === 'identifier') {
+            loaded.push(schema.value);
+            offset = objects.find(variable => variable.binding.value === schema.value)?.value.span.start;
+        }
+        if (offset === undefined) {
+            continue;
+        }
+        models.push({
+            name,
+            schema: attempt(() => parseSchema(source, offset as number)),
+            declaration: loadSchemaDeclaration(all, call, declared)
+        });
+    }
+
+    const examples = loadSchemaCalls(comments.flatMap(comment => lex(source, comment.start, comment.end).tokens));
+    for (const { start, binding, value: object, exported } of objects) {
+        if (!exported || loaded.includes(binding.value)) {
+            continue;
+        }
+        let value: Value;
+        try {
+            value = parseValue(source, object.span.start);
+        } catch {
+            continue;
+        }
+        if (!get(value, 'fields')) {
+            continue;
+        }
+        const schema = attempt(() => schemaFromValue(value));
+        const tableName = schema instanceof SchemaError ? undefined : schema.tableName ?? schema.options?.tableName;
+        const name = examples.find(example => isIdentifier(example.schema, binding.value))?.name ?? tableName;
+        if (name) {
+            const declaration: Declaration = { kind: 'export', head: span(start, object.span.start), binding };
+            models.push({ name, schema, declaration });
+        }
+    }
+    return models.sort((a, b) => a.name.span.start - b.name.span.start);
+}
+
+// The index of the `loadSchema` token, the model name and the token starting
+// the schema of each `loadSchema('name', schema)` call, type arguments such as
+// `loadSchema<User>(...)` included.
+function loadSchemaCalls(all: Token[]): { call: number; name: Spanned<string>; schema: Token }[] {
+    const calls: { call: number; name: Spanned<string>; schema: Token }[] = [];
+    all.forEach((token, i) => {
+        if (!isIdentifier(token, 'loadSchema')) {
+            return;
+        }
+        let next = i + 1;
+        if (isPunct(all[next], '<')) {
+            for (let depth = 0; next < all.length; next++) {
+                depth += isPunct(all[next], '<') ? 1 : isPunct(all[next], '>') ? -1 : 0;
+                if (depth === 0) {
+                    break;
+                }
+            }
+            next++;
+        }
+        const [open, name, comma, schema] = all.slice(next, next + 4);
+        if (!schema || name.kind !== 'string' || !isPunct(open, '(') || !isPunct(comma, ',')) {
+            return;
+        }
+        calls.push({ call: i, name: { value: name.value, span: shrink(name.span, 1) }, schema });
+    });
+    return calls;
+}
+
+// The declaration of the `loadSchema` call at `all[call]`: its receiver, such
+// as `this.client.`, an `await`, and the variable it's assigned to.
+function loadSchemaDeclaration(all: Token[], call: number, declared: Variable[]): Declaration {
+    let start = call;
+    while (start >= 2 && isPunct(all[start - 1], '.') && all[start - 2].kind === 'identifier') {
+        start -= 2;
+    }
+    if (start >= 1 && isIdentifier(all[start - 1], 'await')) {
+        start -= 1;
+    }
+    const variable = start < call ? declared.find(v => v.value.span.start === all[start].span.start) : undefined;
+    return {
+        kind: 'loadSchema',
+        head: span(variable?.start ?? all[start].span.start, all[call].span.start),
+        binding: variable?.binding
+    };
+}
+
+// Finds the user-defined types a document declares in `udts` objects, such as
+// the `ormOptions` of `createClient({ ormOptions: { udts: { address } } })`.
+export function findUserTypes(source: string): string[] {
+    const all = tokens(source);
+    const names: string[] = [];
+    all.forEach((token, i) => {
+        const [colon, open] = all.slice(i + 1, i + 3);
+        if (!isIdentifier(token, 'udts') || !isPunct(colon, ':') || !isPunct(open, '{')) {
+            return;
+        }
+        try {
+            const value = parseValue(source, open.span.start);
+            if (value.kind === 'object') {
+                names.push(...value.properties.map(property => property.key.value));
+            }
+        } catch {
+            // Not an object literal after all.
+        }
+    });
+    return names;
+}
+
+// Parses the schema object starting at `offset` of `source`.
+export function parseSchema(source: string, offset: number): ModelSchema {
+    return schemaFromValue(parseValue(source, offset));
+}
+
+function attempt<T>(read: () => T): T | SchemaError {
+    try {
+        return read();
+    } catch (err) {
+        if (err instanceof SchemaError) {
+            return err;
+        }
+        throw err;
+    }
+}
+
+// A `const name = value` declaration, with an optional type annotation and
+// `export`
+interface Variable {
+    // Where `export`, or else `const`, starts
+    start: number;
+    binding: Spanned<string>;
+    // The first token of the value
+    value: Token;
+    exported: boolean;
+}
+
+// Finds the variable declarations among `all`.
+function variables(all: Token[]): Variable[] {
+    const result: Variable[] = [];
+    all.forEach((token, i) => {
+        const name = all[i + 1];
+        if (!['const', 'let', 'var'].some(k => isIdentifier(token, k)) || name?.kind !== 'identifier') {
+            return;
+        }
+        let next = i + 2;
+        if (isPunct(all[next], ':')) {
+            // Skip a type annotation such as `ModelSchema` or `Schema<User>`.
+            while (next < all.length && !isPunct(all[next], '=')) {
+                next++;
+            }
+        }
+        if (!isPunct(all[next], '=') || !all[next + 1]) {
+            return;
+        }
+        const exported = i > 0 && isIdentifier(all[i - 1], 'export');
+        result.push({
+            start: all[exported ? i - 1 : i].span.start,
+            binding: { value: name.value, span: name.span },
+            value: all[next + 1],
+            exported
+        });
+    });
+    return result;
+}
+
+function schemaFromValue(value: Value): ModelSchema {
+    const schema = new Fields(value, '');
+    return {
+        span: value.span,
+        fields: schema.required('fields', (fields, path) => object(fields, path).map(fieldFromProperty)),
+        key: schema.optional('key', primaryKey),
+        unique: schema.optional('unique', strings) ?? [],
+        clusteringOrder: schema.optional('clustering_order', clusteringOrder) ?? [],
+        relations: schema.optional('relations', (relations, path) => object(relations, path).map(relation)) ?? [],
+        indexes: schema.optional('indexes', indexes) ?? [],
+        materializedViews: schema.optional('materialized_views', (views, path) =>
+            object(views, path).map(materializedView)) ?? [],
+        options: schema.optional('options', modelOptions),
+        tableName: schema.optional('table_name', string),
+        methods: schema.optional('methods', (methods, path) => object(methods, path).map(method => method.key)) ?? []
+    };
+}
+
+function fieldFromProperty(property: Property): FieldDefinition {
+    const path = `fields.${property.key.value}`;
+    const value = property.value;
+    const definition: FieldDefinition = {
+        name: property.key,
+        span: propertySpan(property),
+        cqlType: { value: '', span: value.span }
+    };
+    if (value.kind === 'string') {
+        definition.cqlType = { value: value.value, span: shrink(value.span, 1) };
+    } else if (value.kind === 'object') {
+        const fields = new Fields(value, path);
+        definition.cqlType = fields.required('type', string);
+        definition.typeDef = fields.optional('typeDef', string);
+        definition.unique = fields.optional('unique', boolean);
+        definition.required = fields.optional('required', boolean);
+        definition.default = fields.value('default');
+        definition.isVirtual = fields.optional('virtual', boolean);
+        definition.validate = fields.optional('validate', validation);
+    } else {
+        throw invalid(path, 'expected a CQL type or a field definition', value.span);
+    }
+    return definition;
+}
+
+function validation(value: Value, path: string): Validation {
+    const rules = new Fields(value, path);
+    return {
+        span: value.span,
+        required: rules.optional('required', boolean),
+        isEmail: rules.optional('isEmail', boolean),
+        minLength: rules.optional('minLength', number),
+        maxLength: rules.optional('maxLength', number),
+        min: rules.optional('min', number),
+        max: rules.optional('max', number),
+        pattern: rules.value('pattern'),
+        custom: rules.value('custom')
+    };
+}
+
+function primaryKey(value: Value, path: string): PrimaryKey {
+    const key: PrimaryKey = { span: value.span, partition: [], clustering: [] };
+    if (value.kind === 'string') {
+        key.partition.push(string(value, path));
+    } else if (value.kind === 'array') {
+        const [first, ...rest] = value.items;
+        if (!first) {
+            throw invalid(path, 'the key is empty', value.span);
+        }
+        key.partition = first.kind === 'array' ? strings(first, `${path}[0]`) : [string(first, `${path}[0]`)];
+        key.clustering = rest.map((item, i) => string(item, `${path}[${i + 1}]`));
+    } else {
+        throw invalid(path, 'expected a column or a list of columns', value.span);
+    }
+    return key;
+}
+
+function relation(property: Property): RelationDefinition {
+    const path = `relations.${property.key.value}`;
+    const fields = new Fields(property.value, path);
+    return {
+        name: property.key,
+        span: propertySpan(property),
+        model: fields.required('model', string),
+        foreignKey: fields.required('foreignKey', string),
+        kind: fields.required('type', (value, path) => {
+            const kind = string(value, path);
+            if (kind.value !== 'hasOne' && kind.value !== 'hasMany' && kind.value !== 'belongsTo') {
+                throw invalid(path, "expected 'hasOne', 'hasMany' or 'belongsTo'", kind.span);
+            }
+            return { value: kind.value, span: kind.span };
+        })
+    };
+}
+
+// Reads `indexes: { name: { target } }` or `indexes: ['column', ...]`.
+function indexes(value: Value, path: string): IndexDefinition[] {
+    if (value.kind === 'array') {
+        return value.items.map((item, i) => ({
+            span: item.span,
+            target: [string(item, `${path}[${i}]`)],
+            options: []
+        }));
+    }
+    return object(value, path).map(property => {
+        const index = new Fields(property.value, `${path}.${property.key.value}`);
+        return {
+            name: property.key,
+            span: propertySpan(property),
+            target: index.required('target', (target, targetPath) =>
+                target.kind === 'array' ? strings(target, targetPath) : [string(target, targetPath)]),
+            options: index.optional('options', object) ?? []
+        };
+    });
+}
+
+function materializedView(property: Property): MaterializedViewDefinition {
+    const view = new Fields(property.value, `materialized_views.${property.key.value}`);
+    return {
+        name: property.key,
+        span: propertySpan(property),
+        select: view.required('select', strings),
+        key: view.required('key', primaryKey),
+        clusteringOrder: view.optional('clustering_order', clusteringOrder) ?? [],
+        filters: view.value('filters')
+    };
+}
+
+function modelOptions(value: Value, path: string): ModelOptions {
+    const options = new Fields(value, path);
+    const map = (properties: Value, mapPath: string) => ({ value: object(properties, mapPath), span: properties.span });
+    return {
+        span: value.span,
+        timestamps: options.optional('timestamps', (timestamps, timestampsPath) => {
+            const [enabled, fields] = toggle(timestamps, timestampsPath);
+            return {
+                span: timestamps.span,
+                enabled,
+                createdAt: fields?.optional('createdAt', string),
+                updatedAt: fields?.optional('updatedAt', string)
+            };
+        }),
+        versions: options.optional('versions', (versions, versionsPath) => {
+            const [enabled, fields] = toggle(versions, versionsPath);
+            return { span: versions.span, enabled, key: fields?.optional('key', string) };
+        }),
+        compaction: options.optional('compaction', map),
+        compression: options.optional('compression', map),
+        gcGraceSeconds: options.optional('gc_grace_seconds', number),
+        defaultTimeToLive: options.optional('default_time_to_live', number),
+        bloomFilterFpChance: options.optional('bloom_filter_fp_chance', number),
+        caching: options.optional('caching', map),
+        comment: options.optional('comment', string),
+        tableName: options.optional('table_name', string)
+    };
+}
+
+// The properties of an object being read, with the path used in errors
+class Fields {
+    private object: Value;
+    private path: string;
+
+    constructor(value: Value, path: string) {
+        object(value, path);
+        this.object = value;
+        this.path = path;
+    }
+
+    value(key: string): Value | undefined {
+        return get(this.object, key);
+    }
+
+    optional<T>(key: string, read: (value: Value, path: string) => T): T | undefined {
+        const value = this.value(key);
+        return value && read(value, this.path ? `${this.path}.${key}` : key);
+    }
+
+    required<T>(key: string, read: (value: Value, path: string) => T): T {
+        const value = this.value(key);
+        if (!value) {
+            throw invalid(this.path, `missing \`${key}\``, this.object.span);
+        }
+        return read(value, this.path ? `${this.path}.${key}` : key);
+    }
+}
+
+// Creates an error about the value at `path`, such as `fields.email.type`.
+function invalid(path: string, message: string, at: Span): SchemaError {
+    return new SchemaError(path ? `${path}: ${message}` : message, at);
+}
+
+function object(value: Value, path: string): Property[] {
+    if (value.kind !== 'object') {
+        throw invalid(path, 'expected an object', value.span);
+    }
+    return value.properties;
+}
+
+function string(value: Value, path: string): Spanned<string> {
+    if (value.kind !== 'string') {
+        throw invalid(path, 'expected a string', value.span);
+    }
+    return { value: value.value, span: shrink(value.span, 1) };
+}
+
+function strings(value: Value, path: string): Spanned<string>[] {
+    if (value.kind !== 'array') {
+        throw invalid(path, 'expected a list of strings', value.span);
+    }
+    return value.items.map((item, i) => string(item, `${path}[${i}]`));
+}
+
+function boolean(value: Value, path: string): Spanned<boolean> {
+    if (value.kind !== 'bool') {
+        throw invalid(path, 'expected true or false', value.span);
+    }
+    return { value: value.value, span: value.span };
+}
+
+function number(value: Value, path: string): Spanned<number> {
+    if (value.kind !== 'number') {
+        throw invalid(path, 'expected a number', value.span);
+    }
+    return { value: value.value, span: value.span };
+}
+
+// Reads `true`, `false` or an object of settings, which enables the option.
+function toggle(value: Value, path: string): [boolean, Fields | undefined] {
+    if (value.kind === 'bool') {
+        return [value.value, undefined];
+    }
+    if (value.kind === 'object') {
+        return [true, new Fields(value, path)];
+    }
+    throw invalid(path, 'expected true, false or an object', value.span);
+}
+
+function clusteringOrder(value: Value, path: string): ClusteringOrder[] {
+    return object(value, path).map(property => {
+        const orderPath = `${path}.${property.key.value}`;
+        const order = string(property.value, orderPath);
+        const direction = order.value.toLowerCase();
+        if (direction !== 'asc' && direction !== 'desc') {
+            throw invalid(orderPath, "expected 'ASC' or 'DESC'", order.span);
+        }
+        return { column: property.key, order: { value: direction, span: order.span } };
+    });
+}
@@ -0,0 +1,177 @@
+use crate::workspace::{depends_on, join_path};
+use zed_extension_api::{self as zed, Result};
+
+/// How a located language server has to be launched.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ServerBinary {
+    /// A JavaScript entry point, run with Node.
+    NodeScript(String),
+    /// A native executable or shell shim, run directly.
+    Executable(String),
+}
+
+impl ServerBinary {
+    /// Classifies a path configured in `lsp.<server>.binary.path`.
+    pub fn from_configured_path(path: String) -> Self {
+        if [".js", ".mjs", ".cjs"]
+            .iter()
+            .any(|extension| path.ends_with(extension))
+        {
+            Self::NodeScript(path)
+        } else {
+            Self::Executable(path)
+        }
+    }
+}
+
+/// Overlays `overrides` on `defaults`, replacing variables that are already set.
+pub fn merge_env(
+    mut defaults: zed::EnvVars,
+    overrides: impl IntoIterator<Item = (String, String)>,
+) -> zed::EnvVars {
+    let mut overrides = overrides.into_iter().collect::<Vec<_>>();
+    overrides.sort();
+    for (key, value) in overrides {
+        match defaults.iter_mut().find(|(existing, _)| *existing == key) {
+            Some(entry) => entry.1 = value,
+            None => defaults.push((key, value)),
+        }
+    }
+    defaults
+}
+
+/// Finds the project's own copy of a server published as the npm package
+/// `binary_name`: the script its `package.json` names as the `binary_name`
+/// bin when the package folder depends on the package and has it installed,
+/// or else `binary_name` on the `$PATH` through [`zed::Worktree::which`].
+///
+/// The script is run with Node rather than through `node_modules/.bin`, whose
+/// entries are `.cmd` shims on Windows.
+pub fn locate_node_bin(worktree: &zed::Worktree, binary_name: &str) -> Result<ServerBinary> {
+    let read_file = |path: &str| worktree.read_text_file(path).ok();
+    let depends =
+        read_file("package.json").is_some_and(|package| depends_on(&package, binary_name));
+    if depends {
+        if let Some(script) = installed_bin(read_file, binary_name) {
+            return Ok(ServerBinary::NodeScript(join_path(
+                &worktree.root_path(),
+                &script,
+            )));
+        }
+    }
+    if let Some(path) = worktree.which(binary_name) {
+        return Ok(ServerBinary::Executable(path));
+    }
+    let manifest = join_path(&worktree.root_path(), "package.json");
+    Err(if depends {
+        format!(
+            "{binary_name} is a dependency of {manifest} but isn't installed, and isn't on $PATH"
+        )
+    } else {
+        format!("{binary_name} isn't a dependency of {manifest} and isn't on $PATH")
+    })
+}
+
+/// The path of the script the installed npm package `binary_name` runs as its
+/// `binary_name` bin, relative to the package folder, if the script exists.
+fn installed_bin(read_file: impl Fn(&str) -> Option<String>, binary_name: &str) -> Option<String> {
+    let package_dir = format!("node_modules/{binary_name}");
+    let manifest = read_file(&format!("{package_dir}/package.json"))?;
+    let manifest = serde_json::from_str::<serde_json::Value>(&manifest).ok()?;
+    // A string `bin` is named after the package.
+    let bin = match &manifest["bin"] {
+        serde_json::Value::String(bin) => bin.as_str(),
+        bins => bins.get(binary_name)?.as_str()?,
+    };
+    let script = join_path(&package_dir, bin.trim_start_matches("./"));
+    read_file(&script).map(|_| script)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn configured_scripts_run_with_node() {
+        assert_eq!(
+            ServerBinary::from_configured_path("/dev/lsp/dist/lsp-server.js".into()),
+            ServerBinary::NodeScript("/dev/lsp/dist/lsp-server.js".into())
+        );
+        assert_eq!(
+            ServerBinary::from_configured_path("/usr/local/bin/cassandraorm-lsp".into()),
+            ServerBinary::Executable("/usr/local/bin/cassandraorm-lsp".into())
+        );
+    }
+
+    #[test]
+    fn installed_bins_resolve_to_their_scripts() {
+        let files = |files: &'static [(&'static str, &'static str)]| {
+            move |path: &str| {
+                files
+                    .iter()
+                    .find(|(name, _)| *name == path)
+                    .map(|(_, text)| text.to_string())
+            }
+        };
+
+        let map = files(&[
+            (
+                "node_modules/typescript-language-server/package.json",
+                r#"{ "bin": { "typescript-language-server": "./lib/cli.mjs" } }"#,
+            ),
+            ("node_modules/typescript-language-server/lib/cli.mjs", ""),
+        ]);
+        assert_eq!(
+            installed_bin(map, "typescript-language-server").as_deref(),
+            Some("node_modules/typescript-language-server/lib/cli.mjs")
+        );
+
+        let string = files(&[
+            (
+                "node_modules/cassandraorm-lsp/package.json",
+                r#"{ "bin": "dist/lsp-server.js" }"#,
+            ),
+            ("node_modules/cassandraorm-lsp/dist/lsp-server.js", ""),
+        ]);
+        assert_eq!(
+            installed_bin(string, "cassandraorm-lsp").as_deref(),
+            Some("node_modules/cassandraorm-lsp/dist/lsp-server.js")
+        );
+
+        // Listed in package.json but never installed, or installed without
+        // its build output
+        assert_eq!(installed_bin(files(&[]), "cassandraorm-lsp"), None);
+        let unbuilt = files(&[(
+            "node_modules/cassandraorm-lsp/package.json",
+            r#"{ "bin": "dist/lsp-server.js" }"#,
+        )]);
+        assert_eq!(installed_bin(unbuilt, "cassandraorm-lsp"), None);
+        let other_bin = files(&[
+            (
+                "node_modules/cassandraorm-lsp/package.json",
+                r#"{ "bin": { "cassandraorm": "dist/cli.js" } }"#,
+            ),
+            ("node_modules/cassandraorm-lsp/dist/cli.js", ""),
+        ]);
+        assert_eq!(installed_bin(other_bin, "cassandraorm-lsp"), None);
+    }
+
+    #[test]
+    fn merge_env_overrides_existing_variables() {
+        let defaults = vec![("NODE_ENV".to_string(), "production".to_string())];
+        let merged = merge_env(
+            defaults,
+            [
+                ("NODE_ENV".to_string(), "development".to_string()),
+                ("CASSANDRA_KEYSPACE".to_string(), "app".to_string()),
+            ],
+        );
+        assert_eq!(
+            merged,
+            vec![
+                ("NODE_ENV".to_string(), "development".to_string()),
+                ("CASSANDRA_KEYSPACE".to_string(), "app".to_string()),
+            ]
+        );
+    }
+}
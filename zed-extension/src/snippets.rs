@@ -0,0 +1,133 @@
+//! Keeps the snippets in `snippets/` in step with the VS Code extension's
+//! `vscode-extension/snippets/typescript.json`, which is where new snippets are
+//! usually added first.
+
+use serde::Deserialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Snippet {
+    prefix: String,
+    body: Vec<String>,
+    description: String,
+}
+
+type Snippets = BTreeMap<String, Snippet>;
+
+fn read_snippets(path: &str) -> Snippets {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(path);
+    let text = fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("failed to read {}: {err}", path.display()));
+    serde_json::from_str(&text).unwrap_or_else(|err| panic!("{} is invalid: {err}", path.display()))
+}
+
+/// Returns the tab stops of a snippet body, `${2:id}` as `(2, "id")` and `$0`
+/// as `(0, "")`.
+fn tab_stops(body: &[String]) -> BTreeSet<(u32, String)> {
+    let mut stops = BTreeSet::new();
+    for line in body {
+        let mut rest = line.as_str();
+        while let Some(start) = rest.find('$') {
+            rest = &rest[start + 1..];
+            let (index, placeholder) = if let Some(inner) = rest.strip_prefix('{') {
+                let digits =
+                    inner.len() - inner.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+                let Some(after) = inner[digits..].strip_prefix(':') else {
+                    continue;
+                };
+                let end = after.find('}').expect("placeholders are closed");
+                (&inner[..digits], &after[..end])
+            } else {
+                let digits =
+                    rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+                (&rest[..digits], "")
+            };
+            if let Ok(index) = index.parse() {
+                stops.insert((index, placeholder.to_string()));
+            }
+        }
+    }
+    stops
+}
+
+#[test]
+fn typescript_snippets_match_vscode() {
+    assert_eq!(
+        read_snippets("snippets/typescript.json"),
+        read_snippets("../vscode-extension/snippets/typescript.json"),
+        "zed-extension/snippets/typescript.json must be a copy of the VS Code snippets"
+    );
+}
+
+#[test]
+fn javascript_snippets_mirror_typescript() {
+    let typescript = read_snippets("snippets/typescript.json");
+    let javascript = read_snippets("snippets/javascript.json");
+    assert_eq!(
+        javascript.keys().collect::<Vec<_>>(),
+        typescript.keys().collect::<Vec<_>>()
+    );
+    for (name, snippet) in &javascript {
+        let original = &typescript[name];
+        assert_eq!(snippet.prefix, original.prefix, "{name}");
+        assert_eq!(snippet.description, original.description, "{name}");
+        assert_eq!(
+            tab_stops(&snippet.body),
+            tab_stops(&original.body),
+            "{name} has different tab stops in JavaScript"
+        );
+    }
+}
+
+#[test]
+fn snippets_have_unique_prefixes_and_consistent_tab_stops() {
+    for path in [
+        "snippets/typescript.json",
+        "snippets/javascript.json",
+        "snippets/cql.json",
+    ] {
+        let snippets = read_snippets(path);
+        let mut prefixes = BTreeSet::new();
+        for (name, snippet) in &snippets {
+            assert!(
+                snippet.prefix.starts_with("cassandra-"),
+                "{path}: {name} has prefix {}",
+                snippet.prefix
+            );
+            assert!(
+                prefixes.insert(&snippet.prefix),
+                "{path}: prefix {} is used twice",
+                snippet.prefix
+            );
+
+            // A tab stop that appears more than once mirrors the same text, so
+            // every occurrence must use the same placeholder.
+            let stops = tab_stops(&snippet.body);
+            let indexes = stops
+                .iter()
+                .map(|(index, _)| index)
+                .collect::<BTreeSet<_>>();
+            assert_eq!(
+                indexes.len(),
+                stops.len(),
+                "{path}: {name} gives one tab stop different placeholders: {stops:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn tab_stops_are_parsed() {
+    let body = vec!["const ${1:User} = ${1:User}.find({ $2 }); $0".to_string()];
+    assert_eq!(
+        tab_stops(&body),
+        BTreeSet::from([
+            (0, String::new()),
+            (1, "User".to_string()),
+            (2, String::new())
+        ])
+    );
+}
@@ -0,0 +1,44 @@
+pub fn join_path(dir: &str, relative: &str) -> String {
+    format!("{}/{relative}", dir.trim_end_matches(['/', '\\']))
+}
+
+/// Whether a `package.json` lists `package` among its dependencies.
+pub fn depends_on(package_json: &str, package: &str) -> bool {
+    let Ok(manifest) = serde_json::from_str::<serde_json::Value>(package_json) else {
+        return false;
+    };
+    [
+        "dependencies",
+        "devDependencies",
+        "optionalDependencies",
+        "peerDependencies",
+    ]
+    .iter()
+    .any(|section| manifest[section].get(package).is_some())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn join_path_avoids_double_separators() {
+        assert_eq!(
+            join_path("/", "node_modules/.bin/tsserver"),
+            "/node_modules/.bin/tsserver"
+        );
+        assert_eq!(join_path("/repo/", "a"), "/repo/a");
+    }
+
+    #[test]
+    fn depends_on_checks_every_dependency_section() {
+        let package = r#"{
+            "dependencies": { "cassandraorm-js": "^2.1.0" },
+            "devDependencies": { "cassandraorm-lsp": "1.0.0" }
+        }"#;
+        assert!(depends_on(package, "cassandraorm-js"));
+        assert!(depends_on(package, "cassandraorm-lsp"));
+        assert!(!depends_on(package, "typescript-language-server"));
+        assert!(!depends_on("{ not json", "cassandraorm-js"));
+    }
+}
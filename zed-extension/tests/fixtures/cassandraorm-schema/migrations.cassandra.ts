@@ -0,0 +1,24 @@
+// @cassandraorm
+await client.execute(`SELECT * FROM users WHERE id = ?`, [id]);
+await client.eachRow('SELECT email FROM users', [], onRow);
+
+await migrationManager.addColumn('users', 'phone', 'text');
+await this.migrations.run(`ALTER TABLE users ADD phone text`);
+
+const query = cql`UPDATE users SET email = ? WHERE id = ?`;
+
+evolution
+  .migration('001_add_user_preferences', 'Add preferences column')
+  .addColumn('users', 'preferences', 'map<text,text>');
+
+evolution.addMigration({
+  id: '002_drop_legacy',
+  description: 'Drop the legacy table',
+  up: async () => {
+    await client.execute(`DROP TABLE IF EXISTS legacy`);
+  }
+});
+
+// Interpolations split a template into fragments, parsed as one statement.
+const byEmail = cql`SELECT * FROM ${keyspace}.users WHERE email = ?`;
+await client.execute(`SELECT * FROM users WHERE id IN (${ids.map(() => '?').join(', ')})`, ids);
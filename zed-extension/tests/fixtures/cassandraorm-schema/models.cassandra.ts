@@ -0,0 +1,33 @@
+// @cassandraorm
+import { createEnhancedClient } from 'cassandraorm-js';
+
+const client = createEnhancedClient({ clientOptions: { keyspace: 'myapp' } });
+
+const postSchema = {
+  fields: { id: 'uuid', title: 'text' },
+  key: ['id']
+};
+
+export const users = await client.loadSchema('users', {
+  fields: {
+    tenant: 'uuid',
+    id: 'timeuuid',
+    email: { type: 'text', validate: { required: true, isEmail: true } },
+    tags: 'set<text>'
+  },
+  key: [['tenant'], 'id'],
+  clustering_order: { id: 'desc' },
+  relations: {
+    posts: { model: 'posts', foreignKey: 'user_id', type: 'hasMany' }
+  },
+  indexes: {
+    users_email_idx: { on: 'email' }
+  },
+  materialized_views: {
+    users_by_email: { select: ['*'], key: ['email', 'tenant', 'id'] }
+  }
+});
+
+const found = await users.findOne({ email });
+const vector = await client.generateEmbedding('search text');
+await client.withDistributedLock('users', async () => {});